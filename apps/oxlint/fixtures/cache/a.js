@@ -0,0 +1,3 @@
+import { b } from './b.js';
+debugger;
+export const a = b;
@@ -0,0 +1,5 @@
+/* global foo: yes */
+/* eslint-env nope */
+/* eslint eqeqeq: ["error" */
+/* eslint no-debugger: loud, no-such-rule: error */
+/* eslint react/jsx-key: error */
@@ -0,0 +1,8 @@
+/* global foo, bar: writable */
+/* eslint-env node */
+/* eslint eqeqeq: ["error", "smart"], no-debugger: off -- legacy code */
+
+foo(process, bar, baz);
+if (foo == null) {}
+if (foo == 1) {}
+debugger;
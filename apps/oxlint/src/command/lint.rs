@@ -35,6 +35,12 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
+    #[bpaf(external)]
+    pub suppression_options: SuppressionOptions,
+
     /// List all the rules that are currently registered
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
@@ -243,9 +249,49 @@ pub struct WarningOptions {
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
     /// Use a specific output format. Possible values:
-    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `stylish`, `unix`
+    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `sarif`, `stylish`, `unix`
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
+
+    /// Report the time spent in each rule. The slowest rules are printed after linting,
+    /// all rules are included in the `json` format. Not supported by other formats
+    #[bpaf(switch, hide_usage)]
+    pub timing: bool,
+
+    /// Number of rules printed by `--timing`
+    #[bpaf(argument("INT"), fallback(10), display_fallback, hide_usage)]
+    pub timing_rows: usize,
+}
+
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint files that changed since the last run, reusing the stored results of unchanged
+    /// files. A file is linted again when a module it imports changes.
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path to the cache file or directory. Defaults to `.oxlintcache`.
+    /// A path ending with a path separator is always a directory, and is created if needed.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub cache_location: Option<PathBuf>,
+}
+
+/// Suppressing Violations
+#[derive(Debug, Default, Clone, Bpaf)]
+pub struct SuppressionOptions {
+    /// Record all current violations in `oxlint-suppressions.json`, so that only new violations are reported
+    #[bpaf(switch, hide_usage)]
+    pub suppress_all: bool,
+
+    /// Record the current violations of a rule in `oxlint-suppressions.json`.
+    /// Accepts a rule name (`no-debugger`) or a rule with its plugin (`eslint/no-debugger`)
+    #[bpaf(argument("NAME"), many, hide_usage)]
+    pub suppress_rule: Vec<String>,
+
+    /// Remove suppressions from `oxlint-suppressions.json` that no longer occur
+    #[bpaf(switch, hide_usage)]
+    pub prune_suppressions: bool,
 }
 
 /// Enable/Disable Plugins
@@ -430,6 +476,11 @@ pub enum ReportUnusedDirectives {
 /// Inline Configuration Comments
 #[derive(Debug, Clone, Bpaf)]
 pub struct InlineConfigOptions {
+    /// Ignore configuration comments, like `/* global foo */`, `/* eslint-env node */`,
+    /// `/* eslint eqeqeq: "error" */` and `// eslint-disable-line`
+    #[bpaf(switch, hide_usage)]
+    pub no_inline_config: bool,
+
     #[bpaf(external)]
     pub report_unused_directives: ReportUnusedDirectives,
 }
@@ -566,6 +617,35 @@ mod lint_options {
         assert!(options.fix_options.fix);
     }
 
+    #[test]
+    fn cache() {
+        let options = get_lint_options("test.js");
+        assert!(!options.cache_options.cache);
+        assert_eq!(options.cache_options.cache_location, None);
+
+        let options = get_lint_options("--cache --cache-location .cache/oxlint test.js");
+        assert!(options.cache_options.cache);
+        assert_eq!(options.cache_options.cache_location, Some(PathBuf::from(".cache/oxlint")));
+    }
+
+    #[test]
+    fn suppressions() {
+        let options = get_lint_options("test.js");
+        assert!(!options.suppression_options.suppress_all);
+        assert!(options.suppression_options.suppress_rule.is_empty());
+        assert!(!options.suppression_options.prune_suppressions);
+
+        let options = get_lint_options(
+            "--suppress-all --suppress-rule no-debugger --suppress-rule eslint/no-var --prune-suppressions test.js",
+        );
+        assert!(options.suppression_options.suppress_all);
+        assert_eq!(
+            options.suppression_options.suppress_rule,
+            ["no-debugger".to_string(), "eslint/no-var".to_string()]
+        );
+        assert!(options.suppression_options.prune_suppressions);
+    }
+
     #[test]
     fn filter() {
         let options =
@@ -588,6 +668,29 @@ mod lint_options {
         assert!(options.paths.is_empty());
     }
 
+    #[test]
+    fn no_inline_config() {
+        let options = get_lint_options("test.js");
+        assert!(!options.inline_config_options.no_inline_config);
+
+        let options = get_lint_options("--no-inline-config test.js");
+        assert!(options.inline_config_options.no_inline_config);
+    }
+
+    #[test]
+    fn timing() {
+        let options = get_lint_options("test.js");
+        assert!(!options.output_options.timing);
+
+        assert_eq!(options.output_options.timing_rows, 10);
+
+        let options = get_lint_options("--timing test.js");
+        assert!(options.output_options.timing);
+
+        let options = get_lint_options("--timing --timing-rows 25 test.js");
+        assert_eq!(options.output_options.timing_rows, 25);
+    }
+
     #[test]
     fn format_error() {
         let args = "-f asdf".split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -7,6 +7,7 @@ mod lint;
 mod lsp;
 mod output_formatter;
 mod result;
+mod suppressions;
 mod walk;
 
 #[cfg(test)]
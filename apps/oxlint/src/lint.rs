@@ -16,13 +16,17 @@ use serde_json::Value;
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
 use oxc_linter::{
     AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, ExternalLinter, ExternalPluginStore,
-    InvalidFilterKind, LintFilter, LintOptions, LintRunner, LintServiceOptions, Linter, Oxlintrc,
-    table::RuleTable,
+    InvalidFilterKind, LintCache, LintFilter, LintOptions, LintRunner, LintServiceOptions, Linter,
+    Oxlintrc, RuleTimings, table::RuleTable,
 };
 
 use crate::{
-    cli::{CliRunResult, LintCommand, MiscOptions, ReportUnusedDirectives, WarningOptions},
+    cli::{
+        CacheOptions, CliRunResult, LintCommand, MiscOptions, ReportUnusedDirectives,
+        WarningOptions,
+    },
     output_formatter::{LintCommandInfo, OutputFormat, OutputFormatter},
+    suppressions::Suppressions,
     walk::Walk,
 };
 use oxc_linter::LintIgnoreMatcher;
@@ -56,6 +60,8 @@ impl CliRunner {
             warning_options,
             ignore_options,
             fix_options,
+            cache_options,
+            suppression_options,
             enable_plugins,
             misc_options,
             disable_nested_config,
@@ -69,6 +75,16 @@ impl CliRunner {
         let provided_path_count = paths.len();
         let now = Instant::now();
 
+        if self.options.output_options.timing
+            && !matches!(format_str, OutputFormat::Default | OutputFormat::Json)
+        {
+            print_and_flush_stdout(
+                stdout,
+                "The `--timing` flag is only supported by the `default` and `json` formats.\n",
+            );
+            return CliRunResult::InvalidOptionConfig;
+        }
+
         let filters = match Self::get_filters(filter) {
             Ok(filters) => filters,
             Err((result, message)) => {
@@ -154,6 +170,8 @@ impl CliRunner {
                     number_of_rules: None,
                     threads_count: rayon::current_num_threads(),
                     start_time: now.elapsed(),
+                    timings: None,
+                    timing_rows: self.options.output_options.timing_rows,
                 }) {
                     print_and_flush_stdout(stdout, &end);
                 }
@@ -286,7 +304,23 @@ impl CliRunner {
         // the same functionality.
         let use_cross_module = config_builder.plugins().has_import()
             || nested_configs.values().any(|config| config.plugins().has_import());
-        let mut options = LintServiceOptions::new(self.cwd).with_cross_module(use_cross_module);
+        let cache = Self::get_cache(&self.cwd, &cache_options)
+            .map(|cache| cache.with_type_aware(self.options.type_aware, self.options.type_check))
+            .map(Arc::new);
+        let suppressions = match Suppressions::load(&self.cwd, &suppression_options) {
+            Ok(suppressions) => suppressions,
+            Err(err) => {
+                print_and_flush_stdout(stdout, &format!("{}\n", render_report(&handler, &err)));
+                return CliRunResult::InvalidOptionConfig;
+            }
+        };
+        let cwd = self.cwd.clone();
+        // The cache needs the imported modules of each file, to lint it again when they change.
+        let mut options = LintServiceOptions::new(self.cwd)
+            .with_cross_module(use_cross_module || cache.is_some());
+        if let Some(cache) = &cache {
+            options = options.with_cache(Arc::clone(cache));
+        }
 
         let lint_config = match config_builder.build(&mut external_plugin_store) {
             Ok(config) => config,
@@ -358,9 +392,15 @@ impl CliRunner {
             .collect::<Vec<Arc<OsStr>>>();
 
         let has_external_linter = external_linter.is_some();
+        let timings = self.options.output_options.timing.then(|| Arc::new(RuleTimings::new()));
         let linter = Linter::new(LintOptions::default(), config_store, external_linter)
             .with_fix(fix_options.fix_kind())
-            .with_report_unused_directives(report_unused_directives);
+            .with_report_unused_directives(report_unused_directives)
+            .with_no_inline_config(inline_config_options.no_inline_config)
+            // SARIF output includes fixes and results suppressed by disable directives.
+            .with_report_fixes(self.options.output_options.format == OutputFormat::Sarif)
+            .with_report_suppressed(self.options.output_options.format == OutputFormat::Sarif)
+            .with_timings(timings.clone());
 
         let number_of_files = files_to_lint.len();
         let tsconfig = basic_options.tsconfig;
@@ -428,7 +468,30 @@ impl CliRunner {
             None
         };
 
-        match lint_runner.lint_files(&files_to_lint, tx_error.clone(), file_system) {
+        // With bulk suppressions, all diagnostics have to be collected before it is known
+        // which of them are suppressed, so they are sent through an intermediate channel.
+        let (tx_error, suppressions) = match suppressions {
+            Some(suppressions) => {
+                let (tx, rx) = std::sync::mpsc::channel();
+                (tx, Some((suppressions, tx_error, rx)))
+            }
+            None => (tx_error, None),
+        };
+
+        let lint_result = lint_runner.lint_files(&files_to_lint, tx_error.clone(), file_system);
+
+        // Results are incomplete when type-aware linting fails, so they are not saved.
+        if let Some(cache) = &cache
+            && lint_result.is_ok()
+            && let Err(err) = cache.save()
+        {
+            print_and_flush_stdout(
+                stdout,
+                &format!("Failed to write cache file {}: {err}\n", cache.path().display()),
+            );
+        }
+
+        match lint_result {
             Ok(lint_runner) => {
                 lint_runner.report_unused_directives(report_unused_directives, &tx_error);
             }
@@ -440,6 +503,12 @@ impl CliRunner {
 
         drop(tx_error);
 
+        if let Some((mut suppressions, tx_error, rx_error)) = suppressions
+            && let Err(err) = suppressions.apply(&cwd, &files_to_lint, rx_error, &tx_error)
+        {
+            print_and_flush_stdout(stdout, &format!("{}\n", render_report(&handler, &err)));
+        }
+
         let diagnostic_result = diagnostic_service.run(stdout);
 
         if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
@@ -447,6 +516,8 @@ impl CliRunner {
             number_of_rules,
             threads_count: rayon::current_num_threads(),
             start_time: now.elapsed(),
+            timings: timings.map(|timings| timings.sorted()),
+            timing_rows: self.options.output_options.timing_rows,
         }) {
             print_and_flush_stdout(stdout, &end);
         }
@@ -465,6 +536,7 @@ impl CliRunner {
 
 impl CliRunner {
     const DEFAULT_OXLINTRC: &'static str = ".oxlintrc.json";
+    const DEFAULT_CACHE_LOCATION: &'static str = ".oxlintcache";
 
     #[must_use]
     pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
@@ -487,6 +559,31 @@ impl CliRunner {
         )
     }
 
+    /// Loads the lint cache when `--cache` is passed.
+    /// If `--cache-location` points to a directory, or ends with a path separator like ESLint's
+    /// `--cache-location`, the cache file is stored in that directory.
+    fn get_cache(cwd: &Path, cache_options: &CacheOptions) -> Option<LintCache> {
+        if !cache_options.cache {
+            return None;
+        }
+        let location = cache_options
+            .cache_location
+            .as_deref()
+            .unwrap_or(Path::new(Self::DEFAULT_CACHE_LOCATION));
+        let is_dir = location
+            .as_os_str()
+            .as_encoded_bytes()
+            .last()
+            .is_some_and(|&byte| std::path::is_separator(byte as char));
+        let location = cwd.join(location);
+        let location = if is_dir || location.is_dir() {
+            location.join(Self::DEFAULT_CACHE_LOCATION)
+        } else {
+            location
+        };
+        Some(LintCache::load(location, env!("CARGO_PKG_VERSION")))
+    }
+
     // moved into a separate function for readability, but it's only ever used
     // in one place.
     fn get_filters(
@@ -1180,6 +1277,24 @@ mod test {
         Tester::new().with_cwd("fixtures/report_unused_directives".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_inline_config() {
+        let args = &["-D", "no-undef", "-D", "no-debugger", "test.js", "invalid.js"];
+        Tester::new().with_cwd("fixtures/inline_config".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_no_inline_config() {
+        let args = &["-D", "no-undef", "-D", "no-debugger", "--no-inline-config", "test.js"];
+        Tester::new().with_cwd("fixtures/inline_config".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_timing_unsupported_format() {
+        let args = &["--timing", "-f", "unix", "fixtures/linter/debugger.js"];
+        Tester::new().test_and_snapshot(args);
+    }
+
     #[test]
     fn test_nested_config() {
         let args = &[];
@@ -1468,4 +1583,100 @@ export { redundant };
             &["--type-aware", "-D", "no-unnecessary-type-assertion"],
         );
     }
+
+    #[test]
+    fn test_cache() {
+        let tester = Tester::new().with_cwd("fixtures/cache".into()).with_temp_copy();
+        let args = &[
+            "--cache",
+            "--cache-location",
+            // The location ends with a separator, so the cache file is stored in that directory.
+            ".cache/",
+            "--import-plugin",
+            "-D",
+            "import/no-cycle",
+            "a.js",
+            "b.js",
+        ];
+
+        // The second run restores the results of the first one from the cache.
+        tester.test_and_snapshot_multiple(&[args, args]);
+        assert!(tester.cwd().join(".cache").join(CliRunner::DEFAULT_CACHE_LOCATION).is_file());
+
+        // Changing `b.js` invalidates `a.js`, which imports it.
+        fs::write(tester.cwd().join("b.js"), "export const b = 1;\n").unwrap();
+        tester.test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_cache_without_import_plugin() {
+        let tester = Tester::new().with_cwd("fixtures/cache".into()).with_temp_copy();
+        let args = &["--cache", "-A", "all", "-D", "no-debugger", "a.js"];
+        tester.test(args);
+        let cache =
+            fs::read_to_string(tester.cwd().join(CliRunner::DEFAULT_CACHE_LOCATION)).unwrap();
+        let cache = serde_json::from_str::<serde_json::Value>(&cache).unwrap();
+
+        // Imported modules are tracked without the import plugin, so `a.js` is linted again
+        // when `b.js` changes.
+        let a = &cache["modules"][tester.cwd().join("a.js").to_str().unwrap()];
+        assert_eq!(a["dependencies"], serde_json::json!([tester.cwd().join("b.js")]));
+        assert!(a["result"].is_object());
+    }
+
+    #[test]
+    fn test_suppressions() {
+        // `a.js` has more violations than suppressed, so all of them are reported.
+        // `b.js` is fully suppressed. `c.js` has fewer violations than suppressed,
+        // so its suppression is reported as unused.
+        let args = &["a.js", "b.js", "c.js"];
+        let json_args = &["-f", "json", "a.js", "b.js", "c.js"];
+        Tester::new()
+            .with_cwd("fixtures/suppressions".into())
+            .test_and_snapshot_multiple(&[args, json_args]);
+    }
+
+    #[test]
+    fn test_suppressions_unused() {
+        // `c.js` is fully suppressed, its unused suppression is reported without failing the run.
+        Tester::new().with_cwd("fixtures/suppressions".into()).test_and_snapshot(&[
+            "--deny-warnings",
+            "--max-warnings",
+            "0",
+            "c.js",
+        ]);
+    }
+
+    #[test]
+    fn test_suppressions_update() {
+        use crate::suppressions::Suppressions;
+
+        let tester = Tester::new().with_cwd("fixtures/suppressions".into()).with_temp_copy();
+        let path = tester.cwd().join(Suppressions::FILE_NAME);
+        let read_suppressions = || {
+            serde_json::from_str::<serde_json::Value>(&fs::read_to_string(&path).unwrap()).unwrap()
+        };
+
+        // Suppressions which do not occur anymore are lowered or removed.
+        tester.test(&["--prune-suppressions", "a.js", "b.js", "c.js"]);
+        assert_eq!(
+            read_suppressions(),
+            serde_json::json!({
+                "a.js": { "eslint/no-debugger": { "count": 2 } },
+                "b.js": { "eslint/no-debugger": { "count": 1 } },
+                "c.js": { "eslint/no-debugger": { "count": 1 } },
+            })
+        );
+
+        // All current violations of the rule are recorded.
+        tester.test(&["--suppress-rule", "no-debugger", "a.js"]);
+        assert_eq!(
+            read_suppressions(),
+            serde_json::json!({
+                "a.js": { "eslint/no-debugger": { "count": 3 } },
+                "b.js": { "eslint/no-debugger": { "count": 1 } },
+                "c.js": { "eslint/no-debugger": { "count": 1 } },
+            })
+        );
+    }
 }
@@ -1,11 +1,11 @@
-use std::time::Duration;
+use std::{fmt::Write, time::Duration};
 
 use crate::output_formatter::InternalFormatter;
 use oxc_diagnostics::{
     Error, GraphicalReportHandler,
     reporter::{DiagnosticReporter, DiagnosticResult},
 };
-use oxc_linter::table::RuleTable;
+use oxc_linter::{RuleTiming, table::RuleTable};
 
 #[derive(Debug)]
 pub struct DefaultOutputFormatter;
@@ -27,17 +27,26 @@ impl InternalFormatter for DefaultOutputFormatter {
         let time = Self::get_execution_time(&lint_command_info.start_time);
         let s = if lint_command_info.number_of_files == 1 { "" } else { "s" };
 
+        let mut output = lint_command_info
+            .timings
+            .as_deref()
+            .map(|timings| Self::render_timings(timings, lint_command_info.timing_rows) + "\n")
+            .unwrap_or_default();
+
         if let Some(number_of_rules) = lint_command_info.number_of_rules {
-            Some(format!(
-                "Finished in {time} on {} file{s} with {} rules using {} threads.\n",
+            let _ = writeln!(
+                output,
+                "Finished in {time} on {} file{s} with {} rules using {} threads.",
                 lint_command_info.number_of_files, number_of_rules, lint_command_info.threads_count
-            ))
+            );
         } else {
-            Some(format!(
-                "Finished in {time} on {} file{s} using {} threads.\n",
+            let _ = writeln!(
+                output,
+                "Finished in {time} on {} file{s} using {} threads.",
                 lint_command_info.number_of_files, lint_command_info.threads_count
-            ))
+            );
         }
+        Some(output)
     }
 
     #[cfg(not(any(test, feature = "testing")))]
@@ -58,6 +67,47 @@ impl DefaultOutputFormatter {
         let ms = duration.as_millis();
         if ms < 1000 { format!("{ms}ms") } else { format!("{:.1}s", duration.as_secs_f64()) }
     }
+
+    /// Renders the `rows` slowest rules as a markdown table, like ESLint's `TIMING=1`.
+    fn render_timings(timings: &[RuleTiming], rows: usize) -> String {
+        let total = timings.iter().map(|timing| timing.duration).sum::<Duration>().as_secs_f64();
+        let rows = timings
+            .iter()
+            .take(rows)
+            .map(|timing| {
+                let secs = timing.duration.as_secs_f64();
+                let relative = if total > 0.0 { secs / total * 100.0 } else { 0.0 };
+                (timing.rule.as_str(), format!("{:.3}", secs * 1000.0), format!("{relative:.1}%"))
+            })
+            .collect::<Vec<_>>();
+
+        let header = ("Rule", "Time (ms)", "Relative");
+        let rule_width = rows.iter().map(|row| row.0.len()).chain([header.0.len()]).max().unwrap();
+        let time_width = rows.iter().map(|row| row.1.len()).chain([header.1.len()]).max().unwrap();
+        let relative_width =
+            rows.iter().map(|row| row.2.len()).chain([header.2.len()]).max().unwrap();
+
+        let mut output = String::new();
+        let _ = writeln!(
+            output,
+            "{:<rule_width$} | {:>time_width$} | {:>relative_width$}",
+            header.0, header.1, header.2
+        );
+        let _ = writeln!(
+            output,
+            ":{}|{}:|{}:",
+            "-".repeat(rule_width),
+            "-".repeat(time_width + 1),
+            "-".repeat(relative_width)
+        );
+        for (rule, time, relative) in rows {
+            let _ = writeln!(
+                output,
+                "{rule:<rule_width$} | {time:>time_width$} | {relative:>relative_width$}"
+            );
+        }
+        output
+    }
 }
 
 /// Pretty-prints diagnostics. Primarily meant for human-readable output in a terminal.
@@ -163,6 +213,7 @@ mod test {
         default::{DefaultOutputFormatter, GraphicalReporter},
     };
     use oxc_diagnostics::reporter::{DiagnosticReporter, DiagnosticResult};
+    use oxc_linter::RuleTiming;
 
     #[test]
     fn all_rules() {
@@ -180,6 +231,8 @@ mod test {
             number_of_rules: Some(10),
             threads_count: 12,
             start_time: Duration::new(1, 0),
+            timings: None,
+            timing_rows: 10,
         });
 
         assert!(result.is_some());
@@ -197,12 +250,77 @@ mod test {
             number_of_rules: None,
             threads_count: 12,
             start_time: Duration::new(1, 0),
+            timings: None,
+            timing_rows: 10,
         });
 
         assert!(result.is_some());
         assert_eq!(result.unwrap(), "Finished in 1.0s on 5 files using 12 threads.\n");
     }
 
+    #[test]
+    fn lint_command_info_timings() {
+        let formatter = DefaultOutputFormatter;
+        let timing = |rule: &str, millis| RuleTiming {
+            rule: rule.to_string(),
+            duration: Duration::from_millis(millis),
+        };
+        let result = formatter.lint_command_info(&LintCommandInfo {
+            number_of_files: 1,
+            number_of_rules: Some(3),
+            threads_count: 1,
+            start_time: Duration::new(1, 0),
+            timings: Some(vec![
+                timing("eslint/no-unused-vars", 1500),
+                timing("eslint/no-debugger", 500),
+                timing("(js plugins)", 0),
+            ]),
+            timing_rows: 10,
+        });
+
+        assert_eq!(
+            result.unwrap(),
+            "Rule                  | Time (ms) | Relative
+:---------------------|----------:|--------:
+eslint/no-unused-vars |  1500.000 |    75.0%
+eslint/no-debugger    |   500.000 |    25.0%
+(js plugins)          |     0.000 |     0.0%
+
+Finished in 1.0s on 1 file with 3 rules using 1 threads.
+"
+        );
+    }
+
+    #[test]
+    fn lint_command_info_timing_rows() {
+        let formatter = DefaultOutputFormatter;
+        let timing = |rule: &str, millis| RuleTiming {
+            rule: rule.to_string(),
+            duration: Duration::from_millis(millis),
+        };
+        let result = formatter.lint_command_info(&LintCommandInfo {
+            number_of_files: 1,
+            number_of_rules: Some(2),
+            threads_count: 1,
+            start_time: Duration::new(1, 0),
+            timings: Some(vec![
+                timing("eslint/no-unused-vars", 1500),
+                timing("eslint/no-debugger", 500),
+            ]),
+            timing_rows: 1,
+        });
+
+        assert_eq!(
+            result.unwrap(),
+            "Rule                  | Time (ms) | Relative
+:---------------------|----------:|--------:
+eslint/no-unused-vars |  1500.000 |    75.0%
+
+Finished in 1.0s on 1 file with 2 rules using 1 threads.
+"
+        );
+    }
+
     #[test]
     fn reporter_finish_no_results() {
         let mut reporter = GraphicalReporter::default();
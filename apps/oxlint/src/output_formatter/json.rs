@@ -7,7 +7,7 @@ use oxc_diagnostics::{
     Error,
     reporter::{DiagnosticReporter, DiagnosticResult},
 };
-use oxc_linter::{RuleCategory, rules::RULES};
+use oxc_linter::{RuleCategory, RuleTiming, rules::RULES};
 
 use crate::output_formatter::InternalFormatter;
 
@@ -42,13 +42,18 @@ impl InternalFormatter for JsonOutputFormatter {
         let number_of_rules =
             lint_command_info.number_of_rules.map_or("null".to_string(), |x| x.to_string());
         let start_time = lint_command_info.start_time.as_secs_f64();
+        let timing = lint_command_info
+            .timings
+            .as_deref()
+            .map(|timings| format!(",\n              \"timing\": {}", format_timings(timings)))
+            .unwrap_or_default();
 
         Some(format!(
             r#"{{ "diagnostics": {},
               "number_of_files": {},
               "number_of_rules": {},
               "threads_count": {},
-              "start_time": {}
+              "start_time": {}{}
             }}
             "#,
             diagnostics,
@@ -56,6 +61,7 @@ impl InternalFormatter for JsonOutputFormatter {
             number_of_rules,
             lint_command_info.threads_count,
             start_time,
+            timing,
         ))
     }
 
@@ -118,11 +124,30 @@ fn format_json(diagnostics: &mut Vec<Error>) -> String {
     format!("[{messages}]")
 }
 
+/// Serializes rule timings as a JSON array, with the time in milliseconds.
+fn format_timings(timings: &[RuleTiming]) -> String {
+    #[derive(Debug, Serialize)]
+    struct RuleTimingJson<'a> {
+        rule: &'a str,
+        time: f64,
+    }
+
+    let timings = timings
+        .iter()
+        .map(|timing| RuleTimingJson {
+            rule: &timing.rule,
+            time: timing.duration.as_secs_f64() * 1000.0,
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string(&timings).expect("Failed to serialize")
+}
+
 #[cfg(test)]
 mod test {
     use std::time::Duration;
 
     use oxc_diagnostics::{NamedSource, OxcDiagnostic, reporter::DiagnosticResult};
+    use oxc_linter::RuleTiming;
     use oxc_span::Span;
 
     use crate::output_formatter::{InternalFormatter, LintCommandInfo, json::JsonOutputFormatter};
@@ -151,6 +176,8 @@ mod test {
                 number_of_rules: Some(0),
                 start_time: Duration::new(0, 0),
                 threads_count: 1,
+                timings: None,
+                timing_rows: 10,
             })
             .unwrap();
         assert_eq!(
@@ -158,4 +185,27 @@ mod test {
             "{ \"diagnostics\": [{\"message\": \"error message\",\"severity\": \"warning\",\"causes\": [],\"filename\": \"file://test.ts\",\"labels\": [{\"span\": {\"offset\": 0,\"length\": 8,\"line\": 1,\"column\": 1}}],\"related\": []}],\n              \"number_of_files\": 0,\n              \"number_of_rules\": 0,\n              \"threads_count\": 1,\n              \"start_time\": 0\n            }\n            "
         );
     }
+
+    #[test]
+    fn timings() {
+        let formatter = JsonOutputFormatter::default();
+        let output = formatter
+            .lint_command_info(&LintCommandInfo {
+                number_of_files: 1,
+                number_of_rules: Some(1),
+                start_time: Duration::new(0, 0),
+                threads_count: 1,
+                timings: Some(vec![RuleTiming {
+                    rule: "eslint/no-debugger".to_string(),
+                    duration: Duration::from_micros(1500),
+                }]),
+                timing_rows: 10,
+            })
+            .unwrap();
+        let output = serde_json::from_str::<serde_json::Value>(&output).unwrap();
+        assert_eq!(
+            output["timing"],
+            serde_json::json!([{ "rule": "eslint/no-debugger", "time": 1.5 }])
+        );
+    }
 }
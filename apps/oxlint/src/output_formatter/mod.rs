@@ -4,6 +4,7 @@ mod github;
 mod gitlab;
 mod json;
 mod junit;
+mod sarif;
 mod stylish;
 mod unix;
 mod xml_utils;
@@ -15,10 +16,12 @@ use checkstyle::CheckStyleOutputFormatter;
 use github::GithubOutputFormatter;
 use gitlab::GitlabOutputFormatter;
 use junit::JUnitOutputFormatter;
+use sarif::SarifOutputFormatter;
 use stylish::StylishOutputFormatter;
 use unix::UnixOutputFormatter;
 
 use oxc_diagnostics::reporter::DiagnosticReporter;
+use oxc_linter::RuleTiming;
 
 use crate::output_formatter::{default::DefaultOutputFormatter, json::JsonOutputFormatter};
 
@@ -34,6 +37,9 @@ pub enum OutputFormat {
     Checkstyle,
     Stylish,
     JUnit,
+    /// SARIF 2.1.0
+    /// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+    Sarif,
 }
 
 impl FromStr for OutputFormat {
@@ -49,6 +55,7 @@ impl FromStr for OutputFormat {
             "gitlab" => Ok(Self::Gitlab),
             "stylish" => Ok(Self::Stylish),
             "junit" => Ok(Self::JUnit),
+            "sarif" => Ok(Self::Sarif),
             _ => Err(format!("'{s}' is not a known format")),
         }
     }
@@ -66,6 +73,10 @@ pub struct LintCommandInfo {
     pub threads_count: usize,
     /// Some reporters want to output the duration it took to finished the task
     pub start_time: Duration,
+    /// Time spent in each rule, slowest first. Only collected with `--timing`.
+    pub timings: Option<Vec<RuleTiming>>,
+    /// Number of rules shown by formatters which print the slowest rules of [`Self::timings`].
+    pub timing_rows: usize,
 }
 
 /// An Interface for the different output formats.
@@ -105,6 +116,7 @@ impl OutputFormatter {
             OutputFormat::Default => Box::new(DefaultOutputFormatter),
             OutputFormat::Stylish => Box::<StylishOutputFormatter>::default(),
             OutputFormat::JUnit => Box::<JUnitOutputFormatter>::default(),
+            OutputFormat::Sarif => Box::<SarifOutputFormatter>::default(),
         }
     }
 
@@ -177,4 +189,11 @@ mod test {
 
         Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
     }
+
+    #[test]
+    fn test_output_formatter_diagnostic_sarif() {
+        let args = &["--format=sarif", "test.js"];
+
+        Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
+    }
 }
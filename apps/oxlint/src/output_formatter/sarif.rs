@@ -0,0 +1,447 @@
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use miette::{SourceCode, SourceSpan};
+
+use oxc_diagnostics::{
+    Error, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult, Info},
+};
+use oxc_linter::{ReportedMessage, RuleCategory, prefix_to_plugin_name, rules::RULES};
+
+use crate::output_formatter::InternalFormatter;
+
+#[derive(Debug, Default)]
+pub struct SarifOutputFormatter;
+
+impl InternalFormatter for SarifOutputFormatter {
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(SarifReporter::default())
+    }
+}
+
+/// Renders reports as a SARIF 2.1.0 log.
+///
+/// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+///
+/// Columns are counted in UTF-16 code units, the default `columnKind` of SARIF.
+///
+/// Note that, due to syntactic restrictions of JSON, this reporter waits until all
+/// diagnostics have been reported before writing them to the output stream.
+#[derive(Default)]
+struct SarifReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for SarifReporter {
+    fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+        Some(format_sarif(&self.diagnostics))
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun; 1],
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<SarifRuleProperties>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRuleProperties {
+    category: RuleCategory,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifMessage,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suppressions: Vec<SarifSuppression>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<SarifMessage>,
+    artifact_changes: [SarifArtifactChange; 1],
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: [SarifReplacement; 1],
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    inserted_content: SarifMessage,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifSuppression {
+    kind: &'static str,
+}
+
+fn format_sarif(diagnostics: &[Error]) -> String {
+    let mut rules = Vec::new();
+    let mut rule_indices = FxHashMap::<String, usize>::default();
+
+    let results = diagnostics
+        .iter()
+        .map(|error| {
+            let Info { filename, severity, rule_id, .. } = Info::new(error);
+            let reported = ReportedMessage::from_error(error);
+
+            let rule_index = rule_id.as_ref().map(|rule_id| {
+                *rule_indices.entry(rule_id.clone()).or_insert_with(|| {
+                    rules.push(sarif_rule(rule_id, error));
+                    rules.len() - 1
+                })
+            });
+
+            let artifact_location = SarifArtifactLocation { uri: filename };
+            let locations = error
+                .labels()
+                .and_then(|mut labels| labels.next())
+                .and_then(|label| {
+                    let span = label.inner();
+                    sarif_region(error, span.offset(), span.offset() + span.len())
+                })
+                .map(|region| SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location: artifact_location.clone(),
+                        region,
+                    },
+                })
+                .into_iter()
+                .collect();
+
+            let fixes = reported
+                .into_iter()
+                .flat_map(ReportedMessage::fixes)
+                .filter_map(|fix| {
+                    let deleted_region =
+                        sarif_region(error, fix.span.start as usize, fix.span.end as usize)?;
+                    Some(SarifFix {
+                        description: fix
+                            .message
+                            .as_ref()
+                            .map(|message| SarifMessage { text: message.to_string() }),
+                        artifact_changes: [SarifArtifactChange {
+                            artifact_location: artifact_location.clone(),
+                            replacements: [SarifReplacement {
+                                deleted_region,
+                                inserted_content: SarifMessage { text: fix.content.to_string() },
+                            }],
+                        }],
+                    })
+                })
+                .collect();
+
+            let suppressions = if reported.is_some_and(ReportedMessage::is_suppressed) {
+                vec![SarifSuppression { kind: "inSource" }]
+            } else {
+                vec![]
+            };
+
+            SarifResult {
+                rule_id,
+                rule_index,
+                // Suppressed messages are reported with their original severity.
+                level: match reported.map_or(severity, ReportedMessage::message_severity) {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                    Severity::Advice => "note",
+                },
+                message: SarifMessage { text: error.to_string() },
+                locations,
+                fixes,
+                suppressions,
+            }
+        })
+        .collect();
+
+    let log = SarifLog {
+        schema: "https://json.schemastore.org/sarif-2.1.0.json",
+        version: "2.1.0",
+        runs: [SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver { name: "oxlint", information_uri: "https://oxc.rs", rules },
+            },
+            column_kind: "utf16CodeUnits",
+            results,
+        }],
+    };
+
+    serde_json::to_string_pretty(&log).expect("Failed to serialize")
+}
+
+/// Describes the rule with code `rule_id` (e.g. `eslint(no-debugger)`).
+///
+/// Rules which are not built into oxlint, such as JS plugin rules, have no category.
+fn sarif_rule(rule_id: &str, error: &Error) -> SarifRule {
+    let rule = rule_id.strip_suffix(')').and_then(|rule_id| rule_id.split_once('(')).and_then(
+        |(scope, name)| {
+            let plugin_name = prefix_to_plugin_name(scope);
+            RULES.iter().find(|rule| rule.name() == name && rule.plugin_name() == plugin_name)
+        },
+    );
+
+    SarifRule {
+        id: rule_id.to_string(),
+        help_uri: error.url().map(|url| url.to_string()),
+        properties: rule.map(|rule| SarifRuleProperties { category: rule.category() }),
+    }
+}
+
+/// Region between the byte offsets `start` and `end` in the source code of `error`.
+fn sarif_region(error: &Error, start: usize, end: usize) -> Option<SarifRegion> {
+    let source = error.source_code()?;
+    let (start_line, start_column) = sarif_position(source, start)?;
+    let (end_line, end_column) = sarif_position(source, end)?;
+    Some(SarifRegion { start_line, start_column, end_line, end_column })
+}
+
+/// 1-based line and column of the byte `offset` in `source`, with the column in UTF-16 code
+/// units. `miette` counts columns in bytes.
+fn sarif_position(source: &dyn SourceCode, offset: usize) -> Option<(usize, usize)> {
+    let position = source.read_span(&SourceSpan::from((offset, 0)), 0, 0).ok()?;
+    let line_start = offset - position.column();
+    let line = source.read_span(&SourceSpan::from((line_start, position.column())), 0, 0).ok()?;
+    let before = std::str::from_utf8(line.data().get(..position.column())?).ok()?;
+    Some((position.line() + 1, before.encode_utf16().count() + 1))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use oxc_diagnostics::{
+        NamedSource, OxcDiagnostic,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+    };
+    use oxc_linter::{Fix, Message, PossibleFixes, ReportedMessage};
+    use oxc_span::Span;
+
+    use super::SarifReporter;
+
+    #[test]
+    fn reporter() {
+        let mut reporter = SarifReporter::default();
+
+        let error = OxcDiagnostic::warn("error message")
+            .with_label(Span::new(0, 8))
+            .with_source_code(NamedSource::new("file://test.ts", "debugger;"));
+
+        let first_result = reporter.render_error(error);
+
+        // reporter keeps it in memory
+        assert!(first_result.is_none());
+
+        // reporter gives results when finishing
+        let second_result = reporter.finish(&DiagnosticResult::default());
+
+        assert!(second_result.is_some());
+        let json: serde_json::Value = serde_json::from_str(&second_result.unwrap()).unwrap();
+        assert_eq!(json["version"], "2.1.0");
+        let run = &json["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "oxlint");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 0);
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        let result = results[0].as_object().unwrap();
+        assert_eq!(result["level"], "warning");
+        assert_eq!(result["message"]["text"], "error message");
+        assert!(!result.contains_key("ruleId"));
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "file://test.ts");
+        assert_eq!(location["region"]["startLine"], 1);
+        assert_eq!(location["region"]["startColumn"], 1);
+        assert_eq!(location["region"]["endLine"], 1);
+        assert_eq!(location["region"]["endColumn"], 9);
+    }
+
+    #[test]
+    fn reporter_rule_fixes_and_suppressions() {
+        let mut reporter = SarifReporter::default();
+
+        let diagnostic = OxcDiagnostic::warn("`debugger` statement is not allowed")
+            .with_error_code("eslint", "no-debugger")
+            .with_url("https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html");
+        let diagnostics = ReportedMessage::wrap_messages(
+            "",
+            "test.js",
+            "debugger;\ndebugger;",
+            vec![
+                Message::new(
+                    diagnostic.clone().with_label(Span::new(0, 9)),
+                    PossibleFixes::Single(
+                        Fix::delete(Span::new(0, 9)).with_message("Remove the debugger statement"),
+                    ),
+                ),
+                Message::new(diagnostic.with_label(Span::new(10, 19)), PossibleFixes::None)
+                    .with_suppressed(true),
+            ],
+        );
+        for error in diagnostics {
+            reporter.render_error(error);
+        }
+
+        let output = reporter.finish(&DiagnosticResult::default()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let run = &json["runs"][0];
+
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0]["id"], "eslint(no-debugger)");
+        assert_eq!(
+            rules[0]["helpUri"],
+            "https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html"
+        );
+        assert_eq!(rules[0]["properties"]["category"], "correctness");
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "eslint(no-debugger)");
+        assert_eq!(results[0]["ruleIndex"], 0);
+        let fix = &results[0]["fixes"][0];
+        assert_eq!(fix["description"]["text"], "Remove the debugger statement");
+        let replacement = &fix["artifactChanges"][0]["replacements"][0];
+        assert_eq!(replacement["deletedRegion"]["startColumn"], 1);
+        assert_eq!(replacement["deletedRegion"]["endColumn"], 10);
+        assert_eq!(replacement["insertedContent"]["text"], "");
+        assert!(results[0].get("suppressions").is_none());
+
+        assert_eq!(results[1]["locations"][0]["physicalLocation"]["region"]["startLine"], 2);
+        assert_eq!(results[1]["level"], "warning");
+        assert_eq!(results[1]["suppressions"][0]["kind"], "inSource");
+    }
+
+    #[test]
+    fn reporter_utf16_columns() {
+        let mut reporter = SarifReporter::default();
+
+        // `ü` is 2 bytes and 1 UTF-16 code unit, `😀` is 4 bytes and 2 UTF-16 code units.
+        let diagnostics = ReportedMessage::wrap_messages(
+            "",
+            "test.js",
+            "const ü = '😀'; debugger;",
+            vec![Message::new(
+                OxcDiagnostic::warn("`debugger` statement is not allowed")
+                    .with_error_code("eslint", "no-debugger")
+                    .with_label(Span::new(19, 27)),
+                PossibleFixes::Single(Fix::delete(Span::new(19, 28))),
+            )],
+        );
+        for error in diagnostics {
+            reporter.render_error(error);
+        }
+
+        let output = reporter.finish(&DiagnosticResult::default()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let run = &json["runs"][0];
+        assert_eq!(run["columnKind"], "utf16CodeUnits");
+        let result = &run["results"][0];
+        let region = &result["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(region["startLine"], 1);
+        assert_eq!(region["startColumn"], 17);
+        assert_eq!(region["endColumn"], 25);
+        let deleted_region =
+            &result["fixes"][0]["artifactChanges"][0]["replacements"][0]["deletedRegion"];
+        assert_eq!(deleted_region["startColumn"], 17);
+        assert_eq!(deleted_region["endColumn"], 26);
+    }
+
+    #[test]
+    fn reporter_without_labels() {
+        let mut reporter = SarifReporter::default();
+        let error = OxcDiagnostic::warn("error message")
+            .with_source_code(Arc::new(NamedSource::new("test.js", String::new())));
+        reporter.render_error(error);
+        let output = reporter.finish(&DiagnosticResult::default()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert!(json["runs"][0]["results"][0].get("locations").is_none());
+    }
+}
@@ -0,0 +1,348 @@
+use std::{
+    collections::BTreeMap,
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, mpsc},
+};
+
+use cow_utils::CowUtils;
+use miette::SourceSpan;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+
+use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic, Severity};
+use oxc_linter::prefix_to_plugin_name;
+
+use crate::cli::SuppressionOptions;
+
+/// Bulk suppressions of existing violations, stored in `oxlint-suppressions.json`.
+///
+/// Violations are counted per file and per rule. A rule's violations in a file are
+/// suppressed as long as there are no more of them than the recorded count, so that only
+/// new violations are reported.
+///
+/// Rules are identified by their plugin and name, e.g. `eslint/no-debugger`, like in ESLint.
+/// Paths are relative to the current working directory.
+pub struct Suppressions {
+    path: PathBuf,
+    options: SuppressionOptions,
+    files: SuppressionsFile,
+}
+
+/// `file path -> plugin/rule -> count`
+type SuppressionsFile = BTreeMap<String, BTreeMap<String, RuleSuppression>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct RuleSuppression {
+    count: usize,
+}
+
+impl Suppressions {
+    pub const FILE_NAME: &'static str = "oxlint-suppressions.json";
+
+    /// Loads the suppressions file in `cwd`.
+    ///
+    /// Returns `Ok(None)` if there is no suppressions file and no suppression options were passed.
+    ///
+    /// # Errors
+    /// Returns an error if the suppressions file cannot be read or parsed.
+    pub fn load(cwd: &Path, options: &SuppressionOptions) -> Result<Option<Self>, OxcDiagnostic> {
+        let path = cwd.join(Self::FILE_NAME);
+        let files = if path.is_file() {
+            let content = fs::read_to_string(&path).map_err(|err| {
+                OxcDiagnostic::error(format!("Failed to read {}: {err}", path.display()))
+            })?;
+            serde_json::from_str(&content).map_err(|err| {
+                OxcDiagnostic::error(format!("Failed to parse {}: {err}", path.display()))
+            })?
+        } else if options.is_enabled() {
+            SuppressionsFile::default()
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(Self { path, options: options.clone(), files }))
+    }
+
+    /// Applies the suppressions to all diagnostics received on `rx_error`,
+    /// and forwards the diagnostics which are not suppressed to `tx_error`.
+    ///
+    /// With `--suppress-all` or `--suppress-rule`, violations of the selected rules are recorded.
+    /// With `--prune-suppressions`, suppressions that no longer occur in `linted_files` are removed.
+    /// The suppressions file is written when either changes it.
+    ///
+    /// Suppressions in `linted_files` that do not occur anymore are reported as advice, so that
+    /// every output format includes them without failing the run with `--deny-warnings` or
+    /// `--max-warnings`.
+    ///
+    /// # Errors
+    /// Returns an error if the suppressions file cannot be written.
+    pub fn apply(
+        &mut self,
+        cwd: &Path,
+        linted_files: &[Arc<OsStr>],
+        rx_error: mpsc::Receiver<Vec<Error>>,
+        tx_error: &DiagnosticSender,
+    ) -> Result<(), OxcDiagnostic> {
+        let batches = rx_error.into_iter().collect::<Vec<_>>();
+
+        let mut counts = FxHashMap::<(String, String), usize>::default();
+        for diagnostic in batches.iter().flatten() {
+            if let Some(key) = suppression_key(diagnostic) {
+                *counts.entry(key).or_default() += 1;
+            }
+        }
+
+        let linted_files = linted_files
+            .iter()
+            .map(|path| relative_path(cwd, Path::new(path)))
+            .collect::<FxHashSet<_>>();
+        let original_files = self.files.clone();
+
+        if self.options.prune_suppressions {
+            self.prune(&linted_files, &counts);
+        }
+        if self.options.suppress_all || !self.options.suppress_rule.is_empty() {
+            for ((path, rule), count) in &counts {
+                if self.options.suppress_all
+                    || self.options.suppress_rule.iter().any(|name| rule_matches(rule, name))
+                {
+                    self.files
+                        .entry(path.clone())
+                        .or_default()
+                        .insert(rule.clone(), RuleSuppression { count: *count });
+                }
+            }
+        }
+
+        for batch in batches {
+            let batch = batch
+                .into_iter()
+                .filter(|diagnostic| {
+                    suppression_key(diagnostic).is_none_or(|key| !self.is_suppressed(&key, &counts))
+                })
+                .collect::<Vec<_>>();
+            if !batch.is_empty() {
+                tx_error.send(batch).expect("failed to send diagnostics");
+            }
+        }
+
+        if self.files != original_files {
+            self.save()?;
+        }
+
+        for (path, rules) in &self.files {
+            if !linted_files.contains(path.as_str()) {
+                continue;
+            }
+            let unused = rules
+                .iter()
+                .filter_map(|(rule, suppression)| {
+                    let count =
+                        counts.get(&(path.clone(), rule.clone())).copied().unwrap_or_default();
+                    (count < suppression.count)
+                        .then(|| unused_suppression(path, rule, suppression.count, count))
+                })
+                .collect::<Vec<_>>();
+            if !unused.is_empty() {
+                let diagnostics = DiagnosticService::wrap_diagnostics(cwd, path, "", unused);
+                tx_error.send(diagnostics).expect("failed to send diagnostics");
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_suppressed(
+        &self,
+        (path, rule): &(String, String),
+        counts: &FxHashMap<(String, String), usize>,
+    ) -> bool {
+        let Some(suppression) = self.files.get(path).and_then(|rules| rules.get(rule)) else {
+            return false;
+        };
+        // If there are more violations than suppressed, all of them are reported.
+        counts.get(&(path.clone(), rule.clone())).is_some_and(|count| *count <= suppression.count)
+    }
+
+    /// Lowers the counts of suppressions in `linted_files` to the number of violations that
+    /// still occur, removing suppressions without violations.
+    fn prune(
+        &mut self,
+        linted_files: &FxHashSet<String>,
+        counts: &FxHashMap<(String, String), usize>,
+    ) {
+        self.files.retain(|path, rules| {
+            if !linted_files.contains(path) {
+                return true;
+            }
+            rules.retain(|rule, suppression| {
+                let count = counts.get(&(path.clone(), rule.clone())).copied().unwrap_or_default();
+                suppression.count = suppression.count.min(count);
+                suppression.count > 0
+            });
+            !rules.is_empty()
+        });
+    }
+
+    fn save(&self) -> Result<(), OxcDiagnostic> {
+        let mut content = serde_json::to_string_pretty(&self.files)
+            .map_err(|err| OxcDiagnostic::error(err.to_string()))?;
+        content.push('\n');
+        fs::write(&self.path, content).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to write {}: {err}", self.path.display()))
+        })
+    }
+}
+
+impl SuppressionOptions {
+    pub fn is_enabled(&self) -> bool {
+        self.suppress_all || !self.suppress_rule.is_empty() || self.prune_suppressions
+    }
+}
+
+/// Returns the `(path, plugin/rule)` pair a diagnostic is counted under,
+/// or `None` if the diagnostic is not a rule violation.
+fn suppression_key(diagnostic: &Error) -> Option<(String, String)> {
+    // Diagnostics suppressed by disable directives are reported as advice, and not counted.
+    if !matches!(diagnostic.severity(), Some(Severity::Warning | Severity::Error) | None) {
+        return None;
+    }
+    // Only rule diagnostics have a code of the form `plugin(rule)`.
+    let code = diagnostic.code()?.to_string();
+    let (prefix, rule) = code.strip_suffix(')')?.split_once('(')?;
+    // `NamedSource` only exposes its name through the contents of a span.
+    let source = diagnostic.source_code()?.read_span(&SourceSpan::from((0, 0)), 0, 0).ok()?;
+    let path = source.name()?.to_string();
+    Some((path, format!("{}/{rule}", prefix_to_plugin_name(prefix))))
+}
+
+/// Whether `rule` (e.g. `eslint/no-debugger`) is selected by `name`,
+/// which is either a rule with its plugin or a bare rule name (e.g. `no-debugger`).
+fn rule_matches(rule: &str, name: &str) -> bool {
+    if name.contains('/') {
+        return rule == name;
+    }
+    rule.split_once('/').is_some_and(|(_, rule_name)| rule_name == name)
+}
+
+fn unused_suppression(path: &str, rule: &str, suppressed: usize, count: usize) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Unused suppression of `{rule}` in {path}: {suppressed} suppressed, but {count} found"
+    ))
+    .with_help("Remove unused suppressions with `--prune-suppressions`.")
+    .with_severity(Severity::Advice)
+}
+
+/// Same path format as the diagnostics' source names.
+fn relative_path(cwd: &Path, path: &Path) -> String {
+    let relative_path = path.strip_prefix(cwd).unwrap_or(path).to_string_lossy();
+    relative_path.cow_replace('\\', "/").into_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use rustc_hash::FxHashMap;
+
+    use oxc_diagnostics::{DiagnosticService, OxcDiagnostic, Severity};
+    use oxc_span::Span;
+
+    use super::{RuleSuppression, Suppressions, SuppressionsFile, rule_matches, suppression_key};
+    use crate::cli::SuppressionOptions;
+
+    fn key(diagnostic: OxcDiagnostic) -> Option<(String, String)> {
+        let cwd = Path::new("/project");
+        let diagnostics = DiagnosticService::wrap_diagnostics(
+            cwd,
+            cwd.join("src/a.js"),
+            "debugger;",
+            vec![diagnostic.with_label(Span::new(0, 9))],
+        );
+        suppression_key(&diagnostics[0])
+    }
+
+    #[test]
+    fn test_suppression_key() {
+        let key_of =
+            |scope, number| key(OxcDiagnostic::warn("message").with_error_code(scope, number));
+        let expected = |rule: &str| Some(("src/a.js".to_string(), rule.to_string()));
+
+        assert_eq!(key_of("eslint", "no-debugger"), expected("eslint/no-debugger"));
+        assert_eq!(
+            key(OxcDiagnostic::error("message").with_error_code("eslint", "no-debugger")),
+            expected("eslint/no-debugger")
+        );
+        assert_eq!(
+            key_of("typescript-eslint", "no-explicit-any"),
+            expected("typescript/no-explicit-any")
+        );
+        assert_eq!(key_of("eslint-plugin-import", "no-cycle"), expected("import/no-cycle"));
+        assert_eq!(key_of("eslint-plugin-react", "jsx-key"), expected("react/jsx-key"));
+        assert_eq!(
+            key_of("eslint-plugin-react-perf", "jsx-no-new-object-as-prop"),
+            expected("react_perf/jsx-no-new-object-as-prop")
+        );
+        // JS plugins use their own name as the prefix.
+        assert_eq!(key_of("my-plugin", "my-rule"), expected("my-plugin/my-rule"));
+
+        // Diagnostics suppressed by disable directives, and diagnostics which are not rule violations.
+        assert_eq!(
+            key(OxcDiagnostic::warn("message")
+                .with_error_code("eslint", "no-debugger")
+                .with_severity(Severity::Advice)),
+            None
+        );
+        assert_eq!(key(OxcDiagnostic::error("Unexpected token")), None);
+    }
+
+    #[test]
+    fn test_prune() {
+        let suppression = |count| RuleSuppression { count };
+        let files = SuppressionsFile::from([
+            (
+                "a.js".to_string(),
+                [
+                    ("eslint/no-debugger".to_string(), suppression(3)),
+                    ("eslint/no-console".to_string(), suppression(1)),
+                ]
+                .into(),
+            ),
+            ("b.js".to_string(), [("eslint/no-debugger".to_string(), suppression(1))].into()),
+            ("c.js".to_string(), [("eslint/no-debugger".to_string(), suppression(1))].into()),
+        ]);
+        let mut suppressions = Suppressions {
+            path: PathBuf::from(Suppressions::FILE_NAME),
+            options: SuppressionOptions::default(),
+            files,
+        };
+        let counts = FxHashMap::from_iter([
+            (("a.js".to_string(), "eslint/no-debugger".to_string()), 2),
+            (("b.js".to_string(), "eslint/no-debugger".to_string()), 5),
+        ]);
+        // `c.js` was not linted.
+        let linted_files = ["a.js", "b.js"].map(str::to_string).into_iter().collect();
+        suppressions.prune(&linted_files, &counts);
+
+        // Counts are lowered to the violations that still occur, but never raised.
+        // Suppressions without violations are removed, and files outside of this run are kept.
+        assert_eq!(
+            suppressions.files,
+            SuppressionsFile::from([
+                ("a.js".to_string(), [("eslint/no-debugger".to_string(), suppression(2))].into()),
+                ("b.js".to_string(), [("eslint/no-debugger".to_string(), suppression(1))].into()),
+                ("c.js".to_string(), [("eslint/no-debugger".to_string(), suppression(1))].into()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_rule_matches() {
+        assert!(rule_matches("eslint/no-debugger", "no-debugger"));
+        assert!(rule_matches("eslint/no-debugger", "eslint/no-debugger"));
+        assert!(rule_matches("typescript/no-floating-promises", "no-floating-promises"));
+        assert!(!rule_matches("eslint/no-debugger", "debugger"));
+        assert!(!rule_matches("eslint/no-debugger", "unicorn/no-debugger"));
+    }
+}
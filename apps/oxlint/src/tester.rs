@@ -1,12 +1,19 @@
-use std::{env, path::PathBuf};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
 
 use cow_utils::CowUtils;
 use lazy_regex::Regex;
+use tempfile::TempDir;
 
 use crate::cli::{CliRunner, lint_command};
 
 pub struct Tester {
     cwd: PathBuf,
+    /// Temporary copy of the working directory and the original working directory,
+    /// see [`Tester::with_temp_copy`].
+    temp_copy: Option<(TempDir, PathBuf)>,
 }
 
 impl Tester {
@@ -18,7 +25,7 @@ impl Tester {
         // do not unwrap because we can set it only one time.
         let _ = rayon::ThreadPoolBuilder::new().num_threads(1).build_global();
 
-        Self { cwd }
+        Self { cwd, temp_copy: None }
     }
 
     pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
@@ -26,6 +33,21 @@ impl Tester {
         self
     }
 
+    /// Run in a temporary copy of the working directory, so that tests can modify the fixtures.
+    /// Snapshots still show the original working directory.
+    pub fn with_temp_copy(mut self) -> Self {
+        let temp_dir = tempfile::tempdir().unwrap();
+        copy_dir(&self.cwd, temp_dir.path());
+        let cwd = std::mem::replace(&mut self.cwd, temp_dir.path().to_path_buf());
+        self.temp_copy = Some((temp_dir, cwd));
+        self
+    }
+
+    /// The directory oxlint runs in.
+    pub fn cwd(&self) -> &Path {
+        &self.cwd
+    }
+
     pub fn test(&self, args: &[&str]) {
         let mut new_args = vec!["--silent"];
         new_args.extend(args);
@@ -52,7 +74,6 @@ impl Tester {
 
     /// Test fix with additional CLI arguments (e.g., `--type-aware` for tsgolint)
     pub fn test_fix_with_args(file: &str, before: &str, after: &str, extra_args: &[&str]) {
-        use std::fs;
         #[expect(clippy::disallowed_methods)]
         let content_original = fs::read_to_string(file).unwrap().replace("\r\n", "\n");
         assert_eq!(content_original, before);
@@ -85,7 +106,8 @@ impl Tester {
     pub fn test_and_snapshot_multiple(&self, multiple_args: &[&[&str]]) {
         let mut output: Vec<u8> = Vec::new();
         let current_cwd = std::env::current_dir().unwrap();
-        let relative_dir = self.cwd.strip_prefix(&current_cwd).unwrap_or(&self.cwd);
+        let display_cwd = self.temp_copy.as_ref().map_or(&self.cwd, |(_, cwd)| cwd);
+        let relative_dir = display_cwd.strip_prefix(&current_cwd).unwrap_or(display_cwd);
 
         for args in multiple_args {
             let options = lint_command().run_inner(*args).unwrap();
@@ -121,6 +143,14 @@ impl Tester {
         let cwd_string = current_cwd.to_str().unwrap();
         let cwd_string = cwd_string.cow_replace('\\', "/").to_string(); // for windows
         let output_string = output_string.cow_replace(&cwd_string, "<cwd>");
+        let output_string = match &self.temp_copy {
+            Some((temp_dir, _)) => {
+                let temp_dir = temp_dir.path().to_str().unwrap().cow_replace('\\', "/");
+                let relative_dir = relative_dir.to_str().unwrap();
+                output_string.cow_replace(temp_dir.as_ref(), relative_dir).into_owned()
+            }
+            None => output_string.into_owned(),
+        };
 
         let full_args_list =
             multiple_args.iter().map(|args| args.join(" ")).collect::<Vec<String>>().join(" ");
@@ -135,3 +165,17 @@ impl Tester {
         });
     }
 }
+
+/// Recursively copy the contents of the directory `from` into the directory `to`.
+fn copy_dir(from: &Path, to: &Path) {
+    for entry in fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let to = to.join(entry.file_name());
+        if entry.file_type().unwrap().is_dir() {
+            fs::create_dir_all(&to).unwrap();
+            copy_dir(&entry.path(), &to);
+        } else {
+            fs::copy(entry.path(), to).unwrap();
+        }
+    }
+}
@@ -10,6 +10,7 @@ use oxc_minifier::{CompressOptions, Compressor};
 use oxc_parser::{ParseOptions, Parser, ParserReturn};
 use oxc_semantic::{Scoping, SemanticBuilder, SemanticBuilderReturn};
 use oxc_span::SourceType;
+use oxc_syntax::module_record::ModuleRecord;
 use oxc_transformer::{TransformOptions, Transformer, TransformerReturn};
 use oxc_transformer_plugins::{
     InjectGlobalVariables, InjectGlobalVariablesConfig, ReplaceGlobalDefines,
@@ -155,8 +156,14 @@ pub trait CompilerInterface {
         /* Transform */
 
         if let Some(options) = self.transform_options() {
-            let mut transformer_return =
-                self.transform(options, &allocator, &mut program, source_path, scoping);
+            let mut transformer_return = self.transform(
+                options,
+                &allocator,
+                &mut program,
+                &parser_return.module_record,
+                source_path,
+                scoping,
+            );
 
             if !transformer_return.errors.is_empty() {
                 self.handle_errors(transformer_return.errors);
@@ -261,10 +268,13 @@ pub trait CompilerInterface {
         options: &TransformOptions,
         allocator: &'a Allocator,
         program: &mut Program<'a>,
+        module_record: &ModuleRecord<'a>,
         source_path: &Path,
         scoping: Scoping,
     ) -> TransformerReturn {
-        Transformer::new(allocator, source_path, options).build_with_scoping(scoping, program)
+        Transformer::new(allocator, source_path, options)
+            .with_module_record(module_record)
+            .build_with_scoping(scoping, program)
     }
 
     fn compress<'a>(
@@ -291,13 +301,14 @@ pub trait CompilerInterface {
         if self.enable_sourcemap() {
             options.source_map_path = Some(source_path.to_path_buf());
         }
-        let (scoping, class_private_mappings) = mangler_return
-            .map(|m| (Some(m.scoping), Some(m.class_private_mappings)))
+        let (scoping, class_private_mappings, property_mappings) = mangler_return
+            .map(|m| (Some(m.scoping), Some(m.class_private_mappings), Some(m.property_mappings)))
             .unwrap_or_default();
         Codegen::new()
             .with_options(options)
             .with_scoping(scoping)
             .with_private_member_mappings(class_private_mappings)
+            .with_property_mappings(property_mappings)
             .build(program)
     }
 }
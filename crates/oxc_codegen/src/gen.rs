@@ -95,7 +95,7 @@ impl Gen for Directive<'_> {
             }
         }
         quote.print(p);
-        p.print_str(directive);
+        p.print_raw_str(directive);
         quote.print(p);
         p.print_ascii_byte(b';');
         p.print_soft_newline();
@@ -1386,7 +1386,7 @@ impl Gen for RegExpLiteral<'_> {
             p.print_hard_space();
         }
         p.print_ascii_byte(b'/');
-        p.print_str(self.regex.pattern.text.as_str());
+        p.print_raw_str(self.regex.pattern.text.as_str());
         p.print_ascii_byte(b'/');
         p.print_str(self.regex.flags.to_inline_string().as_str());
         p.prev_reg_exp_end = p.code().len();
@@ -1428,7 +1428,11 @@ impl GenExpr for ComputedMemberExpression<'_> {
             p.print_str("?.");
         }
         p.print_ascii_byte(b'[');
-        self.expression.print_expr(p, Precedence::Lowest, Context::empty());
+        if let Expression::StringLiteral(s) = &self.expression {
+            p.print_property_string_literal(s);
+        } else {
+            self.expression.print_expr(p, Precedence::Lowest, Context::empty());
+        }
         p.print_ascii_byte(b']');
     }
 }
@@ -1443,7 +1447,7 @@ impl GenExpr for StaticMemberExpression<'_> {
             p.print_hard_space();
         }
         p.print_ascii_byte(b'.');
-        self.property.print(p, ctx);
+        p.print_property_identifier(&self.property);
     }
 }
 
@@ -1660,7 +1664,7 @@ impl Gen for ObjectProperty<'_> {
             if key.name == "__proto__" {
                 shorthand = self.shorthand;
             } else if let Expression::Identifier(ident) = self.value.without_parentheses()
-                && key.name == p.get_identifier_reference_name(ident)
+                && p.get_property_name(&key.name) == p.get_identifier_reference_name(ident)
             {
                 shorthand = true;
             }
@@ -1696,9 +1700,9 @@ impl Gen for ObjectProperty<'_> {
 impl Gen for PropertyKey<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
-            Self::StaticIdentifier(ident) => ident.print(p, ctx),
+            Self::StaticIdentifier(ident) => p.print_property_identifier(ident),
             Self::PrivateIdentifier(ident) => ident.print(p, ctx),
-            Self::StringLiteral(s) => p.print_string_literal(s, /* allow_backtick */ false),
+            Self::StringLiteral(s) => p.print_property_string_literal(s),
             _ => self.to_expression().print_expr(p, Precedence::Comma, Context::empty()),
         }
     }
@@ -2026,11 +2030,11 @@ impl Gen for AssignmentTargetProperty<'_> {
 impl Gen for AssignmentTargetPropertyIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         let ident_name = p.get_identifier_reference_name(&self.binding);
-        if ident_name == self.binding.name.as_str() {
+        if ident_name == p.get_property_name(&self.binding.name) {
             self.binding.print(p, ctx);
         } else {
             // `({x: a} = y);`
-            p.print_str(self.binding.name.as_str());
+            p.print_property_name(self.binding.name.as_str());
             p.print_colon();
             p.print_soft_space();
             p.print_str(ident_name);
@@ -2048,7 +2052,7 @@ impl Gen for AssignmentTargetPropertyProperty<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         let omit_key = if p.options.minify {
             let key_name = match &self.name {
-                PropertyKey::StaticIdentifier(ident) => Some(&ident.name),
+                PropertyKey::StaticIdentifier(ident) => Some(p.get_property_name(&ident.name)),
                 _ => None,
             };
             let value_name =
@@ -2063,7 +2067,7 @@ impl Gen for AssignmentTargetPropertyProperty<'_> {
         if !omit_key {
             match &self.name {
                 PropertyKey::StaticIdentifier(ident) => {
-                    ident.print(p, ctx);
+                    p.print_property_identifier(ident);
                 }
                 PropertyKey::PrivateIdentifier(ident) => {
                     ident.print(p, ctx);
@@ -2072,7 +2076,7 @@ impl Gen for AssignmentTargetPropertyProperty<'_> {
                     if self.computed {
                         p.print_ascii_byte(b'[');
                     }
-                    p.print_string_literal(s, /* allow_backtick */ false);
+                    p.print_property_string_literal(s);
                     if self.computed {
                         p.print_ascii_byte(b']');
                     }
@@ -2167,19 +2171,7 @@ impl GenExpr for ImportExpression<'_> {
 
 impl Gen for TemplateLiteral<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span);
-        p.print_ascii_byte(b'`');
-        debug_assert_eq!(self.quasis.len(), self.expressions.len() + 1);
-        let (first_quasi, remaining_quasis) = self.quasis.split_first().unwrap();
-        p.print_str_escaping_script_close_tag(first_quasi.value.raw.as_str());
-        for (expr, quasi) in self.expressions.iter().zip(remaining_quasis) {
-            p.print_str("${");
-            p.print_expression(expr);
-            p.print_ascii_byte(b'}');
-            p.add_source_mapping(quasi.span);
-            p.print_str_escaping_script_close_tag(quasi.value.raw.as_str());
-        }
-        p.print_ascii_byte(b'`');
+        p.print_template_literal(self, /* is_tagged */ false);
     }
 }
 
@@ -2190,7 +2182,7 @@ impl Gen for TaggedTemplateExpression<'_> {
         if let Some(type_parameters) = &self.type_arguments {
             type_parameters.print(p, ctx);
         }
-        self.quasi.print(p, ctx);
+        p.print_template_literal(&self.quasi, /* is_tagged */ true);
     }
 }
 
@@ -2413,14 +2405,21 @@ impl Gen for ClassElement<'_> {
 impl Gen for JSXIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.add_source_mapping_for_name(self.span, &self.name);
-        p.print_str(self.name.as_str());
+        p.print_jsx_name(self.name.as_str());
     }
 }
 
+/// Print an identifier reference used as a JSX element name, e.g. `Foo` in `<Foo />` or `<Foo.Bar />`.
+fn print_jsx_identifier_reference(ident: &IdentifierReference<'_>, p: &mut Codegen) {
+    let name = p.get_identifier_reference_name(ident);
+    p.add_source_mapping_for_name(ident.span, name);
+    p.print_jsx_name(name);
+}
+
 impl Gen for JSXMemberExpressionObject<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
-            Self::IdentifierReference(ident) => ident.print(p, ctx),
+            Self::IdentifierReference(ident) => print_jsx_identifier_reference(ident, p),
             Self::MemberExpression(member_expr) => member_expr.print(p, ctx),
             Self::ThisExpression(expr) => expr.print(p, ctx),
         }
@@ -2439,7 +2438,7 @@ impl Gen for JSXElementName<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
             Self::Identifier(identifier) => identifier.print(p, ctx),
-            Self::IdentifierReference(identifier) => identifier.print(p, ctx),
+            Self::IdentifierReference(identifier) => print_jsx_identifier_reference(identifier, p),
             Self::NamespacedName(namespaced_name) => namespaced_name.print(p, ctx),
             Self::MemberExpression(member_expr) => member_expr.print(p, ctx),
             Self::ThisExpression(expr) => expr.print(p, ctx),
@@ -2505,7 +2504,7 @@ impl Gen for JSXAttributeValue<'_> {
             Self::StringLiteral(lit) => {
                 let quote = if lit.value.contains('"') { b'\'' } else { b'"' };
                 p.print_ascii_byte(quote);
-                p.print_str(&lit.value);
+                p.print_jsx_str(&lit.value);
                 p.print_ascii_byte(quote);
             }
             Self::ExpressionContainer(expr_container) => expr_container.print(p, ctx),
@@ -2587,7 +2586,7 @@ impl Gen for JSXClosingFragment {
 impl Gen for JSXText<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.add_source_mapping(self.span);
-        p.print_str(self.value.as_str());
+        p.print_jsx_str(self.value.as_str());
     }
 }
 
@@ -2872,13 +2871,13 @@ impl Gen for BindingProperty<'_> {
         if let PropertyKey::StaticIdentifier(key) = &self.key {
             match &self.value {
                 BindingPattern::BindingIdentifier(ident)
-                    if key.name == p.get_binding_identifier_name(ident) =>
+                    if p.get_property_name(&key.name) == p.get_binding_identifier_name(ident) =>
                 {
                     shorthand = true;
                 }
                 BindingPattern::AssignmentPattern(assignment_pattern) => {
                     if let BindingPattern::BindingIdentifier(ident) = &assignment_pattern.left
-                        && key.name == p.get_binding_identifier_name(ident)
+                        && p.get_property_name(&key.name) == p.get_binding_identifier_name(ident)
                     {
                         shorthand = true;
                     }
@@ -37,7 +37,7 @@ use comment::CommentsMap;
 use operator::Operator;
 #[cfg(feature = "sourcemap")]
 use sourcemap_builder::SourcemapBuilder;
-use str::{Quote, cold_branch, is_script_close_tag};
+use str::{NonAsciiEscape, Quote, cold_branch, escape_non_ascii, is_script_close_tag};
 
 pub use context::Context;
 pub use r#gen::{Gen, GenExpr};
@@ -91,11 +91,16 @@ pub struct Codegen<'a> {
     /// Private member name mappings for mangling
     private_member_mappings: Option<IndexVec<ClassId, FxHashMap<String, CompactStr>>>,
 
+    /// Property name mappings for mangling
+    property_mappings: Option<FxHashMap<String, CompactStr>>,
+
     /// Output Code
     code: CodeBuffer,
 
     // states
     prev_op_end: usize,
+    /// Position after which an identifier must be preceded by a space,
+    /// e.g. after regex flags or an escaped identifier ending with `}`.
     prev_reg_exp_end: usize,
     need_space_before_dot: usize,
     print_next_indent_as_space: bool,
@@ -118,6 +123,11 @@ pub struct Codegen<'a> {
     /// Track the current indentation level
     indent: u32,
 
+    /// Start of the current line, for [`CodegenOptions::max_line_length`]
+    line_start: usize,
+    /// End of the code which has been searched for line breaks, for [`CodegenOptions::max_line_length`]
+    line_checked_end: usize,
+
     /// Fast path for [CodegenOptions::single_quote]
     quote: Quote,
 
@@ -159,6 +169,7 @@ impl<'a> Codegen<'a> {
             source_text: None,
             scoping: None,
             private_member_mappings: None,
+            property_mappings: None,
             code: CodeBuffer::default(),
             needs_semicolon: false,
             need_space_before_dot: 0,
@@ -174,6 +185,8 @@ impl<'a> Codegen<'a> {
             start_of_default_export: 0,
             is_jsx: false,
             indent: 0,
+            line_start: 0,
+            line_checked_end: 0,
             quote: Quote::Double,
             comments: CommentsMap::default(),
             #[cfg(feature = "sourcemap")]
@@ -219,9 +232,23 @@ impl<'a> Codegen<'a> {
         self
     }
 
+    /// Set property name mappings for mangling.
+    ///
+    /// This allows renaming of properties like `obj._field` -> `obj.a`.
+    /// The mappings apply to every property in the program with a matching name.
+    #[must_use]
+    pub fn with_property_mappings(
+        mut self,
+        mappings: Option<FxHashMap<String, CompactStr>>,
+    ) -> Self {
+        self.property_mappings = mappings;
+        self
+    }
+
     /// Print a [`Program`] into a string of source code.
     ///
     /// A source map will be generated if [`CodegenOptions::source_map_path`] is set.
+    /// It is composed with [`CodegenOptions::input_source_map`] if that is set too.
     #[must_use]
     pub fn build(mut self, program: &Program<'a>) -> CodegenReturn {
         self.quote = if self.options.single_quote { Quote::Single } else { Quote::Double };
@@ -237,7 +264,13 @@ impl<'a> Codegen<'a> {
         let legal_comments = self.handle_eof_linked_or_external_comments(program);
         let code = self.code.into_string();
         #[cfg(feature = "sourcemap")]
-        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
+        let map =
+            self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap).map(|map| {
+                match &self.options.input_source_map {
+                    Some(input_map) => sourcemap_builder::compose_sourcemaps(&map, input_map),
+                    None => map,
+                }
+            });
         CodegenReturn {
             code,
             #[cfg(feature = "sourcemap")]
@@ -269,14 +302,78 @@ impl<'a> Codegen<'a> {
     }
 
     /// Push str into the buffer
+    ///
+    /// If [`CodegenOptions::ascii_only`] is enabled, non-ASCII characters are escaped as in identifiers.
     #[inline]
     pub fn print_str(&mut self, s: &str) {
+        if self.options.ascii_only && !s.is_ascii() {
+            cold_branch(|| self.print_str_escaping_non_ascii(s, NonAsciiEscape::Identifier));
+            return;
+        }
         self.code.print_str(s);
     }
 
+    /// Push str which is printed as written in source (e.g. a regular expression) into the buffer.
+    ///
+    /// If [`CodegenOptions::ascii_only`] is enabled, non-ASCII characters are escaped,
+    /// taking into account that they may be preceded by a backslash.
+    fn print_raw_str(&mut self, s: &str) {
+        if self.options.ascii_only && !s.is_ascii() {
+            cold_branch(|| self.print_str_escaping_non_ascii(s, NonAsciiEscape::Raw));
+            return;
+        }
+        self.code.print_str(s);
+    }
+
+    /// Push JSX element or attribute name into the buffer.
+    ///
+    /// JSX names cannot contain escapes, so they are printed as written,
+    /// even if [`CodegenOptions::ascii_only`] is enabled.
+    fn print_jsx_name(&mut self, s: &str) {
+        self.code.print_str(s);
+    }
+
+    /// Push JSX text or attribute value into the buffer.
+    ///
+    /// If [`CodegenOptions::ascii_only`] is enabled, non-ASCII characters are escaped as HTML entities.
+    fn print_jsx_str(&mut self, s: &str) {
+        if self.options.ascii_only && !s.is_ascii() {
+            cold_branch(|| self.print_str_escaping_non_ascii(s, NonAsciiEscape::Jsx));
+            return;
+        }
+        self.code.print_str(s);
+    }
+
+    /// Push str into the buffer, escaping non-ASCII characters with `escape`.
+    fn print_str_escaping_non_ascii(&mut self, s: &str, escape: NonAsciiEscape) {
+        let escaped = escape_non_ascii(s, escape);
+        self.code.print_str(&escaped);
+        // `\u{XXXXX}` is part of an identifier, but `}` is not an identifier char.
+        // Make sure a following identifier is separated by a space.
+        if escaped.ends_with('}') {
+            self.prev_reg_exp_end = self.code.len();
+        }
+    }
+
     /// Push str into the buffer, escaping `</script` to `<\/script`.
+    ///
+    /// If [`CodegenOptions::ascii_only`] is enabled, non-ASCII characters are escaped too.
     #[inline]
     pub fn print_str_escaping_script_close_tag(&mut self, s: &str) {
+        if self.options.ascii_only && !s.is_ascii() {
+            cold_branch(|| {
+                let escaped = escape_non_ascii(s, NonAsciiEscape::Raw);
+                self.print_raw_str_escaping_script_close_tag(&escaped);
+            });
+            return;
+        }
+        self.print_raw_str_escaping_script_close_tag(s);
+    }
+
+    /// Push str into the buffer, escaping `</script` to `<\/script`.
+    ///
+    /// Unlike [`Codegen::print_str_escaping_script_close_tag`], non-ASCII characters are never escaped.
+    fn print_raw_str_escaping_script_close_tag(&mut self, s: &str) {
         // `</script` will be very rare. So we try to make the search as quick as possible by:
         // 1. Searching for `<` first, and only checking if followed by `/script` once `<` is found.
         // 2. Searching longer strings for `<` in chunks of 16 bytes using SIMD, and only doing the
@@ -336,7 +433,6 @@ impl<'a> Codegen<'a> {
         // Search string in chunks of 16 bytes
         let mut chunks = bytes.chunks_exact(16);
         for (chunk_index, chunk) in chunks.by_ref().enumerate() {
-            #[expect(clippy::missing_panics_doc, reason = "infallible")]
             let chunk: &[u8; 16] = chunk.try_into().unwrap();
 
             // Compiler vectorizes this loop to a few SIMD ops
@@ -431,11 +527,37 @@ impl<'a> Codegen<'a> {
     #[inline]
     fn print_semicolon(&mut self) {
         self.print_ascii_byte(b';');
+        self.print_newline_past_line_limit();
     }
 
     #[inline]
     fn print_comma(&mut self) {
         self.print_ascii_byte(b',');
+        self.print_newline_past_line_limit();
+    }
+
+    /// Print a line break if the current line is longer than [`CodegenOptions::max_line_length`].
+    ///
+    /// Must only be called where a line break cannot change the meaning of the code.
+    #[inline]
+    fn print_newline_past_line_limit(&mut self) {
+        if let Some(max_line_length) = self.options.max_line_length {
+            cold_branch(|| {
+                // Only search the code printed since the last call for line breaks
+                let len = self.code.len();
+                if let Some(index) =
+                    self.code.as_bytes()[self.line_checked_end..].iter().rposition(|&b| b == b'\n')
+                {
+                    self.line_start = self.line_checked_end + index + 1;
+                }
+                self.line_checked_end = len;
+                if len - self.line_start > max_line_length {
+                    self.print_hard_newline();
+                    self.line_start = self.code.len();
+                    self.line_checked_end = self.line_start;
+                }
+            });
+        }
     }
 
     #[inline]
@@ -557,6 +679,7 @@ impl<'a> Codegen<'a> {
     fn print_curly_braces<F: FnOnce(&mut Self)>(&mut self, span: Span, single_line: bool, op: F) {
         self.add_source_mapping(span);
         self.print_ascii_byte(b'{');
+        self.print_newline_past_line_limit();
         if !single_line {
             self.print_soft_newline();
             self.indent();
@@ -572,6 +695,7 @@ impl<'a> Codegen<'a> {
     fn print_block_start(&mut self, span: Span) {
         self.add_source_mapping(span);
         self.print_ascii_byte(b'{');
+        self.print_newline_past_line_limit();
         self.print_soft_newline();
         self.indent();
     }
@@ -749,6 +873,70 @@ impl<'a> Codegen<'a> {
         ident.name.as_str()
     }
 
+    /// Get the (possibly mangled) name of a property.
+    fn get_property_name<'n>(&'n self, name: &'n str) -> &'n str {
+        self.property_mappings
+            .as_ref()
+            .and_then(|mappings| mappings.get(name))
+            .map_or(name, CompactStr::as_str)
+    }
+
+    fn print_property_name(&mut self, name: &str) {
+        if let Some(mangled) = self.property_mappings.as_ref().and_then(|m| m.get(name)) {
+            self.code.print_str(mangled.as_str());
+        } else {
+            self.print_str(name);
+        }
+    }
+
+    /// Print a [`TemplateLiteral`].
+    ///
+    /// The raw strings of tagged templates are observable at runtime, so non-ASCII characters in them
+    /// are not escaped even with [`CodegenOptions::ascii_only`].
+    fn print_template_literal(&mut self, template: &TemplateLiteral<'_>, is_tagged: bool) {
+        let print_quasi = |p: &mut Self, quasi: &TemplateElement<'_>| {
+            if is_tagged {
+                p.print_raw_str_escaping_script_close_tag(quasi.value.raw.as_str());
+            } else {
+                p.print_str_escaping_script_close_tag(quasi.value.raw.as_str());
+            }
+        };
+        self.add_source_mapping(template.span);
+        self.print_ascii_byte(b'`');
+        debug_assert_eq!(template.quasis.len(), template.expressions.len() + 1);
+        let (first_quasi, remaining_quasis) = template.quasis.split_first().unwrap();
+        print_quasi(self, first_quasi);
+        for (expr, quasi) in template.expressions.iter().zip(remaining_quasis) {
+            self.print_str("${");
+            self.print_expression(expr);
+            self.print_ascii_byte(b'}');
+            self.add_source_mapping(quasi.span);
+            print_quasi(self, quasi);
+        }
+        self.print_ascii_byte(b'`');
+    }
+
+    /// Print the name of a property, e.g. the key of `{ a: 1 }` or the property of `obj.a`.
+    fn print_property_identifier(&mut self, ident: &IdentifierName<'_>) {
+        self.print_space_before_identifier();
+        self.add_source_mapping_for_name(ident.span, &ident.name);
+        self.print_property_name(ident.name.as_str());
+    }
+
+    /// Print a quoted property name, e.g. the key of `{ "a": 1 }` or the property of `obj["a"]`.
+    fn print_property_string_literal(&mut self, s: &StringLiteral<'_>) {
+        if let Some(mangled) =
+            self.property_mappings.as_ref().and_then(|m| m.get(s.value.as_str())).cloned()
+        {
+            self.add_source_mapping(s.span);
+            self.quote.print(self);
+            self.print_str(mangled.as_str());
+            self.quote.print(self);
+        } else {
+            self.print_string_literal(s, /* allow_backtick */ false);
+        }
+    }
+
     fn print_space_before_operator(&mut self, next: Operator) {
         if self.prev_op_end != self.code.len() {
             return;
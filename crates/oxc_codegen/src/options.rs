@@ -29,6 +29,16 @@ pub struct CodegenOptions {
     /// Default is `None` - no sourcemap is produced.
     pub source_map_path: Option<PathBuf>,
 
+    /// Source map of the input source text, e.g. from a preprocessor which ran before Oxc.
+    ///
+    /// When set together with [`CodegenOptions::source_map_path`], the returned sourcemap is
+    /// composed with it, so it maps the generated code back to the original sources
+    /// (including their `sourcesContent` and `names`) instead of the input source text.
+    ///
+    /// Default is `None`.
+    #[cfg(feature = "sourcemap")]
+    pub input_source_map: Option<oxc_sourcemap::SourceMap>,
+
     /// Indentation character.
     ///
     /// Default is [`IndentChar::Tab`].
@@ -43,6 +53,27 @@ pub struct CodegenOptions {
     ///
     /// Default is `0`.
     pub initial_indent: u32,
+
+    /// Escape all non-ASCII characters, so the output is safe to serve with any charset.
+    ///
+    /// Non-ASCII characters are escaped in strings, template literals, identifiers, regular expressions,
+    /// comments and JSX (as HTML entities). The raw strings of tagged templates are observable at runtime,
+    /// so they are printed as is.
+    ///
+    /// Equivalent to esbuild's `charset: "ascii"`.
+    ///
+    /// Default is `false`.
+    pub ascii_only: bool,
+
+    /// Break lines longer than this many bytes.
+    ///
+    /// A line break is inserted at the next position where it cannot change the meaning of the code
+    /// (after a `,`, `;` or `{`), so lines can still exceed this length, e.g. because of a long string.
+    ///
+    /// Equivalent to esbuild's `lineLimit`.
+    ///
+    /// Default is `None` - lines are not broken.
+    pub max_line_length: Option<usize>,
 }
 
 impl Default for CodegenOptions {
@@ -52,9 +83,13 @@ impl Default for CodegenOptions {
             minify: false,
             comments: CommentOptions::default(),
             source_map_path: None,
+            #[cfg(feature = "sourcemap")]
+            input_source_map: None,
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
             initial_indent: 0,
+            ascii_only: false,
+            max_line_length: None,
         }
     }
 }
@@ -67,9 +102,13 @@ impl CodegenOptions {
             minify: true,
             comments: CommentOptions::disabled(),
             source_map_path: None,
+            #[cfg(feature = "sourcemap")]
+            input_source_map: None,
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
             initial_indent: 0,
+            ascii_only: false,
+            max_line_length: None,
         }
     }
 
@@ -1,4 +1,6 @@
-use std::path::Path;
+use std::{path::Path, sync::Arc};
+
+use rustc_hash::FxHashMap;
 
 use oxc_index::{IndexVec, define_nonmax_u32_index_type};
 use oxc_span::Span;
@@ -442,6 +444,73 @@ impl<'a> SourcemapBuilder<'a> {
     }
 }
 
+/// Compose `map`, which maps generated code to the input source text,
+/// with `input_map`, which maps the input source text to the original sources.
+///
+/// The returned sourcemap maps generated code to the original sources.
+/// `sources`, `sourcesContent`, `sourceRoot` and `x_google_ignoreList` are taken from `input_map`.
+/// Tokens which point to a position not mapped by `input_map` are dropped.
+///
+/// Token names are taken from `input_map` where the token points exactly at a named token,
+/// otherwise the name of the generated token is kept.
+pub fn compose_sourcemaps(
+    map: &oxc_sourcemap::SourceMap,
+    input_map: &oxc_sourcemap::SourceMap,
+) -> oxc_sourcemap::SourceMap {
+    let lookup_table = input_map.generate_lookup_table();
+
+    let mut names = Vec::<Arc<str>>::new();
+    let mut name_ids = FxHashMap::<Arc<str>, u32>::default();
+    let mut add_name = |name: &Arc<str>| {
+        *name_ids.entry(Arc::clone(name)).or_insert_with(|| {
+            names.push(Arc::clone(name));
+            #[expect(clippy::cast_possible_truncation)]
+            let id = names.len() as u32 - 1;
+            id
+        })
+    };
+
+    let mut tokens = Vec::new();
+    for token in map.get_tokens() {
+        let Some(original) =
+            input_map.lookup_token(&lookup_table, token.get_src_line(), token.get_src_col())
+        else {
+            continue;
+        };
+        let Some(source_id) = original.get_source_id() else {
+            continue;
+        };
+        let is_exact = original.get_dst_col() == token.get_src_col();
+        let name = original
+            .get_name_id()
+            .filter(|_| is_exact)
+            .and_then(|id| input_map.get_name(id))
+            .or_else(|| token.get_name_id().and_then(|id| map.get_name(id)));
+        tokens.push(oxc_sourcemap::Token::new(
+            token.get_dst_line(),
+            token.get_dst_col(),
+            original.get_src_line(),
+            original.get_src_col(),
+            Some(source_id),
+            name.map(&mut add_name),
+        ));
+    }
+
+    let mut composed = oxc_sourcemap::SourceMap::new(
+        map.get_file().cloned(),
+        names,
+        input_map.get_source_root().map(ToString::to_string),
+        input_map.get_sources().cloned().collect(),
+        input_map.get_source_contents().map(|content| content.map(Arc::clone)).collect(),
+        tokens.into_boxed_slice(),
+        None,
+    );
+    if let Some(ignore_list) = input_map.get_x_google_ignore_list() {
+        composed.set_x_google_ignore_list(ignore_list.to_vec());
+    }
+    composed
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
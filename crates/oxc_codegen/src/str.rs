@@ -4,7 +4,7 @@ use oxc_ast::ast::StringLiteral;
 use oxc_data_structures::{assert_unchecked, slice_iter::SliceIter};
 use oxc_syntax::{
     identifier::NBSP,
-    line_terminator::{LS_LAST_2_BYTES, PS_LAST_2_BYTES},
+    line_terminator::{LS, LS_LAST_2_BYTES, PS, PS_LAST_2_BYTES},
 };
 
 use crate::Codegen;
@@ -56,10 +56,13 @@ impl Codegen<'_> {
             allow_backtick,
         };
 
+        // In `ascii_only` mode, use a table where the first byte of every non-ASCII char needs escaping.
+        let escapes = if self.options.ascii_only { &ESCAPES_ASCII_ONLY.0 } else { &ESCAPES.0 };
+
         // Loop through bytes.
         while let Some(b) = state.peek() {
             // Look up whether byte needs escaping
-            let escape = ESCAPES.0[b as usize];
+            let escape = escapes[b as usize];
             if escape == Escape::__ {
                 // No escape required.
                 // SAFETY: We just checked there's a byte to consume.
@@ -331,6 +334,7 @@ enum Escape {
     LS = 15, // LS/PS - U+2028 LINE SEPARATOR or U+2029 PARAGRAPH SEPARATOR (first byte)
     NB = 16, // NBSP  - Non-breaking space (first byte)
     LO = 17, // �     - U+FFFD lossy replacement character (first byte)
+    NA = 18, // Any other non-ASCII character (first byte). Only used in `ascii_only` mode.
 }
 
 /// Struct which ensures content is aligned on 128.
@@ -342,10 +346,30 @@ struct Aligned128<T>(T);
 /// Aligned on 128, so top half (ASCII chars) occupies a pair of L1 cache lines.
 /// Bottom half (non-ASCII chars) also occupies a pair of L1 cache lines,
 /// but will not be accessed for strings which only contain ASCII (common case).
-static ESCAPES: Aligned128<[Escape; 256]> = {
+static ESCAPES: Aligned128<[Escape; 256]> = Aligned128(ESCAPES_TABLE);
+
+/// Table mapping bytes to `Escape`s, used in `ascii_only` mode.
+///
+/// Same as [`ESCAPES`], except the first byte of any non-ASCII character which doesn't have
+/// a specific `Escape` maps to `Escape::NA`.
+static ESCAPES_ASCII_ONLY: Aligned128<[Escape; 256]> = {
+    let mut table = ESCAPES_TABLE;
+    // 0xC0 - 0xFF are the first bytes of multi-byte UTF-8 characters.
+    // 0x80 - 0xBF are continuation bytes, which are never the current byte when looking up the table.
+    let mut i = 0xC0;
+    while i < 256 {
+        if matches!(table[i], Escape::__) {
+            table[i] = Escape::NA;
+        }
+        i += 1;
+    }
+    Aligned128(table)
+};
+
+const ESCAPES_TABLE: [Escape; 256] = {
     #[allow(clippy::enum_glob_use, clippy::allow_attributes)]
     use Escape::*;
-    Aligned128([
+    [
         //   1   2   3   4   5   6   7   8   9   A   B   C   D   E   F
         NU, __, __, __, __, __, __, BE, BK, __, NL, VT, FF, CR, __, __, // 0
         __, __, __, __, __, __, __, __, __, __, __, ES, __, __, __, __, // 1
@@ -363,7 +387,7 @@ static ESCAPES: Aligned128<[Escape; 256]> = {
         __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, // D
         __, __, LS, __, __, __, __, __, __, __, __, __, __, __, __, LO, // E
         __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, // F
-    ])
+    ]
 };
 
 type ByteHandler = unsafe fn(&mut Codegen, &mut PrintStringState);
@@ -373,10 +397,10 @@ type ByteHandler = unsafe fn(&mut Codegen, &mut PrintStringState);
 /// Indexed by `escape as usize - 1` (where `escape` is not `Escape::__`).
 /// Must be in same order as discriminants in `Escape`.
 ///
-/// Function pointers are 8 bytes each, so `BYTE_HANDLERS` is 136 bytes in total.
+/// Function pointers are 8 bytes each, so `BYTE_HANDLERS` is 144 bytes in total.
 /// Aligned on 128, so first 16 occupy a pair of L1 cache lines.
-/// The last will be in separate cache line, but it should be vanishingly rare that it's accessed.
-static BYTE_HANDLERS: Aligned128<[ByteHandler; 17]> = Aligned128([
+/// The last 2 will be in separate cache line, but it should be vanishingly rare that they're accessed.
+static BYTE_HANDLERS: Aligned128<[ByteHandler; 18]> = Aligned128([
     print_null,
     print_bell,
     print_backspace,
@@ -394,6 +418,7 @@ static BYTE_HANDLERS: Aligned128<[ByteHandler; 17]> = Aligned128([
     print_ls_or_ps,
     print_non_breaking_space,
     print_lossy_replacement,
+    print_non_ascii,
 ]);
 
 /// Call byte handler for byte which needs escaping.
@@ -609,7 +634,7 @@ unsafe fn print_ls_or_ps(codegen: &mut Codegen, state: &mut PrintStringState) {
         _ => {
             // Some other character starting with 0xE2. Advance past it.
             // SAFETY: 0xE2 is always the start of a 3-byte Unicode character
-            unsafe { state.consume_bytes_unchecked(3) };
+            unsafe { consume_other_non_ascii(codegen, state, 3) };
             return;
         }
     };
@@ -634,7 +659,7 @@ unsafe fn print_non_breaking_space(codegen: &mut Codegen, state: &mut PrintStrin
     } else {
         // Some other character starting with 0xC2. Advance past it.
         // SAFETY: 0xC2 is always the start of a 2-byte Unicode character.
-        unsafe { state.consume_bytes_unchecked(2) };
+        unsafe { consume_other_non_ascii(codegen, state, 2) };
     }
 }
 
@@ -662,7 +687,7 @@ unsafe fn print_lossy_replacement(codegen: &mut Codegen, state: &mut PrintString
                 // Actual lossy replacement character.
                 // Flush up to and including the lossy replacement character, then skip the 4 hex bytes.
                 // SAFETY: 0xEF is always the start of a 3-byte Unicode character
-                unsafe { state.consume_bytes_unchecked(3) };
+                unsafe { consume_other_non_ascii(codegen, state, 3) };
                 state.flush(codegen);
                 // SAFETY: 0xEF is always the start of a 3-byte Unicode character.
                 // `bytes.as_slice()[3..7]` would have panicked if there weren't 4 more bytes after it.
@@ -698,7 +723,43 @@ unsafe fn print_lossy_replacement(codegen: &mut Codegen, state: &mut PrintString
     // `lone_surrogates` is `false` or character is some other character starting with 0xEF.
     // Advance past the character.
     // SAFETY: 0xEF is always the start of a 3-byte Unicode character
-    unsafe { state.consume_bytes_unchecked(3) };
+    unsafe { consume_other_non_ascii(codegen, state, 3) };
+}
+
+// Any other non-ASCII character, in `ascii_only` mode
+unsafe fn print_non_ascii(codegen: &mut Codegen, state: &mut PrintStringState) {
+    debug_assert!(state.peek().is_some_and(|b| b >= 0xC0));
+
+    let len = match state.peek() {
+        Some(0xC0..=0xDF) => 2,
+        Some(0xE0..=0xEF) => 3,
+        _ => 4,
+    };
+    // SAFETY: Caller guarantees next byte is the first byte of a non-ASCII character of `len` bytes
+    unsafe { consume_other_non_ascii(codegen, state, len) };
+}
+
+/// Consume a non-ASCII character of `len` bytes which doesn't need escaping unless in `ascii_only` mode.
+///
+/// In `ascii_only` mode, print it as an escape sequence.
+///
+/// # SAFETY
+///
+/// Next byte in `bytes` iterator must be the first byte of a non-ASCII character of `len` bytes.
+unsafe fn consume_other_non_ascii(codegen: &mut Codegen, state: &mut PrintStringState, len: usize) {
+    if !codegen.options.ascii_only {
+        // SAFETY: Caller guarantees there is a `len`-byte character to consume
+        unsafe { state.consume_bytes_unchecked(len) };
+        return;
+    }
+
+    // SAFETY: `bytes` iterator is always positioned on a UTF-8 character boundary
+    let ch = unsafe { std::str::from_utf8_unchecked(state.bytes.as_slice()) }.chars().next();
+    // SAFETY: Caller guarantees there is a `len`-byte character to consume
+    unsafe { state.flush_and_consume_bytes(codegen, len) };
+    if let Some(ch) = ch {
+        codegen.print_str(CharEscape::new(ch, NonAsciiEscape::String).as_str());
+    }
 }
 
 /// Call a closure while hinting to compiler that this branch is rarely taken.
@@ -728,3 +789,101 @@ pub fn is_script_close_tag(slice: &[u8]) -> bool {
     }
     bytes == *b"</script"
 }
+
+/// How non-ASCII characters are escaped in `ascii_only` mode.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum NonAsciiEscape {
+    /// `\uXXXX`, or `\u{XXXXX}` for characters outside the Basic Multilingual Plane.
+    /// Used for identifiers and comments.
+    Identifier,
+    /// `\xXX`, `\uXXXX`, or a surrogate pair `\uXXXX\uXXXX` for characters outside the BMP.
+    /// Used for string literals.
+    String,
+    /// `\uXXXX`, or a surrogate pair `\uXXXX\uXXXX` for characters outside the BMP.
+    /// Used for text which is printed as written in source (regular expressions, template literals, directives).
+    ///
+    /// Surrogate pairs are valid in regular expressions with and without the `u` flag.
+    Raw,
+    /// `&#xXXXX;` HTML entity. Used for JSX text and attributes.
+    Jsx,
+}
+
+/// Escape sequence for a single non-ASCII character.
+pub struct CharEscape {
+    bytes: [u8; 16],
+    len: usize,
+}
+
+impl CharEscape {
+    pub fn new(ch: char, escape: NonAsciiEscape) -> Self {
+        let mut this = Self { bytes: [0; 16], len: 0 };
+        let code = ch as u32;
+        match escape {
+            NonAsciiEscape::Identifier if code > 0xFFFF => {
+                this.push(b"\\u{");
+                this.push_hex(code, 1);
+                this.push(b"}");
+            }
+            NonAsciiEscape::String if code <= 0xFF => {
+                this.push(b"\\x");
+                this.push_hex(code, 2);
+            }
+            NonAsciiEscape::Identifier | NonAsciiEscape::String | NonAsciiEscape::Raw => {
+                for unit in ch.encode_utf16(&mut [0; 2]) {
+                    this.push(b"\\u");
+                    this.push_hex(u32::from(*unit), 4);
+                }
+            }
+            NonAsciiEscape::Jsx => {
+                this.push(b"&#x");
+                this.push_hex(code, 1);
+                this.push(b";");
+            }
+        }
+        this
+    }
+
+    fn push(&mut self, bytes: &[u8]) {
+        self.bytes[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+
+    fn push_hex(&mut self, value: u32, min_digits: u32) {
+        let digits = (32 - value.leading_zeros()).div_ceil(4).max(min_digits);
+        for i in (0..digits).rev() {
+            self.bytes[self.len] = b"0123456789ABCDEF"[((value >> (i * 4)) & 0xF) as usize];
+            self.len += 1;
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        // SAFETY: Only ASCII bytes are pushed to `bytes`
+        unsafe { std::str::from_utf8_unchecked(&self.bytes[..self.len]) }
+    }
+}
+
+/// Escape all non-ASCII characters in `s`.
+///
+/// With [`NonAsciiEscape::Raw`], a backslash before a non-ASCII character is an identity escape
+/// (or a line continuation for `<LS>` and `<PS>`), so it's dropped rather than escaping the escape sequence.
+pub fn escape_non_ascii(s: &str, escape: NonAsciiEscape) -> String {
+    let mut escaped = String::with_capacity(s.len() + 16);
+    let mut after_backslash = false;
+    for ch in s.chars() {
+        if ch.is_ascii() {
+            after_backslash = ch == '\\' && !after_backslash;
+            escaped.push(ch);
+            continue;
+        }
+        if escape == NonAsciiEscape::Raw && after_backslash {
+            escaped.pop();
+            if matches!(ch, LS | PS) {
+                after_backslash = false;
+                continue;
+            }
+        }
+        after_backslash = false;
+        escaped.push_str(CharEscape::new(ch, escape).as_str());
+    }
+    escaped
+}
@@ -691,3 +691,63 @@ fn indentation() {
         CodegenOptions { initial_indent: 1, ..CodegenOptions::default() },
     );
 }
+
+#[test]
+fn ascii_only() {
+    let options = CodegenOptions { ascii_only: true, minify: true, ..CodegenOptions::default() };
+    let test = |source_text: &str, expected: &str| {
+        test_options(source_text, expected, options.clone());
+    };
+
+    // Strings
+    test("x = 'ü中😀'", "x=`\\xFC\\u4E2D\\uD83D\\uDE00`;");
+    test("x = 'a\u{2028}b\u{A0}c'", "x=`a\\u2028b\\xA0c`;");
+    test("x = '\\uD800中'", "x=`\\ud800\\u4E2D`;");
+    test("x = '�'", "x=`\\uFFFD`;");
+    // Template literals
+    test("x = `ü${y}中`", "x=`\\u00FC${y}\\u4E2D`;");
+    test("x = `\\ü`", "x=`\\u00FC`;");
+    test("x = `😀`", "x=`\\uD83D\\uDE00`;");
+    // Raw strings of tagged templates are observable, so are not escaped
+    test("x = tag`ü`", "x=tag`ü`;");
+    // Identifiers
+    test("let ü = 1; ü.ü; ({ ü })", "let \\u00FC=1;\\u00FC.\\u00FC;({\\u00FC});");
+    test("class A { #ü }", "class A{#\\u00FC}");
+    test("let 𐀀 = 1; 𐀀 in x", "let \\u{10000}=1;\\u{10000} in x;");
+    // Regular expressions
+    test("x = /ü[中]/u", "x=/\\u00FC[\\u4E2D]/u;");
+    test("x = /😀/", "x=/\\uD83D\\uDE00/;");
+    test("x = /\\ü/", "x=/\\u00FC/;");
+    // Directives
+    test("'ü'", "\"\\u00FC\";");
+    // JSX
+    test("<div title='ü'>中</div>", "<div title=\"&#xFC;\">&#x4E2D;</div>;");
+    // JSX names cannot be escaped, so are printed as written
+    test("<café data-é={x} />", "<café data-é={x}/>;");
+    test("<Café.ü é:ü />", "<Café.ü é:ü/>;");
+    test("<ü:é></ü:é>", "<ü:é></ü:é>;");
+
+    // Comments
+    test_options(
+        "/* ü */ x",
+        "/* \\u00FC */ x;\n",
+        CodegenOptions { ascii_only: true, ..CodegenOptions::default() },
+    );
+}
+
+#[test]
+fn max_line_length() {
+    let options =
+        CodegenOptions { max_line_length: Some(10), minify: true, ..CodegenOptions::default() };
+    test_options(
+        "foo(aaaa, bbbb, cccc, dddd); bar(); if (x) { baz() }",
+        "foo(aaaa,bbbb,\ncccc,dddd);\nbar();if(x){\nbaz()}",
+        options.clone(),
+    );
+    // Line breaks are only inserted where they are safe
+    test_options(
+        "let x = 'aaaaaaaaaaaaaaaa' + y; function f() { return aaaaaaaaaa++ }",
+        "let x=`aaaaaaaaaaaaaaaa`+y;\nfunction f(){\nreturn aaaaaaaaaa++}",
+        options,
+    );
+}
@@ -210,3 +210,116 @@ fn execute_with_node(code: &str, sourcemap_url: &str) -> String {
         .collect::<Vec<_>>()
         .join("\n")
 }
+
+#[test]
+fn input_source_map() {
+    // Input source map from a preprocessor, which renamed `myVar` to `a`.
+    let original = "let myVar = 1;\nconsole.log(myVar);";
+    let source_text = "let a = 1;\nconsole.log(a);";
+    let mut builder = oxc_sourcemap::SourceMapBuilder::default();
+    let source_id = builder.set_source_and_content("original.js", original);
+    let name_id = builder.add_name("myVar");
+    builder.add_token(0, 0, 0, 0, Some(source_id), None);
+    builder.add_token(0, 4, 0, 4, Some(source_id), Some(name_id));
+    builder.add_token(1, 0, 1, 0, Some(source_id), None);
+    builder.add_token(1, 12, 1, 12, Some(source_id), Some(name_id));
+    let input_map = builder.into_sourcemap();
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    assert!(ret.errors.is_empty());
+    let ret = Codegen::new()
+        .with_options(CodegenOptions {
+            minify: true,
+            source_map_path: Some(PathBuf::from("input.js")),
+            input_source_map: Some(input_map),
+            ..Default::default()
+        })
+        .build(&ret.program);
+    assert_eq!(ret.code, "let a=1;console.log(a);");
+
+    let map = ret.map.unwrap();
+    assert_eq!(map.get_sources().map(AsRef::as_ref).collect::<Vec<_>>(), ["original.js"]);
+    assert_eq!(
+        map.get_source_contents().map(|content| content.map(AsRef::as_ref)).collect::<Vec<_>>(),
+        [Some(original)]
+    );
+
+    let lookup_table = map.generate_lookup_table();
+    let lookup = |col| {
+        let token = map.lookup_token(&lookup_table, 0, col).unwrap();
+        let name = token.get_name_id().and_then(|id| map.get_name(id)).map(ToString::to_string);
+        (token.get_src_line(), token.get_src_col(), name)
+    };
+    // `a` in `let a=1`
+    assert_eq!(lookup(4), (0, 4, Some("myVar".to_string())));
+    // `console`
+    assert_eq!(lookup(8), (1, 0, None));
+    // `a` in `console.log(a)`
+    assert_eq!(lookup(20), (1, 12, Some("myVar".to_string())));
+}
+
+/// Sourcemaps chain through multiple Oxc passes.
+#[test]
+fn input_source_map_from_previous_pass() {
+    let original = "const  foo =   1;\n\n\nexport { foo };";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, original, SourceType::mjs()).parse();
+    assert!(ret.errors.is_empty());
+    let first = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some(PathBuf::from("original.js")),
+            ..Default::default()
+        })
+        .build(&ret.program);
+
+    let ret = Parser::new(&allocator, &first.code, SourceType::mjs()).parse();
+    assert!(ret.errors.is_empty());
+    let second = Codegen::new()
+        .with_options(CodegenOptions {
+            minify: true,
+            source_map_path: Some(PathBuf::from("intermediate.js")),
+            input_source_map: first.map,
+            ..Default::default()
+        })
+        .build(&ret.program);
+    assert_eq!(second.code, "const foo=1;export{foo};");
+
+    let map = second.map.unwrap();
+    assert_eq!(map.get_sources().map(AsRef::as_ref).collect::<Vec<_>>(), ["original.js"]);
+    let lookup_table = map.generate_lookup_table();
+    let lookup = |col| {
+        let token = map.lookup_token(&lookup_table, 0, col).unwrap();
+        (token.get_src_line(), token.get_src_col())
+    };
+    // `foo` in `const foo`
+    assert_eq!(lookup(6), (0, 7));
+    // `1`
+    assert_eq!(lookup(10), (0, 15));
+    // `export`
+    assert_eq!(lookup(12), (3, 0));
+}
+
+#[test]
+fn max_line_length() {
+    let source_text = "foo(aaaa, bbbb, cccc, dddd);";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    assert!(ret.errors.is_empty());
+    let ret = Codegen::new()
+        .with_options(CodegenOptions {
+            minify: true,
+            max_line_length: Some(10),
+            source_map_path: Some(PathBuf::from("test.js")),
+            ..Default::default()
+        })
+        .build(&ret.program);
+    assert_eq!(ret.code, "foo(aaaa,bbbb,\ncccc,dddd);\n");
+
+    // Tokens after the inserted line break are on the next line
+    let map = ret.map.unwrap();
+    let lookup_table = map.generate_lookup_table();
+    let token = map.lookup_token(&lookup_table, 1, 5).unwrap();
+    assert_eq!((token.get_dst_line(), token.get_dst_col()), (1, 5));
+    assert_eq!((token.get_src_line(), token.get_src_col()), (0, 22));
+}
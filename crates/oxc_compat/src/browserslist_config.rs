@@ -0,0 +1,257 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use rustc_hash::FxHashMap;
+
+use crate::BrowserslistQuery;
+
+/// A browserslist config found in `.browserslistrc`, `browserslist` or the `browserslist` key of `package.json`.
+///
+/// See <https://github.com/browserslist/browserslist#config-file>.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BrowserslistConfig {
+    /// Path of the config file.
+    pub path: PathBuf,
+    /// Queries of each environment section.
+    ///
+    /// Queries outside of any section are in the `defaults` section.
+    sections: FxHashMap<String, Vec<String>>,
+}
+
+type ConfigCache = RwLock<FxHashMap<PathBuf, Option<Arc<BrowserslistConfig>>>>;
+
+fn cache() -> &'static ConfigCache {
+    static CACHE: OnceLock<ConfigCache> = OnceLock::new();
+    CACHE.get_or_init(|| RwLock::new(FxHashMap::default()))
+}
+
+impl BrowserslistConfig {
+    /// Find the config that applies to `path`, by walking up from `path` (or its directory if it is a file).
+    ///
+    /// Lookups are cached per directory, in a process-global cache that is never invalidated.
+    /// Config files created, changed or removed after a directory was looked up are not seen
+    /// until the process restarts, which long-running processes like the language server should keep in mind.
+    ///
+    /// # Errors
+    ///
+    /// * A config file cannot be read or parsed.
+    /// * A directory contains more than one config.
+    ///
+    /// # Panics
+    /// When the rwlock is poisoned.
+    pub fn find(path: &Path) -> Result<Option<Arc<Self>>, String> {
+        let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        let start = if path.is_file() { path.parent().unwrap_or(&path) } else { &path };
+        let mut visited = vec![];
+        let mut found = None;
+        for dir in start.ancestors() {
+            let cached = cache().read().unwrap().get(dir).cloned();
+            if let Some(config) = cached {
+                found = config;
+                break;
+            }
+            visited.push(dir.to_path_buf());
+            if let Some(config) = Self::read_dir(dir)? {
+                found = Some(Arc::new(config));
+                break;
+            }
+        }
+        let mut cache = cache().write().unwrap();
+        for dir in visited {
+            cache.insert(dir, found.clone());
+        }
+        Ok(found)
+    }
+
+    /// The browserslist environment, from `BROWSERSLIST_ENV` or `NODE_ENV`, defaulting to `production`.
+    pub fn env() -> String {
+        std::env::var("BROWSERSLIST_ENV")
+            .or_else(|_| std::env::var("NODE_ENV"))
+            .unwrap_or_else(|_| "production".to_string())
+    }
+
+    /// The query of the `env` section, falling back to the queries outside of any section,
+    /// and then to the `defaults` query.
+    pub fn query(&self, env: &str) -> BrowserslistQuery {
+        self.sections.get(env).or_else(|| self.sections.get("defaults")).map_or_else(
+            || BrowserslistQuery::Single(String::new()),
+            |queries| BrowserslistQuery::Multiple(queries.clone()),
+        )
+    }
+
+    /// Parse the content of a `.browserslistrc` file.
+    ///
+    /// # Errors
+    ///
+    /// * An environment section is declared more than once.
+    pub fn parse_rc(path: PathBuf, source_text: &str) -> Result<Self, String> {
+        let mut config = Self { path, ..Self::default() };
+        let mut sections = vec!["defaults".to_string()];
+        for line in source_text.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(names) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                sections = names.split_whitespace().map(str::to_string).collect();
+                for section in &sections {
+                    if config.sections.insert(section.clone(), vec![]).is_some() {
+                        return Err(format!(
+                            "Duplicate section {section} in {}",
+                            config.path.display()
+                        ));
+                    }
+                }
+                continue;
+            }
+            for section in &sections {
+                config.sections.entry(section.clone()).or_default().push(line.to_string());
+            }
+        }
+        Ok(config)
+    }
+
+    /// Parse the `browserslist` key of a `package.json` file.
+    ///
+    /// Returns `None` if the key is missing.
+    ///
+    /// # Errors
+    ///
+    /// * The file is not valid JSON.
+    /// * The `browserslist` key is not a string, an array of strings or an object of them.
+    pub fn parse_package_json(path: PathBuf, source_text: &str) -> Result<Option<Self>, String> {
+        let json: serde_json::Value = serde_json::from_str(source_text)
+            .map_err(|err| format!("Failed to parse {}: {err}", path.display()))?;
+        let Some(value) = json.get("browserslist") else {
+            return Ok(None);
+        };
+        let invalid = || format!("Invalid browserslist key in {}", path.display());
+        let mut sections = FxHashMap::default();
+        if let Some(values) = value.as_object() {
+            for (section, value) in values {
+                let queries = Self::json_queries(value).ok_or_else(invalid)?;
+                sections.insert(section.clone(), queries);
+            }
+        } else {
+            let queries = Self::json_queries(value).ok_or_else(invalid)?;
+            sections.insert("defaults".to_string(), queries);
+        }
+        Ok(Some(Self { path, sections }))
+    }
+
+    fn json_queries(value: &serde_json::Value) -> Option<Vec<String>> {
+        match value {
+            serde_json::Value::String(s) => Some(vec![s.clone()]),
+            serde_json::Value::Array(values) => {
+                values.iter().map(|value| value.as_str().map(str::to_string)).collect()
+            }
+            _ => None,
+        }
+    }
+
+    /// Read the config in `dir`, if any.
+    fn read_dir(dir: &Path) -> Result<Option<Self>, String> {
+        let mut configs = vec![];
+        for name in [".browserslistrc", "browserslist"] {
+            let path = dir.join(name);
+            if path.is_file() {
+                let source_text = fs::read_to_string(&path)
+                    .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+                configs.push(Self::parse_rc(path, &source_text)?);
+            }
+        }
+        let path = dir.join("package.json");
+        if path.is_file() {
+            let source_text = fs::read_to_string(&path)
+                .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+            configs.extend(Self::parse_package_json(path, &source_text)?);
+        }
+        if configs.len() > 1 {
+            return Err(format!("{} contains more than one browserslist config", dir.display()));
+        }
+        Ok(configs.pop())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use super::{BrowserslistConfig, BrowserslistQuery};
+
+    fn queries(queries: &[&str]) -> BrowserslistQuery {
+        BrowserslistQuery::Multiple(queries.iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn parse_rc() {
+        let source_text = "
+            # comment
+            > 1%, not dead # trailing comment
+            [production staging]
+            chrome 100
+            [development]
+            last 1 chrome version
+        ";
+        let config = BrowserslistConfig::parse_rc(PathBuf::new(), source_text).unwrap();
+        assert_eq!(config.query("production"), queries(&["chrome 100"]));
+        assert_eq!(config.query("staging"), queries(&["chrome 100"]));
+        assert_eq!(config.query("development"), queries(&["last 1 chrome version"]));
+        assert_eq!(config.query("test"), queries(&["> 1%, not dead"]));
+
+        let config =
+            BrowserslistConfig::parse_rc(PathBuf::new(), "[development]\nchrome 100").unwrap();
+        assert_eq!(config.query("production"), BrowserslistQuery::Single(String::new()));
+
+        assert!(BrowserslistConfig::parse_rc(PathBuf::new(), "[a]\n[b a]").is_err());
+    }
+
+    #[test]
+    fn parse_package_json() {
+        let parse =
+            |source_text| BrowserslistConfig::parse_package_json(PathBuf::new(), source_text);
+        assert_eq!(parse(r#"{ "name": "a" }"#).unwrap(), None);
+        let config = parse(r#"{ "browserslist": "chrome 100" }"#).unwrap().unwrap();
+        assert_eq!(config.query("production"), queries(&["chrome 100"]));
+        let config = parse(r#"{ "browserslist": { "production": ["chrome 100", "firefox 100"], "defaults": "ie 11" } }"#)
+            .unwrap()
+            .unwrap();
+        assert_eq!(config.query("production"), queries(&["chrome 100", "firefox 100"]));
+        assert_eq!(config.query("development"), queries(&["ie 11"]));
+        assert!(parse(r#"{ "browserslist": 1 }"#).is_err());
+    }
+
+    #[test]
+    fn find() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("packages/a/src")).unwrap();
+        fs::create_dir_all(root.join("packages/b/src")).unwrap();
+        fs::write(root.join(".browserslistrc"), "chrome 100").unwrap();
+        fs::write(root.join("packages/a/package.json"), r#"{ "name": "a" }"#).unwrap();
+        fs::write(root.join("packages/b/package.json"), r#"{ "browserslist": "firefox 100" }"#)
+            .unwrap();
+        fs::write(root.join("packages/a/src/index.js"), "").unwrap();
+
+        let config =
+            BrowserslistConfig::find(&root.join("packages/a/src/index.js")).unwrap().unwrap();
+        assert_eq!(config.path, root.join(".browserslistrc"));
+        let config = BrowserslistConfig::find(&root.join("packages/b/src")).unwrap().unwrap();
+        assert_eq!(config.path, root.join("packages/b/package.json"));
+
+        // Lookups are cached per directory.
+        fs::write(root.join("packages/b/.browserslistrc"), "chrome 100").unwrap();
+        let config = BrowserslistConfig::find(&root.join("packages/b/src")).unwrap().unwrap();
+        assert_eq!(config.path, root.join("packages/b/package.json"));
+
+        // More than one config in a directory.
+        fs::create_dir_all(root.join("packages/c")).unwrap();
+        fs::write(root.join("packages/c/.browserslistrc"), "chrome 100").unwrap();
+        fs::write(root.join("packages/c/package.json"), r#"{ "browserslist": "firefox 100" }"#)
+            .unwrap();
+        assert!(BrowserslistConfig::find(&root.join("packages/c")).is_err());
+    }
+}
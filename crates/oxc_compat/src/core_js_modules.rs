@@ -0,0 +1,203 @@
+//! Engine support of the [core-js] 3 modules injected by usage-based polyfilling.
+//!
+//! Minimum versions are taken from [core-js-compat]. Only Chrome, Firefox, Safari and Node.js
+//! versions are listed, the versions of Chromium based engines and iOS are derived from them.
+//!
+//! [core-js]: https://github.com/zloirock/core-js
+//! [core-js-compat]: https://github.com/zloirock/core-js/tree/master/packages/core-js-compat
+
+use std::sync::OnceLock;
+
+use browserslist::Version;
+use rustc_hash::FxHashMap;
+
+use crate::{Engine, EngineTargets};
+
+/// Minimum versions which ship a core-js module natively.
+struct Support {
+    chrome: u16,
+    firefox: u16,
+    safari: (u16, u16),
+    node: (u16, u16),
+    /// ECMAScript edition of the feature, `None` for web platform features.
+    es: Option<u16>,
+}
+
+const fn support(
+    chrome: u16,
+    firefox: u16,
+    safari: (u16, u16),
+    node: (u16, u16),
+    es: Option<u16>,
+) -> Support {
+    Support { chrome, firefox, safari, node, es }
+}
+
+#[rustfmt::skip]
+const MODULES: &[(&str, Support)] = &[
+    ("es.aggregate-error", support(85, 79, (14, 0), (15, 0), Some(2021))),
+    ("es.array.at", support(92, 90, (15, 4), (16, 6), Some(2022))),
+    ("es.array.copy-within", support(45, 48, (9, 0), (4, 0), Some(2015))),
+    ("es.array.fill", support(45, 31, (8, 0), (4, 0), Some(2015))),
+    ("es.array.find", support(45, 25, (8, 0), (4, 0), Some(2015))),
+    ("es.array.find-index", support(45, 25, (8, 0), (4, 0), Some(2015))),
+    ("es.array.find-last", support(97, 104, (15, 4), (18, 0), Some(2023))),
+    ("es.array.find-last-index", support(97, 104, (15, 4), (18, 0), Some(2023))),
+    ("es.array.flat", support(69, 62, (12, 0), (11, 0), Some(2019))),
+    ("es.array.flat-map", support(69, 62, (12, 0), (11, 0), Some(2019))),
+    ("es.array.from", support(51, 53, (10, 0), (6, 5), Some(2015))),
+    ("es.array.from-async", support(121, 115, (16, 4), (22, 0), Some(2026))),
+    ("es.array.includes", support(53, 102, (10, 0), (7, 0), Some(2016))),
+    ("es.array.iterator", support(66, 60, (15, 4), (10, 0), Some(2015))),
+    ("es.array.of", support(45, 25, (9, 0), (4, 0), Some(2015))),
+    ("es.array.to-reversed", support(110, 115, (16, 0), (20, 0), Some(2023))),
+    ("es.array.to-sorted", support(110, 115, (16, 0), (20, 0), Some(2023))),
+    ("es.array.to-spliced", support(110, 115, (16, 0), (20, 0), Some(2023))),
+    ("es.array.with", support(110, 115, (16, 0), (20, 0), Some(2023))),
+    ("es.global-this", support(71, 65, (12, 1), (12, 0), Some(2020))),
+    ("es.map", support(51, 53, (10, 0), (6, 5), Some(2015))),
+    ("es.map.group-by", support(117, 119, (17, 4), (21, 0), Some(2024))),
+    ("es.math.cbrt", support(38, 25, (8, 0), (0, 12), Some(2015))),
+    ("es.math.log10", support(38, 25, (8, 0), (0, 12), Some(2015))),
+    ("es.math.log2", support(38, 25, (8, 0), (0, 12), Some(2015))),
+    ("es.math.sign", support(38, 25, (8, 0), (0, 12), Some(2015))),
+    ("es.math.trunc", support(38, 25, (8, 0), (0, 12), Some(2015))),
+    ("es.number.is-finite", support(34, 32, (9, 0), (0, 12), Some(2015))),
+    ("es.number.is-integer", support(34, 32, (9, 0), (0, 12), Some(2015))),
+    ("es.number.is-nan", support(34, 32, (9, 0), (0, 12), Some(2015))),
+    ("es.number.is-safe-integer", support(34, 32, (9, 0), (0, 12), Some(2015))),
+    ("es.object.assign", support(49, 36, (9, 0), (6, 0), Some(2015))),
+    ("es.object.entries", support(54, 47, (10, 1), (7, 0), Some(2017))),
+    ("es.object.from-entries", support(73, 63, (12, 1), (12, 0), Some(2019))),
+    ("es.object.get-own-property-descriptors", support(54, 50, (10, 1), (7, 0), Some(2017))),
+    ("es.object.group-by", support(117, 119, (17, 4), (21, 0), Some(2024))),
+    ("es.object.has-own", support(93, 92, (15, 4), (16, 9), Some(2022))),
+    ("es.object.to-string", support(49, 51, (10, 0), (6, 0), Some(2015))),
+    ("es.object.values", support(54, 47, (10, 1), (7, 0), Some(2017))),
+    ("es.promise", support(67, 69, (14, 1), (10, 4), Some(2015))),
+    ("es.promise.all-settled", support(76, 71, (13, 0), (12, 9), Some(2020))),
+    ("es.promise.any", support(85, 79, (14, 0), (15, 0), Some(2021))),
+    ("es.promise.finally", support(67, 69, (13, 1), (10, 4), Some(2018))),
+    ("es.promise.try", support(128, 134, (18, 2), (23, 0), Some(2025))),
+    ("es.promise.with-resolvers", support(119, 121, (17, 4), (22, 0), Some(2024))),
+    ("es.reflect.apply", support(49, 42, (10, 0), (6, 0), Some(2015))),
+    ("es.reflect.has", support(49, 42, (10, 0), (6, 0), Some(2015))),
+    ("es.reflect.own-keys", support(49, 42, (10, 0), (6, 0), Some(2015))),
+    ("es.set", support(51, 53, (10, 0), (6, 5), Some(2015))),
+    ("es.set.difference.v2", support(122, 127, (17, 0), (22, 0), Some(2025))),
+    ("es.set.intersection.v2", support(122, 127, (17, 0), (22, 0), Some(2025))),
+    ("es.set.is-disjoint-from.v2", support(122, 127, (17, 0), (22, 0), Some(2025))),
+    ("es.set.is-subset-of.v2", support(122, 127, (17, 0), (22, 0), Some(2025))),
+    ("es.set.is-superset-of.v2", support(122, 127, (17, 0), (22, 0), Some(2025))),
+    ("es.set.symmetric-difference.v2", support(122, 127, (17, 0), (22, 0), Some(2025))),
+    ("es.set.union.v2", support(122, 127, (17, 0), (22, 0), Some(2025))),
+    ("es.string.at-alternative", support(92, 90, (15, 4), (16, 6), Some(2022))),
+    ("es.string.ends-with", support(51, 40, (10, 0), (6, 0), Some(2015))),
+    ("es.string.from-code-point", support(41, 29, (9, 0), (4, 0), Some(2015))),
+    ("es.string.includes", support(41, 40, (10, 0), (4, 0), Some(2015))),
+    ("es.string.is-well-formed", support(111, 119, (16, 4), (20, 0), Some(2024))),
+    ("es.string.iterator", support(39, 36, (9, 0), (0, 12), Some(2015))),
+    ("es.string.match-all", support(80, 73, (13, 1), (14, 0), Some(2020))),
+    ("es.string.pad-end", support(57, 48, (11, 0), (8, 0), Some(2017))),
+    ("es.string.pad-start", support(57, 48, (11, 0), (8, 0), Some(2017))),
+    ("es.string.raw", support(41, 34, (9, 0), (4, 0), Some(2015))),
+    ("es.string.repeat", support(41, 24, (9, 0), (4, 0), Some(2015))),
+    ("es.string.replace-all", support(85, 77, (13, 1), (15, 0), Some(2021))),
+    ("es.string.starts-with", support(51, 40, (10, 0), (6, 0), Some(2015))),
+    ("es.string.to-well-formed", support(111, 119, (16, 4), (20, 0), Some(2024))),
+    ("es.string.trim-end", support(66, 61, (12, 0), (10, 0), Some(2019))),
+    ("es.string.trim-start", support(66, 61, (12, 0), (10, 0), Some(2019))),
+    ("es.symbol", support(49, 51, (10, 0), (6, 0), Some(2015))),
+    ("es.symbol.async-iterator", support(63, 55, (11, 1), (10, 0), Some(2018))),
+    ("es.symbol.description", support(70, 63, (12, 1), (11, 0), Some(2019))),
+    ("es.weak-map", support(51, 53, (10, 0), (6, 5), Some(2015))),
+    ("es.weak-set", support(51, 53, (10, 0), (6, 5), Some(2015))),
+    ("web.queue-microtask", support(71, 69, (12, 1), (12, 0), None)),
+    ("web.structured-clone", support(98, 94, (15, 4), (17, 0), None)),
+];
+
+/// Samsung Internet versions and the Chromium versions they are based on.
+#[rustfmt::skip]
+const SAMSUNG_TO_CHROME: &[((u16, u16), u16)] = &[
+    ((4, 0), 44), ((5, 0), 51), ((6, 2), 56), ((7, 2), 59), ((8, 2), 63), ((9, 2), 67),
+    ((10, 1), 71), ((11, 1), 75), ((12, 0), 79), ((13, 0), 83), ((14, 0), 87), ((15, 0), 90),
+    ((16, 0), 92), ((17, 0), 96), ((18, 0), 99), ((19, 0), 102), ((20, 0), 106), ((21, 0), 110),
+    ((22, 0), 111), ((23, 0), 115), ((24, 0), 117), ((25, 0), 121), ((26, 0), 122),
+    ((27, 0), 125), ((28, 0), 130),
+];
+
+/// Electron versions and the Chromium versions they are based on.
+#[rustfmt::skip]
+const ELECTRON_TO_CHROME: &[((u16, u16), u16)] = &[
+    ((0, 37), 49), ((1, 4), 53), ((1, 6), 56), ((1, 7), 58), ((2, 0), 61), ((3, 0), 66),
+    ((4, 0), 69), ((5, 0), 73), ((6, 0), 76), ((7, 0), 78), ((8, 0), 80), ((9, 0), 83),
+    ((10, 0), 85), ((11, 0), 87), ((12, 0), 89), ((13, 0), 91), ((14, 0), 93), ((15, 0), 94),
+    ((16, 0), 96), ((17, 0), 98), ((18, 0), 100), ((19, 0), 102), ((20, 0), 104),
+    ((21, 0), 106), ((22, 0), 108), ((23, 0), 110), ((24, 0), 112), ((25, 0), 114),
+    ((26, 0), 116), ((27, 0), 118), ((28, 0), 120), ((29, 0), 122), ((30, 0), 124),
+    ((31, 0), 126), ((32, 0), 128), ((33, 0), 130), ((34, 0), 132), ((35, 0), 134),
+];
+
+/// Deno versions and the Chromium versions of the V8 they ship.
+#[rustfmt::skip]
+const DENO_TO_CHROME: &[((u16, u16), u16)] = &[
+    ((1, 0), 83), ((1, 3), 85), ((1, 5), 86), ((1, 6), 87), ((1, 7), 88), ((1, 8), 89),
+    ((1, 9), 90), ((1, 11), 91), ((1, 13), 92), ((1, 14), 93), ((1, 15), 95), ((1, 16), 96),
+    ((1, 17), 97), ((1, 19), 98), ((1, 20), 99), ((1, 21), 100), ((1, 22), 101),
+    ((1, 23), 102), ((1, 24), 103), ((1, 25), 104), ((1, 26), 105), ((1, 27), 106),
+    ((1, 28), 107), ((1, 29), 108), ((1, 30), 109), ((1, 31), 110), ((1, 32), 111),
+    ((1, 33), 112), ((1, 34), 113), ((1, 35), 114), ((1, 36), 115), ((1, 37), 116),
+    ((1, 38), 118), ((1, 39), 119), ((1, 40), 120), ((1, 41), 121), ((1, 42), 122),
+    ((1, 43), 124), ((1, 44), 125), ((1, 45), 126), ((1, 46), 127), ((2, 0), 129),
+    ((2, 1), 130), ((2, 2), 131),
+];
+
+/// The first version in `table` which is based on Chromium `chrome` or later.
+fn from_chrome(table: &[((u16, u16), u16)], chrome: u16) -> Option<Version> {
+    table
+        .iter()
+        .find(|(_, based_on)| *based_on >= chrome)
+        .map(|((major, minor), _)| Version(*major, *minor, 0))
+}
+
+/// Returns the engines that ship each core-js module natively.
+///
+/// Engines missing from the map of a module need the polyfill.
+pub fn core_js_modules() -> &'static FxHashMap<&'static str, EngineTargets> {
+    static MODULES_MAP: OnceLock<FxHashMap<&'static str, EngineTargets>> = OnceLock::new();
+    MODULES_MAP.get_or_init(|| {
+        MODULES
+            .iter()
+            .map(|(name, s)| {
+                let chrome = Version(s.chrome, 0, 0);
+                let safari = Version(s.safari.0, s.safari.1, 0);
+                let mut engines = FxHashMap::from_iter([
+                    (Engine::Chrome, chrome),
+                    (Engine::Android, chrome),
+                    // Chromium based Edge starts from 79.
+                    (Engine::Edge, Version(s.chrome.max(79), 0, 0)),
+                    // Opera 15 is based on Chromium 28, and is 14 versions behind since Chromium 41.
+                    (Engine::Opera, Version(s.chrome.max(41) - 14, 0, 0)),
+                    (Engine::Firefox, Version(s.firefox, 0, 0)),
+                    (Engine::Safari, safari),
+                    (Engine::Ios, safari),
+                    (Engine::Node, Version(s.node.0, s.node.1, 0)),
+                ]);
+                let derived = [
+                    (Engine::Samsung, SAMSUNG_TO_CHROME),
+                    (Engine::Electron, ELECTRON_TO_CHROME),
+                    (Engine::Deno, DENO_TO_CHROME),
+                ];
+                for (engine, table) in derived {
+                    if let Some(version) = from_chrome(table, s.chrome) {
+                        engines.insert(engine, version);
+                    }
+                }
+                if let Some(es) = s.es {
+                    engines.insert(Engine::Es, Version(es, 0, 0));
+                }
+                (*name, EngineTargets::new(engines))
+            })
+            .collect()
+    })
+}
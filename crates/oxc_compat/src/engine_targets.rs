@@ -1,6 +1,7 @@
 use std::{
     fmt::{Debug, Display},
     ops::{Deref, DerefMut},
+    path::Path,
     str::FromStr,
 };
 
@@ -9,11 +10,12 @@ use oxc_syntax::es_target::ESTarget;
 use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
-use crate::browserslist_query::BrowserslistQuery;
 use crate::{babel_targets::BabelTargets, es_target::ESVersion};
+use crate::{browserslist_config::BrowserslistConfig, browserslist_query::BrowserslistQuery};
 
 use super::{
     Engine,
+    core_js_modules::core_js_modules,
     es_features::{ESFeature, features},
 };
 
@@ -65,6 +67,22 @@ impl EngineTargets {
         BrowserslistQuery::Single(query.to_string()).exec()
     }
 
+    /// Resolve the browserslist config that applies to `path`, for the environment
+    /// given by [`BrowserslistConfig::env`].
+    ///
+    /// Returns `None` if no config is found.
+    ///
+    /// # Errors
+    ///
+    /// * The config cannot be read or parsed.
+    /// * The query is invalid.
+    pub fn from_browserslist_config(path: &Path) -> Result<Option<Self>, String> {
+        let Some(config) = BrowserslistConfig::find(path)? else {
+            return Ok(None);
+        };
+        config.query(&BrowserslistConfig::env()).exec().map(Some)
+    }
+
     /// Returns true if all fields are empty.
     pub fn is_any_target(&self) -> bool {
         self.0.is_empty()
@@ -89,6 +107,23 @@ impl EngineTargets {
         false
     }
 
+    /// Check if the target engines need the given [core-js](https://github.com/zloirock/core-js) module,
+    /// e.g. `es.array.at`.
+    ///
+    /// Returns `true` if any target engine does not ship the feature natively,
+    /// including engines without compat data and modules unknown to [`core_js_modules`].
+    pub fn needs_core_js_module(&self, module: &str) -> bool {
+        let Some(module_engine_targets) = core_js_modules().get(module) else {
+            return true;
+        };
+        self.iter().any(|(engine, target_version)| match module_engine_targets.get(engine) {
+            Some(module_version) if *engine == Engine::Es => target_version.0 < module_version.0,
+            Some(module_version) => target_version < module_version,
+            // Web platform features are not part of any ES edition.
+            None => *engine != Engine::Es,
+        })
+    }
+
     /// Parses the value returned from `browserslist`.
     pub fn parse_versions(versions: Vec<(String, String)>) -> Self {
         let mut engine_targets = Self::default();
@@ -151,6 +186,27 @@ impl EngineTargets {
     }
 }
 
+#[test]
+fn test_needs_core_js_module() {
+    let targets = EngineTargets::from_target_list(&["chrome90", "safari16"]).unwrap();
+    assert!(targets.needs_core_js_module("es.array.at"));
+    assert!(targets.needs_core_js_module("es.object.has-own"));
+    assert!(!targets.needs_core_js_module("es.array.flat"));
+    assert!(targets.needs_core_js_module("es.unknown-module"));
+
+    let targets = EngineTargets::from_target("es2022").unwrap();
+    assert!(!targets.needs_core_js_module("es.array.at"));
+    assert!(targets.needs_core_js_module("es.array.find-last"));
+    assert!(!targets.needs_core_js_module("web.structured-clone"));
+
+    let targets = EngineTargets::from_target("ie11").unwrap();
+    assert!(targets.needs_core_js_module("es.array.of"));
+
+    let targets = EngineTargets::from_target_list(&["samsung20", "edge120", "deno1.40"]).unwrap();
+    assert!(!targets.needs_core_js_module("es.object.has-own"));
+    assert!(targets.needs_core_js_module("es.array.from-async"));
+}
+
 #[test]
 fn test_displayed_value_is_parsable() {
     let target = EngineTargets::new(FxHashMap::from_iter([
@@ -0,0 +1,809 @@
+//! Persistent lint result cache, used by `oxlint --cache`.
+//!
+//! Each linted file is stored with the hash of its source text, the hash of the resolved config
+//! and linter options it was linted with, and the diagnostics (including fixes) that were
+//! reported. Modules are stored with their resolved dependencies, so that a file is linted again
+//! when any module it (transitively) imports changes.
+//!
+//! With `--type-aware`, the diagnostics reported by `tsgolint` are stored with the file, and files
+//! with a cached result are not passed to `tsgolint`.
+
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+};
+
+use rayon::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+use oxc_diagnostics::{LabeledSpan, OxcCode, OxcDiagnostic, Severity};
+use oxc_span::Span;
+
+use crate::{
+    AllowWarnDeny, DisableDirectives, Fix, Linter, Message, PossibleFixes, ResolvedLinterState,
+    config::{GlobalValue, LintPlugins, OxlintSettings, ResolvedConfigKey},
+    disable_directives::SerializedDisableDirectives,
+};
+
+/// Cache of lint results, loaded from and saved to a file on disk.
+pub struct LintCache {
+    /// Path of the cache file.
+    path: PathBuf,
+    /// Version of the linter. Caches written by other versions are discarded.
+    version: String,
+    /// Modules loaded from the cache file.
+    previous: FxHashMap<PathBuf, CachedModule>,
+    /// Modules in `previous` that changed, or whose dependencies changed, since the cache was saved.
+    invalidated: Mutex<FxHashSet<PathBuf>>,
+    /// Modules processed during this run.
+    current: Mutex<FxHashMap<PathBuf, CachedModule>>,
+    /// Hashes of the configs resolved during this run, so each one is only hashed once.
+    config_hashes: Mutex<FxHashMap<ResolvedConfigKey, String>>,
+    /// Paths and source hashes of the JS plugins, sorted by path.
+    js_plugins: OnceLock<Vec<(PathBuf, String)>>,
+    /// Paths of the files whose result was restored from the cache during this run.
+    restored: Mutex<FxHashSet<PathBuf>>,
+    /// Whether type-aware rules run, and their results are included in the lint results.
+    type_aware: bool,
+    /// Whether TypeScript compiler diagnostics are included in the type-aware results.
+    type_check: bool,
+}
+
+impl std::fmt::Debug for LintCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LintCache").field("path", &self.path).finish_non_exhaustive()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    modules: FxHashMap<PathBuf, CachedModule>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct CachedModule {
+    /// Hash of the source text.
+    hash: String,
+    /// Resolved paths of the modules imported by this module.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    dependencies: Vec<PathBuf>,
+    /// Lint result. `None` for modules which were only loaded as a dependency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    result: Option<CachedLintResult>,
+}
+
+/// Lint result of a file, restored from the cache.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CachedLintResult {
+    /// Hash of the resolved config and linter options.
+    config: String,
+    messages: Vec<CachedMessage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    disable_directives: Option<SerializedDisableDirectives>,
+}
+
+impl CachedLintResult {
+    pub fn into_parts(self) -> (Vec<Message>, Option<DisableDirectives>) {
+        (
+            self.messages.into_iter().map(Message::from).collect(),
+            self.disable_directives.map(DisableDirectives::from),
+        )
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedMessage {
+    message: String,
+    severity: CachedSeverity,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    help: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    labels: Vec<CachedLabel>,
+    #[serde(with = "serde_span")]
+    span: Span,
+    fixes: CachedFixes,
+    section_offset: u32,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    suppressed: bool,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum CachedSeverity {
+    Advice,
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedLabel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    offset: usize,
+    len: usize,
+    primary: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+enum CachedFixes {
+    None,
+    Single(CachedFix),
+    Multiple(Vec<CachedFix>),
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedFix {
+    content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    #[serde(with = "serde_span")]
+    span: Span,
+}
+
+impl LintCache {
+    /// Load the cache from `path`.
+    ///
+    /// Starts with an empty cache if the file does not exist, can't be parsed,
+    /// or was written by a different `version`.
+    pub fn load<P: Into<PathBuf>, V: Into<String>>(path: P, version: V) -> Self {
+        let path = path.into();
+        let version = version.into();
+        let previous = fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<CacheFile>(&bytes).ok())
+            .filter(|file| file.version == version)
+            .map(|file| file.modules)
+            .unwrap_or_default();
+        Self {
+            path,
+            version,
+            previous,
+            invalidated: Mutex::new(FxHashSet::default()),
+            current: Mutex::new(FxHashMap::default()),
+            config_hashes: Mutex::new(FxHashMap::default()),
+            js_plugins: OnceLock::new(),
+            restored: Mutex::new(FxHashSet::default()),
+            type_aware: false,
+            type_check: false,
+        }
+    }
+
+    /// Set whether type-aware rules (`type_aware`) and TypeScript compiler diagnostics
+    /// (`type_check`) are included in the lint results.
+    /// Results cached with different settings are not reused.
+    ///
+    /// Default is `false` for both.
+    #[must_use]
+    pub fn with_type_aware(mut self, type_aware: bool, type_check: bool) -> Self {
+        self.type_aware = type_aware;
+        self.type_check = type_check;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether the result of the file at `path` was restored from the cache during this run.
+    ///
+    /// # Panics
+    /// Panics if the mutex is poisoned.
+    pub fn is_restored(&self, path: &Path) -> bool {
+        self.restored.lock().expect("LintCache mutex poisoned in is_restored").contains(path)
+    }
+
+    /// Write the cache to disk, creating its directory if needed.
+    ///
+    /// Modules processed during this run replace their previous entries. Previous entries that
+    /// were invalidated and not processed again are dropped.
+    ///
+    /// # Errors
+    /// Returns an error if the cache file cannot be written.
+    ///
+    /// # Panics
+    /// Panics if the mutex is poisoned.
+    pub fn save(&self) -> std::io::Result<()> {
+        let invalidated = self.invalidated.lock().expect("LintCache mutex poisoned in save");
+        let current = self.current.lock().expect("LintCache mutex poisoned in save");
+
+        let mut modules = FxHashMap::<&Path, CachedModuleRef>::default();
+        for (path, module) in &self.previous {
+            if invalidated.contains(path) {
+                continue;
+            }
+            let result = module.result.as_ref();
+            modules.insert(
+                path,
+                CachedModuleRef { hash: &module.hash, dependencies: &module.dependencies, result },
+            );
+        }
+        for (path, module) in current.iter() {
+            let result = module
+                .result
+                .as_ref()
+                .or_else(|| modules.get(path.as_path()).and_then(|previous| previous.result));
+            modules.insert(
+                path,
+                CachedModuleRef { hash: &module.hash, dependencies: &module.dependencies, result },
+            );
+        }
+
+        let file = CacheFileRef { version: &self.version, modules };
+        let json = serde_json::to_vec(&file).map_err(std::io::Error::other)?;
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, json)
+    }
+
+    /// Split `paths` into files with a reusable cached result and files that need to be linted.
+    ///
+    /// A cached result is reused when the file, and every module it imports, is unchanged,
+    /// and the file resolves to the same config as when it was cached.
+    ///
+    /// # Panics
+    /// Panics if the mutex is poisoned.
+    pub(crate) fn partition<P: AsRef<OsStr> + Send>(
+        &self,
+        linter: &Linter,
+        paths: Vec<P>,
+    ) -> (Vec<(P, CachedLintResult)>, Vec<P>) {
+        if self.previous.is_empty() {
+            return (vec![], paths);
+        }
+
+        // Modules whose source text changed, or which no longer exist.
+        let changed = self
+            .previous
+            .par_iter()
+            .filter(|(path, module)| {
+                fs::read(path).map_or(true, |source| hash_source(&source) != module.hash)
+            })
+            .map(|(path, _)| path.as_path())
+            .collect::<Vec<_>>();
+
+        // Propagate changes to every module which (transitively) imports a changed module.
+        let mut dependents = FxHashMap::<&Path, Vec<&Path>>::default();
+        for (path, module) in &self.previous {
+            for dependency in &module.dependencies {
+                dependents.entry(dependency.as_path()).or_default().push(path.as_path());
+            }
+        }
+        let mut invalidated = FxHashSet::<&Path>::default();
+        let mut queue = VecDeque::from(changed);
+        while let Some(path) = queue.pop_front() {
+            if invalidated.insert(path) {
+                queue.extend(dependents.get(path).into_iter().flatten().copied());
+            }
+        }
+
+        let (cached, uncached): (Vec<_>, Vec<_>) = paths
+            .into_par_iter()
+            .map(|path| {
+                let module_path = Path::new(path.as_ref());
+                let result = self
+                    .previous
+                    .get(module_path)
+                    .filter(|_| !invalidated.contains(module_path))
+                    .and_then(|module| module.result.as_ref())
+                    .filter(|result| result.config == self.config_hash(linter, module_path))
+                    .cloned();
+                match result {
+                    Some(result) => (Some((path, result)), None),
+                    None => (None, Some(path)),
+                }
+            })
+            .unzip();
+
+        *self.invalidated.lock().expect("LintCache mutex poisoned in partition") =
+            invalidated.into_iter().map(Path::to_path_buf).collect();
+        self.restored
+            .lock()
+            .expect("LintCache mutex poisoned in partition")
+            .extend(cached.iter().flatten().map(|(path, _)| PathBuf::from(path.as_ref())));
+
+        (cached.into_iter().flatten().collect(), uncached.into_iter().flatten().collect())
+    }
+
+    /// Hash of the resolved config and linter options used to lint the file at `path`.
+    ///
+    /// # Panics
+    /// Panics if the mutex is poisoned.
+    fn config_hash(&self, linter: &Linter, path: &Path) -> String {
+        let key = linter.config.resolve_key(path);
+        if let Some(hash) =
+            self.config_hashes.lock().expect("LintCache mutex poisoned in config_hash").get(&key)
+        {
+            return hash.clone();
+        }
+        let js_plugins = self.js_plugins.get_or_init(|| hash_js_plugins(linter));
+        let hash = hash_config(
+            linter,
+            &linter.config.resolve(path),
+            js_plugins,
+            self.type_aware,
+            self.type_check,
+        );
+        self.config_hashes
+            .lock()
+            .expect("LintCache mutex poisoned in config_hash")
+            .insert(key, hash.clone());
+        hash
+    }
+
+    /// Record the source text and resolved dependencies of a module processed during this run.
+    ///
+    /// # Panics
+    /// Panics if the mutex is poisoned.
+    pub(crate) fn insert_module(&self, path: &Path, hash: String, dependencies: Vec<PathBuf>) {
+        let mut current = self.current.lock().expect("LintCache mutex poisoned in insert_module");
+        let module = current.entry(path.to_path_buf()).or_default();
+        module.hash = hash;
+        module.dependencies = dependencies;
+    }
+
+    /// Record the lint result of a file linted during this run.
+    ///
+    /// # Panics
+    /// Panics if the mutex is poisoned.
+    pub(crate) fn insert_result(
+        &self,
+        linter: &Linter,
+        path: &Path,
+        messages: &[Message],
+        disable_directives: Option<&DisableDirectives>,
+    ) {
+        let result = CachedLintResult {
+            config: self.config_hash(linter, path),
+            messages: messages.iter().map(CachedMessage::from).collect(),
+            disable_directives: disable_directives.cloned().map(SerializedDisableDirectives::from),
+        };
+        let mut current = self.current.lock().expect("LintCache mutex poisoned in insert_result");
+        current.entry(path.to_path_buf()).or_default().result = Some(result);
+    }
+
+    /// Add a message reported by `tsgolint` to the lint result of a file linted during this run.
+    /// Does nothing if the file has no result, e.g. because it has parse errors.
+    ///
+    /// # Panics
+    /// Panics if the mutex is poisoned.
+    pub(crate) fn insert_type_aware_message(&self, path: &Path, message: &Message) {
+        let mut current =
+            self.current.lock().expect("LintCache mutex poisoned in insert_type_aware_message");
+        if let Some(result) = current.get_mut(path).and_then(|module| module.result.as_mut()) {
+            result.messages.push(CachedMessage::from(message));
+        }
+    }
+
+    /// Replace the disable directives in the lint results of files linted during this run.
+    ///
+    /// Disable directives are marked as used by `tsgolint` diagnostics after the result was
+    /// recorded, so they are updated once `tsgolint` has finished.
+    ///
+    /// # Panics
+    /// Panics if the mutex is poisoned.
+    pub(crate) fn update_disable_directives(
+        &self,
+        disable_directives_map: &FxHashMap<PathBuf, DisableDirectives>,
+    ) {
+        let mut current =
+            self.current.lock().expect("LintCache mutex poisoned in update_disable_directives");
+        for (path, module) in current.iter_mut() {
+            if let Some(result) = &mut module.result {
+                result.disable_directives = disable_directives_map
+                    .get(path)
+                    .cloned()
+                    .map(SerializedDisableDirectives::from);
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct CacheFileRef<'a> {
+    version: &'a str,
+    modules: FxHashMap<&'a Path, CachedModuleRef<'a>>,
+}
+
+#[derive(Clone, Copy, Serialize)]
+struct CachedModuleRef<'a> {
+    hash: &'a str,
+    #[serde(skip_serializing_if = "<[PathBuf]>::is_empty")]
+    dependencies: &'a [PathBuf],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<&'a CachedLintResult>,
+}
+
+/// Hash of a module's source text, as stored in the cache.
+pub fn hash_source(source: &[u8]) -> String {
+    format!("{:x}", Sha1::digest(source))
+}
+
+/// Stable serialized form of a resolved config and the linter options, hashed to detect when
+/// a file has to be linted again. Maps are sorted and rules are sorted by name, so the form does
+/// not depend on the order in which the config was built.
+#[derive(Serialize)]
+struct ConfigFingerprint<'a> {
+    fix: u8,
+    framework_hints: u32,
+    report_unused_directive: Option<AllowWarnDeny>,
+    report_suppressed: bool,
+    report_fixes: bool,
+    no_inline_config: bool,
+    plugins: LintPlugins,
+    settings: &'a OxlintSettings,
+    env: BTreeSet<&'a str>,
+    globals: BTreeMap<&'a str, GlobalValue>,
+    /// `plugin/rule`, severity and options.
+    /// Built-in rules have no serialized form of their options, so their `Debug` output is used.
+    /// It can only change between versions, which discard the cache anyway.
+    rules: Vec<(String, AllowWarnDeny, String)>,
+    /// `plugin/rule`, severity and options of JS plugin rules.
+    external_rules: Vec<(String, AllowWarnDeny, &'a [serde_json::Value])>,
+    /// Paths and source hashes of the JS plugins.
+    js_plugins: &'a [(PathBuf, String)],
+    type_aware: bool,
+    type_check: bool,
+}
+
+/// Paths and source hashes of the JS plugins, sorted by path.
+///
+/// Only the file each plugin is loaded from is hashed, not the modules it imports.
+fn hash_js_plugins(linter: &Linter) -> Vec<(PathBuf, String)> {
+    let mut plugins = linter
+        .config
+        .external_plugin_store()
+        .plugin_paths()
+        .map(|path| (path.to_path_buf(), fs::read(path).map(|source| hash_source(&source))))
+        .map(|(path, hash)| (path, hash.unwrap_or_default()))
+        .collect::<Vec<_>>();
+    plugins.sort_unstable();
+    plugins
+}
+
+/// Hash of a resolved config and the linter options.
+fn hash_config(
+    linter: &Linter,
+    resolved: &ResolvedLinterState,
+    js_plugins: &[(PathBuf, String)],
+    type_aware: bool,
+    type_check: bool,
+) -> String {
+    let options = linter.options();
+    let config = &resolved.config;
+
+    let mut rules = resolved
+        .rules
+        .iter()
+        .map(|(rule, severity)| {
+            (format!("{}/{}", rule.plugin_name(), rule.name()), *severity, format!("{rule:?}"))
+        })
+        .collect::<Vec<_>>();
+    rules.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+    let store = linter.config.external_plugin_store();
+    let mut external_rules = resolved
+        .external_rules
+        .iter()
+        .map(|&(rule_id, options_id, severity)| {
+            let (plugin_name, rule_name) = store.resolve_plugin_rule_names(rule_id);
+            (format!("{plugin_name}/{rule_name}"), severity, store.options(options_id))
+        })
+        .collect::<Vec<_>>();
+    external_rules.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+    let fingerprint = ConfigFingerprint {
+        fix: options.fix.bits(),
+        framework_hints: options.framework_hints.bits(),
+        report_unused_directive: options.report_unused_directive,
+        report_suppressed: options.report_suppressed,
+        report_fixes: options.report_fixes,
+        no_inline_config: options.no_inline_config,
+        plugins: config.plugins,
+        settings: &config.settings,
+        env: config.env.iter().collect(),
+        globals: config.globals.iter().map(|(name, value)| (name.as_str(), *value)).collect(),
+        rules,
+        external_rules,
+        js_plugins,
+        type_aware,
+        type_check,
+    };
+    let json = serde_json::to_vec(&fingerprint).expect("config fingerprint is serializable");
+    format!("{:x}", Sha1::digest(json))
+}
+
+impl From<&Message> for CachedMessage {
+    fn from(message: &Message) -> Self {
+        let error = &message.error;
+        Self {
+            message: error.message.to_string(),
+            severity: match error.severity {
+                Severity::Advice => CachedSeverity::Advice,
+                Severity::Warning => CachedSeverity::Warning,
+                Severity::Error => CachedSeverity::Error,
+            },
+            scope: error.code.scope.as_deref().map(str::to_string),
+            number: error.code.number.as_deref().map(str::to_string),
+            help: error.help.as_deref().map(str::to_string),
+            note: error.note.as_deref().map(str::to_string),
+            url: error.url.as_deref().map(str::to_string),
+            labels: error
+                .labels
+                .iter()
+                .flatten()
+                .map(|label| CachedLabel {
+                    label: label.label().map(str::to_string),
+                    offset: label.offset(),
+                    len: label.len(),
+                    primary: label.primary(),
+                })
+                .collect(),
+            span: message.span,
+            fixes: match &message.fixes {
+                PossibleFixes::None => CachedFixes::None,
+                PossibleFixes::Single(fix) => CachedFixes::Single(fix.into()),
+                PossibleFixes::Multiple(fixes) => {
+                    CachedFixes::Multiple(fixes.iter().map(CachedFix::from).collect())
+                }
+            },
+            section_offset: message.section_offset,
+            suppressed: message.suppressed,
+        }
+    }
+}
+
+impl From<CachedMessage> for Message {
+    fn from(message: CachedMessage) -> Self {
+        let mut error =
+            OxcDiagnostic::error(message.message).with_severity(match message.severity {
+                CachedSeverity::Advice => Severity::Advice,
+                CachedSeverity::Warning => Severity::Warning,
+                CachedSeverity::Error => Severity::Error,
+            });
+        error.code = OxcCode {
+            scope: message.scope.map(Cow::Owned),
+            number: message.number.map(Cow::Owned),
+        };
+        error.help = message.help.map(Cow::Owned);
+        error.note = message.note.map(Cow::Owned);
+        error.url = message.url.map(Cow::Owned);
+        if !message.labels.is_empty() {
+            error.labels = Some(
+                message
+                    .labels
+                    .into_iter()
+                    .map(|label| {
+                        let span = (label.offset, label.len);
+                        if label.primary {
+                            LabeledSpan::new_primary_with_span(label.label, span)
+                        } else {
+                            LabeledSpan::new_with_span(label.label, span)
+                        }
+                    })
+                    .collect(),
+            );
+        }
+        let fixes = match message.fixes {
+            CachedFixes::None => PossibleFixes::None,
+            CachedFixes::Single(fix) => PossibleFixes::Single(fix.into()),
+            CachedFixes::Multiple(fixes) => {
+                PossibleFixes::Multiple(fixes.into_iter().map(Fix::from).collect())
+            }
+        };
+        let mut result = Message::new(error, fixes)
+            .with_section_offset(message.section_offset)
+            .with_suppressed(message.suppressed);
+        result.span = message.span;
+        result
+    }
+}
+
+impl From<&Fix> for CachedFix {
+    fn from(fix: &Fix) -> Self {
+        Self {
+            content: fix.content.to_string(),
+            message: fix.message.as_deref().map(str::to_string),
+            span: fix.span,
+        }
+    }
+}
+
+impl From<CachedFix> for Fix {
+    fn from(fix: CachedFix) -> Self {
+        Self {
+            content: Cow::Owned(fix.content),
+            message: fix.message.map(Cow::Owned),
+            span: fix.span,
+        }
+    }
+}
+
+/// (De)serialize a [`Span`] as a `[start, end]` pair.
+pub mod serde_span {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use oxc_span::Span;
+
+    #[expect(clippy::trivially_copy_pass_by_ref)] // `serde(with)` passes a reference
+    pub fn serialize<S: Serializer>(span: &Span, serializer: S) -> Result<S::Ok, S::Error> {
+        [span.start, span.end].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Span, D::Error> {
+        let [start, end] = <[u32; 2]>::deserialize(deserializer)?;
+        Ok(Span::new(start, end))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf};
+
+    use rustc_hash::FxHashMap;
+
+    use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+    use oxc_span::Span;
+
+    use super::{CachedMessage, LintCache, hash_source};
+    use crate::{
+        ConfigStore, ConfigStoreBuilder, ExternalPluginStore, Fix, LintOptions, Linter, Message,
+        PossibleFixes,
+    };
+
+    fn linter() -> Linter {
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config = ConfigStoreBuilder::empty().build(&mut external_plugin_store).unwrap();
+        let config = ConfigStore::new(config, FxHashMap::default(), external_plugin_store);
+        Linter::new(LintOptions::default(), config, None)
+    }
+
+    fn message() -> Message {
+        let error = OxcDiagnostic::warn("`debugger` statement is not allowed")
+            .with_error_code("eslint", "no-debugger")
+            .with_help("Remove the debugger statement")
+            .with_url("https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html")
+            .with_labels([
+                LabeledSpan::new_primary_with_span(Some("here".to_string()), (1, 9)),
+                LabeledSpan::new_with_span(None, (12, 3)),
+            ]);
+        let fixes = PossibleFixes::Multiple(vec![
+            Fix::delete(Span::new(1, 10)).with_message("Remove the debugger statement"),
+            Fix::new("", Span::new(1, 10)),
+        ]);
+        Message::new(error, fixes).with_section_offset(1).with_suppressed(true)
+    }
+
+    /// Write `files` to `dir` and record them in `cache` as if they were linted,
+    /// with the given dependencies and one message each.
+    fn lint(cache: &LintCache, linter: &Linter, dir: &std::path::Path, files: &[(&str, &[&str])]) {
+        for (name, dependencies) in files {
+            let path = dir.join(name);
+            if !path.exists() {
+                fs::write(&path, format!("// {name}\n")).unwrap();
+            }
+            let hash = hash_source(&fs::read(&path).unwrap());
+            let dependencies = dependencies.iter().map(|name| dir.join(name)).collect();
+            cache.insert_module(&path, hash, dependencies);
+            cache.insert_result(linter, &path, &[message()], None);
+        }
+    }
+
+    /// Names of the files in `paths` with a cached result.
+    fn cached(cache: &LintCache, linter: &Linter, paths: Vec<PathBuf>) -> Vec<String> {
+        let (cached, _) = cache.partition(linter, paths);
+        let mut names = cached
+            .into_iter()
+            .map(|(path, _)| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn message_round_trip() {
+        let message = message();
+        let cached = serde_json::to_string(&CachedMessage::from(&message)).unwrap();
+        let restored = Message::from(serde_json::from_str::<CachedMessage>(&cached).unwrap());
+        assert_eq!(restored, message);
+    }
+
+    #[test]
+    fn restores_unchanged_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join(".oxlintcache");
+        let linter = linter();
+        let paths = vec![dir.path().join("a.js"), dir.path().join("b.js")];
+
+        let cache = LintCache::load(&cache_path, "1.0.0");
+        lint(&cache, &linter, dir.path(), &[("a.js", &[]), ("b.js", &[])]);
+        cache.save().unwrap();
+
+        let cache = LintCache::load(&cache_path, "1.0.0");
+        let (restored, uncached) = cache.partition(&linter, paths.clone());
+        assert!(uncached.is_empty());
+        assert_eq!(restored.len(), 2);
+        let (messages, _) = restored[0].1.clone().into_parts();
+        assert_eq!(messages, vec![message()]);
+        assert!(cache.is_restored(&paths[0]));
+
+        // Caches of other versions are discarded.
+        let cache = LintCache::load(&cache_path, "2.0.0");
+        assert!(cached(&cache, &linter, paths.clone()).is_empty());
+
+        // Results cached with different options are not reused.
+        let cache = LintCache::load(&cache_path, "1.0.0").with_type_aware(true, false);
+        assert!(cached(&cache, &linter, paths).is_empty());
+    }
+
+    #[test]
+    fn invalidates_dependents_of_changed_modules() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join(".oxlintcache");
+        let linter = linter();
+        let paths = ["a.js", "b.js", "c.js"].map(|name| dir.path().join(name)).to_vec();
+
+        // `a.js` imports `b.js`, which imports `dep.js`. `dep.js` is not linted itself.
+        let cache = LintCache::load(&cache_path, "1.0.0");
+        lint(
+            &cache,
+            &linter,
+            dir.path(),
+            &[("a.js", &["b.js"]), ("b.js", &["dep.js"]), ("c.js", &[])],
+        );
+        let dep = dir.path().join("dep.js");
+        fs::write(&dep, "export {};\n").unwrap();
+        cache.insert_module(&dep, hash_source(b"export {};\n"), vec![]);
+        cache.save().unwrap();
+
+        let cache = LintCache::load(&cache_path, "1.0.0");
+        assert_eq!(cached(&cache, &linter, paths.clone()), ["a.js", "b.js", "c.js"]);
+
+        fs::write(&dep, "export const dep = 1;\n").unwrap();
+        let cache = LintCache::load(&cache_path, "1.0.0");
+        assert_eq!(cached(&cache, &linter, paths.clone()), ["c.js"]);
+        assert!(!cache.is_restored(&paths[0]));
+
+        // Invalidated modules which are not linted again are dropped.
+        cache.save().unwrap();
+        let cache = LintCache::load(&cache_path, "1.0.0");
+        assert_eq!(cached(&cache, &linter, paths), ["c.js"]);
+    }
+
+    #[test]
+    fn stores_type_aware_messages() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join(".oxlintcache");
+        let linter = linter();
+        let path = dir.path().join("a.ts");
+
+        let cache = LintCache::load(&cache_path, "1.0.0").with_type_aware(true, false);
+        lint(&cache, &linter, dir.path(), &[("a.ts", &[])]);
+        let type_aware = Message::new(OxcDiagnostic::warn("type-aware"), PossibleFixes::None);
+        cache.insert_type_aware_message(&path, &type_aware);
+        // Files without a result, e.g. with parse errors, are not cached.
+        cache.insert_type_aware_message(&dir.path().join("b.ts"), &type_aware);
+        cache.save().unwrap();
+
+        let cache = LintCache::load(&cache_path, "1.0.0").with_type_aware(true, false);
+        let (restored, _) = cache.partition(&linter, vec![path, dir.path().join("b.ts")]);
+        assert_eq!(restored.len(), 1);
+        let (messages, _) = restored[0].1.clone().into_parts();
+        assert_eq!(messages, vec![message(), type_aware]);
+    }
+}
@@ -119,9 +119,10 @@ impl Config {
         self.base.rules.len()
     }
 
-    pub fn apply_overrides(&self, path: &Path) -> ResolvedLinterState {
+    /// Indices of the overrides which apply to `path`, in the order they are applied.
+    fn matching_overrides(&self, path: &Path) -> Vec<usize> {
         if self.overrides.is_empty() {
-            return self.base.clone();
+            return vec![];
         }
 
         let relative_path = self
@@ -135,15 +136,22 @@ impl Config {
             .unwrap_or(path);
 
         let path = relative_path.to_string_lossy();
-        let overrides_to_apply =
-            self.overrides.iter().filter(|config| config.files.is_match(path.as_ref()));
-
-        let mut overrides_to_apply = overrides_to_apply.peekable();
+        self.overrides
+            .iter()
+            .enumerate()
+            .filter(|(_, config)| config.files.is_match(path.as_ref()))
+            .map(|(index, _)| index)
+            .collect()
+    }
 
-        if overrides_to_apply.peek().is_none() {
+    pub fn apply_overrides(&self, path: &Path) -> ResolvedLinterState {
+        let matching_overrides = self.matching_overrides(path);
+        if matching_overrides.is_empty() {
             return self.base.clone();
         }
 
+        let overrides_to_apply = matching_overrides.iter().map(|&index| &self.overrides.0[index]);
+
         let mut env = self.base.config.env.clone();
         let mut globals = self.base.config.globals.clone();
         let mut plugins = self.base.config.plugins;
@@ -270,6 +278,16 @@ impl Config {
     }
 }
 
+/// Identifies a [`ResolvedLinterState`]: the nested config it comes from
+/// (`None` for the root config) and the overrides applied on top of it.
+///
+/// See [`ConfigStore::resolve_key`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResolvedConfigKey {
+    config_dir: Option<PathBuf>,
+    overrides: Vec<usize>,
+}
+
 /// Stores the configuration state for the linter including:
 /// 1. the root configuration (base)
 /// 2. any nested configurations (`nested_configs`)
@@ -321,13 +339,18 @@ impl ConfigStore {
     }
 
     pub(crate) fn get_related_config(&self, path: &Path) -> &Config {
-        if self.nested_configs.is_empty() {
-            &self.base
-        } else if let Some(config) = self.get_nearest_config(path) {
-            config
-        } else {
-            &self.base
-        }
+        self.get_nearest_config(path).map_or(&self.base, |(_, config)| config)
+    }
+
+    /// Identifies the [`ResolvedLinterState`] of `path` without resolving it.
+    ///
+    /// Paths with equal keys resolve to the same state.
+    pub(crate) fn resolve_key(&self, path: &Path) -> ResolvedConfigKey {
+        let (config_dir, config) = match self.get_nearest_config(path) {
+            Some((dir, config)) => (Some(dir.to_path_buf()), config),
+            None => (None, &self.base),
+        };
+        ResolvedConfigKey { config_dir, overrides: config.matching_overrides(path) }
     }
 
     // NOTE: This function is not crate visible because it is used in `oxlint` as well to resolve configs
@@ -336,13 +359,16 @@ impl ConfigStore {
         Config::apply_overrides(self.get_related_config(path), path)
     }
 
-    fn get_nearest_config(&self, path: &Path) -> Option<&Config> {
+    fn get_nearest_config<'p>(&self, path: &'p Path) -> Option<(&'p Path, &Config)> {
+        if self.nested_configs.is_empty() {
+            return None;
+        }
         // TODO(perf): should we cache the computed nearest config for every directory,
         // so we don't have to recompute it for every file?
         let mut current = path.parent();
         while let Some(dir) = current {
             if let Some(config) = self.nested_configs.get(dir) {
-                return Some(config);
+                return Some((dir, config));
             }
             current = dir.parent();
         }
@@ -41,6 +41,12 @@ impl Deref for OxlintGlobals {
     }
 }
 
+impl FromIterator<(String, GlobalValue)> for OxlintGlobals {
+    fn from_iter<T: IntoIterator<Item = (String, GlobalValue)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
 impl OxlintGlobals {
     pub fn is_enabled<Q>(&self, name: &Q) -> bool
     where
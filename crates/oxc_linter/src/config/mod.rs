@@ -13,7 +13,7 @@ pub mod plugins;
 mod rules;
 mod settings;
 pub use config_builder::{ConfigBuilderError, ConfigStoreBuilder};
-pub use config_store::{Config, ConfigStore, ResolvedLinterState};
+pub use config_store::{Config, ConfigStore, ResolvedConfigKey, ResolvedLinterState};
 pub use env::OxlintEnv;
 pub use globals::{GlobalValue, OxlintGlobals};
 pub use ignore_matcher::LintIgnoreMatcher;
@@ -97,15 +97,8 @@ impl OxlintRules {
                             .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
                     });
                     if let Some(rule) = rule {
-                        // Configs are stored as `SmallVec<[Value; 1]>`, but `from_configuration` expects
-                        // a single `Value` with `Value::Null` being the equivalent of empty config
-                        let config = if rule_config.config.is_empty() {
-                            serde_json::Value::Null
-                        } else {
-                            serde_json::Value::Array(rule_config.config.to_vec())
-                        };
                         rules_to_replace.push((
-                            rule.from_configuration(config)
+                            rule.from_configuration(rule_config.configuration())
                                 .expect("failed to parse rule configuration"),
                             severity,
                         ));
@@ -347,6 +340,38 @@ fn failed_to_parse_rule_value(value: &str, err: &str) -> OxcDiagnostic {
 }
 
 impl ESLintRule {
+    /// Parses a single entry of a rules map, e.g. `"eqeqeq": ["error", "smart"]`.
+    pub(crate) fn parse(key: &str, value: serde_json::Value) -> Result<Self, Error> {
+        let (plugin_name, rule_name) = parse_rule_key(key);
+        let (severity, config) = parse_rule_value(value)?;
+        Ok(Self { plugin_name, rule_name, severity, config })
+    }
+
+    /// Returns the built-in rule this configuration refers to, or `None` if the plugin is
+    /// not a built-in plugin or has no such rule.
+    pub(crate) fn find_builtin_rule(&self) -> Option<&'static RuleEnum> {
+        let (rule_name, plugin_name) =
+            transform_rule_and_plugin_name(&self.rule_name, &self.plugin_name);
+        RULES.iter().find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
+    }
+
+    /// Returns `true` if the plugin is a built-in plugin, rather than a JS plugin.
+    pub(crate) fn is_builtin_plugin(&self) -> bool {
+        let (_, plugin_name) = transform_rule_and_plugin_name(&self.rule_name, &self.plugin_name);
+        LintPlugins::try_from(plugin_name).is_ok()
+    }
+
+    /// The configuration as passed to [`RuleEnum::from_configuration`].
+    pub(crate) fn configuration(&self) -> serde_json::Value {
+        // Configs are stored as `SmallVec<[Value; 1]>`, but `from_configuration` expects
+        // a single `Value` with `Value::Null` being the equivalent of empty config
+        if self.config.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::Value::Array(self.config.to_vec())
+        }
+    }
+
     /// Returns `<plugin_name>/<rule_name>` for non-eslint rules. For eslint rules, returns
     /// `<rule_name>`.
     // This is effectively the inverse operation for `parse_rule_key`.
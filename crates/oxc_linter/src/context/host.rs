@@ -1,12 +1,13 @@
 use std::{
     borrow::Cow,
-    cell::{Cell, RefCell},
+    cell::{Cell, OnceCell, RefCell},
     ffi::OsStr,
     path::Path,
     rc::Rc,
     sync::Arc,
 };
 
+use oxc_compat::EngineTargets;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_semantic::Semantic;
 use oxc_span::{SourceType, Span};
@@ -17,6 +18,7 @@ use crate::{
     disable_directives::{DisableDirectives, DisableDirectivesBuilder, RuleCommentType},
     fixer::{Fix, FixKind, Message, PossibleFixes},
     frameworks::{self, FrameworkOptions},
+    inline_config::InlineConfig,
     module_record::ModuleRecord,
     options::LintOptions,
     rules::RuleEnum,
@@ -34,6 +36,8 @@ pub struct ContextSubHost<'a> {
     /// Information about specific rules that should be disabled or enabled, via comment directives like
     /// `eslint-disable` or `eslint-disable-next-line`.
     pub(super) disable_directives: DisableDirectives,
+    /// Globals, environments and rule configuration from comments like `/* global foo */`.
+    pub(super) inline_config: InlineConfig,
     // Specific framework options, for example, whether the context is inside `<script setup>` in Vue files.
     pub(super) framework_options: FrameworkOptions,
     /// The source text offset of the sub host
@@ -71,12 +75,14 @@ impl<'a> ContextSubHost<'a> {
 
         let disable_directives =
             DisableDirectivesBuilder::new().build(semantic.source_text(), semantic.comments());
+        let inline_config = InlineConfig::new(semantic.source_text(), semantic.comments());
 
         Self {
             semantic,
             module_record,
             source_text_offset,
             disable_directives,
+            inline_config,
             framework_options: frameworks_options,
         }
     }
@@ -147,8 +153,13 @@ pub struct ContextHost<'a> {
     /// Global linter configuration, such as globals to include and the target
     /// environments, and other settings.
     pub(super) config: Arc<LintConfig>,
+    /// Target environments from the browserslist config that applies to the file.
+    /// Resolved on first use, since most rules never ask for them.
+    targets: OnceCell<Option<EngineTargets>>,
     /// Front-end frameworks that might be in use in the target file.
     pub(super) frameworks: FrameworkFlags,
+    /// Whether to report diagnostics suppressed by disable directives, instead of discarding them.
+    pub(super) report_suppressed: bool,
 }
 
 impl std::fmt::Debug for ContextHost<'_> {
@@ -162,7 +173,7 @@ impl<'a> ContextHost<'a> {
     /// If `sub_hosts` is empty.
     pub fn new<P: AsRef<Path>>(
         file_path: P,
-        sub_hosts: Vec<ContextSubHost<'a>>,
+        mut sub_hosts: Vec<ContextSubHost<'a>>,
         options: LintOptions,
         config: Arc<LintConfig>,
     ) -> Self {
@@ -176,15 +187,41 @@ impl<'a> ContextHost<'a> {
         let file_path = file_path.as_ref().to_path_buf().into_boxed_path();
         let file_extension = file_path.extension().map(|ext| ext.to_owned().into_boxed_os_str());
 
+        if options.no_inline_config {
+            for sub_host in &mut sub_hosts {
+                sub_host.disable_directives = DisableDirectivesBuilder::new().build("", &[]);
+                sub_host.inline_config = InlineConfig::default();
+            }
+        }
+
+        // Globals and environments from comments apply to the whole file.
+        let config =
+            if sub_hosts.iter().any(|sub_host| sub_host.inline_config.has_globals_or_envs()) {
+                let mut config = LintConfig::clone(&config);
+                for sub_host in &sub_hosts {
+                    sub_host.inline_config.override_config(&mut config);
+                }
+                Arc::new(config)
+            } else {
+                config
+            };
+
         Self {
             sub_hosts,
             current_sub_host_index: Cell::new(0),
             diagnostics: RefCell::new(Vec::with_capacity(DIAGNOSTICS_INITIAL_CAPACITY)),
-            fix: options.fix,
+            // When fixes are only reported, they are still collected but never applied.
+            fix: if options.fix.is_none() && options.report_fixes {
+                FixKind::SafeFixOrSuggestion
+            } else {
+                options.fix
+            },
             file_path,
             file_extension,
             config,
+            targets: OnceCell::new(),
             frameworks: options.framework_hints,
+            report_suppressed: options.report_suppressed,
         }
         .sniff_for_frameworks()
     }
@@ -227,6 +264,11 @@ impl<'a> ContextHost<'a> {
         &self.current_sub_host().disable_directives
     }
 
+    /// Shared reference to the [`InlineConfig`] of the current script block.
+    pub(crate) fn inline_config(&self) -> &InlineConfig {
+        &self.current_sub_host().inline_config
+    }
+
     /// Path to the file being linted.
     ///
     /// When created from a [`LintService`](`crate::service::LintService`), this
@@ -269,6 +311,18 @@ impl<'a> ContextHost<'a> {
         &self.config.env
     }
 
+    /// Target environments from the [browserslist config] that applies to the file,
+    /// i.e. the same targets the transformer and minifier resolve for it.
+    ///
+    /// Returns `None` if there is no config, or if it cannot be read or parsed.
+    ///
+    /// [browserslist config]: <https://github.com/browserslist/browserslist#config-file>
+    pub fn targets(&self) -> Option<&EngineTargets> {
+        self.targets
+            .get_or_init(|| EngineTargets::from_browserslist_config(&self.file_path).ok().flatten())
+            .as_ref()
+    }
+
     /// Add a diagnostic message to the end of the list of diagnostics. Can be used
     /// by any rule to report issues.
     #[inline]
@@ -279,6 +333,13 @@ impl<'a> ContextHost<'a> {
         self.diagnostics.borrow_mut().push(diagnostic);
     }
 
+    /// Report invalid configuration comments, like `/* eslint eqeqeq: [ */`, of the current script block.
+    pub fn report_inline_config_errors(&self) {
+        for diagnostic in self.inline_config().diagnostics(self.plugins()) {
+            self.push_diagnostic(Message::new(diagnostic, PossibleFixes::None));
+        }
+    }
+
     // Append a list of diagnostics. Only used in report_unused_directives.
     fn append_diagnostics(&self, mut diagnostics: Vec<Message>) {
         if self.current_sub_host().source_text_offset != 0 {
@@ -486,3 +547,42 @@ impl<'a> From<ContextHost<'a>> for Vec<Message> {
         ctx_host.diagnostics.into_inner()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_compat::ESFeature;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::{ContextHost, ModuleRecord, context::ContextSubHost, options::LintOptions};
+
+    #[test]
+    fn test_targets() {
+        let allocator = Allocator::default();
+
+        let build_ctx = |path: &'static str| {
+            let parser_ret = Parser::new(&allocator, "", SourceType::default()).parse();
+            let program = allocator.alloc(parser_ret.program);
+            let semantic = SemanticBuilder::new().with_cfg(true).build(program).semantic;
+            Rc::new(ContextHost::new(
+                path,
+                vec![ContextSubHost::new(semantic, Arc::new(ModuleRecord::default()), 0)],
+                LintOptions::default(),
+                Arc::default(),
+            ))
+            .spawn_for_test()
+        };
+
+        let ctx = build_ctx("fixtures/browserslist/foo.js");
+        let targets = ctx.targets().expect("fixtures/browserslist/.browserslistrc");
+        assert!(targets.has_feature(ESFeature::ES2018ObjectRestSpread));
+        assert!(!targets.has_feature(ESFeature::ES2015ArrowFunctions));
+
+        let ctx = build_ctx("/foo.js");
+        assert!(ctx.targets().is_none());
+    }
+}
@@ -6,6 +6,7 @@ use javascript_globals::GLOBALS;
 
 use oxc_ast::ast::IdentifierReference;
 use oxc_cfg::ControlFlowGraph;
+use oxc_compat::EngineTargets;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_semantic::Semantic;
 use oxc_span::Span;
@@ -225,6 +226,15 @@ impl<'a> LintContext<'a> {
         &self.parent.config.env
     }
 
+    /// Target environments from the browserslist config of the file being linted,
+    /// e.g. `.browserslistrc` or the `browserslist` key of `package.json`.
+    ///
+    /// Returns `None` if there is no config, or if it cannot be read or parsed.
+    #[inline]
+    pub fn targets(&self) -> Option<&EngineTargets> {
+        self.parent.targets()
+    }
+
     fn get_env_global_entry(&self, var: &str) -> Option<GlobalValue> {
         // builtin is always readonly
         if GLOBALS["builtin"].contains_key(var) {
@@ -268,7 +278,10 @@ impl<'a> LintContext<'a> {
     /// name, severity, and a link to the rule's documentation URL.
     fn add_diagnostic(&self, mut message: Message) {
         if self.parent.disable_directives().contains(self.current_rule_name, message.span) {
-            return;
+            if !self.parent.report_suppressed {
+                return;
+            }
+            message.suppressed = true;
         }
         message.error = message
             .error
@@ -573,3 +586,45 @@ fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
         _ => plugin_name,
     }
 }
+
+/// Gets the short plugin name, given the prefixed plugin name in a diagnostic code.
+/// This is the inverse of [`plugin_name_to_prefix`]. Other names, like those of JS plugins,
+/// are returned as is.
+///
+/// Example:
+///
+/// ```text
+/// assert_eq!(prefix_to_plugin_name("eslint-plugin-react"), "react");
+/// ```
+pub fn prefix_to_plugin_name(prefix: &str) -> &str {
+    match prefix {
+        "eslint-plugin-import" => "import",
+        "eslint-plugin-jest" => "jest",
+        "eslint-plugin-jsdoc" => "jsdoc",
+        "eslint-plugin-jsx-a11y" => "jsx_a11y",
+        "eslint-plugin-next" => "nextjs",
+        "eslint-plugin-promise" => "promise",
+        "eslint-plugin-react-perf" => "react_perf",
+        "eslint-plugin-react" => "react",
+        "typescript-eslint" => "typescript",
+        "eslint-plugin-unicorn" => "unicorn",
+        "eslint-plugin-vitest" => "vitest",
+        "eslint-plugin-node" => "node",
+        "eslint-plugin-vue" => "vue",
+        _ => prefix,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{plugin_name_to_prefix, prefix_to_plugin_name};
+    use crate::rules::RULES;
+
+    #[test]
+    fn test_prefix_to_plugin_name() {
+        for rule in RULES.iter() {
+            let plugin_name = rule.plugin_name();
+            assert_eq!(prefix_to_plugin_name(plugin_name_to_prefix(plugin_name)), plugin_name);
+        }
+    }
+}
@@ -5,10 +5,11 @@ use oxc_ast::Comment;
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
 use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
 
-use crate::fixer::Fix;
+use crate::{cache::serde_span, fixer::Fix};
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 enum DisabledRule {
     /// Disables all linting rules for a span of code.
     /// Used by directives like `eslint-disable`, `eslint-disable-next-line`, or `eslint-disable-line` without specific rule names.
@@ -20,6 +21,7 @@ enum DisabledRule {
     /// ```
     All {
         /// Span of the comment containing the disable directive
+        #[serde(with = "serde_span")]
         comment_span: Span,
         /// Whether this is a line-specific directive (`-next-line` or `-line`).
         is_next_line: bool,
@@ -39,10 +41,12 @@ enum DisabledRule {
         /// Span of the rule name within the comment.
         ///
         /// For `/* eslint-disable no-debugger */`, this points to "no-debugger".
+        #[serde(with = "serde_span")]
         name_span: Span,
         /// Span of the entire comment content containing the disable directive.
         ///
         /// For `/* eslint-disable no-debugger */`, this points to "eslint-disable no-debugger".
+        #[serde(with = "serde_span")]
         comment_span: Span,
         /// Whether this is a line-specific directive (`-next-line` or `-line`).
         /// When true, only diagnostics starting within the interval are suppressed.
@@ -80,7 +84,7 @@ impl DisabledRule {
 ///                   ^^^^^^^^^^^              name_span (for this RuleCommentRule)
 ///                   no-debugger              rule_name
 /// ```
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct RuleCommentRule {
     /// Name of the rule (e.g., "no-debugger", "no-console")
     pub rule_name: String,
@@ -88,6 +92,7 @@ pub struct RuleCommentRule {
     ///
     /// For `/* eslint-disable no-debugger, no-console */`, the first
     /// `RuleCommentRule` would have a `name_span` pointing to "no-debugger".
+    #[serde(with = "serde_span")]
     pub name_span: Span,
 }
 
@@ -145,7 +150,7 @@ impl RuleCommentRule {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum RuleCommentType {
     // disable/enable all the rules
     All,
@@ -154,9 +159,10 @@ pub enum RuleCommentType {
 }
 
 /// A comment which disables one or more specific rules
-#[derive(Debug, Eq, PartialEq, Clone)]
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
 pub struct DisableRuleComment {
     /// Span of the comment
+    #[serde(with = "serde_span")]
     pub span: Span,
     /// Rules disabled by the comment
     pub r#type: RuleCommentType,
@@ -308,6 +314,58 @@ impl DisableDirectives {
     }
 }
 
+/// Serialized form of [`DisableDirectives`], used by the lint cache.
+///
+/// Includes the directives marked as used, so that restoring cached directives reports the same
+/// unused directives as linting the file again.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerializedDisableDirectives {
+    intervals: Vec<(u32, u32, DisabledRule)>,
+    disable_rule_comments: Vec<DisableRuleComment>,
+    unused_enable_comments: Vec<(Option<String>, u32, u32)>,
+    used_disable_comments: Vec<DisabledRule>,
+}
+
+impl From<DisableDirectives> for SerializedDisableDirectives {
+    fn from(directives: DisableDirectives) -> Self {
+        Self {
+            intervals: directives
+                .intervals
+                .into_iter()
+                .map(|interval| (interval.start, interval.stop, interval.val))
+                .collect(),
+            disable_rule_comments: directives.disable_rule_comments.into_vec(),
+            unused_enable_comments: directives
+                .unused_enable_comments
+                .into_iter()
+                .map(|(rule_name, span)| (rule_name, span.start, span.end))
+                .collect(),
+            used_disable_comments: directives.used_disable_comments.into_inner(),
+        }
+    }
+}
+
+impl From<SerializedDisableDirectives> for DisableDirectives {
+    fn from(directives: SerializedDisableDirectives) -> Self {
+        Self {
+            intervals: Lapper::new(
+                directives
+                    .intervals
+                    .into_iter()
+                    .map(|(start, stop, val)| Interval { start, stop, val })
+                    .collect(),
+            ),
+            disable_rule_comments: directives.disable_rule_comments.into_boxed_slice(),
+            unused_enable_comments: directives
+                .unused_enable_comments
+                .into_iter()
+                .map(|(rule_name, start, end)| (rule_name, Span::new(start, end)))
+                .collect(),
+            used_disable_comments: RefCell::new(directives.used_disable_comments),
+        }
+    }
+}
+
 pub struct DisableDirectivesBuilder {
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule>,
@@ -83,6 +83,11 @@ impl ExternalPluginStore {
         self.registered_plugin_paths.contains(plugin_path)
     }
 
+    /// Paths of the registered plugins.
+    pub fn plugin_paths(&self) -> impl Iterator<Item = &Path> {
+        self.registered_plugin_paths.iter().map(PathBuf::as_path)
+    }
+
     /// Register plugin.
     ///
     /// # Panics
@@ -159,6 +164,11 @@ impl ExternalPluginStore {
         }
     }
 
+    /// Options registered with [`ExternalPluginStore::add_options`].
+    pub fn options(&self, options_id: ExternalOptionsId) -> &[serde_json::Value] {
+        &self.options[options_id].1
+    }
+
     /// Send options to JS side.
     ///
     /// # Errors
@@ -238,6 +238,9 @@ pub struct Message {
     pub span: Span,
     fixed: bool,
     pub section_offset: u32,
+    /// Whether this message is suppressed by a disable directive.
+    /// Suppressed messages are only reported, never fixed.
+    pub suppressed: bool,
 }
 
 impl Message {
@@ -250,7 +253,7 @@ impl Message {
             .map(|span| Span::new(span.offset() as u32, (span.offset() + span.len()) as u32))
             .unwrap_or_default();
 
-        Self { error, span, fixes, fixed: false, section_offset: 0 }
+        Self { error, span, fixes, fixed: false, section_offset: 0, suppressed: false }
     }
 
     #[must_use]
@@ -259,6 +262,12 @@ impl Message {
         self
     }
 
+    #[must_use]
+    pub fn with_suppressed(mut self, suppressed: bool) -> Self {
+        self.suppressed = suppressed;
+        self
+    }
+
     /// move the offset of all spans to the right
     pub fn move_offset(&mut self, offset: u32) -> &mut Self {
         debug_assert!(offset != 0);
@@ -359,6 +368,8 @@ impl<'a> Fixer<'a> {
 
         for mut m in self.messages {
             let fix = match &m.fixes {
+                // Suppressed diagnostics are only reported, never fixed.
+                _ if m.suppressed => None,
                 PossibleFixes::None => None,
                 PossibleFixes::Single(fix) => Some(fix),
                 // For multiple fixes, we take the first one as a representative fix.
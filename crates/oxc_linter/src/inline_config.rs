@@ -0,0 +1,437 @@
+use std::borrow::Cow;
+
+use javascript_globals::GLOBALS;
+use oxc_ast::Comment;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use serde_json::{Map, Value};
+
+use crate::{
+    AllowWarnDeny, LintPlugins,
+    config::{ESLintRule, GlobalValue, LintConfig, OxlintEnv, OxlintGlobals},
+    rules::RuleEnum,
+};
+
+/// Configuration from inline comments in a script block:
+///
+/// - `/* global foo, bar: writable */` declares globals
+/// - `/* eslint-env node, browser */` enables environments
+/// - `/* eslint eqeqeq: ["error", "smart"] */` configures rules
+///
+/// Like in ESLint, these must be block comments, and text after ` -- ` is a description.
+/// `oxlint` can be used in place of `eslint`, as with disable directives.
+#[derive(Debug, Default)]
+pub struct InlineConfig {
+    globals: Vec<(String, GlobalValue)>,
+    envs: Vec<String>,
+    /// Configured rules, in the order of the comments
+    rules: Vec<InlineRule>,
+    /// Invalid configuration comments
+    diagnostics: Vec<OxcDiagnostic>,
+}
+
+#[derive(Debug)]
+struct InlineRule {
+    /// The rule name as written in the comment
+    key: String,
+    span: Span,
+    rule: RuleEnum,
+    severity: AllowWarnDeny,
+    /// `false` if only the severity is configured, in which case the rule keeps its options
+    has_options: bool,
+}
+
+impl InlineConfig {
+    pub fn new(source_text: &str, comments: &[Comment]) -> Self {
+        let mut config = Self::default();
+
+        for comment in comments.iter().filter(|comment| comment.is_block()) {
+            let text = comment.content_span().source_text(source_text).trim_start();
+            let directive_end = text.find(char::is_whitespace).unwrap_or(text.len());
+            let (directive, value) = text.split_at(directive_end);
+            let value = strip_description(value).trim();
+
+            match directive {
+                "global" | "globals" => config.parse_globals(value, comment.span),
+                "eslint-env" | "oxlint-env" => config.parse_envs(value, comment.span),
+                "eslint" | "oxlint" => config.parse_rules(value, comment.span),
+                _ => {}
+            }
+        }
+
+        config
+    }
+
+    /// Diagnostics for invalid configuration comments.
+    ///
+    /// Rules of plugins which are not enabled by `plugins` are reported as not found.
+    pub fn diagnostics(&self, plugins: LintPlugins) -> impl Iterator<Item = OxcDiagnostic> + '_ {
+        let plugins = enabled_plugins(plugins);
+        self.diagnostics.iter().cloned().chain(
+            self.rules
+                .iter()
+                .filter(move |inline_rule| !is_plugin_enabled(&inline_rule.rule, plugins))
+                .map(|inline_rule| rule_not_found(&inline_rule.key, inline_rule.span)),
+        )
+    }
+
+    /// Returns `true` if there are globals or environments to add to the configuration.
+    pub fn has_globals_or_envs(&self) -> bool {
+        !self.globals.is_empty() || !self.envs.is_empty()
+    }
+
+    /// Adds the globals and environments of the comments to `config`.
+    pub(crate) fn override_config(&self, config: &mut LintConfig) {
+        self.envs.iter().cloned().collect::<OxlintEnv>().override_envs(&mut config.env);
+        self.globals
+            .iter()
+            .cloned()
+            .collect::<OxlintGlobals>()
+            .override_globals(&mut config.globals);
+    }
+
+    /// Applies the rule configuration of the comments to `rules`.
+    ///
+    /// Rules set to `off` are removed. If only a severity is given, a rule keeps its configured options.
+    /// Rules of plugins which are not enabled by `plugins` can't be enabled, and are ignored.
+    pub fn override_rules<'r>(
+        &self,
+        rules: &'r [(RuleEnum, AllowWarnDeny)],
+        plugins: LintPlugins,
+    ) -> Cow<'r, [(RuleEnum, AllowWarnDeny)]> {
+        if self.rules.is_empty() {
+            return Cow::Borrowed(rules);
+        }
+
+        let plugins = enabled_plugins(plugins);
+        let mut rules = rules.to_vec();
+        for InlineRule { rule, severity, has_options, .. } in
+            self.rules.iter().filter(|inline_rule| is_plugin_enabled(&inline_rule.rule, plugins))
+        {
+            let existing = rules.iter().position(|(r, _)| r == rule);
+            match existing {
+                Some(index) if severity.is_allow() => {
+                    rules.remove(index);
+                }
+                Some(index) => {
+                    if *has_options {
+                        rules[index].0 = rule.clone();
+                    }
+                    rules[index].1 = *severity;
+                }
+                None if severity.is_allow() => {}
+                None => rules.push((rule.clone(), *severity)),
+            }
+        }
+        Cow::Owned(rules)
+    }
+
+    fn parse_globals(&mut self, value: &str, span: Span) {
+        // Whitespace is allowed around `:`, and names are separated by commas or whitespace,
+        // e.g. `foo, bar : writable baz:off`.
+        let value = value.split(':').map(str::trim).collect::<Vec<_>>().join(":");
+        for item in value.split(|c: char| c == ',' || c.is_whitespace()).filter(|s| !s.is_empty()) {
+            let (name, value) = match item.split_once(':') {
+                Some((name, value)) => (name, Some(value)),
+                None => (item, None),
+            };
+            let global_value = match value {
+                None | Some("false") => Ok(GlobalValue::Readonly),
+                Some("true") => Ok(GlobalValue::Writable),
+                Some(value) => GlobalValue::try_from(value),
+            };
+            match global_value {
+                Ok(global_value) => self.globals.push((name.to_string(), global_value)),
+                Err(_) => {
+                    self.diagnostics.push(invalid_global_value(value.unwrap_or_default(), span));
+                }
+            }
+        }
+    }
+
+    fn parse_envs(&mut self, value: &str, span: Span) {
+        for env in value.split(',').map(str::trim).filter(|env| !env.is_empty()) {
+            if GLOBALS.contains_key(env) {
+                self.envs.push(env.to_string());
+            } else {
+                self.diagnostics.push(unknown_env(env, span));
+            }
+        }
+    }
+
+    fn parse_rules(&mut self, value: &str, span: Span) {
+        let rules = match parse_rules_json(value) {
+            Ok(rules) => rules,
+            Err(err) => {
+                self.diagnostics.push(invalid_rules_json(&err.to_string(), span));
+                return;
+            }
+        };
+
+        for (key, value) in rules {
+            let rule_config = match ESLintRule::parse(&key, value) {
+                Ok(rule_config) => rule_config,
+                Err(err) => {
+                    self.diagnostics.push(invalid_rule_config(&key, &err.to_string(), span));
+                    continue;
+                }
+            };
+            // The rules of JS plugins are not known here, so they are ignored. Unknown rules which
+            // are turned off, like ESLint rules oxlint does not implement, have no effect either.
+            let Some(rule) = rule_config.find_builtin_rule() else {
+                if rule_config.is_builtin_plugin() && !rule_config.severity.is_allow() {
+                    self.diagnostics.push(rule_not_found(&key, span));
+                }
+                continue;
+            };
+            match rule.from_configuration(rule_config.configuration()) {
+                Ok(rule) => self.rules.push(InlineRule {
+                    key,
+                    span,
+                    rule,
+                    severity: rule_config.severity,
+                    has_options: !rule_config.config.is_empty(),
+                }),
+                Err(err) => {
+                    self.diagnostics.push(invalid_rule_config(&key, &err.to_string(), span));
+                }
+            }
+        }
+    }
+}
+
+/// The plugins whose rules are enabled by `plugins`. Like in config files, `vitest` enables `jest` rules.
+fn enabled_plugins(plugins: LintPlugins) -> LintPlugins {
+    if plugins.contains(LintPlugins::VITEST) { plugins | LintPlugins::JEST } else { plugins }
+}
+
+fn is_plugin_enabled(rule: &RuleEnum, plugins: LintPlugins) -> bool {
+    LintPlugins::try_from(rule.plugin_name()).is_ok_and(|plugin| plugins.contains(plugin))
+}
+
+/// Removes a ` -- description` from the end of a comment.
+fn strip_description(text: &str) -> &str {
+    text.match_indices("--")
+        .find(|(index, _)| {
+            text[..*index].ends_with(char::is_whitespace)
+                && text[index + 2..].trim_start_matches('-').starts_with(char::is_whitespace)
+        })
+        .map_or(text, |(index, _)| &text[..index])
+}
+
+/// Parses the rules of an `/* eslint */` comment, e.g. `eqeqeq: ["error", "smart"], curly: off`.
+///
+/// This is JSON without the surrounding braces, in which rule names and other strings
+/// without special characters do not need to be quoted.
+fn parse_rules_json(text: &str) -> Result<Map<String, Value>, serde_json::Error> {
+    fn is_word_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '/' | '@' | '.' | '$')
+    }
+
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('{');
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c == '"' {
+            json.push(c);
+            while let Some((_, c)) = chars.next() {
+                json.push(c);
+                if c == '\\' {
+                    if let Some((_, c)) = chars.next() {
+                        json.push(c);
+                    }
+                } else if c == '"' {
+                    break;
+                }
+            }
+        } else if is_word_char(c) {
+            let mut end = start + c.len_utf8();
+            while let Some(&(index, c)) = chars.peek()
+                && is_word_char(c)
+            {
+                end = index + c.len_utf8();
+                chars.next();
+            }
+            let word = &text[start..end];
+            let is_number = word.starts_with(|c: char| c.is_ascii_digit() || c == '-')
+                && word.parse::<f64>().is_ok();
+            if is_number || matches!(word, "true" | "false" | "null") {
+                json.push_str(word);
+            } else {
+                json.push('"');
+                json.push_str(word);
+                json.push('"');
+            }
+        } else {
+            json.push(c);
+        }
+    }
+    json.push('}');
+
+    serde_json::from_str(&json)
+}
+
+fn invalid_global_value(value: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "'{value}' is not a valid configuration for a global (use 'readonly', 'writable', or 'off')"
+    ))
+    .with_label(span)
+}
+
+fn unknown_env(env: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Environment key '{env}' is unknown")).with_label(span)
+}
+
+fn invalid_rules_json(err: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Failed to parse inline configuration: {err}")).with_label(span)
+}
+
+fn rule_not_found(rule: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Definition for rule '{rule}' was not found")).with_label(span)
+}
+
+fn invalid_rule_config(rule: &str, err: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Inline configuration for rule '{rule}' is invalid: {err}"))
+        .with_label(span)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+    use serde_json::{Value, json};
+
+    use super::{InlineConfig, parse_rules_json, strip_description};
+    use crate::{AllowWarnDeny, LintPlugins, config::GlobalValue};
+
+    fn parse(source_text: &str) -> InlineConfig {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        InlineConfig::new(source_text, &ret.program.comments)
+    }
+
+    #[test]
+    fn test_globals() {
+        let config = parse(
+            "/* global foo, bar: writable baz : off */\n/*globals qux:true, quux:false -- legacy */\n// global ignored",
+        );
+        assert_eq!(
+            config.globals,
+            [
+                ("foo".to_string(), GlobalValue::Readonly),
+                ("bar".to_string(), GlobalValue::Writable),
+                ("baz".to_string(), GlobalValue::Off),
+                ("qux".to_string(), GlobalValue::Writable),
+                ("quux".to_string(), GlobalValue::Readonly),
+            ]
+        );
+        assert!(config.diagnostics.is_empty());
+
+        let config = parse("/* global foo: yes */");
+        assert!(config.globals.is_empty());
+        assert_eq!(
+            config.diagnostics[0].message,
+            "'yes' is not a valid configuration for a global (use 'readonly', 'writable', or 'off')"
+        );
+    }
+
+    #[test]
+    fn test_envs() {
+        let config = parse("/* eslint-env node, browser */\n/* eslint-env nope */");
+        assert_eq!(config.envs, ["node", "browser"]);
+        assert_eq!(config.diagnostics[0].message, "Environment key 'nope' is unknown");
+    }
+
+    #[test]
+    fn test_rules() {
+        let config = parse(
+            r#"/* eslint eqeqeq: ["error", "smart"], no-debugger: off, "no-console": warn -- reason */"#,
+        );
+        assert!(config.diagnostics.is_empty());
+        let rules = config
+            .rules
+            .iter()
+            .map(|rule| (rule.rule.name(), rule.severity, rule.has_options))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rules,
+            [
+                ("eqeqeq", AllowWarnDeny::Deny, true),
+                ("no-debugger", AllowWarnDeny::Allow, false),
+                ("no-console", AllowWarnDeny::Warn, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let config = parse(
+            "/* eslint eqeqeq: [error */\n/* eslint no-debugger: loud */\n/* eslint no-such-rule: error, semi: off */\n/* eslint foo/bar: error */",
+        );
+        assert!(config.rules.is_empty());
+        let messages = config
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.message.as_ref())
+            .collect::<Vec<_>>();
+        // Rules of JS plugins and unknown rules which are turned off are ignored
+        assert_eq!(messages.len(), 3);
+        assert!(messages[0].starts_with("Failed to parse inline configuration"));
+        assert!(messages[1].starts_with("Inline configuration for rule 'no-debugger' is invalid"));
+        assert_eq!(messages[2], "Definition for rule 'no-such-rule' was not found");
+    }
+
+    #[test]
+    fn test_rules_of_disabled_plugins() {
+        let config = parse("/* eslint react/jsx-key: error, vitest/no-focused-tests: error */");
+        let plugins = LintPlugins::default();
+
+        // Rules of disabled plugins are not enabled, and reported as not found
+        assert!(config.override_rules(&[], plugins).is_empty());
+        let messages = config
+            .diagnostics(plugins)
+            .map(|diagnostic| diagnostic.message.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                "Definition for rule 'react/jsx-key' was not found",
+                "Definition for rule 'vitest/no-focused-tests' was not found",
+            ]
+        );
+
+        let plugins = plugins | LintPlugins::REACT | LintPlugins::VITEST;
+        let rules = config.override_rules(&[], plugins);
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].0.name(), "jsx-key");
+        assert_eq!(config.diagnostics(plugins).count(), 0);
+    }
+
+    #[test]
+    fn test_parse_rules_json() {
+        assert_eq!(
+            Value::Object(
+                parse_rules_json(r#"eqeqeq: [2, smart], "no-restricted-syntax": ["error", "a:b"]"#)
+                    .unwrap()
+            ),
+            json!({ "eqeqeq": [2, "smart"], "no-restricted-syntax": ["error", "a:b"] })
+        );
+        assert_eq!(
+            Value::Object(
+                parse_rules_json("react/jsx-key: [warn, { checkFragmentShorthand: true }]")
+                    .unwrap()
+            ),
+            json!({ "react/jsx-key": ["warn", { "checkFragmentShorthand": true }] })
+        );
+        assert!(parse_rules_json("eqeqeq: [").is_err());
+    }
+
+    #[test]
+    fn test_strip_description() {
+        assert_eq!(strip_description(" foo -- bar"), " foo ");
+        assert_eq!(strip_description(" foo --- bar"), " foo ");
+        assert_eq!(strip_description(" foo: \"--x\""), " foo: \"--x\"");
+        assert_eq!(strip_description(" foo--bar"), " foo--bar");
+    }
+}
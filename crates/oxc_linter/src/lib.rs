@@ -5,6 +5,8 @@ use std::{
     path::Path,
     ptr::{self, NonNull},
     rc::Rc,
+    sync::Arc,
+    time::Instant,
 };
 
 use oxc_allocator::{Allocator, AllocatorPool, CloneIn};
@@ -17,6 +19,7 @@ use oxc_semantic::AstNode;
 use oxc_span::Span;
 
 mod ast_util;
+mod cache;
 mod config;
 mod context;
 mod disable_directives;
@@ -25,11 +28,14 @@ mod external_plugin_store;
 mod fixer;
 mod frameworks;
 mod globals;
+mod inline_config;
 mod module_graph_visitor;
 mod module_record;
 mod options;
+mod reported_message;
 mod rule;
 mod service;
+mod timing;
 mod tsgolint;
 mod utils;
 
@@ -54,11 +60,12 @@ pub use crate::disable_directives::{
     create_unused_directives_diagnostics,
 };
 pub use crate::{
+    cache::LintCache,
     config::{
         Config, ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ESLintRule, LintIgnoreMatcher,
         LintPlugins, Oxlintrc, ResolvedLinterState,
     },
-    context::{ContextSubHost, LintContext},
+    context::{ContextSubHost, LintContext, prefix_to_plugin_name},
     external_linter::{
         ExternalLinter, ExternalLinterLintFileCb, ExternalLinterLoadPluginCb,
         ExternalLinterSetupConfigsCb, JsFix, LintFileResult, LoadPluginResult,
@@ -71,8 +78,10 @@ pub use crate::{
     module_record::ModuleRecord,
     options::LintOptions,
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
+    reported_message::ReportedMessage,
     rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleRunFunctionsImplemented, RuleRunner},
     service::{LintService, LintServiceOptions, OsFileSystem, RuntimeFileSystem},
+    timing::{RuleTiming, RuleTimings},
     tsgolint::TsGoLintState,
     utils::{read_to_arena_str, read_to_string},
 };
@@ -104,6 +113,7 @@ pub struct Linter {
     options: LintOptions,
     config: ConfigStore,
     external_linter: Option<ExternalLinter>,
+    timings: Option<Arc<RuleTimings>>,
 }
 
 impl Linter {
@@ -112,7 +122,7 @@ impl Linter {
         config: ConfigStore,
         external_linter: Option<ExternalLinter>,
     ) -> Self {
-        Self { options, config, external_linter }
+        Self { options, config, external_linter, timings: None }
     }
 
     /// Set the kind of auto fixes to apply.
@@ -128,6 +138,44 @@ impl Linter {
         self
     }
 
+    /// Report diagnostics which are suppressed by disable directives, marked as
+    /// [suppressed](Message::suppressed), instead of discarding them.
+    #[must_use]
+    pub fn with_report_suppressed(mut self, yes: bool) -> Self {
+        self.options.report_suppressed = yes;
+        self
+    }
+
+    /// Ignore configuration comments, such as `/* global */`, `/* eslint rule: "error" */`
+    /// and disable directives.
+    #[must_use]
+    pub fn with_no_inline_config(mut self, yes: bool) -> Self {
+        self.options.no_inline_config = yes;
+        self
+    }
+
+    /// Collect fixes and suggestions for reporting, see [`ReportedMessage::fixes`], even
+    /// when no fixes are applied. Dangerous fixes are only included if they are applied.
+    #[must_use]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.options.report_fixes = yes;
+        self
+    }
+
+    /// Accumulate the time spent in each rule into `timings`.
+    ///
+    /// Timing forces rules to run one after another over the whole file, instead of interleaving
+    /// rules per AST node for large files, so that each rule's time can be measured.
+    #[must_use]
+    pub fn with_timings(mut self, timings: Option<Arc<RuleTimings>>) -> Self {
+        self.timings = timings;
+        self
+    }
+
+    pub fn timings(&self) -> Option<&Arc<RuleTimings>> {
+        self.timings.as_ref()
+    }
+
     pub(crate) fn options(&self) -> &LintOptions {
         &self.options
     }
@@ -184,7 +232,9 @@ impl Linter {
 
         loop {
             let semantic = ctx_host.semantic();
-            let rules = rules
+            let configured_rules =
+                ctx_host.inline_config().override_rules(&rules, ctx_host.plugins());
+            let rules = configured_rules
                 .iter()
                 .filter(|(rule, _)| {
                     if rule.is_tsgolint_rule() {
@@ -227,7 +277,9 @@ impl Linter {
                 // don't thrash the cache too much. Feel free to tweak based on benchmarking.
                 //
                 // See https://github.com/oxc-project/oxc/pull/6600 for more context.
-                if semantic.nodes().len() > 200_000 {
+                //
+                // When timing rules, always use the second branch, so that each rule can be timed on its own.
+                if self.timings.is_none() && semantic.nodes().len() > 200_000 {
                     // TODO: It seems like there is probably a more intelligent way to preallocate space here. This will
                     // likely incur quite a few unnecessary reallocs currently. We theoretically could compute this at
                     // compile-time since we know all of the rules and their AST node type information ahead of time.
@@ -282,7 +334,13 @@ impl Linter {
                         }
                     }
                 } else {
+                    // Only time the optimized run, which is the one used in release builds.
+                    let timings = self.timings.as_ref().filter(|_| with_runtime_optimization);
+                    let mut rule_timings = Vec::new();
+
                     for (rule, ctx) in &rules {
+                        let start = timings.is_some().then(Instant::now);
+
                         let run_info = rule.run_info();
                         if !with_runtime_optimization || run_info.is_run_once_implemented() {
                             rule.run_once(ctx);
@@ -313,6 +371,15 @@ impl Linter {
                                 rule.run_on_jest_node(&jest_node, ctx);
                             }
                         }
+
+                        if let Some(start) = start {
+                            let rule_name = format!("{}/{}", rule.plugin_name(), rule.name());
+                            rule_timings.push((rule_name, start.elapsed()));
+                        }
+                    }
+
+                    if let Some(timings) = timings {
+                        timings.extend(rule_timings);
                     }
                 }
             };
@@ -356,6 +423,8 @@ impl Linter {
                 });
             }
 
+            ctx_host.report_inline_config_errors();
+
             // Drop `rules` to release its `Rc` clones of `ctx_host`, ensuring `run_external_rules`
             // can mutably access `ctx_host` via `Rc::get_mut` without panicking due to multiple references.
             drop(rules);
@@ -582,6 +651,7 @@ impl Linter {
         let external_linter = self.external_linter.as_ref().unwrap();
 
         // Pass AST and rule IDs + options IDs to JS
+        let start = self.timings.is_some().then(Instant::now);
         let result = (external_linter.lint_file)(
             path.to_owned(),
             external_rules.iter().map(|(rule_id, _, _)| rule_id.raw()).collect(),
@@ -590,6 +660,10 @@ impl Linter {
             globals_json,
             allocator,
         );
+        // All JS rules run in a single traversal, so they can only be timed together
+        if let (Some(timings), Some(start)) = (&self.timings, start) {
+            timings.add(RuleTimings::JS_PLUGINS, start.elapsed());
+        }
         match result {
             Ok(diagnostics) => {
                 for diagnostic in diagnostics {
@@ -605,10 +679,10 @@ impl Linter {
                     let (plugin_name, rule_name) =
                         self.config.resolve_plugin_rule_names(external_rule_id);
 
-                    if ctx_host
+                    let suppressed = ctx_host
                         .disable_directives()
-                        .contains(&format!("{plugin_name}/{rule_name}"), span)
-                    {
+                        .contains(&format!("{plugin_name}/{rule_name}"), span);
+                    if suppressed && !self.options.report_suppressed {
                         continue;
                     }
 
@@ -649,13 +723,16 @@ impl Linter {
                         PossibleFixes::None
                     };
 
-                    ctx_host.push_diagnostic(Message::new(
-                        OxcDiagnostic::error(diagnostic.message)
-                            .with_label(span)
-                            .with_error_code(plugin_name.to_string(), rule_name.to_string())
-                            .with_severity(severity.into()),
-                        fix,
-                    ));
+                    ctx_host.push_diagnostic(
+                        Message::new(
+                            OxcDiagnostic::error(diagnostic.message)
+                                .with_label(span)
+                                .with_error_code(plugin_name.to_string(), rule_name.to_string())
+                                .with_severity(severity.into()),
+                            fix,
+                        )
+                        .with_suppressed(suppressed),
+                    );
                 }
             }
             Err(err) => {
@@ -10,8 +10,8 @@ use oxc_diagnostics::{DiagnosticSender, DiagnosticService};
 use oxc_span::Span;
 
 use crate::{
-    AllowWarnDeny, DisableDirectives, FixKind, LintService, LintServiceOptions, Linter, Message,
-    OsFileSystem, TsGoLintState,
+    AllowWarnDeny, DisableDirectives, FixKind, LintCache, LintService, LintServiceOptions, Linter,
+    Message, OsFileSystem, RuleTimings, TsGoLintState,
 };
 
 /// Unified runner that orchestrates both regular (oxc) and type-aware (tsgolint) linting
@@ -25,6 +25,10 @@ pub struct LintRunner {
     directives_store: DirectivesStore,
     /// Current working directory
     cwd: PathBuf,
+    /// Per-rule timings, shared with the regular linter
+    timings: Option<Arc<RuleTimings>>,
+    /// Cache of lint results, shared with the regular linter
+    cache: Option<Arc<LintCache>>,
 }
 
 /// Manages disable directives across all linting engines.
@@ -179,7 +183,14 @@ impl LintRunnerBuilder {
                 self.regular_linter.config.clone(),
                 self.fix_kind,
             ) {
-                Ok(state) => Some(state.with_silent(self.silent).with_type_check(self.type_check)),
+                Ok(state) => Some(
+                    state
+                        .with_silent(self.silent)
+                        .with_type_check(self.type_check)
+                        .with_report_suppressed(self.regular_linter.options().report_suppressed)
+                        .with_report_fixes(self.regular_linter.options().report_fixes)
+                        .with_cache(self.lint_service_options.cache().cloned()),
+                ),
                 Err(e) => return Err(e),
             }
         } else {
@@ -187,6 +198,8 @@ impl LintRunnerBuilder {
         };
 
         let cwd = self.lint_service_options.cwd().to_path_buf();
+        let timings = self.regular_linter.timings().cloned();
+        let cache = self.lint_service_options.cache().cloned();
         let mut lint_service = LintService::new(self.regular_linter, self.lint_service_options);
         lint_service.set_disable_directives_map(directives_coordinator.map());
 
@@ -195,6 +208,8 @@ impl LintRunnerBuilder {
             type_aware_linter,
             directives_store: directives_coordinator,
             cwd,
+            timings,
+            cache,
         })
     }
 }
@@ -208,6 +223,9 @@ impl LintRunner {
     /// Run both regular and type-aware linting on files
     /// # Errors
     /// Returns an error if type-aware linting fails.
+    ///
+    /// # Panics
+    /// Panics if the disable directives mutex is poisoned.
     pub fn lint_files(
         mut self,
         files: &[Arc<OsStr>],
@@ -222,7 +240,26 @@ impl LintRunner {
         self.lint_service.run(fs, files.to_owned(), &tx_error);
 
         if let Some(type_aware_linter) = self.type_aware_linter.take() {
-            type_aware_linter.lint(files, self.directives_store.map(), tx_error, fs)?;
+            // Results restored from the cache include the type-aware diagnostics.
+            let files = match &self.cache {
+                Some(cache) => files
+                    .iter()
+                    .filter(|path| !cache.is_restored(Path::new(path)))
+                    .cloned()
+                    .collect::<Vec<_>>(),
+                None => files.to_vec(),
+            };
+            let lint = || type_aware_linter.lint(&files, self.directives_store.map(), tx_error, fs);
+            match &self.timings {
+                Some(timings) => timings.time(RuleTimings::TSGOLINT, lint)?,
+                None => lint()?,
+            }
+            if let Some(cache) = &self.cache {
+                let map = self.directives_store.map();
+                cache.update_disable_directives(
+                    &map.lock().expect("DirectivesStore mutex poisoned in lint_files"),
+                );
+            }
         } else {
             drop(tx_error);
         }
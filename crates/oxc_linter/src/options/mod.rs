@@ -13,4 +13,7 @@ pub struct LintOptions {
     pub fix: FixKind,
     pub framework_hints: FrameworkFlags,
     pub report_unused_directive: Option<AllowWarnDeny>,
+    pub report_suppressed: bool,
+    pub report_fixes: bool,
+    pub no_inline_config: bool,
 }
@@ -0,0 +1,115 @@
+use std::{
+    fmt::{self, Display},
+    path::Path,
+};
+
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+
+use oxc_diagnostics::{DiagnosticService, Error, Severity};
+
+use crate::{Fix, Message, PossibleFixes};
+
+/// A [`Message`] converted into an [`Error`], which keeps its fixes and whether it is
+/// suppressed by a disable directive, for reporters which output them.
+///
+/// Reporters get it back from an [`Error`] with [`ReportedMessage::from_error`].
+///
+/// Suppressed messages have a severity of [`Severity::Advice`],
+/// so they are not counted as warnings or errors.
+#[derive(Debug)]
+pub struct ReportedMessage {
+    error: Error,
+    severity: Severity,
+    fixes: PossibleFixes,
+    suppressed: bool,
+}
+
+impl ReportedMessage {
+    /// Wrap [messages] with the source code and path, converting them into [`Error`]s.
+    ///
+    /// Same as [`DiagnosticService::wrap_diagnostics`], but for [messages].
+    ///
+    /// [messages]: Message
+    pub fn wrap_messages<C: AsRef<Path>, P: AsRef<Path>>(
+        cwd: C,
+        path: P,
+        source_text: &str,
+        messages: Vec<Message>,
+    ) -> Vec<Error> {
+        let (diagnostics, rest): (Vec<_>, Vec<_>) = messages
+            .into_iter()
+            .map(|message| {
+                let severity = message.error.severity;
+                (message.error, (severity, message.fixes, message.suppressed))
+            })
+            .unzip();
+        DiagnosticService::wrap_diagnostics(cwd, path, source_text, diagnostics)
+            .into_iter()
+            .zip(rest)
+            .map(|(error, (severity, fixes, suppressed))| {
+                Error::new(Self { error, severity, fixes, suppressed })
+            })
+            .collect()
+    }
+
+    /// Get the [`ReportedMessage`] an [`Error`] was created from by
+    /// [`ReportedMessage::wrap_messages`].
+    ///
+    /// Returns [`None`] for any other [`Error`].
+    pub fn from_error(error: &Error) -> Option<&Self> {
+        error.downcast_ref::<Self>()
+    }
+
+    /// Severity of the message, which is kept even if it is suppressed.
+    pub fn message_severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Suggested fixes for the message. Each fix is an alternative to the others.
+    pub fn fixes(&self) -> &[Fix] {
+        match &self.fixes {
+            PossibleFixes::None => &[],
+            PossibleFixes::Single(fix) => std::slice::from_ref(fix),
+            PossibleFixes::Multiple(fixes) => fixes,
+        }
+    }
+
+    /// Whether the message is suppressed by a disable directive.
+    pub fn is_suppressed(&self) -> bool {
+        self.suppressed
+    }
+}
+
+impl Display for ReportedMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for ReportedMessage {}
+
+impl Diagnostic for ReportedMessage {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        if self.suppressed { Some(Severity::Advice) } else { self.error.severity() }
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.error.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.error.labels()
+    }
+}
@@ -1224,9 +1224,9 @@ fn test() {
             Some(json!([{ "varsIgnorePattern": "^_" }])),
         ),
         // https://github.com/typescript-eslint/typescript-eslint/issues/2844
+        // `collect-unused-vars` is a test rule of typescript-eslint, and is not configured here.
         (
-            r#"
-        /* eslint collect-unused-vars: "error" */
+            "
         declare module 'next-auth' {
           interface User {
             id: string;
@@ -1234,7 +1234,7 @@ fn test() {
             familyName: string;
           }
         }
-            "#,
+            ",
             None,
         ),
         // https://github.com/typescript-eslint/typescript-eslint/issues/2972
@@ -8,7 +8,7 @@ use rustc_hash::FxHashMap;
 
 use oxc_diagnostics::DiagnosticSender;
 
-use crate::Linter;
+use crate::{LintCache, Linter};
 
 mod runtime;
 use runtime::Runtime;
@@ -21,6 +21,9 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Cache of lint results from previous runs
+    cache: Option<Arc<LintCache>>,
 }
 
 impl LintServiceOptions {
@@ -29,7 +32,7 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), tsconfig: None, cross_module: false }
+        Self { cwd: cwd.into(), tsconfig: None, cross_module: false, cache: None }
     }
 
     #[inline]
@@ -54,10 +57,22 @@ impl LintServiceOptions {
         self
     }
 
+    #[inline]
+    #[must_use]
+    pub fn with_cache(mut self, cache: Arc<LintCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
     }
+
+    #[inline]
+    pub fn cache(&self) -> Option<&Arc<LintCache>> {
+        self.cache.as_ref()
+    }
 }
 
 pub struct LintService {
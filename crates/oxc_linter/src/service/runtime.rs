@@ -12,7 +12,7 @@ use indexmap::IndexSet;
 use rayon::iter::ParallelDrainRange;
 use rayon::{
     Scope,
-    iter::IntoParallelRefIterator,
+    iter::{IntoParallelIterator, IntoParallelRefIterator},
     prelude::{ParallelIterator, ParallelSliceMut},
 };
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet, FxHasher};
@@ -27,7 +27,8 @@ use oxc_semantic::{Semantic, SemanticBuilder};
 use oxc_span::{CompactStr, SourceType, VALID_EXTENSIONS};
 
 use crate::{
-    Fixer, Linter, Message, PossibleFixes,
+    Fixer, LintCache, Linter, Message, PossibleFixes, ReportedMessage,
+    cache::{CachedLintResult, hash_source},
     context::ContextSubHost,
     disable_directives::DisableDirectives,
     loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
@@ -64,6 +65,8 @@ pub struct Runtime {
     modules_by_path: ModulesByPath,
     /// Collected disable directives from linted files
     disable_directives_map: Arc<Mutex<FxHashMap<PathBuf, DisableDirectives>>>,
+    /// Cache of lint results from previous runs
+    cache: Option<Arc<LintCache>>,
 }
 
 /// Output of `Runtime::process_path`
@@ -92,6 +95,9 @@ struct ProcessedModule<'alloc_pool> {
     /// Note that `content` is `Some` even if parsing is unsuccessful as long as the source to lint is valid utf-8.
     /// It is designed this way to cover the case where some but not all the sections fail to parse.
     content: Option<ModuleContent<'alloc_pool>>,
+
+    /// Hash of the source text. Only computed when the lint cache is enabled.
+    source_hash: Option<String>,
 }
 
 struct ResolvedModuleRequest {
@@ -258,6 +264,7 @@ impl Runtime {
                 .resize_mode(papaya::ResizeMode::Blocking)
                 .build(),
             disable_directives_map: Arc::new(Mutex::new(FxHashMap::default())),
+            cache: options.cache,
         }
     }
 
@@ -354,8 +361,9 @@ impl Runtime {
     ) {
         if self.resolver.is_none() {
             paths.par_iter().for_each(|path| {
-                let output =
+                let mut output =
                     self.process_path(file_system, paths, path, check_syntax_errors, tx_error);
+                self.insert_cached_module(&output.path, &mut output.processed_module);
                 let Some(entry) =
                     ModuleToLint::from_processed_module(output.path, output.processed_module)
                 else {
@@ -503,6 +511,8 @@ impl Runtime {
                     }
                 }
 
+                self.insert_cached_module(&path, &mut processed_module);
+
                 // Populate this module to `modules_by_path`
                 self.modules_by_path.pin().insert(
                     Arc::clone(&path),
@@ -582,6 +592,14 @@ impl Runtime {
         paths: Vec<Arc<OsStr>>,
         tx_error: &DiagnosticSender,
     ) {
+        let (cached, paths) = match &self.cache {
+            Some(cache) => cache.partition(&self.linter, paths),
+            None => (vec![], paths),
+        };
+        cached.into_par_iter().for_each(|(path, result)| {
+            self.report_cached_result(file_system, Path::new(&path), result, tx_error);
+        });
+
         self.modules_by_path.pin().reserve(paths.len());
         let paths_set: IndexSet<Arc<OsStr>, FxBuildHasher> = paths.into_iter().collect();
 
@@ -594,11 +612,6 @@ impl Runtime {
                 Some(tx_error),
                 move |me, mut module_to_lint| {
                     module_to_lint.content.with_dependent_mut(|allocator_guard, dep| {
-                        // If there are fixes, we will accumulate all of them and write to the file at the end.
-                        // This means we do not write multiple times to the same file if there are multiple sources
-                        // in the same file (for example, multiple scripts in an `.astro` file).
-                        let mut new_source_text = Cow::from(dep.source_text);
-
                         let path = Path::new(&module_to_lint.path);
 
                         assert_eq!(
@@ -606,6 +619,11 @@ impl Runtime {
                             dep.section_contents.len()
                         );
 
+                        // Results of modules with parse errors are not cached,
+                        // as the parse errors are reported separately.
+                        let is_cacheable =
+                            module_to_lint.section_module_records.iter().all(Result::is_ok);
+
                         let context_sub_hosts: Vec<ContextSubHost<'_>> = module_to_lint
                             .section_module_records
                             .into_iter()
@@ -638,13 +656,21 @@ impl Runtime {
                             return;
                         }
 
-                        let (mut messages, disable_directives) =
-                            me.linter.run_with_disable_directives(
+                        let (messages, disable_directives) = me.linter.run_with_disable_directives(
+                            path,
+                            context_sub_hosts,
+                            allocator_guard,
+                            me.js_allocator_pool(),
+                        );
+
+                        if is_cacheable && let Some(cache) = &me.cache {
+                            cache.insert_result(
+                                &me.linter,
                                 path,
-                                context_sub_hosts,
-                                allocator_guard,
-                                me.js_allocator_pool(),
+                                &messages,
+                                disable_directives.as_ref(),
                             );
+                        }
 
                         // Store the disable directives for this file
                         if let Some(disable_directives) = disable_directives {
@@ -654,48 +680,112 @@ impl Runtime {
                                 .insert(path.to_path_buf(), disable_directives);
                         }
 
-                        if me.linter.options().fix.is_some() {
-                            let fix_result = Fixer::new(
-                                dep.source_text,
-                                messages,
-                                SourceType::from_path(path).ok().map(|st| {
-                                    if st.is_javascript() { st.with_jsx(true) } else { st }
-                                }),
-                            )
-                            .fix();
-                            if fix_result.fixed {
-                                // write to file, replacing only the changed part
-                                let start = 0;
-                                let end = start + dep.source_text.len();
-                                new_source_text
-                                    .to_mut()
-                                    .replace_range(start..end, &fix_result.fixed_code);
-                            }
-                            messages = fix_result.messages;
-                        }
-
-                        if !messages.is_empty() {
-                            let errors = messages.into_iter().map(Into::into).collect();
-                            let diagnostics = DiagnosticService::wrap_diagnostics(
-                                &me.cwd,
-                                path,
-                                dep.source_text,
-                                errors,
-                            );
-                            tx_error.send(diagnostics).unwrap();
-                        }
-
-                        // If the new source text is owned, that means it was modified,
-                        // so we write the new source text to the file.
-                        if let Cow::Owned(new_source_text) = &new_source_text {
-                            file_system.write_file(path, new_source_text).unwrap();
-                        }
+                        me.report_messages(file_system, path, dep.source_text, messages, tx_error);
                     });
                 },
             );
         });
     }
 
+    /// Report a lint result restored from the cache, as if the file was linted again.
+    fn report_cached_result(
+        &self,
+        file_system: &(dyn RuntimeFileSystem + Sync + Send),
+        path: &Path,
+        result: CachedLintResult,
+        tx_error: &DiagnosticSender,
+    ) {
+        let allocator_guard = self.allocator_pool.get();
+        let source_text = match file_system.read_to_arena_str(path, &allocator_guard) {
+            Ok(source_text) => source_text,
+            Err(e) => {
+                let error = Error::new(OxcDiagnostic::error(format!(
+                    "Failed to open file {} with error \"{e}\"",
+                    path.display()
+                )));
+                tx_error.send(vec![error]).unwrap();
+                return;
+            }
+        };
+
+        let (messages, disable_directives) = result.into_parts();
+
+        if let Some(disable_directives) = disable_directives {
+            self.disable_directives_map
+                .lock()
+                .expect("disable_directives_map mutex poisoned")
+                .insert(path.to_path_buf(), disable_directives);
+        }
+
+        self.report_messages(file_system, path, source_text, messages, tx_error);
+    }
+
+    /// Apply fixes (if enabled) and send the remaining diagnostics of a linted file.
+    fn report_messages(
+        &self,
+        file_system: &(dyn RuntimeFileSystem + Sync + Send),
+        path: &Path,
+        source_text: &str,
+        mut messages: Vec<Message>,
+        tx_error: &DiagnosticSender,
+    ) {
+        // If there are fixes, we will accumulate all of them and write to the file at the end.
+        // This means we do not write multiple times to the same file if there are multiple sources
+        // in the same file (for example, multiple scripts in an `.astro` file).
+        let mut new_source_text = Cow::from(source_text);
+
+        if self.linter.options().fix.is_some() {
+            let fix_result = Fixer::new(
+                source_text,
+                messages,
+                SourceType::from_path(path)
+                    .ok()
+                    .map(|st| if st.is_javascript() { st.with_jsx(true) } else { st }),
+            )
+            .fix();
+            if fix_result.fixed {
+                // write to file, replacing only the changed part
+                let start = 0;
+                let end = start + source_text.len();
+                new_source_text.to_mut().replace_range(start..end, &fix_result.fixed_code);
+            }
+            messages = fix_result.messages;
+        }
+
+        if !messages.is_empty() {
+            let options = self.linter.options();
+            let diagnostics = if options.report_fixes || options.report_suppressed {
+                ReportedMessage::wrap_messages(&self.cwd, path, source_text, messages)
+            } else {
+                let errors = messages.into_iter().map(Into::into).collect();
+                DiagnosticService::wrap_diagnostics(&self.cwd, path, source_text, errors)
+            };
+            tx_error.send(diagnostics).unwrap();
+        }
+
+        // If the new source text is owned, that means it was modified,
+        // so we write the new source text to the file.
+        if let Cow::Owned(new_source_text) = &new_source_text {
+            file_system.write_file(path, new_source_text).unwrap();
+        }
+    }
+
+    /// Record a processed module in the lint cache, if the cache is enabled.
+    fn insert_cached_module(&self, path: &OsStr, processed_module: &mut ProcessedModule) {
+        let (Some(cache), Some(source_hash)) = (&self.cache, processed_module.source_hash.take())
+        else {
+            return;
+        };
+        let dependencies = processed_module
+            .section_module_records
+            .iter()
+            .filter_map(|record_result| record_result.as_ref().ok())
+            .flat_map(|record| &record.resolved_module_requests)
+            .map(|request| PathBuf::from(&request.resolved_requested_path))
+            .collect();
+        cache.insert_module(Path::new(path), source_hash, dependencies);
+    }
+
     // language_server: the language server needs line and character position
     // the struct not using `oxc_diagnostic::Error, because we are just collecting information
     // and returning it to the client to let him display it.
@@ -881,6 +971,7 @@ impl Runtime {
         if paths.contains(path) {
             let mut records =
                 SmallVec::<[Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1]>::new();
+            let mut source_hash = None;
 
             let module_content = ModuleContent::try_new(allocator_guard, |allocator_guard| {
                 let allocator = &**allocator_guard;
@@ -901,6 +992,10 @@ impl Runtime {
                     }
                 };
 
+                if self.cache.is_some() {
+                    source_hash = Some(hash_source(source_text.as_bytes()));
+                }
+
                 let mut section_contents = SmallVec::new();
                 records = self.process_source(
                     Path::new(path),
@@ -916,7 +1011,11 @@ impl Runtime {
             });
             let module_content = module_content.ok()?;
 
-            Some(ProcessedModule { section_module_records: records, content: Some(module_content) })
+            Some(ProcessedModule {
+                section_module_records: records,
+                content: Some(module_content),
+                source_hash,
+            })
         } else {
             let allocator = &*allocator_guard;
 
@@ -941,8 +1040,9 @@ impl Runtime {
                 allocator,
                 None,
             );
+            let source_hash = self.cache.is_some().then(|| hash_source(source_text.as_bytes()));
 
-            Some(ProcessedModule { section_module_records: records, content: None })
+            Some(ProcessedModule { section_module_records: records, content: None, source_hash })
         }
     }
 
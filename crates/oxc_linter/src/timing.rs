@@ -0,0 +1,114 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use rustc_hash::FxHashMap;
+
+/// Wall time spent in each rule, accumulated across all linted files and threads.
+///
+/// Enabled with [`Linter::with_timings`](crate::Linter::with_timings).
+/// Rules are keyed by `plugin/rule`, e.g. `eslint/no-debugger`.
+///
+/// Rules which run together in a single call are reported as one entry:
+/// all JS plugin rules as [`RuleTimings::JS_PLUGINS`],
+/// and all type-aware rules as [`RuleTimings::TSGOLINT`].
+#[derive(Debug, Default)]
+pub struct RuleTimings {
+    timings: Mutex<FxHashMap<String, Duration>>,
+}
+
+/// Time spent in a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleTiming {
+    /// `plugin/rule`
+    pub rule: String,
+    pub duration: Duration,
+}
+
+impl RuleTimings {
+    /// Entry for the time spent running JS plugin rules.
+    pub const JS_PLUGINS: &'static str = "(js plugins)";
+    /// Entry for the time spent running type-aware rules in `tsgolint`.
+    pub const TSGOLINT: &'static str = "(tsgolint)";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the timings of a single file.
+    ///
+    /// Timings are collected per file first, so the lock is only taken once per file.
+    ///
+    /// # Panics
+    /// Panics if the mutex is poisoned.
+    pub fn extend<I: IntoIterator<Item = (String, Duration)>>(&self, timings: I) {
+        let mut map = self.timings.lock().expect("RuleTimings mutex poisoned");
+        for (rule, duration) in timings {
+            *map.entry(rule).or_default() += duration;
+        }
+    }
+
+    /// Adds `duration` to `rule`.
+    pub fn add(&self, rule: &str, duration: Duration) {
+        self.extend([(rule.to_string(), duration)]);
+    }
+
+    /// Runs `f`, adding the time it took to `rule`.
+    pub fn time<T>(&self, rule: &str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = f();
+        self.add(rule, start.elapsed());
+        result
+    }
+
+    /// All timings, slowest first. Rules with equal timings are sorted by name.
+    ///
+    /// # Panics
+    /// Panics if the mutex is poisoned.
+    pub fn sorted(&self) -> Vec<RuleTiming> {
+        let map = self.timings.lock().expect("RuleTimings mutex poisoned");
+        let mut timings = map
+            .iter()
+            .map(|(rule, duration)| RuleTiming { rule: rule.clone(), duration: *duration })
+            .collect::<Vec<_>>();
+        timings.sort_unstable_by(|a, b| b.duration.cmp(&a.duration).then(a.rule.cmp(&b.rule)));
+        timings
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{RuleTiming, RuleTimings};
+
+    #[test]
+    fn test_sorted() {
+        let timings = RuleTimings::new();
+        timings.extend([
+            ("eslint/no-debugger".to_string(), Duration::from_millis(2)),
+            ("eslint/no-console".to_string(), Duration::from_millis(5)),
+        ]);
+        timings.add("eslint/no-debugger", Duration::from_millis(3));
+        timings.add("eslint/eqeqeq", Duration::from_millis(1));
+
+        assert_eq!(
+            timings.sorted(),
+            vec![
+                RuleTiming {
+                    rule: "eslint/no-console".to_string(),
+                    duration: Duration::from_millis(5)
+                },
+                RuleTiming {
+                    rule: "eslint/no-debugger".to_string(),
+                    duration: Duration::from_millis(5)
+                },
+                RuleTiming {
+                    rule: "eslint/eqeqeq".to_string(),
+                    duration: Duration::from_millis(1)
+                },
+            ]
+        );
+    }
+}
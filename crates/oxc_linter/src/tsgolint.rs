@@ -15,7 +15,10 @@ use oxc_span::{SourceType, Span};
 
 use super::{AllowWarnDeny, ConfigStore, DisableDirectives, ResolvedLinterState, read_to_string};
 
-use crate::{CompositeFix, FixKind, Fixer, Message, PossibleFixes, WEBSITE_BASE_RULES_URL};
+use crate::{
+    CompositeFix, FixKind, Fixer, LintCache, Message, PossibleFixes, ReportedMessage,
+    WEBSITE_BASE_RULES_URL,
+};
 
 /// State required to initialize the `tsgolint` linter.
 #[derive(Debug, Clone)]
@@ -35,6 +38,12 @@ pub struct TsGoLintState {
     fix_suggestions: bool,
     /// If `true`, include TypeScript compiler syntactic and semantic diagnostics.
     type_check: bool,
+    /// If `true`, report diagnostics suppressed by disable directives instead of discarding them.
+    report_suppressed: bool,
+    /// If `true`, report diagnostics with their fixes, for reporters which output them.
+    report_fixes: bool,
+    /// Cache of lint results, which the reported diagnostics are added to.
+    cache: Option<Arc<LintCache>>,
 }
 
 impl TsGoLintState {
@@ -50,6 +59,9 @@ impl TsGoLintState {
             fix: fix_kind.contains(FixKind::Fix),
             fix_suggestions: fix_kind.contains(FixKind::Suggestion),
             type_check: false,
+            report_suppressed: false,
+            report_fixes: false,
+            cache: None,
         }
     }
 
@@ -72,6 +84,9 @@ impl TsGoLintState {
             fix: fix_kind.contains(FixKind::Fix),
             fix_suggestions: fix_kind.contains(FixKind::Suggestion),
             type_check: false,
+            report_suppressed: false,
+            report_fixes: false,
+            cache: None,
         })
     }
 
@@ -94,6 +109,34 @@ impl TsGoLintState {
         self
     }
 
+    /// Set to `true` to report diagnostics suppressed by disable directives, marked as suppressed.
+    ///
+    /// Default is `false`.
+    #[must_use]
+    pub fn with_report_suppressed(mut self, yes: bool) -> Self {
+        self.report_suppressed = yes;
+        self
+    }
+
+    /// Set to `true` to report diagnostics with their fixes, for reporters which output them.
+    ///
+    /// Default is `false`.
+    #[must_use]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.report_fixes = yes;
+        self
+    }
+
+    /// Set the cache of lint results, to store the reported diagnostics with the results of the
+    /// linted files.
+    ///
+    /// Default is `None`.
+    #[must_use]
+    pub fn with_cache(mut self, cache: Option<Arc<LintCache>>) -> Self {
+        self.cache = cache;
+        self
+    }
+
     /// # Panics
     /// - when `stdin` of subprocess cannot be opened
     /// - when `stdout` of subprocess cannot be opened
@@ -120,6 +163,7 @@ impl TsGoLintState {
         }
 
         let should_fix = self.fix || self.fix_suggestions;
+        let report_fixes = self.report_fixes;
         let cwd = self.cwd.clone();
         let sender_for_fixes = error_sender.clone();
 
@@ -140,7 +184,9 @@ impl TsGoLintState {
                         self.silent,
                         should_fix,
                         error_sender,
-                    );
+                    )
+                    .with_report_fixes(self.report_fixes)
+                    .with_cache(self.cache.clone());
 
                     let msg_iter = TsGoLintMessageStream::new(stdout);
 
@@ -184,6 +230,12 @@ impl TsGoLintState {
                                             path,
                                             &tsgolint_diagnostic,
                                         ) {
+                                            if self.report_suppressed {
+                                                diagnostic_handler.send_suppressed_diagnostic(
+                                                    tsgolint_diagnostic,
+                                                    severity,
+                                                );
+                                            }
                                             continue;
                                         }
 
@@ -243,12 +295,21 @@ impl TsGoLintState {
                     if !fix_result.messages.is_empty() {
                         let source_for_diagnostics: &str =
                             if fix_result.fixed { &fix_result.fixed_code } else { &source_text };
-                        let diagnostics = DiagnosticService::wrap_diagnostics(
-                            &cwd,
-                            &path,
-                            source_for_diagnostics,
-                            fix_result.messages.into_iter().map(Into::into).collect(),
-                        );
+                        let diagnostics = if report_fixes {
+                            ReportedMessage::wrap_messages(
+                                &cwd,
+                                &path,
+                                source_for_diagnostics,
+                                fix_result.messages,
+                            )
+                        } else {
+                            DiagnosticService::wrap_diagnostics(
+                                &cwd,
+                                &path,
+                                source_for_diagnostics,
+                                fix_result.messages.into_iter().map(Into::into).collect(),
+                            )
+                        };
                         sender_for_fixes.send(diagnostics).expect("Failed to send diagnostics");
                     }
                 }
@@ -940,6 +1001,10 @@ struct DiagnosticHandler {
     error_sender: DiagnosticSender,
     /// Messages requiring fixes, grouped by file path: messages.
     messages_requiring_fixes: FxHashMap<PathBuf, Vec<Message>>,
+    /// Report messages with their fixes, for reporters which output them.
+    report_fixes: bool,
+    /// Cache of lint results, which reported messages are added to.
+    cache: Option<Arc<LintCache>>,
 }
 
 impl DiagnosticHandler {
@@ -951,15 +1016,32 @@ impl DiagnosticHandler {
             source_text_cache: SourceTextCache::default(),
             error_sender,
             messages_requiring_fixes: FxHashMap::default(),
+            report_fixes: false,
+            cache: None,
         }
     }
 
+    fn with_report_fixes(mut self, report_fixes: bool) -> Self {
+        self.report_fixes = report_fixes;
+        self
+    }
+
+    fn with_cache(mut self, cache: Option<Arc<LintCache>>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Whether the source text is needed, to print diagnostics, or to apply, report or cache fixes.
+    fn needs_source_text(&self) -> bool {
+        !self.silent || self.should_fix || self.report_fixes || self.cache.is_some()
+    }
+
     fn get_source_text(&mut self, path: &Path) -> &str {
-        if self.silent && !self.should_fix {
+        if self.needs_source_text() {
+            self.source_text_cache.get_or_insert(path)
+        } else {
             // The source text is not needed in silent mode, the diagnostic isn't printed.
             ""
-        } else {
-            self.source_text_cache.get_or_insert(path)
         }
     }
 
@@ -974,14 +1056,16 @@ impl DiagnosticHandler {
 
         if has_fixes {
             // Collect for later fix application
-            let mut message =
-                Message::from_tsgo_lint_diagnostic(diagnostic, self.get_source_text(&path));
-            message.error.severity =
-                if severity == AllowWarnDeny::Deny { Severity::Error } else { Severity::Warning };
+            let message = self.create_message(diagnostic, severity);
+            self.insert_cached_message(&path, &message);
 
             let entry = self.messages_requiring_fixes.entry(path).or_default();
 
             entry.push(message);
+        } else if self.report_fixes || self.cache.is_some() {
+            let message = self.create_message(diagnostic, severity);
+            self.insert_cached_message(&path, &message);
+            self.send_message(&path, message);
         } else {
             // Stream immediately
             self.send_diagnostic(&path, diagnostic.into(), severity);
@@ -993,6 +1077,10 @@ impl DiagnosticHandler {
         let oxc_diagnostic: OxcDiagnostic = e.into();
 
         let diagnostics = if let Some(ref file_path) = file_path {
+            self.insert_cached_message(
+                file_path,
+                &Message::new(oxc_diagnostic.clone(), PossibleFixes::None),
+            );
             let source_text = self.get_source_text(file_path).to_string();
             DiagnosticService::wrap_diagnostics(
                 &self.cwd,
@@ -1028,15 +1116,63 @@ impl DiagnosticHandler {
         self.error_sender.send(diagnostics).expect("Failed to send diagnostics");
     }
 
+    /// Send a message, with its fixes if they are reported.
+    fn send_message(&mut self, path: &Path, message: Message) {
+        let source_text = self.get_source_text(path).to_string();
+        let diagnostics = if self.report_fixes {
+            ReportedMessage::wrap_messages(&self.cwd, path, &source_text, vec![message])
+        } else {
+            DiagnosticService::wrap_diagnostics(&self.cwd, path, &source_text, vec![message.into()])
+        };
+        self.error_sender.send(diagnostics).expect("Failed to send diagnostics");
+    }
+
+    /// Send a diagnostic suppressed by a disable directive, for reporters which output them.
+    fn send_suppressed_diagnostic(
+        &mut self,
+        diagnostic: TsGoLintRuleDiagnostic,
+        severity: AllowWarnDeny,
+    ) {
+        let path = diagnostic.file_path.clone();
+        let message = self.create_message(diagnostic, severity).with_suppressed(true);
+        self.insert_cached_message(&path, &message);
+        let source_text = self.get_source_text(&path).to_string();
+        let diagnostics =
+            ReportedMessage::wrap_messages(&self.cwd, &path, &source_text, vec![message]);
+        self.error_sender.send(diagnostics).expect("Failed to send diagnostics");
+    }
+
+    /// Convert a diagnostic into a [`Message`] with its fixes, reported with `severity`.
+    fn create_message(
+        &mut self,
+        diagnostic: TsGoLintRuleDiagnostic,
+        severity: AllowWarnDeny,
+    ) -> Message {
+        let path = diagnostic.file_path.clone();
+        let mut message =
+            Message::from_tsgo_lint_diagnostic(diagnostic, self.get_source_text(&path));
+        message.error.severity =
+            if severity == AllowWarnDeny::Deny { Severity::Error } else { Severity::Warning };
+        message
+    }
+
+    /// Add a reported message to the cached lint result of the file, if the cache is enabled.
+    fn insert_cached_message(&self, path: &Path, message: &Message) {
+        if let Some(cache) = &self.cache {
+            cache.insert_type_aware_message(path, message);
+        }
+    }
+
     /// Consume the handler and return collected messages requiring fixes.
     fn into_messages_requiring_fixes(self) -> Vec<(PathBuf, String, Vec<Message>)> {
-        let Self { messages_requiring_fixes, mut source_text_cache, should_fix, silent, .. } = self;
+        let needs_source_text = self.needs_source_text();
+        let Self { messages_requiring_fixes, mut source_text_cache, .. } = self;
 
         messages_requiring_fixes
             .into_iter()
             .map(|(path, messages)| {
                 let source_text = source_text_cache.0.remove(&path).unwrap_or_else(|| {
-                    if !silent || should_fix {
+                    if needs_source_text {
                         read_to_string(&path).unwrap_or_default()
                     } else {
                         String::new()
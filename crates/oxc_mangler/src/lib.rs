@@ -1,4 +1,7 @@
-use std::iter::{self, repeat_with};
+use std::{
+    iter::{self, repeat_with},
+    mem,
+};
 
 use itertools::Itertools;
 use keep_names::collect_name_symbols;
@@ -15,10 +18,12 @@ use oxc_span::{Atom, CompactStr};
 
 pub(crate) mod base54;
 mod keep_names;
+mod properties;
 
 pub use keep_names::MangleOptionsKeepNames;
+pub use properties::{MangleOptionsProperties, NameCache};
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone)]
 pub struct MangleOptions {
     /// Pass true to mangle names declared in the top level scope.
     ///
@@ -33,6 +38,11 @@ pub struct MangleOptions {
     ///
     /// Uses base54 if false.
     pub debug: bool,
+
+    /// Mangle property names.
+    ///
+    /// Default: `None` (property names are not mangled)
+    pub properties: Option<MangleOptionsProperties>,
 }
 
 type Slot = u32;
@@ -62,6 +72,21 @@ pub struct ManglerReturn {
     /// A vector where each element corresponds to a class in declaration order.
     /// Each element is a mapping from original private member names to their mangled names.
     pub class_private_mappings: IndexVec<ClassId, FxHashMap<String, CompactStr>>,
+    /// A mapping from original property names to their mangled names.
+    /// Empty unless [`MangleOptions::properties`] is set.
+    pub property_mappings: FxHashMap<String, CompactStr>,
+    /// The name cache passed to [`Mangler::with_name_cache`], updated with the names mangled in this program.
+    pub name_cache: NameCache,
+}
+
+/// Name mappings returned by [`Mangler::build_with_semantic`].
+pub struct ManglerMappings {
+    /// See [`ManglerReturn::class_private_mappings`].
+    pub class_private_mappings: IndexVec<ClassId, FxHashMap<String, CompactStr>>,
+    /// See [`ManglerReturn::property_mappings`].
+    pub property_mappings: FxHashMap<String, CompactStr>,
+    /// See [`ManglerReturn::name_cache`].
+    pub name_cache: NameCache,
 }
 
 /// # Name Mangler / Symbol Minification
@@ -82,7 +107,7 @@ pub struct ManglerReturn {
 /// assert!(parsed.errors.is_empty());
 ///
 /// let mangled_symbols = Mangler::new()
-///     .with_options(MangleOptions { top_level: true, debug: true })
+///     .with_options(MangleOptions { top_level: true, debug: true, ..MangleOptions::default() })
 ///     .build(&parsed.program);
 ///
 /// let js = Codegen::new().with_symbol_table(mangled_symbols).build(&parsed.program);
@@ -184,6 +209,7 @@ pub struct ManglerReturn {
 /// - slot 3: `bar`
 pub struct Mangler<'t> {
     options: MangleOptions,
+    name_cache: NameCache,
     /// An allocator meant to be used for temporary allocations during mangling.
     /// It can be cleared after mangling is done, to free up memory for subsequent
     /// files or other operations.
@@ -194,6 +220,7 @@ impl Default for Mangler<'_> {
     fn default() -> Self {
         Self {
             options: MangleOptions::default(),
+            name_cache: NameCache::default(),
             temp_allocator: TempAllocator::Owned(Allocator::default()),
         }
     }
@@ -252,6 +279,7 @@ impl<'t> Mangler<'t> {
     pub fn new_with_temp_allocator(temp_allocator: &'t Allocator) -> Self {
         Self {
             options: MangleOptions::default(),
+            name_cache: NameCache::default(),
             temp_allocator: TempAllocator::Borrowed(temp_allocator),
         }
     }
@@ -262,31 +290,50 @@ impl<'t> Mangler<'t> {
         self
     }
 
+    /// Reuse the mangled property names from a previous build,
+    /// so that the same properties get the same names in separately built chunks.
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: NameCache) -> Self {
+        self.name_cache = name_cache;
+        self
+    }
+
     /// Mangles the program. The resulting SymbolTable contains the mangled symbols - `program` is not modified.
     /// Pass the symbol table to oxc_codegen to generate the mangled code.
     #[must_use]
     pub fn build(self, program: &Program<'_>) -> ManglerReturn {
         let mut semantic =
             SemanticBuilder::new().with_scope_tree_child_ids(true).build(program).semantic;
-        let class_private_mappings = self.build_with_semantic(&mut semantic, program);
-        ManglerReturn { scoping: semantic.into_scoping(), class_private_mappings }
+        let ManglerMappings { class_private_mappings, property_mappings, name_cache } =
+            self.build_with_semantic(&mut semantic, program);
+        ManglerReturn {
+            scoping: semantic.into_scoping(),
+            class_private_mappings,
+            property_mappings,
+            name_cache,
+        }
     }
 
     /// # Panics
     ///
     /// Panics if the child_ids does not exist in scope_tree.
     pub fn build_with_semantic(
-        self,
+        mut self,
         semantic: &mut Semantic<'_>,
         program: &Program<'_>,
-    ) -> IndexVec<ClassId, FxHashMap<String, CompactStr>> {
+    ) -> ManglerMappings {
         let class_private_mappings = Self::collect_private_members_from_semantic(semantic);
+        let mut name_cache = mem::take(&mut self.name_cache);
+        let property_mappings =
+            self.options.properties.as_ref().map_or_else(FxHashMap::default, |options| {
+                properties::mangle_properties(options, &mut name_cache, semantic.nodes())
+            });
         if self.options.debug {
             self.build_with_semantic_impl(semantic, program, debug_name);
         } else {
             self.build_with_semantic_impl(semantic, program, base54);
         }
-        class_private_mappings
+        ManglerMappings { class_private_mappings, property_mappings, name_cache }
     }
 
     fn build_with_semantic_impl<const CAPACITY: usize, G: Fn(u32) -> InlineString<CAPACITY, u8>>(
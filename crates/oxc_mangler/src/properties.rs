@@ -0,0 +1,209 @@
+use std::collections::BTreeMap;
+
+use lazy_regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_ast::{AstKind, ast::*};
+use oxc_semantic::AstNodes;
+use oxc_span::CompactStr;
+
+use crate::base54::base54;
+
+/// Property names which are never mangled, because they have special meaning to the runtime.
+const BUILTIN_RESERVED: [&str; 3] = ["__proto__", "constructor", "prototype"];
+
+/// Options for property mangling, similar to terser's `mangle.properties` and esbuild's `mangleProps`.
+///
+/// Property mangling renames properties across the whole program, so it is only safe for properties
+/// which are never accessed by name from outside of the program.
+/// Only properties matching [`MangleOptionsProperties::regex`] are mangled.
+#[derive(Debug, Clone)]
+pub struct MangleOptionsProperties {
+    /// Only mangle property names matching this regular expression.
+    pub regex: Regex,
+
+    /// Property names which are not mangled, even if they match [`MangleOptionsProperties::regex`].
+    pub reserved: Vec<String>,
+
+    /// Mangle quoted property names (`{ "_foo": 1 }` and `obj["_foo"]`) too.
+    ///
+    /// If `false`, quoted property names are kept as is,
+    /// and a name which is quoted anywhere in the program is not mangled anywhere else either.
+    ///
+    /// Default: `false`
+    pub mangle_quoted: bool,
+}
+
+impl MangleOptionsProperties {
+    /// Mangle property names matching `regex`.
+    pub fn new(regex: Regex) -> Self {
+        Self { regex, reserved: Vec::new(), mangle_quoted: false }
+    }
+
+    /// Mangle property names starting with `prefix` (e.g. `_`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the escaped `prefix` is too large to compile as a regular expression.
+    pub fn with_prefix(prefix: &str) -> Self {
+        let regex = Regex::new(&format!("^{}", lazy_regex::regex::escape(prefix)))
+            .expect("escaped prefix is a valid regular expression");
+        Self::new(regex)
+    }
+
+    fn is_reserved(&self, name: &str) -> bool {
+        BUILTIN_RESERVED.contains(&name) || self.reserved.iter().any(|reserved| reserved == name)
+    }
+}
+
+/// Mangled names which persist across builds.
+///
+/// Pass the cache returned by one build to the next one with [`crate::Mangler::with_name_cache`],
+/// so separately built chunks use the same mangled names for the same properties.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct NameCache {
+    /// Mangled property names, keyed by original name.
+    pub properties: BTreeMap<String, CompactStr>,
+}
+
+#[derive(Default)]
+struct PropertyName {
+    /// Number of occurrences in the program
+    frequency: usize,
+    /// Order of first occurrence in the program
+    order: usize,
+}
+
+/// Names of properties in a program.
+#[derive(Default)]
+struct PropertyNames<'a> {
+    /// Property names which may be mangled
+    candidates: FxHashMap<&'a str, PropertyName>,
+    /// Property names which must be kept as is
+    kept: FxHashSet<&'a str>,
+    /// Number of property names seen so far
+    count: usize,
+}
+
+impl<'a> PropertyNames<'a> {
+    fn collect(options: &MangleOptionsProperties, nodes: &AstNodes<'a>) -> Self {
+        let mut names = Self::default();
+        for node in nodes.iter() {
+            match node.kind() {
+                AstKind::StaticMemberExpression(expr) => {
+                    names.add(options, expr.property.name.as_str(), false);
+                }
+                AstKind::ComputedMemberExpression(expr) => {
+                    if let Expression::StringLiteral(lit) = &expr.expression {
+                        names.add(options, lit.value.as_str(), true);
+                    }
+                }
+                AstKind::ObjectProperty(prop) => names.add_key(options, &prop.key),
+                AstKind::MethodDefinition(def) => names.add_key(options, &def.key),
+                AstKind::PropertyDefinition(def) => names.add_key(options, &def.key),
+                AstKind::AccessorProperty(def) => names.add_key(options, &def.key),
+                AstKind::BindingProperty(prop) => names.add_key(options, &prop.key),
+                AstKind::AssignmentTargetPropertyProperty(prop) => {
+                    names.add_key(options, &prop.name);
+                }
+                AstKind::AssignmentTargetPropertyIdentifier(prop) => {
+                    names.add(options, prop.binding.name.as_str(), false);
+                }
+                // `<obj._foo />` can't be renamed by codegen
+                AstKind::JSXMemberExpression(expr) => {
+                    names.keep(expr.property.name.as_str());
+                }
+                _ => {}
+            }
+        }
+        names
+    }
+
+    fn add_key(&mut self, options: &MangleOptionsProperties, key: &PropertyKey<'a>) {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => self.add(options, ident.name.as_str(), false),
+            PropertyKey::StringLiteral(lit) => self.add(options, lit.value.as_str(), true),
+            _ => {}
+        }
+    }
+
+    fn add(&mut self, options: &MangleOptionsProperties, name: &'a str, quoted: bool) {
+        if (quoted && !options.mangle_quoted)
+            || options.is_reserved(name)
+            || !options.regex.is_match(name)
+        {
+            self.keep(name);
+            return;
+        }
+        if self.kept.contains(name) {
+            return;
+        }
+        let order = self.count;
+        self.count += 1;
+        self.candidates
+            .entry(name)
+            .or_insert_with(|| PropertyName { frequency: 0, order })
+            .frequency += 1;
+    }
+
+    fn keep(&mut self, name: &'a str) {
+        self.candidates.remove(name);
+        self.kept.insert(name);
+    }
+}
+
+/// Assign mangled names to the properties in the program matching `options`.
+///
+/// Names already in `name_cache` are reused, unless they collide with a property name which is kept as is.
+/// New names are added to `name_cache`.
+/// Returns the mapping from original property names to mangled names for this program.
+pub fn mangle_properties(
+    options: &MangleOptionsProperties,
+    name_cache: &mut NameCache,
+    nodes: &AstNodes<'_>,
+) -> FxHashMap<String, CompactStr> {
+    let PropertyNames { candidates, kept, .. } = PropertyNames::collect(options, nodes);
+
+    // The most frequent properties get the shortest names.
+    let mut candidates = candidates.into_iter().collect::<Vec<_>>();
+    candidates.sort_unstable_by(|(_, a), (_, b)| {
+        b.frequency.cmp(&a.frequency).then(a.order.cmp(&b.order))
+    });
+
+    // Names generated here are never generated twice, as `count` only increases.
+    let used_names =
+        name_cache.properties.values().map(CompactStr::as_str).collect::<FxHashSet<_>>();
+    let mut new_names = Vec::new();
+    let mut count = 0;
+    let mut mappings = FxHashMap::default();
+    for (name, _) in candidates {
+        // A cached name is dropped if it is a property this program does not mangle,
+        // e.g. `x.a` with `_foo -> a` in the cache, and replaced with a new name below.
+        let mangled = if let Some(mangled) = name_cache.properties.get(name)
+            && !kept.contains(mangled.as_str())
+            && !options.is_reserved(mangled)
+        {
+            mangled.clone()
+        } else {
+            let mangled = loop {
+                let mangled = base54(count);
+                count += 1;
+                let mangled = mangled.as_str();
+                if !kept.contains(mangled)
+                    && !used_names.contains(mangled)
+                    && !options.is_reserved(mangled)
+                {
+                    break CompactStr::new(mangled);
+                }
+            };
+            new_names.push((name, mangled.clone()));
+            mangled
+        };
+        mappings.insert(name.to_string(), mangled);
+    }
+    for (name, mangled) in new_names {
+        name_cache.properties.insert(name.to_string(), mangled);
+    }
+    mappings
+}
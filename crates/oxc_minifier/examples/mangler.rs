@@ -46,12 +46,13 @@ fn main() -> std::io::Result<()> {
         top_level: source_type.is_module(),
         keep_names: MangleOptionsKeepNames { function: keep_names, class: keep_names },
         debug,
+        properties: None,
     };
-    let printed = mangler(&source_text, source_type, options);
+    let printed = mangler(&source_text, source_type, &options);
     println!("{printed}");
 
     if twice {
-        let printed2 = mangler(&printed, source_type, options);
+        let printed2 = mangler(&printed, source_type, &options);
         println!("{printed2}");
         println!("same = {}", printed == printed2);
     }
@@ -59,14 +60,15 @@ fn main() -> std::io::Result<()> {
     Ok(())
 }
 
-fn mangler(source_text: &str, source_type: SourceType, options: MangleOptions) -> String {
+fn mangler(source_text: &str, source_type: SourceType, options: &MangleOptions) -> String {
     let allocator = Allocator::default();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     assert!(ret.errors.is_empty());
-    let mangler_return = Mangler::new().with_options(options).build(&ret.program);
+    let mangler_return = Mangler::new().with_options(options.clone()).build(&ret.program);
     Codegen::new()
         .with_scoping(Some(mangler_return.scoping))
         .with_private_member_mappings(Some(mangler_return.class_private_mappings))
+        .with_property_mappings(Some(mangler_return.property_mappings))
         .build(&ret.program)
         .code
 }
@@ -7,8 +7,18 @@ use crate::{
     CompressOptions,
     peephole::{DeadCodeElimination, Normalize, NormalizeOptions, PeepholeOptimizations},
     state::MinifierState,
+    trace::Rewrite,
 };
 
+#[derive(Default)]
+pub struct CompressorReturn {
+    /// Total number of iterations ran.
+    pub iterations: u8,
+
+    /// Rewrites recorded when [`CompressOptions::trace`] is enabled.
+    pub trace: Vec<Rewrite>,
+}
+
 pub struct Compressor<'a> {
     allocator: &'a Allocator,
 }
@@ -30,7 +40,19 @@ impl<'a> Compressor<'a> {
         scoping: Scoping,
         options: CompressOptions,
     ) -> u8 {
+        self.build_with_scoping_and_trace(program, scoping, options).iterations
+    }
+
+    /// Same as [`Compressor::build_with_scoping`],
+    /// and also returns the rewrites recorded when [`CompressOptions::trace`] is enabled.
+    pub fn build_with_scoping_and_trace(
+        self,
+        program: &mut Program<'a>,
+        scoping: Scoping,
+        options: CompressOptions,
+    ) -> CompressorReturn {
         let max_iterations = options.max_iterations;
+        let trace = options.trace;
         let state = MinifierState::new(program.source_type, options);
         let mut ctx = ReusableTraverseCtx::new(state, scoping, self.allocator);
         let normalize_options = NormalizeOptions {
@@ -39,7 +61,9 @@ impl<'a> Compressor<'a> {
             remove_unnecessary_use_strict: true,
         };
         Normalize::new(normalize_options).build(program, &mut ctx);
-        PeepholeOptimizations::new(max_iterations).run_in_loop(program, &mut ctx)
+        let mut peephole = PeepholeOptimizations::new(max_iterations, trace);
+        let iterations = peephole.run_in_loop(program, &mut ctx);
+        CompressorReturn { iterations, trace: peephole.into_trace() }
     }
 
     pub fn dead_code_elimination(self, program: &mut Program<'a>, options: CompressOptions) -> u8 {
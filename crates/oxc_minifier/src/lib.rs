@@ -51,6 +51,7 @@ mod options;
 mod peephole;
 mod state;
 mod symbol_value;
+mod trace;
 
 #[cfg(test)]
 mod tester;
@@ -58,15 +59,19 @@ mod tester;
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
 use oxc_index::IndexVec;
-use oxc_mangler::Mangler;
+use oxc_mangler::{Mangler, ManglerMappings};
 use oxc_semantic::{Scoping, SemanticBuilder};
 use oxc_span::CompactStr;
 use oxc_syntax::class::ClassId;
 use rustc_hash::FxHashMap;
 
-pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames};
+pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, MangleOptionsProperties, NameCache};
 
-pub use crate::{compressor::Compressor, options::*};
+pub use crate::{
+    compressor::{Compressor, CompressorReturn},
+    options::*,
+    trace::Rewrite,
+};
 
 #[derive(Debug, Clone)]
 pub struct MinifierOptions {
@@ -87,17 +92,34 @@ pub struct MinifierReturn {
     /// Each element is a mapping from original private member names to their mangled names.
     pub class_private_mappings: Option<IndexVec<ClassId, FxHashMap<String, CompactStr>>>,
 
+    /// A mapping from original property names to their mangled names.
+    pub property_mappings: Option<FxHashMap<String, CompactStr>>,
+
+    /// The name cache passed to [`Minifier::with_name_cache`], updated with the names mangled in this program.
+    pub name_cache: Option<NameCache>,
+
     /// Total number of iterations ran. Useful for debugging performance issues.
     pub iterations: u8,
+
+    /// Rewrites made by the peephole optimizations, recorded when [`CompressOptions::trace`] is enabled.
+    pub trace: Vec<Rewrite>,
 }
 
 pub struct Minifier {
     options: MinifierOptions,
+    name_cache: NameCache,
 }
 
 impl<'a> Minifier {
     pub fn new(options: MinifierOptions) -> Self {
-        Self { options }
+        Self { options, name_cache: NameCache::default() }
+    }
+
+    /// Reuse the mangled names from a previous build. See [`Mangler::with_name_cache`].
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: NameCache) -> Self {
+        self.name_cache = name_cache;
+        self
     }
 
     pub fn minify(self, allocator: &'a Allocator, program: &mut Program<'a>) -> MinifierReturn {
@@ -114,7 +136,7 @@ impl<'a> Minifier {
         allocator: &'a Allocator,
         program: &mut Program<'a>,
     ) -> MinifierReturn {
-        let (stats, iterations) = self
+        let (stats, CompressorReturn { iterations, trace }) = self
             .options
             .compress
             .map(|options| {
@@ -122,34 +144,51 @@ impl<'a> Minifier {
                 let stats = semantic.stats();
                 let scoping = semantic.into_scoping();
                 let compressor = Compressor::new(allocator);
-                let iterations = if dce {
+                let ret = if dce {
                     let options = CompressOptions {
                         target: options.target,
                         treeshake: options.treeshake,
                         ..CompressOptions::dce()
                     };
-                    compressor.dead_code_elimination_with_scoping(program, scoping, options)
+                    let iterations =
+                        compressor.dead_code_elimination_with_scoping(program, scoping, options);
+                    CompressorReturn { iterations, ..CompressorReturn::default() }
                 } else {
-                    compressor.build_with_scoping(program, scoping, options)
+                    compressor.build_with_scoping_and_trace(program, scoping, options)
                 };
-                (stats, iterations)
+                (stats, ret)
             })
             .unwrap_or_default();
-        let (scoping, class_private_mappings) = self
-            .options
-            .mangle
-            .map(|options| {
-                let mut semantic = SemanticBuilder::new()
-                    .with_stats(stats)
-                    .with_scope_tree_child_ids(true)
-                    .build(program)
-                    .semantic;
-                let class_private_mappings = Mangler::default()
-                    .with_options(options)
-                    .build_with_semantic(&mut semantic, program);
-                (semantic.into_scoping(), class_private_mappings)
-            })
-            .map_or((None, None), |(scoping, mappings)| (Some(scoping), Some(mappings)));
-        MinifierReturn { scoping, class_private_mappings, iterations }
+        let name_cache = self.name_cache;
+        let Some((scoping, mappings)) = self.options.mangle.map(|options| {
+            let mut semantic = SemanticBuilder::new()
+                .with_stats(stats)
+                .with_scope_tree_child_ids(true)
+                .build(program)
+                .semantic;
+            let mappings = Mangler::default()
+                .with_options(options)
+                .with_name_cache(name_cache)
+                .build_with_semantic(&mut semantic, program);
+            (semantic.into_scoping(), mappings)
+        }) else {
+            return MinifierReturn {
+                scoping: None,
+                class_private_mappings: None,
+                property_mappings: None,
+                name_cache: None,
+                iterations,
+                trace,
+            };
+        };
+        let ManglerMappings { class_private_mappings, property_mappings, name_cache } = mappings;
+        MinifierReturn {
+            scoping: Some(scoping),
+            class_private_mappings: Some(class_private_mappings),
+            property_mappings: Some(property_mappings),
+            name_cache: Some(name_cache),
+            iterations,
+            trace,
+        }
     }
 }
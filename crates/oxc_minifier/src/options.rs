@@ -10,6 +10,9 @@ pub struct CompressOptions {
     /// Used to determine which ES features are supported by the target engines
     /// and whether transformations can be applied.
     ///
+    /// Use [`EngineTargets::from_browserslist_config`] to share the project's browserslist
+    /// config with the transformer.
+    ///
     /// Default: empty (supports all features)
     pub target: EngineTargets,
 
@@ -35,6 +38,14 @@ pub struct CompressOptions {
     /// Default `true`
     pub sequences: bool,
 
+    /// Inline functions called once, immediately invoked function expressions,
+    /// and small pure functions into their call sites.
+    ///
+    /// `function foo(a) { return a.b } foo(x)` -> `x.b`
+    ///
+    /// Default `false`
+    pub inline_functions: bool,
+
     /// Drop unreferenced functions and variables.
     pub unused: CompressOptionsUnused,
 
@@ -54,6 +65,14 @@ pub struct CompressOptions {
 
     /// Limit the maximum number of iterations for debugging purpose.
     pub max_iterations: Option<u8>,
+
+    /// Record every rewrite made by the peephole optimizations in [`crate::MinifierReturn::trace`].
+    ///
+    /// Each rewrite prints the rewritten node before and after the optimization,
+    /// which is slow. Use it for debugging only.
+    ///
+    /// Default `false`
+    pub trace: bool,
 }
 
 impl Default for CompressOptions {
@@ -71,10 +90,12 @@ impl CompressOptions {
             drop_console: false,
             join_vars: true,
             sequences: true,
+            inline_functions: false,
             unused: CompressOptionsUnused::Remove,
             treeshake: TreeShakeOptions::default(),
             drop_labels: FxHashSet::default(),
             max_iterations: None,
+            trace: false,
         }
     }
 
@@ -86,10 +107,12 @@ impl CompressOptions {
             drop_console: false,
             join_vars: true,
             sequences: true,
+            inline_functions: false,
             unused: CompressOptionsUnused::Keep,
             treeshake: TreeShakeOptions::default(),
             drop_labels: FxHashSet::default(),
             max_iterations: None,
+            trace: false,
         }
     }
 
@@ -101,10 +124,12 @@ impl CompressOptions {
             drop_console: false,
             join_vars: false,
             sequences: false,
+            inline_functions: false,
             unused: CompressOptionsUnused::Remove,
             treeshake: TreeShakeOptions::default(),
             drop_labels: FxHashSet::default(),
             max_iterations: None,
+            trace: false,
         }
     }
 }
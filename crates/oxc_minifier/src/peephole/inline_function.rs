@@ -0,0 +1,747 @@
+use oxc_allocator::{CloneIn, TakeIn, Vec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_ecmascript::{constant_evaluation::IsLiteralValue, side_effects::MayHaveSideEffects};
+use oxc_semantic::{ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::Atom;
+use oxc_traverse::Ancestor;
+
+use crate::{ctx::Ctx, state::InlinableFunction};
+
+use super::{PeepholeOptimizations, ReferencesCounter};
+
+/// Functions called more than once are only inlined when the returned expression
+/// has at most this many nodes ...
+const MAX_SMALL_FUNCTION_EXPRESSIONS: usize = 3;
+
+/// ... and there are at most this many references to the function.
+const MAX_SMALL_FUNCTION_REFERENCES: usize = 3;
+
+/// Function inlining.
+///
+/// * Function declarations called exactly once are inlined into their call site.
+/// * Small pure function declarations are inlined into all their call sites.
+/// * Immediately invoked function expressions are unwrapped.
+///
+/// The function body must consist of expression statements followed by an optional `return`,
+/// and every argument must be safe to move into the function body.
+///
+/// <https://github.com/terser/terser#compress-options> (`inline`)
+impl<'a> PeepholeOptimizations {
+    /// Save function declarations that can be inlined into their call sites.
+    ///
+    /// The function body is saved after it has been minimized, so calls appearing before the
+    /// declaration are inlined in the next iteration.
+    pub fn keep_track_of_inlinable_functions(stmt: &Statement<'a>, ctx: &mut Ctx<'a, '_>) {
+        if !ctx.state.options.inline_functions {
+            return;
+        }
+        let Statement::FunctionDeclaration(f) = stmt else { return };
+        let Some(symbol_id) = f.id.as_ref().and_then(|id| id.symbol_id.get()) else { return };
+        let Some(function) = Self::get_inlinable_function(f, symbol_id, ctx) else {
+            ctx.state.inlinable_functions.remove(&symbol_id);
+            return;
+        };
+        let is_new = ctx.state.inlinable_functions.insert(symbol_id, function).is_none();
+        // Run another iteration for calls before the declaration.
+        if is_new && !ctx.scoping().symbol_is_unused(symbol_id) {
+            ctx.state.changed = true;
+        }
+    }
+
+    fn get_inlinable_function(
+        f: &Function<'a>,
+        symbol_id: SymbolId,
+        ctx: &Ctx<'a, '_>,
+    ) -> Option<InlinableFunction<'a>> {
+        if f.r#async || f.generator || f.declare {
+            return None;
+        }
+        // Skip function declarations in blocks, they have surprising semantics in sloppy mode.
+        if !matches!(ctx.parent(), Ancestor::ProgramBody(_) | Ancestor::FunctionBodyStatements(_)) {
+            return None;
+        }
+        // Top level functions in scripts can be reassigned by other scripts.
+        if Self::keep_top_level_var_in_script_mode(ctx)
+            || ctx.current_scope_flags().contains_direct_eval()
+            || ctx.scoping().symbol_is_mutated(symbol_id)
+        {
+            return None;
+        }
+        let body = f.body.as_ref()?;
+        let params = Self::get_inlinable_params(&f.params, f.scope_id(), ctx)?;
+        if !Self::is_inlinable_body(body) {
+            return None;
+        }
+        let mut checker = InlinableBodyChecker::new(ctx.scoping(), &params, Some(symbol_id));
+        checker.has_own_this = true;
+        checker.allow_nested_scopes = false;
+        checker.visit_statements(&body.statements);
+        if checker.rejected {
+            return None;
+        }
+        let reference_count = ctx.scoping().get_resolved_reference_ids(symbol_id).len();
+        if reference_count > 1 {
+            // Only small pure functions are worth inlining into multiple call sites.
+            let [Statement::ReturnStatement(ret)] = body.statements.as_slice() else { return None };
+            let argument = ret.argument.as_ref()?;
+            if reference_count > MAX_SMALL_FUNCTION_REFERENCES
+                || checker.expression_count > MAX_SMALL_FUNCTION_EXPRESSIONS
+                || argument.may_have_side_effects(ctx)
+            {
+                return None;
+            }
+        }
+        let references = checker.references;
+        Some(InlinableFunction {
+            params,
+            body: body.statements.clone_in_with_semantic_ids(ctx.ast.allocator),
+            references,
+        })
+    }
+
+    /// Returns the symbols of the parameters if they are all plain identifiers
+    /// that are never reassigned.
+    fn get_inlinable_params(
+        params: &FormalParameters<'a>,
+        scope_id: ScopeId,
+        ctx: &Ctx<'a, '_>,
+    ) -> Option<std::vec::Vec<SymbolId>> {
+        if params.rest.is_some() || ctx.scoping().scope_flags(scope_id).contains_direct_eval() {
+            return None;
+        }
+        params
+            .items
+            .iter()
+            .map(|param| {
+                if param.initializer.is_some() {
+                    return None;
+                }
+                let BindingPattern::BindingIdentifier(ident) = &param.pattern else { return None };
+                let symbol_id = ident.symbol_id.get()?;
+                (!ctx.scoping().symbol_is_mutated(symbol_id)).then_some(symbol_id)
+            })
+            .collect()
+    }
+
+    /// Whether the body consists of expression statements followed by an optional `return`.
+    fn is_inlinable_body(body: &FunctionBody<'a>) -> bool {
+        // Directives such as `"use strict"` change the semantics of the body.
+        if !body.directives.is_empty() {
+            return false;
+        }
+        let Some((last, rest)) = body.statements.split_last() else { return true };
+        matches!(last, Statement::ExpressionStatement(_) | Statement::ReturnStatement(_))
+            && rest.iter().all(|stmt| matches!(stmt, Statement::ExpressionStatement(_)))
+    }
+
+    /// Inline a call to a function declaration saved by [Self::keep_track_of_inlinable_functions],
+    /// or unwrap an immediately invoked function expression.
+    ///
+    /// `function foo(a) { return a + 1 } foo(1)` -> `1 + 1`
+    /// `(function(a) { a.foo(); return a.bar })(b)` -> `(b.foo(), b.bar)`
+    pub fn inline_function_call(expr: &mut Expression<'a>, ctx: &mut Ctx<'a, '_>) {
+        if !ctx.state.options.inline_functions {
+            return;
+        }
+        let Expression::CallExpression(call_expr) = expr else { return };
+        if call_expr.optional || ctx.current_scope_flags().contains_direct_eval() {
+            return;
+        }
+        // Names in the inlined body could resolve to properties of the `with` object.
+        let scoping = ctx.scoping();
+        if scoping
+            .scope_ancestors(ctx.current_scope_id())
+            .any(|scope_id| scoping.scope_flags(scope_id).is_with())
+        {
+            return;
+        }
+        let inlined = match &call_expr.callee {
+            Expression::Identifier(_) => Self::inline_function_declaration_call(call_expr, ctx),
+            Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => {
+                Self::inline_iife(call_expr, ctx)
+            }
+            _ => None,
+        };
+        if let Some(inlined) = inlined {
+            *expr = inlined;
+            ctx.state.changed = true;
+        }
+    }
+
+    fn inline_function_declaration_call(
+        call_expr: &mut CallExpression<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        let Expression::Identifier(ident) = &call_expr.callee else { return None };
+        let callee_reference_id = ident.reference_id();
+        let symbol_id = ctx.scoping().get_reference(callee_reference_id).symbol_id()?;
+        let function = ctx.state.inlinable_functions.get(&symbol_id)?;
+        // The body is moved into another scope, all names must still resolve to the same symbols.
+        let current_scope_id = ctx.current_scope_id();
+        if function.references.iter().any(|(name, symbol_id)| {
+            ctx.scoping().find_binding(current_scope_id, name) != *symbol_id
+        }) {
+            return None;
+        }
+        let params = function.params.clone();
+        let args = Self::take_arguments(&params, &mut call_expr.arguments, ctx)?;
+        let function = &ctx.state.inlinable_functions[&symbol_id];
+        let mut body = function.body.clone_in_with_semantic_ids(ctx.ast.allocator);
+        let mut substitute = ParamSubstitute::new(&params, args, true, ctx);
+        substitute.visit_statements(&mut body);
+        let dropped_args = substitute.args;
+        Self::delete_references(dropped_args.iter().flatten(), ctx);
+        ctx.scoping_mut().delete_resolved_reference(symbol_id, callee_reference_id);
+        Some(Self::function_body_to_expression(body, false, call_expr.span, ctx))
+    }
+
+    fn inline_iife(
+        call_expr: &mut CallExpression<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        let (params, body, has_own_this, is_expression) = match &mut call_expr.callee {
+            Expression::FunctionExpression(f) => {
+                if f.r#async || f.generator {
+                    return None;
+                }
+                // `(function foo() { foo })()`
+                if f.id
+                    .as_ref()
+                    .and_then(|id| id.symbol_id.get())
+                    .is_some_and(|symbol_id| !ctx.scoping().symbol_is_unused(symbol_id))
+                {
+                    return None;
+                }
+                let params = Self::get_inlinable_params(&f.params, f.scope_id(), ctx)?;
+                (params, f.body.as_mut()?, true, false)
+            }
+            Expression::ArrowFunctionExpression(f) => {
+                if f.r#async {
+                    return None;
+                }
+                let params = Self::get_inlinable_params(&f.params, f.scope_id(), ctx)?;
+                let is_expression = f.expression;
+                (params, &mut f.body, false, is_expression)
+            }
+            _ => return None,
+        };
+        if !Self::is_inlinable_body(body) {
+            return None;
+        }
+        let mut checker = InlinableBodyChecker::new(ctx.scoping(), &params, None);
+        checker.has_own_this = has_own_this;
+        checker.allow_nested_scopes = true;
+        checker.visit_statements(&body.statements);
+        if checker.rejected {
+            return None;
+        }
+        let child_scope_ids = checker.child_scope_ids;
+        let args = Self::take_arguments(&params, &mut call_expr.arguments, ctx)?;
+        let mut body = body.statements.take_in(ctx.ast);
+        let mut substitute = ParamSubstitute::new(&params, args, false, ctx);
+        substitute.visit_statements(&mut body);
+        let dropped_args = substitute.args;
+        Self::delete_references(dropped_args.iter().flatten(), ctx);
+        // The function scope is removed, its nested scopes now belong to the current scope.
+        let current_scope_id = ctx.current_scope_id();
+        for scope_id in child_scope_ids {
+            ctx.scoping_mut().change_scope_parent_id(scope_id, Some(current_scope_id));
+        }
+        Some(Self::function_body_to_expression(body, is_expression, call_expr.span, ctx))
+    }
+
+    /// Take the arguments of a call if all of them can be substituted for their parameters.
+    ///
+    /// Arguments must not have side effects and their value must not change
+    /// when they are evaluated later, i.e. literals or identifiers of initialized local bindings
+    /// that are never reassigned. Arguments of unused parameters are dropped.
+    fn take_arguments(
+        params: &[SymbolId],
+        arguments: &mut Vec<'a, Argument<'a>>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> Option<std::vec::Vec<Option<Expression<'a>>>> {
+        let can_substitute = arguments.iter().enumerate().all(|(i, arg)| {
+            let Some(arg) = arg.as_expression() else { return false };
+            if let Expression::Identifier(ident) = arg {
+                return match ctx.scoping().get_reference(ident.reference_id()).symbol_id() {
+                    Some(symbol_id) => Self::is_initialized_local_binding(symbol_id, ctx),
+                    None => arg.is_literal_value(false, ctx),
+                };
+            }
+            let Some(&param) = params.get(i) else {
+                // Extra arguments are dropped.
+                return !arg.may_have_side_effects(ctx);
+            };
+            let reference_count = ctx.scoping().get_resolved_reference_ids(param).len();
+            match arg {
+                // Literals are cheap to copy.
+                Expression::BooleanLiteral(_)
+                | Expression::NullLiteral(_)
+                | Expression::NumericLiteral(_) => true,
+                _ => {
+                    reference_count <= 1
+                        && arg.is_literal_value(false, ctx)
+                        && !arg.may_have_side_effects(ctx)
+                }
+            }
+        });
+        if !can_substitute {
+            return None;
+        }
+        let mut args = arguments
+            .iter_mut()
+            .take(params.len())
+            .map(|arg| Some(arg.to_expression_mut().take_in(ctx.ast)))
+            .collect::<std::vec::Vec<_>>();
+        args.resize_with(params.len(), || None);
+        // Extra arguments are dropped.
+        Self::delete_references(
+            arguments.iter().skip(params.len()).map(Argument::to_expression),
+            ctx,
+        );
+        Some(args)
+    }
+
+    /// Delete the references in expressions which are removed from the program,
+    /// so reference counts are correct for the rest of this iteration.
+    fn delete_references<'e>(
+        exprs: impl IntoIterator<Item = &'e Expression<'a>>,
+        ctx: &mut Ctx<'a, '_>,
+    ) where
+        'a: 'e,
+    {
+        let mut counter = ReferencesCounter::default();
+        for expr in exprs {
+            counter.visit_expression(expr);
+        }
+        for reference_id in counter.refs {
+            ctx.scoping_mut().delete_reference(reference_id);
+        }
+    }
+
+    /// Whether reading `symbol_id` always gives the same value and never throws.
+    ///
+    /// * Imports are live bindings, which may change when the inlined body calls other code.
+    /// * `let` and `const` bindings throw until they are initialized, so their declaration must
+    ///   have been visited before the call, in the same function and not in a `switch` case
+    ///   which may be skipped.
+    /// * `var`, parameter, catch and function bindings are initialized when their scope is entered.
+    fn is_initialized_local_binding(symbol_id: SymbolId, ctx: &Ctx<'a, '_>) -> bool {
+        let flags = ctx.scoping().symbol_flags(symbol_id);
+        if flags.is_import() || ctx.scoping().symbol_is_mutated(symbol_id) {
+            return false;
+        }
+        if flags.intersects(SymbolFlags::BlockScopedVariable) {
+            return ctx.state.symbol_values.get_symbol_value(symbol_id).is_some()
+                && Self::is_declared_in_current_function(symbol_id, ctx)
+                && !Self::is_declared_in_enclosing_switch(symbol_id, ctx);
+        }
+        flags.is_function_scoped_declaration() || flags.is_catch_variable() || flags.is_function()
+    }
+
+    /// A function declared after the binding can still be called before it is initialized.
+    fn is_declared_in_current_function(symbol_id: SymbolId, ctx: &Ctx<'a, '_>) -> bool {
+        let scoping = ctx.scoping();
+        let function_scope_id = |scope_id| {
+            scoping.scope_ancestors(scope_id).find(|&scope_id| {
+                let flags = scoping.scope_flags(scope_id);
+                flags.is_var() || flags.is_arrow()
+            })
+        };
+        function_scope_id(scoping.symbol_scope_id(symbol_id))
+            == function_scope_id(ctx.current_scope_id())
+    }
+
+    /// `switch (a) { case 0: let v = 1; case 1: foo(v) }`
+    fn is_declared_in_enclosing_switch(symbol_id: SymbolId, ctx: &Ctx<'a, '_>) -> bool {
+        let symbol_scope_id = ctx.scoping().symbol_scope_id(symbol_id);
+        ctx.ancestors().any(|ancestor| {
+            matches!(ancestor, Ancestor::SwitchStatementCases(switch)
+                if switch.scope_id().get() == Some(symbol_scope_id))
+        })
+    }
+
+    /// `a(); b(); return c` -> `(a(), b(), c)`
+    fn function_body_to_expression(
+        body: Vec<'a, Statement<'a>>,
+        is_expression: bool,
+        span: Span,
+        ctx: &Ctx<'a, '_>,
+    ) -> Expression<'a> {
+        let mut exprs = ctx.ast.vec_with_capacity(body.len() + 1);
+        let mut has_return_value = is_expression;
+        for stmt in body {
+            match stmt {
+                Statement::ExpressionStatement(stmt) => exprs.push(stmt.unbox().expression),
+                Statement::ReturnStatement(stmt) => {
+                    exprs.push(stmt.unbox().argument.unwrap_or_else(|| ctx.ast.void_0(span)));
+                    has_return_value = true;
+                }
+                _ => unreachable!(),
+            }
+        }
+        if !has_return_value {
+            exprs.push(ctx.ast.void_0(span));
+        }
+        if exprs.len() == 1 {
+            exprs.pop().unwrap()
+        } else {
+            ctx.ast.expression_sequence(span, exprs)
+        }
+    }
+}
+
+/// Checks whether a function body can be moved out of its function.
+struct InlinableBodyChecker<'a, 'b> {
+    scoping: &'b Scoping,
+    params: &'b [SymbolId],
+    function_symbol_id: Option<SymbolId>,
+    /// `this`, `arguments`, `super` and `new.target` refer to the function being inlined.
+    has_own_this: bool,
+    /// Nested functions and classes can be moved, but not cloned.
+    allow_nested_scopes: bool,
+    nested_scope_depth: u32,
+    /// Scopes of the functions and classes directly in the body.
+    child_scope_ids: std::vec::Vec<ScopeId>,
+    expression_count: usize,
+    /// References that do not refer to a parameter.
+    references: std::vec::Vec<(Atom<'a>, Option<SymbolId>)>,
+    rejected: bool,
+}
+
+impl<'b> InlinableBodyChecker<'_, 'b> {
+    fn new(
+        scoping: &'b Scoping,
+        params: &'b [SymbolId],
+        function_symbol_id: Option<SymbolId>,
+    ) -> Self {
+        Self {
+            scoping,
+            params,
+            function_symbol_id,
+            has_own_this: false,
+            allow_nested_scopes: false,
+            nested_scope_depth: 0,
+            child_scope_ids: std::vec::Vec::new(),
+            expression_count: 0,
+            references: std::vec::Vec::new(),
+            rejected: false,
+        }
+    }
+
+    fn enter_nested_scope(&mut self, scope_id: ScopeId) -> bool {
+        if !self.allow_nested_scopes {
+            self.rejected = true;
+            return false;
+        }
+        if self.nested_scope_depth == 0 {
+            self.child_scope_ids.push(scope_id);
+        }
+        self.nested_scope_depth += 1;
+        true
+    }
+}
+
+impl<'a> Visit<'a> for InlinableBodyChecker<'a, '_> {
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        self.expression_count += 1;
+        walk::walk_expression(self, it);
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        let symbol_id = self.scoping.get_reference(it.reference_id()).symbol_id();
+        if let Some(symbol_id) = symbol_id
+            && self.params.contains(&symbol_id)
+        {
+            // The argument would be evaluated when the nested function is called.
+            if self.nested_scope_depth > 0 {
+                self.rejected = true;
+            }
+            return;
+        }
+        // Recursive functions cannot be inlined.
+        if symbol_id.is_some() && symbol_id == self.function_symbol_id {
+            self.rejected = true;
+        }
+        if self.has_own_this && symbol_id.is_none() && it.name == "arguments" {
+            self.rejected = true;
+        }
+        self.references.push((it.name, symbol_id));
+    }
+
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        if self.has_own_this {
+            self.rejected = true;
+        }
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        if self.has_own_this {
+            self.rejected = true;
+        }
+    }
+
+    fn visit_meta_property(&mut self, it: &MetaProperty<'a>) {
+        if self.has_own_this && it.meta.name == "new" {
+            self.rejected = true;
+        }
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        if self.enter_nested_scope(it.scope_id()) {
+            walk::walk_function(self, it, flags);
+            self.nested_scope_depth -= 1;
+        }
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        if self.enter_nested_scope(it.scope_id()) {
+            walk::walk_arrow_function_expression(self, it);
+            self.nested_scope_depth -= 1;
+        }
+    }
+
+    fn visit_class(&mut self, it: &Class<'a>) {
+        if self.enter_nested_scope(it.scope_id()) {
+            walk::walk_class(self, it);
+            self.nested_scope_depth -= 1;
+        }
+    }
+}
+
+/// Replaces parameter references with the call arguments.
+struct ParamSubstitute<'a, 'b, 'c> {
+    params: &'b [SymbolId],
+    args: std::vec::Vec<Option<Expression<'a>>>,
+    /// The body is a copy of a function body, other references need new reference ids.
+    create_references: bool,
+    ctx: &'b mut Ctx<'a, 'c>,
+}
+
+impl<'a, 'b, 'c> ParamSubstitute<'a, 'b, 'c> {
+    fn new(
+        params: &'b [SymbolId],
+        args: std::vec::Vec<Option<Expression<'a>>>,
+        create_references: bool,
+        ctx: &'b mut Ctx<'a, 'c>,
+    ) -> Self {
+        Self { params, args, create_references, ctx }
+    }
+
+    fn get_param_index(&self, ident: &IdentifierReference<'a>) -> Option<usize> {
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        self.params.iter().position(|&param| param == symbol_id)
+    }
+
+    /// Give a copied identifier its own reference, so reference counts stay correct.
+    fn create_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.ctx.scoping().get_reference(ident.reference_id());
+        let (symbol_id, flags) = (reference.symbol_id(), reference.flags());
+        let reference_id = self.ctx.create_reference(ident.name.as_str(), symbol_id, flags);
+        ident.reference_id.set(Some(reference_id));
+    }
+}
+
+impl<'a> VisitMut<'a> for ParamSubstitute<'a, '_, '_> {
+    fn visit_expression(&mut self, it: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = it
+            && let Some(index) = self.get_param_index(ident)
+        {
+            let span = ident.span;
+            let reference_count =
+                self.ctx.scoping().get_resolved_reference_ids(self.params[index]).len();
+            *it = match &mut self.args[index] {
+                None => self.ctx.ast.void_0(span),
+                Some(arg) if reference_count > 1 => {
+                    let mut arg = arg.clone_in_with_semantic_ids(self.ctx.ast.allocator);
+                    if let Expression::Identifier(ident) = &mut arg {
+                        self.create_reference(ident);
+                    }
+                    arg
+                }
+                Some(arg) => arg.take_in(self.ctx.ast),
+            };
+            return;
+        }
+        walk_mut::walk_expression(self, it);
+    }
+
+    fn visit_object_property(&mut self, it: &mut ObjectProperty<'a>) {
+        // `{ a }` -> `{ a: 1 }`
+        if it.shorthand
+            && let Expression::Identifier(ident) = &it.value
+            && self.get_param_index(ident).is_some()
+        {
+            it.shorthand = false;
+        }
+        walk_mut::walk_object_property(self, it);
+    }
+
+    fn visit_identifier_reference(&mut self, it: &mut IdentifierReference<'a>) {
+        if self.create_references {
+            self.create_reference(it);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+
+    use crate::{
+        CompressOptions,
+        tester::{test_options, test_same_options, test_same_options_source_type},
+    };
+
+    fn options() -> CompressOptions {
+        CompressOptions { inline_functions: true, sequences: false, ..CompressOptions::smallest() }
+    }
+
+    /// `x`, `y`, `z` and `w` are initialized and never reassigned.
+    #[track_caller]
+    fn test(source_text: &str, expected: &str) {
+        let declarations = "export const x = a(), y = b(), z = c(), w = d();";
+        test_options(
+            &format!("{declarations} {source_text}"),
+            &format!("{declarations} {expected}"),
+            &options(),
+        );
+    }
+
+    #[track_caller]
+    fn test_same(source_text: &str) {
+        test(source_text, source_text);
+    }
+
+    #[test]
+    fn single_use_function() {
+        test("function foo(a) { return a.b } log(foo(x))", "log(x.b)");
+        test("function foo() { a(); return b() } log(foo())", "log((a(), b()))");
+        test("function foo() { a() } log(foo())", "log((a(), void 0))");
+        test("function foo(a, b) { return [a, b] } log(foo(1))", "log([1, void 0])");
+        test("function foo(a) { return { a } } log(foo('x'))", "log({ a: 'x' })");
+        test("log(foo(1)); function foo(a) { return a.b }", "log(1 .b)");
+        test(
+            "function foo(c) { function bar(a) { return a.b } return bar(c) } log(foo(x))",
+            "log(x.b)",
+        );
+
+        // side effects in arguments
+        test_same("function foo(a) { return a.b } log(foo(x()))");
+        test("function foo(a) { return b(), a } log(foo(x))", "log((b(), x))");
+        test("const v = 1; function foo(a) { b(); return a } log(foo(v))", "log((b(), 1))");
+        // imports are live bindings, `b()` may change them
+        test_same("import { i } from 'mod'; function foo(a) { return b(), a } log(foo(i))");
+        test_same("import { i } from 'mod'; function foo(a) { return a.b } log(foo(i))");
+        // reading an uninitialized binding throws
+        test_same("function foo(a) { return b() } log(foo(v)); let v = 1");
+        test_same("function foo() { return b() } log(foo(v)); let v = 1");
+        test_same("function foo(a) { return b(), a } log(foo(v)); let v = 1");
+        test_same(
+            "function foo(a) { return b(), a } g(); let v = c(); g(); function g() { log(foo(v)) }",
+        );
+        test_same(
+            "function foo(a) { return b(), a } switch (a) { case 0: let v = c(); case 1: log(foo(v)) }",
+        );
+        test(
+            "function foo(a) { return b(), a } export function g() { let v = c(); log(foo(v)) }",
+            "export function g() { let v = c(); log((b(), v)) }",
+        );
+        // parameters
+        test_same("function foo(a = 1) { return a.b } log(foo(x))");
+        test_same("function foo(...a) { return a.b } log(foo(x))");
+        test_same("function foo({ a }) { return a.b } log(foo(x))");
+        test_same("function foo(a) { a = 1; return a.b } log(foo(x))");
+        test_same("function foo() { return x.b } log(foo(...a))");
+        // body
+        test_same("function foo() { if (a) return b } log(foo())");
+        test_same("function foo() { var a = b(); return [a, a] } log(foo())");
+        test_same("function foo() { 'use asm'; return a.b } log(foo())");
+        test_same("function foo() { return this.a } log(foo())");
+        test_same("function foo() { return arguments.length } log(foo())");
+        test_same("function foo() { return new.target } log(foo())");
+        test_same("function foo() { return () => a } log(foo())");
+        test_same("function* foo() { return a.b } log(foo())");
+        test_same("async function foo() { return a.b } log(foo())");
+        // references
+        test_same("function foo() { return a.b } log(foo()), log(foo()), foo = a");
+        test_same("function foo(a) { return a.b } log(foo(x)); export { foo }");
+        test_same("function foo() { return a.b } log(foo()); eval('foo')");
+        test_same("function foo(a) { return a && foo(a.b) } log(foo(x))");
+        // shadowed names
+        test_same(
+            "function foo() { return x.a } function bar(x) { return [x, foo()] } log(bar(y), bar(z))",
+        );
+        // blocks
+        test_same("if (a) { function foo() { return a.b } log(foo()) }");
+    }
+
+    #[test]
+    fn small_pure_function() {
+        test(
+            "function foo(a) { return a === null } log(foo(x)), log(foo(y))",
+            "log(x === null), log(y === null)",
+        );
+        test("function foo(a) { return !a } log(foo(x), foo(y), foo(z))", "log(!x, !y, !z)");
+        test_same("function foo(a) { return !a } log(foo(x), foo(y), foo(z), foo(w))");
+        test_same("function foo(a) { return a.b } log(foo(x)), log(foo(y))");
+        test_same(
+            "function foo(a, b) { return a === b || a === null } log(foo(x, y)), log(foo(y, x))",
+        );
+    }
+
+    #[test]
+    fn iife() {
+        test("log((function(a) { return a.b })(x))", "log(x.b)");
+        test("log(((a) => a.b)(x))", "log(x.b)");
+        test("log(((a, b) => a + b)(1, 2))", "log(3)");
+        test("log(((a, b) => a === b)(1, x))", "log(x === 1)");
+        test("log((function(a) { a.b(); return a.c })(x))", "log((x.b(), x.c))");
+        test("log(((a) => () => a)(1))", "log(((a) => () => a)(1))");
+        test("log((() => { a(); b() })())", "log((a(), b(), void 0))");
+        test_same("log((function() { return () => this })())");
+        test_same("log((function(a) { return this.b })(x))");
+        test("log(((a) => this.b)(x))", "log(this.b)");
+        test_same("log((function(a) { return arguments })(x))");
+        test_same("log((function foo(a) { return foo })(x))");
+        test_same("log((function(a) { var b = a.c; return [b, b] })(x))");
+        test_same("log(((a) => a.b)(x()))");
+        test_same("log((async (a) => a.b)(x))");
+        test_same("log((function*(a) { return a.b })(x))");
+        // nested scopes are moved out of the removed function scope,
+        // so `a` in `foo` no longer resolves to its parameter in the next iteration
+        test(
+            "function foo() { return a.b } log((function(a) { return () => foo() })(1))",
+            "log(() => a.b)",
+        );
+    }
+
+    #[test]
+    fn with_statement() {
+        // `a` and `v` could resolve to `o.a` and `o.v` in a `with` statement.
+        let source_type = SourceType::cjs();
+        test_same_options_source_type(
+            "function f() { function foo() { return a.b } with (o) log(foo()) } f()",
+            source_type,
+            &options(),
+        );
+        test_same_options_source_type(
+            "function f(v) { function foo(c) { return c.b } with (o) log(foo(v)) } f()",
+            source_type,
+            &options(),
+        );
+        test_same_options_source_type(
+            "function f() { with (o) log((function(c) { return c.b })(v)) } f()",
+            source_type,
+            &options(),
+        );
+    }
+
+    #[test]
+    fn disabled() {
+        let options = CompressOptions { inline_functions: false, ..options() };
+        test_same_options("function foo(a) { return a.b } log(foo(a))", &options);
+        test_same_options("log((function(a) { return a.b })(1))", &options);
+    }
+}
@@ -1,6 +1,7 @@
 mod convert_to_dotted_properties;
 mod fold_constants;
 mod inline;
+mod inline_function;
 mod minimize_conditional_expression;
 mod minimize_conditions;
 mod minimize_expression_in_boolean_context;
@@ -28,6 +29,7 @@ use oxc_traverse::{ReusableTraverseCtx, Traverse, traverse_mut_with_ctx};
 use crate::{
     ctx::{Ctx, TraverseCtx},
     state::MinifierState,
+    trace::{Rewrite, TraceNode, Tracer},
 };
 
 pub use self::normalize::{Normalize, NormalizeOptions};
@@ -40,11 +42,30 @@ pub struct PeepholeOptimizations {
     /// in the previous walk.
     iteration: u8,
     changed: bool,
+    /// Records the rewrites made by each optimization when [`crate::CompressOptions::trace`] is enabled.
+    tracer: Option<Tracer>,
 }
 
 impl<'a> PeepholeOptimizations {
-    pub fn new(max_iterations: Option<u8>) -> Self {
-        Self { max_iterations, iteration: 0, changed: false }
+    pub fn new(max_iterations: Option<u8>, trace: bool) -> Self {
+        Self { max_iterations, iteration: 0, changed: false, tracer: trace.then(Tracer::default) }
+    }
+
+    /// Rewrites recorded in all iterations, in the order they were made.
+    pub fn into_trace(self) -> std::vec::Vec<Rewrite> {
+        self.tracer.map(Tracer::into_rewrites).unwrap_or_default()
+    }
+
+    fn trace_enter(&mut self, node: &impl TraceNode) {
+        if let Some(tracer) = &mut self.tracer {
+            tracer.enter(node);
+        }
+    }
+
+    fn trace(&mut self, pass: &'static str, node: &impl TraceNode) {
+        if let Some(tracer) = &mut self.tracer {
+            tracer.record(self.iteration, pass, node);
+        }
     }
 
     fn run_once(
@@ -149,6 +170,14 @@ impl<'a> PeepholeOptimizations {
     }
 }
 
+/// Run an optimization and record its rewrite of `$node` when tracing is enabled.
+macro_rules! trace {
+    ($self:ident, $node:expr, $pass:ident($($arg:expr),* $(,)?)) => {{
+        PeepholeOptimizations::$pass($($arg),*);
+        $self.trace(stringify!($pass), $node);
+    }};
+}
+
 impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
     fn enter_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         ctx.state.symbol_values.clear();
@@ -172,7 +201,8 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
 
     fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
         let ctx = &mut Ctx::new(ctx);
-        Self::minimize_statements(stmts, ctx);
+        self.trace_enter(stmts);
+        trace!(self, stmts, minimize_statements(stmts, ctx));
     }
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -182,51 +212,60 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
 
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         let ctx = &mut Ctx::new(ctx);
+        self.trace_enter(stmt);
         match stmt {
-            Statement::BlockStatement(_) => Self::try_optimize_block(stmt, ctx),
+            Statement::BlockStatement(_) => trace!(self, stmt, try_optimize_block(stmt, ctx)),
             Statement::IfStatement(s) => {
-                Self::minimize_expression_in_boolean_context(&mut s.test, ctx);
-                Self::try_fold_if(stmt, ctx);
+                trace!(self, stmt, minimize_expression_in_boolean_context(&mut s.test, ctx));
+                trace!(self, stmt, try_fold_if(stmt, ctx));
                 if let Statement::IfStatement(if_stmt) = stmt
                     && let Some(folded_stmt) = Self::try_minimize_if(if_stmt, ctx)
                 {
                     *stmt = folded_stmt;
                     ctx.state.changed = true;
                 }
+                self.trace("try_minimize_if", stmt);
             }
             Statement::WhileStatement(s) => {
-                Self::minimize_expression_in_boolean_context(&mut s.test, ctx);
+                trace!(self, stmt, minimize_expression_in_boolean_context(&mut s.test, ctx));
             }
             Statement::ForStatement(s) => {
                 if let Some(test) = &mut s.test {
-                    Self::minimize_expression_in_boolean_context(test, ctx);
+                    trace!(self, stmt, minimize_expression_in_boolean_context(test, ctx));
                 }
-                Self::try_fold_for(stmt, ctx);
+                trace!(self, stmt, try_fold_for(stmt, ctx));
             }
             Statement::DoWhileStatement(s) => {
-                Self::minimize_expression_in_boolean_context(&mut s.test, ctx);
+                trace!(self, stmt, minimize_expression_in_boolean_context(&mut s.test, ctx));
             }
-            Statement::TryStatement(_) => Self::try_fold_try(stmt, ctx),
-            Statement::LabeledStatement(_) => Self::try_fold_labeled(stmt, ctx),
+            Statement::TryStatement(_) => trace!(self, stmt, try_fold_try(stmt, ctx)),
+            Statement::LabeledStatement(_) => trace!(self, stmt, try_fold_labeled(stmt, ctx)),
             Statement::FunctionDeclaration(_) => {
-                Self::remove_unused_function_declaration(stmt, ctx);
+                trace!(self, stmt, remove_unused_function_declaration(stmt, ctx));
+                Self::keep_track_of_inlinable_functions(stmt, ctx);
+            }
+            Statement::ClassDeclaration(_) => {
+                trace!(self, stmt, remove_unused_class_declaration(stmt, ctx));
+            }
+            Statement::ImportDeclaration(_) => {
+                trace!(self, stmt, remove_unused_import_specifiers(stmt, ctx));
             }
-            Statement::ClassDeclaration(_) => Self::remove_unused_class_declaration(stmt, ctx),
-            Statement::ImportDeclaration(_) => Self::remove_unused_import_specifiers(stmt, ctx),
             _ => {}
         }
-        Self::try_fold_expression_stmt(stmt, ctx);
+        trace!(self, stmt, try_fold_expression_stmt(stmt, ctx));
     }
 
     fn exit_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         let ctx = &mut Ctx::new(ctx);
-        Self::substitute_for_statement(stmt, ctx);
-        Self::minimize_for_statement(stmt, ctx);
+        self.trace_enter(stmt);
+        trace!(self, stmt, substitute_for_statement(stmt, ctx));
+        trace!(self, stmt, minimize_for_statement(stmt, ctx));
     }
 
     fn exit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         let ctx = &mut Ctx::new(ctx);
-        Self::substitute_return_statement(stmt, ctx);
+        self.trace_enter(stmt);
+        trace!(self, stmt, substitute_return_statement(stmt, ctx));
     }
 
     fn exit_variable_declaration(
@@ -235,7 +274,8 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let ctx = &mut Ctx::new(ctx);
-        Self::substitute_variable_declaration(decl, ctx);
+        self.trace_enter(decl);
+        trace!(self, decl, substitute_variable_declaration(decl, ctx));
     }
 
     fn exit_variable_declarator(
@@ -249,80 +289,105 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         let ctx = &mut Ctx::new(ctx);
+        self.trace_enter(expr);
         match expr {
             Expression::TemplateLiteral(t) => {
-                Self::inline_template_literal(t, ctx);
-                Self::substitute_template_literal(expr, ctx);
+                trace!(self, expr, inline_template_literal(t, ctx));
+                trace!(self, expr, substitute_template_literal(expr, ctx));
             }
-            Expression::ObjectExpression(e) => Self::fold_object_exp(e, ctx),
+            Expression::ObjectExpression(e) => trace!(self, expr, fold_object_exp(e, ctx)),
             Expression::BinaryExpression(e) => {
-                Self::substitute_swap_binary_expressions(e);
-                Self::fold_binary_expr(expr, ctx);
-                Self::fold_binary_typeof_comparison(expr, ctx);
-                Self::minimize_loose_boolean(expr, ctx);
-                Self::minimize_binary(expr, ctx);
-                Self::substitute_loose_equals_undefined(expr, ctx);
-                Self::substitute_typeof_undefined(expr, ctx);
-                Self::substitute_rotate_binary_expression(expr, ctx);
+                trace!(self, expr, substitute_swap_binary_expressions(e));
+                trace!(self, expr, fold_binary_expr(expr, ctx));
+                trace!(self, expr, fold_binary_typeof_comparison(expr, ctx));
+                trace!(self, expr, minimize_loose_boolean(expr, ctx));
+                trace!(self, expr, minimize_binary(expr, ctx));
+                trace!(self, expr, substitute_loose_equals_undefined(expr, ctx));
+                trace!(self, expr, substitute_typeof_undefined(expr, ctx));
+                trace!(self, expr, substitute_rotate_binary_expression(expr, ctx));
             }
             Expression::UnaryExpression(_) => {
-                Self::fold_unary_expr(expr, ctx);
-                Self::minimize_unary(expr, ctx);
-                Self::substitute_unary_plus(expr, ctx);
+                trace!(self, expr, fold_unary_expr(expr, ctx));
+                trace!(self, expr, minimize_unary(expr, ctx));
+                trace!(self, expr, substitute_unary_plus(expr, ctx));
             }
             Expression::StaticMemberExpression(_) => {
-                Self::fold_static_member_expr(expr, ctx);
-                Self::replace_known_property_access(expr, ctx);
+                trace!(self, expr, fold_static_member_expr(expr, ctx));
+                trace!(self, expr, replace_known_property_access(expr, ctx));
             }
             Expression::ComputedMemberExpression(_) => {
-                Self::fold_computed_member_expr(expr, ctx);
-                Self::replace_known_property_access(expr, ctx);
+                trace!(self, expr, fold_computed_member_expr(expr, ctx));
+                trace!(self, expr, replace_known_property_access(expr, ctx));
             }
             Expression::LogicalExpression(_) => {
-                Self::fold_logical_expr(expr, ctx);
-                Self::minimize_logical_expression(expr, ctx);
-                Self::substitute_is_object_and_not_null(expr, ctx);
-                Self::substitute_rotate_logical_expression(expr, ctx);
+                trace!(self, expr, fold_logical_expr(expr, ctx));
+                trace!(self, expr, minimize_logical_expression(expr, ctx));
+                trace!(self, expr, substitute_is_object_and_not_null(expr, ctx));
+                trace!(self, expr, substitute_rotate_logical_expression(expr, ctx));
             }
             Expression::ChainExpression(_) => {
-                Self::fold_chain_expr(expr, ctx);
-                Self::substitute_chain_expression(expr, ctx);
+                trace!(self, expr, fold_chain_expr(expr, ctx));
+                trace!(self, expr, substitute_chain_expression(expr, ctx));
             }
             Expression::CallExpression(_) => {
-                Self::fold_call_expression(expr, ctx);
-                Self::substitute_iife_call(expr, ctx);
-                Self::remove_dead_code_call_expression(expr, ctx);
-                Self::replace_concat_chain(expr, ctx);
-                Self::replace_known_global_methods(expr, ctx);
-                Self::substitute_simple_function_call(expr, ctx);
-                Self::substitute_object_or_array_constructor(expr, ctx);
+                trace!(self, expr, fold_call_expression(expr, ctx));
+                trace!(self, expr, substitute_iife_call(expr, ctx));
+                trace!(self, expr, inline_function_call(expr, ctx));
+                trace!(self, expr, remove_dead_code_call_expression(expr, ctx));
+                trace!(self, expr, replace_concat_chain(expr, ctx));
+                trace!(self, expr, replace_known_global_methods(expr, ctx));
+                trace!(self, expr, substitute_simple_function_call(expr, ctx));
+                trace!(self, expr, substitute_object_or_array_constructor(expr, ctx));
             }
             Expression::ConditionalExpression(logical_expr) => {
-                Self::minimize_expression_in_boolean_context(&mut logical_expr.test, ctx);
-                if let Some(changed) = Self::minimize_conditional_expression(logical_expr, ctx) {
+                trace!(
+                    self,
+                    expr,
+                    minimize_expression_in_boolean_context(&mut logical_expr.test, ctx)
+                );
+                if let Expression::ConditionalExpression(logical_expr) = expr
+                    && let Some(changed) = Self::minimize_conditional_expression(logical_expr, ctx)
+                {
                     *expr = changed;
                     ctx.state.changed = true;
                 }
-                Self::try_fold_conditional_expression(expr, ctx);
+                self.trace("minimize_conditional_expression", expr);
+                trace!(self, expr, try_fold_conditional_expression(expr, ctx));
             }
             Expression::AssignmentExpression(e) => {
-                Self::minimize_normal_assignment_to_combined_logical_assignment(e, ctx);
-                Self::minimize_normal_assignment_to_combined_assignment(e, ctx);
-                Self::minimize_assignment_to_update_expression(expr, ctx);
-                Self::remove_unused_assignment_expr(expr, ctx);
-            }
-            Expression::SequenceExpression(_) => Self::remove_sequence_expression(expr, ctx),
-            Expression::ArrowFunctionExpression(e) => Self::substitute_arrow_expression(e, ctx),
-            Expression::FunctionExpression(e) => Self::try_remove_name_from_functions(e, ctx),
-            Expression::ClassExpression(e) => Self::try_remove_name_from_classes(e, ctx),
+                trace!(
+                    self,
+                    expr,
+                    minimize_normal_assignment_to_combined_logical_assignment(e, ctx)
+                );
+                if let Expression::AssignmentExpression(e) = expr {
+                    trace!(self, expr, minimize_normal_assignment_to_combined_assignment(e, ctx));
+                }
+                trace!(self, expr, minimize_assignment_to_update_expression(expr, ctx));
+                trace!(self, expr, remove_unused_assignment_expr(expr, ctx));
+            }
+            Expression::SequenceExpression(_) => {
+                trace!(self, expr, remove_sequence_expression(expr, ctx));
+            }
+            Expression::ArrowFunctionExpression(e) => {
+                trace!(self, expr, substitute_arrow_expression(e, ctx));
+            }
+            Expression::FunctionExpression(e) => {
+                trace!(self, expr, try_remove_name_from_functions(e, ctx));
+            }
+            Expression::ClassExpression(e) => {
+                trace!(self, expr, try_remove_name_from_classes(e, ctx));
+            }
             Expression::NewExpression(e) => {
-                Self::substitute_typed_array_constructor(e, ctx);
-                Self::substitute_global_new_expression(expr, ctx);
-                Self::substitute_object_or_array_constructor(expr, ctx);
+                trace!(self, expr, substitute_typed_array_constructor(e, ctx));
+                trace!(self, expr, substitute_global_new_expression(expr, ctx));
+                trace!(self, expr, substitute_object_or_array_constructor(expr, ctx));
+            }
+            Expression::BooleanLiteral(_) => trace!(self, expr, substitute_boolean(expr, ctx)),
+            Expression::ArrayExpression(_) => {
+                trace!(self, expr, substitute_array_expression(expr, ctx));
             }
-            Expression::BooleanLiteral(_) => Self::substitute_boolean(expr, ctx),
-            Expression::ArrayExpression(_) => Self::substitute_array_expression(expr, ctx),
-            Expression::Identifier(_) => Self::inline_identifier_reference(expr, ctx),
+            Expression::Identifier(_) => trace!(self, expr, inline_identifier_reference(expr, ctx)),
             _ => {}
         }
     }
@@ -330,25 +395,29 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
     fn exit_unary_expression(&mut self, expr: &mut UnaryExpression<'a>, ctx: &mut TraverseCtx<'a>) {
         if expr.operator.is_not() {
             let ctx = &mut Ctx::new(ctx);
-            Self::minimize_expression_in_boolean_context(&mut expr.argument, ctx);
+            self.trace_enter(expr);
+            trace!(self, expr, minimize_expression_in_boolean_context(&mut expr.argument, ctx));
         }
     }
 
     fn exit_call_expression(&mut self, e: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
         let ctx = &mut Ctx::new(ctx);
-        Self::substitute_call_expression(e, ctx);
-        Self::remove_empty_spread_arguments(&mut e.arguments);
+        self.trace_enter(e);
+        trace!(self, e, substitute_call_expression(e, ctx));
+        trace!(self, e, remove_empty_spread_arguments(&mut e.arguments));
     }
 
     fn exit_new_expression(&mut self, e: &mut NewExpression<'a>, ctx: &mut TraverseCtx<'a>) {
         let ctx = &mut Ctx::new(ctx);
-        Self::substitute_new_expression(e, ctx);
-        Self::remove_empty_spread_arguments(&mut e.arguments);
+        self.trace_enter(e);
+        trace!(self, e, substitute_new_expression(e, ctx));
+        trace!(self, e, remove_empty_spread_arguments(&mut e.arguments));
     }
 
     fn exit_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
         let ctx = &mut Ctx::new(ctx);
-        Self::substitute_object_property(prop, ctx);
+        self.trace_enter(prop);
+        trace!(self, prop, substitute_object_property(prop, ctx));
     }
 
     fn exit_assignment_target_property(
@@ -357,7 +426,8 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let ctx = &mut Ctx::new(ctx);
-        Self::substitute_assignment_target_property(node, ctx);
+        self.trace_enter(node);
+        trace!(self, node, substitute_assignment_target_property(node, ctx));
     }
 
     fn exit_assignment_target_property_property(
@@ -366,12 +436,14 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let ctx = &mut Ctx::new(ctx);
-        Self::substitute_assignment_target_property_property(prop, ctx);
+        self.trace_enter(prop);
+        trace!(self, prop, substitute_assignment_target_property_property(prop, ctx));
     }
 
     fn exit_binding_property(&mut self, prop: &mut BindingProperty<'a>, ctx: &mut TraverseCtx<'a>) {
         let ctx = &mut Ctx::new(ctx);
-        Self::substitute_binding_property(prop, ctx);
+        self.trace_enter(prop);
+        trace!(self, prop, substitute_binding_property(prop, ctx));
     }
 
     fn exit_method_definition(
@@ -380,7 +452,8 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let ctx = &mut Ctx::new(ctx);
-        Self::substitute_method_definition(prop, ctx);
+        self.trace_enter(prop);
+        trace!(self, prop, substitute_method_definition(prop, ctx));
     }
 
     fn exit_property_definition(
@@ -389,7 +462,8 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let ctx = &mut Ctx::new(ctx);
-        Self::substitute_property_definition(prop, ctx);
+        self.trace_enter(prop);
+        trace!(self, prop, substitute_property_definition(prop, ctx));
     }
 
     fn exit_accessor_property(
@@ -398,7 +472,8 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let ctx = &mut Ctx::new(ctx);
-        Self::substitute_accessor_property(prop, ctx);
+        self.trace_enter(prop);
+        trace!(self, prop, substitute_accessor_property(prop, ctx));
     }
 
     fn exit_member_expression(
@@ -407,7 +482,8 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let ctx = Ctx::new(ctx);
-        Self::convert_to_dotted_properties(expr, &ctx);
+        self.trace_enter(expr);
+        trace!(self, expr, convert_to_dotted_properties(expr, &ctx));
     }
 
     fn enter_class_body(&mut self, _body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -416,14 +492,16 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
 
     fn exit_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
         let ctx = &mut Ctx::new(ctx);
-        Self::remove_dead_code_exit_class_body(body, ctx);
-        Self::remove_unused_private_members(body, ctx);
+        self.trace_enter(body);
+        trace!(self, body, remove_dead_code_exit_class_body(body, ctx));
+        trace!(self, body, remove_unused_private_members(body, ctx));
         ctx.state.class_symbols_stack.pop_class_scope(Self::get_declared_private_symbols(body));
     }
 
     fn exit_catch_clause(&mut self, catch: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
         let ctx = Ctx::new(ctx);
-        Self::substitute_catch_clause(catch, &ctx);
+        self.trace_enter(catch);
+        trace!(self, catch, substitute_catch_clause(catch, &ctx));
     }
 
     fn exit_private_field_expression(
@@ -1,6 +1,8 @@
 use oxc_ecmascript::constant_evaluation::ConstantValue;
 use rustc_hash::{FxHashMap, FxHashSet};
 
+use oxc_allocator::Vec;
+use oxc_ast::ast::Statement;
 use oxc_data_structures::stack::NonEmptyStack;
 use oxc_span::{Atom, SourceType};
 use oxc_syntax::symbol::SymbolId;
@@ -17,6 +19,9 @@ pub struct MinifierState<'a> {
 
     pub symbol_values: SymbolValues<'a>,
 
+    /// Function declarations that can be inlined into their call sites
+    pub inlinable_functions: FxHashMap<SymbolId, InlinableFunction<'a>>,
+
     /// Private member usage for classes
     pub class_symbols_stack: ClassSymbolsStack<'a>,
 
@@ -30,12 +35,24 @@ impl MinifierState<'_> {
             options,
             pure_functions: FxHashMap::default(),
             symbol_values: SymbolValues::default(),
+            inlinable_functions: FxHashMap::default(),
             class_symbols_stack: ClassSymbolsStack::new(),
             changed: false,
         }
     }
 }
 
+/// A copy of a function declaration that can be inlined.
+pub struct InlinableFunction<'a> {
+    pub params: std::vec::Vec<SymbolId>,
+
+    /// Statements of the function body, copied with their semantic ids.
+    pub body: Vec<'a, Statement<'a>>,
+
+    /// Names referenced in the body, excluding parameters, with the symbols they resolve to.
+    pub references: std::vec::Vec<(Atom<'a>, Option<SymbolId>)>,
+}
+
 /// Stack to track class symbol information
 pub struct ClassSymbolsStack<'a> {
     stack: NonEmptyStack<FxHashSet<Atom<'a>>>,
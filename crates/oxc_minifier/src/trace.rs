@@ -0,0 +1,134 @@
+use std::mem;
+
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_codegen::{Codegen, Context, Gen, GenExpr};
+use oxc_span::GetSpan;
+use oxc_syntax::precedence::Precedence;
+
+/// A rewrite made by a peephole optimization.
+///
+/// Recorded when [`CompressOptions::trace`](crate::CompressOptions::trace) is enabled.
+#[derive(Debug, Clone)]
+pub struct Rewrite {
+    /// The fixed-point iteration the rewrite was made in, starting from `0`.
+    pub iteration: u8,
+
+    /// Name of the optimization, e.g. `fold_binary_expr`.
+    pub pass: &'static str,
+
+    /// Span of the rewritten node in the original source text.
+    pub span: Span,
+
+    /// The rewritten node before the optimization.
+    pub before: String,
+
+    /// The rewritten node after the optimization.
+    pub after: String,
+}
+
+/// Records rewrites by printing a node before and after each optimization.
+#[derive(Default)]
+pub struct Tracer {
+    rewrites: std::vec::Vec<Rewrite>,
+    span: Span,
+    before: String,
+}
+
+impl Tracer {
+    /// Start tracing the optimizations of `node`.
+    pub fn enter(&mut self, node: &impl TraceNode) {
+        self.span = node.trace_span();
+        self.before = print(node);
+    }
+
+    /// Record a rewrite if `pass` changed the node passed to [`Tracer::enter`].
+    pub fn record(&mut self, iteration: u8, pass: &'static str, node: &impl TraceNode) {
+        let after = print(node);
+        if after != self.before {
+            let before = mem::replace(&mut self.before, after.clone());
+            self.rewrites.push(Rewrite { iteration, pass, span: self.span, before, after });
+        }
+    }
+
+    pub fn into_rewrites(self) -> std::vec::Vec<Rewrite> {
+        self.rewrites
+    }
+}
+
+fn print(node: &impl TraceNode) -> String {
+    let mut codegen = Codegen::new();
+    node.print_trace(&mut codegen);
+    let mut text = codegen.into_source_text();
+    text.truncate(text.trim_end().len());
+    text
+}
+
+/// AST nodes visited by the peephole optimizations.
+pub trait TraceNode {
+    fn trace_span(&self) -> Span;
+
+    fn print_trace(&self, codegen: &mut Codegen);
+}
+
+impl TraceNode for Vec<'_, Statement<'_>> {
+    fn trace_span(&self) -> Span {
+        match (self.first(), self.last()) {
+            (Some(first), Some(last)) => Span::new(first.span().start, last.span().end),
+            _ => Span::default(),
+        }
+    }
+
+    fn print_trace(&self, codegen: &mut Codegen) {
+        for stmt in self {
+            stmt.print(codegen, Context::empty());
+        }
+    }
+}
+
+macro_rules! impl_trace_node {
+    (Gen: $($ty:ident),+ $(,)?) => {
+        $(
+            impl TraceNode for $ty<'_> {
+                fn trace_span(&self) -> Span {
+                    self.span()
+                }
+
+                fn print_trace(&self, codegen: &mut Codegen) {
+                    self.print(codegen, Context::empty());
+                }
+            }
+        )+
+    };
+    (GenExpr: $($ty:ident),+ $(,)?) => {
+        $(
+            impl TraceNode for $ty<'_> {
+                fn trace_span(&self) -> Span {
+                    self.span()
+                }
+
+                fn print_trace(&self, codegen: &mut Codegen) {
+                    self.print_expr(codegen, Precedence::Lowest, Context::empty());
+                }
+            }
+        )+
+    };
+}
+
+impl_trace_node!(
+    Gen: Statement,
+    ForStatement,
+    ReturnStatement,
+    VariableDeclaration,
+    ObjectProperty,
+    AssignmentTargetProperty,
+    AssignmentTargetPropertyProperty,
+    BindingProperty,
+    MethodDefinition,
+    PropertyDefinition,
+    AccessorProperty,
+    ClassBody,
+    CatchClause,
+);
+
+impl_trace_node!(GenExpr: Expression, UnaryExpression, CallExpression, NewExpression, MemberExpression);
@@ -2,22 +2,35 @@ use std::fmt::Write;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
-use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler};
+use oxc_mangler::{
+    MangleOptions, MangleOptionsKeepNames, MangleOptionsProperties, Mangler, NameCache,
+};
 use oxc_parser::Parser;
-use oxc_span::SourceType;
+use oxc_span::{CompactStr, SourceType};
 
-fn mangle(source_text: &str, options: MangleOptions) -> String {
+fn mangle(source_text: &str, options: &MangleOptions) -> String {
+    mangle_with_name_cache(source_text, options, NameCache::default()).0
+}
+
+fn mangle_with_name_cache(
+    source_text: &str,
+    options: &MangleOptions,
+    name_cache: NameCache,
+) -> (String, NameCache) {
     let allocator = Allocator::default();
     let source_type = SourceType::mjs();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     assert!(ret.errors.is_empty(), "Parser errors: {:?}", ret.errors);
     let program = ret.program;
-    let mangler_return = Mangler::new().with_options(options).build(&program);
-    Codegen::new()
+    let mangler_return =
+        Mangler::new().with_options(options.clone()).with_name_cache(name_cache).build(&program);
+    let code = Codegen::new()
         .with_scoping(Some(mangler_return.scoping))
         .with_private_member_mappings(Some(mangler_return.class_private_mappings))
+        .with_property_mappings(Some(mangler_return.property_mappings))
         .build(&program)
-        .code
+        .code;
+    (code, mangler_return.name_cache)
 }
 
 #[test]
@@ -26,12 +39,12 @@ fn direct_eval() {
 
     // Symbols in scopes with direct eval should NOT be mangled
     let source_text = "function foo() { let NO_MANGLE; eval('') }";
-    let mangled = mangle(source_text, options);
+    let mangled = mangle(source_text, &options);
     assert_eq!(mangled, "function foo() {\n\tlet NO_MANGLE;\n\teval(\"\");\n}\n");
 
     // Nested direct eval: parent scope also should not mangle
     let source_text = "function foo() { let NO_MANGLE; function bar() { eval('') } }";
-    let mangled = mangle(source_text, options);
+    let mangled = mangle(source_text, &options);
     assert_eq!(
         mangled,
         "function foo() {\n\tlet NO_MANGLE;\n\tfunction bar() {\n\t\teval(\"\");\n\t}\n}\n"
@@ -40,19 +53,19 @@ fn direct_eval() {
     // Sibling scope without direct eval should be mangled
     let source_text =
         "function foo() { let NO_MANGLE; eval('') } function bar() { let SHOULD_MANGLE; }";
-    let mangled = mangle(source_text, options);
+    let mangled = mangle(source_text, &options);
     // SHOULD_MANGLE gets mangled (to some short name), NO_MANGLE stays as is
     assert!(mangled.contains("NO_MANGLE"));
     assert!(!mangled.contains("SHOULD_MANGLE"));
 
     // Child function scope without direct eval CAN be mangled (eval in parent cannot access child function locals)
     let source_text = "function foo() { eval(''); function bar() { let CAN_MANGLE; } }";
-    let mangled = mangle(source_text, options);
+    let mangled = mangle(source_text, &options);
     assert!(!mangled.contains("CAN_MANGLE"));
 
     // Indirect eval should still allow mangling
     let source_text = "function foo() { let SHOULD_MANGLE; (0, eval)('') }";
-    let mangled = mangle(source_text, options);
+    let mangled = mangle(source_text, &options);
     assert!(!mangled.contains("SHOULD_MANGLE"));
 }
 
@@ -115,12 +128,12 @@ fn mangler() {
     let mut snapshot = String::new();
     cases.into_iter().fold(&mut snapshot, |w, case| {
         let options = MangleOptions::default();
-        write!(w, "{case}\n{}\n", mangle(case, options)).unwrap();
+        write!(w, "{case}\n{}\n", mangle(case, &options)).unwrap();
         w
     });
     top_level_cases.into_iter().fold(&mut snapshot, |w, case| {
         let options = MangleOptions { top_level: true, ..MangleOptions::default() };
-        write!(w, "{case}\n{}\n", mangle(case, options)).unwrap();
+        write!(w, "{case}\n{}\n", mangle(case, &options)).unwrap();
         w
     });
     keep_name_cases.into_iter().fold(&mut snapshot, |w, case| {
@@ -128,7 +141,7 @@ fn mangler() {
             keep_names: MangleOptionsKeepNames::all_true(),
             ..MangleOptions::default()
         };
-        write!(w, "{case}\n{}\n", mangle(case, options)).unwrap();
+        write!(w, "{case}\n{}\n", mangle(case, &options)).unwrap();
         w
     });
 
@@ -160,7 +173,7 @@ fn private_member_mangling() {
     let mut snapshot = String::new();
     cases.into_iter().fold(&mut snapshot, |w, case| {
         let options = MangleOptions::default();
-        write!(w, "{case}\n{}\n", mangle(case, options)).unwrap();
+        write!(w, "{case}\n{}\n", mangle(case, &options)).unwrap();
         w
     });
 
@@ -168,3 +181,70 @@ fn private_member_mangling() {
         insta::assert_snapshot!("private_member_mangling", snapshot);
     });
 }
+
+#[test]
+fn property_mangling() {
+    let cases = [
+        "x._foo = 1; x._bar; x._bar; x.baz",
+        "let o = { _foo: 1, _bar() {}, get _baz() {}, bar: 2 }; o._foo",
+        "class Foo { _foo = 1; static _bar() {} _baz; #_qux; bar() { return this._foo } }",
+        "let { _foo, _bar: bar, _baz = 1 } = o; ({ _foo, _bar: bar } = o)",
+        "function _(_foo) { return { _foo } }",
+        // Quoted names are kept, and are not mangled elsewhere either
+        "x._foo; x['_foo']; x._bar; ({ '_baz': 1 }); x._baz",
+        // Reserved names
+        "x._reserved; x.__proto__; x._foo",
+        // Names used by unmangled properties are not reused
+        "x._foo; x.a; x.b",
+        // Only properties are mangled
+        "import { _foo } from 'x'; export { _foo as _bar }; _foo; label: x._foo",
+    ];
+    let quoted_cases = ["x._foo; x['_foo']; ({ '_bar': 1, _bar: 2 })"];
+
+    let properties = MangleOptionsProperties {
+        reserved: vec!["_reserved".to_string()],
+        ..MangleOptionsProperties::with_prefix("_")
+    };
+    let mut snapshot = String::new();
+    cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options =
+            MangleOptions { properties: Some(properties.clone()), ..MangleOptions::default() };
+        write!(w, "{case}\n{}\n", mangle(case, &options)).unwrap();
+        w
+    });
+    quoted_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let properties = MangleOptionsProperties { mangle_quoted: true, ..properties.clone() };
+        let options = MangleOptions { properties: Some(properties), ..MangleOptions::default() };
+        write!(w, "{case}\n{}\n", mangle(case, &options)).unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("property_mangling", snapshot);
+    });
+}
+
+#[test]
+fn property_mangling_name_cache() {
+    let options = MangleOptions {
+        properties: Some(MangleOptionsProperties::with_prefix("_")),
+        ..MangleOptions::default()
+    };
+    let (first, name_cache) =
+        mangle_with_name_cache("x._foo; x._foo; x._bar", &options, NameCache::default());
+    assert_eq!(first, "x.e;\nx.e;\nx.t;\n");
+
+    // Cached names are reused, and new names don't collide with them
+    let (second, name_cache) =
+        mangle_with_name_cache("x._baz; x._baz; x._bar", &options, name_cache);
+    assert_eq!(second, "x.n;\nx.n;\nx.t;\n");
+    assert_eq!(
+        name_cache.properties.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect::<Vec<_>>(),
+        [("_bar", "t"), ("_baz", "n"), ("_foo", "e")]
+    );
+
+    // Cached names which collide with unmangled properties are replaced
+    let (third, name_cache) = mangle_with_name_cache("x._foo; x.e; x.t", &options, name_cache);
+    assert_eq!(third, "x.r;\nx.e;\nx.t;\n");
+    assert_eq!(name_cache.properties.get("_foo").map(CompactStr::as_str), Some("r"));
+}
@@ -9,3 +9,4 @@ mod obscure_edge_cases;
 mod oxc;
 mod real_world_patterns;
 mod statement_fusion;
+mod trace;
@@ -0,0 +1,40 @@
+use oxc_allocator::Allocator;
+use oxc_minifier::{CompressOptions, Minifier, MinifierOptions, Rewrite};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+
+use crate::default_options;
+
+fn trace(source_text: &str, trace: bool) -> Vec<Rewrite> {
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let options = MinifierOptions {
+        mangle: None,
+        compress: Some(CompressOptions { trace, ..default_options() }),
+    };
+    Minifier::new(options).minify(&allocator, &mut program).trace
+}
+
+#[test]
+fn records_rewrites() {
+    let rewrites = trace("x(1 + 1)", true);
+    let rewrite = rewrites.iter().find(|r| r.pass == "fold_binary_expr").unwrap();
+    assert_eq!(rewrite.iteration, 0);
+    assert_eq!(rewrite.span, Span::new(2, 7));
+    assert_eq!(rewrite.before, "1 + 1");
+    assert_eq!(rewrite.after, "2");
+}
+
+#[test]
+fn records_iterations() {
+    let rewrites = trace("if (a) { b() } else { c() }", true);
+    assert!(!rewrites.is_empty());
+    assert!(rewrites.windows(2).all(|w| w[0].iteration <= w[1].iteration));
+    assert!(rewrites.iter().all(|r| r.before != r.after));
+}
+
+#[test]
+fn disabled_by_default() {
+    assert!(!default_options().trace);
+    assert!(trace("x(1 + 1)", false).is_empty());
+}
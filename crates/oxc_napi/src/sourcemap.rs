@@ -0,0 +1,25 @@
+use oxc_sourcemap::{JSONSourceMap, napi::SourceMap};
+
+/// Convert a source map passed from JS (e.g. the `inputSourceMap` option) to [`oxc_sourcemap::SourceMap`].
+///
+/// # Errors
+///
+/// * The `mappings` are not valid VLQ.
+/// * The mappings or `x_google_ignoreList` refer to non-existent sources or names.
+pub fn parse_input_source_map(map: SourceMap) -> Result<oxc_sourcemap::SourceMap, String> {
+    let json = JSONSourceMap {
+        version: 3,
+        file: map.file,
+        mappings: map.mappings,
+        source_root: map.source_root,
+        sources: map.sources,
+        sources_content: map
+            .sources_content
+            .map(|contents| contents.into_iter().map(Some).collect::<Vec<_>>()),
+        names: map.names,
+        debug_id: None,
+        x_google_ignore_list: map.x_google_ignorelist,
+    };
+    oxc_sourcemap::SourceMap::from_json(json)
+        .map_err(|err| format!("Invalid input source map: {err}"))
+}
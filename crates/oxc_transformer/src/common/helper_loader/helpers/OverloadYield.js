@@ -0,0 +1,4 @@
+function _OverloadYield(e, d) {
+  this.v = e, this.k = d;
+}
+export { _OverloadYield as default };
\ No newline at end of file
@@ -0,0 +1,4 @@
+function _arrayWithHoles(r) {
+  if (Array.isArray(r)) return r;
+}
+export { _arrayWithHoles as default };
\ No newline at end of file
@@ -0,0 +1,8 @@
+function _isNativeFunction(t) {
+  try {
+    return -1 !== Function.toString.call(t).indexOf("[native code]");
+  } catch (n) {
+    return "function" == typeof t;
+  }
+}
+export { _isNativeFunction as default };
\ No newline at end of file
@@ -0,0 +1,4 @@
+function _writeOnlyError(r) {
+  throw new TypeError('"' + r + '" is write-only');
+}
+export { _writeOnlyError as default };
\ No newline at end of file
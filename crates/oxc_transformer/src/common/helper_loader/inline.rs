@@ -0,0 +1,233 @@
+//! Inline helpers, for [`HelperLoaderMode::Inline`].
+//!
+//! Helper sources in `helpers/` are the ES module builds of `@oxc-project/runtime` helpers,
+//! copied from `npm/runtime/src/helpers/esm`. Only the helpers which [`Helper`] can load,
+//! and the helpers they depend on, are included.
+//!
+//! When a helper is first loaded, it is parsed into the program's allocator, and:
+//!
+//! * Its top-level bindings are renamed to UIDs generated in the program's root scope.
+//! * Its `import`s of other helpers are replaced with references to those helpers, which are loaded too.
+//!   So each helper is inlined once, however many other helpers depend on it.
+//! * Its `import` and `export` statements are removed.
+//!
+//! The helpers' statements are only inserted into the program after traversal,
+//! as the helpers' own scopes and symbols are not in `Scoping`.
+//! [`crate::Transformer`] rebuilds `Scoping` once they have been inserted.
+//!
+//! [`Helper`]: super::Helper
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_parser::Parser;
+use oxc_semantic::{Scoping, SemanticBuilder, SymbolFlags, SymbolId};
+use oxc_span::{SPAN, SourceType, Span};
+use oxc_traverse::BoundIdentifier;
+
+use crate::context::TraverseCtx;
+
+#[cfg(doc)]
+use super::HelperLoaderMode;
+
+/// Helpers loaded in [`HelperLoaderMode::Inline`] mode.
+#[derive(Default)]
+pub struct InlineHelpers<'a> {
+    /// Bindings of loaded helpers, keyed by helper name.
+    /// Includes helpers which are only used by other helpers.
+    bindings: FxHashMap<&'a str, BoundIdentifier<'a>>,
+    /// Statements of loaded helpers. A helper's dependencies come before the helper.
+    statements: Vec<Statement<'a>>,
+}
+
+impl<'a> InlineHelpers<'a> {
+    /// Load helper `name` and its dependencies, and return the helper's binding.
+    pub fn load(&mut self, name: &'a str, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        if let Some(binding) = self.bindings.get(name) {
+            return binding.clone();
+        }
+        let binding = ctx.generate_uid_in_root_scope(name, SymbolFlags::Function);
+        self.bindings.insert(name, binding.clone());
+
+        let source = helper_source(name).unwrap_or_else(|| unreachable!("Unknown helper `{name}`"));
+        let ret = Parser::new(ctx.ast.allocator, source, SourceType::mjs()).parse();
+        debug_assert!(ret.errors.is_empty(), "Failed to parse helper `{name}`");
+        let scoping = SemanticBuilder::new().build(&ret.program).semantic.into_scoping();
+
+        let mut renames = FxHashMap::default();
+        let mut statements = vec![];
+        for stmt in ret.program.body {
+            match stmt {
+                // `import dependency from "./dependency.js";`
+                Statement::ImportDeclaration(decl) => {
+                    let dependency = decl.source.value.as_str();
+                    let dependency = dependency.trim_start_matches("./").trim_end_matches(".js");
+                    let dependency = self.load(dependency, ctx);
+                    for specifier in decl.specifiers.iter().flatten() {
+                        renames.insert(specifier.local().symbol_id(), dependency.name);
+                    }
+                }
+                // `export { _helper as default };`
+                Statement::ExportNamedDeclaration(decl) => {
+                    for specifier in &decl.specifiers {
+                        if let Some(symbol_id) = scoping.get_root_binding(&specifier.local.name()) {
+                            renames.insert(symbol_id, binding.name);
+                        }
+                    }
+                }
+                stmt => statements.push(stmt),
+            }
+        }
+
+        // Other top-level bindings, e.g. `ownKeys` in `objectSpread2`
+        let mut symbol_ids = scoping
+            .get_bindings(scoping.root_scope_id())
+            .values()
+            .copied()
+            .filter(|symbol_id| !renames.contains_key(symbol_id))
+            .collect::<Vec<_>>();
+        symbol_ids.sort_unstable();
+        for symbol_id in symbol_ids {
+            let uid = ctx.generate_uid_in_root_scope(
+                scoping.symbol_name(symbol_id),
+                scoping.symbol_flags(symbol_id),
+            );
+            renames.insert(symbol_id, uid.name);
+        }
+
+        let mut renamer = HelperRenamer { scoping: &scoping, renames };
+        for stmt in &mut statements {
+            renamer.visit_statement(stmt);
+        }
+        self.statements.extend(statements);
+
+        binding
+    }
+
+    /// Insert the statements of all loaded helpers at the top of `program`, after any `import`s.
+    ///
+    /// Returns `true` if any helpers were inserted.
+    pub fn insert_into_program(&mut self, program: &mut Program<'a>) -> bool {
+        if self.statements.is_empty() {
+            return false;
+        }
+        let index = program
+            .body
+            .iter()
+            .position(|stmt| !matches!(stmt, Statement::ImportDeclaration(_)))
+            .unwrap_or(program.body.len());
+        program.body.splice(index..index, self.statements.drain(..));
+        true
+    }
+}
+
+/// Rename the top-level bindings of a helper, and reset its spans,
+/// as they refer to the helper's source rather than the program's.
+struct HelperRenamer<'a, 'b> {
+    scoping: &'b Scoping,
+    renames: FxHashMap<SymbolId, Atom<'a>>,
+}
+
+impl<'a> VisitMut<'a> for HelperRenamer<'a, '_> {
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = SPAN;
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(name) = self.renames.get(&ident.symbol_id()) {
+            ident.name = *name;
+        }
+        walk_mut::walk_binding_identifier(self, ident);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if let Some(symbol_id) = self.scoping.get_reference(ident.reference_id()).symbol_id()
+            && let Some(name) = self.renames.get(&symbol_id)
+        {
+            ident.name = *name;
+        }
+        walk_mut::walk_identifier_reference(self, ident);
+    }
+}
+
+/// Source of helper `name`.
+fn helper_source(name: &str) -> Option<&'static str> {
+    let source = match name {
+        "applyDecs2311" => include_str!("helpers/applyDecs2311.js"),
+        "arrayLikeToArray" => include_str!("helpers/arrayLikeToArray.js"),
+        "arrayWithHoles" => include_str!("helpers/arrayWithHoles.js"),
+        "arrayWithoutHoles" => include_str!("helpers/arrayWithoutHoles.js"),
+        "assertClassBrand" => include_str!("helpers/assertClassBrand.js"),
+        "assertThisInitialized" => include_str!("helpers/assertThisInitialized.js"),
+        "asyncGeneratorDelegate" => include_str!("helpers/asyncGeneratorDelegate.js"),
+        "asyncIterator" => include_str!("helpers/asyncIterator.js"),
+        "asyncToGenerator" => include_str!("helpers/asyncToGenerator.js"),
+        "awaitAsyncGenerator" => include_str!("helpers/awaitAsyncGenerator.js"),
+        "callSuper" => include_str!("helpers/callSuper.js"),
+        "checkInRHS" => include_str!("helpers/checkInRHS.js"),
+        "checkPrivateRedeclaration" => include_str!("helpers/checkPrivateRedeclaration.js"),
+        "classCallCheck" => include_str!("helpers/classCallCheck.js"),
+        "classPrivateFieldGet2" => include_str!("helpers/classPrivateFieldGet2.js"),
+        "classPrivateFieldInitSpec" => include_str!("helpers/classPrivateFieldInitSpec.js"),
+        "classPrivateFieldLooseBase" => include_str!("helpers/classPrivateFieldLooseBase.js"),
+        "classPrivateFieldLooseKey" => include_str!("helpers/classPrivateFieldLooseKey.js"),
+        "classPrivateFieldSet2" => include_str!("helpers/classPrivateFieldSet2.js"),
+        "classPrivateMethodInitSpec" => include_str!("helpers/classPrivateMethodInitSpec.js"),
+        "construct" => include_str!("helpers/construct.js"),
+        "createClass" => include_str!("helpers/createClass.js"),
+        "createForOfIteratorHelper" => include_str!("helpers/createForOfIteratorHelper.js"),
+        "createForOfIteratorHelperLoose" => {
+            include_str!("helpers/createForOfIteratorHelperLoose.js")
+        }
+        "decorate" => include_str!("helpers/decorate.js"),
+        "decorateMetadata" => include_str!("helpers/decorateMetadata.js"),
+        "decorateParam" => include_str!("helpers/decorateParam.js"),
+        "defineAccessor" => include_str!("helpers/defineAccessor.js"),
+        "defineProperty" => include_str!("helpers/defineProperty.js"),
+        "extends" => include_str!("helpers/extends.js"),
+        "get" => include_str!("helpers/get.js"),
+        "getPrototypeOf" => include_str!("helpers/getPrototypeOf.js"),
+        "inherits" => include_str!("helpers/inherits.js"),
+        "inheritsLoose" => include_str!("helpers/inheritsLoose.js"),
+        "interopRequireDefault" => include_str!("helpers/interopRequireDefault.js"),
+        "interopRequireWildcard" => include_str!("helpers/interopRequireWildcard.js"),
+        "isNativeFunction" => include_str!("helpers/isNativeFunction.js"),
+        "isNativeReflectConstruct" => include_str!("helpers/isNativeReflectConstruct.js"),
+        "iterableToArray" => include_str!("helpers/iterableToArray.js"),
+        "iterableToArrayLimit" => include_str!("helpers/iterableToArrayLimit.js"),
+        "maybeArrayLike" => include_str!("helpers/maybeArrayLike.js"),
+        "nonIterableRest" => include_str!("helpers/nonIterableRest.js"),
+        "nonIterableSpread" => include_str!("helpers/nonIterableSpread.js"),
+        "objectDestructuringEmpty" => include_str!("helpers/objectDestructuringEmpty.js"),
+        "objectSpread2" => include_str!("helpers/objectSpread2.js"),
+        "objectWithoutProperties" => include_str!("helpers/objectWithoutProperties.js"),
+        "objectWithoutPropertiesLoose" => include_str!("helpers/objectWithoutPropertiesLoose.js"),
+        "OverloadYield" => include_str!("helpers/OverloadYield.js"),
+        "possibleConstructorReturn" => include_str!("helpers/possibleConstructorReturn.js"),
+        "readOnlyError" => include_str!("helpers/readOnlyError.js"),
+        "regeneratorRuntime" => include_str!("helpers/regeneratorRuntime.js"),
+        "set" => include_str!("helpers/set.js"),
+        "setFunctionName" => include_str!("helpers/setFunctionName.js"),
+        "setPrototypeOf" => include_str!("helpers/setPrototypeOf.js"),
+        "slicedToArray" => include_str!("helpers/slicedToArray.js"),
+        "superPropBase" => include_str!("helpers/superPropBase.js"),
+        "superPropGet" => include_str!("helpers/superPropGet.js"),
+        "superPropSet" => include_str!("helpers/superPropSet.js"),
+        "taggedTemplateLiteral" => include_str!("helpers/taggedTemplateLiteral.js"),
+        "taggedTemplateLiteralLoose" => include_str!("helpers/taggedTemplateLiteralLoose.js"),
+        "toArray" => include_str!("helpers/toArray.js"),
+        "toConsumableArray" => include_str!("helpers/toConsumableArray.js"),
+        "toPrimitive" => include_str!("helpers/toPrimitive.js"),
+        "toPropertyKey" => include_str!("helpers/toPropertyKey.js"),
+        "toSetter" => include_str!("helpers/toSetter.js"),
+        "typeof" => include_str!("helpers/typeof.js"),
+        "unsupportedIterableToArray" => include_str!("helpers/unsupportedIterableToArray.js"),
+        "usingCtx" => include_str!("helpers/usingCtx.js"),
+        "wrapAsyncGenerator" => include_str!("helpers/wrapAsyncGenerator.js"),
+        "wrapNativeSuper" => include_str!("helpers/wrapNativeSuper.js"),
+        "writeOnlyError" => include_str!("helpers/writeOnlyError.js"),
+        _ => return None,
+    };
+    Some(source)
+}
@@ -1,7 +1,7 @@
 //! Utility to load helper functions.
 //!
 //! This module provides functionality to load helper functions in different modes.
-//! It supports runtime, external, and inline (not yet implemented) modes for loading helper functions.
+//! It supports runtime, external, and inline modes for loading helper functions.
 //!
 //! ## Usage
 //!
@@ -47,23 +47,23 @@
 //!
 //! ### Inline ([`HelperLoaderMode::Inline`])
 //!
-//! > Note: This mode is not currently implemented.
-//!
 //! Inline helper functions are inserted directly into the top of program.
+//! Each helper is inserted once, along with the helpers it depends on.
 //!
 //! Generated code example:
 //!
 //! ```js
-//! function helperName(...arguments) { ... } // Inlined helper function
-//! helperName(...arguments);
+//! function _helperName(...arguments) { ... } // Inlined helper function
+//! _helperName(...arguments);
 //! ```
 //!
 //! Based on [@babel/helper](https://github.com/babel/babel/tree/v7.26.2/packages/babel-helpers).
+//! See [`inline`] for details.
 //!
 //! ## Implementation
 //!
 //! Unlike other "common" utilities, this one has no transformer. It adds imports to the program
-//! via `ModuleImports` transform.
+//! via `ModuleImports` transform. Inline helpers are inserted by [`crate::Transformer`] after traversal.
 
 use std::{borrow::Cow, cell::RefCell};
 
@@ -73,7 +73,7 @@ use serde::Deserialize;
 use oxc_allocator::Vec as ArenaVec;
 use oxc_ast::{
     NONE,
-    ast::{Argument, CallExpression, Expression},
+    ast::{Argument, CallExpression, Expression, Program},
 };
 use oxc_semantic::{ReferenceFlags, SymbolFlags};
 use oxc_span::{Atom, SPAN, Span};
@@ -81,17 +81,19 @@ use oxc_traverse::BoundIdentifier;
 
 use crate::context::{TransformCtx, TraverseCtx};
 
+mod inline;
+
+use inline::InlineHelpers;
+
 /// Defines the mode for loading helper functions.
 #[derive(Default, Clone, Copy, Debug, Deserialize)]
 pub enum HelperLoaderMode {
     /// Inline mode: Helper functions are directly inserted into the program.
     ///
-    /// Note: This mode is not currently implemented.
-    ///
     /// Example output:
     /// ```js
-    /// function helperName(...arguments) { ... } // Inlined helper function
-    /// helperName(...arguments);
+    /// function _helperName(...arguments) { ... } // Inlined helper function
+    /// _helperName(...arguments);
     /// ```
     Inline,
     /// External mode: Helper functions are accessed from a global `babelHelpers` object.
@@ -167,8 +169,29 @@ pub enum Helper {
     Decorate,
     DecorateParam,
     DecorateMetadata,
+    ApplyDecs2311,
     UsingCtx,
     TaggedTemplateLiteral,
+    TaggedTemplateLiteralLoose,
+    ClassCallCheck,
+    CreateClass,
+    Inherits,
+    InheritsLoose,
+    CallSuper,
+    WrapNativeSuper,
+    PossibleConstructorReturn,
+    AssertThisInitialized,
+    SlicedToArray,
+    ToArray,
+    ToConsumableArray,
+    MaybeArrayLike,
+    Construct,
+    DefineAccessor,
+    CreateForOfIterator,
+    CreateForOfIteratorLoose,
+    RegeneratorRuntime,
+    InteropRequireDefault,
+    InteropRequireWildcard,
 }
 
 impl Helper {
@@ -201,8 +224,29 @@ impl Helper {
             Self::Decorate => "decorate",
             Self::DecorateParam => "decorateParam",
             Self::DecorateMetadata => "decorateMetadata",
+            Self::ApplyDecs2311 => "applyDecs2311",
             Self::UsingCtx => "usingCtx",
             Self::TaggedTemplateLiteral => "taggedTemplateLiteral",
+            Self::TaggedTemplateLiteralLoose => "taggedTemplateLiteralLoose",
+            Self::ClassCallCheck => "classCallCheck",
+            Self::CreateClass => "createClass",
+            Self::Inherits => "inherits",
+            Self::InheritsLoose => "inheritsLoose",
+            Self::CallSuper => "callSuper",
+            Self::WrapNativeSuper => "wrapNativeSuper",
+            Self::PossibleConstructorReturn => "possibleConstructorReturn",
+            Self::AssertThisInitialized => "assertThisInitialized",
+            Self::SlicedToArray => "slicedToArray",
+            Self::ToArray => "toArray",
+            Self::ToConsumableArray => "toConsumableArray",
+            Self::MaybeArrayLike => "maybeArrayLike",
+            Self::Construct => "construct",
+            Self::DefineAccessor => "defineAccessor",
+            Self::CreateForOfIterator => "createForOfIteratorHelper",
+            Self::CreateForOfIteratorLoose => "createForOfIteratorHelperLoose",
+            Self::RegeneratorRuntime => "regeneratorRuntime",
+            Self::InteropRequireDefault => "interopRequireDefault",
+            Self::InteropRequireWildcard => "interopRequireWildcard",
         }
     }
 
@@ -217,18 +261,40 @@ pub struct HelperLoaderStore<'a> {
     mode: HelperLoaderMode,
     /// Loaded helpers, determined what helpers are loaded and what imports should be added.
     loaded_helpers: RefCell<FxHashMap<Helper, BoundIdentifier<'a>>>,
+    /// Helpers loaded in [`HelperLoaderMode::Inline`] mode, to be inserted into the program.
+    inline_helpers: RefCell<InlineHelpers<'a>>,
     pub(crate) used_helpers: RefCell<FxHashMap<Helper, String>>,
 }
 
-impl HelperLoaderStore<'_> {
+impl<'a> HelperLoaderStore<'a> {
     pub fn new(options: &HelperLoaderOptions) -> Self {
         Self {
             module_name: options.module_name.clone(),
             mode: options.mode,
             loaded_helpers: RefCell::new(FxHashMap::default()),
+            inline_helpers: RefCell::new(InlineHelpers::default()),
             used_helpers: RefCell::new(FxHashMap::default()),
         }
     }
+
+    pub fn mode(&self) -> HelperLoaderMode {
+        self.mode
+    }
+
+    /// Check if `source` is the module specifier of a helper loaded in [`HelperLoaderMode::Runtime`] mode.
+    pub fn is_runtime_helper_source(&self, source: &str) -> bool {
+        matches!(self.mode, HelperLoaderMode::Runtime)
+            && source
+                .strip_prefix(&*self.module_name)
+                .is_some_and(|rest| rest.starts_with("/helpers/"))
+    }
+
+    /// Insert helpers loaded in [`HelperLoaderMode::Inline`] mode into the program.
+    ///
+    /// Returns `true` if any helpers were inserted, in which case `Scoping` is out of date.
+    pub fn insert_inline_helpers(&self, program: &mut Program<'a>) -> bool {
+        self.inline_helpers.borrow_mut().insert_into_program(program)
+    }
 }
 
 // Public methods implemented directly on `TransformCtx`, as they need access to `TransformCtx::module_imports`.
@@ -272,9 +338,11 @@ impl<'a> TransformCtx<'a> {
             HelperLoaderMode::External => {
                 HelperLoaderStore::transform_for_external_helper(helper, ctx)
             }
-            HelperLoaderMode::Inline => {
-                unreachable!("Inline helpers are not supported yet");
-            }
+            HelperLoaderMode::Inline => helper_loader
+                .inline_helpers
+                .borrow_mut()
+                .load(helper.name(), ctx)
+                .create_read_expression(ctx),
         }
     }
 }
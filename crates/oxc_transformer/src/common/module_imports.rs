@@ -25,6 +25,10 @@
 //!     Atom::from("React"),
 //!     symbol_id
 //! );
+//!
+//! // ESM: import 'core-js/modules/es.array.at.js';
+//! // CJS: require('core-js/modules/es.array.at.js');
+//! self.ctx.module_imports.add_side_effect_import(Atom::from("core-js/modules/es.array.at.js"));
 //! ```
 //!
 //! > NOTE: Using `import` or `require` is determined by [`TransformCtx::source_type`].
@@ -118,6 +122,16 @@ impl<'a> ModuleImportsStore<'a> {
         self.add_import(source, Import::Named(NamedImport { imported, local }), front);
     }
 
+    /// Add side-effect only `import` or `require` to top of program, unless `source` is already imported.
+    ///
+    /// Which it will be depends on the source type.
+    ///
+    /// * `import 'source';` or
+    /// * `require('source');`
+    pub fn add_side_effect_import(&self, source: Atom<'a>) {
+        self.imports.borrow_mut().entry(source).or_default();
+    }
+
     /// Returns `true` if no imports have been scheduled for insertion.
     pub fn is_empty(&self) -> bool {
         self.imports.borrow().is_empty()
@@ -194,25 +208,31 @@ impl<'a> ModuleImportsStore<'a> {
         names: Vec<Import<'a>>,
         ctx: &TraverseCtx<'a>,
     ) -> Statement<'a> {
-        let specifiers = ctx.ast.vec_from_iter(names.into_iter().map(|import| match import {
-            Import::Named(import) => {
-                ImportDeclarationSpecifier::ImportSpecifier(ctx.ast.alloc_import_specifier(
-                    SPAN,
-                    ModuleExportName::IdentifierName(
-                        ctx.ast.identifier_name(SPAN, import.imported),
+        let specifiers =
+            (!names.is_empty()).then(|| {
+                ctx.ast.vec_from_iter(names.into_iter().map(|import| match import {
+                    Import::Named(import) => {
+                        ImportDeclarationSpecifier::ImportSpecifier(ctx.ast.alloc_import_specifier(
+                            SPAN,
+                            ModuleExportName::IdentifierName(
+                                ctx.ast.identifier_name(SPAN, import.imported),
+                            ),
+                            import.local.create_binding_identifier(ctx),
+                            ImportOrExportKind::Value,
+                        ))
+                    }
+                    Import::Default(local) => ImportDeclarationSpecifier::ImportDefaultSpecifier(
+                        ctx.ast.alloc_import_default_specifier(
+                            SPAN,
+                            local.create_binding_identifier(ctx),
+                        ),
                     ),
-                    import.local.create_binding_identifier(ctx),
-                    ImportOrExportKind::Value,
-                ))
-            }
-            Import::Default(local) => ImportDeclarationSpecifier::ImportDefaultSpecifier(
-                ctx.ast.alloc_import_default_specifier(SPAN, local.create_binding_identifier(ctx)),
-            ),
-        }));
+                }))
+            });
 
         Statement::from(ctx.ast.module_declaration_import_declaration(
             SPAN,
-            Some(specifiers),
+            specifiers,
             ctx.ast.string_literal(SPAN, source, None),
             None,
             NONE,
@@ -237,12 +257,15 @@ impl<'a> ModuleImportsStore<'a> {
             let arg = Argument::from(ctx.ast.expression_string_literal(SPAN, source, None));
             ctx.ast.vec1(arg)
         };
-        let Some(Import::Default(local)) = names.into_iter().next() else { unreachable!() };
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, args, false);
+        let Some(import) = names.into_iter().next() else {
+            return ctx.ast.statement_expression(SPAN, call);
+        };
+        let Import::Default(local) = import else { unreachable!() };
         let id = local.create_binding_pattern(ctx);
         let var_kind = VariableDeclarationKind::Var;
         let decl = {
-            let init = ctx.ast.expression_call(SPAN, callee, NONE, args, false);
-            let decl = ctx.ast.variable_declarator(SPAN, var_kind, id, NONE, Some(init), false);
+            let decl = ctx.ast.variable_declarator(SPAN, var_kind, id, NONE, Some(call), false);
             ctx.ast.vec1(decl)
         };
         Statement::from(ctx.ast.declaration_variable(SPAN, var_kind, decl, false))
@@ -0,0 +1,1603 @@
+//! Standard decorators
+//!
+//! This plugin transforms decorators which follow the `2023-11` version of the
+//! [TC39 decorators proposal](https://github.com/tc39/proposal-decorators),
+//! by calling the `_applyDecs2311` helper to apply decorators.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! @dec
+//! class Class {
+//!   @dec
+//!   prop = 0;
+//!
+//!   @dec
+//!   accessor value;
+//!
+//!   @dec
+//!   method() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _initClass, _Class, _init_prop, _init_extra_prop, _init_value, _init_extra_value, _initProto;
+//! let Class = (class Class {
+//!   static {
+//!     ({
+//!       e: [_init_value, _init_extra_value, _init_prop, _init_extra_prop, _initProto],
+//!       c: [_Class, _initClass]
+//!     } = _applyDecs2311(this, [dec], [[dec, 1, "value"], [dec, 2, "method"], [dec, 0, "prop"]]));
+//!   }
+//!   constructor() {
+//!     _init_extra_value(this);
+//!   }
+//!   prop = (_initProto(this), _init_prop(this, 0));
+//!   #A = (_init_extra_prop(this), _init_value(this));
+//!   get value() { return this.#A; }
+//!   set value(v) { this.#A = v; }
+//!   method() {}
+//!   static {
+//!     _initClass();
+//!   }
+//! }, _Class);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-decorators](https://babel.dev/docs/babel-plugin-proposal-decorators),
+//! with `version: "2023-11"`.
+//!
+//! * Decorators of the class and its elements are passed to `_applyDecs2311` in a static block,
+//!   which is inserted at the start of the class body.
+//!   The helper returns functions to run field initializers and extra initializers
+//!   added by `context.addInitializer`, and the decorated class.
+//! * Decorators which are not identifiers are evaluated before the class, and stored in temp vars.
+//!   Computed keys of a decorated class are evaluated before the class too, to preserve evaluation order.
+//! * Auto-accessors (`accessor x`) are transformed to a private field with a getter and setter.
+//! * Decorated private methods are moved into the helper call, and replaced with getters.
+//! * If the class has class decorators, references to the class are replaced with a temp var
+//!   holding the decorated class.
+//! * Decorator metadata (`Symbol.metadata`) is defined by the helper.
+//!
+//! Unlike Babel, static fields of a class with class decorators are not moved into a separate class,
+//! so `this` in static field initializers refers to the undecorated class.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-helper-create-class-features-plugin/src/decorators.ts>
+//! * Decorators proposal: <https://github.com/tc39/proposal-decorators>
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{Box as ArenaBox, GetAddress, TakeIn, UnstableAddress, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_semantic::{NodeId, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{Atom, SPAN, Span};
+use oxc_syntax::operator::AssignmentOperator;
+use oxc_traverse::{Ancestor, BoundIdentifier, MaybeBoundIdentifier, Traverse};
+
+use crate::{
+    Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::create_class_constructor,
+};
+
+/// Kinds of decorated elements, as expected by `_applyDecs2311`.
+#[derive(Clone, Copy)]
+#[repr(u8)]
+enum ElementKind {
+    Field = 0,
+    Accessor = 1,
+    Method = 2,
+    Getter = 3,
+    Setter = 4,
+}
+
+/// Flag of a decorated element which is static.
+const STATIC: u8 = 8;
+/// Flag of a decorated element whose decorators are passed with their `this` values.
+const DECORATORS_HAVE_THIS: u8 = 16;
+
+/// Decorated class element, to be passed to `_applyDecs2311`.
+struct DecoratedElement<'a> {
+    /// `[decorators, flags, name, ...]`
+    info: Expression<'a>,
+    /// Order in which `_applyDecs2311` applies decorators:
+    /// static methods and accessors, methods and accessors, static fields, fields.
+    order: u8,
+    /// Bindings which receive the values returned by `_applyDecs2311` for this element
+    locals: Vec<BoundIdentifier<'a>>,
+}
+
+/// Evaluated decorators of a class or a class element.
+struct Decorations<'a> {
+    elements: ArenaVec<'a, ArrayExpressionElement<'a>>,
+    /// `true` if `elements` are pairs of `this` value and decorator
+    has_this: bool,
+}
+
+/// State of the class being transformed.
+struct ClassState<'a> {
+    class_scope_id: ScopeId,
+    /// Scope outside of the class
+    outer_scope_id: ScopeId,
+    /// Scope of the static block which calls `_applyDecs2311`
+    static_block_scope_id: ScopeId,
+    /// Decorators and computed keys which are evaluated before the class
+    memos: Vec<Expression<'a>>,
+    /// `true` if computed keys need to be evaluated before the class
+    memoize_keys: bool,
+    elements: Vec<DecoratedElement<'a>>,
+    /// Initializers which run when an instance is created, before the next field is initialized.
+    /// They are called with `this` as argument.
+    instance_initializers: Vec<BoundIdentifier<'a>>,
+    /// Private name to check whether an object is an instance of the class
+    instance_brand: Option<Atom<'a>>,
+    /// Private names declared in the class, to generate unique names for accessor storages
+    private_names: FxHashSet<Atom<'a>>,
+    storage_count: usize,
+}
+
+/// Class declaration with class decorators, to be transformed in `exit_statement`.
+struct DecoratedClassDeclaration<'a> {
+    /// `None` if the class is an anonymous default export
+    binding: Option<BoundIdentifier<'a>>,
+    /// Span of the class name
+    span: Span,
+    decorated_class: BoundIdentifier<'a>,
+}
+
+/// Class expression to be wrapped in a sequence expression in `exit_expression`.
+struct ClassExpressionWrapper<'a> {
+    memos: Vec<Expression<'a>>,
+    decorated_class: Option<BoundIdentifier<'a>>,
+}
+
+pub struct StandardDecorator<'a, 'ctx> {
+    /// Class declaration which needs to be replaced with a `let` declaration.
+    ///
+    /// It is set in [`Self::exit_class`] and consumed in [`Self::exit_statement`] of the statement
+    /// containing the class, for the same reason as in the legacy decorator transform.
+    class_declaration: Option<DecoratedClassDeclaration<'a>>,
+    /// Class expression which needs to be wrapped.
+    ///
+    /// It is set in [`Self::exit_class`] and consumed in [`Self::exit_expression`] of the class expression,
+    /// which runs after other plugins have transformed the class.
+    class_expression: Option<ClassExpressionWrapper<'a>>,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> StandardDecorator<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { class_declaration: None, class_expression: None, ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for StandardDecorator<'a, '_> {
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if class.declare || !Self::should_transform(class) {
+            return;
+        }
+        self.transform_class(class, ctx);
+    }
+
+    // `#[inline]` because this is a hot path
+    #[inline]
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.class_declaration.is_some() {
+            self.transform_class_statement(stmt, ctx);
+        }
+    }
+
+    // `#[inline]` because this is a hot path
+    #[inline]
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(wrapper) = self.class_expression.take() {
+            Self::wrap_class_expression(expr, wrapper, ctx);
+        }
+    }
+}
+
+impl<'a> StandardDecorator<'a, '_> {
+    /// Check if a class has decorators or auto-accessors.
+    fn should_transform(class: &Class<'a>) -> bool {
+        !class.decorators.is_empty()
+            || class.body.body.iter().any(|element| match element {
+                ClassElement::MethodDefinition(method) => !method.decorators.is_empty(),
+                ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+                ClassElement::AccessorProperty(_) => true,
+                ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => false,
+            })
+    }
+
+    fn transform_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        let has_decorators = !class.decorators.is_empty()
+            || class.body.body.iter().any(|element| match element {
+                ClassElement::MethodDefinition(method) => !method.decorators.is_empty(),
+                ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+                ClassElement::AccessorProperty(prop) => !prop.decorators.is_empty(),
+                ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => false,
+            });
+        let memoize_keys = has_decorators
+            || class.body.body.iter().any(|element| {
+                matches!(element, ClassElement::AccessorProperty(prop) if Self::needs_key_memo(&prop.key))
+            });
+
+        let class_scope_id = class.scope_id();
+        let mut state = ClassState {
+            class_scope_id,
+            outer_scope_id: ctx.current_scope_id(),
+            static_block_scope_id: ctx.create_child_scope(
+                class_scope_id,
+                ScopeFlags::StrictMode | ScopeFlags::ClassStaticBlock,
+            ),
+            memos: vec![],
+            memoize_keys,
+            elements: vec![],
+            instance_initializers: vec![],
+            instance_brand: None,
+            private_names: class
+                .body
+                .body
+                .iter()
+                .filter_map(|element| element.property_key().and_then(PropertyKey::private_name))
+                .collect(),
+            storage_count: 0,
+        };
+
+        // Class decorators are evaluated first
+        let class_decorations = if class.decorators.is_empty() {
+            None
+        } else {
+            let decorators = class.decorators.take_in(ctx.ast);
+            Some(self.transform_decorators(decorators, false, &mut state, ctx))
+        };
+
+        // References to the class in the class body refer to the decorated class
+        let decorated_class = class_decorations.as_ref().map(|_| {
+            let name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+            let decorated_class = self.ctx.var_declarations.create_uid_var(name, ctx);
+            if let Some(id) = &class.id {
+                ClassReferenceReplacer::new(id.symbol_id(), &decorated_class, ctx)
+                    .visit_class_body(&mut class.body);
+            }
+            decorated_class
+        });
+
+        // Initializers of decorated methods, getters and setters, which are returned last
+        let (proto_initializer, static_initializer) = self.create_method_initializers(class, ctx);
+        if let Some(proto_initializer) = &proto_initializer {
+            state.instance_initializers.push(proto_initializer.clone());
+        }
+
+        let elements = class.body.body.take_in(ctx.ast);
+        let mut new_elements = ctx.ast.vec_with_capacity(elements.len() + 2);
+        for element in elements {
+            match element {
+                ClassElement::PropertyDefinition(prop) => {
+                    self.transform_property(prop, &mut new_elements, &mut state, ctx);
+                }
+                ClassElement::AccessorProperty(prop) => {
+                    self.transform_accessor(prop, &mut new_elements, &mut state, ctx);
+                }
+                ClassElement::MethodDefinition(method) => {
+                    self.transform_method(method, &mut new_elements, &mut state, ctx);
+                }
+                element @ (ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_)) => {
+                    new_elements.push(element);
+                }
+            }
+        }
+        class.body.body = new_elements;
+
+        // Initializers which were not run by a field initializer run in the constructor
+        if !state.instance_initializers.is_empty() {
+            let initializers = std::mem::take(&mut state.instance_initializers);
+            Self::insert_initializers_into_constructor(class, &initializers, ctx);
+        }
+
+        let class_initializer = class_decorations
+            .as_ref()
+            .map(|_| self.ctx.var_declarations.create_uid_var("initClass", ctx));
+
+        if has_decorators {
+            let static_block = self.create_apply_decorators_block(
+                class,
+                class_decorations,
+                decorated_class.as_ref().zip(class_initializer.as_ref()),
+                proto_initializer,
+                static_initializer,
+                &mut state,
+                ctx,
+            );
+            class.body.body.insert(0, static_block);
+        } else {
+            ctx.scoping_mut().delete_scope(state.static_block_scope_id);
+        }
+
+        // `static { _initClass(); }`
+        if let Some(class_initializer) = &class_initializer {
+            let call = Self::create_call(class_initializer, None, None, ctx);
+            let stmt = ctx.ast.statement_expression(SPAN, call);
+            class.body.body.push(Self::create_static_block(stmt, class_scope_id, ctx));
+        }
+
+        self.store_class_replacement(class, state.memos, decorated_class, ctx);
+    }
+
+    /// Create `_initProto` and `_initStatic`, if there are decorated methods, getters or setters.
+    fn create_method_initializers(
+        &self,
+        class: &Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Option<BoundIdentifier<'a>>, Option<BoundIdentifier<'a>>) {
+        let mut has_proto_methods = false;
+        let mut has_static_methods = false;
+        for element in &class.body.body {
+            if let ClassElement::MethodDefinition(method) = element
+                && !method.decorators.is_empty()
+            {
+                if method.r#static {
+                    has_static_methods = true;
+                } else {
+                    has_proto_methods = true;
+                }
+            }
+        }
+        let proto_initializer =
+            has_proto_methods.then(|| self.ctx.var_declarations.create_uid_var("initProto", ctx));
+        let static_initializer =
+            has_static_methods.then(|| self.ctx.var_declarations.create_uid_var("initStatic", ctx));
+        (proto_initializer, static_initializer)
+    }
+
+    /// Transform a field.
+    ///
+    /// * `@dec a = 1` -> `a = _init_a(this, 1)`
+    /// * `@dec static a = 1` -> `static a = _init_a(1); static { _init_extra_a(); }`
+    fn transform_property(
+        &self,
+        mut prop: ArenaBox<'a, PropertyDefinition<'a>>,
+        new_elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_field = prop.r#type == PropertyDefinitionType::PropertyDefinition && !prop.declare;
+        let mut extra_initializer = None;
+        if prop.decorators.is_empty() || !is_field {
+            if state.memoize_keys {
+                self.memoize_key(&mut prop.key, state, ctx);
+            }
+        } else {
+            let decorators = prop.decorators.take_in(ctx.ast);
+            let decorations = self.transform_decorators(decorators, true, state, ctx);
+            let (name, base_name) = self.get_element_name(&mut prop.key, state, ctx);
+            let init = self.create_local("init", base_name, ctx);
+            let extra = self.create_local("init_extra", base_name, ctx);
+
+            let mut info = Self::create_element_info(
+                decorations,
+                ElementKind::Field,
+                prop.r#static,
+                name,
+                ctx,
+            );
+            if let PropertyKey::PrivateIdentifier(ident) = &prop.key {
+                Self::push_private_field_accessors(&mut info, ident.name, state, ctx);
+                if !prop.r#static {
+                    state.instance_brand.get_or_insert(ident.name);
+                }
+            }
+
+            let value = prop.value.take();
+            prop.value = Some(Self::create_call(&init, Some(prop.r#static), value, ctx));
+
+            state.elements.push(DecoratedElement {
+                info: Self::create_array(info, ctx),
+                order: if prop.r#static { 2 } else { 3 },
+                locals: vec![init, extra.clone()],
+            });
+            extra_initializer = Some(extra);
+        }
+
+        if is_field && !prop.r#static {
+            Self::prefix_instance_initializers(&mut prop.value, state, ctx);
+        }
+        let is_static = prop.r#static;
+        new_elements.push(ClassElement::PropertyDefinition(prop));
+
+        if let Some(extra) = extra_initializer {
+            Self::push_extra_initializer(extra, is_static, new_elements, state, ctx);
+        }
+    }
+
+    /// Transform an auto-accessor into a private field with a getter and setter.
+    ///
+    /// * `accessor a = 1` -> `#A = 1; get a() { return this.#A; } set a(v) { this.#A = v; }`
+    /// * `@dec accessor a = 1` -> `#A = _init_a(this, 1); get a() { return this.#A; } set a(v) { this.#A = v; }`
+    /// * `@dec accessor #a = 1` -> `#A = _init_a(this, 1); get #a() { return _get_a(this); } set #a(v) { _set_a(this, v); }`
+    fn transform_accessor(
+        &self,
+        mut prop: ArenaBox<'a, AccessorProperty<'a>>,
+        new_elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let storage = Self::create_storage_name(state, ctx);
+        let is_static = prop.r#static;
+        let mut value = prop.value.take();
+        let mut extra_initializer = None;
+        // `_get_a` and `_set_a` of a decorated private accessor
+        let mut private_accessors = None;
+
+        if prop.decorators.is_empty() {
+            if state.memoize_keys {
+                self.memoize_key(&mut prop.key, state, ctx);
+            }
+        } else {
+            let decorators = prop.decorators.take_in(ctx.ast);
+            let decorations = self.transform_decorators(decorators, true, state, ctx);
+            let (name, base_name) = self.get_element_name(&mut prop.key, state, ctx);
+            let init = self.create_local("init", base_name, ctx);
+            let mut info =
+                Self::create_element_info(decorations, ElementKind::Accessor, is_static, name, ctx);
+            let mut locals = vec![init.clone()];
+            if let PropertyKey::PrivateIdentifier(ident) = &prop.key {
+                Self::push_private_field_accessors(&mut info, storage, state, ctx);
+                let get = self.create_local("get", base_name, ctx);
+                let set = self.create_local("set", base_name, ctx);
+                locals.push(get.clone());
+                locals.push(set.clone());
+                private_accessors = Some((get, set));
+                if !is_static {
+                    state.instance_brand.get_or_insert(ident.name);
+                }
+            }
+            let extra = self.create_local("init_extra", base_name, ctx);
+            locals.push(extra.clone());
+            extra_initializer = Some(extra);
+
+            value = Some(Self::create_call(&init, Some(is_static), value, ctx));
+            state.elements.push(DecoratedElement {
+                info: Self::create_array(info, ctx),
+                order: u8::from(!is_static),
+                locals,
+            });
+        }
+
+        // `#A = value`
+        if !is_static {
+            Self::prefix_instance_initializers(&mut value, state, ctx);
+        }
+        new_elements.push(ctx.ast.class_element_property_definition(
+            prop.span,
+            PropertyDefinitionType::PropertyDefinition,
+            ctx.ast.vec(),
+            PropertyKey::PrivateIdentifier(ctx.ast.alloc_private_identifier(SPAN, storage)),
+            NONE,
+            value,
+            false,
+            is_static,
+            false,
+            false,
+            false,
+            false,
+            false,
+            None,
+        ));
+
+        // `get a() { return this.#A; }` and `set a(v) { this.#A = v; }`
+        let class_scope_id = state.class_scope_id;
+        let getter_key = Self::clone_key(&prop.key, ctx);
+        let getter = Self::create_accessor_method(
+            MethodDefinitionKind::Get,
+            getter_key,
+            &prop,
+            storage,
+            private_accessors.as_ref().map(|(get, _)| get),
+            class_scope_id,
+            ctx,
+        );
+        new_elements.push(getter);
+        let setter_key = prop.key.take_in(ctx.ast);
+        let setter = Self::create_accessor_method(
+            MethodDefinitionKind::Set,
+            setter_key,
+            &prop,
+            storage,
+            private_accessors.as_ref().map(|(_, set)| set),
+            class_scope_id,
+            ctx,
+        );
+        new_elements.push(setter);
+
+        if let Some(extra) = extra_initializer {
+            Self::push_extra_initializer(extra, is_static, new_elements, state, ctx);
+        }
+    }
+
+    /// Transform a method, getter or setter.
+    ///
+    /// * `@dec method() {}` -> `method() {}`
+    /// * `@dec #method() {}` -> `get #method() { return _call_method; }`
+    /// * `@dec get #a() {}` -> `get #a() { return _call_a(this); }`
+    /// * `@dec set #a(v) {}` -> `set #a(v) { _call_a(this, v); }`
+    fn transform_method(
+        &self,
+        mut method: ArenaBox<'a, MethodDefinition<'a>>,
+        new_elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if method.decorators.is_empty() || method.kind.is_constructor() {
+            if state.memoize_keys {
+                self.memoize_key(&mut method.key, state, ctx);
+            }
+            new_elements.push(ClassElement::MethodDefinition(method));
+            return;
+        }
+
+        let decorators = method.decorators.take_in(ctx.ast);
+        let decorations = self.transform_decorators(decorators, true, state, ctx);
+        let (name, base_name) = self.get_element_name(&mut method.key, state, ctx);
+        let kind = match method.kind {
+            MethodDefinitionKind::Get => ElementKind::Getter,
+            MethodDefinitionKind::Set => ElementKind::Setter,
+            MethodDefinitionKind::Method | MethodDefinitionKind::Constructor => ElementKind::Method,
+        };
+        let mut info = Self::create_element_info(decorations, kind, method.r#static, name, ctx);
+        let order = u8::from(!method.r#static);
+
+        let PropertyKey::PrivateIdentifier(ident) = &method.key else {
+            state.elements.push(DecoratedElement {
+                info: Self::create_array(info, ctx),
+                order,
+                locals: vec![],
+            });
+            new_elements.push(ClassElement::MethodDefinition(method));
+            return;
+        };
+        let private_name = ident.name;
+        if !method.r#static {
+            state.instance_brand.get_or_insert(private_name);
+        }
+
+        // Move the method into `_applyDecs2311` call as a function expression
+        let call = self.create_local("call", base_name, ctx);
+        let class_scope_id = state.class_scope_id;
+        let mut func = method.value.take_in_box(ctx.ast);
+        func.r#type = FunctionType::FunctionExpression;
+        let func_scope_id = func.scope_id();
+        let scoping = ctx.scoping_mut();
+        scoping.change_scope_parent_id(func_scope_id, Some(state.static_block_scope_id));
+        scoping
+            .scope_flags_mut(func_scope_id)
+            .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+        info.push(ArrayExpressionElement::from(Expression::FunctionExpression(func)));
+        state.elements.push(DecoratedElement {
+            info: Self::create_array(info, ctx),
+            order,
+            locals: vec![call.clone()],
+        });
+
+        let (method_kind, body) = match kind {
+            // `return _call_method;`
+            ElementKind::Method => (
+                MethodDefinitionKind::Get,
+                ctx.ast.statement_return(SPAN, Some(call.create_read_expression(ctx))),
+            ),
+            // `return _call_a(this);`
+            ElementKind::Getter => (
+                MethodDefinitionKind::Get,
+                ctx.ast
+                    .statement_return(SPAN, Some(Self::create_call(&call, Some(false), None, ctx))),
+            ),
+            // `_call_a(this, v);`
+            _ => (MethodDefinitionKind::Set, ctx.ast.statement_empty(SPAN)),
+        };
+        let key =
+            PropertyKey::PrivateIdentifier(ctx.ast.alloc_private_identifier(SPAN, private_name));
+        let element = if method_kind == MethodDefinitionKind::Set {
+            Self::create_setter(key, method.r#static, class_scope_id, ctx, |value, ctx| {
+                Self::create_call(&call, Some(false), Some(value), ctx)
+            })
+        } else {
+            Self::create_getter(key, method.r#static, body, class_scope_id, ctx)
+        };
+        new_elements.push(element);
+    }
+
+    /// Create the getter or setter of an auto-accessor.
+    fn create_accessor_method(
+        kind: MethodDefinitionKind,
+        key: PropertyKey<'a>,
+        prop: &AccessorProperty<'a>,
+        storage: Atom<'a>,
+        private_accessor: Option<&BoundIdentifier<'a>>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let is_static = prop.r#static;
+        let computed = prop.computed;
+        let element = if kind == MethodDefinitionKind::Get {
+            // `return this.#A;` or `return _get_a(this);`
+            let value = match private_accessor {
+                Some(get) => Self::create_call(get, Some(is_static), None, ctx),
+                None => Self::create_private_field(ctx.ast.expression_this(SPAN), storage, ctx),
+            };
+            let body = ctx.ast.statement_return(SPAN, Some(value));
+            Self::create_getter(key, is_static, body, class_scope_id, ctx)
+        } else {
+            // `this.#A = v;` or `_set_a(this, v);`
+            Self::create_setter(key, is_static, class_scope_id, ctx, |value, ctx| {
+                if let Some(set) = private_accessor {
+                    return Self::create_call(set, Some(is_static), Some(value), ctx);
+                }
+                let field = Self::create_private_field(ctx.ast.expression_this(SPAN), storage, ctx);
+                let Expression::PrivateFieldExpression(field) = field else { unreachable!() };
+                let target = AssignmentTarget::PrivateFieldExpression(field);
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+            })
+        };
+        let ClassElement::MethodDefinition(mut method) = element else { unreachable!() };
+        method.computed = computed;
+        ClassElement::MethodDefinition(method)
+    }
+
+    /// `get key() { body }`
+    fn create_getter(
+        key: PropertyKey<'a>,
+        is_static: bool,
+        body: Statement<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let scope_id = ctx.create_child_scope(
+            class_scope_id,
+            ScopeFlags::Function | ScopeFlags::StrictMode | ScopeFlags::GetAccessor,
+        );
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        Self::create_method(MethodDefinitionKind::Get, key, is_static, params, body, scope_id, ctx)
+    }
+
+    /// `set key(v) { body(v); }`
+    fn create_setter(
+        key: PropertyKey<'a>,
+        is_static: bool,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+        body: impl FnOnce(Expression<'a>, &mut TraverseCtx<'a>) -> Expression<'a>,
+    ) -> ClassElement<'a> {
+        let scope_id = ctx.create_child_scope(
+            class_scope_id,
+            ScopeFlags::Function | ScopeFlags::StrictMode | ScopeFlags::SetAccessor,
+        );
+        let value =
+            ctx.generate_binding(Atom::from("v"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let param = ctx.ast.plain_formal_parameter(SPAN, value.create_binding_pattern(ctx));
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec1(param),
+            NONE,
+        );
+        let value = value.create_read_expression(ctx);
+        let body = ctx.ast.statement_expression(SPAN, body(value, ctx));
+        Self::create_method(MethodDefinitionKind::Set, key, is_static, params, body, scope_id, ctx)
+    }
+
+    fn create_method(
+        kind: MethodDefinitionKind,
+        key: PropertyKey<'a>,
+        is_static: bool,
+        params: FormalParameters<'a>,
+        body: Statement<'a>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(body));
+        let func = ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            body,
+            scope_id,
+        );
+        let computed = key.is_expression();
+        ctx.ast.class_element_method_definition(
+            SPAN,
+            MethodDefinitionType::MethodDefinition,
+            ctx.ast.vec(),
+            key,
+            func,
+            kind,
+            computed,
+            is_static,
+            false,
+            false,
+            None,
+        )
+    }
+
+    /// Create the static block which applies decorators.
+    ///
+    /// ```js
+    /// static {
+    ///   ({ e: [_init_a, _init_extra_a, _initProto], c: [_Class, _initClass] } =
+    ///     _applyDecs2311(this, [dec], [[dec, 0, "a"]], 0, _ => #a in _, _SuperClass));
+    ///   _initStatic(this);
+    /// }
+    /// ```
+    #[expect(clippy::too_many_arguments)]
+    fn create_apply_decorators_block(
+        &self,
+        class: &mut Class<'a>,
+        class_decorations: Option<Decorations<'a>>,
+        class_locals: Option<(&BoundIdentifier<'a>, &BoundIdentifier<'a>)>,
+        proto_initializer: Option<BoundIdentifier<'a>>,
+        static_initializer: Option<BoundIdentifier<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        // Elements are sorted in the order in which `_applyDecs2311` applies decorators,
+        // so its results are in the same order as locals
+        let mut elements = std::mem::take(&mut state.elements);
+        elements.sort_by_key(|element| element.order);
+        let mut infos = ctx.ast.vec_with_capacity(elements.len());
+        let mut element_locals = vec![];
+        for element in elements {
+            infos.push(ArrayExpressionElement::from(element.info));
+            element_locals.extend(element.locals);
+        }
+        element_locals.extend(proto_initializer);
+        let static_initializer_call = static_initializer.as_ref().map(|static_initializer| {
+            Self::create_call(static_initializer, Some(false), None, ctx)
+        });
+        element_locals.extend(static_initializer);
+
+        // `_applyDecs2311(this, classDecs, memberDecs, classDecsHaveThis, instanceBrand, parentClass)`
+        let mut arguments = ctx.ast.vec_with_capacity(6);
+        arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        let class_decorators_have_this =
+            class_decorations.as_ref().is_some_and(|decorations| decorations.has_this);
+        let class_decorators =
+            class_decorations.map_or_else(|| ctx.ast.vec(), |decorations| decorations.elements);
+        arguments.push(Argument::from(ctx.ast.expression_array(SPAN, class_decorators)));
+        arguments.push(Argument::from(ctx.ast.expression_array(SPAN, infos)));
+        let instance_brand =
+            state.instance_brand.map(|name| Self::create_instance_brand(name, state, ctx));
+        let parent_class = class
+            .super_class
+            .as_mut()
+            .map(|super_class| self.memoize_super_class(super_class, ctx));
+        if class_decorators_have_this || instance_brand.is_some() || parent_class.is_some() {
+            arguments.push(Argument::from(ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(u8::from(class_decorators_have_this)),
+                None,
+                NumberBase::Decimal,
+            )));
+        }
+        if instance_brand.is_some() || parent_class.is_some() {
+            arguments.push(Argument::from(instance_brand.unwrap_or_else(|| ctx.ast.void_0(SPAN))));
+        }
+        if let Some(parent_class) = parent_class {
+            arguments.push(Argument::from(parent_class));
+        }
+        let call = self.ctx.helper_call_expr(Helper::ApplyDecs2311, SPAN, arguments, ctx);
+
+        let element_target = (!element_locals.is_empty())
+            .then(|| Self::create_array_target(element_locals.iter(), ctx));
+        let class_target = class_locals.map(|(decorated_class, class_initializer)| {
+            Self::create_array_target(
+                <[_; 2]>::from((decorated_class, class_initializer)).into_iter(),
+                ctx,
+            )
+        });
+        let assignment = match (element_target, class_target) {
+            // `({ e: [...], c: [...] } = _applyDecs2311(...))`
+            (Some(element_target), Some(class_target)) => {
+                let properties = ctx.ast.vec_from_array([
+                    Self::create_target_property("e", element_target, ctx),
+                    Self::create_target_property("c", class_target, ctx),
+                ]);
+                let target = AssignmentTarget::from(
+                    ctx.ast
+                        .assignment_target_pattern_object_assignment_target(SPAN, properties, NONE),
+                );
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, call)
+            }
+            // `[...] = _applyDecs2311(...).e` or `[...] = _applyDecs2311(...).c`
+            (Some(target), None) | (None, Some(target)) => {
+                let property = if class_locals.is_some() { "c" } else { "e" };
+                let property = ctx.ast.identifier_name(SPAN, property);
+                let result =
+                    Expression::from(ctx.ast.member_expression_static(SPAN, call, property, false));
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, result)
+            }
+            (None, None) => call,
+        };
+
+        let mut stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment));
+        if let Some(call) = static_initializer_call {
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+        ctx.ast.class_element_static_block_with_scope_id(SPAN, stmts, state.static_block_scope_id)
+    }
+
+    /// `_ => #a in _`
+    fn create_instance_brand(
+        name: Atom<'a>,
+        state: &ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id = ctx.create_child_scope(
+            state.static_block_scope_id,
+            ScopeFlags::Arrow | ScopeFlags::Function | ScopeFlags::StrictMode,
+        );
+        let param =
+            ctx.generate_binding(Atom::from("_"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let private_in = ctx.ast.expression_private_in(
+            SPAN,
+            ctx.ast.private_identifier(SPAN, name),
+            param.create_read_expression(ctx),
+        );
+        Self::create_arrow_function(&[param], private_in, scope_id, ctx)
+    }
+
+    /// Push `o => o.#a, (o, v) => o.#a = v` to info of a private field or accessor.
+    fn push_private_field_accessors(
+        info: &mut ArenaVec<'a, ArrayExpressionElement<'a>>,
+        name: Atom<'a>,
+        state: &ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let flags = ScopeFlags::Arrow | ScopeFlags::Function | ScopeFlags::StrictMode;
+
+        // `o => o.#a`
+        let scope_id = ctx.create_child_scope(state.static_block_scope_id, flags);
+        let object =
+            ctx.generate_binding(Atom::from("o"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let field = Self::create_private_field(object.create_read_expression(ctx), name, ctx);
+        let getter = Self::create_arrow_function(&[object], field, scope_id, ctx);
+        info.push(ArrayExpressionElement::from(getter));
+
+        // `(o, v) => o.#a = v`
+        let scope_id = ctx.create_child_scope(state.static_block_scope_id, flags);
+        let object =
+            ctx.generate_binding(Atom::from("o"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let value =
+            ctx.generate_binding(Atom::from("v"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let field = Self::create_private_field(object.create_read_expression(ctx), name, ctx);
+        let Expression::PrivateFieldExpression(field) = field else { unreachable!() };
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::PrivateFieldExpression(field),
+            value.create_read_expression(ctx),
+        );
+        let setter = Self::create_arrow_function(&[object, value], assignment, scope_id, ctx);
+        info.push(ArrayExpressionElement::from(setter));
+    }
+
+    /// `(params) => expr`
+    fn create_arrow_function<const N: usize>(
+        params: &[BoundIdentifier<'a>; N],
+        expr: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let items =
+            ctx.ast.vec_from_iter(params.iter().map(|param| {
+                ctx.ast.plain_formal_parameter(SPAN, param.create_binding_pattern(ctx))
+            }));
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            items,
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, expr)),
+        );
+        ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+        )
+    }
+
+    /// `object.#name`
+    fn create_private_field(
+        object: Expression<'a>,
+        name: Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let field = ctx.ast.private_identifier(SPAN, name);
+        Expression::from(
+            ctx.ast.member_expression_private_field_expression(SPAN, object, field, false),
+        )
+    }
+
+    /// `[decorators, flags, name]`
+    fn create_element_info(
+        decorations: Decorations<'a>,
+        kind: ElementKind,
+        is_static: bool,
+        name: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ArenaVec<'a, ArrayExpressionElement<'a>> {
+        let mut flags = kind as u8;
+        if is_static {
+            flags |= STATIC;
+        }
+        if decorations.has_this {
+            flags |= DECORATORS_HAVE_THIS;
+        }
+        let Decorations { mut elements, has_this } = decorations;
+        let decorators = if elements.len() == 1 && !has_this {
+            elements.pop().unwrap()
+        } else {
+            ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, elements))
+        };
+        ctx.ast.vec_from_array([
+            decorators,
+            ArrayExpressionElement::from(ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(flags),
+                None,
+                NumberBase::Decimal,
+            )),
+            ArrayExpressionElement::from(name),
+        ])
+    }
+
+    fn create_array(
+        elements: ArenaVec<'a, ArrayExpressionElement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        ctx.ast.expression_array(SPAN, elements)
+    }
+
+    /// Evaluate decorators.
+    ///
+    /// Identifiers are used as they are, and member expressions are passed with their object
+    /// as `this` value. Other decorators are evaluated before the class, and stored in temp vars.
+    ///
+    /// * `@dec` -> `dec`
+    /// * `@obj.dec` -> `obj, obj.dec`
+    /// * `@this.dec` -> `_obj, _dec` with `_dec = (_obj = this).dec` evaluated before the class
+    /// * `@dec()` -> `_dec` with `_dec = dec()` evaluated before the class
+    fn transform_decorators(
+        &self,
+        decorators: ArenaVec<'a, Decorator<'a>>,
+        in_class_scope: bool,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Decorations<'a> {
+        let mut pairs = Vec::with_capacity(decorators.len());
+        for decorator in decorators {
+            let pair = match decorator.expression {
+                expr @ Expression::Identifier(_) => (None, expr),
+                Expression::StaticMemberExpression(member)
+                    if !member.optional && matches!(member.object, Expression::Identifier(_)) =>
+                {
+                    let Expression::Identifier(object) = &member.object else { unreachable!() };
+                    let object = MaybeBoundIdentifier::from_identifier_reference(object, ctx)
+                        .create_read_expression(ctx);
+                    (Some(object), Expression::StaticMemberExpression(member))
+                }
+                mut expr @ (Expression::StaticMemberExpression(_)
+                | Expression::ComputedMemberExpression(_)) => {
+                    // `_dec = (_obj = object).dec`
+                    let object_binding = self.ctx.var_declarations.create_uid_var("obj", ctx);
+                    let object = expr.as_member_expression_mut().unwrap().object_mut();
+                    let value = object.take_in(ctx.ast);
+                    *object = ctx.ast.expression_assignment(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        object_binding.create_write_target(ctx),
+                        value,
+                    );
+                    let decorator = self.memoize(expr, "dec", in_class_scope, state, ctx);
+                    (Some(object_binding.create_read_expression(ctx)), decorator)
+                }
+                expr => (None, self.memoize(expr, "dec", in_class_scope, state, ctx)),
+            };
+            pairs.push(pair);
+        }
+
+        let has_this = pairs.iter().any(|(this, _)| this.is_some());
+        let mut elements = ctx.ast.vec_with_capacity(pairs.len() * (1 + usize::from(has_this)));
+        for (this, decorator) in pairs {
+            if has_this {
+                let this = this.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+                elements.push(ArrayExpressionElement::from(this));
+            }
+            elements.push(ArrayExpressionElement::from(decorator));
+        }
+        Decorations { elements, has_this }
+    }
+
+    /// Evaluate `expr` before the class, and return a reference to the temp var which stores it.
+    fn memoize(
+        &self,
+        expr: Expression<'a>,
+        name: &str,
+        in_class_scope: bool,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if in_class_scope {
+            ChildScopeMover::new(state.outer_scope_id, ctx).visit_expression(&expr);
+        }
+        let binding = self.ctx.var_declarations.create_uid_var(name, ctx);
+        state.memos.push(ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            expr,
+        ));
+        binding.create_read_expression(ctx)
+    }
+
+    /// Get name of a decorated element to pass to `_applyDecs2311`, and a base name for its locals.
+    ///
+    /// Computed keys are evaluated before the class.
+    fn get_element_name(
+        &self,
+        key: &mut PropertyKey<'a>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, &'a str) {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                (ctx.ast.expression_string_literal(SPAN, ident.name, None), ident.name.as_str())
+            }
+            PropertyKey::PrivateIdentifier(ident) => {
+                (ctx.ast.expression_string_literal(SPAN, ident.name, None), ident.name.as_str())
+            }
+            PropertyKey::StringLiteral(lit) => {
+                (ctx.ast.expression_string_literal(SPAN, lit.value, None), "computedKey")
+            }
+            PropertyKey::NumericLiteral(lit) => {
+                (ctx.ast.expression_numeric_literal(SPAN, lit.value, None, lit.base), "computedKey")
+            }
+            _ => {
+                let binding = self.memoize_key(key, state, ctx).unwrap();
+                (binding.create_read_expression(ctx), "computedKey")
+            }
+        }
+    }
+
+    /// Evaluate a computed key before the class.
+    ///
+    /// `[key]` -> `[_computedKey]` with `_computedKey = _toPropertyKey(key)`
+    fn memoize_key(
+        &self,
+        key: &mut PropertyKey<'a>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<BoundIdentifier<'a>> {
+        if !Self::needs_key_memo(key) {
+            return None;
+        }
+        let expr = key.to_expression_mut().take_in(ctx.ast);
+        ChildScopeMover::new(state.outer_scope_id, ctx).visit_expression(&expr);
+        let arguments = ctx.ast.vec1(Argument::from(expr));
+        let value = self.ctx.helper_call_expr(Helper::ToPropertyKey, SPAN, arguments, ctx);
+        let binding = self.ctx.var_declarations.create_uid_var("computedKey", ctx);
+        state.memos.push(ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            value,
+        ));
+        *key = PropertyKey::from(binding.create_read_expression(ctx));
+        Some(binding)
+    }
+
+    fn needs_key_memo(key: &PropertyKey<'a>) -> bool {
+        key.is_expression()
+            && !matches!(key, PropertyKey::StringLiteral(_) | PropertyKey::NumericLiteral(_))
+    }
+
+    /// Clone a key which has been memoized, for the getter of an auto-accessor.
+    fn clone_key(key: &PropertyKey<'a>, ctx: &mut TraverseCtx<'a>) -> PropertyKey<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                PropertyKey::StaticIdentifier(ctx.ast.alloc_identifier_name(SPAN, ident.name))
+            }
+            PropertyKey::PrivateIdentifier(ident) => {
+                PropertyKey::PrivateIdentifier(ctx.ast.alloc_private_identifier(SPAN, ident.name))
+            }
+            PropertyKey::StringLiteral(lit) => {
+                PropertyKey::from(ctx.ast.expression_string_literal(SPAN, lit.value, None))
+            }
+            PropertyKey::NumericLiteral(lit) => PropertyKey::from(
+                ctx.ast.expression_numeric_literal(SPAN, lit.value, None, lit.base),
+            ),
+            PropertyKey::Identifier(ident) => PropertyKey::from(
+                MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                    .create_read_expression(ctx),
+            ),
+            _ => unreachable!("Computed keys of auto-accessors are memoized"),
+        }
+    }
+
+    /// `extends (_SuperClass = expr)`
+    ///
+    /// Returns reference to the super class to pass to `_applyDecs2311`.
+    fn memoize_super_class(
+        &self,
+        super_class: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let Expression::Identifier(ident) = super_class {
+            return MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                .create_read_expression(ctx);
+        }
+        let binding = self.ctx.var_declarations.create_uid_var_based_on_node(super_class, ctx);
+        let value = super_class.take_in(ctx.ast);
+        *super_class = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            value,
+        );
+        binding.create_read_expression(ctx)
+    }
+
+    /// Generate a unique name for storage of an auto-accessor, e.g. `#A`.
+    fn create_storage_name(state: &mut ClassState<'a>, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+        loop {
+            let index = state.storage_count;
+            state.storage_count += 1;
+            #[expect(clippy::cast_possible_truncation)]
+            let letter = char::from(b'A' + (index % 26) as u8);
+            let name = if index < 26 {
+                ctx.ast.atom(letter.encode_utf8(&mut [0; 4]))
+            } else {
+                ctx.ast.atom(&format!("{letter}{}", index / 26))
+            };
+            if state.private_names.insert(name) {
+                return name;
+            }
+        }
+    }
+
+    /// Create a local which receives a value returned by `_applyDecs2311`, e.g. `_init_a`.
+    fn create_local(
+        &self,
+        prefix: &str,
+        base_name: &str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        self.ctx.var_declarations.create_uid_var(&format!("{prefix}_{base_name}"), ctx)
+    }
+
+    /// Create a call of a local.
+    ///
+    /// * `is_static` is `None`: `callee()`
+    /// * `is_static` is `Some(true)`: `callee(value)`
+    /// * `is_static` is `Some(false)`: `callee(this, value)`
+    fn create_call(
+        callee: &BoundIdentifier<'a>,
+        is_static: Option<bool>,
+        value: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec();
+        if is_static == Some(false) {
+            arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        }
+        if let Some(value) = value {
+            arguments.push(Argument::from(value));
+        }
+        ctx.ast.expression_call(SPAN, callee.create_read_expression(ctx), NONE, arguments, false)
+    }
+
+    /// Run pending instance initializers before the value of a field.
+    ///
+    /// `a = 1` -> `a = (_initProto(this), 1)`
+    fn prefix_instance_initializers(
+        value: &mut Option<Expression<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if state.instance_initializers.is_empty() {
+            return;
+        }
+        let mut expressions = ctx.ast.vec_with_capacity(state.instance_initializers.len() + 1);
+        for initializer in state.instance_initializers.drain(..) {
+            expressions.push(Self::create_call(&initializer, Some(false), None, ctx));
+        }
+        expressions.push(value.take().unwrap_or_else(|| ctx.ast.void_0(SPAN)));
+        *value = Some(ctx.ast.expression_sequence(SPAN, expressions));
+    }
+
+    /// Run the extra initializers of a field or accessor after it is initialized.
+    ///
+    /// Extra initializers of instance fields run before the value of the next field,
+    /// and extra initializers of static fields run in a static block after the field.
+    fn push_extra_initializer(
+        extra: BoundIdentifier<'a>,
+        is_static: bool,
+        new_elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if is_static {
+            // `static { _init_extra_a(); }`
+            let call = Self::create_call(&extra, None, None, ctx);
+            let stmt = ctx.ast.statement_expression(SPAN, call);
+            new_elements.push(Self::create_static_block(stmt, state.class_scope_id, ctx));
+        } else {
+            state.instance_initializers.push(extra);
+        }
+    }
+
+    /// `static { stmt }`
+    fn create_static_block(
+        stmt: Statement<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let scope_id = ctx.create_child_scope(
+            class_scope_id,
+            ScopeFlags::StrictMode | ScopeFlags::ClassStaticBlock,
+        );
+        ctx.ast.class_element_static_block_with_scope_id(SPAN, ctx.ast.vec1(stmt), scope_id)
+    }
+
+    /// Insert initializers into the constructor, creating one if the class has no constructor.
+    ///
+    /// * No constructor: `constructor() { _initProto(this); }`
+    /// * Base class: `constructor() { _initProto(this); ... }`
+    /// * Derived class: `super()` -> `_initProto(super())`
+    fn insert_initializers_into_constructor(
+        class: &mut Class<'a>,
+        initializers: &[BoundIdentifier<'a>],
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let constructor = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(method)
+                if method.kind.is_constructor() && method.value.body.is_some() =>
+            {
+                Some(method)
+            }
+            _ => None,
+        });
+
+        let Some(constructor) = constructor else {
+            let scope_id = ctx.create_child_scope(
+                class.scope_id(),
+                ScopeFlags::StrictMode | ScopeFlags::Function | ScopeFlags::Constructor,
+            );
+            let stmts = initializers
+                .iter()
+                .map(|initializer| {
+                    let call = Self::create_call(initializer, Some(false), None, ctx);
+                    ctx.ast.statement_expression(SPAN, call)
+                })
+                .collect::<Vec<_>>();
+            let constructor =
+                create_class_constructor(stmts, class.super_class.is_some(), scope_id, ctx);
+            class.body.body.insert(0, constructor);
+            return;
+        };
+
+        let body = constructor.value.body.as_mut().unwrap();
+        if class.super_class.is_some() {
+            SuperCallWrapper::new(initializers, ctx).visit_function_body(body);
+        } else {
+            let stmts = initializers.iter().map(|initializer| {
+                let call = Self::create_call(initializer, Some(false), None, ctx);
+                ctx.ast.statement_expression(SPAN, call)
+            });
+            let stmts = stmts.collect::<Vec<_>>();
+            body.statements.splice(0..0, stmts);
+        }
+    }
+
+    /// `[a, b, c]` assignment target
+    fn create_array_target<'b>(
+        locals: impl Iterator<Item = &'b BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a>
+    where
+        'a: 'b,
+    {
+        let elements = locals
+            .map(|local| Some(AssignmentTargetMaybeDefault::from(local.create_write_target(ctx))))
+            .collect::<Vec<_>>();
+        let elements = ctx.ast.vec_from_iter(elements);
+        AssignmentTarget::from(
+            ctx.ast.assignment_target_pattern_array_assignment_target(SPAN, elements, NONE),
+        )
+    }
+
+    /// `name: target` assignment target property
+    fn create_target_property(
+        name: &'static str,
+        target: AssignmentTarget<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> AssignmentTargetProperty<'a> {
+        let key = ctx.ast.property_key_static_identifier(SPAN, name);
+        ctx.ast.assignment_target_property_assignment_target_property_property(
+            SPAN,
+            key,
+            AssignmentTargetMaybeDefault::from(target),
+            false,
+        )
+    }
+
+    /// Record how the class needs to be replaced after it is transformed.
+    fn store_class_replacement(
+        &mut self,
+        class: &Class<'a>,
+        memos: Vec<Expression<'a>>,
+        decorated_class: Option<BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if class.is_expression() {
+            if !memos.is_empty() || decorated_class.is_some() {
+                self.class_expression = Some(ClassExpressionWrapper { memos, decorated_class });
+            }
+            return;
+        }
+
+        // `_dec = dec(), _computedKey = _toPropertyKey(key);` before the class declaration
+        if !memos.is_empty() {
+            let address = match ctx.parent() {
+                parent @ (Ancestor::ExportDefaultDeclarationDeclaration(_)
+                | Ancestor::ExportNamedDeclarationDeclaration(_)) => parent.address(),
+                // `Class` is always stored in a `Box`, so has a stable memory location
+                _ => class.unstable_address(),
+            };
+            let expression = ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter(memos));
+            let stmt = ctx.ast.statement_expression(SPAN, expression);
+            self.ctx.statement_injector.insert_before(&address, stmt);
+        }
+
+        let Some(decorated_class) = decorated_class else { return };
+        // The class declaration becomes a `let` declaration, and the class gets a new binding
+        let span = class.id.as_ref().map_or(SPAN, |ident| ident.span);
+        let binding = class.id.as_ref().map(|ident| {
+            let scoping = ctx.scoping_mut();
+            let new_class_symbol_id = scoping.create_symbol(
+                ident.span,
+                &ident.name,
+                SymbolFlags::Class,
+                class.scope_id(),
+                NodeId::DUMMY,
+            );
+            scoping.add_binding(class.scope_id(), &ident.name, new_class_symbol_id);
+            let old_class_symbol_id = ident.symbol_id.replace(Some(new_class_symbol_id));
+            let old_class_symbol_id = old_class_symbol_id.expect("class always has a symbol id");
+            *ctx.scoping_mut().symbol_flags_mut(old_class_symbol_id) =
+                SymbolFlags::BlockScopedVariable;
+            BoundIdentifier::new(ident.name, old_class_symbol_id)
+        });
+        self.class_declaration = Some(DecoratedClassDeclaration { binding, span, decorated_class });
+    }
+
+    /// Transform a statement containing a class declaration with class decorators.
+    ///
+    /// * `class C {}` -> `let C = (class C {}, _C);`
+    /// * `export class C {}` -> `export let C = (class C {}, _C);`
+    /// * `export default class C {}` -> `let C = (class C {}, _C); export default C;`
+    /// * `export default class {}` -> `export default (class {}, _Class);`
+    fn transform_class_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(DecoratedClassDeclaration { binding, span, decorated_class }) =
+            self.class_declaration.take()
+        else {
+            return;
+        };
+
+        match stmt {
+            Statement::ClassDeclaration(class) => {
+                let init = Self::create_decorated_class_expression(class, &decorated_class, ctx);
+                let binding = binding.expect("class declaration always has a binding");
+                let new_stmt = Statement::VariableDeclaration(Self::create_let_declaration(
+                    &binding, span, init, ctx,
+                ));
+                self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+                *stmt = new_stmt;
+            }
+            Statement::ExportNamedDeclaration(export) => {
+                let Some(Declaration::ClassDeclaration(class)) = &mut export.declaration else {
+                    unreachable!()
+                };
+                let init = Self::create_decorated_class_expression(class, &decorated_class, ctx);
+                let binding = binding.expect("class declaration always has a binding");
+                let declaration = Self::create_let_declaration(&binding, span, init, ctx);
+                export.declaration = Some(Declaration::VariableDeclaration(declaration));
+            }
+            Statement::ExportDefaultDeclaration(export) => {
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) = &mut export.declaration
+                else {
+                    unreachable!()
+                };
+                let init = Self::create_decorated_class_expression(class, &decorated_class, ctx);
+                let Some(binding) = binding else {
+                    let Expression::SequenceExpression(init) = init else { unreachable!() };
+                    export.declaration = ExportDefaultDeclarationKind::SequenceExpression(init);
+                    return;
+                };
+                let new_stmt = Statement::VariableDeclaration(Self::create_let_declaration(
+                    &binding, span, init, ctx,
+                ));
+                // `export default C`
+                let export_default =
+                    Statement::from(ctx.ast.module_declaration_export_default_declaration(
+                        SPAN,
+                        ExportDefaultDeclarationKind::Identifier(
+                            ctx.ast.alloc(binding.create_read_reference(ctx)),
+                        ),
+                    ));
+                self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+                self.ctx.statement_injector.insert_after(&new_stmt, export_default);
+                *stmt = new_stmt;
+            }
+            _ => unreachable!("Class declaration with class decorators is always in a statement"),
+        }
+    }
+
+    /// `(class C {}, _C)`
+    fn create_decorated_class_expression(
+        class: &mut ArenaBox<'a, Class<'a>>,
+        decorated_class: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        class.r#type = ClassType::ClassExpression;
+        let class = Expression::ClassExpression(class.take_in_box(ctx.ast));
+        ctx.ast.expression_sequence(
+            SPAN,
+            ctx.ast.vec_from_array([class, decorated_class.create_read_expression(ctx)]),
+        )
+    }
+
+    /// `let C = init`
+    fn create_let_declaration(
+        binding: &BoundIdentifier<'a>,
+        span: Span,
+        init: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ArenaBox<'a, VariableDeclaration<'a>> {
+        let ident =
+            ctx.ast.binding_identifier_with_symbol_id(span, binding.name, binding.symbol_id);
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Let,
+            BindingPattern::BindingIdentifier(ctx.ast.alloc(ident)),
+            NONE,
+            Some(init),
+            false,
+        );
+        ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Let,
+            ctx.ast.vec1(declarator),
+            false,
+        )
+    }
+
+    /// `class {}` -> `(_dec = dec(), class {}, _Class)`
+    fn wrap_class_expression(
+        expr: &mut Expression<'a>,
+        wrapper: ClassExpressionWrapper<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let ClassExpressionWrapper { memos, decorated_class } = wrapper;
+        let mut expressions = ctx.ast.vec_with_capacity(memos.len() + 2);
+        expressions.extend(memos);
+        expressions.push(expr.take_in(ctx.ast));
+        if let Some(decorated_class) = decorated_class {
+            expressions.push(decorated_class.create_read_expression(ctx));
+        }
+        *expr = ctx.ast.expression_sequence(SPAN, expressions);
+    }
+}
+
+/// Visitor to replace references to a class with a reference to the decorated class.
+struct ClassReferenceReplacer<'a, 'c> {
+    class_symbol_id: SymbolId,
+    decorated_class: &'c BoundIdentifier<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'c> ClassReferenceReplacer<'a, 'c> {
+    fn new(
+        class_symbol_id: SymbolId,
+        decorated_class: &'c BoundIdentifier<'a>,
+        ctx: &'c mut TraverseCtx<'a>,
+    ) -> Self {
+        Self { class_symbol_id, decorated_class, ctx }
+    }
+}
+
+impl<'a> VisitMut<'a> for ClassReferenceReplacer<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let reference = self.ctx.scoping().get_reference(reference_id);
+        if reference.symbol_id() != Some(self.class_symbol_id) {
+            return;
+        }
+        let flags = reference.flags();
+        self.ctx.scoping_mut().delete_resolved_reference(self.class_symbol_id, reference_id);
+        *ident = self.decorated_class.create_spanned_reference(ident.span, flags, self.ctx);
+    }
+}
+
+/// Visitor to move the top-level scopes of an expression under a new parent scope.
+struct ChildScopeMover<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+    parent_scope_id: ScopeId,
+    depth: usize,
+}
+
+impl<'a, 'c> ChildScopeMover<'a, 'c> {
+    fn new(parent_scope_id: ScopeId, ctx: &'c mut TraverseCtx<'a>) -> Self {
+        Self { ctx, parent_scope_id, depth: 0 }
+    }
+}
+
+impl<'a> Visit<'a> for ChildScopeMover<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            let scope_id = scope_id.get().unwrap();
+            self.ctx.scoping_mut().change_scope_parent_id(scope_id, Some(self.parent_scope_id));
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+/// Visitor to run initializers after `super()` calls in a constructor.
+///
+/// `super()` -> `_initProto(super())`
+struct SuperCallWrapper<'a, 'c> {
+    initializers: &'c [BoundIdentifier<'a>],
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'c> SuperCallWrapper<'a, 'c> {
+    fn new(initializers: &'c [BoundIdentifier<'a>], ctx: &'c mut TraverseCtx<'a>) -> Self {
+        Self { initializers, ctx }
+    }
+}
+
+impl<'a> VisitMut<'a> for SuperCallWrapper<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_mut::walk_expression(self, expr);
+        if matches!(expr, Expression::CallExpression(call) if call.callee.is_super()) {
+            for initializer in self.initializers {
+                let call = expr.take_in(self.ctx.ast);
+                let callee = initializer.create_read_expression(self.ctx);
+                let arguments = self.ctx.ast.vec1(Argument::from(call));
+                *expr = self.ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            }
+        }
+    }
+
+    // `super()` can only be called in arrow functions, not in nested functions or classes
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
@@ -0,0 +1,1224 @@
+//! ES2015: Block Scoping - Loops
+//!
+//! Each iteration of a loop gets fresh bindings for `let` and `const` declarations in the loop's head
+//! and body. When these bindings are converted to `var`, all iterations share one binding.
+//! This changes behavior if a closure in the loop body captures the binding.
+//!
+//! To preserve behavior, the loop body is moved into a function, which is called on each iteration.
+//! Bindings from loop head are passed to the function as arguments.
+//!
+//! ```js
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(() => i);
+//!   if (i === 1) break;
+//!   i++;
+//! }
+//! ```
+//! ->
+//! ```js
+//! var _loop = function (_i) {
+//!   fns.push(() => _i);
+//!   if (_i === 1) {
+//!     i = _i;
+//!     return 0;
+//!   }
+//!   _i++;
+//!   i = _i;
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   var _ret = _loop(i);
+//!   if (_ret === 0) break;
+//! }
+//! ```
+//!
+//! Within the loop body:
+//! * `break` and `continue` statements which target the loop (or a loop outside it),
+//!   and `return` statements are converted to `return` from the function, and the result is checked
+//!   after the function call.
+//! * `this` and `arguments` are replaced with references to temp vars.
+//! * `var` declarations are hoisted out of the function.
+//! * If a binding from loop head is updated, the updated value is written back to the binding
+//!   before the function returns.
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{Address, GetAddress, TakeIn};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::operator::BinaryOperator;
+use oxc_traverse::{Ancestor, BoundIdentifier};
+
+use crate::{ArenaVec, context::TraverseCtx};
+
+use super::BlockScoping;
+
+/// Loop whose body is being converted to a closure.
+pub(super) struct LoopClosure<'a> {
+    /// Address of the loop statement.
+    pub(super) address: Address,
+    /// Scope of the `_loop` function.
+    scope_id: ScopeId,
+    /// Bindings declared in loop head which are captured by closures in loop body.
+    /// `bool` is `true` if binding is updated in loop body.
+    captured_head_bindings: Vec<(BoundIdentifier<'a>, bool)>,
+}
+
+/// `_loop` declaration which needs to be inserted before a labeled statement,
+/// which is not in a statement list.
+pub(super) struct PendingLabeledLoop<'a> {
+    /// Address of the outermost labeled statement.
+    address: Address,
+    declaration: Statement<'a>,
+    /// Scope of the `_loop` function.
+    scope_id: ScopeId,
+}
+
+/// Completion of the loop body, which has to be performed after `_loop` function returns.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Completion<'a> {
+    /// `break` / `break label`
+    Break(Option<Atom<'a>>),
+    /// `continue label`, where label is outside the loop
+    Continue(Atom<'a>),
+}
+
+impl<'a> BlockScoping<'a, '_> {
+    /// Determine if loop body needs to be converted to a closure, and prepare for it.
+    ///
+    /// Performed on entering the loop, so that declarations in the loop body are moved to
+    /// the scope of the `_loop` function, rather than the enclosing function.
+    pub(super) fn enter_loop(&mut self, stmt: &Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let (head, body, loop_scope_id) = match stmt {
+            Statement::ForStatement(for_stmt) => {
+                let head = match &for_stmt.init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => Some(&**decl),
+                    _ => None,
+                };
+                (head, &for_stmt.body, Some(for_stmt.scope_id()))
+            }
+            Statement::ForInStatement(for_stmt) => {
+                let head = match &for_stmt.left {
+                    ForStatementLeft::VariableDeclaration(decl) => Some(&**decl),
+                    _ => None,
+                };
+                (head, &for_stmt.body, Some(for_stmt.scope_id()))
+            }
+            Statement::ForOfStatement(for_stmt) => {
+                let head = match &for_stmt.left {
+                    ForStatementLeft::VariableDeclaration(decl) => Some(&**decl),
+                    _ => None,
+                };
+                (head, &for_stmt.body, Some(for_stmt.scope_id()))
+            }
+            Statement::WhileStatement(while_stmt) => (None, &while_stmt.body, None),
+            Statement::DoWhileStatement(do_while_stmt) => (None, &do_while_stmt.body, None),
+            _ => return,
+        };
+
+        // Collect bindings which are created anew on each iteration
+        let mut head_bindings = vec![];
+        if let Some(decl) = head
+            && decl.kind.is_lexical()
+        {
+            decl.bound_names(&mut |ident| {
+                head_bindings.push(BoundIdentifier::from_binding_ident(ident));
+            });
+        }
+
+        let scoping = ctx.scoping();
+        let mut bindings =
+            head_bindings.iter().map(|binding| binding.symbol_id).collect::<FxHashSet<_>>();
+        let mut scopes_collector = LoopBodyScopesCollector::default();
+        scopes_collector.visit_statement(body);
+        let block_scoped_flags = if self.transform_class_declarations {
+            SymbolFlags::BlockScopedVariable | SymbolFlags::Class
+        } else {
+            SymbolFlags::BlockScopedVariable
+        };
+        for scope_id in scopes_collector.scope_ids {
+            bindings.extend(scoping.iter_bindings_in(scope_id).filter(|&symbol_id| {
+                scoping.symbol_flags(symbol_id).intersects(block_scoped_flags)
+            }));
+        }
+        if bindings.is_empty() {
+            return;
+        }
+
+        let mut capture_finder = ClosureCaptureFinder::new(&bindings, scoping);
+        capture_finder.visit_statement(body);
+        if capture_finder.captured.is_empty() {
+            return;
+        }
+
+        if self.options.throw_if_closure_required {
+            self.ctx.error(
+                OxcDiagnostic::error(
+                    "Compiling let/const in this block would add a closure (throwIfClosureRequired).",
+                )
+                .with_label(stmt.span()),
+            );
+            return;
+        }
+
+        let captured_head_bindings = head_bindings
+            .into_iter()
+            .filter(|binding| capture_finder.captured.contains(&binding.symbol_id))
+            .map(|binding| {
+                let is_updated = capture_finder.updated.contains(&binding.symbol_id);
+                (binding, is_updated)
+            })
+            .collect();
+
+        // Convert loop body's scope to the scope of `_loop` function.
+        // `_loop` function is inserted before the loop, so its scope is a child of current scope.
+        let parent_scope_id = ctx.current_scope_id();
+        let scope_id = if let Statement::BlockStatement(block) = body {
+            let scope_id = block.scope_id();
+            let scoping = ctx.scoping_mut();
+            let flags = scoping.scope_flags_mut(scope_id);
+            *flags = (*flags & ScopeFlags::StrictMode) | ScopeFlags::Function;
+            scoping.change_scope_parent_id(scope_id, Some(parent_scope_id));
+            scope_id
+        } else {
+            let scope_id = ctx.insert_scope_below_statement_from_scope_id(
+                body,
+                loop_scope_id.unwrap_or(parent_scope_id),
+                ScopeFlags::Function,
+            );
+            ctx.scoping_mut().change_scope_parent_id(scope_id, Some(parent_scope_id));
+            scope_id
+        };
+
+        self.loops.push(LoopClosure { address: stmt.address(), scope_id, captured_head_bindings });
+    }
+
+    /// Move loop body into `_loop` function, and insert `_loop` declaration before the loop.
+    pub(super) fn exit_loop(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.loops.last().is_none_or(|loop_closure| loop_closure.address != stmt.address()) {
+            return;
+        }
+        let LoopClosure { scope_id, captured_head_bindings, .. } = self.loops.pop().unwrap();
+
+        let outer_scope_id = ctx.current_scope_id();
+        let outer_var_scope_id = Self::var_scope_id(outer_scope_id, ctx);
+        let enclosing_function = EnclosingFunction::new(ctx);
+        let labels = ctx
+            .ancestors()
+            .map_while(|ancestor| match ancestor {
+                Ancestor::LabeledStatementBody(labeled) => Some(labeled.label().name),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Create params for `_loop` function.
+        // Updated bindings come first, to match Babel.
+        let mut params = ctx.ast.vec();
+        let mut arguments = ctx.ast.vec();
+        let mut replacements = vec![];
+        let mut updates = vec![];
+        for is_updated in [true, false] {
+            for (binding, _) in captured_head_bindings.iter().filter(|(_, u)| *u == is_updated) {
+                let param = if is_updated {
+                    ctx.generate_uid(&binding.name, scope_id, SymbolFlags::FunctionScopedVariable)
+                } else {
+                    ctx.generate_binding(
+                        binding.name,
+                        scope_id,
+                        SymbolFlags::FunctionScopedVariable,
+                    )
+                };
+                params
+                    .push(ctx.ast.plain_formal_parameter(SPAN, param.create_binding_pattern(ctx)));
+                arguments.push(Argument::from(binding.create_read_expression(ctx)));
+                if is_updated {
+                    updates.push((binding.clone(), param.clone()));
+                }
+                replacements.push((binding.symbol_id, param));
+            }
+        }
+
+        let (body, body_parent_scope_id) = match stmt {
+            Statement::ForStatement(for_stmt) => {
+                let scope_id = for_stmt.scope_id();
+                (&mut for_stmt.body, scope_id)
+            }
+            Statement::ForInStatement(for_stmt) => {
+                let scope_id = for_stmt.scope_id();
+                (&mut for_stmt.body, scope_id)
+            }
+            Statement::ForOfStatement(for_stmt) => {
+                let scope_id = for_stmt.scope_id();
+                (&mut for_stmt.body, scope_id)
+            }
+            Statement::WhileStatement(while_stmt) => (&mut while_stmt.body, outer_scope_id),
+            Statement::DoWhileStatement(do_while_stmt) => (&mut do_while_stmt.body, outer_scope_id),
+            _ => unreachable!(),
+        };
+
+        let mut statements = match body.take_in(ctx.ast) {
+            Statement::BlockStatement(block) => block.unbox().body,
+            body => ctx.ast.vec1(body),
+        };
+
+        let mut body_transformer = LoopBodyTransformer::new(
+            scope_id,
+            outer_var_scope_id,
+            &enclosing_function,
+            replacements,
+            updates,
+            labels,
+            ctx,
+        );
+        body_transformer.visit_statements(&mut statements);
+        if let Some(updater) = body_transformer.create_updater() {
+            statements.push(updater);
+        }
+        let LoopBodyTransformer {
+            this_binding,
+            arguments_binding,
+            hoisted_vars,
+            completions,
+            has_return,
+            has_yield,
+            has_await,
+            ..
+        } = body_transformer;
+
+        // `var _loop = function (i) { ... }`
+        let is_generator = enclosing_function.is_generator && has_yield;
+        let is_async = enclosing_function.is_async && has_await;
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+        let function_body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
+        let function = ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            is_generator,
+            is_async,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(function_body),
+            scope_id,
+        );
+        let loop_binding =
+            ctx.generate_uid("loop", outer_var_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        let mut declarators = ctx.ast.vec();
+        if let Some(this_binding) = this_binding {
+            declarators.push(Self::create_var_declarator(
+                &this_binding,
+                Some(ctx.ast.expression_this(SPAN)),
+                ctx,
+            ));
+        }
+        if let Some(arguments_binding) = arguments_binding {
+            let arguments =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("arguments"), ReferenceFlags::Read);
+            declarators.push(Self::create_var_declarator(&arguments_binding, Some(arguments), ctx));
+        }
+        declarators.push(Self::create_var_declarator(
+            &loop_binding,
+            Some(Expression::FunctionExpression(function)),
+            ctx,
+        ));
+        for ident in hoisted_vars {
+            declarators.push(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                BindingPattern::BindingIdentifier(ctx.ast.alloc(ident)),
+                NONE,
+                None,
+                false,
+            ));
+        }
+        let declaration = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarators,
+            false,
+        ));
+
+        // Replace loop body with call to `_loop` function
+        let call = ctx.ast.expression_call(
+            SPAN,
+            loop_binding.create_read_expression(ctx),
+            NONE,
+            arguments,
+            false,
+        );
+        let call = if is_generator {
+            ctx.ast.expression_yield(SPAN, true, Some(call))
+        } else if is_async {
+            ctx.ast.expression_await(SPAN, call)
+        } else {
+            call
+        };
+        let body_stmts = if completions.is_empty() && !has_return {
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call))
+        } else {
+            let ret_binding =
+                ctx.generate_uid("ret", outer_var_scope_id, SymbolFlags::FunctionScopedVariable);
+            let mut body_stmts = ctx.ast.vec_with_capacity(completions.len() + 2);
+            // `var _ret = _loop(i);`
+            let declarator = Self::create_var_declarator(&ret_binding, Some(call), ctx);
+            body_stmts.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            )));
+            // `if (_ret === 0) break;`
+            for (index, completion) in completions.iter().enumerate() {
+                #[expect(clippy::cast_precision_loss)]
+                let value = ctx.ast.expression_numeric_literal(
+                    SPAN,
+                    index as f64,
+                    None,
+                    NumberBase::Decimal,
+                );
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    ret_binding.create_read_expression(ctx),
+                    BinaryOperator::StrictEquality,
+                    value,
+                );
+                let consequent = match completion {
+                    Completion::Break(label) => ctx.ast.statement_break(
+                        SPAN,
+                        label.map(|label| ctx.ast.label_identifier(SPAN, label)),
+                    ),
+                    Completion::Continue(label) => ctx
+                        .ast
+                        .statement_continue(SPAN, Some(ctx.ast.label_identifier(SPAN, *label))),
+                };
+                body_stmts.push(ctx.ast.statement_if(SPAN, test, consequent, None));
+            }
+            // `if (_ret) return _ret.v;`
+            if has_return {
+                let value = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    ret_binding.create_read_expression(ctx),
+                    ctx.ast.identifier_name(SPAN, "v"),
+                    false,
+                ));
+                let consequent = ctx.ast.statement_return(SPAN, Some(value));
+                body_stmts.push(ctx.ast.statement_if(
+                    SPAN,
+                    ret_binding.create_read_expression(ctx),
+                    consequent,
+                    None,
+                ));
+            }
+            body_stmts
+        };
+        let body_scope_id = ctx.create_child_scope(body_parent_scope_id, ScopeFlags::empty());
+        *body = ctx.ast.statement_block_with_scope_id(SPAN, body_stmts, body_scope_id);
+
+        self.insert_loop_declaration(stmt, declaration, scope_id, ctx);
+    }
+
+    /// Insert `_loop` declaration before the loop, or before the outermost label of a labeled loop.
+    fn insert_loop_declaration(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        declaration: Statement<'a>,
+        function_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut labeled_address = None;
+        let mut in_statement_list = false;
+        for ancestor in ctx.ancestors() {
+            if let Ancestor::LabeledStatementBody(labeled) = ancestor {
+                labeled_address = Some(labeled.address());
+                continue;
+            }
+            in_statement_list = matches!(
+                ancestor,
+                Ancestor::ProgramBody(_)
+                    | Ancestor::BlockStatementBody(_)
+                    | Ancestor::FunctionBodyStatements(_)
+                    | Ancestor::StaticBlockBody(_)
+                    | Ancestor::SwitchCaseConsequent(_)
+                    | Ancestor::TSModuleBlockBody(_)
+            );
+            break;
+        }
+
+        match (in_statement_list, labeled_address) {
+            (true, Some(address)) => {
+                self.ctx.statement_injector.insert_before(&address, declaration);
+            }
+            (true, None) => self.ctx.statement_injector.insert_before(stmt, declaration),
+            // `if (x) for (let i of y) ...` -> `if (x) { var _loop = ...; for (var i of y) ... }`
+            (false, None) => Self::wrap_in_block(stmt, declaration, function_scope_id, ctx),
+            // Labeled statement is wrapped on exit
+            (false, Some(address)) => self.pending_labeled_loops.push(PendingLabeledLoop {
+                address,
+                declaration,
+                scope_id: function_scope_id,
+            }),
+        }
+    }
+
+    pub(super) fn exit_labeled_statement(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.pending_labeled_loops.last().is_none_or(|pending| pending.address != stmt.address())
+        {
+            return;
+        }
+        let PendingLabeledLoop { declaration, scope_id, .. } =
+            self.pending_labeled_loops.pop().unwrap();
+        Self::wrap_in_block(stmt, declaration, scope_id, ctx);
+    }
+
+    /// `stmt` -> `{ declaration; stmt }`
+    fn wrap_in_block(
+        stmt: &mut Statement<'a>,
+        declaration: Statement<'a>,
+        function_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let block_scope_id = ctx.insert_scope_below_statement(stmt, ScopeFlags::empty());
+        ctx.scoping_mut().change_scope_parent_id(function_scope_id, Some(block_scope_id));
+        let stmts = ctx.ast.vec_from_array([declaration, stmt.take_in(ctx.ast)]);
+        *stmt = ctx.ast.statement_block_with_scope_id(SPAN, stmts, block_scope_id);
+    }
+
+    fn create_var_declarator(
+        binding: &BoundIdentifier<'a>,
+        init: Option<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(ctx),
+            NONE,
+            init,
+            false,
+        )
+    }
+}
+
+/// Details of the function which contains the loop.
+struct EnclosingFunction {
+    is_async: bool,
+    is_generator: bool,
+    /// `true` if loop is within a non-arrow function, so `arguments` refers to its arguments
+    has_arguments: bool,
+}
+
+impl EnclosingFunction {
+    fn new(ctx: &TraverseCtx<'_>) -> Self {
+        let mut is_async_or_generator = None;
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::FunctionBody(func) => {
+                    let (is_async, is_generator) =
+                        is_async_or_generator.unwrap_or((*func.r#async(), *func.generator()));
+                    return Self { is_async, is_generator, has_arguments: true };
+                }
+                Ancestor::ArrowFunctionExpressionBody(arrow) => {
+                    is_async_or_generator.get_or_insert((*arrow.r#async(), false));
+                }
+                Ancestor::StaticBlockBody(_) | Ancestor::PropertyDefinitionValue(_) => {
+                    let (is_async, is_generator) = is_async_or_generator.unwrap_or_default();
+                    return Self { is_async, is_generator, has_arguments: false };
+                }
+                _ => {}
+            }
+        }
+        let (is_async, is_generator) = is_async_or_generator.unwrap_or_default();
+        Self { is_async, is_generator, has_arguments: false }
+    }
+}
+
+/// Visitor to collect scopes in a loop body whose bindings are created anew on each iteration.
+///
+/// Does not enter functions, classes, expressions or nested loops.
+#[derive(Default)]
+struct LoopBodyScopesCollector {
+    scope_ids: Vec<ScopeId>,
+}
+
+impl<'a> Visit<'a> for LoopBodyScopesCollector {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_ids.push(scope_id.get().unwrap());
+    }
+
+    fn visit_expression(&mut self, _expr: &Expression<'a>) {}
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+
+    fn visit_for_statement(&mut self, _stmt: &ForStatement<'a>) {}
+
+    fn visit_for_in_statement(&mut self, _stmt: &ForInStatement<'a>) {}
+
+    fn visit_for_of_statement(&mut self, _stmt: &ForOfStatement<'a>) {}
+
+    fn visit_while_statement(&mut self, _stmt: &WhileStatement<'a>) {}
+
+    fn visit_do_while_statement(&mut self, _stmt: &DoWhileStatement<'a>) {}
+}
+
+/// Visitor to find bindings which are referenced in closures within a loop body,
+/// and bindings which are written to in the loop body.
+struct ClosureCaptureFinder<'v> {
+    bindings: &'v FxHashSet<SymbolId>,
+    scoping: &'v Scoping,
+    closure_depth: u32,
+    captured: FxHashSet<SymbolId>,
+    updated: FxHashSet<SymbolId>,
+}
+
+impl<'v> ClosureCaptureFinder<'v> {
+    fn new(bindings: &'v FxHashSet<SymbolId>, scoping: &'v Scoping) -> Self {
+        Self {
+            bindings,
+            scoping,
+            closure_depth: 0,
+            captured: FxHashSet::default(),
+            updated: FxHashSet::default(),
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ClosureCaptureFinder<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.scoping.get_reference(ident.reference_id());
+        if let Some(symbol_id) = reference.symbol_id()
+            && self.bindings.contains(&symbol_id)
+        {
+            if self.closure_depth > 0 {
+                self.captured.insert(symbol_id);
+            }
+            if reference.is_write() {
+                self.updated.insert(symbol_id);
+            }
+        }
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.closure_depth += 1;
+        walk::walk_function(self, func, flags);
+        self.closure_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.closure_depth += 1;
+        walk::walk_arrow_function_expression(self, arrow);
+        self.closure_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.closure_depth += 1;
+        walk::walk_class(self, class);
+        self.closure_depth -= 1;
+    }
+}
+
+/// Visitor to transform loop body into body of `_loop` function.
+struct LoopBodyTransformer<'a, 'v> {
+    /// Scope of `_loop` function
+    function_scope_id: ScopeId,
+    /// Function scope which `_loop` function is declared in
+    outer_var_scope_id: ScopeId,
+    has_arguments: bool,
+    /// Bindings from loop head, and the `_loop` function params which replace them
+    replacements: Vec<(SymbolId, BoundIdentifier<'a>)>,
+    /// Bindings from loop head which are updated in loop body, and the params which replace them
+    updates: Vec<(BoundIdentifier<'a>, BoundIdentifier<'a>)>,
+    /// Labels of the loop
+    loop_labels: Vec<Atom<'a>>,
+    ctx: &'v mut TraverseCtx<'a>,
+
+    // ----- Output -----
+    this_binding: Option<BoundIdentifier<'a>>,
+    arguments_binding: Option<BoundIdentifier<'a>>,
+    /// `var` declarations which are hoisted out of `_loop` function
+    hoisted_vars: Vec<BindingIdentifier<'a>>,
+    completions: Vec<Completion<'a>>,
+    has_return: bool,
+    has_yield: bool,
+    has_await: bool,
+
+    // ----- State -----
+    /// Depth of functions and classes, where `this` differs from `this` in loop body
+    function_depth: u32,
+    /// Depth of arrow functions
+    arrow_depth: u32,
+    /// Depth of nested loops
+    loop_depth: u32,
+    /// Depth of nested `switch` statements
+    switch_depth: u32,
+    /// Labels of labeled statements within loop body
+    inner_labels: Vec<Atom<'a>>,
+    scope_ids: Vec<ScopeId>,
+}
+
+impl<'a, 'v> LoopBodyTransformer<'a, 'v> {
+    fn new(
+        function_scope_id: ScopeId,
+        outer_var_scope_id: ScopeId,
+        enclosing_function: &EnclosingFunction,
+        replacements: Vec<(SymbolId, BoundIdentifier<'a>)>,
+        updates: Vec<(BoundIdentifier<'a>, BoundIdentifier<'a>)>,
+        loop_labels: Vec<Atom<'a>>,
+        ctx: &'v mut TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            function_scope_id,
+            outer_var_scope_id,
+            has_arguments: enclosing_function.has_arguments,
+            replacements,
+            updates,
+            loop_labels,
+            ctx,
+            this_binding: None,
+            arguments_binding: None,
+            hoisted_vars: vec![],
+            completions: vec![],
+            has_return: false,
+            has_yield: false,
+            has_await: false,
+            function_depth: 0,
+            arrow_depth: 0,
+            loop_depth: 0,
+            switch_depth: 0,
+            inner_labels: vec![],
+            scope_ids: vec![function_scope_id],
+        }
+    }
+
+    fn is_in_closure(&self) -> bool {
+        self.function_depth > 0 || self.arrow_depth > 0
+    }
+
+    /// `i = _i`, to write back updated values of bindings from loop head.
+    fn create_updater(&mut self) -> Option<Statement<'a>> {
+        if self.updates.is_empty() {
+            return None;
+        }
+        let ctx = &mut *self.ctx;
+        let mut expressions = ctx.ast.vec_from_iter(self.updates.iter().map(|(outer, inner)| {
+            ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                outer.create_write_target(ctx),
+                inner.create_read_expression(ctx),
+            )
+        }));
+        let expression = if expressions.len() == 1 {
+            expressions.pop().unwrap()
+        } else {
+            ctx.ast.expression_sequence(SPAN, expressions)
+        };
+        Some(ctx.ast.statement_expression(SPAN, expression))
+    }
+
+    /// Convert `break`, `continue` or `return` statement which exits the loop body
+    /// to a `return` from `_loop` function.
+    ///
+    /// Returns `None` if statement is not one of these, or does not exit the loop body.
+    fn transform_completion(&mut self, stmt: &mut Statement<'a>) -> Option<Statement<'a>> {
+        let argument = match stmt {
+            Statement::BreakStatement(break_stmt) => {
+                let label = break_stmt.label.as_ref().map(|label| label.name);
+                match label {
+                    Some(label) if self.inner_labels.contains(&label) => return None,
+                    None if self.loop_depth > 0 || self.switch_depth > 0 => return None,
+                    _ => {}
+                }
+                Some(self.create_completion(Completion::Break(label)))
+            }
+            Statement::ContinueStatement(continue_stmt) => {
+                match continue_stmt.label.as_ref().map(|label| label.name) {
+                    Some(label) if self.inner_labels.contains(&label) => return None,
+                    // `continue` of this loop -> `return;`
+                    Some(label) if self.loop_labels.contains(&label) => None,
+                    Some(label) => Some(self.create_completion(Completion::Continue(label))),
+                    None if self.loop_depth > 0 => return None,
+                    None => None,
+                }
+            }
+            // `return x` -> `return { v: x }`
+            Statement::ReturnStatement(return_stmt) => {
+                self.has_return = true;
+                let value = match &mut return_stmt.argument {
+                    Some(argument) => {
+                        self.visit_expression(argument);
+                        argument.take_in(self.ctx.ast)
+                    }
+                    None => self.ctx.ast.void_0(SPAN),
+                };
+                let ast = self.ctx.ast;
+                let property = ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    ast.property_key_static_identifier(SPAN, "v"),
+                    value,
+                    false,
+                    false,
+                    false,
+                );
+                Some(ast.expression_object(SPAN, ast.vec1(property)))
+            }
+            _ => return None,
+        };
+        Some(self.ctx.ast.statement_return(stmt.span(), argument))
+    }
+
+    /// Get numeric ID for completion.
+    fn create_completion(&mut self, completion: Completion<'a>) -> Expression<'a> {
+        let index = self.completions.iter().position(|c| *c == completion).unwrap_or_else(|| {
+            self.completions.push(completion);
+            self.completions.len() - 1
+        });
+        #[expect(clippy::cast_precision_loss)]
+        self.ctx.ast.expression_numeric_literal(SPAN, index as f64, None, NumberBase::Decimal)
+    }
+
+    /// Check if `var` declaration is in the enclosing function, and needs to be hoisted
+    /// out of `_loop` function.
+    fn is_hoisted_var(&self, decl: &VariableDeclaration<'a>) -> bool {
+        if decl.kind != VariableDeclarationKind::Var || decl.declare || self.is_in_closure() {
+            return false;
+        }
+        let mut is_hoisted = false;
+        decl.bound_names(&mut |ident| {
+            is_hoisted |=
+                self.ctx.scoping().symbol_scope_id(ident.symbol_id()) != self.function_scope_id;
+        });
+        is_hoisted
+    }
+
+    /// Record binding of a `var` declaration, to be declared outside `_loop` function.
+    fn hoist_var(&mut self, ident: &BindingIdentifier<'a>) {
+        let symbol_id = ident.symbol_id();
+        if !self.hoisted_vars.iter().any(|hoisted| hoisted.symbol_id() == symbol_id) {
+            self.hoisted_vars.push(
+                self.ctx.ast.binding_identifier_with_symbol_id(ident.span, ident.name, symbol_id),
+            );
+        }
+    }
+
+    /// `var x = 1, y` -> `x = 1`. `x` and `y` are hoisted out of `_loop` function.
+    ///
+    /// Returns `None` if no declarators have an initializer.
+    fn transform_hoisted_var(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut expressions = self.ctx.ast.vec();
+        for declarator in decl.declarations.take_in(self.ctx.ast) {
+            declarator.id.bound_names(&mut |ident| self.hoist_var(ident));
+            if let Some(mut init) = declarator.init {
+                self.visit_expression(&mut init);
+                let mut target = binding_pattern_to_assignment_target(declarator.id, self.ctx);
+                self.visit_assignment_target(&mut target);
+                expressions.push(self.ctx.ast.expression_assignment(
+                    declarator.span,
+                    AssignmentOperator::Assign,
+                    target,
+                    init,
+                ));
+            }
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, expressions)),
+        }
+    }
+
+    /// `var x` in head of `for (var x in obj)` -> `x`
+    fn transform_hoisted_for_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        let ForStatementLeft::VariableDeclaration(decl) = left else { return };
+        if !self.is_hoisted_var(decl) {
+            return;
+        }
+        let Some(declarator) = decl.declarations.first_mut() else { return };
+        declarator.id.bound_names(&mut |ident| self.hoist_var(ident));
+        let pattern = declarator.id.take_in(self.ctx.ast);
+        *left = ForStatementLeft::from(binding_pattern_to_assignment_target(pattern, self.ctx));
+    }
+
+    /// Replace `this` with `_this`, and `arguments` with `_arguments`.
+    fn transform_this_or_arguments(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(this) => {
+                let span = this.span;
+                let binding = self.this_binding.get_or_insert_with(|| {
+                    self.ctx.generate_uid(
+                        "this",
+                        self.outer_var_scope_id,
+                        SymbolFlags::FunctionScopedVariable,
+                    )
+                });
+                *expr = binding.create_spanned_read_expression(span, self.ctx);
+            }
+            Expression::Identifier(ident)
+                if self.has_arguments
+                    && ident.name == "arguments"
+                    && self
+                        .ctx
+                        .scoping()
+                        .get_reference(ident.reference_id())
+                        .symbol_id()
+                        .is_none() =>
+            {
+                let span = ident.span;
+                self.ctx.delete_reference_for_identifier(ident);
+                let binding = self.arguments_binding.get_or_insert_with(|| {
+                    self.ctx.generate_uid(
+                        "arguments",
+                        self.outer_var_scope_id,
+                        SymbolFlags::FunctionScopedVariable,
+                    )
+                });
+                *expr = binding.create_spanned_read_expression(span, self.ctx);
+            }
+            _ => {}
+        }
+    }
+
+    /// Wrap statements in a block, if more than one.
+    fn create_statement(&mut self, mut stmts: Vec<Statement<'a>>) -> Statement<'a> {
+        if stmts.len() == 1 {
+            return stmts.pop().unwrap();
+        }
+        if stmts.is_empty() {
+            return self.ctx.ast.statement_empty(SPAN);
+        }
+        let parent_scope_id = *self.scope_ids.last().unwrap();
+        let scope_id = self.ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let stmts = self.ctx.ast.vec_from_iter(stmts);
+        self.ctx.ast.statement_block_with_scope_id(SPAN, stmts, scope_id)
+    }
+
+    /// Transform a statement in loop body which may need replacing with other statements.
+    ///
+    /// Returns `None` if statement is not replaced.
+    fn transform_statement(&mut self, stmt: &mut Statement<'a>) -> Option<Vec<Statement<'a>>> {
+        if self.is_in_closure() {
+            return None;
+        }
+        if let Some(return_stmt) = self.transform_completion(stmt) {
+            let mut stmts = Vec::with_capacity(2);
+            stmts.extend(self.create_updater());
+            stmts.push(return_stmt);
+            return Some(stmts);
+        }
+        if let Statement::VariableDeclaration(decl) = stmt
+            && self.is_hoisted_var(decl)
+        {
+            let expr = self.transform_hoisted_var(decl);
+            return Some(
+                expr.map(|expr| self.ctx.ast.statement_expression(decl.span, expr))
+                    .into_iter()
+                    .collect(),
+            );
+        }
+        None
+    }
+}
+
+impl<'a> VisitMut<'a> for LoopBodyTransformer<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_ids.push(scope_id.get().unwrap());
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_ids.pop();
+    }
+
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        if self.is_in_closure() {
+            walk_mut::walk_statements(self, stmts);
+            return;
+        }
+
+        let mut new_stmts = self.ctx.ast.vec_with_capacity(stmts.len());
+        for mut stmt in stmts.take_in(self.ctx.ast) {
+            if let Some(replacements) = self.transform_statement(&mut stmt) {
+                new_stmts.extend(replacements);
+            } else {
+                self.visit_statement(&mut stmt);
+                new_stmts.push(stmt);
+            }
+        }
+        *stmts = new_stmts;
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if let Some(replacements) = self.transform_statement(stmt) {
+            *stmt = self.create_statement(replacements);
+            return;
+        }
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if self.function_depth == 0 {
+            self.transform_this_or_arguments(expr);
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let Some(symbol_id) = self.ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let Some((_, param)) = self.replacements.iter().find(|(id, _)| *id == symbol_id) else {
+            return;
+        };
+        ident.name = param.name;
+        let scoping = self.ctx.scoping_mut();
+        scoping.delete_resolved_reference(symbol_id, reference_id);
+        scoping.get_reference_mut(reference_id).set_symbol_id(param.symbol_id);
+        scoping.add_resolved_reference(param.symbol_id, reference_id);
+    }
+
+    /// `({ i } = obj)` -> `({ i: _i } = obj)`, if `i` is replaced with `_i`.
+    fn visit_assignment_target_property(&mut self, property: &mut AssignmentTargetProperty<'a>) {
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) = property {
+            let name = prop.binding.name;
+            self.visit_identifier_reference(&mut prop.binding);
+            if let Some(init) = &mut prop.init {
+                self.visit_expression(init);
+            }
+            if prop.binding.name != name {
+                let prop = prop.take_in(self.ctx.ast);
+                let ast = self.ctx.ast;
+                let key = ast.property_key_static_identifier(prop.span, name);
+                let target = AssignmentTarget::AssignmentTargetIdentifier(ast.alloc(prop.binding));
+                let binding = match prop.init {
+                    Some(init) => ast
+                        .assignment_target_maybe_default_assignment_target_with_default(
+                            prop.span, target, init,
+                        ),
+                    None => AssignmentTargetMaybeDefault::from(target),
+                };
+                *property = ast.assignment_target_property_assignment_target_property_property(
+                    prop.span, key, binding, false,
+                );
+            }
+            return;
+        }
+        walk_mut::walk_assignment_target_property(self, property);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init
+            && self.is_hoisted_var(decl)
+        {
+            stmt.init = self.transform_hoisted_var(decl).map(ForStatementInit::from);
+        }
+        self.loop_depth += 1;
+        walk_mut::walk_for_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        self.transform_hoisted_for_left(&mut stmt.left);
+        self.loop_depth += 1;
+        walk_mut::walk_for_in_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        if stmt.r#await && !self.is_in_closure() {
+            self.has_await = true;
+        }
+        self.transform_hoisted_for_left(&mut stmt.left);
+        self.loop_depth += 1;
+        walk_mut::walk_for_of_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_while_statement(&mut self, stmt: &mut WhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &mut DoWhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_do_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &mut SwitchStatement<'a>) {
+        self.switch_depth += 1;
+        walk_mut::walk_switch_statement(self, stmt);
+        self.switch_depth -= 1;
+    }
+
+    fn visit_labeled_statement(&mut self, stmt: &mut LabeledStatement<'a>) {
+        self.inner_labels.push(stmt.label.name);
+        walk_mut::walk_labeled_statement(self, stmt);
+        self.inner_labels.pop();
+    }
+
+    fn visit_await_expression(&mut self, expr: &mut AwaitExpression<'a>) {
+        if !self.is_in_closure() {
+            self.has_await = true;
+        }
+        walk_mut::walk_await_expression(self, expr);
+    }
+
+    fn visit_yield_expression(&mut self, expr: &mut YieldExpression<'a>) {
+        if !self.is_in_closure() {
+            self.has_yield = true;
+        }
+        walk_mut::walk_yield_expression(self, expr);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_class(self, class);
+        self.function_depth -= 1;
+    }
+}
+
+/// Convert `BindingPattern` to `AssignmentTarget`.
+///
+/// `{ a, b: [c = 1] }` -> `{ a, b: [c = 1] }`, with binding identifiers converted to references.
+pub fn binding_pattern_to_assignment_target<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTarget<'a> {
+    let ast = ctx.ast;
+    match pattern {
+        BindingPattern::BindingIdentifier(ident) => {
+            BoundIdentifier::from_binding_ident(&ident).create_spanned_write_target(ident.span, ctx)
+        }
+        BindingPattern::ObjectPattern(pattern) => {
+            let pattern = pattern.unbox();
+            let properties = ast.vec_from_iter(
+                pattern
+                    .properties
+                    .into_iter()
+                    .map(|property| binding_property_to_assignment_target_property(property, ctx)),
+            );
+            let rest = pattern.rest.map(|rest| {
+                let rest = rest.unbox();
+                let target = binding_pattern_to_assignment_target(rest.argument, ctx);
+                ast.alloc_assignment_target_rest(rest.span, target)
+            });
+            AssignmentTarget::ObjectAssignmentTarget(ast.alloc_object_assignment_target(
+                pattern.span,
+                properties,
+                rest,
+            ))
+        }
+        BindingPattern::ArrayPattern(pattern) => {
+            let pattern = pattern.unbox();
+            let elements = ast.vec_from_iter(pattern.elements.into_iter().map(|element| {
+                element
+                    .map(|element| binding_pattern_to_assignment_target_maybe_default(element, ctx))
+            }));
+            let rest = pattern.rest.map(|rest| {
+                let rest = rest.unbox();
+                let target = binding_pattern_to_assignment_target(rest.argument, ctx);
+                ast.alloc_assignment_target_rest(rest.span, target)
+            });
+            AssignmentTarget::ArrayAssignmentTarget(ast.alloc_array_assignment_target(
+                pattern.span,
+                elements,
+                rest,
+            ))
+        }
+        BindingPattern::AssignmentPattern(pattern) => {
+            binding_pattern_to_assignment_target(pattern.unbox().left, ctx)
+        }
+    }
+}
+
+fn binding_pattern_to_assignment_target_maybe_default<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTargetMaybeDefault<'a> {
+    if let BindingPattern::AssignmentPattern(pattern) = pattern {
+        let pattern = pattern.unbox();
+        let target = binding_pattern_to_assignment_target(pattern.left, ctx);
+        ctx.ast.assignment_target_maybe_default_assignment_target_with_default(
+            pattern.span,
+            target,
+            pattern.right,
+        )
+    } else {
+        AssignmentTargetMaybeDefault::from(binding_pattern_to_assignment_target(pattern, ctx))
+    }
+}
+
+fn binding_property_to_assignment_target_property<'a>(
+    property: BindingProperty<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTargetProperty<'a> {
+    if property.shorthand {
+        // `{ x }`, `{ x = 1 }`
+        let (ident, init) = match property.value {
+            BindingPattern::BindingIdentifier(ident) => (ident, None),
+            BindingPattern::AssignmentPattern(pattern) => {
+                let pattern = pattern.unbox();
+                match pattern.left {
+                    BindingPattern::BindingIdentifier(ident) => (ident, Some(pattern.right)),
+                    _ => unreachable!(),
+                }
+            }
+            _ => unreachable!(),
+        };
+        let reference = BoundIdentifier::from_binding_ident(&ident).create_spanned_reference(
+            ident.span,
+            ReferenceFlags::Write,
+            ctx,
+        );
+        return ctx.ast.assignment_target_property_assignment_target_property_identifier(
+            property.span,
+            reference,
+            init,
+        );
+    }
+
+    // `{ x: y }`
+    let binding = binding_pattern_to_assignment_target_maybe_default(property.value, ctx);
+    ctx.ast.assignment_target_property_assignment_target_property_property(
+        property.span,
+        property.key,
+        binding,
+        property.computed,
+    )
+}
@@ -0,0 +1,651 @@
+//! ES2015: Block Scoping
+//!
+//! This plugin transforms `let` and `const` declarations to `var` declarations.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let a = 1;
+//! {
+//!   let a = 2;
+//!   const b = 3;
+//! }
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(() => i);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = 1;
+//! {
+//!   var _a = 2;
+//!   var b = 3;
+//! }
+//! var _loop = function (i) {
+//!   fns.push(() => i);
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   _loop(i);
+//! }
+//! ```
+//!
+//! ## Options
+//!
+//! ### `throwIfClosureRequired`
+//!
+//! When `true`, raise an error instead of wrapping a loop body in a closure
+//! when closures in the loop body capture a block-scoped binding.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-block-scoping](https://babel.dev/docs/babel-plugin-transform-block-scoping).
+//!
+//! Transform happens in 3 phases:
+//!
+//! 1. On entering a statement list, `switch` statement, or loop head
+//!    ([`BlockScoping::enter_statements`], [`BlockScoping::enter_switch_statement`],
+//!    [`BlockScoping::enter_for_statement`] etc):
+//!    Move bindings of `let` / `const` declarations into the enclosing function's scope.
+//!    If the binding would shadow, or be shadowed by, another binding once it's in the function scope,
+//!    it is renamed. Binding names are updated in `Scoping` and the AST.
+//!
+//! 2. During traversal ([`BlockScoping::enter_identifier_reference`], [`BlockScoping::enter_expression`]):
+//!    * Report references to a binding which are definitely in its temporal dead zone.
+//!    * Replace assignments to `const` bindings with a call to `readOnlyError` helper.
+//!
+//! 3. On exiting a statement ([`BlockScoping::exit_statement`]):
+//!    * Convert `let` and `const` declarations to `var`.
+//!    * If closures in a loop body capture a binding which is created anew on each iteration,
+//!      move the loop body into a function, which is called on each iteration.
+//!      See [`loops`] module.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-block-scoping>
+//! * Let and const declarations specification: <https://tc39.es/ecma262/#sec-let-and-const-declarations>
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use oxc_allocator::{GetAddress, TakeIn};
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::VisitMut;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_traverse::{Ancestor, Traverse};
+
+use crate::{
+    ArenaVec,
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+mod loops;
+
+pub use loops::binding_pattern_to_assignment_target;
+use loops::{LoopClosure, PendingLabeledLoop};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BlockScopingOptions {
+    pub throw_if_closure_required: bool,
+}
+
+/// Binding which is subject to temporal dead zone checks.
+#[derive(Clone, Copy)]
+struct TdzBinding {
+    /// End of the declarator which declares the binding.
+    /// References before this position are in temporal dead zone.
+    declaration_end: u32,
+    /// Function scope which binding is in.
+    var_scope_id: ScopeId,
+}
+
+/// Block scoping transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct BlockScoping<'a, 'ctx> {
+    options: BlockScopingOptions,
+    /// `true` if classes transform is enabled.
+    /// Class declarations are converted to `let` declarations by classes transform,
+    /// so their bindings need to be moved to function scope too.
+    transform_class_declarations: bool,
+
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// `let` and `const` bindings, for checking references in temporal dead zone.
+    tdz_bindings: FxHashMap<SymbolId, TdzBinding>,
+    /// `const` bindings.
+    const_bindings: FxHashSet<SymbolId>,
+    /// Stack of loops whose bodies are being converted to a closure.
+    loops: Vec<LoopClosure<'a>>,
+    /// Labeled loops whose `_loop` declaration needs to be inserted before the labeled statement,
+    /// but labeled statement is not in a statement list.
+    pending_labeled_loops: Vec<PendingLabeledLoop<'a>>,
+}
+
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    pub fn new(
+        options: BlockScopingOptions,
+        transform_class_declarations: bool,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        Self {
+            options,
+            transform_class_declarations,
+            ctx,
+            tdz_bindings: FxHashMap::default(),
+            const_bindings: FxHashSet::default(),
+            loops: vec![],
+            pending_labeled_loops: vec![],
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for BlockScoping<'a, '_> {
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Declarations in `switch` cases are handled in `enter_switch_statement`,
+        // as all cases share one scope
+        if matches!(ctx.parent(), Ancestor::SwitchCaseConsequent(_)) {
+            return;
+        }
+
+        let scope_id = ctx.current_scope_id();
+        let mut renames = FxHashMap::default();
+        self.move_declarations(stmts.iter_mut(), scope_id, &mut renames, ctx);
+        if !renames.is_empty() {
+            BindingRenamer::new(&renames, ctx).visit_statements(stmts);
+        }
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let scope_id = stmt.scope_id();
+        let mut renames = FxHashMap::default();
+        self.move_declarations(
+            stmt.cases.iter_mut().flat_map(|case| case.consequent.iter_mut()),
+            scope_id,
+            &mut renames,
+            ctx,
+        );
+        if !renames.is_empty() {
+            BindingRenamer::new(&renames, ctx).visit_switch_cases(&mut stmt.cases);
+        }
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(ForStatementInit::VariableDeclaration(decl)) = &stmt.init else { return };
+        let mut renames = FxHashMap::default();
+        self.move_loop_head_declaration(decl, stmt.scope_id(), &mut renames, ctx);
+        if !renames.is_empty() {
+            BindingRenamer::new(&renames, ctx).visit_for_statement(stmt);
+        }
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let ForStatementLeft::VariableDeclaration(decl) = &stmt.left else { return };
+        let mut renames = FxHashMap::default();
+        self.move_loop_head_declaration(decl, stmt.scope_id(), &mut renames, ctx);
+        if !renames.is_empty() {
+            BindingRenamer::new(&renames, ctx).visit_for_in_statement(stmt);
+        }
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let ForStatementLeft::VariableDeclaration(decl) = &stmt.left else { return };
+        let mut renames = FxHashMap::default();
+        self.move_loop_head_declaration(decl, stmt.scope_id(), &mut renames, ctx);
+        if !renames.is_empty() {
+            BindingRenamer::new(&renames, ctx).visit_for_of_statement(stmt);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(
+            stmt,
+            Statement::ForStatement(_)
+                | Statement::ForInStatement(_)
+                | Statement::ForOfStatement(_)
+                | Statement::WhileStatement(_)
+                | Statement::DoWhileStatement(_)
+        ) {
+            self.enter_loop(stmt, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::VariableDeclaration(decl) => Self::convert_to_var(decl, ctx),
+            Statement::ExportNamedDeclaration(export) => {
+                if let Some(Declaration::VariableDeclaration(decl)) = &mut export.declaration {
+                    Self::convert_to_var(decl, ctx);
+                }
+            }
+            Statement::ForStatement(for_stmt) => {
+                if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut for_stmt.init {
+                    Self::convert_to_var(decl, ctx);
+                }
+                self.exit_loop(stmt, ctx);
+            }
+            Statement::ForInStatement(for_stmt) => {
+                if let ForStatementLeft::VariableDeclaration(decl) = &mut for_stmt.left {
+                    Self::convert_to_var(decl, ctx);
+                }
+                self.exit_loop(stmt, ctx);
+            }
+            Statement::ForOfStatement(for_stmt) => {
+                if let ForStatementLeft::VariableDeclaration(decl) = &mut for_stmt.left {
+                    Self::convert_to_var(decl, ctx);
+                }
+                self.exit_loop(stmt, ctx);
+            }
+            Statement::WhileStatement(_) | Statement::DoWhileStatement(_) => {
+                self.exit_loop(stmt, ctx);
+            }
+            Statement::LabeledStatement(_) => self.exit_labeled_statement(stmt, ctx),
+            _ => {}
+        }
+    }
+
+    /// Report reference to `let` or `const` binding before its declaration.
+    // `#[inline]` for fast exit for the common case of no `let` or `const` bindings
+    #[inline]
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !self.tdz_bindings.is_empty() {
+            self.check_tdz(ident, ctx);
+        }
+    }
+
+    // `#[inline]` for fast exit for expressions which are not assignments
+    #[inline]
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.const_bindings.is_empty() {
+            return;
+        }
+        match expr {
+            Expression::AssignmentExpression(assign) => {
+                if let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left
+                    && self.is_const_reference(ident, ctx)
+                {
+                    self.transform_const_assignment(expr, ctx);
+                }
+            }
+            Expression::UpdateExpression(update) => {
+                if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument
+                    && self.is_const_reference(ident, ctx)
+                {
+                    self.transform_const_update(expr, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> BlockScoping<'a, '_> {
+    /// Move bindings of `let` and `const` declarations in `stmts` from `scope_id` to the enclosing
+    /// function scope, renaming bindings where required.
+    ///
+    /// Renamed bindings are added to `renames`. Caller must update the AST with the new names.
+    fn move_declarations<'s>(
+        &mut self,
+        stmts: impl Iterator<Item = &'s mut Statement<'a>>,
+        scope_id: ScopeId,
+        renames: &mut FxHashMap<SymbolId, Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) where
+        'a: 's,
+    {
+        let var_scope_id = Self::var_scope_id(scope_id, ctx);
+        // Only calculated when required, as it involves walking up the ancestors
+        let mut in_loop = None;
+
+        for stmt in stmts {
+            let decl = match stmt {
+                Statement::VariableDeclaration(decl) => decl,
+                Statement::ExportNamedDeclaration(export) => match &mut export.declaration {
+                    Some(Declaration::VariableDeclaration(decl)) => decl,
+                    _ => continue,
+                },
+                Statement::ClassDeclaration(class)
+                    if self.transform_class_declarations && !class.declare =>
+                {
+                    if let Some(id) = &class.id {
+                        Self::move_binding(
+                            id.symbol_id(),
+                            id.name,
+                            scope_id,
+                            var_scope_id,
+                            renames,
+                            ctx,
+                        );
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            if !decl.kind.is_lexical() || decl.declare {
+                continue;
+            }
+
+            // `let x;` in a loop body -> `var x = void 0;`.
+            // Otherwise `x` would retain its value from previous iteration.
+            if decl.kind == VariableDeclarationKind::Let
+                && decl.declarations.iter().any(|declarator| declarator.init.is_none())
+                && *in_loop.get_or_insert_with(|| self.is_in_loop_body(ctx))
+            {
+                for declarator in &mut decl.declarations {
+                    if declarator.init.is_none() {
+                        declarator.init = Some(ctx.ast.void_0(SPAN));
+                    }
+                }
+            }
+
+            self.move_declaration(decl, scope_id, var_scope_id, renames, ctx);
+        }
+    }
+
+    /// Move bindings of `let` or `const` declaration in head of a `for` loop
+    /// from the loop's scope to the enclosing function scope.
+    fn move_loop_head_declaration(
+        &mut self,
+        decl: &VariableDeclaration<'a>,
+        scope_id: ScopeId,
+        renames: &mut FxHashMap<SymbolId, Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if decl.kind.is_lexical() {
+            let var_scope_id = Self::var_scope_id(scope_id, ctx);
+            self.move_declaration(decl, scope_id, var_scope_id, renames, ctx);
+        }
+    }
+
+    fn move_declaration(
+        &mut self,
+        decl: &VariableDeclaration<'a>,
+        scope_id: ScopeId,
+        var_scope_id: ScopeId,
+        renames: &mut FxHashMap<SymbolId, Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_const = decl.kind == VariableDeclarationKind::Const;
+        for declarator in &decl.declarations {
+            let binding = TdzBinding { declaration_end: declarator.span.end, var_scope_id };
+            declarator.id.bound_names(&mut |ident| {
+                let symbol_id = ident.symbol_id();
+                self.tdz_bindings.insert(symbol_id, binding);
+                if is_const {
+                    self.const_bindings.insert(symbol_id);
+                }
+                Self::move_binding(symbol_id, ident.name, scope_id, var_scope_id, renames, ctx);
+            });
+        }
+    }
+
+    /// Move binding from block scope `scope_id` to function scope `var_scope_id`.
+    ///
+    /// If the name is already bound in any scope above `scope_id`, or is a global,
+    /// rename the binding to a UID.
+    fn move_binding(
+        symbol_id: SymbolId,
+        name: Atom<'a>,
+        scope_id: ScopeId,
+        var_scope_id: ScopeId,
+        renames: &mut FxHashMap<SymbolId, Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if scope_id == var_scope_id {
+            return;
+        }
+
+        let scoping = ctx.scoping();
+        let parent_scope_id = scoping.scope_parent_id(scope_id).unwrap();
+        let has_conflict = scoping
+            .scope_ancestors(parent_scope_id)
+            .any(|ancestor_id| scoping.scope_has_binding(ancestor_id, &name))
+            || scoping.root_unresolved_references().contains_key(name.as_str());
+
+        let name = if has_conflict {
+            let new_name = ctx.generate_uid_name(&name);
+            ctx.scoping_mut().rename_symbol(symbol_id, scope_id, &new_name);
+            renames.insert(symbol_id, new_name);
+            new_name
+        } else {
+            name
+        };
+
+        let scoping = ctx.scoping_mut();
+        scoping.move_binding(scope_id, var_scope_id, &name);
+        scoping.set_symbol_scope_id(symbol_id, var_scope_id);
+    }
+
+    /// Convert `let` or `const` declaration to `var`.
+    fn convert_to_var(decl: &mut VariableDeclaration<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !decl.kind.is_lexical() || decl.declare {
+            return;
+        }
+
+        decl.kind = VariableDeclarationKind::Var;
+        for declarator in &mut decl.declarations {
+            declarator.kind = VariableDeclarationKind::Var;
+            declarator.id.bound_names(&mut |ident| {
+                let flags = ctx.scoping_mut().symbol_flags_mut(ident.symbol_id());
+                flags.remove(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable);
+                flags.insert(SymbolFlags::FunctionScopedVariable);
+            });
+        }
+    }
+
+    /// Get the function scope which contains `scope_id`.
+    fn var_scope_id(scope_id: ScopeId, ctx: &TraverseCtx<'a>) -> ScopeId {
+        let scoping = ctx.scoping();
+        scoping
+            .scope_ancestors(scope_id)
+            .find(|&ancestor_id| scoping.scope_flags(ancestor_id).is_var())
+            .unwrap()
+    }
+
+    /// Check if the current statement list is within the body of a loop,
+    /// and not within a function inside the loop body.
+    ///
+    /// Loop bodies which are converted to a closure do not count.
+    fn is_in_loop_body(&self, ctx: &TraverseCtx<'a>) -> bool {
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::ForStatementBody(_)
+                | Ancestor::ForInStatementBody(_)
+                | Ancestor::ForOfStatementBody(_)
+                | Ancestor::WhileStatementBody(_)
+                | Ancestor::DoWhileStatementBody(_) => {
+                    let address = ancestor.address();
+                    return !self.loops.iter().any(|loop_closure| loop_closure.address == address);
+                }
+                Ancestor::FunctionBody(_)
+                | Ancestor::ArrowFunctionExpressionBody(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleDeclarationBody(_) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// Report an error if `ident` refers to a `let` or `const` binding and is definitely
+    /// evaluated before the binding's declaration.
+    ///
+    /// Only references in the same function as the declaration, and preceding it, are reported.
+    /// A reference from within a nested function may be valid, depending on when the function is called.
+    fn check_tdz(&self, ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) {
+        let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        let Some(binding) = self.tdz_bindings.get(&symbol_id) else { return };
+        // Reference created by another transform
+        if ident.span.is_empty() || ident.span.start >= binding.declaration_end {
+            return;
+        }
+        if Self::var_scope_id(ctx.current_scope_id(), ctx) != binding.var_scope_id {
+            return;
+        }
+
+        self.ctx.error(
+            OxcDiagnostic::error(format!("Cannot access '{}' before initialization", ident.name))
+                .with_label(ident.span),
+        );
+    }
+
+    fn is_const_reference(&self, ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        ctx.scoping()
+            .get_reference(ident.reference_id())
+            .symbol_id()
+            .is_some_and(|symbol_id| self.const_bindings.contains(&symbol_id))
+    }
+
+    /// Transform assignment to a `const` binding.
+    ///
+    /// * `x = value` -> `value, _readOnlyError("x")`
+    /// * `x += value` -> `x + value, _readOnlyError("x")`
+    /// * `x ||= value` -> `x || (value, _readOnlyError("x"))`
+    fn transform_const_assignment(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::AssignmentExpression(assign) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let assign = assign.unbox();
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = assign.left else {
+            unreachable!()
+        };
+        let error = self.create_read_only_error(ident.name, ctx);
+
+        *expr = if assign.operator == AssignmentOperator::Assign {
+            ctx.delete_reference_for_identifier(&ident);
+            ctx.ast.expression_sequence(assign.span, ctx.ast.vec_from_array([assign.right, error]))
+        } else {
+            *ctx.scoping_mut().get_reference_mut(ident.reference_id()).flags_mut() =
+                ReferenceFlags::Read;
+            let left = Expression::Identifier(ident);
+            if let Some(operator) = assign.operator.to_logical_operator() {
+                let right = ctx.ast.expression_sequence(
+                    assign.right.span(),
+                    ctx.ast.vec_from_array([assign.right, error]),
+                );
+                ctx.ast.expression_logical(assign.span, left, operator, right)
+            } else {
+                let operator = assign.operator.to_binary_operator().unwrap();
+                let binary = ctx.ast.expression_binary(assign.span, left, operator, assign.right);
+                ctx.ast.expression_sequence(assign.span, ctx.ast.vec_from_array([binary, error]))
+            }
+        };
+    }
+
+    /// Transform update of a `const` binding.
+    ///
+    /// `x++` -> `+x, _readOnlyError("x")`
+    fn transform_const_update(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::UpdateExpression(update) = expr.take_in(ctx.ast) else { unreachable!() };
+        let update = update.unbox();
+        let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = update.argument else {
+            unreachable!()
+        };
+        let error = self.create_read_only_error(ident.name, ctx);
+
+        *ctx.scoping_mut().get_reference_mut(ident.reference_id()).flags_mut() =
+            ReferenceFlags::Read;
+        let value = ctx.ast.expression_unary(
+            update.span,
+            UnaryOperator::UnaryPlus,
+            Expression::Identifier(ident),
+        );
+        *expr = ctx.ast.expression_sequence(update.span, ctx.ast.vec_from_array([value, error]));
+    }
+
+    /// `_readOnlyError("x")`
+    fn create_read_only_error(&self, name: Atom<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let arguments =
+            ctx.ast.vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)));
+        self.ctx.helper_call_expr(Helper::ReadOnlyError, SPAN, arguments, ctx)
+    }
+}
+
+/// Visitor to rename bindings and references.
+pub(super) struct BindingRenamer<'a, 'v> {
+    renames: &'v FxHashMap<SymbolId, Atom<'a>>,
+    scoping: &'v Scoping,
+    ast: AstBuilder<'a>,
+}
+
+impl<'a, 'v> BindingRenamer<'a, 'v> {
+    pub(super) fn new(
+        renames: &'v FxHashMap<SymbolId, Atom<'a>>,
+        ctx: &'v TraverseCtx<'a>,
+    ) -> Self {
+        Self { renames, scoping: ctx.scoping(), ast: ctx.ast }
+    }
+
+    fn get_new_name(&self, ident: &IdentifierReference<'a>) -> Option<Atom<'a>> {
+        let symbol_id = self.scoping.get_reference(ident.reference_id()).symbol_id()?;
+        self.renames.get(&symbol_id).copied()
+    }
+}
+
+impl<'a> VisitMut<'a> for BindingRenamer<'a, '_> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(new_name) = self.renames.get(&ident.symbol_id()) {
+            ident.name = *new_name;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if let Some(new_name) = self.get_new_name(ident) {
+            ident.name = new_name;
+        }
+    }
+
+    /// `({ x } = obj)` -> `({ x: _x } = obj)`
+    fn visit_assignment_target_property(&mut self, property: &mut AssignmentTargetProperty<'a>) {
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) = property
+            && self.get_new_name(&prop.binding).is_some()
+        {
+            let prop = prop.take_in(self.ast);
+            let key = self.ast.property_key_static_identifier(prop.binding.span, prop.binding.name);
+            let mut target =
+                AssignmentTarget::AssignmentTargetIdentifier(self.ast.alloc(prop.binding));
+            if let AssignmentTarget::AssignmentTargetIdentifier(ident) = &mut target {
+                self.visit_identifier_reference(ident);
+            }
+            let binding = match prop.init {
+                Some(mut init) => {
+                    self.visit_expression(&mut init);
+                    self.ast.assignment_target_maybe_default_assignment_target_with_default(
+                        prop.span, target, init,
+                    )
+                }
+                None => AssignmentTargetMaybeDefault::from(target),
+            };
+            *property = self.ast.assignment_target_property_assignment_target_property_property(
+                prop.span, key, binding, false,
+            );
+            return;
+        }
+        oxc_ast_visit::walk_mut::walk_assignment_target_property(self, property);
+    }
+}
@@ -0,0 +1,779 @@
+//! ES2015: Classes
+//! Transform of class declarations and class expressions.
+
+use oxc_allocator::{Box as ArenaBox, TakeIn};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::keyword::is_reserved_keyword_or_global_object;
+use oxc_traverse::BoundIdentifier;
+
+use crate::{Helper, context::TraverseCtx, utils::ast_builder::create_prototype_member};
+
+use super::Classes;
+
+/// Built-in classes which need to be wrapped with `_wrapNativeSuper` helper when extended.
+const NATIVE_SUPER_CLASSES: &[&str] = &[
+    "Array",
+    "ArrayBuffer",
+    "Boolean",
+    "DataView",
+    "Date",
+    "Error",
+    "EvalError",
+    "Float32Array",
+    "Float64Array",
+    "Int8Array",
+    "Int16Array",
+    "Int32Array",
+    "Map",
+    "Number",
+    "Promise",
+    "RangeError",
+    "ReferenceError",
+    "RegExp",
+    "Set",
+    "String",
+    "SyntaxError",
+    "TypeError",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "Uint16Array",
+    "Uint32Array",
+    "URIError",
+    "WeakMap",
+    "WeakSet",
+    "HTMLElement",
+];
+
+/// Property descriptor for a method or accessor, passed to `_createClass` helper.
+///
+/// `{ key: "foo", value: function foo() {} }` or `{ key: "foo", get: function () {}, set: function (v) {} }`
+struct Descriptor<'a> {
+    key: Expression<'a>,
+    /// Name of key, if it's not computed. Used to merge getters and setters with same key.
+    name: Option<Atom<'a>>,
+    value: Option<Expression<'a>>,
+    get: Option<Expression<'a>>,
+    set: Option<Expression<'a>>,
+}
+
+impl<'a> Classes<'a, '_> {
+    /// Transform class declaration.
+    ///
+    /// `class Foo {}` -> `let Foo = /*#__PURE__*/_createClass(function Foo() {});`
+    pub(super) fn transform_class_declaration(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Statement::ClassDeclaration(class) = stmt else { unreachable!() };
+        let Some(new_stmt) = self.transform_class_declaration_to_let(class, ctx) else { return };
+        self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+        *stmt = new_stmt;
+    }
+
+    /// Transform exported class declaration.
+    ///
+    /// `export class Foo {}` -> `export let Foo = /*#__PURE__*/_createClass(function Foo() {});`
+    pub(super) fn transform_export_named_class(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Statement::ExportNamedDeclaration(export) = stmt else { unreachable!() };
+        let Some(Declaration::ClassDeclaration(class)) = &mut export.declaration else {
+            unreachable!()
+        };
+        let Some(Statement::VariableDeclaration(var_decl)) =
+            self.transform_class_declaration_to_let(class, ctx)
+        else {
+            return;
+        };
+        export.declaration = Some(Declaration::VariableDeclaration(var_decl));
+    }
+
+    /// Transform default exported class declaration.
+    ///
+    /// * `export default class Foo {}`
+    ///   -> `let Foo = /*#__PURE__*/_createClass(function Foo() {}); export { Foo as default };`
+    /// * `export default class {}`
+    ///   -> `export default /*#__PURE__*/_createClass(function _default() {});`
+    pub(super) fn transform_export_default_class(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Statement::ExportDefaultDeclaration(export) = stmt else { unreachable!() };
+        let ExportDefaultDeclarationKind::ClassDeclaration(class) = &mut export.declaration else {
+            unreachable!()
+        };
+
+        if class.id.is_none() {
+            if let Some(expr) = self.transform_class(class, ctx) {
+                export.declaration = ExportDefaultDeclarationKind::from(expr);
+            }
+            return;
+        }
+
+        let class_name = BoundIdentifier::from_binding_ident(class.id.as_ref().unwrap());
+        let Some(new_stmt) = self.transform_class_declaration_to_let(class, ctx) else { return };
+
+        // `export { Foo as default };`
+        let specifier = ctx.ast.export_specifier(
+            SPAN,
+            ModuleExportName::IdentifierReference(class_name.create_read_reference(ctx)),
+            ctx.ast.module_export_name_identifier_name(SPAN, "default"),
+            ImportOrExportKind::Value,
+        );
+        let export_stmt =
+            Statement::ExportNamedDeclaration(ctx.ast.alloc_export_named_declaration(
+                SPAN,
+                None,
+                ctx.ast.vec1(specifier),
+                None,
+                ImportOrExportKind::Value,
+                NONE,
+            ));
+
+        self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+        self.ctx.statement_injector.insert_after(&new_stmt, export_stmt);
+        *stmt = new_stmt;
+    }
+
+    /// Transform class expression.
+    ///
+    /// `x = class {}` -> `x = /*#__PURE__*/_createClass(function x() {})`
+    pub(super) fn transform_class_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::ClassExpression(class) = expr else { unreachable!() };
+        if let Some(new_expr) = self.transform_class(class, ctx) {
+            *expr = new_expr;
+        }
+    }
+
+    /// Transform class declaration to `let` declaration.
+    ///
+    /// `class Foo {}` -> `let Foo = /*#__PURE__*/_createClass(function Foo() {});`
+    ///
+    /// Returns `None` if class cannot be transformed.
+    fn transform_class_declaration_to_let(
+        &mut self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        let init = self.transform_class(class, ctx)?;
+
+        let id = class.id.as_ref().unwrap();
+        *ctx.scoping_mut().symbol_flags_mut(id.symbol_id()) = SymbolFlags::BlockScopedVariable;
+
+        let kind = VariableDeclarationKind::Let;
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            ctx.ast.binding_pattern_binding_identifier_with_symbol_id(
+                id.span,
+                id.name,
+                id.symbol_id(),
+            ),
+            NONE,
+            Some(init),
+            false,
+        );
+        Some(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            class.span,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        )))
+    }
+
+    /// Transform class to an expression.
+    ///
+    /// * Class without super class or methods:
+    ///   `/*#__PURE__*/_createClass(function Foo() {})`
+    /// * Other classes:
+    ///   ```js
+    ///   /*#__PURE__*/function (_Bar) {
+    ///     function Foo() {}
+    ///     _inherits(Foo, _Bar);
+    ///     return _createClass(Foo, [/* methods */], [/* static methods */]);
+    ///   }(Bar)
+    ///   ```
+    ///
+    /// Returns `None` if class cannot be transformed.
+    fn transform_class(
+        &mut self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let class_scope_id = class.scope_id();
+        let class_binding = self.class_bindings.remove(&class_scope_id)?;
+
+        if !self.check_class_elements(class) {
+            return None;
+        }
+
+        // Re-point references to class name within class body to constructor function
+        if class.is_declaration()
+            && let Some(ident) = &class.id
+        {
+            let outer_symbol_id = ident.symbol_id();
+            let mut repointer = ReferencesRepointer {
+                from: outer_symbol_id,
+                to: class_binding.symbol_id,
+                scoping: ctx.scoping_mut(),
+            };
+            repointer.visit_class_body(&class.body);
+        }
+        *ctx.scoping_mut().symbol_flags_mut(class_binding.symbol_id) = SymbolFlags::Function;
+
+        let outer_scope_id = ctx.current_scope_id();
+        let is_strict = ctx.current_scope_flags().is_strict_mode();
+
+        // Split class elements into constructor and methods
+        let mut constructor = None;
+        let mut methods = vec![];
+        for element in class.body.body.take_in(ctx.ast) {
+            if let ClassElement::MethodDefinition(method) = element {
+                if method.kind == MethodDefinitionKind::Constructor {
+                    constructor = Some(method.unbox().value);
+                } else {
+                    methods.push(method);
+                }
+            }
+        }
+
+        // Super class is passed as argument to IIFE, so scopes within it need to be moved out of class
+        let super_class = class.super_class.take();
+        let super_binding = super_class.as_ref().map(|super_class| {
+            reparent_child_scopes(super_class, outer_scope_id, ctx);
+            ctx.generate_uid_based_on_node(
+                super_class,
+                class_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            )
+        });
+
+        // Constructor function
+        let is_default = constructor.is_none();
+        let mut constructor = constructor.unwrap_or_else(|| {
+            self.create_default_constructor(
+                class_scope_id,
+                &class_binding,
+                super_binding.as_ref(),
+                ctx,
+            )
+        });
+        self.transform_constructor(
+            &mut constructor,
+            &class_binding,
+            super_binding.as_ref(),
+            is_default,
+            ctx,
+        );
+        constructor.id = Some(class_binding.create_binding_identifier(ctx));
+
+        // Simple class can be transformed without an IIFE.
+        // IIFE is also required in sloppy mode, to contain `"use strict"` directive.
+        if super_class.is_none() && methods.is_empty() && is_strict {
+            return Some(self.transform_class_without_iife(
+                constructor,
+                &class_binding,
+                class_scope_id,
+                outer_scope_id,
+                ctx,
+            ));
+        }
+
+        let mut statements = ctx.ast.vec_with_capacity(methods.len() + 3);
+
+        // `function Foo() {}`
+        constructor.r#type = FunctionType::FunctionDeclaration;
+        statements.push(Statement::FunctionDeclaration(constructor));
+
+        // `_inherits(Foo, _Bar);`
+        if let Some(super_binding) = &super_binding {
+            let helper = if self.super_is_callable_constructor {
+                Helper::InheritsLoose
+            } else {
+                Helper::Inherits
+            };
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(class_binding.create_read_expression(ctx)),
+                Argument::from(super_binding.create_read_expression(ctx)),
+            ]);
+            let call = self.ctx.helper_call_expr(helper, SPAN, arguments, ctx);
+            statements.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        // Methods
+        let mut proto_descriptors = vec![];
+        let mut static_descriptors = vec![];
+        let mut proto_binding = None;
+        for method in methods {
+            let MethodDefinition { key, value: mut func, kind, r#static, computed, .. } =
+                method.unbox();
+
+            ctx.scoping_mut()
+                .scope_flags_mut(func.scope_id())
+                .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+            func.r#type = FunctionType::FunctionExpression;
+
+            if kind == MethodDefinitionKind::Method {
+                Self::name_method_function(&mut func, &key, computed, ctx);
+            }
+            let func = Expression::FunctionExpression(func);
+
+            if self.set_class_methods && kind == MethodDefinitionKind::Method {
+                // `_proto.foo = function foo() {}` or `Foo.foo = function foo() {}`
+                let object = if r#static {
+                    class_binding.create_read_expression(ctx)
+                } else {
+                    let proto_binding = proto_binding.get_or_insert_with(|| {
+                        let binding = ctx.generate_uid(
+                            "proto",
+                            class_scope_id,
+                            SymbolFlags::FunctionScopedVariable,
+                        );
+                        // `var _proto = Foo.prototype;`
+                        let prototype =
+                            create_prototype_member(class_binding.create_read_expression(ctx), ctx);
+                        statements.push(Self::create_var_declaration(&binding, prototype, ctx));
+                        binding
+                    });
+                    proto_binding.create_read_expression(ctx)
+                };
+                let target = Self::create_method_assignment_target(object, key, computed, ctx);
+                let assignment =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, func);
+                statements.push(ctx.ast.statement_expression(SPAN, assignment));
+                continue;
+            }
+
+            let name =
+                if computed { None } else { key.static_name().map(|name| ctx.ast.atom(&name)) };
+            let descriptors =
+                if r#static { &mut static_descriptors } else { &mut proto_descriptors };
+            Self::add_descriptor(descriptors, key, name, kind, func, ctx);
+        }
+
+        // `return _createClass(Foo, [...], [...]);` or `return Foo;`
+        let return_value = if self.set_class_methods
+            && proto_descriptors.is_empty()
+            && static_descriptors.is_empty()
+        {
+            class_binding.create_read_expression(ctx)
+        } else {
+            self.create_create_class_call(
+                &class_binding,
+                proto_descriptors,
+                static_descriptors,
+                ctx,
+            )
+        };
+        statements.push(ctx.ast.statement_return(SPAN, Some(return_value)));
+
+        // Reuse class scope as scope of IIFE
+        *ctx.scoping_mut().scope_flags_mut(class_scope_id) =
+            ScopeFlags::Function | ScopeFlags::StrictMode;
+
+        let directives =
+            if is_strict { ctx.ast.vec() } else { ctx.ast.vec1(ctx.ast.use_strict_directive()) };
+        let params = if let Some(super_binding) = &super_binding {
+            ctx.ast.vec1(
+                ctx.ast.plain_formal_parameter(SPAN, super_binding.create_binding_pattern(ctx)),
+            )
+        } else {
+            ctx.ast.vec()
+        };
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, directives, statements);
+        let iife = ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            class_scope_id,
+        );
+
+        let arguments = match super_class {
+            Some(super_class) => {
+                ctx.ast.vec1(Argument::from(self.wrap_super_class(super_class, ctx)))
+            }
+            None => ctx.ast.vec(),
+        };
+        Some(ctx.ast.expression_call_with_pure(
+            class.span,
+            Expression::FunctionExpression(iife),
+            NONE,
+            arguments,
+            false,
+            true,
+        ))
+    }
+
+    /// Transform class which has no super class or methods.
+    ///
+    /// `class Foo { constructor() {} }` -> `/*#__PURE__*/_createClass(function Foo() {})`
+    ///
+    /// Class scope is removed, and constructor function's scope takes its place.
+    fn transform_class_without_iife(
+        &self,
+        mut constructor: ArenaBox<'a, Function<'a>>,
+        class_binding: &BoundIdentifier<'a>,
+        class_scope_id: ScopeId,
+        outer_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        constructor.r#type = FunctionType::FunctionExpression;
+
+        let constructor_scope_id = constructor.scope_id();
+        let scoping = ctx.scoping_mut();
+        scoping.change_scope_parent_id(constructor_scope_id, Some(outer_scope_id));
+        scoping.move_binding(class_scope_id, constructor_scope_id, &class_binding.name);
+        scoping.set_symbol_scope_id(class_binding.symbol_id, constructor_scope_id);
+        scoping.delete_scope(class_scope_id);
+
+        let constructor = Expression::FunctionExpression(constructor);
+        if self.set_class_methods {
+            return constructor;
+        }
+
+        let mut call = self.ctx.helper_call_expr(
+            Helper::CreateClass,
+            SPAN,
+            ctx.ast.vec1(Argument::from(constructor)),
+            ctx,
+        );
+        if let Expression::CallExpression(call) = &mut call {
+            call.pure = true;
+        }
+        call
+    }
+
+    /// Check class contains only methods, and so can be transformed.
+    ///
+    /// Properties, static blocks and private methods must be transformed by class properties transform
+    /// before this transform can run. If class contains any of these, raise an error.
+    fn check_class_elements(&self, class: &Class<'a>) -> bool {
+        let unsupported = class.body.body.iter().find(|element| match element {
+            ClassElement::MethodDefinition(method) => method.key.is_private_identifier(),
+            ClassElement::PropertyDefinition(_)
+            | ClassElement::AccessorProperty(_)
+            | ClassElement::StaticBlock(_) => true,
+            ClassElement::TSIndexSignature(_) => false,
+        });
+
+        if let Some(element) = unsupported {
+            self.ctx.error(
+                OxcDiagnostic::error(
+                    "Classes containing properties, static blocks or private methods cannot be transformed to ES5 unless class properties transform is enabled.",
+                )
+                .with_label(element.span()),
+            );
+            return false;
+        }
+        true
+    }
+
+    /// Give a name to method function, if it's safe to do so.
+    ///
+    /// `foo() {}` -> `function foo() {}`
+    ///
+    /// Function is not named if its key is not a valid identifier, or naming it would shadow
+    /// a reference to a variable of same name within the function.
+    fn name_method_function(
+        func: &mut Function<'a>,
+        key: &PropertyKey<'a>,
+        computed: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let PropertyKey::StaticIdentifier(ident) = key else { return };
+        if computed {
+            return;
+        }
+        let name = ident.name;
+        if is_reserved_keyword_or_global_object(&name) || name == "eval" || name == "arguments" {
+            return;
+        }
+
+        let scope_id = func.scope_id();
+        if ctx.scoping().scope_has_binding(scope_id, &name) {
+            return;
+        }
+        let mut finder = NameReferenceFinder { name: &name, found: false };
+        finder.visit_function(func, ScopeFlags::Function);
+        if finder.found {
+            return;
+        }
+
+        let binding = ctx.generate_binding(name, scope_id, SymbolFlags::Function);
+        func.id = Some(binding.create_binding_identifier(ctx));
+    }
+
+    /// Add descriptor for a method to list of descriptors.
+    ///
+    /// Getters and setters with same key are merged into a single descriptor.
+    fn add_descriptor(
+        descriptors: &mut Vec<Descriptor<'a>>,
+        key: PropertyKey<'a>,
+        name: Option<Atom<'a>>,
+        kind: MethodDefinitionKind,
+        func: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        if kind != MethodDefinitionKind::Method
+            && let Some(name) = name
+            && let Some(descriptor) = descriptors
+                .iter_mut()
+                .rev()
+                .find(|descriptor| descriptor.name == Some(name) && descriptor.value.is_none())
+        {
+            if kind == MethodDefinitionKind::Get {
+                descriptor.get = Some(func);
+            } else {
+                descriptor.set = Some(func);
+            }
+            return;
+        }
+
+        let key = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(ident.span, ident.name, None)
+            }
+            key => key.into_expression(),
+        };
+        let mut descriptor = Descriptor { key, name, value: None, get: None, set: None };
+        match kind {
+            MethodDefinitionKind::Get => descriptor.get = Some(func),
+            MethodDefinitionKind::Set => descriptor.set = Some(func),
+            _ => descriptor.value = Some(func),
+        }
+        descriptors.push(descriptor);
+    }
+
+    /// `_createClass(Foo, [{ key: "foo", value: function foo() {} }], [...])`
+    fn create_create_class_call(
+        &self,
+        class_binding: &BoundIdentifier<'a>,
+        proto_descriptors: Vec<Descriptor<'a>>,
+        static_descriptors: Vec<Descriptor<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec_with_capacity(3);
+        arguments.push(Argument::from(class_binding.create_read_expression(ctx)));
+        if !proto_descriptors.is_empty() || !static_descriptors.is_empty() {
+            let proto = if proto_descriptors.is_empty() {
+                ctx.ast.expression_null_literal(SPAN)
+            } else {
+                Self::create_descriptors_array(proto_descriptors, ctx)
+            };
+            arguments.push(Argument::from(proto));
+        }
+        if !static_descriptors.is_empty() {
+            arguments.push(Argument::from(Self::create_descriptors_array(static_descriptors, ctx)));
+        }
+        self.ctx.helper_call_expr(Helper::CreateClass, SPAN, arguments, ctx)
+    }
+
+    /// `[{ key: "foo", value: function foo() {} }, { key: "bar", get: function () {} }]`
+    fn create_descriptors_array(
+        descriptors: Vec<Descriptor<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let elements = descriptors.into_iter().map(|descriptor| {
+            let mut properties = ctx.ast.vec_with_capacity(3);
+            properties.push(Self::create_descriptor_property("key", descriptor.key, ctx));
+            if let Some(value) = descriptor.value {
+                properties.push(Self::create_descriptor_property("value", value, ctx));
+            }
+            if let Some(get) = descriptor.get {
+                properties.push(Self::create_descriptor_property("get", get, ctx));
+            }
+            if let Some(set) = descriptor.set {
+                properties.push(Self::create_descriptor_property("set", set, ctx));
+            }
+            ArrayExpressionElement::from(ctx.ast.expression_object(SPAN, properties))
+        });
+        ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(elements))
+    }
+
+    /// `key: value`
+    fn create_descriptor_property(
+        name: &'static str,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            ctx.ast.property_key_static_identifier(SPAN, name),
+            value,
+            false,
+            false,
+            false,
+        )
+    }
+
+    /// `_proto.foo` or `_proto[foo]`
+    fn create_method_assignment_target(
+        object: Expression<'a>,
+        key: PropertyKey<'a>,
+        computed: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) if !computed => {
+                let property = ctx.ast.identifier_name(ident.span, ident.name);
+                AssignmentTarget::from(
+                    ctx.ast.member_expression_static(SPAN, object, property, false),
+                )
+            }
+            key => AssignmentTarget::from(ctx.ast.member_expression_computed(
+                SPAN,
+                object,
+                key.into_expression(),
+                false,
+            )),
+        }
+    }
+
+    /// `var _proto = Foo.prototype;`
+    fn create_var_declaration(
+        binding: &BoundIdentifier<'a>,
+        init: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            binding.create_binding_pattern(ctx),
+            NONE,
+            Some(init),
+            false,
+        );
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+
+    /// Wrap super class in `_wrapNativeSuper` helper if it's a built-in class.
+    ///
+    /// `Array` -> `/*#__PURE__*/_wrapNativeSuper(Array)`
+    fn wrap_super_class(
+        &self,
+        super_class: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let Expression::Identifier(ident) = &super_class
+            && NATIVE_SUPER_CLASSES.contains(&ident.name.as_str())
+            && ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none()
+        {
+            let mut call = self.ctx.helper_call_expr(
+                Helper::WrapNativeSuper,
+                SPAN,
+                ctx.ast.vec1(Argument::from(super_class)),
+                ctx,
+            );
+            if let Expression::CallExpression(call) = &mut call {
+                call.pure = true;
+            }
+            return call;
+        }
+        super_class
+    }
+}
+
+/// Check if class body contains a reference to a variable called `name`.
+pub(super) fn class_body_references_name(class: &Class<'_>, name: &str) -> bool {
+    let mut finder = NameReferenceFinder { name, found: false };
+    finder.visit_class_body(&class.body);
+    finder.found
+}
+
+/// Move direct child scopes of an expression to be children of `parent_scope_id`.
+fn reparent_child_scopes(
+    expr: &Expression<'_>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'_>,
+) {
+    let mut collector = ChildScopeCollector { scope_ids: vec![], depth: 0 };
+    collector.visit_expression(expr);
+    for scope_id in collector.scope_ids {
+        ctx.scoping_mut().change_scope_parent_id(scope_id, Some(parent_scope_id));
+    }
+}
+
+/// Visitor to find references to a variable with a specific name.
+struct NameReferenceFinder<'n> {
+    name: &'n str,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for NameReferenceFinder<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == self.name {
+            self.found = true;
+        }
+    }
+}
+
+/// Visitor to re-point references from one symbol to another.
+struct ReferencesRepointer<'s> {
+    from: SymbolId,
+    to: SymbolId,
+    scoping: &'s mut Scoping,
+}
+
+impl<'a> Visit<'a> for ReferencesRepointer<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let reference = self.scoping.get_reference_mut(reference_id);
+        if reference.symbol_id() == Some(self.from) {
+            reference.set_symbol_id(self.to);
+            self.scoping.delete_resolved_reference(self.from, reference_id);
+            self.scoping.add_resolved_reference(self.to, reference_id);
+        }
+    }
+}
+
+/// Visitor to collect direct child scopes of an AST node.
+struct ChildScopeCollector {
+    scope_ids: Vec<ScopeId>,
+    depth: u32,
+}
+
+impl Visit<'_> for ChildScopeCollector {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &std::cell::Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            self.scope_ids.push(scope_id.get().unwrap());
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
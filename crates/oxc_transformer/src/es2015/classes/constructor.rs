@@ -0,0 +1,443 @@
+//! ES2015: Classes
+//! Transform of class constructor.
+//!
+//! * Insert `_classCallCheck(this, Foo)` at start of constructor.
+//! * In derived classes:
+//!   * Replace `super(...)` with `_this = _callSuper(this, Foo, [...])`.
+//!   * Replace `this` with `_this`.
+//!   * Return `_this` at end of constructor.
+
+use oxc_allocator::{Box as ArenaBox, TakeIn};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    Helper,
+    context::TraverseCtx,
+    utils::ast_builder::{create_assignment, create_member_callee},
+};
+
+use super::{Classes, super_converter::create_super_call_arguments_array};
+
+impl<'a> Classes<'a, '_> {
+    /// Create constructor function for a class which has no constructor.
+    ///
+    /// * Base class: `function Foo() {}`
+    /// * Derived class: `function Foo() { return _callSuper(this, Foo, arguments); }`
+    pub(super) fn create_default_constructor(
+        &self,
+        class_scope_id: ScopeId,
+        class_binding: &BoundIdentifier<'a>,
+        super_binding: Option<&BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        let scope_id =
+            ctx.create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::StrictMode);
+
+        let statements = if let Some(super_binding) = super_binding {
+            let arguments =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("arguments"), ReferenceFlags::Read);
+            let this = ctx.ast.expression_this(SPAN);
+            let super_call =
+                self.create_super_call(class_binding, super_binding, this, Some(arguments), ctx);
+            ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(super_call)))
+        } else {
+            ctx.ast.vec()
+        };
+
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
+        ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+        )
+    }
+
+    /// Transform class constructor.
+    ///
+    /// Inserts `_classCallCheck(this, Foo)` at start of constructor, and transforms `super()` calls
+    /// and `this` in derived class constructors.
+    pub(super) fn transform_constructor(
+        &self,
+        func: &mut Function<'a>,
+        class_binding: &BoundIdentifier<'a>,
+        super_binding: Option<&BoundIdentifier<'a>>,
+        is_default: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let scope_id = func.scope_id();
+        ctx.scoping_mut().scope_flags_mut(scope_id).remove(ScopeFlags::Constructor);
+
+        let body = func.body.as_mut().unwrap();
+        let mut insert_stmts = Vec::with_capacity(2);
+
+        if let Some(super_binding) = super_binding
+            && !is_default
+            && let Some(this_var_stmt) = self.transform_derived_constructor_body(
+                body,
+                scope_id,
+                class_binding,
+                super_binding,
+                ctx,
+            )
+        {
+            insert_stmts.push(this_var_stmt);
+        }
+
+        if !self.no_class_calls {
+            // `_classCallCheck(this, Foo);`
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ctx.ast.expression_this(SPAN)),
+                Argument::from(class_binding.create_read_expression(ctx)),
+            ]);
+            let call = self.ctx.helper_call_expr(Helper::ClassCallCheck, SPAN, arguments, ctx);
+            insert_stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        if !insert_stmts.is_empty() {
+            body.statements.splice(0..0, insert_stmts);
+        }
+    }
+
+    /// Transform body of constructor of a derived class.
+    ///
+    /// Returns `var _this;` statement to insert at start of constructor, if required.
+    fn transform_derived_constructor_body(
+        &self,
+        body: &mut FunctionBody<'a>,
+        scope_id: ScopeId,
+        class_binding: &BoundIdentifier<'a>,
+        super_binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        // `constructor(x) { super(x); }` -> `function Foo(x) { return _callSuper(this, Foo, [x]); }`
+        let mut counter = ThisAndSuperCounter::default();
+        counter.visit_function_body(body);
+        if counter.this_count == 0
+            && counter.super_call_count == 1
+            && counter.return_count == 0
+            && let Some(Statement::ExpressionStatement(expr_stmt)) = body.statements.last_mut()
+            && let Expression::CallExpression(call_expr) = &mut expr_stmt.expression
+            && call_expr.callee.is_super()
+        {
+            let span = call_expr.span;
+            let arguments = call_expr.arguments.take_in(ctx.ast);
+            let arguments = create_super_call_arguments_array(arguments, ctx);
+            let this = ctx.ast.expression_this(SPAN);
+            let super_call =
+                self.create_super_call(class_binding, super_binding, this, arguments, ctx);
+            *body.statements.last_mut().unwrap() = ctx.ast.statement_return(span, Some(super_call));
+            return None;
+        }
+
+        let this_binding = ctx.generate_uid("this", scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // Is `super()` called unconditionally at top level of constructor?
+        let has_top_level_super_call = body.statements.iter().any(|stmt| {
+            matches!(stmt, Statement::ExpressionStatement(expr_stmt)
+                if matches!(&expr_stmt.expression, Expression::CallExpression(call) if call.callee.is_super()))
+        });
+
+        let mut converter = DerivedConstructorConverter {
+            classes: self,
+            class_binding,
+            super_binding,
+            this_binding: &this_binding,
+            arrow_depth: 0,
+            ctx,
+        };
+        converter.visit_statements(&mut body.statements);
+
+        // Append `return _this;` or `return _assertThisInitialized(_this);`
+        if !matches!(body.statements.last(), Some(Statement::ReturnStatement(_))) {
+            let this = this_binding.create_read_expression(ctx);
+            let argument = if has_top_level_super_call {
+                this
+            } else {
+                self.ctx.helper_call_expr(
+                    Helper::AssertThisInitialized,
+                    SPAN,
+                    ctx.ast.vec1(Argument::from(this)),
+                    ctx,
+                )
+            };
+            body.statements.push(ctx.ast.statement_return(SPAN, Some(argument)));
+        }
+
+        // `var _this;`
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            this_binding.create_binding_pattern(ctx),
+            NONE,
+            None,
+            false,
+        );
+        Some(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec1(declarator),
+            false,
+        )))
+    }
+
+    /// Create expression calling super class constructor.
+    ///
+    /// * `_callSuper(this, Foo, [a, b])`
+    /// * `_Bar.call(this, a, b) || this` (loose)
+    ///
+    /// `arguments` is the array of arguments (or `arguments`) to pass to the super class constructor.
+    fn create_super_call(
+        &self,
+        class_binding: &BoundIdentifier<'a>,
+        super_binding: &BoundIdentifier<'a>,
+        this: Expression<'a>,
+        arguments: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        // `super(...a)` -> `[...a]` -> `_toConsumableArray(a)`, if spread transform is enabled
+        let arguments = match (arguments, &self.spread) {
+            (Some(Expression::ArrayExpression(array)), Some(spread))
+                if array.elements.iter().any(ArrayExpressionElement::is_spread) =>
+            {
+                Some(spread.transform_array_expression(array, ctx))
+            }
+            (arguments, _) => arguments,
+        };
+
+        if self.super_is_callable_constructor {
+            let super_class = super_binding.create_read_expression(ctx);
+            let call = match arguments {
+                // `_Bar.call(this, a, b)`
+                Some(Expression::ArrayExpression(array))
+                    if !array.elements.iter().any(ArrayExpressionElement::is_spread) =>
+                {
+                    let callee = create_member_callee(super_class, "call", ctx);
+                    let mut call_arguments = ctx.ast.vec_with_capacity(array.elements.len() + 1);
+                    call_arguments.push(Argument::from(this));
+                    call_arguments.extend(
+                        array
+                            .unbox()
+                            .elements
+                            .into_iter()
+                            .map(|element| Argument::from(element.into_expression())),
+                    );
+                    ctx.ast.expression_call(SPAN, callee, NONE, call_arguments, false)
+                }
+                // `_Bar.apply(this, arguments)`
+                Some(arguments) => {
+                    let callee = create_member_callee(super_class, "apply", ctx);
+                    let call_arguments =
+                        ctx.ast.vec_from_array([Argument::from(this), Argument::from(arguments)]);
+                    ctx.ast.expression_call(SPAN, callee, NONE, call_arguments, false)
+                }
+                // `_Bar.call(this)`
+                None => {
+                    let callee = create_member_callee(super_class, "call", ctx);
+                    ctx.ast.expression_call(
+                        SPAN,
+                        callee,
+                        NONE,
+                        ctx.ast.vec1(Argument::from(this)),
+                        false,
+                    )
+                }
+            };
+            // `_Bar.call(this) || this`
+            ctx.ast.expression_logical(
+                SPAN,
+                call,
+                LogicalOperator::Or,
+                ctx.ast.expression_this(SPAN),
+            )
+        } else {
+            // `_callSuper(this, Foo, [a, b])`
+            let mut call_arguments = ctx.ast.vec_with_capacity(3);
+            call_arguments.push(Argument::from(this));
+            call_arguments.push(Argument::from(class_binding.create_read_expression(ctx)));
+            if let Some(arguments) = arguments {
+                call_arguments.push(Argument::from(arguments));
+            }
+            self.ctx.helper_call_expr(Helper::CallSuper, SPAN, call_arguments, ctx)
+        }
+    }
+}
+
+/// Visitor to replace `this` and `super()` in constructor of a derived class.
+///
+/// * `super(a, b)` -> `_this = _callSuper(this, Foo, [a, b])`
+/// * `this` -> `_this`
+/// * `return;` -> `return _assertThisInitialized(_this);`
+/// * `return x;` -> `return _possibleConstructorReturn(_this, x);`
+struct DerivedConstructorConverter<'a, 'ctx, 'v> {
+    classes: &'v Classes<'a, 'ctx>,
+    class_binding: &'v BoundIdentifier<'a>,
+    super_binding: &'v BoundIdentifier<'a>,
+    this_binding: &'v BoundIdentifier<'a>,
+    /// Depth of arrow functions. `return` statements in arrow functions are not transformed.
+    arrow_depth: u32,
+    ctx: &'v mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for DerivedConstructorConverter<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(this) => {
+                *expr = self.this_binding.create_spanned_read_expression(this.span, self.ctx);
+            }
+            Expression::CallExpression(call_expr) if call_expr.callee.is_super() => {
+                // Visit arguments first. `super(this.x)` is not valid, but `super(() => this)` is.
+                self.visit_arguments(&mut call_expr.arguments);
+
+                let span = call_expr.span;
+                let arguments = call_expr.arguments.take_in(self.ctx.ast);
+                let arguments = create_super_call_arguments_array(arguments, self.ctx);
+                let this = self.ctx.ast.expression_this(SPAN);
+                let super_call = self.classes.create_super_call(
+                    self.class_binding,
+                    self.super_binding,
+                    this,
+                    arguments,
+                    self.ctx,
+                );
+                let mut assignment = create_assignment(self.this_binding, super_call, self.ctx);
+                if let Expression::AssignmentExpression(assignment) = &mut assignment {
+                    assignment.span = span;
+                }
+                *expr = assignment;
+            }
+            _ => walk_mut::walk_expression(self, expr),
+        }
+    }
+
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        walk_mut::walk_return_statement(self, stmt);
+        if self.arrow_depth > 0 {
+            return;
+        }
+
+        let this = self.this_binding.create_read_expression(self.ctx);
+        let argument = if let Some(argument) = stmt.argument.take() {
+            // `return x;` -> `return _possibleConstructorReturn(_this, x);`
+            let arguments =
+                self.ctx.ast.vec_from_array([Argument::from(this), Argument::from(argument)]);
+            self.classes.ctx.helper_call_expr(
+                Helper::PossibleConstructorReturn,
+                SPAN,
+                arguments,
+                self.ctx,
+            )
+        } else {
+            // `return;` -> `return _assertThisInitialized(_this);`
+            let arguments = self.ctx.ast.vec1(Argument::from(this));
+            self.classes.ctx.helper_call_expr(
+                Helper::AssertThisInitialized,
+                SPAN,
+                arguments,
+                self.ctx,
+            )
+        };
+        stmt.argument = Some(argument);
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+
+    #[inline] // `#[inline]` because is a no-op
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {
+        // `this` and `super()` in a nested function do not refer to the class being transformed
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        // Only `extends` clause and computed keys of nested class are evaluated in constructor's scope
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in &mut class.body.body {
+            match element {
+                ClassElement::MethodDefinition(method) if method.computed => {
+                    self.visit_property_key(&mut method.key);
+                }
+                ClassElement::PropertyDefinition(prop) if prop.computed => {
+                    self.visit_property_key(&mut prop.key);
+                }
+                ClassElement::AccessorProperty(prop) if prop.computed => {
+                    self.visit_property_key(&mut prop.key);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Visitor to count `this`, `super()` and `return` statements in a constructor.
+#[derive(Default)]
+struct ThisAndSuperCounter {
+    this_count: u32,
+    super_call_count: u32,
+    return_count: u32,
+    arrow_depth: u32,
+}
+
+impl<'a> Visit<'a> for ThisAndSuperCounter {
+    fn visit_this_expression(&mut self, _this: &ThisExpression) {
+        self.this_count += 1;
+    }
+
+    fn visit_call_expression(&mut self, call_expr: &CallExpression<'a>) {
+        if call_expr.callee.is_super() {
+            self.super_call_count += 1;
+        }
+        walk::walk_call_expression(self, call_expr);
+    }
+
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        if self.arrow_depth == 0 {
+            self.return_count += 1;
+        }
+        walk::walk_return_statement(self, stmt);
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+
+    #[inline] // `#[inline]` because is a no-op
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if let Some(super_class) = &class.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in &class.body.body {
+            if let Some(key) = element.property_key()
+                && element.computed()
+            {
+                self.visit_property_key(key);
+            }
+        }
+    }
+}
@@ -0,0 +1,296 @@
+//! ES2015: Classes
+//!
+//! This plugin transforms class declarations and class expressions to ES5 constructor functions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class Foo extends Bar {
+//!   constructor(x) {
+//!     super(x);
+//!     this.y = 1;
+//!   }
+//!   method() {
+//!     return super.method();
+//!   }
+//!   get prop() {
+//!     return 1;
+//!   }
+//!   static create() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! let Foo = /*#__PURE__*/ function (_Bar) {
+//!   function Foo(x) {
+//!     var _this;
+//!     babelHelpers.classCallCheck(this, Foo);
+//!     _this = babelHelpers.callSuper(this, Foo, [x]);
+//!     _this.y = 1;
+//!     return _this;
+//!   }
+//!   babelHelpers.inherits(Foo, _Bar);
+//!   return babelHelpers.createClass(Foo, [{
+//!     key: "method",
+//!     value: function method() {
+//!       return babelHelpers.superPropGet(Foo, "method", this, 3)([]);
+//!     }
+//!   }, {
+//!     key: "prop",
+//!     get: function () {
+//!       return 1;
+//!     }
+//!   }], [{
+//!     key: "create",
+//!     value: function create() {}
+//!   }]);
+//! }(Bar);
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! This option can also be enabled with `CompilerAssumptions::set_class_methods`,
+//! `CompilerAssumptions::no_class_calls` and `CompilerAssumptions::super_is_callable_constructor`.
+//!
+//! When `true`:
+//! * Methods are assigned to the prototype, instead of being defined with `createClass` helper.
+//! * `classCallCheck` is omitted.
+//! * Super class constructor is called with `_Bar.call(this) || this`, and `inheritsLoose` is used
+//!   instead of `inherits`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-classes](https://babel.dev/docs/babel-plugin-transform-classes).
+//!
+//! Transform happens in 3 phases:
+//!
+//! 1. On entering class ([`Classes::enter_class`]):
+//!    Create the binding for the constructor function, which `super` property accesses in class methods
+//!    refer to.
+//!
+//! 2. During traversal of class body ([`Classes::enter_expression`]):
+//!    Transform `super.prop`, `super.method()` and `super.prop = value` in class methods
+//!    to `superPropGet` / `superPropSet` helper calls.
+//!    This needs to happen during the main traversal, so that `this` inside arrow functions in these
+//!    expressions is handled by arrow functions transform.
+//!
+//! 3. On exiting class declaration / class expression ([`Classes::exit_statement`] and
+//!    [`Classes::exit_expression`]):
+//!    Build the constructor function, method descriptors, and wrap them in an IIFE.
+//!    This happens after all other class transforms (e.g. class properties, decorators) have been applied,
+//!    so the class contains only a constructor and methods at this point.
+//!
+//! ### Interaction with class properties transform
+//!
+//! Classes which still contain properties, accessor properties, static blocks or private methods
+//! cannot be lowered. These are expected to be transformed by class properties transform first.
+//! If they are found, an error is raised.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-classes>
+//! * Class definitions specification: <https://tc39.es/ecma262/#sec-class-definitions>
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use oxc_ast::ast::*;
+use oxc_semantic::{ScopeId, SymbolFlags};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::Spread;
+
+mod class;
+mod constructor;
+mod super_converter;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ClassesOptions {
+    pub loose: bool,
+}
+
+/// Classes transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct Classes<'a, 'ctx> {
+    // ----- Options -----
+    //
+    /// If `true`, assign methods to prototype instead of using `createClass` helper.
+    set_class_methods: bool,
+    /// If `true`, omit `classCallCheck` in constructor.
+    no_class_calls: bool,
+    /// If `true`, call super class with `_Super.call(this)`, instead of `callSuper` helper.
+    super_is_callable_constructor: bool,
+
+    ctx: &'ctx TransformCtx<'a>,
+    /// Spread transform, used to transform spread elements in `super()` call arguments.
+    spread: Option<Spread<'a, 'ctx>>,
+
+    /// Bindings for the constructor functions of classes being transformed.
+    /// Keyed by class's `ScopeId`.
+    class_bindings: FxHashMap<ScopeId, BoundIdentifier<'a>>,
+}
+
+impl<'a, 'ctx> Classes<'a, 'ctx> {
+    pub fn new(
+        options: ClassesOptions,
+        spread: Option<Spread<'a, 'ctx>>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        let assumptions = ctx.assumptions;
+        Self {
+            set_class_methods: options.loose || assumptions.set_class_methods,
+            no_class_calls: options.loose || assumptions.no_class_calls,
+            super_is_callable_constructor: options.loose
+                || assumptions.super_is_callable_constructor,
+            ctx,
+            spread,
+            class_bindings: FxHashMap::default(),
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Classes<'a, '_> {
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if class.declare {
+            return;
+        }
+        let binding = Self::create_class_binding(class, ctx);
+        self.class_bindings.insert(class.scope_id(), binding);
+    }
+
+    // `#[inline]` for fast exit for expressions which are not any of the transformed types
+    #[inline]
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            // `super.prop`, `super[prop]`
+            Expression::StaticMemberExpression(member) if member.object.is_super() => {
+                self.transform_super_member_expression(expr, ctx);
+            }
+            Expression::ComputedMemberExpression(member) if member.object.is_super() => {
+                self.transform_super_member_expression(expr, ctx);
+            }
+            // `super.method()`
+            Expression::CallExpression(call) if call.callee.is_member_expression() => {
+                if call.callee.as_member_expression().is_some_and(|m| m.object().is_super()) {
+                    self.transform_super_call_expression(expr, ctx);
+                }
+            }
+            // `super.prop = value`
+            Expression::AssignmentExpression(assign) => {
+                if assign.left.as_member_expression().is_some_and(|m| m.object().is_super()) {
+                    self.transform_super_assignment_expression(expr, ctx);
+                }
+            }
+            // `super.prop++`
+            Expression::UpdateExpression(update) => {
+                if update.argument.as_member_expression().is_some_and(|m| m.object().is_super()) {
+                    self.transform_super_update_expression(expr, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::ClassDeclaration(class) if !class.declare => {
+                self.transform_class_declaration(stmt, ctx);
+            }
+            Statement::ExportNamedDeclaration(export) => {
+                if matches!(&export.declaration, Some(Declaration::ClassDeclaration(class)) if !class.declare)
+                {
+                    self.transform_export_named_class(stmt, ctx);
+                }
+            }
+            Statement::ExportDefaultDeclaration(export) => {
+                if matches!(export.declaration, ExportDefaultDeclarationKind::ClassDeclaration(_)) {
+                    self.transform_export_default_class(stmt, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // `#[inline]` for fast exit for expressions which are not `Class`es
+    #[inline]
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::ClassExpression(_) => self.transform_class_expression(expr, ctx),
+            // Class properties transform wraps class expressions with static properties in a sequence:
+            // `(_Class = class {}, _defineProperty(_Class, "x", 1), _Class)`
+            Expression::SequenceExpression(seq) => {
+                for expr in &mut seq.expressions {
+                    match expr {
+                        Expression::ClassExpression(_) => {
+                            self.transform_class_expression(expr, ctx);
+                        }
+                        Expression::AssignmentExpression(assign)
+                            if matches!(assign.right, Expression::ClassExpression(_)) =>
+                        {
+                            self.transform_class_expression(&mut assign.right, ctx);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Classes<'a, '_> {
+    /// Create binding for constructor function of the class.
+    ///
+    /// * `class Foo {}` -> New `Foo` binding in class scope.
+    ///   References to `Foo` within class body are re-pointed to it when class is transformed.
+    /// * `let x = class Foo {}` -> Existing `Foo` binding.
+    /// * `let x = class {}` -> New `x` binding, or a UID if class body references `x`.
+    /// * `export default class {}` -> New `_default` binding.
+    fn create_class_binding(class: &Class<'a>, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        let scope_id = class.scope_id();
+        if let Some(ident) = &class.id {
+            return if class.is_declaration() {
+                ctx.generate_binding(ident.name, scope_id, SymbolFlags::Function)
+            } else {
+                BoundIdentifier::from_binding_ident(ident)
+            };
+        }
+
+        let (name, is_uid) = match ctx.parent() {
+            // `export default class {}`
+            Ancestor::ExportDefaultDeclarationDeclaration(_) => (Atom::from("default"), true),
+            // `let x = class {}`
+            Ancestor::VariableDeclaratorInit(decl) => match decl.id().get_binding_identifier() {
+                Some(ident) => (ident.name, false),
+                None => (Atom::from("Class"), true),
+            },
+            // `x = class {}`
+            Ancestor::AssignmentExpressionRight(assign) => match assign.left() {
+                AssignmentTarget::AssignmentTargetIdentifier(ident) => (ident.name, false),
+                _ => (Atom::from("Class"), true),
+            },
+            _ => (Atom::from("Class"), true),
+        };
+
+        if is_uid || class::class_body_references_name(class, &name) {
+            ctx.generate_uid(&name, scope_id, SymbolFlags::Function)
+        } else {
+            ctx.generate_binding(name, scope_id, SymbolFlags::Function)
+        }
+    }
+}
@@ -0,0 +1,309 @@
+//! ES2015: Classes
+//! Transform of `super` expressions in class methods.
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+use oxc_traverse::{Ancestor, BoundIdentifier, ast_operations::get_var_name_from_node};
+
+use crate::{Helper, context::TraverseCtx, utils::ast_builder::create_assignment};
+
+use super::Classes;
+
+/// Class which a `super` expression refers to.
+struct SuperHome<'a> {
+    /// Binding for the class's constructor function
+    class_binding: BoundIdentifier<'a>,
+    /// `true` if `super` is in a static method
+    is_static: bool,
+}
+
+impl<'a> Classes<'a, '_> {
+    /// Transform member expression where object is `super`.
+    ///
+    /// * `super.prop` -> `_superPropGet(Foo, "prop", this, 1)`
+    /// * `super[prop]` -> `_superPropGet(Foo, prop, this, 1)`
+    ///
+    /// In static methods, the last argument is omitted:
+    /// * `super.prop` -> `_superPropGet(Foo, "prop", this)`
+    pub(super) fn transform_super_member_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(home) = self.get_super_home(ctx) else { return };
+        let (span, property) = match expr {
+            Expression::StaticMemberExpression(member) => {
+                (member.span, Self::create_static_property(&member.property, ctx))
+            }
+            Expression::ComputedMemberExpression(member) => {
+                (member.span, member.expression.get_inner_expression_mut().take_in(ctx.ast))
+            }
+            _ => unreachable!(),
+        };
+        *expr = self.create_super_prop_get(&home, span, property, false, ctx);
+    }
+
+    /// Transform call expression where callee is a member expression with `super` as object.
+    ///
+    /// * `super.method()` -> `_superPropGet(Foo, "method", this, 3)([])`
+    /// * `super.method(1, 2)` -> `_superPropGet(Foo, "method", this, 3)([1, 2])`
+    ///
+    /// In static methods:
+    /// * `super.method()` -> `_superPropGet(Foo, "method", this, 2)([])`
+    pub(super) fn transform_super_call_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(home) = self.get_super_home(ctx) else { return };
+        let Expression::CallExpression(call_expr) = expr else { unreachable!() };
+        let (span, property) = match &mut call_expr.callee {
+            Expression::StaticMemberExpression(member) => {
+                (member.span, Self::create_static_property(&member.property, ctx))
+            }
+            Expression::ComputedMemberExpression(member) => {
+                (member.span, member.expression.get_inner_expression_mut().take_in(ctx.ast))
+            }
+            _ => unreachable!(),
+        };
+        call_expr.callee = self.create_super_prop_get(&home, span, property, true, ctx);
+
+        // `(a, b)` -> `([a, b])`
+        let elements = call_expr.arguments.drain(..).map(ArrayExpressionElement::from);
+        let elements = ctx.ast.vec_from_iter(elements);
+        let array = ctx.ast.expression_array(SPAN, elements);
+        call_expr.arguments.push(Argument::from(array));
+    }
+
+    /// Transform assignment expression where the left-hand side is a member expression with `super`
+    /// as object.
+    ///
+    /// * `super.prop = value`
+    ///   -> `_superPropSet(Foo, "prop", value, this, 1, 1)`
+    /// * `super.prop += value`
+    ///   -> `_superPropSet(Foo, "prop", _superPropGet(Foo, "prop", this, 1) + value, this, 1, 1)`
+    /// * `super.prop &&= value`
+    ///   -> `_superPropGet(Foo, "prop", this, 1) && _superPropSet(Foo, "prop", value, this, 1, 1)`
+    pub(super) fn transform_super_assignment_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(home) = self.get_super_home(ctx) else { return };
+        let Expression::AssignmentExpression(assign_expr) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, operator, right: value, left } = assign_expr.unbox();
+        let property = match left {
+            AssignmentTarget::StaticMemberExpression(member) => {
+                Self::create_static_property(&member.property, ctx)
+            }
+            AssignmentTarget::ComputedMemberExpression(member) => {
+                member.unbox().expression.into_inner_expression()
+            }
+            _ => unreachable!(),
+        };
+
+        *expr = if operator == AssignmentOperator::Assign {
+            self.create_super_prop_set(&home, span, property, value, ctx)
+        } else {
+            let (property1, property2) = self.ctx.duplicate_expression(property, false, ctx);
+            if let Some(operator) = operator.to_binary_operator() {
+                let get_call = self.create_super_prop_get(&home, SPAN, property2, false, ctx);
+                let value = ctx.ast.expression_binary(SPAN, get_call, operator, value);
+                self.create_super_prop_set(&home, span, property1, value, ctx)
+            } else if let Some(operator) = operator.to_logical_operator() {
+                let get_call = self.create_super_prop_get(&home, SPAN, property1, false, ctx);
+                let set_call = self.create_super_prop_set(&home, SPAN, property2, value, ctx);
+                ctx.ast.expression_logical(span, get_call, operator, set_call)
+            } else {
+                // The above covers all types of `AssignmentOperator`
+                unreachable!();
+            }
+        };
+    }
+
+    /// Transform update expression where the argument is a member expression with `super` as object.
+    ///
+    /// * `++super.prop`
+    ///   -> `_superPropSet(Foo, "prop", (_super$prop = _superPropGet(Foo, "prop", this, 1), ++_super$prop), this, 1, 1)`
+    /// * `super.prop++`
+    ///   -> `(_superPropSet(Foo, "prop", (_super$prop = _superPropGet(Foo, "prop", this, 1), _super$prop2 = _super$prop++, _super$prop), this, 1, 1), _super$prop2)`
+    pub(super) fn transform_super_update_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(home) = self.get_super_home(ctx) else { return };
+        let Expression::UpdateExpression(mut update_expr) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let (temp_var_name_base, property) = match &mut update_expr.argument {
+            SimpleAssignmentTarget::StaticMemberExpression(member) => (
+                get_var_name_from_node(member.as_ref()),
+                Self::create_static_property(&member.property, ctx),
+            ),
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => (
+                get_var_name_from_node(member.as_ref()),
+                member.expression.get_inner_expression_mut().take_in(ctx.ast),
+            ),
+            _ => unreachable!(),
+        };
+
+        let (property1, property2) = self.ctx.duplicate_expression(property, false, ctx);
+
+        // `_super$prop = _superPropGet(Foo, prop, this, 1)`
+        let get_call = self.create_super_prop_get(&home, SPAN, property2, false, ctx);
+        let temp_binding = self.ctx.var_declarations.create_uid_var(&temp_var_name_base, ctx);
+        let assignment = create_assignment(&temp_binding, get_call, ctx);
+
+        // `++_super$prop` / `_super$prop++` (reusing existing `UpdateExpression`)
+        let span = update_expr.span;
+        let prefix = update_expr.prefix;
+        update_expr.span = SPAN;
+        update_expr.argument = temp_binding.create_read_write_simple_target(ctx);
+        let update_expr = Expression::UpdateExpression(update_expr);
+
+        *expr = if prefix {
+            // `(_super$prop = _superPropGet(Foo, prop, this, 1), ++_super$prop)`
+            let value = ctx
+                .ast
+                .expression_sequence(SPAN, ctx.ast.vec_from_array([assignment, update_expr]));
+            self.create_super_prop_set(&home, span, property1, value, ctx)
+        } else {
+            // `_super$prop2 = _super$prop++`
+            let temp_binding2 = self.ctx.var_declarations.create_uid_var(&temp_var_name_base, ctx);
+            let assignment2 = create_assignment(&temp_binding2, update_expr, ctx);
+
+            // `(_super$prop = _superPropGet(Foo, prop, this, 1), _super$prop2 = _super$prop++, _super$prop)`
+            let value = ctx.ast.expression_sequence(
+                SPAN,
+                ctx.ast.vec_from_array([
+                    assignment,
+                    assignment2,
+                    temp_binding.create_read_expression(ctx),
+                ]),
+            );
+            let set_call = self.create_super_prop_set(&home, SPAN, property1, value, ctx);
+            ctx.ast.expression_sequence(
+                span,
+                ctx.ast.vec_from_array([set_call, temp_binding2.create_read_expression(ctx)]),
+            )
+        };
+    }
+
+    /// Find the class method which `super` is in.
+    ///
+    /// Returns `None` if `super` is not inside a method of a class being transformed.
+    /// `super` in static blocks and property initializers is handled by class properties transform.
+    fn get_super_home(&self, ctx: &TraverseCtx<'a>) -> Option<SuperHome<'a>> {
+        let mut ancestors = ctx.ancestors();
+        loop {
+            match ancestors.next()? {
+                Ancestor::FunctionBody(_) | Ancestor::FunctionParams(_) => break,
+                Ancestor::StaticBlockBody(_)
+                | Ancestor::PropertyDefinitionValue(_)
+                | Ancestor::AccessorPropertyValue(_)
+                | Ancestor::ClassBodyBody(_) => return None,
+                _ => {}
+            }
+        }
+
+        let Ancestor::MethodDefinitionValue(method) = ancestors.next()? else { return None };
+        let is_static = *method.r#static();
+        let Ancestor::ClassBodyBody(_) = ancestors.next()? else { return None };
+        let Ancestor::ClassBody(class) = ancestors.next()? else { return None };
+        let scope_id = class.scope_id().get()?;
+        let class_binding = self.class_bindings.get(&scope_id)?.clone();
+        Some(SuperHome { class_binding, is_static })
+    }
+
+    /// `super.prop` -> `"prop"`
+    fn create_static_property(
+        property: &IdentifierName<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        ctx.ast.expression_string_literal(property.span, property.name, None)
+    }
+
+    /// Member:
+    ///  `_superPropGet(Foo, prop, this, 1)` (instance) or `_superPropGet(Foo, prop, this)` (static)
+    ///
+    /// Callee:
+    ///  `_superPropGet(Foo, prop, this, 3)` (instance) or `_superPropGet(Foo, prop, this, 2)` (static)
+    fn create_super_prop_get(
+        &self,
+        home: &SuperHome<'a>,
+        span: Span,
+        property: Expression<'a>,
+        is_callee: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec_with_capacity(4);
+        arguments.push(Argument::from(home.class_binding.create_read_expression(ctx)));
+        arguments.push(Argument::from(property));
+        arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+
+        let flags = u8::from(!home.is_static) | (u8::from(is_callee) << 1);
+        if flags != 0 {
+            arguments.push(Self::create_number_argument(f64::from(flags), ctx));
+        }
+
+        self.ctx.helper_call_expr(Helper::SuperPropGet, span, arguments, ctx)
+    }
+
+    /// `_superPropSet(Foo, prop, value, this, 1, 1)` (instance)
+    /// or `_superPropSet(Foo, prop, value, this, 1)` (static)
+    fn create_super_prop_set(
+        &self,
+        home: &SuperHome<'a>,
+        span: Span,
+        property: Expression<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments: ArenaVec<'a, Argument<'a>> = ctx.ast.vec_from_array([
+            Argument::from(home.class_binding.create_read_expression(ctx)),
+            Argument::from(property),
+            Argument::from(value),
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            Self::create_number_argument(1.0, ctx),
+        ]);
+        if !home.is_static {
+            arguments.push(Self::create_number_argument(1.0, ctx));
+        }
+
+        self.ctx.helper_call_expr(Helper::SuperPropSet, span, arguments, ctx)
+    }
+
+    fn create_number_argument(value: f64, ctx: &TraverseCtx<'a>) -> Argument<'a> {
+        Argument::from(ctx.ast.expression_numeric_literal(SPAN, value, None, NumberBase::Decimal))
+    }
+}
+
+/// Convert arguments of `super(...)` call to a single argument to pass to `callSuper` or `apply`.
+///
+/// * `super()` -> `None`
+/// * `super(...arguments)` -> `arguments`
+/// * `super(a, b)` -> `[a, b]`
+pub(super) fn create_super_call_arguments_array<'a>(
+    arguments: ArenaVec<'a, Argument<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> Option<Expression<'a>> {
+    if arguments.is_empty() {
+        return None;
+    }
+    if arguments.len() == 1
+        && let Argument::SpreadElement(spread) = &arguments[0]
+        && matches!(&spread.argument, Expression::Identifier(ident) if ident.name == "arguments")
+    {
+        let Some(Argument::SpreadElement(spread)) = arguments.into_iter().next() else {
+            unreachable!()
+        };
+        return Some(ArenaBox::unbox(spread).argument);
+    }
+
+    let elements = ctx.ast.vec_from_iter(arguments.into_iter().map(ArrayExpressionElement::from));
+    Some(ctx.ast.expression_array(SPAN, elements))
+}
@@ -0,0 +1,231 @@
+//! ES2015: Computed Properties
+//!
+//! This plugin transforms object literals containing computed property keys.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var a = { [x]: 1 };
+//! var b = { c: 1, [x]: 2, d: 3, get [y]() {} };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _obj;
+//! var a = babelHelpers.defineProperty({}, x, 1);
+//! var b = (_obj = { c: 1 }, babelHelpers.defineProperty(_obj, x, 2), babelHelpers.defineProperty(_obj, "d", 3), babelHelpers.defineAccessor("get", _obj, y, function() {}), _obj);
+//! ```
+//!
+//! Properties before the first computed key are kept in the object literal.
+//! All properties after it are defined in order, so that keys and values are evaluated in order.
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! This option can also be enabled with `CompilerAssumptions::set_computed_properties`.
+//!
+//! When `true`, properties are set with assignments instead of `defineProperty` helper:
+//! `(_obj = { c: 1 }, _obj[x] = 2, _obj.d = 3, _obj)`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-computed-properties](https://babel.dev/docs/babel-plugin-transform-computed-properties).
+//!
+//! Object literals containing spread properties after a computed key are not transformed.
+//! Spread properties are transformed to helper calls by ES2018 object-rest-spread transform.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-computed-properties>
+//! * Object initializer specification: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use serde::Deserialize;
+
+use oxc_allocator::{Box as ArenaBox, TakeIn};
+use oxc_ast::ast::*;
+use oxc_semantic::ScopeFlags;
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ComputedPropertiesOptions {
+    pub loose: bool,
+}
+
+/// Computed properties transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct ComputedProperties<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// If `true`, set properties with assignments instead of `defineProperty` helper.
+    set_computed_properties: bool,
+}
+
+impl<'a, 'ctx> ComputedProperties<'a, 'ctx> {
+    pub fn new(options: ComputedPropertiesOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            set_computed_properties: options.loose || ctx.assumptions.set_computed_properties,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ComputedProperties<'a, '_> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ObjectExpression(object) = expr else { return };
+        let Some(first_computed_index) = object.properties.iter().position(
+            |prop| matches!(prop, ObjectPropertyKind::ObjectProperty(prop) if prop.computed),
+        ) else {
+            return;
+        };
+        if object.properties[first_computed_index..]
+            .iter()
+            .any(|prop| matches!(prop, ObjectPropertyKind::SpreadProperty(_)))
+        {
+            return;
+        }
+
+        let Expression::ObjectExpression(object) = expr.take_in(ctx.ast) else { unreachable!() };
+        *expr = self.transform_object_expression(object, first_computed_index, ctx);
+    }
+}
+
+impl<'a> ComputedProperties<'a, '_> {
+    fn transform_object_expression(
+        &self,
+        mut object: ArenaBox<'a, ObjectExpression<'a>>,
+        first_computed_index: usize,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut computed_properties =
+            object.properties.drain(first_computed_index..).collect::<Vec<_>>();
+        let init = Expression::ObjectExpression(object);
+
+        // `babelHelpers.defineProperty({}, x, 1)`
+        if !self.set_computed_properties
+            && computed_properties.len() == 1
+            && matches!(
+                &computed_properties[0],
+                ObjectPropertyKind::ObjectProperty(prop) if prop.kind == PropertyKind::Init
+            )
+        {
+            let Some(ObjectPropertyKind::ObjectProperty(prop)) = computed_properties.pop() else {
+                unreachable!()
+            };
+            let ObjectProperty { key, value, .. } = prop.unbox();
+            let key = Self::create_key(key, ctx);
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(init),
+                Argument::from(key),
+                Argument::from(value),
+            ]);
+            return self.ctx.helper_call_expr(Helper::DefineProperty, SPAN, arguments, ctx);
+        }
+
+        // `(_obj = { c: 1 }, babelHelpers.defineProperty(_obj, x, 2), _obj)`
+        let binding = self.ctx.var_declarations.create_uid_var("obj", ctx);
+        let mut expressions = ctx.ast.vec_with_capacity(computed_properties.len() + 2);
+        expressions.push(ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            init,
+        ));
+        for prop in computed_properties {
+            let ObjectPropertyKind::ObjectProperty(prop) = prop else { unreachable!() };
+            expressions.push(self.define_property(prop.unbox(), &binding, ctx));
+        }
+        expressions.push(binding.create_read_expression(ctx));
+        ctx.ast.expression_sequence(SPAN, expressions)
+    }
+
+    /// Define a property on object.
+    ///
+    /// * `[x]: 1` -> `babelHelpers.defineProperty(_obj, x, 1)`
+    /// * `[x]: 1` -> `_obj[x] = 1` (`set_computed_properties`)
+    /// * `get [x]() {}` -> `babelHelpers.defineAccessor("get", _obj, x, function() {})`
+    fn define_property(
+        &self,
+        prop: ObjectProperty<'a>,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let ObjectProperty { kind, key, value, .. } = prop;
+        let object = binding.create_read_expression(ctx);
+
+        match kind {
+            PropertyKind::Init if self.set_computed_properties => {
+                let target = match key {
+                    PropertyKey::StaticIdentifier(ident) => {
+                        let property = ctx.ast.identifier_name(ident.span, ident.name);
+                        AssignmentTarget::from(
+                            ctx.ast.member_expression_static(SPAN, object, property, false),
+                        )
+                    }
+                    key => {
+                        let key = Self::create_key(key, ctx);
+                        AssignmentTarget::from(
+                            ctx.ast.member_expression_computed(SPAN, object, key, false),
+                        )
+                    }
+                };
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+            }
+            PropertyKind::Init => {
+                let key = Self::create_key(key, ctx);
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(object),
+                    Argument::from(key),
+                    Argument::from(value),
+                ]);
+                self.ctx.helper_call_expr(Helper::DefineProperty, SPAN, arguments, ctx)
+            }
+            PropertyKind::Get | PropertyKind::Set => {
+                let kind = if kind == PropertyKind::Get { "get" } else { "set" };
+                // Accessor becomes a plain function expression
+                if let Expression::FunctionExpression(func) = &value {
+                    ctx.scoping_mut()
+                        .scope_flags_mut(func.scope_id())
+                        .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+                }
+                let key = Self::create_key(key, ctx);
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(ctx.ast.expression_string_literal(SPAN, kind, None)),
+                    Argument::from(object),
+                    Argument::from(key),
+                    Argument::from(value),
+                ]);
+                self.ctx.helper_call_expr(Helper::DefineAccessor, SPAN, arguments, ctx)
+            }
+        }
+    }
+
+    /// Convert property key to an expression.
+    ///
+    /// * `[x]` -> `x`
+    /// * `a` -> `"a"`
+    /// * `"a"` -> `"a"`
+    fn create_key(key: PropertyKey<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(ident.span, ident.name, None)
+            }
+            PropertyKey::PrivateIdentifier(_) => unreachable!(),
+            key => key.into_expression(),
+        }
+    }
+}
@@ -0,0 +1,999 @@
+//! ES2015: Destructuring
+//!
+//! This plugin transforms destructuring patterns into plain assignments.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var [a, b = 1, ...c] = arr;
+//! var { x, y: { z } } = obj;
+//! [a, b] = [b, a];
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _arr = babelHelpers.toArray(arr),
+//!   a = _arr[0],
+//!   _arr$ = _arr[1],
+//!   b = _arr$ === void 0 ? 1 : _arr$,
+//!   c = _arr.slice(2);
+//! var _obj = obj,
+//!   x = _obj.x,
+//!   z = _obj.y.z;
+//! var _ref = [b, a];
+//! _ref2 = babelHelpers.slicedToArray(_ref, 2), a = _ref2[0], b = _ref2[1];
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! This option can also be enabled with `CompilerAssumptions::iterable_is_array`.
+//!
+//! When `true`, array patterns index into the value directly (`_arr[0]`), instead of iterating it
+//! with `slicedToArray` / `toArray` helpers.
+//!
+//! ### `allowArrayLike`
+//!
+//! This option can also be enabled with `CompilerAssumptions::array_like_is_iterable`.
+//!
+//! When `true`, array-like objects (objects with a `length` property, which are not iterable)
+//! can be destructured by array patterns. The helper call is wrapped in `maybeArrayLike` helper.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-destructuring](https://babel.dev/docs/babel-plugin-transform-destructuring).
+//!
+//! Patterns are transformed on entering the node which contains them, so that default values and
+//! computed keys which are moved into the flattened output are visited by other transforms afterwards.
+//!
+//! * Variable declarations: The declarator is replaced with a list of declarators.
+//! * Assignment expressions: The assignment is replaced with a sequence expression.
+//!   Temp vars are hoisted to the enclosing function.
+//! * Function parameters, catch clause parameters, and `for-in` / `for-of` heads:
+//!   The pattern is replaced with a `_ref` binding, and a variable declaration `var <pattern> = _ref`
+//!   is inserted at start of the body. That declaration is then transformed when the body is visited.
+//!
+//! Object rest (`{ a, ...rest }`) must be transformed by object rest spread transform first.
+//! If an object rest is found, an error is raised.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-destructuring>
+//! * Destructuring assignment specification: <https://tc39.es/ecma262/#sec-destructuring-assignment>
+
+use std::mem;
+
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    ArenaVec,
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DestructuringOptions {
+    pub loose: bool,
+
+    pub allow_array_like: bool,
+}
+
+/// Destructuring transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct Destructuring<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// If `true`, index into array patterns' values directly, instead of using `slicedToArray` helper.
+    iterable_is_array: bool,
+    /// If `true`, wrap `slicedToArray` / `toArray` helper calls in `maybeArrayLike` helper.
+    array_like_is_iterable: bool,
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    pub fn new(options: DestructuringOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            iterable_is_array: options.loose || ctx.assumptions.iterable_is_array,
+            array_like_is_iterable: options.allow_array_like
+                || ctx.assumptions.array_like_is_iterable,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Destructuring<'a, '_> {
+    // `[a, b] = c`, `({ a, b } = c)`.
+    // `#[inline]` for fast exit for expressions which are not assignments.
+    #[inline]
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::AssignmentExpression(assign) = expr
+            && assign.operator == AssignmentOperator::Assign
+            && assign.left.is_assignment_target_pattern()
+        {
+            self.transform_assignment_expression(expr, ctx);
+        }
+    }
+
+    // `var [a, b] = c`.
+    // Includes `for (var [a, b] = c;;);`.
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.transform_variable_declaration(decl, ctx);
+    }
+
+    // `function f([a, b]) {}`.
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = func.scope_id();
+        if let Some(body) = &mut func.body {
+            Self::transform_parameters(&mut func.params, &mut body.statements, scope_id, ctx);
+        }
+    }
+
+    // `([a, b]) => {}`.
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // If the arrow function has an expression body, the inserted declarations come before
+        // the expression statement, which is converted to a `return` statement on exit.
+        let scope_id = arrow.scope_id();
+        Self::transform_parameters(&mut arrow.params, &mut arrow.body.statements, scope_id, ctx);
+    }
+
+    // `try {} catch ([a, b]) {}`.
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        Self::transform_catch_clause(clause, ctx);
+    }
+
+    // `for (var [a, b] of c);`, `for ([a, b] of c);`, `export var [a, b] = c;`.
+    //
+    // `for-in` / `for-of` heads are transformed in `enter_statement` rather than
+    // `enter_for_of_statement`, so that block scoping transform sees the bindings in the loop body.
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::ForInStatement(for_in) => {
+                let for_in = &mut **for_in;
+                let scope_id = for_in.scope_id();
+                Self::transform_for_statement_left(
+                    &mut for_in.left,
+                    &mut for_in.body,
+                    scope_id,
+                    ctx,
+                );
+            }
+            Statement::ForOfStatement(for_of) => {
+                let for_of = &mut **for_of;
+                let scope_id = for_of.scope_id();
+                Self::transform_for_statement_left(
+                    &mut for_of.left,
+                    &mut for_of.body,
+                    scope_id,
+                    ctx,
+                );
+            }
+            Statement::ExportNamedDeclaration(export) => {
+                if let Some(Declaration::VariableDeclaration(decl)) = &export.declaration
+                    && decl.declarations.iter().any(|d| d.id.is_destructuring_pattern())
+                {
+                    self.transform_export_variable_declaration(stmt, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Destructuring<'a, '_> {
+    /// Transform variable declaration.
+    ///
+    /// `var [a, b] = c, d = 1` -> `var _c = _slicedToArray(c, 2), a = _c[0], b = _c[1], d = 1`
+    fn transform_variable_declaration(
+        &self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !decl.declarations.iter().any(|d| d.init.is_some() && d.id.is_destructuring_pattern()) {
+            return;
+        }
+
+        let mut declarators = ctx.ast.vec_with_capacity(decl.declarations.len());
+        for declarator in decl.declarations.take_in(ctx.ast) {
+            if declarator.init.is_some() && declarator.id.is_destructuring_pattern() {
+                declarators.extend(self.flatten_variable_declarator(declarator, ctx));
+            } else {
+                declarators.push(declarator);
+            }
+        }
+        decl.declarations = declarators;
+    }
+
+    fn flatten_variable_declarator(
+        &self,
+        declarator: VariableDeclarator<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<VariableDeclarator<'a>> {
+        let VariableDeclarator { kind, id, init, .. } = declarator;
+
+        // Temp vars are created in same scope as the bindings.
+        // Scope differs for `for (var [a] = [];;);` and `for (let [a] = [];;);`.
+        let mut scope_id = None;
+        let mut bound_symbols = vec![];
+        id.bound_names(&mut |ident| {
+            let symbol_id = ident.symbol_id();
+            scope_id.get_or_insert_with(|| ctx.scoping().symbol_scope_id(symbol_id));
+            bound_symbols.push(symbol_id);
+        });
+        let scope_id = scope_id.unwrap_or_else(|| {
+            if kind.is_var() { ctx.current_hoist_scope_id() } else { ctx.current_scope_id() }
+        });
+
+        let output = Output::Declarators {
+            kind,
+            scope_id,
+            symbol_flags: kind_to_symbol_flags(kind),
+            declarators: vec![],
+        };
+        let mut flattener = PatternFlattener::new(self, output, bound_symbols);
+        flattener.flatten_binding_pattern(id, init.unwrap(), ctx);
+        let Output::Declarators { declarators, .. } = flattener.output else { unreachable!() };
+        declarators
+    }
+
+    /// Transform assignment expression.
+    ///
+    /// * `[a, b] = c;` -> `_c = _slicedToArray(c, 2), a = _c[0], b = _c[1];`
+    /// * `x = [a, b] = c` -> `x = (_c = c, _c2 = _slicedToArray(_c, 2), a = _c2[0], b = _c2[1], _c)`
+    fn transform_assignment_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::AssignmentExpression(assign) = expr else { unreachable!() };
+
+        let span = assign.span;
+        let target = assign.left.take_in(ctx.ast);
+        let mut value = assign.right.take_in(ctx.ast);
+
+        let mut flattener = PatternFlattener::new(self, Output::Assignments(vec![]), vec![]);
+
+        // Value of the assignment expression is the right hand side, so store it in a temp var
+        // if the value is used
+        let result = if Self::is_assignment_value_used(ctx) {
+            let temp = flattener.create_temp(&value, ctx);
+            flattener.push_temp(&temp, value, ctx);
+            value = temp.create_read_expression(ctx);
+            Some(temp)
+        } else {
+            None
+        };
+
+        flattener.flatten_assignment_target(target, value, ctx);
+
+        let Output::Assignments(mut expressions) = flattener.output else { unreachable!() };
+        if let Some(result) = result {
+            expressions.push(result.create_read_expression(ctx));
+        }
+        *expr = if expressions.len() == 1 {
+            expressions.pop().unwrap()
+        } else {
+            ctx.ast.expression_sequence(span, ctx.ast.vec_from_iter(expressions))
+        };
+    }
+
+    /// Check if value of the current assignment expression is used.
+    ///
+    /// `[a, b] = c;` -> `false`
+    /// `x = [a, b] = c` -> `true`
+    /// `() => [a, b] = c` -> `true`
+    fn is_assignment_value_used(ctx: &TraverseCtx<'a>) -> bool {
+        let mut ancestors = ctx.ancestors().skip_while(|ancestor| {
+            matches!(ancestor, Ancestor::ParenthesizedExpressionExpression(_))
+        });
+        match ancestors.next() {
+            Some(Ancestor::ExpressionStatementExpression(_)) => {
+                // Expression statement which is body of an arrow function with expression body
+                if let Some(Ancestor::FunctionBodyStatements(_)) = ancestors.next()
+                    && let Some(Ancestor::ArrowFunctionExpressionBody(arrow)) = ancestors.next()
+                {
+                    return *arrow.expression();
+                }
+                false
+            }
+            Some(Ancestor::ForStatementInit(_) | Ancestor::ForStatementUpdate(_)) => false,
+            _ => true,
+        }
+    }
+
+    /// Transform destructuring in function parameters.
+    ///
+    /// `function f([a, b], { c }) {}` -> `function f(_ref, _ref2) { var [a, b] = _ref, { c } = _ref2; }`
+    ///
+    /// The inserted declarations are transformed when function body is visited.
+    fn transform_parameters(
+        params: &mut FormalParameters<'a>,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let kind = VariableDeclarationKind::Var;
+        let mut declarators = vec![];
+        for param in &mut params.items {
+            if param.pattern.is_destructuring_pattern() {
+                declarators.push(Self::replace_pattern_with_reference(
+                    &mut param.pattern,
+                    kind,
+                    scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                    ctx,
+                ));
+            }
+        }
+        if let Some(rest) = &mut params.rest
+            && rest.rest.argument.is_destructuring_pattern()
+        {
+            declarators.push(Self::replace_pattern_with_reference(
+                &mut rest.rest.argument,
+                kind,
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+                ctx,
+            ));
+        }
+
+        if !declarators.is_empty() {
+            let declarators = ctx.ast.vec_from_iter(declarators);
+            let decl = ctx.ast.alloc_variable_declaration(SPAN, kind, declarators, false);
+            statements.insert(0, Statement::VariableDeclaration(decl));
+        }
+    }
+
+    /// Transform destructuring in catch clause parameter.
+    ///
+    /// `try {} catch ([a, b]) {}` -> `try {} catch (_ref) { let [a, b] = _ref; }`
+    fn transform_catch_clause(clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        let body_scope_id = clause.body.scope_id();
+        let Some(param) = &mut clause.param else { return };
+        if !param.pattern.is_destructuring_pattern() {
+            return;
+        }
+
+        // Bindings are already in scope of the catch body, but are now declared with `let`
+        param.pattern.bound_names(&mut |ident| {
+            ctx.scoping_mut()
+                .symbol_flags_mut(ident.symbol_id())
+                .remove(SymbolFlags::CatchVariable);
+        });
+
+        let kind = VariableDeclarationKind::Let;
+        let declarator = Self::replace_pattern_with_reference(
+            &mut param.pattern,
+            kind,
+            body_scope_id,
+            SymbolFlags::FunctionScopedVariable | SymbolFlags::CatchVariable,
+            ctx,
+        );
+        let decl = ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false);
+        clause.body.body.insert(0, Statement::VariableDeclaration(decl));
+    }
+
+    /// Transform destructuring in `for-in` / `for-of` loop head.
+    ///
+    /// * `for (const [a, b] of c) {}` -> `for (const _ref of c) { const [a, b] = _ref; }`
+    /// * `for ([a, b] of c) {}` -> `for (var _ref of c) { [a, b] = _ref; }`
+    ///
+    /// The inserted statement is transformed when loop body is visited.
+    fn transform_for_statement_left(
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let stmt = if let ForStatementLeft::VariableDeclaration(decl) = left {
+            let kind = decl.kind;
+            let declarator = &mut decl.declarations[0];
+            if !declarator.id.is_destructuring_pattern() {
+                return;
+            }
+
+            // Move bindings of `let` / `const` into the loop body
+            let body_scope_id = Self::wrap_statement_in_block(body, scope_id, ctx);
+            let binding_scope_id = if kind.is_var() {
+                ctx.current_hoist_scope_id()
+            } else {
+                declarator.id.bound_names(&mut |ident| {
+                    let scoping = ctx.scoping_mut();
+                    scoping.set_symbol_scope_id(ident.symbol_id(), body_scope_id);
+                    scoping.move_binding(scope_id, body_scope_id, &ident.name);
+                });
+                scope_id
+            };
+
+            let declarator = Self::replace_pattern_with_reference(
+                &mut declarator.id,
+                kind,
+                binding_scope_id,
+                kind_to_symbol_flags(kind),
+                ctx,
+            );
+            let decl =
+                ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false);
+            Statement::VariableDeclaration(decl)
+        } else {
+            if !left.to_assignment_target().is_assignment_target_pattern() {
+                return;
+            }
+
+            Self::wrap_statement_in_block(body, scope_id, ctx);
+            let target = left.to_assignment_target_mut().take_in(ctx.ast);
+            let kind = VariableDeclarationKind::Var;
+            let binding = ctx.generate_uid(
+                "ref",
+                ctx.current_hoist_scope_id(),
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                binding.create_binding_pattern(ctx),
+                NONE,
+                None,
+                false,
+            );
+            let decl =
+                ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false);
+            *left = ForStatementLeft::VariableDeclaration(decl);
+
+            let value = binding.create_read_expression(ctx);
+            let expr =
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+            ctx.ast.statement_expression(SPAN, expr)
+        };
+
+        let Statement::BlockStatement(block) = body else { unreachable!() };
+        block.body.insert(0, stmt);
+    }
+
+    /// Transform exported variable declaration containing destructuring.
+    ///
+    /// Temp vars must not be exported, so the declaration is converted to a plain declaration,
+    /// followed by an export of the bindings.
+    ///
+    /// `export var [a, b] = c;` -> `var [a, b] = c; export { a, b };`
+    fn transform_export_variable_declaration(
+        &self,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Statement::ExportNamedDeclaration(export) = stmt else { unreachable!() };
+        let Some(Declaration::VariableDeclaration(decl)) = export.declaration.take() else {
+            unreachable!()
+        };
+
+        let mut specifiers = ctx.ast.vec();
+        decl.bound_names(&mut |ident| {
+            let binding = BoundIdentifier::from_binding_ident(ident);
+            let local = ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+            let exported =
+                ModuleExportName::IdentifierName(ctx.ast.identifier_name(SPAN, ident.name));
+            specifiers.push(ctx.ast.export_specifier(
+                SPAN,
+                local,
+                exported,
+                ImportOrExportKind::Value,
+            ));
+        });
+        let export_stmt =
+            Statement::ExportNamedDeclaration(ctx.ast.alloc_export_named_declaration(
+                export.span,
+                None,
+                specifiers,
+                None,
+                ImportOrExportKind::Value,
+                NONE,
+            ));
+
+        *stmt = Statement::VariableDeclaration(decl);
+        self.ctx.statement_injector.insert_after(&*stmt, export_stmt);
+    }
+
+    /// Replace pattern with a `_ref` binding, and return declarator `<pattern> = _ref`.
+    fn replace_pattern_with_reference(
+        pattern: &mut BindingPattern<'a>,
+        kind: VariableDeclarationKind,
+        scope_id: ScopeId,
+        flags: SymbolFlags,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        let binding = ctx.generate_uid("ref", scope_id, flags);
+        let pattern = mem::replace(pattern, binding.create_binding_pattern(ctx));
+        let init = binding.create_read_expression(ctx);
+        ctx.ast.variable_declarator(SPAN, kind, pattern, NONE, Some(init), false)
+    }
+
+    /// Wrap statement in a block statement, if it isn't one already.
+    /// Returns `ScopeId` of the block.
+    fn wrap_statement_in_block(
+        stmt: &mut Statement<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ScopeId {
+        if let Statement::BlockStatement(block) = stmt {
+            return block.scope_id();
+        }
+        let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let (span, stmts) = if let Statement::EmptyStatement(empty_stmt) = stmt {
+            (empty_stmt.span, ctx.ast.vec())
+        } else {
+            (stmt.span(), ctx.ast.vec1(stmt.take_in(ctx.ast)))
+        };
+        *stmt = ctx.ast.statement_block_with_scope_id(span, stmts, scope_id);
+        scope_id
+    }
+}
+
+/// Where assignments produced by flattening a pattern are emitted.
+enum Output<'a> {
+    /// `var a = _ref[0], b = _ref[1]`
+    Declarators {
+        kind: VariableDeclarationKind,
+        scope_id: ScopeId,
+        symbol_flags: SymbolFlags,
+        declarators: Vec<VariableDeclarator<'a>>,
+    },
+    /// `a = _ref[0], b = _ref[1]`
+    Assignments(Vec<Expression<'a>>),
+}
+
+/// A value being destructured, which may be read multiple times.
+struct ValueReference<'a> {
+    /// Expression used for the first read
+    expression: Option<Expression<'a>>,
+    /// Binding used for subsequent reads
+    binding: Option<BoundIdentifier<'a>>,
+}
+
+impl<'a> ValueReference<'a> {
+    fn read(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match self.expression.take() {
+            Some(expr) => expr,
+            None => self.binding.as_ref().unwrap().create_read_expression(ctx),
+        }
+    }
+}
+
+/// Flattens a pattern into a list of assignments.
+struct PatternFlattener<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    iterable_is_array: bool,
+    array_like_is_iterable: bool,
+    /// Symbols bound by the pattern. These cannot be read repeatedly in place of a temp var.
+    bound_symbols: Vec<SymbolId>,
+    output: Output<'a>,
+}
+
+impl<'a, 'ctx> PatternFlattener<'a, 'ctx> {
+    fn new(
+        transform: &Destructuring<'a, 'ctx>,
+        output: Output<'a>,
+        bound_symbols: Vec<SymbolId>,
+    ) -> Self {
+        Self {
+            ctx: transform.ctx,
+            iterable_is_array: transform.iterable_is_array,
+            array_like_is_iterable: transform.array_like_is_iterable,
+            bound_symbols,
+            output,
+        }
+    }
+
+    /// Flatten binding pattern in a variable declaration.
+    fn flatten_binding_pattern(
+        &mut self,
+        pattern: BindingPattern<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match pattern {
+            BindingPattern::BindingIdentifier(ident) => {
+                let Output::Declarators { kind, declarators, .. } = &mut self.output else {
+                    unreachable!()
+                };
+                let id = BindingPattern::BindingIdentifier(ident);
+                declarators.push(ctx.ast.variable_declarator(
+                    SPAN,
+                    *kind,
+                    id,
+                    NONE,
+                    Some(value),
+                    false,
+                ));
+            }
+            BindingPattern::AssignmentPattern(assign) => {
+                let AssignmentPattern { left, right, .. } = assign.unbox();
+                let value = self.create_default_value(value, right, ctx);
+                self.flatten_binding_pattern(left, value, ctx);
+            }
+            BindingPattern::ArrayPattern(array) => {
+                let ArrayPattern { elements, rest, .. } = array.unbox();
+                let element_count = elements.len();
+                let mut array =
+                    self.create_array_reference(value, element_count, rest.is_some(), ctx);
+                for (index, element) in elements.into_iter().enumerate() {
+                    if let Some(element) = element {
+                        let value = Self::create_index_access(array.read(ctx), index, ctx);
+                        self.flatten_binding_pattern(element, value, ctx);
+                    }
+                }
+                if let Some(rest) = rest {
+                    let value = Self::create_slice_call(array.read(ctx), element_count, ctx);
+                    self.flatten_binding_pattern(rest.unbox().argument, value, ctx);
+                }
+            }
+            BindingPattern::ObjectPattern(object) => {
+                let ObjectPattern { span, properties, rest } = object.unbox();
+                if let Some(rest) = rest {
+                    self.report_object_rest(rest.span);
+                }
+                if properties.is_empty() {
+                    self.push_empty_object_check(value, span, ctx);
+                    return;
+                }
+                let mut object = self.create_object_reference(value, properties.len(), ctx);
+                for property in properties {
+                    let value = Self::create_property_access(object.read(ctx), property.key, ctx);
+                    self.flatten_binding_pattern(property.value, value, ctx);
+                }
+            }
+        }
+    }
+
+    /// Flatten assignment target in an assignment expression.
+    fn flatten_assignment_target(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match target {
+            AssignmentTarget::ArrayAssignmentTarget(array) => {
+                let ArrayAssignmentTarget { elements, rest, .. } = array.unbox();
+                let element_count = elements.len();
+                let mut array =
+                    self.create_array_reference(value, element_count, rest.is_some(), ctx);
+                for (index, element) in elements.into_iter().enumerate() {
+                    if let Some(element) = element {
+                        let value = Self::create_index_access(array.read(ctx), index, ctx);
+                        self.flatten_assignment_target_maybe_default(element, value, ctx);
+                    }
+                }
+                if let Some(rest) = rest {
+                    let value = Self::create_slice_call(array.read(ctx), element_count, ctx);
+                    self.flatten_assignment_target(rest.unbox().target, value, ctx);
+                }
+            }
+            AssignmentTarget::ObjectAssignmentTarget(object) => {
+                let ObjectAssignmentTarget { span, properties, rest } = object.unbox();
+                if let Some(rest) = rest {
+                    self.report_object_rest(rest.span);
+                }
+                if properties.is_empty() {
+                    self.push_empty_object_check(value, span, ctx);
+                    return;
+                }
+                let mut object = self.create_object_reference(value, properties.len(), ctx);
+                for property in properties {
+                    match property {
+                        // `{ a = 1 } = obj`
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                            let AssignmentTargetPropertyIdentifier { binding, init, .. } =
+                                property.unbox();
+                            let key = ctx.ast.identifier_name(binding.span, binding.name);
+                            let mut value = Expression::from(ctx.ast.member_expression_static(
+                                SPAN,
+                                object.read(ctx),
+                                key,
+                                false,
+                            ));
+                            if let Some(init) = init {
+                                value = self.create_default_value(value, init, ctx);
+                            }
+                            let target = AssignmentTarget::AssignmentTargetIdentifier(
+                                ctx.ast.alloc(binding),
+                            );
+                            self.push_assignment(target, value, ctx);
+                        }
+                        // `{ a: b = 1 } = obj`
+                        AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                            let AssignmentTargetPropertyProperty { name, binding, .. } =
+                                property.unbox();
+                            let value = Self::create_property_access(object.read(ctx), name, ctx);
+                            self.flatten_assignment_target_maybe_default(binding, value, ctx);
+                        }
+                    }
+                }
+            }
+            target => self.push_assignment(target, value, ctx),
+        }
+    }
+
+    fn flatten_assignment_target_maybe_default(
+        &mut self,
+        target: AssignmentTargetMaybeDefault<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                let AssignmentTargetWithDefault { binding, init, .. } = target.unbox();
+                let value = self.create_default_value(value, init, ctx);
+                self.flatten_assignment_target(binding, value, ctx);
+            }
+            target => self.flatten_assignment_target(target.into_assignment_target(), value, ctx),
+        }
+    }
+
+    /// Create reference to value destructured by an array pattern.
+    ///
+    /// * `_slicedToArray(value, 2)`
+    /// * `_toArray(value)` (pattern has rest element)
+    /// * `_maybeArrayLike(_slicedToArray, value, 2)` (`array_like_is_iterable`)
+    /// * `value` (`iterable_is_array`)
+    fn create_array_reference(
+        &mut self,
+        value: Expression<'a>,
+        element_count: usize,
+        has_rest: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ValueReference<'a> {
+        if self.iterable_is_array {
+            return self.create_object_reference(value, usize::MAX, ctx);
+        }
+
+        let temp = self.create_temp(&value, ctx);
+        let (helper, length) = if has_rest {
+            (Helper::ToArray, None)
+        } else {
+            (Helper::SlicedToArray, Some(Self::create_number(element_count, ctx)))
+        };
+        let mut arguments = ctx.ast.vec_with_capacity(3);
+        let helper = if self.array_like_is_iterable {
+            arguments.push(Argument::from(self.ctx.helper_load(helper, ctx)));
+            Helper::MaybeArrayLike
+        } else {
+            helper
+        };
+        arguments.push(Argument::from(value));
+        arguments.extend(length.map(Argument::from));
+        let value = self.ctx.helper_call_expr(helper, SPAN, arguments, ctx);
+        self.push_temp(&temp, value, ctx);
+        ValueReference { expression: None, binding: Some(temp) }
+    }
+
+    /// Create reference to value destructured by an object pattern.
+    ///
+    /// Value is stored in a temp var, unless it is only read once, or it is an identifier
+    /// which is never reassigned.
+    fn create_object_reference(
+        &mut self,
+        value: Expression<'a>,
+        read_count: usize,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ValueReference<'a> {
+        if read_count == 1 {
+            return ValueReference { expression: Some(value), binding: None };
+        }
+        if let Some(binding) = self.get_constant_binding(&value, ctx) {
+            return ValueReference { expression: Some(value), binding: Some(binding) };
+        }
+        let temp = self.create_temp(&value, ctx);
+        self.push_temp(&temp, value, ctx);
+        ValueReference { expression: None, binding: Some(temp) }
+    }
+
+    /// Get binding for an identifier which refers to a binding which is never reassigned,
+    /// and is not bound by the pattern being flattened.
+    fn get_constant_binding(
+        &self,
+        expr: &Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<BoundIdentifier<'a>> {
+        let Expression::Identifier(ident) = expr else { return None };
+        let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        if ctx.scoping().symbol_is_mutated(symbol_id) || self.bound_symbols.contains(&symbol_id) {
+            return None;
+        }
+        Some(BoundIdentifier::new(ident.name, symbol_id))
+    }
+
+    /// `_value === void 0 ? default : _value`
+    fn create_default_value(
+        &mut self,
+        value: Expression<'a>,
+        default: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let temp = self.create_temp(&value, ctx);
+        self.push_temp(&temp, value, ctx);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            temp.create_read_expression(ctx),
+            BinaryOperator::StrictEquality,
+            ctx.ast.void_0(SPAN),
+        );
+        ctx.ast.expression_conditional(SPAN, test, default, temp.create_read_expression(ctx))
+    }
+
+    /// Add `_objectDestructuringEmpty(value)`, which throws if value is `null` or `undefined`.
+    fn push_empty_object_check(
+        &mut self,
+        value: Expression<'a>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let arguments = ctx.ast.vec1(Argument::from(value));
+        let check =
+            self.ctx.helper_call_expr(Helper::ObjectDestructuringEmpty, span, arguments, ctx);
+        match &mut self.output {
+            Output::Declarators { kind, scope_id, symbol_flags, declarators } => {
+                // Declaration needs a binding. `var _ref = _objectDestructuringEmpty(value)`.
+                let binding = ctx.generate_uid("ref", *scope_id, *symbol_flags);
+                let id = binding.create_binding_pattern(ctx);
+                declarators.push(ctx.ast.variable_declarator(
+                    SPAN,
+                    *kind,
+                    id,
+                    NONE,
+                    Some(check),
+                    false,
+                ));
+            }
+            Output::Assignments(expressions) => expressions.push(check),
+        }
+    }
+
+    fn report_object_rest(&self, span: Span) {
+        self.ctx.error(
+            OxcDiagnostic::error(
+                "Object rest in destructuring must be transformed by object-rest-spread transform.",
+            )
+            .with_label(span),
+        );
+    }
+
+    /// Create a temp var, with name based on `node`.
+    fn create_temp(&self, node: &Expression<'a>, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        match &self.output {
+            Output::Declarators { scope_id, symbol_flags, .. } => {
+                ctx.generate_uid_based_on_node(node, *scope_id, *symbol_flags)
+            }
+            Output::Assignments(_) => {
+                self.ctx.var_declarations.create_uid_var_based_on_node(node, ctx)
+            }
+        }
+    }
+
+    /// Add `_temp = value`.
+    fn push_temp(
+        &mut self,
+        temp: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match &mut self.output {
+            Output::Declarators { kind, declarators, .. } => {
+                let id = temp.create_binding_pattern(ctx);
+                declarators.push(ctx.ast.variable_declarator(
+                    SPAN,
+                    *kind,
+                    id,
+                    NONE,
+                    Some(value),
+                    false,
+                ));
+            }
+            Output::Assignments(expressions) => {
+                let target = temp.create_write_target(ctx);
+                expressions.push(ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    value,
+                ));
+            }
+        }
+    }
+
+    /// Add `target = value`.
+    fn push_assignment(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let Output::Assignments(expressions) = &mut self.output else { unreachable!() };
+        expressions.push(ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            target,
+            value,
+        ));
+    }
+
+    /// `object.key` or `object[key]`
+    fn create_property_access(
+        object: Expression<'a>,
+        key: PropertyKey<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let member = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                let property = ctx.ast.identifier_name(ident.span, ident.name);
+                ctx.ast.member_expression_static(SPAN, object, property, false)
+            }
+            PropertyKey::PrivateIdentifier(_) => unreachable!(),
+            key => ctx.ast.member_expression_computed(SPAN, object, key.into_expression(), false),
+        };
+        Expression::from(member)
+    }
+
+    /// `array[index]`
+    fn create_index_access(
+        array: Expression<'a>,
+        index: usize,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let index = Self::create_number(index, ctx);
+        Expression::from(ctx.ast.member_expression_computed(SPAN, array, index, false))
+    }
+
+    /// `array.slice(start)`
+    fn create_slice_call(
+        array: Expression<'a>,
+        start: usize,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let property = ctx.ast.identifier_name(SPAN, "slice");
+        let callee =
+            Expression::from(ctx.ast.member_expression_static(SPAN, array, property, false));
+        let arguments = ctx.ast.vec1(Argument::from(Self::create_number(start, ctx)));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    #[expect(clippy::cast_precision_loss)]
+    fn create_number(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        ctx.ast.expression_numeric_literal(SPAN, value as f64, None, NumberBase::Decimal)
+    }
+}
+
+fn kind_to_symbol_flags(kind: VariableDeclarationKind) -> SymbolFlags {
+    match kind {
+        VariableDeclarationKind::Var => SymbolFlags::FunctionScopedVariable,
+        VariableDeclarationKind::Let
+        | VariableDeclarationKind::Using
+        | VariableDeclarationKind::AwaitUsing => SymbolFlags::BlockScopedVariable,
+        VariableDeclarationKind::Const => {
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+        }
+    }
+}
@@ -0,0 +1,575 @@
+//! ES2015: For Of
+//!
+//! This plugin transforms `for...of` statements.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! for (const x of y) {
+//!   foo(x);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _iterator = babelHelpers.createForOfIteratorHelper(y), _step;
+//! try {
+//!   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+//!     const x = _step.value;
+//!     {
+//!       foo(x);
+//!     }
+//!   }
+//! } catch (err) {
+//!   _iterator.e(err);
+//! } finally {
+//!   _iterator.f();
+//! }
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! This option can also be enabled with `CompilerAssumptions::skip_for_of_iterator_closing`.
+//!
+//! When `true`, iterators are not closed when the loop is exited early:
+//!
+//! ```js
+//! for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(y), _step; !(_step = _iterator()).done;) {
+//!   const x = _step.value;
+//!   { foo(x); }
+//! }
+//! ```
+//!
+//! ### `assumeArray`
+//!
+//! This option can also be enabled with `CompilerAssumptions::iterable_is_array`.
+//!
+//! When `true`, iterated values are assumed to be arrays, and are iterated by index:
+//!
+//! ```js
+//! for (let _i = 0, _y = y; _i < _y.length; _i++) {
+//!   const x = _y[_i];
+//!   { foo(x); }
+//! }
+//! ```
+//!
+//! ### `allowArrayLike`
+//!
+//! This option can also be enabled with `CompilerAssumptions::array_like_is_iterable`.
+//!
+//! When `true`, array-like objects, such as `arguments`, can be iterated.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-for-of](https://babel.dev/docs/babel-plugin-transform-for-of).
+//!
+//! `for await...of` statements are transformed by ES2018 async generator functions transform.
+//!
+//! The scope of the `for...of` statement, which contains the loop bindings, becomes the scope of
+//! the new loop body. The original body is nested in it.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-for-of>
+//! * For-in and for-of statements specification: <https://tc39.es/ecma262/#sec-for-in-and-for-of-statements>
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ScopeFlags, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ForOfOptions {
+    pub loose: bool,
+    pub assume_array: bool,
+    pub allow_array_like: bool,
+}
+
+/// For-of transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct ForOf<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// If `true`, iterate by index.
+    iterable_is_array: bool,
+    /// If `true`, use `createForOfIteratorHelperLoose` helper, which does not close iterators.
+    skip_iterator_closing: bool,
+    /// If `true`, pass `true` to helpers to allow iterating array-like objects.
+    array_like_is_iterable: bool,
+}
+
+impl<'a, 'ctx> ForOf<'a, 'ctx> {
+    pub fn new(options: ForOfOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let assumptions = ctx.assumptions;
+        Self {
+            ctx,
+            iterable_is_array: options.assume_array || assumptions.iterable_is_array,
+            skip_iterator_closing: options.loose || assumptions.skip_for_of_iterator_closing,
+            array_like_is_iterable: options.allow_array_like || assumptions.array_like_is_iterable,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ForOf<'a, '_> {
+    // `for...of` statements are transformed in `enter_statement`, because spec mode replaces
+    // the statement (including its label) with a `try` statement.
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let for_of_stmt = match &mut *stmt {
+            Statement::LabeledStatement(labeled) => &mut labeled.body,
+            stmt => stmt,
+        };
+        if !matches!(for_of_stmt, Statement::ForOfStatement(for_of) if !for_of.r#await) {
+            return;
+        }
+
+        if self.iterable_is_array {
+            let Statement::ForOfStatement(for_of) = for_of_stmt else { unreachable!() };
+            *for_of_stmt = Self::create_array_loop(for_of, ctx);
+        } else if self.skip_iterator_closing {
+            let Statement::ForOfStatement(for_of) = for_of_stmt else { unreachable!() };
+            *for_of_stmt = self.create_loose_loop(for_of, ctx);
+        } else {
+            self.transform_statement(stmt, ctx);
+        }
+    }
+}
+
+impl<'a> ForOf<'a, '_> {
+    /// Transform `for...of` statement with iterator closing.
+    ///
+    /// `var _iterator = ..., _step;` is inserted before the statement,
+    /// and the statement is replaced with a `try` statement.
+    fn transform_statement(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let in_statement_list = matches!(
+            ctx.parent(),
+            Ancestor::ProgramBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleBlockBody(_)
+        );
+        let parent_scope_id = if in_statement_list {
+            ctx.current_scope_id()
+        } else {
+            ctx.create_child_scope_of_current(ScopeFlags::empty())
+        };
+
+        let var_scope_id = ctx.current_hoist_scope_id();
+        let iterator =
+            ctx.generate_uid("iterator", var_scope_id, SymbolFlags::FunctionScopedVariable);
+        let step = ctx.generate_uid("step", var_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // `for (_iterator.s(); !(_step = _iterator.n()).done;) {}`
+        let try_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let for_of_stmt = match &mut *stmt {
+            Statement::LabeledStatement(labeled) => &mut labeled.body,
+            stmt => stmt,
+        };
+        let Statement::ForOfStatement(for_of) = for_of_stmt else { unreachable!() };
+        let right = for_of.right.take_in(ctx.ast);
+        let init = Self::create_iterator_method_call(&iterator, "s", ctx);
+        let next = Self::create_iterator_method_call(&iterator, "n", ctx);
+        let test = Self::create_step_not_done(&step, next, ctx);
+        let body = Self::create_loop_body(for_of, &step, ctx);
+        let for_scope_id = ctx.create_child_scope(try_scope_id, ScopeFlags::empty());
+        ctx.scoping_mut().change_scope_parent_id(for_of.scope_id(), Some(for_scope_id));
+        *for_of_stmt = ctx.ast.statement_for_with_scope_id(
+            SPAN,
+            Some(ForStatementInit::from(init)),
+            Some(test),
+            None,
+            body,
+            for_scope_id,
+        );
+
+        // `try { <loop> }`
+        let block = ctx.ast.block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(stmt.take_in(ctx.ast)),
+            try_scope_id,
+        );
+
+        // `catch (err) { _iterator.e(err); }`
+        let catch_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::CatchClause);
+        let catch_body_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+        let err = ctx.generate_binding(
+            Atom::from("err"),
+            catch_body_scope_id,
+            SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+        );
+        let call = Self::create_iterator_method_call_with_argument(
+            &iterator,
+            "e",
+            err.create_read_expression(ctx),
+            ctx,
+        );
+        let catch_body = ctx.ast.block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+            catch_body_scope_id,
+        );
+        let catch_clause = ctx.ast.catch_clause_with_scope_id(
+            SPAN,
+            Some(ctx.ast.catch_parameter(SPAN, err.create_binding_pattern(ctx), NONE)),
+            catch_body,
+            catch_scope_id,
+        );
+
+        // `finally { _iterator.f(); }`
+        let finally_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let call = Self::create_iterator_method_call(&iterator, "f", ctx);
+        let finalizer = ctx.ast.block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+            finally_scope_id,
+        );
+
+        let try_stmt = ctx.ast.statement_try(SPAN, block, Some(catch_clause), Some(finalizer));
+
+        // `var _iterator = babelHelpers.createForOfIteratorHelper(right), _step;`
+        let kind = VariableDeclarationKind::Var;
+        let helper_call = self.create_helper_call(Helper::CreateForOfIterator, right, ctx);
+        let declarations = ctx.ast.vec_from_array([
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                iterator.create_binding_pattern(ctx),
+                NONE,
+                Some(helper_call),
+                false,
+            ),
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                step.create_binding_pattern(ctx),
+                NONE,
+                None,
+                false,
+            ),
+        ]);
+        let declaration =
+            Statement::from(ctx.ast.declaration_variable(SPAN, kind, declarations, false));
+
+        *stmt = if in_statement_list {
+            self.ctx.statement_injector.insert_before(&try_stmt, declaration);
+            try_stmt
+        } else {
+            // `if (x) for (const a of b) {}` -> `if (x) { var _iterator = ...; try { ... } }`
+            ctx.ast.statement_block_with_scope_id(
+                SPAN,
+                ctx.ast.vec_from_array([declaration, try_stmt]),
+                parent_scope_id,
+            )
+        };
+    }
+
+    /// Create loop without iterator closing.
+    ///
+    /// `for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(right), _step; !(_step = _iterator()).done;) {}`
+    fn create_loose_loop(
+        &self,
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let var_scope_id = ctx.current_hoist_scope_id();
+        let iterator =
+            ctx.generate_uid("iterator", var_scope_id, SymbolFlags::FunctionScopedVariable);
+        let step = ctx.generate_uid("step", var_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        let right = for_of.right.take_in(ctx.ast);
+        let helper_call = self.create_helper_call(Helper::CreateForOfIteratorLoose, right, ctx);
+        let kind = VariableDeclarationKind::Var;
+        let declarations = ctx.ast.vec_from_array([
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                iterator.create_binding_pattern(ctx),
+                NONE,
+                Some(helper_call),
+                false,
+            ),
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                step.create_binding_pattern(ctx),
+                NONE,
+                None,
+                false,
+            ),
+        ]);
+        let init = ctx.ast.for_statement_init_variable_declaration(SPAN, kind, declarations, false);
+
+        // `!(_step = _iterator()).done`
+        let next = ctx.ast.expression_call(
+            SPAN,
+            iterator.create_read_expression(ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        let test = Self::create_step_not_done(&step, next, ctx);
+
+        let body = Self::create_loop_body(for_of, &step, ctx);
+        let for_scope_id = ctx.create_child_scope_of_current(ScopeFlags::empty());
+        ctx.scoping_mut().change_scope_parent_id(for_of.scope_id(), Some(for_scope_id));
+        ctx.ast.statement_for_with_scope_id(SPAN, Some(init), Some(test), None, body, for_scope_id)
+    }
+
+    /// Create loop iterating by index.
+    ///
+    /// `for (let _i = 0, _right = right; _i < _right.length; _i++) {}`
+    fn create_array_loop(
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let for_scope_id = ctx.create_child_scope_of_current(ScopeFlags::empty());
+        let flags = SymbolFlags::BlockScopedVariable;
+        let index = ctx.generate_uid("i", for_scope_id, flags);
+
+        let kind = VariableDeclarationKind::Let;
+        let mut declarations = ctx.ast.vec_with_capacity(2);
+        declarations.push(ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            index.create_binding_pattern(ctx),
+            NONE,
+            Some(ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal)),
+            false,
+        ));
+
+        // Reuse `this` and references to constant bindings, otherwise store the array in a temp var
+        let right = for_of.right.take_in(ctx.ast);
+        let constant_symbol_id = match &right {
+            Expression::Identifier(ident) => ctx
+                .scoping()
+                .get_reference(ident.reference_id())
+                .symbol_id()
+                .filter(|&symbol_id| !ctx.scoping().symbol_is_mutated(symbol_id)),
+            _ => None,
+        };
+        let array = if let Expression::ThisExpression(_) = right {
+            ArrayReference::This
+        } else if let Some(symbol_id) = constant_symbol_id {
+            let Expression::Identifier(ident) = right else { unreachable!() };
+            ctx.delete_reference_for_identifier(&ident);
+            ArrayReference::Binding(BoundIdentifier::new(ident.name, symbol_id))
+        } else {
+            let binding = ctx.generate_uid_based_on_node(&right, for_scope_id, flags);
+            declarations.push(ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                binding.create_binding_pattern(ctx),
+                NONE,
+                Some(right),
+                false,
+            ));
+            ArrayReference::Binding(binding)
+        };
+        let init = ctx.ast.for_statement_init_variable_declaration(SPAN, kind, declarations, false);
+
+        // `_i < _right.length`
+        let length = ctx.ast.identifier_name(SPAN, "length");
+        let length = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            array.create_read(ctx),
+            length,
+            false,
+        ));
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            index.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            length,
+        );
+
+        // `_i++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            index.create_read_write_simple_target(ctx),
+        );
+
+        // `_right[_i]`
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            array.create_read(ctx),
+            index.create_read_expression(ctx),
+            false,
+        ));
+        let body = Self::create_loop_body_with_value(for_of, value, ctx);
+        ctx.scoping_mut().change_scope_parent_id(for_of.scope_id(), Some(for_scope_id));
+        ctx.ast.statement_for_with_scope_id(
+            SPAN,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_scope_id,
+        )
+    }
+
+    /// `{ <left> = _step.value; <body> }`
+    fn create_loop_body(
+        for_of: &mut ForOfStatement<'a>,
+        step: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let property = ctx.ast.identifier_name(SPAN, "value");
+        let value = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            step.create_read_expression(ctx),
+            property,
+            false,
+        ));
+        Self::create_loop_body_with_value(for_of, value, ctx)
+    }
+
+    /// `{ <left> = value; <body> }`
+    ///
+    /// Scope of `for...of` statement becomes scope of the new block.
+    fn create_loop_body_with_value(
+        for_of: &mut ForOfStatement<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let assignment = match &mut for_of.left {
+            // `const x = value;`
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let mut declarator = decl.declarations.pop().unwrap();
+                declarator.init = Some(value);
+                Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    declarator.kind,
+                    ctx.ast.vec1(declarator),
+                    false,
+                ))
+            }
+            // `x = value;`
+            left @ match_assignment_target!(ForStatementLeft) => {
+                let target = left.to_assignment_target_mut().take_in(ctx.ast);
+                let expr =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                ctx.ast.statement_expression(SPAN, expr)
+            }
+        };
+
+        let mut statements: ArenaVec<'a, Statement<'a>> = ctx.ast.vec_with_capacity(2);
+        statements.push(assignment);
+        match &for_of.body {
+            // Remove empty block, and its scope
+            Statement::BlockStatement(block) if block.body.is_empty() => {
+                ctx.scoping_mut().delete_scope(block.scope_id());
+            }
+            Statement::EmptyStatement(_) => {}
+            _ => statements.push(for_of.body.take_in(ctx.ast)),
+        }
+        ctx.ast.statement_block_with_scope_id(SPAN, statements, for_of.scope_id())
+    }
+
+    /// `babelHelpers.createForOfIteratorHelper(right)`, with `true` as second argument
+    /// if array-like objects are allowed.
+    fn create_helper_call(
+        &self,
+        helper: Helper,
+        right: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec_with_capacity(2);
+        arguments.push(Argument::from(right));
+        if self.array_like_is_iterable {
+            arguments.push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+        }
+        self.ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+    }
+
+    /// `!(_step = next).done`
+    fn create_step_not_done(
+        step: &BoundIdentifier<'a>,
+        next: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            step.create_write_target(ctx),
+            next,
+        );
+        let assignment = ctx.ast.expression_parenthesized(SPAN, assignment);
+        let done = ctx.ast.identifier_name(SPAN, "done");
+        let done =
+            Expression::from(ctx.ast.member_expression_static(SPAN, assignment, done, false));
+        ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, done)
+    }
+
+    /// `_iterator.method()`
+    fn create_iterator_method_call(
+        iterator: &BoundIdentifier<'a>,
+        method: &'static str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = Self::create_iterator_method(iterator, method, ctx);
+        ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec(), false)
+    }
+
+    /// `_iterator.method(argument)`
+    fn create_iterator_method_call_with_argument(
+        iterator: &BoundIdentifier<'a>,
+        method: &'static str,
+        argument: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = Self::create_iterator_method(iterator, method, ctx);
+        ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(Argument::from(argument)), false)
+    }
+
+    /// `_iterator.method`
+    fn create_iterator_method(
+        iterator: &BoundIdentifier<'a>,
+        method: &'static str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let property = ctx.ast.identifier_name(SPAN, method);
+        Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            iterator.create_read_expression(ctx),
+            property,
+            false,
+        ))
+    }
+}
+
+/// Reference to the array iterated by index.
+enum ArrayReference<'a> {
+    This,
+    Binding(BoundIdentifier<'a>),
+}
+
+impl<'a> ArrayReference<'a> {
+    fn create_read(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match self {
+            Self::This => ctx.ast.expression_this(SPAN),
+            Self::Binding(binding) => binding.create_read_expression(ctx),
+        }
+    }
+}
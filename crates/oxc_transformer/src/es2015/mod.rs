@@ -1,12 +1,48 @@
+use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
 
-use crate::{context::TransformCtx, state::TransformState};
+use crate::{
+    ArenaVec,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
 
 mod arrow_functions;
+mod block_scoping;
+mod classes;
+mod computed_properties;
+mod destructuring;
+mod for_of;
+mod new_target;
 mod options;
+mod parameters;
+mod regenerator;
+mod shorthand_properties;
+mod spread;
+mod template_literals;
 
 pub use arrow_functions::{ArrowFunctions, ArrowFunctionsOptions};
+use block_scoping::BlockScoping;
+pub use block_scoping::BlockScopingOptions;
+pub use block_scoping::binding_pattern_to_assignment_target;
+use classes::Classes;
+pub use classes::ClassesOptions;
+use computed_properties::ComputedProperties;
+pub use computed_properties::ComputedPropertiesOptions;
+use destructuring::Destructuring;
+pub use destructuring::DestructuringOptions;
+use for_of::ForOf;
+pub use for_of::ForOfOptions;
+use new_target::NewTarget;
 pub use options::ES2015Options;
+use parameters::Parameters;
+pub use parameters::ParametersOptions;
+pub use regenerator::Regenerator;
+use shorthand_properties::ShorthandProperties;
+use spread::Spread;
+pub use spread::SpreadOptions;
+use template_literals::TemplateLiterals;
+pub use template_literals::TemplateLiteralsOptions;
 
 pub struct ES2015<'a, 'ctx> {
     #[expect(unused)]
@@ -15,15 +51,218 @@ pub struct ES2015<'a, 'ctx> {
     // Plugins
     #[expect(unused)]
     arrow_functions: ArrowFunctions<'a, 'ctx>,
+    classes: Option<Classes<'a, 'ctx>>,
+    block_scoping: Option<BlockScoping<'a, 'ctx>>,
+    parameters: Option<Parameters>,
+    destructuring: Option<Destructuring<'a, 'ctx>>,
+    spread: Option<Spread<'a, 'ctx>>,
+    template_literals: Option<TemplateLiterals<'a, 'ctx>>,
+    shorthand_properties: Option<ShorthandProperties>,
+    computed_properties: Option<ComputedProperties<'a, 'ctx>>,
+    for_of: Option<ForOf<'a, 'ctx>>,
+    new_target: Option<NewTarget<'a>>,
+    regenerator: Option<Regenerator<'a, 'ctx>>,
 }
 
 impl<'a, 'ctx> ES2015<'a, 'ctx> {
     pub fn new(options: ES2015Options, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             arrow_functions: ArrowFunctions::new(options.arrow_function.unwrap_or_default(), ctx),
+            classes: options.classes.map(|classes_options| {
+                let spread = options.spread.map(|spread_options| Spread::new(spread_options, ctx));
+                Classes::new(classes_options, spread, ctx)
+            }),
+            block_scoping: options.block_scoping.map(|block_scoping_options| {
+                BlockScoping::new(block_scoping_options, options.classes.is_some(), ctx)
+            }),
+            parameters: options.parameters.map(|parameters_options| {
+                Parameters::new(parameters_options, options.arrow_function.is_some(), ctx)
+            }),
+            destructuring: options
+                .destructuring
+                .map(|destructuring_options| Destructuring::new(destructuring_options, ctx)),
+            spread: options.spread.map(|spread_options| Spread::new(spread_options, ctx)),
+            template_literals: options.template_literals.map(|template_literals_options| {
+                TemplateLiterals::new(template_literals_options, ctx)
+            }),
+            shorthand_properties: options.shorthand_properties.then(ShorthandProperties::new),
+            computed_properties: options.computed_properties.map(|computed_properties_options| {
+                ComputedProperties::new(computed_properties_options, ctx)
+            }),
+            for_of: options.for_of.map(|for_of_options| ForOf::new(for_of_options, ctx)),
+            new_target: options.new_target.then(NewTarget::new),
+            regenerator: options.regenerator.then(|| Regenerator::new(ctx)),
             options,
         }
     }
 }
 
-impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a, '_> {}
+impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a, '_> {
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_statements(stmts, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Must run before destructuring transform, which moves destructuring patterns
+        // in `for of` heads into the loop body
+        if let Some(for_of) = &mut self.for_of {
+            for_of.enter_statement(stmt, ctx);
+        }
+        // Must run before block scoping transform, which needs to see bindings of
+        // destructuring patterns in `for in` / `for of` heads moved into the loop body
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_statement(stmt, ctx);
+        }
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_switch_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_for_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_identifier_reference(ident, ctx);
+        }
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Must run before destructuring transform, so destructuring patterns moved into
+        // function body by parameters transform are declared after the leading parameters
+        if let Some(parameters) = &mut self.parameters {
+            parameters.enter_function(func, ctx);
+        }
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_function(func, ctx);
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(new_target) = &mut self.new_target {
+            new_target.exit_function(func, ctx);
+        }
+        if let Some(regenerator) = &mut self.regenerator {
+            regenerator.exit_function(func, ctx);
+        }
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(parameters) = &mut self.parameters {
+            parameters.enter_arrow_function_expression(arrow, ctx);
+        }
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_arrow_function_expression(arrow, ctx);
+        }
+    }
+
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_variable_declaration(decl, ctx);
+        }
+    }
+
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_catch_clause(clause, ctx);
+        }
+    }
+
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(classes) = &mut self.classes {
+            classes.enter_class(class, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(classes) = &mut self.classes {
+            classes.enter_expression(expr, ctx);
+        }
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_expression(expr, ctx);
+        }
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_expression(expr, ctx);
+        }
+        if let Some(spread) = &mut self.spread {
+            spread.enter_expression(expr, ctx);
+        }
+        if let Some(template_literals) = &mut self.template_literals {
+            template_literals.enter_expression(expr, ctx);
+        }
+        if let Some(computed_properties) = &mut self.computed_properties {
+            computed_properties.enter_expression(expr, ctx);
+        }
+        if let Some(new_target) = &mut self.new_target {
+            new_target.enter_expression(expr, ctx);
+        }
+    }
+
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(shorthand_properties) = &mut self.shorthand_properties {
+            shorthand_properties.enter_object_property(prop, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(classes) = &mut self.classes {
+            classes.exit_expression(expr, ctx);
+        }
+        if let Some(regenerator) = &mut self.regenerator {
+            regenerator.exit_expression(expr, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(classes) = &mut self.classes {
+            classes.exit_statement(stmt, ctx);
+        }
+        // Must run after classes transform, which converts class declarations to `let` declarations
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_statement(stmt, ctx);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! ES2015: New Target
+//!
+//! This plugin transforms `new.target` meta property.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function Foo() {
+//!   console.log(new.target);
+//! }
+//! class Bar {
+//!   constructor() {
+//!     console.log(new.target);
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! function Foo() {
+//!   console.log(this instanceof Foo ? this.constructor : void 0);
+//! }
+//! class Bar {
+//!   constructor() {
+//!     console.log(this.constructor);
+//!   }
+//! }
+//! ```
+//!
+//! * In functions, `new.target` is `this.constructor` if `this` is an instance of the function.
+//!   Anonymous functions are given a name, so they can be referenced.
+//! * In class constructors and class property initializers, `new.target` is `this.constructor`.
+//! * In methods, `new.target` is `undefined`, as methods cannot be called with `new`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-new-target](https://babel.dev/docs/babel-plugin-transform-new-target).
+//!
+//! Unlike Babel, bindings which shadow the function name are not renamed.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-new-target>
+//! * Meta properties specification: <https://tc39.es/ecma262/#sec-meta-properties>
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::*;
+use oxc_semantic::{ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+/// New target transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct NewTarget<'a> {
+    /// Names created for anonymous functions containing `new.target`, keyed by function scope.
+    /// Names are added to the functions on exit.
+    function_names: FxHashMap<ScopeId, BoundIdentifier<'a>>,
+}
+
+impl NewTarget<'_> {
+    pub fn new() -> Self {
+        Self { function_names: FxHashMap::default() }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for NewTarget<'a> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::MetaProperty(meta) = expr
+            && meta.meta.name == "new"
+            && meta.property.name == "target"
+            && let Some(replacement) = self.create_replacement(ctx)
+        {
+            *expr = replacement;
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(binding) = self.function_names.remove(&func.scope_id()) else { return };
+        // Name of a function declaration is bound in the parent scope
+        if func.is_declaration() {
+            let scoping = ctx.scoping_mut();
+            let parent_scope_id = scoping.scope_parent_id(func.scope_id()).unwrap();
+            scoping.set_symbol_scope_id(binding.symbol_id, parent_scope_id);
+            scoping.move_binding(func.scope_id(), parent_scope_id, &binding.name);
+        }
+        func.id = Some(binding.create_binding_identifier(ctx));
+    }
+}
+
+impl<'a> NewTarget<'a> {
+    /// Create replacement for `new.target`, depending on the closest non-arrow function or class.
+    fn create_replacement(&mut self, ctx: &mut TraverseCtx<'a>) -> Option<Expression<'a>> {
+        let (scope_id, binding) = match Self::find_target(ctx)? {
+            Target::Class => return Some(Self::create_this_constructor(ctx)),
+            Target::Method => return Some(ctx.ast.void_0(SPAN)),
+            Target::Function(scope_id, binding) => (scope_id, binding),
+        };
+
+        // `this instanceof Foo ? this.constructor : void 0`
+        let binding = binding.unwrap_or_else(|| {
+            self.function_names
+                .entry(scope_id)
+                .or_insert_with(|| ctx.generate_uid("target", scope_id, SymbolFlags::Function))
+                .clone()
+        });
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            ctx.ast.expression_this(SPAN),
+            BinaryOperator::Instanceof,
+            binding.create_read_expression(ctx),
+        );
+        let consequent = Self::create_this_constructor(ctx);
+        Some(ctx.ast.expression_conditional(SPAN, test, consequent, ctx.ast.void_0(SPAN)))
+    }
+
+    /// Find the closest non-arrow function or class containing `new.target`.
+    fn find_target(ctx: &TraverseCtx<'a>) -> Option<Target<'a>> {
+        let mut ancestors = ctx.ancestors();
+        let (scope_id, id) = loop {
+            match ancestors.next()? {
+                Ancestor::ClassBody(_) => return Some(Target::Class),
+                Ancestor::FunctionParams(func) => break (func.scope_id(), func.id()),
+                Ancestor::FunctionBody(func) => break (func.scope_id(), func.id()),
+                _ => {}
+            }
+        };
+
+        match ancestors.next()? {
+            // Class constructor
+            Ancestor::MethodDefinitionValue(method) if method.kind().is_constructor() => {
+                Some(Target::Class)
+            }
+            // Methods cannot be called with `new`
+            Ancestor::MethodDefinitionValue(_) => Some(Target::Method),
+            Ancestor::ObjectPropertyValue(prop)
+                if *prop.method() || *prop.kind() != PropertyKind::Init =>
+            {
+                Some(Target::Method)
+            }
+            _ => Some(Target::Function(
+                scope_id.get().unwrap(),
+                id.as_ref().map(BoundIdentifier::from_binding_ident),
+            )),
+        }
+    }
+
+    /// `this.constructor`
+    fn create_this_constructor(ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let property = ctx.ast.identifier_name(SPAN, "constructor");
+        Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            ctx.ast.expression_this(SPAN),
+            property,
+            false,
+        ))
+    }
+}
+
+/// Closest non-arrow function or class containing `new.target`.
+enum Target<'a> {
+    /// Class constructor, property initializer or static block
+    Class,
+    /// Method, getter or setter
+    Method,
+    /// Function with its scope and name, if it has one
+    Function(ScopeId, Option<BoundIdentifier<'a>>),
+}
@@ -1,10 +1,46 @@
 use serde::Deserialize;
 
-use super::ArrowFunctionsOptions;
+use super::{
+    ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, ComputedPropertiesOptions,
+    DestructuringOptions, ForOfOptions, ParametersOptions, SpreadOptions, TemplateLiteralsOptions,
+};
 
 #[derive(Debug, Default, Clone, Copy, Deserialize)]
 #[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct ES2015Options {
     #[serde(skip)]
     pub arrow_function: Option<ArrowFunctionsOptions>,
+
+    #[serde(skip)]
+    pub classes: Option<ClassesOptions>,
+
+    #[serde(skip)]
+    pub block_scoping: Option<BlockScopingOptions>,
+
+    #[serde(skip)]
+    pub destructuring: Option<DestructuringOptions>,
+
+    #[serde(skip)]
+    pub spread: Option<SpreadOptions>,
+
+    #[serde(skip)]
+    pub parameters: Option<ParametersOptions>,
+
+    #[serde(skip)]
+    pub template_literals: Option<TemplateLiteralsOptions>,
+
+    #[serde(skip)]
+    pub shorthand_properties: bool,
+
+    #[serde(skip)]
+    pub computed_properties: Option<ComputedPropertiesOptions>,
+
+    #[serde(skip)]
+    pub for_of: Option<ForOfOptions>,
+
+    #[serde(skip)]
+    pub new_target: bool,
+
+    #[serde(skip)]
+    pub regenerator: bool,
 }
@@ -0,0 +1,461 @@
+//! ES2015: Parameters
+//!
+//! This plugin transforms default parameters and rest parameters.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function foo(a, b = 1, ...c) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! function foo(a) {
+//!   var b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : 1;
+//!   for (var _len = arguments.length, c = new Array(_len > 2 ? _len - 2 : 0), _key = 2; _key < _len; _key++) {
+//!     c[_key - 2] = arguments[_key];
+//!   }
+//! }
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! This option can also be enabled with `CompilerAssumptions::ignore_function_length`.
+//!
+//! When `true`, parameters with default values are kept as parameters, and default values are
+//! assigned with `if (b === void 0) b = 1;`. The function's `length` property is not preserved.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-parameters](https://babel.dev/docs/babel-plugin-transform-parameters).
+//!
+//! Parameters are transformed on entering the function, so that destructuring patterns which are
+//! moved into the function body are transformed by destructuring transform when the body is visited.
+//!
+//! Default values and rest parameters are read from `arguments`, which arrow functions do not have.
+//! So parameters of arrow functions are only transformed if arrow functions transform is enabled,
+//! which converts them to function expressions on exit.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-parameters>
+//! * Function definitions specification: <https://tc39.es/ecma262/#sec-function-definitions>
+
+use std::mem;
+
+use serde::Deserialize;
+
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::{number::NumberBase, reference::ReferenceFlags};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    ArenaVec,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ParametersOptions {
+    pub loose: bool,
+}
+
+/// Parameters transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct Parameters {
+    /// If `true`, keep parameters with default values, and don't preserve function's `length`.
+    ignore_function_length: bool,
+    /// If `true`, arrow functions are converted to function expressions, so can use `arguments`.
+    transform_arrow_functions: bool,
+}
+
+impl Parameters {
+    pub fn new(
+        options: ParametersOptions,
+        transform_arrow_functions: bool,
+        ctx: &TransformCtx<'_>,
+    ) -> Self {
+        Self {
+            ignore_function_length: options.loose || ctx.assumptions.ignore_function_length,
+            transform_arrow_functions,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Parameters {
+    // `function f(a = 1, ...b) {}`.
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = func.scope_id();
+        if let Some(body) = &mut func.body {
+            self.transform_parameters(&mut func.params, &mut body.statements, scope_id, ctx);
+        }
+    }
+
+    // `(a = 1, ...b) => {}`.
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Async arrow functions are not converted to function expressions by arrow functions transform
+        if self.transform_arrow_functions && !arrow.r#async {
+            let scope_id = arrow.scope_id();
+            let statements = &mut arrow.body.statements;
+            self.transform_parameters(&mut arrow.params, statements, scope_id, ctx);
+        }
+    }
+}
+
+impl<'a> Parameters {
+    fn transform_parameters(
+        &self,
+        params: &mut FormalParameters<'a>,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let first_default_index = params.items.iter().position(|param| param.initializer.is_some());
+        if first_default_index.is_none() && params.rest.is_none() {
+            return;
+        }
+
+        let rest_index = params.items.len();
+        let mut new_statements = vec![];
+
+        if let Some(first_default_index) = first_default_index {
+            if self.ignore_function_length {
+                Self::transform_loose_default_parameters(
+                    params,
+                    &mut new_statements,
+                    scope_id,
+                    ctx,
+                );
+            } else {
+                Self::transform_parameters_from_index(
+                    params,
+                    first_default_index,
+                    &mut new_statements,
+                    scope_id,
+                    ctx,
+                );
+            }
+        }
+
+        if let Some(rest) = params.rest.take() {
+            Self::transform_rest_parameter(
+                rest.unbox().rest.argument,
+                rest_index,
+                &mut new_statements,
+                scope_id,
+                ctx,
+            );
+        }
+
+        statements.splice(0..0, new_statements);
+    }
+
+    /// Move parameters from the first parameter with a default value onwards into function body.
+    ///
+    /// `function f(a, b = 1, c) {}`
+    /// ->
+    /// ```js
+    /// function f(a) {
+    ///   var b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : 1;
+    ///   var c = arguments.length > 2 ? arguments[2] : void 0;
+    /// }
+    /// ```
+    fn transform_parameters_from_index(
+        params: &mut FormalParameters<'a>,
+        first_default_index: usize,
+        new_statements: &mut Vec<Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let moved_params = params.items.drain(first_default_index..).collect::<Vec<_>>();
+        for (index, param) in (first_default_index..).zip(moved_params) {
+            // `arguments.length > 1`
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                Self::create_arguments_length(scope_id, ctx),
+                BinaryOperator::GreaterThan,
+                Self::create_number(index, ctx),
+            );
+            let init = if let Some(default) = param.initializer {
+                // `arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : default`
+                let is_defined = ctx.ast.expression_binary(
+                    SPAN,
+                    Self::create_arguments_access(index, scope_id, ctx),
+                    BinaryOperator::StrictInequality,
+                    ctx.ast.void_0(SPAN),
+                );
+                let test = ctx.ast.expression_logical(SPAN, test, LogicalOperator::And, is_defined);
+                let value = Self::create_arguments_access(index, scope_id, ctx);
+                ctx.ast.expression_conditional(SPAN, test, value, default.unbox())
+            } else {
+                // `arguments.length > 2 ? arguments[2] : void 0`
+                let value = Self::create_arguments_access(index, scope_id, ctx);
+                ctx.ast.expression_conditional(SPAN, test, value, ctx.ast.void_0(SPAN))
+            };
+            new_statements.push(Self::create_var_declaration(param.pattern, init, ctx));
+        }
+    }
+
+    /// Assign default values in function body, keeping the parameters.
+    ///
+    /// * `function f(a = 1) {}` -> `function f(a) { if (a === void 0) a = 1; }`
+    /// * `function f({ a } = {}) {}` -> `function f(_ref) { var { a } = _ref === void 0 ? {} : _ref; }`
+    fn transform_loose_default_parameters(
+        params: &mut FormalParameters<'a>,
+        new_statements: &mut Vec<Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for param in &mut params.items {
+            let Some(default) = param.initializer.take() else { continue };
+            let default = default.unbox();
+
+            if let BindingPattern::BindingIdentifier(ident) = &param.pattern {
+                // `if (a === void 0) a = 1;`
+                let binding = BoundIdentifier::from_binding_ident(ident);
+                let test = Self::create_is_undefined(&binding, ctx);
+                let target = binding.create_write_target(ctx);
+                let assignment = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    default,
+                );
+                let consequent = ctx.ast.statement_expression(SPAN, assignment);
+                new_statements.push(ctx.ast.statement_if(SPAN, test, consequent, None));
+            } else {
+                // `var { a } = _ref === void 0 ? {} : _ref;`
+                let binding =
+                    ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                let pattern = mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+                let test = Self::create_is_undefined(&binding, ctx);
+                let value = binding.create_read_expression(ctx);
+                let init = ctx.ast.expression_conditional(SPAN, test, default, value);
+                new_statements.push(Self::create_var_declaration(pattern, init, ctx));
+            }
+        }
+    }
+
+    /// Collect rest parameter from `arguments`.
+    ///
+    /// `function f(a, ...b) {}`
+    /// ->
+    /// ```js
+    /// function f(a) {
+    ///   for (var _len = arguments.length, b = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+    ///     b[_key - 1] = arguments[_key];
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// If rest parameter is a destructuring pattern, the array is collected into a `_ref` binding,
+    /// and `var <pattern> = _ref;` is inserted after the loop.
+    fn transform_rest_parameter(
+        pattern: BindingPattern<'a>,
+        index: usize,
+        new_statements: &mut Vec<Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let flags = SymbolFlags::FunctionScopedVariable;
+        let (rest_binding, rest_pattern, pattern_declaration) = match pattern {
+            BindingPattern::BindingIdentifier(ident) => {
+                let binding = BoundIdentifier::from_binding_ident(&ident);
+                (binding, BindingPattern::BindingIdentifier(ident), None)
+            }
+            pattern => {
+                let binding = ctx.generate_uid("ref", scope_id, flags);
+                let init = binding.create_read_expression(ctx);
+                let declaration = Self::create_var_declaration(pattern, init, ctx);
+                let binding_pattern = binding.create_binding_pattern(ctx);
+                (binding, binding_pattern, Some(declaration))
+            }
+        };
+        let len_binding = ctx.generate_uid("len", scope_id, flags);
+        let key_binding = ctx.generate_uid("key", scope_id, flags);
+
+        // `_len > 1 ? _len - 1 : 0`, or `_len` if rest is the only parameter
+        let length = if index == 0 {
+            len_binding.create_read_expression(ctx)
+        } else {
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                len_binding.create_read_expression(ctx),
+                BinaryOperator::GreaterThan,
+                Self::create_number(index, ctx),
+            );
+            let remaining = ctx.ast.expression_binary(
+                SPAN,
+                len_binding.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                Self::create_number(index, ctx),
+            );
+            ctx.ast.expression_conditional(SPAN, test, remaining, Self::create_number(0, ctx))
+        };
+
+        // `var _len = arguments.length, b = new Array(length), _key = 1`
+        let kind = VariableDeclarationKind::Var;
+        let array_callee =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Array"), ReferenceFlags::Read);
+        let new_array =
+            ctx.ast.expression_new(SPAN, array_callee, NONE, ctx.ast.vec1(Argument::from(length)));
+        let declarators = ctx.ast.vec_from_array([
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                len_binding.create_binding_pattern(ctx),
+                NONE,
+                Some(Self::create_arguments_length(scope_id, ctx)),
+                false,
+            ),
+            ctx.ast.variable_declarator(SPAN, kind, rest_pattern, NONE, Some(new_array), false),
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                key_binding.create_binding_pattern(ctx),
+                NONE,
+                Some(Self::create_number(index, ctx)),
+                false,
+            ),
+        ]);
+        let init = ForStatementInit::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            declarators,
+            false,
+        ));
+
+        // `_key < _len`
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            key_binding.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            len_binding.create_read_expression(ctx),
+        );
+
+        // `_key++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            key_binding.create_read_write_simple_target(ctx),
+        );
+
+        // `b[_key - 1] = arguments[_key];`
+        let rest_index = if index == 0 {
+            key_binding.create_read_expression(ctx)
+        } else {
+            ctx.ast.expression_binary(
+                SPAN,
+                key_binding.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                Self::create_number(index, ctx),
+            )
+        };
+        let target = AssignmentTarget::from(ctx.ast.member_expression_computed(
+            SPAN,
+            rest_binding.create_read_expression(ctx),
+            rest_index,
+            false,
+        ));
+        let arguments = Self::create_arguments(scope_id, ctx);
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            arguments,
+            key_binding.create_read_expression(ctx),
+            false,
+        ));
+        let assignment =
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+
+        let for_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        let body_scope_id = ctx.create_child_scope(for_scope_id, ScopeFlags::empty());
+        let body = ctx.ast.statement_block_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment)),
+            body_scope_id,
+        );
+        new_statements.push(ctx.ast.statement_for_with_scope_id(
+            SPAN,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_scope_id,
+        ));
+        new_statements.extend(pattern_declaration);
+    }
+
+    /// `var <pattern> = init;`
+    fn create_var_declaration(
+        pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, NONE, Some(init), false);
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+
+    /// `binding === void 0`
+    fn create_is_undefined(
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        ctx.ast.expression_binary(
+            SPAN,
+            binding.create_read_expression(ctx),
+            BinaryOperator::StrictEquality,
+            ctx.ast.void_0(SPAN),
+        )
+    }
+
+    /// `arguments`
+    fn create_arguments(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let symbol_id = ctx.scoping().find_binding(scope_id, "arguments");
+        ctx.create_ident_expr(SPAN, Atom::from("arguments"), symbol_id, ReferenceFlags::Read)
+    }
+
+    /// `arguments.length`
+    fn create_arguments_length(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let arguments = Self::create_arguments(scope_id, ctx);
+        let property = ctx.ast.identifier_name(SPAN, "length");
+        Expression::from(ctx.ast.member_expression_static(SPAN, arguments, property, false))
+    }
+
+    /// `arguments[index]`
+    fn create_arguments_access(
+        index: usize,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments = Self::create_arguments(scope_id, ctx);
+        let index = Self::create_number(index, ctx);
+        Expression::from(ctx.ast.member_expression_computed(SPAN, arguments, index, false))
+    }
+
+    #[expect(clippy::cast_precision_loss)]
+    fn create_number(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        ctx.ast.expression_numeric_literal(SPAN, value as f64, None, NumberBase::Decimal)
+    }
+}
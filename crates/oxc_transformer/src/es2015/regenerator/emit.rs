@@ -0,0 +1,1372 @@
+//! Conversion of a generator function body into a state machine.
+//!
+//! Statements and expressions containing leaps are "exploded" into a flat listing of
+//! statements, with jumps between locations in the listing. The listing is then split
+//! into `case`s of a `switch` in a loop, which dispatches on `_context.next`.
+
+use std::{cell::Cell, mem};
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{Box as ArenaBox, CloneIn, TakeIn};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolId};
+use oxc_span::{Atom, GetSpan, SPAN};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    ArenaVec,
+    context::{TransformCtx, TraverseCtx},
+};
+
+use super::{
+    leap::{LeapFinder, LeapManager},
+    runtime_property,
+};
+
+/// Location in the listing, which may not be known yet.
+#[derive(Clone, Copy)]
+pub(super) struct Loc(usize);
+
+/// Temporary variable, stored as a property of context object (`_context.t0`).
+#[derive(Clone, Copy)]
+struct TempVar(usize);
+
+/// Locations of a `try` statement, passed to the runtime.
+struct TryEntry {
+    first_loc: usize,
+    catch_loc: Option<Loc>,
+    /// Location of `finally` block, and location after the `try` statement.
+    finally_locs: Option<(Loc, Loc)>,
+}
+
+pub(super) struct Emitter<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// `_context` parameter of the inner function.
+    context: BoundIdentifier<'a>,
+    /// Scope of the dispatch `switch` statement.
+    switch_scope_id: ScopeId,
+    /// Emitted statements.
+    listing: Vec<Statement<'a>>,
+    /// Indexes in `listing` which begin a new `case`.
+    marked: FxHashSet<usize>,
+    /// Index in `listing` for each location, once it is marked.
+    locs: Vec<Option<usize>>,
+    next_temp_id: usize,
+    try_entries: Vec<TryEntry>,
+    leap_manager: LeapManager<'a>,
+}
+
+impl<'a, 'ctx> Emitter<'a, 'ctx> {
+    pub(super) fn new(
+        context: BoundIdentifier<'a>,
+        switch_scope_id: ScopeId,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        let mut marked = FxHashSet::default();
+        marked.insert(0);
+        Self {
+            ctx,
+            context,
+            switch_scope_id,
+            listing: vec![],
+            marked,
+            locs: vec![],
+            next_temp_id: 0,
+            try_entries: vec![],
+            leap_manager: LeapManager::default(),
+        }
+    }
+
+    pub(super) fn explode_statements(
+        &mut self,
+        stmts: ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for stmt in stmts {
+            self.explode_statement(stmt, None, ctx);
+        }
+    }
+
+    /// Create the dispatch loop from the listing, and the list of `try` locations if there are any.
+    ///
+    /// ```js
+    /// while (1) switch (_context.prev = _context.next) {
+    ///   case 0: ...
+    ///   case 3:
+    ///   case "end":
+    ///     return _context.stop();
+    /// }
+    /// ```
+    pub(super) fn into_dispatch_loop(
+        mut self,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Statement<'a>, Option<Expression<'a>>) {
+        let final_index = self.listing.len();
+        let mut cases = ctx.ast.vec();
+        let mut current: Option<(usize, ArenaVec<'a, Statement<'a>>)> = None;
+        let mut already_ended = false;
+        let mut resolver = LocResolver { locs: &self.locs };
+        for (index, mut stmt) in mem::take(&mut self.listing).into_iter().enumerate() {
+            if self.marked.contains(&index) {
+                if let Some((case_index, consequent)) = current.take() {
+                    cases.push(ctx.ast.switch_case(
+                        SPAN,
+                        Some(Self::number(case_index, ctx)),
+                        consequent,
+                    ));
+                }
+                current = Some((index, ctx.ast.vec()));
+                already_ended = false;
+            }
+            // Statements after a completion statement are unreachable
+            if already_ended {
+                UnreachableRemover { ctx, depth: 0 }.visit_statement(&stmt);
+            } else {
+                already_ended = matches!(
+                    stmt,
+                    Statement::BreakStatement(_)
+                        | Statement::ContinueStatement(_)
+                        | Statement::ReturnStatement(_)
+                        | Statement::ThrowStatement(_)
+                );
+                resolver.visit_statement(&mut stmt);
+                current.as_mut().unwrap().1.push(stmt);
+            }
+        }
+        if let Some((case_index, consequent)) = current {
+            cases.push(ctx.ast.switch_case(SPAN, Some(Self::number(case_index, ctx)), consequent));
+        }
+        cases.push(ctx.ast.switch_case(SPAN, Some(Self::number(final_index, ctx)), ctx.ast.vec()));
+        let stop = Expression::from(self.context_property("stop", ctx));
+        let stop = ctx.ast.expression_call(SPAN, stop, NONE, ctx.ast.vec(), false);
+        cases.push(ctx.ast.switch_case(
+            SPAN,
+            Some(ctx.ast.expression_string_literal(SPAN, "end", None)),
+            ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(stop))),
+        ));
+
+        let discriminant = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(self.context_property("prev", ctx)),
+            Expression::from(self.context_property("next", ctx)),
+        );
+        let switch =
+            ctx.ast.statement_switch_with_scope_id(SPAN, discriminant, cases, self.switch_scope_id);
+        let dispatch_loop = ctx.ast.statement_while(SPAN, Self::number(1, ctx), switch);
+
+        // `[[firstLoc, catchLoc, finallyLoc, afterLoc]]`, e.g. `[[0, 5, 9, 11], [13,, 16, 19]]`
+        let try_locs = (!self.try_entries.is_empty()).then(|| {
+            let resolve = |loc: Loc, ctx: &TraverseCtx<'a>| {
+                ArrayExpressionElement::from(Self::number(self.locs[loc.0].unwrap(), ctx))
+            };
+            let entries = ctx.ast.vec_from_iter(self.try_entries.iter().map(|entry| {
+                let mut locs = ctx.ast.vec_with_capacity(4);
+                locs.push(ArrayExpressionElement::from(Self::number(entry.first_loc, ctx)));
+                locs.push(match entry.catch_loc {
+                    Some(loc) => resolve(loc, ctx),
+                    None => ctx.ast.array_expression_element_elision(SPAN),
+                });
+                if let Some((finally_loc, after_loc)) = entry.finally_locs {
+                    locs.push(resolve(finally_loc, ctx));
+                    locs.push(resolve(after_loc, ctx));
+                }
+                ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, locs))
+            }));
+            ctx.ast.expression_array(SPAN, entries)
+        });
+
+        (dispatch_loop, try_locs)
+    }
+
+    /// Create a new location, which is marked later.
+    fn loc(&mut self) -> Loc {
+        self.locs.push(None);
+        Loc(self.locs.len() - 1)
+    }
+
+    /// Mark location as the current position in the listing, which begins a new `case`.
+    fn mark(&mut self, loc: Loc) -> usize {
+        let index = self.listing.len();
+        debug_assert!(self.locs[loc.0].is_none_or(|marked| marked == index));
+        self.locs[loc.0] = Some(index);
+        self.marked.insert(index);
+        index
+    }
+
+    /// Create expression for a location.
+    ///
+    /// If the location is not marked yet, a placeholder is created,
+    /// which is replaced with the position in the listing when creating the dispatch loop.
+    fn loc_expr(&self, loc: Loc, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        match self.locs[loc.0] {
+            Some(index) => Self::number(index, ctx),
+            None => ctx.ast.expression_numeric_literal(
+                SPAN,
+                LocResolver::placeholder(loc),
+                None,
+                NumberBase::Decimal,
+            ),
+        }
+    }
+
+    #[expect(clippy::cast_precision_loss)]
+    fn number(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        ctx.ast.expression_numeric_literal(SPAN, value as f64, None, NumberBase::Decimal)
+    }
+
+    fn emit(&mut self, stmt: Statement<'a>) {
+        self.listing.push(stmt);
+    }
+
+    fn emit_expression(&mut self, expr: Expression<'a>, ctx: &TraverseCtx<'a>) {
+        self.emit(ctx.ast.statement_expression(SPAN, expr));
+    }
+
+    fn emit_assign(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let expr = ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        self.emit_expression(expr, ctx);
+    }
+
+    /// `_context.name`
+    fn context_property(&self, name: &'a str, ctx: &mut TraverseCtx<'a>) -> MemberExpression<'a> {
+        let object = self.context.create_read_expression(ctx);
+        let property = ctx.ast.identifier_name(SPAN, name);
+        ctx.ast.member_expression_static(SPAN, object, property, false)
+    }
+
+    /// `_context.name(arguments)`
+    fn call_context_method(
+        &self,
+        name: &'a str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = Expression::from(self.context_property(name, ctx));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    fn make_temp_var(&mut self) -> TempVar {
+        let temp = TempVar(self.next_temp_id);
+        self.next_temp_id += 1;
+        temp
+    }
+
+    fn temp_var_name(temp: TempVar, ctx: &TraverseCtx<'a>) -> &'a str {
+        ctx.ast.atom(&format!("t{}", temp.0)).as_str()
+    }
+
+    /// `_context.t0`
+    fn temp_var_expr(&self, temp: TempVar, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        Expression::from(self.context_property(Self::temp_var_name(temp, ctx), ctx))
+    }
+
+    fn temp_var_target(&self, temp: TempVar, ctx: &mut TraverseCtx<'a>) -> AssignmentTarget<'a> {
+        AssignmentTarget::from(self.context_property(Self::temp_var_name(temp, ctx), ctx))
+    }
+
+    /// `_context.t0 = value`
+    fn emit_assign_temp_var(
+        &mut self,
+        temp: TempVar,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let target = self.temp_var_target(temp, ctx);
+        self.emit_assign(target, value, ctx);
+    }
+
+    /// `_context.t0 = value`, returns `_context.t0`
+    fn assign_temp_var(
+        &mut self,
+        temp: TempVar,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        self.emit_assign_temp_var(temp, value, ctx);
+        self.temp_var_expr(temp, ctx)
+    }
+
+    /// `_context.next = loc; break;`
+    fn jump(&mut self, loc: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let target = AssignmentTarget::from(self.context_property("next", ctx));
+        self.emit_assign(target, loc, ctx);
+        self.emit(ctx.ast.statement_break(SPAN, None));
+    }
+
+    /// `if (test) { _context.next = loc; break; }`
+    fn jump_if(&mut self, test: Expression<'a>, loc: Loc, ctx: &mut TraverseCtx<'a>) {
+        let target = AssignmentTarget::from(self.context_property("next", ctx));
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            target,
+            self.loc_expr(loc, ctx),
+        );
+        let body = ctx.ast.vec_from_array([
+            ctx.ast.statement_expression(SPAN, assignment),
+            ctx.ast.statement_break(SPAN, None),
+        ]);
+        let scope_id = ctx.create_child_scope(self.switch_scope_id, ScopeFlags::empty());
+        let block = ctx.ast.statement_block_with_scope_id(SPAN, body, scope_id);
+        self.emit(ctx.ast.statement_if(SPAN, test, block, None));
+    }
+
+    /// `if (!test) { _context.next = loc; break; }`
+    fn jump_if_not(&mut self, test: Expression<'a>, loc: Loc, ctx: &mut TraverseCtx<'a>) {
+        let test = match test {
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                unary.unbox().argument
+            }
+            test => ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, test),
+        };
+        self.jump_if(test, loc, ctx);
+    }
+
+    /// `return _context.abrupt(type, argument)`
+    fn emit_abrupt_completion(
+        &mut self,
+        r#type: &'a str,
+        argument: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut arguments = ctx.ast.vec_with_capacity(2);
+        arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, r#type, None)));
+        if let Some(argument) = argument {
+            arguments.push(Argument::from(argument));
+        }
+        let abrupt = self.call_context_method("abrupt", arguments, ctx);
+        self.emit(ctx.ast.statement_return(SPAN, Some(abrupt)));
+    }
+
+    /// `_context.prev = index`
+    fn update_context_prev_loc(&mut self, index: usize, ctx: &mut TraverseCtx<'a>) {
+        debug_assert_eq!(index, self.listing.len());
+        let target = AssignmentTarget::from(self.context_property("prev", ctx));
+        self.emit_assign(target, Self::number(index, ctx), ctx);
+    }
+
+    fn report_unsupported(&self, span: Span, node: &str) {
+        self.ctx.error(
+            OxcDiagnostic::error(format!(
+                "{node} containing `yield` cannot be transformed by regenerator transform."
+            ))
+            .with_label(span),
+        );
+    }
+}
+
+// Statements
+impl<'a> Emitter<'a, '_> {
+    fn explode_statement(
+        &mut self,
+        stmt: Statement<'a>,
+        label: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Statement::BlockStatement(block) = stmt {
+            ctx.scoping_mut().delete_scope(block.scope_id());
+            self.explode_statements(block.unbox().body, ctx);
+            return;
+        }
+
+        if !LeapFinder::statement(&stmt) {
+            self.emit(stmt);
+            return;
+        }
+
+        match stmt {
+            Statement::ExpressionStatement(stmt) => {
+                self.explode_expression(stmt.unbox().expression, true, ctx);
+            }
+            Statement::LabeledStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let after = self.loc();
+                self.leap_manager.push_labeled(after, stmt.label.name);
+                self.explode_statement(stmt.body, Some(stmt.label.name), ctx);
+                self.leap_manager.pop();
+                self.mark(after);
+            }
+            Statement::WhileStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let before = self.loc();
+                let after = self.loc();
+                self.mark(before);
+                let test = self.explode(stmt.test, ctx);
+                self.jump_if_not(test, after, ctx);
+                self.leap_manager.push_loop(after, before, label);
+                self.explode_statement(stmt.body, None, ctx);
+                self.leap_manager.pop();
+                let before = self.loc_expr(before, ctx);
+                self.jump(before, ctx);
+                self.mark(after);
+            }
+            Statement::DoWhileStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let first = self.loc();
+                let test = self.loc();
+                let after = self.loc();
+                self.mark(first);
+                self.leap_manager.push_loop(after, test, label);
+                self.explode_statement(stmt.body, None, ctx);
+                self.leap_manager.pop();
+                self.mark(test);
+                let test = self.explode(stmt.test, ctx);
+                self.jump_if(test, first, ctx);
+                self.mark(after);
+            }
+            Statement::ForStatement(stmt) => {
+                ctx.scoping_mut().delete_scope(stmt.scope_id());
+                let stmt = stmt.unbox();
+                let head = self.loc();
+                let update = self.loc();
+                let after = self.loc();
+                match stmt.init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => {
+                        self.emit(Statement::VariableDeclaration(decl));
+                    }
+                    Some(init) => {
+                        self.explode_expression(init.into_expression(), true, ctx);
+                    }
+                    None => {}
+                }
+                self.mark(head);
+                if let Some(test) = stmt.test {
+                    let test = self.explode(test, ctx);
+                    self.jump_if_not(test, after, ctx);
+                }
+                self.leap_manager.push_loop(after, update, label);
+                self.explode_statement(stmt.body, None, ctx);
+                self.leap_manager.pop();
+                self.mark(update);
+                if let Some(update) = stmt.update {
+                    self.explode_expression(update, true, ctx);
+                }
+                let head = self.loc_expr(head, ctx);
+                self.jump(head, ctx);
+                self.mark(after);
+            }
+            Statement::ForInStatement(stmt) => {
+                ctx.scoping_mut().delete_scope(stmt.scope_id());
+                let stmt = stmt.unbox();
+                let head = self.loc();
+                let after = self.loc();
+
+                // `_context.t0 = regeneratorRuntime().keys(object)`
+                let key_iter_next_fn = self.make_temp_var();
+                let right = self.explode(stmt.right, ctx);
+                let keys = runtime_property(self.ctx, "keys", ctx);
+                let keys = ctx.ast.expression_call(
+                    SPAN,
+                    keys,
+                    NONE,
+                    ctx.ast.vec1(Argument::from(right)),
+                    false,
+                );
+                self.emit_assign_temp_var(key_iter_next_fn, keys, ctx);
+
+                // `if ((_context.t1 = _context.t0()).done) { _context.next = after; break; }`
+                self.mark(head);
+                let key_info = self.make_temp_var();
+                let next_fn = self.temp_var_expr(key_iter_next_fn, ctx);
+                let next = ctx.ast.expression_call(SPAN, next_fn, NONE, ctx.ast.vec(), false);
+                let key_info_target = self.temp_var_target(key_info, ctx);
+                let next = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    key_info_target,
+                    next,
+                );
+                let done = ctx.ast.member_expression_static(
+                    SPAN,
+                    ctx.ast.expression_parenthesized(SPAN, next),
+                    ctx.ast.identifier_name(SPAN, "done"),
+                    false,
+                );
+                self.jump_if(Expression::from(done), after, ctx);
+
+                // `left = _context.t1.value`
+                let value = ctx.ast.member_expression_static(
+                    SPAN,
+                    self.temp_var_expr(key_info, ctx),
+                    ctx.ast.identifier_name(SPAN, "value"),
+                    false,
+                );
+                match stmt.left {
+                    ForStatementLeft::VariableDeclaration(_) => unreachable!(),
+                    left => self.emit_assign(
+                        left.into_assignment_target(),
+                        Expression::from(value),
+                        ctx,
+                    ),
+                }
+
+                self.leap_manager.push_loop(after, head, label);
+                self.explode_statement(stmt.body, None, ctx);
+                self.leap_manager.pop();
+                let head = self.loc_expr(head, ctx);
+                self.jump(head, ctx);
+                self.mark(after);
+            }
+            Statement::BreakStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| label.name);
+                let loc = self.leap_manager.break_loc(label).unwrap();
+                let loc = self.loc_expr(loc, ctx);
+                self.emit_abrupt_completion("break", Some(loc), ctx);
+            }
+            Statement::ContinueStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| label.name);
+                let loc = self.leap_manager.continue_loc(label).unwrap();
+                let loc = self.loc_expr(loc, ctx);
+                self.emit_abrupt_completion("continue", Some(loc), ctx);
+            }
+            Statement::SwitchStatement(stmt) => self.explode_switch_statement(stmt, ctx),
+            Statement::IfStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let else_loc = stmt.alternate.is_some().then(|| self.loc());
+                let after = self.loc();
+                let test = self.explode(stmt.test, ctx);
+                self.jump_if_not(test, else_loc.unwrap_or(after), ctx);
+                self.explode_statement(stmt.consequent, None, ctx);
+                if let (Some(else_loc), Some(alternate)) = (else_loc, stmt.alternate) {
+                    let after = self.loc_expr(after, ctx);
+                    self.jump(after, ctx);
+                    self.mark(else_loc);
+                    self.explode_statement(alternate, None, ctx);
+                }
+                self.mark(after);
+            }
+            Statement::ReturnStatement(stmt) => {
+                let argument = stmt.unbox().argument.map(|argument| self.explode(argument, ctx));
+                self.emit_abrupt_completion("return", argument, ctx);
+            }
+            Statement::ThrowStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let argument = self.explode(stmt.argument, ctx);
+                self.emit(ctx.ast.statement_throw(stmt.span, argument));
+            }
+            Statement::TryStatement(stmt) => self.explode_try_statement(stmt, ctx),
+            Statement::ClassDeclaration(mut class) => {
+                self.explode_class(&mut class, ctx);
+                self.emit(Statement::ClassDeclaration(class));
+            }
+            stmt => {
+                let node = match &stmt {
+                    Statement::ForOfStatement(_) => "`for...of` statement",
+                    Statement::WithStatement(_) => "`with` statement",
+                    _ => "Statement",
+                };
+                self.report_unsupported(stmt.span(), node);
+                self.emit(stmt);
+            }
+        }
+    }
+
+    fn explode_switch_statement(
+        &mut self,
+        stmt: ArenaBox<'a, SwitchStatement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        ctx.scoping_mut().delete_scope(stmt.scope_id());
+        let stmt = stmt.unbox();
+
+        // Always save the discriminant into a temporary variable,
+        // in case the test expressions overwrite values like `_context.sent`
+        let discriminant_temp = self.make_temp_var();
+        let discriminant = self.explode(stmt.discriminant, ctx);
+        self.emit_assign_temp_var(discriminant_temp, discriminant, ctx);
+
+        let after = self.loc();
+        let default_loc = self.loc();
+
+        // `_context.t0 === a ? 1 : _context.t0 === b ? 3 : 5`
+        let mut condition = self.loc_expr(default_loc, ctx);
+        let mut case_locs = vec![default_loc; stmt.cases.len()];
+        let mut consequents = Vec::with_capacity(stmt.cases.len());
+        for (index, case) in stmt.cases.into_iter().enumerate().rev() {
+            if let Some(test) = case.test {
+                let loc = self.loc();
+                case_locs[index] = loc;
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    self.temp_var_expr(discriminant_temp, ctx),
+                    BinaryOperator::StrictEquality,
+                    test,
+                );
+                condition =
+                    ctx.ast.expression_conditional(SPAN, test, self.loc_expr(loc, ctx), condition);
+            }
+            consequents.push(case.consequent);
+        }
+        let condition = self.explode(condition, ctx);
+        self.jump(condition, ctx);
+
+        self.leap_manager.push_switch(after);
+        for (loc, consequent) in case_locs.into_iter().zip(consequents.into_iter().rev()) {
+            self.mark(loc);
+            self.explode_statements(consequent, ctx);
+        }
+        self.leap_manager.pop();
+
+        let after_index = self.mark(after);
+        if self.locs[default_loc.0].is_none() {
+            let default_index = self.mark(default_loc);
+            debug_assert_eq!(after_index, default_index);
+        }
+    }
+
+    fn explode_try_statement(
+        &mut self,
+        stmt: ArenaBox<'a, TryStatement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let stmt = stmt.unbox();
+        let after = self.loc();
+        let catch_loc = stmt.handler.is_some().then(|| self.loc());
+        let finally_loc = stmt.finalizer.is_some().then(|| self.loc());
+        let first_loc = self.listing.len();
+        self.try_entries.push(TryEntry {
+            first_loc,
+            catch_loc,
+            finally_locs: finally_loc.map(|finally_loc| (finally_loc, after)),
+        });
+        self.update_context_prev_loc(first_loc, ctx);
+
+        self.explode_statement(Statement::BlockStatement(stmt.block), None, ctx);
+
+        if let (Some(catch_loc), Some(handler)) = (catch_loc, stmt.handler) {
+            // Jump over the `catch` block
+            let loc = self.loc_expr(finally_loc.unwrap_or(after), ctx);
+            self.jump(loc, ctx);
+
+            let index = self.mark(catch_loc);
+            self.update_context_prev_loc(index, ctx);
+
+            // `_context.t0 = _context["catch"](0)`
+            let safe_param = self.make_temp_var();
+            let catch_callee = ctx.ast.member_expression_computed(
+                SPAN,
+                self.context.create_read_expression(ctx),
+                ctx.ast.expression_string_literal(SPAN, "catch", None),
+                false,
+            );
+            let catch_call = ctx.ast.expression_call(
+                SPAN,
+                Expression::from(catch_callee),
+                NONE,
+                ctx.ast.vec1(Argument::from(Self::number(first_loc, ctx))),
+                false,
+            );
+            self.emit_assign_temp_var(safe_param, catch_call, ctx);
+
+            let handler = handler.unbox();
+            ctx.scoping_mut().delete_scope(handler.scope_id());
+            let mut body = handler.body;
+            if let Some(param) = handler.param {
+                match param.pattern {
+                    BindingPattern::BindingIdentifier(ident) => {
+                        CatchParamReplacer {
+                            emitter: self,
+                            symbol_id: ident.symbol_id(),
+                            safe_param,
+                            ctx,
+                        }
+                        .visit_block_statement(&mut body);
+                    }
+                    pattern => self.report_unsupported(
+                        pattern.span(),
+                        "Catch clause with destructuring pattern",
+                    ),
+                }
+            }
+            self.explode_statement(Statement::BlockStatement(body), None, ctx);
+        }
+
+        if let (Some(finally_loc), Some(finalizer)) = (finally_loc, stmt.finalizer) {
+            let index = self.mark(finally_loc);
+            self.update_context_prev_loc(index, ctx);
+            self.explode_statement(Statement::BlockStatement(finalizer), None, ctx);
+            // `return _context.finish(finally_loc)`
+            let finish = self.call_context_method(
+                "finish",
+                ctx.ast.vec1(Argument::from(Self::number(index, ctx))),
+                ctx,
+            );
+            self.emit(ctx.ast.statement_return(SPAN, Some(finish)));
+        }
+
+        self.mark(after);
+    }
+
+    /// Explode `extends` clause and computed keys of a class.
+    fn explode_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut children = vec![];
+        if let Some(super_class) = &mut class.super_class {
+            children.push(super_class);
+        }
+        for element in &mut class.body.body {
+            match element {
+                ClassElement::MethodDefinition(method) if method.computed => {
+                    children.push(method.key.to_expression_mut());
+                }
+                ClassElement::PropertyDefinition(prop) if prop.computed => {
+                    children.push(prop.key.to_expression_mut());
+                }
+                ClassElement::AccessorProperty(prop) if prop.computed => {
+                    children.push(prop.key.to_expression_mut());
+                }
+                _ => {}
+            }
+        }
+
+        let last_index = children.len().saturating_sub(1);
+        for (index, child) in children.into_iter().enumerate() {
+            let expr = child.take_in(ctx.ast);
+            *child = if index == last_index {
+                self.explode(expr, ctx)
+            } else {
+                self.explode_to_temp(expr, ctx)
+            };
+        }
+    }
+}
+
+// Expressions
+impl<'a> Emitter<'a, '_> {
+    /// Explode expression, and return the expression for its result.
+    fn explode(&mut self, expr: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        self.explode_expression(expr, false, ctx).unwrap()
+    }
+
+    /// Explode expression, and store its result in a temp var, unless it is a literal.
+    fn explode_to_temp(
+        &mut self,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        self.explode_via_temp_var(None, expr, false, ctx).unwrap()
+    }
+
+    fn explode_via_temp_var(
+        &mut self,
+        temp: Option<TempVar>,
+        child: Expression<'a>,
+        ignore_child_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let result = self.explode_expression(child, ignore_child_result, ctx);
+        if ignore_child_result {
+            return result;
+        }
+        let result = result.unwrap();
+        if temp.is_some() || !Self::is_literal(&result) {
+            let temp = temp.unwrap_or_else(|| self.make_temp_var());
+            return Some(self.assign_temp_var(temp, result, ctx));
+        }
+        Some(result)
+    }
+
+    /// Explode expression, and store its result in `temp`, if there is one.
+    fn explode_into_temp_var(
+        &mut self,
+        temp: Option<TempVar>,
+        child: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(temp) = temp {
+            let result = self.explode(child, ctx);
+            self.emit_assign_temp_var(temp, result, ctx);
+        } else {
+            self.explode_expression(child, true, ctx);
+        }
+    }
+
+    fn is_literal(expr: &Expression<'a>) -> bool {
+        expr.is_literal() || matches!(expr, Expression::TemplateLiteral(_))
+    }
+
+    /// Return the expression, or emit it if its result is not used.
+    fn finish(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if ignore_result {
+            self.emit_expression(expr, ctx);
+            None
+        } else {
+            Some(expr)
+        }
+    }
+
+    /// Explode expression.
+    ///
+    /// If `ignore_result` is `true`, the expression is emitted, and may return `None`.
+    fn explode_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if !LeapFinder::expression(&expr) {
+            return self.finish(expr, ignore_result, ctx);
+        }
+
+        let expr = match expr {
+            Expression::ParenthesizedExpression(paren) => {
+                return self.explode_expression(paren.unbox().expression, ignore_result, ctx);
+            }
+            Expression::StaticMemberExpression(mut member) => {
+                member.object = self.explode(member.object.take_in(ctx.ast), ctx);
+                Expression::StaticMemberExpression(member)
+            }
+            Expression::ComputedMemberExpression(mut member) => {
+                member.object = self.explode(member.object.take_in(ctx.ast), ctx);
+                member.expression = self.explode_to_temp(member.expression.take_in(ctx.ast), ctx);
+                Expression::ComputedMemberExpression(member)
+            }
+            Expression::PrivateFieldExpression(mut member) => {
+                member.object = self.explode(member.object.take_in(ctx.ast), ctx);
+                Expression::PrivateFieldExpression(member)
+            }
+            Expression::CallExpression(call) => self.explode_call_expression(call, ctx),
+            Expression::NewExpression(mut new_expr) => {
+                new_expr.callee = self.explode_to_temp(new_expr.callee.take_in(ctx.ast), ctx);
+                self.explode_arguments(&mut new_expr.arguments, ctx);
+                Expression::NewExpression(new_expr)
+            }
+            Expression::ObjectExpression(mut object) => {
+                for prop in &mut object.properties {
+                    match prop {
+                        ObjectPropertyKind::ObjectProperty(prop)
+                            if prop.kind == PropertyKind::Init && !prop.method =>
+                        {
+                            if prop.computed && LeapFinder::expression(prop.key.to_expression()) {
+                                let key = prop.key.to_expression_mut();
+                                *key = self.explode_to_temp(key.take_in(ctx.ast), ctx);
+                            }
+                            prop.value = self.explode_to_temp(prop.value.take_in(ctx.ast), ctx);
+                            prop.shorthand = false;
+                        }
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            spread.argument =
+                                self.explode_to_temp(spread.argument.take_in(ctx.ast), ctx);
+                        }
+                        ObjectPropertyKind::ObjectProperty(_) => {}
+                    }
+                }
+                Expression::ObjectExpression(object)
+            }
+            Expression::ArrayExpression(mut array) => {
+                for element in &mut array.elements {
+                    match element {
+                        ArrayExpressionElement::Elision(_) => {}
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            spread.argument =
+                                self.explode_to_temp(spread.argument.take_in(ctx.ast), ctx);
+                        }
+                        element => {
+                            let expr = element.to_expression_mut();
+                            *expr = self.explode_to_temp(expr.take_in(ctx.ast), ctx);
+                        }
+                    }
+                }
+                Expression::ArrayExpression(array)
+            }
+            Expression::SequenceExpression(sequence) => {
+                let mut expressions = sequence.unbox().expressions;
+                let last = expressions.pop().unwrap();
+                for expr in expressions {
+                    self.explode_expression(expr, true, ctx);
+                }
+                return self.explode_expression(last, ignore_result, ctx);
+            }
+            Expression::LogicalExpression(logical) => {
+                let logical = logical.unbox();
+                let after = self.loc();
+                let result = (!ignore_result).then(|| self.make_temp_var());
+                let left = self.explode_via_temp_var(result, logical.left, false, ctx).unwrap();
+                match logical.operator {
+                    LogicalOperator::And => self.jump_if_not(left, after, ctx),
+                    LogicalOperator::Or => self.jump_if(left, after, ctx),
+                    LogicalOperator::Coalesce => {
+                        let test = ctx.ast.expression_binary(
+                            SPAN,
+                            left,
+                            BinaryOperator::Inequality,
+                            ctx.ast.expression_null_literal(SPAN),
+                        );
+                        self.jump_if(test, after, ctx);
+                    }
+                }
+                self.explode_into_temp_var(result, logical.right, ctx);
+                self.mark(after);
+                return result.map(|result| self.temp_var_expr(result, ctx));
+            }
+            Expression::ConditionalExpression(conditional) => {
+                let conditional = conditional.unbox();
+                let else_loc = self.loc();
+                let after = self.loc();
+                let test = self.explode(conditional.test, ctx);
+                self.jump_if_not(test, else_loc, ctx);
+                let result = (!ignore_result).then(|| self.make_temp_var());
+                self.explode_into_temp_var(result, conditional.consequent, ctx);
+                let after_expr = self.loc_expr(after, ctx);
+                self.jump(after_expr, ctx);
+                self.mark(else_loc);
+                self.explode_into_temp_var(result, conditional.alternate, ctx);
+                self.mark(after);
+                return result.map(|result| self.temp_var_expr(result, ctx));
+            }
+            Expression::UnaryExpression(mut unary) => {
+                unary.argument = self.explode(unary.argument.take_in(ctx.ast), ctx);
+                Expression::UnaryExpression(unary)
+            }
+            Expression::BinaryExpression(mut binary) => {
+                binary.left = self.explode_to_temp(binary.left.take_in(ctx.ast), ctx);
+                binary.right = self.explode_to_temp(binary.right.take_in(ctx.ast), ctx);
+                Expression::BinaryExpression(binary)
+            }
+            Expression::PrivateInExpression(mut private_in) => {
+                private_in.right = self.explode_to_temp(private_in.right.take_in(ctx.ast), ctx);
+                Expression::PrivateInExpression(private_in)
+            }
+            Expression::AssignmentExpression(assign) => {
+                self.explode_assignment_expression(assign, ctx)
+            }
+            Expression::UpdateExpression(mut update) => {
+                update.argument =
+                    self.explode_simple_target(update.argument.take_in(ctx.ast), false, ctx);
+                Expression::UpdateExpression(update)
+            }
+            Expression::YieldExpression(yield_expr) => {
+                return self.explode_yield_expression(yield_expr, ignore_result, ctx);
+            }
+            Expression::ClassExpression(mut class) => {
+                self.explode_class(&mut class, ctx);
+                Expression::ClassExpression(class)
+            }
+            Expression::TemplateLiteral(mut template) => {
+                for expr in &mut template.expressions {
+                    *expr = self.explode_to_temp(expr.take_in(ctx.ast), ctx);
+                }
+                Expression::TemplateLiteral(template)
+            }
+            expr => {
+                let node = match &expr {
+                    Expression::ChainExpression(_) => "Optional chain",
+                    Expression::TaggedTemplateExpression(_) => "Tagged template",
+                    _ => "Expression",
+                };
+                self.report_unsupported(expr.span(), node);
+                expr
+            }
+        };
+        self.finish(expr, ignore_result, ctx)
+    }
+
+    fn explode_call_expression(
+        &mut self,
+        mut call: ArenaBox<'a, CallExpression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let has_leaping_args = call
+            .arguments
+            .iter()
+            .any(|argument| LeapFinder::check(|finder| Visit::visit_argument(finder, argument)));
+
+        let callee = call.callee.take_in(ctx.ast);
+        let mut first_argument = None;
+        call.callee = match callee {
+            callee if callee.is_member_expression() && has_leaping_args => {
+                // Evaluate the callee before the arguments, but keep the object of the member
+                // expression as `this` for the call.
+                // `a.b(yield)` -> `_context.t0 = a; ...; _context.t0.b.call(_context.t0, _context.sent)`
+                let mut member = callee.into_member_expression();
+                if matches!(member.object(), Expression::Super(_)) {
+                    Expression::from(member)
+                } else {
+                    let temp = self.make_temp_var();
+                    let object = member.object_mut().take_in(ctx.ast);
+                    *member.object_mut() =
+                        self.explode_via_temp_var(Some(temp), object, false, ctx).unwrap();
+                    if let MemberExpression::ComputedMemberExpression(member) = &mut member {
+                        member.expression =
+                            self.explode_to_temp(member.expression.take_in(ctx.ast), ctx);
+                    }
+                    first_argument = Some(temp);
+                    Expression::from(ctx.ast.member_expression_static(
+                        SPAN,
+                        Expression::from(member),
+                        ctx.ast.identifier_name(SPAN, "call"),
+                        false,
+                    ))
+                }
+            }
+            callee if callee.is_member_expression() => self.explode(callee, ctx),
+            callee => {
+                let callee = self.explode_to_temp(callee, ctx);
+                // Callee was unqualified, so `this` must not be the context object.
+                // `(0, _context.t0)()`
+                if callee.is_member_expression() {
+                    ctx.ast.expression_sequence(
+                        SPAN,
+                        ctx.ast.vec_from_array([Self::number(0, ctx), callee]),
+                    )
+                } else {
+                    callee
+                }
+            }
+        };
+
+        if has_leaping_args {
+            self.explode_arguments(&mut call.arguments, ctx);
+            if let Some(temp) = first_argument {
+                call.arguments.insert(0, Argument::from(self.temp_var_expr(temp, ctx)));
+            }
+        }
+        Expression::CallExpression(call)
+    }
+
+    fn explode_arguments(
+        &mut self,
+        arguments: &mut ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for argument in arguments.iter_mut() {
+            match argument {
+                Argument::SpreadElement(spread) => {
+                    spread.argument = self.explode_to_temp(spread.argument.take_in(ctx.ast), ctx);
+                }
+                argument => {
+                    let expr = argument.to_expression_mut();
+                    *expr = self.explode_to_temp(expr.take_in(ctx.ast), ctx);
+                }
+            }
+        }
+    }
+
+    fn explode_assignment_expression(
+        &mut self,
+        mut assign: ArenaBox<'a, AssignmentExpression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if assign.operator == AssignmentOperator::Assign {
+            // Simple assignment does not read the left side,
+            // so it can be evaluated separately from the right side
+            assign.left = match assign.left.take_in(ctx.ast) {
+                left if left.is_simple_assignment_target() => AssignmentTarget::from(
+                    self.explode_simple_target(left.into_simple_assignment_target(), false, ctx),
+                ),
+                left => {
+                    if LeapFinder::check(|finder| {
+                        Visit::visit_assignment_target(finder, &left);
+                    }) {
+                        self.report_unsupported(left.span(), "Destructuring assignment target");
+                    }
+                    left
+                }
+            };
+            assign.right = self.explode(assign.right.take_in(ctx.ast), ctx);
+            return Expression::AssignmentExpression(assign);
+        }
+
+        // `x += yield y` -> `_context.t0 = x; ...; x = _context.t0 += _context.sent`
+        let AssignmentExpression { span, operator, left, right } = assign.unbox();
+        let target = self.explode_simple_target(left.into_simple_assignment_target(), true, ctx);
+        let value = Self::clone_simple_target(&target, ctx);
+        let temp = self.make_temp_var();
+        self.emit_assign_temp_var(temp, value, ctx);
+        let right = self.explode(right, ctx);
+        let temp_target = self.temp_var_target(temp, ctx);
+        let right = ctx.ast.expression_assignment(span, operator, temp_target, right);
+        ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(target),
+            right,
+        )
+    }
+
+    /// Explode object and property of a member expression assignment target.
+    ///
+    /// If `reuse` is `true`, they're stored in temp vars if they cannot be evaluated twice.
+    fn explode_simple_target(
+        &mut self,
+        target: SimpleAssignmentTarget<'a>,
+        reuse: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> SimpleAssignmentTarget<'a> {
+        let has_leap =
+            LeapFinder::check(|finder| Visit::visit_simple_assignment_target(finder, &target));
+        if !has_leap && !reuse {
+            return target;
+        }
+        match target {
+            SimpleAssignmentTarget::StaticMemberExpression(mut member) => {
+                member.object = self.explode_member_object(
+                    member.object.take_in(ctx.ast),
+                    has_leap,
+                    reuse,
+                    ctx,
+                );
+                SimpleAssignmentTarget::StaticMemberExpression(member)
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(mut member) => {
+                member.object = self.explode_member_object(
+                    member.object.take_in(ctx.ast),
+                    has_leap,
+                    reuse,
+                    ctx,
+                );
+                let mut property = member.expression.take_in(ctx.ast);
+                if has_leap {
+                    property = self.explode_to_temp(property, ctx);
+                }
+                if reuse && !self.is_simple(&property, ctx) {
+                    let temp = self.make_temp_var();
+                    property = self.assign_temp_var(temp, property, ctx);
+                }
+                member.expression = property;
+                SimpleAssignmentTarget::ComputedMemberExpression(member)
+            }
+            SimpleAssignmentTarget::PrivateFieldExpression(mut member) => {
+                member.object = self.explode_member_object(
+                    member.object.take_in(ctx.ast),
+                    has_leap,
+                    reuse,
+                    ctx,
+                );
+                SimpleAssignmentTarget::PrivateFieldExpression(member)
+            }
+            target => target,
+        }
+    }
+
+    fn explode_member_object(
+        &mut self,
+        object: Expression<'a>,
+        has_leap: bool,
+        reuse: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object = if has_leap { self.explode(object, ctx) } else { object };
+        if reuse && !self.is_simple(&object, ctx) {
+            let temp = self.make_temp_var();
+            return self.assign_temp_var(temp, object, ctx);
+        }
+        object
+    }
+
+    /// Check if expression can be evaluated more than once without side effects.
+    fn is_simple(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) => {
+                ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_some()
+            }
+            Expression::StaticMemberExpression(member) => {
+                matches!(&member.object, Expression::Identifier(ident) if ident.name == self.context.name)
+            }
+            Expression::ThisExpression(_) | Expression::Super(_) => true,
+            expr => expr.is_literal(),
+        }
+    }
+
+    /// Clone an expression which passes [`Self::is_simple`].
+    fn clone_simple_expression(expr: &Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match expr {
+            Expression::Identifier(ident) => {
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                ctx.create_ident_expr(ident.span, ident.name, symbol_id, ReferenceFlags::Read)
+            }
+            Expression::StaticMemberExpression(member) => {
+                let object = Self::clone_simple_expression(&member.object, ctx);
+                Expression::from(ctx.ast.member_expression_static(
+                    member.span,
+                    object,
+                    member.property.clone(),
+                    false,
+                ))
+            }
+            expr => expr.clone_in(ctx.ast.allocator),
+        }
+    }
+
+    /// Create an expression to read the value of an assignment target.
+    fn clone_simple_target(
+        target: &SimpleAssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                ctx.create_ident_expr(ident.span, ident.name, symbol_id, ReferenceFlags::Read)
+            }
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                let object = Self::clone_simple_expression(&member.object, ctx);
+                Expression::from(ctx.ast.member_expression_static(
+                    member.span,
+                    object,
+                    member.property.clone(),
+                    false,
+                ))
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => {
+                let object = Self::clone_simple_expression(&member.object, ctx);
+                let property = Self::clone_simple_expression(&member.expression, ctx);
+                Expression::from(ctx.ast.member_expression_computed(
+                    member.span,
+                    object,
+                    property,
+                    false,
+                ))
+            }
+            SimpleAssignmentTarget::PrivateFieldExpression(member) => {
+                let object = Self::clone_simple_expression(&member.object, ctx);
+                Expression::from(ctx.ast.member_expression_private_field_expression(
+                    member.span,
+                    object,
+                    member.field.clone(),
+                    false,
+                ))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn explode_yield_expression(
+        &mut self,
+        yield_expr: ArenaBox<'a, YieldExpression<'a>>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let YieldExpression { span, delegate, argument } = yield_expr.unbox();
+        let after = self.loc();
+        let argument = argument.map(|argument| self.explode(argument, ctx));
+
+        if delegate && let Some(argument) = argument {
+            // `return _context.delegateYield(argument, "t0", after)`
+            let result = self.make_temp_var();
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(argument),
+                Argument::from(ctx.ast.expression_string_literal(
+                    SPAN,
+                    Self::temp_var_name(result, ctx),
+                    None,
+                )),
+                Argument::from(self.loc_expr(after, ctx)),
+            ]);
+            let delegate_yield = self.call_context_method("delegateYield", arguments, ctx);
+            self.emit(ctx.ast.statement_return(span, Some(delegate_yield)));
+            self.mark(after);
+            return (!ignore_result).then(|| self.temp_var_expr(result, ctx));
+        }
+
+        // `_context.next = after; return argument;`
+        let target = AssignmentTarget::from(self.context_property("next", ctx));
+        let loc = self.loc_expr(after, ctx);
+        self.emit_assign(target, loc, ctx);
+        self.emit(ctx.ast.statement_return(span, argument));
+        self.mark(after);
+        (!ignore_result).then(|| Expression::from(self.context_property("sent", ctx)))
+    }
+}
+
+/// Visitor to replace references to a `catch` clause parameter with a temp var.
+struct CatchParamReplacer<'a, 'ctx, 'e, 'c> {
+    emitter: &'e Emitter<'a, 'ctx>,
+    symbol_id: SymbolId,
+    safe_param: TempVar,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> CatchParamReplacer<'a, '_, '_, '_> {
+    fn is_param(&self, ident: &IdentifierReference<'a>) -> bool {
+        self.ctx.scoping().get_reference(ident.reference_id()).symbol_id() == Some(self.symbol_id)
+    }
+
+    fn replace(&mut self, ident: &IdentifierReference<'a>) -> MemberExpression<'a> {
+        self.ctx.scoping_mut().delete_resolved_reference(self.symbol_id, ident.reference_id());
+        let name = Emitter::temp_var_name(self.safe_param, self.ctx);
+        self.emitter.context_property(name, self.ctx)
+    }
+}
+
+impl<'a> VisitMut<'a> for CatchParamReplacer<'a, '_, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr
+            && self.is_param(ident)
+        {
+            *expr = Expression::from(self.replace(ident));
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target
+            && self.is_param(ident)
+        {
+            *target = SimpleAssignmentTarget::from(self.replace(ident));
+            return;
+        }
+        walk_mut::walk_simple_assignment_target(self, target);
+    }
+}
+
+/// Visitor to remove references and scopes of an unreachable statement, which is dropped.
+struct UnreachableRemover<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+    /// Depth of nested scopes
+    depth: usize,
+}
+
+impl<'a> Visit<'a> for UnreachableRemover<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            self.ctx.scoping_mut().delete_scope(scope_id.get().unwrap());
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.ctx.delete_reference_for_identifier(ident);
+    }
+}
+
+/// Visitor to replace placeholders for locations with their positions in the listing.
+///
+/// Placeholders are negative numeric literals, which cannot occur in source code.
+struct LocResolver<'l> {
+    locs: &'l [Option<usize>],
+}
+
+impl LocResolver<'_> {
+    #[expect(clippy::cast_precision_loss)]
+    fn placeholder(loc: Loc) -> f64 {
+        -((loc.0 + 1) as f64)
+    }
+}
+
+impl<'a> VisitMut<'a> for LocResolver<'_> {
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+    fn visit_numeric_literal(&mut self, lit: &mut NumericLiteral<'a>) {
+        if lit.value < 0.0 {
+            let loc = (-lit.value) as usize - 1;
+            lit.value = self.locs[loc].unwrap() as f64;
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//! Hoisting of declarations in generator functions.
+//!
+//! Statements of a generator function body are split into `case`s of a `switch`,
+//! so declarations in the body cannot remain where they are.
+//!
+//! * `var`, `let` and `const` declarations become assignments,
+//!   and their bindings are declared with a single `var` in the outer function.
+//! * Function declarations become assignments of function expressions,
+//!   inserted at the start of the enclosing block.
+//!
+//! Bindings hoisted from nested scopes are renamed if the name is already used in the function.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{Atom, SPAN};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    ArenaVec,
+    context::TraverseCtx,
+    es2015::block_scoping::{BindingRenamer, binding_pattern_to_assignment_target},
+};
+
+/// Visitor to hoist declarations in a generator function body.
+pub(super) struct Hoister<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+    /// Scope of the generator function
+    function_scope_id: ScopeId,
+    /// Symbols of the function's parameters, which are not redeclared
+    params: FxHashSet<SymbolId>,
+    /// Hoisted bindings, in order of declaration
+    bindings: Vec<SymbolId>,
+    /// New names of bindings which were renamed to avoid conflicts
+    renames: FxHashMap<SymbolId, Atom<'a>>,
+}
+
+impl<'a, 'c> Hoister<'a, 'c> {
+    /// Hoist declarations in `func`'s body.
+    ///
+    /// Returns `var` declaration of hoisted bindings, if there are any.
+    pub(super) fn hoist(
+        func: &mut Function<'a>,
+        ctx: &'c mut TraverseCtx<'a>,
+    ) -> Option<VariableDeclaration<'a>> {
+        let mut params = FxHashSet::default();
+        func.params.bound_names(&mut |ident| {
+            params.insert(ident.symbol_id());
+        });
+
+        let mut hoister = Self {
+            ctx,
+            function_scope_id: func.scope_id(),
+            params,
+            bindings: vec![],
+            renames: FxHashMap::default(),
+        };
+        let body = func.body.as_mut().unwrap();
+        hoister.visit_statements(&mut body.statements);
+
+        let Self { ctx, bindings, renames, .. } = hoister;
+        if !renames.is_empty() {
+            BindingRenamer::new(&renames, ctx).visit_function_body(body);
+        }
+        if bindings.is_empty() {
+            return None;
+        }
+
+        let declarations = ctx.ast.vec_from_iter(bindings.into_iter().map(|symbol_id| {
+            let scoping = ctx.scoping();
+            let ident = ctx.ast.binding_identifier_with_symbol_id(
+                scoping.symbol_span(symbol_id),
+                ctx.ast.atom(scoping.symbol_name(symbol_id)),
+                symbol_id,
+            );
+            ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                BindingPattern::BindingIdentifier(ctx.ast.alloc(ident)),
+                NONE,
+                None,
+                false,
+            )
+        }));
+        Some(ctx.ast.variable_declaration(SPAN, VariableDeclarationKind::Var, declarations, false))
+    }
+
+    /// Move binding to function scope, and record it to be declared with `var`.
+    fn hoist_binding(&mut self, ident: &BindingIdentifier<'a>) {
+        let symbol_id = ident.symbol_id();
+        if self.params.contains(&symbol_id) || self.bindings.contains(&symbol_id) {
+            return;
+        }
+        self.bindings.push(symbol_id);
+
+        let function_scope_id = self.function_scope_id;
+        let scope_id = self.ctx.scoping().symbol_scope_id(symbol_id);
+        if scope_id != function_scope_id {
+            let scoping = self.ctx.scoping();
+            let name = if scoping.find_binding(function_scope_id, &ident.name).is_some()
+                || scoping.root_unresolved_references().contains_key(ident.name.as_str())
+            {
+                let new_name = self.ctx.generate_uid_name(&ident.name);
+                self.ctx.scoping_mut().rename_symbol(symbol_id, scope_id, &new_name);
+                self.renames.insert(symbol_id, new_name);
+                new_name
+            } else {
+                ident.name
+            };
+            let scoping = self.ctx.scoping_mut();
+            scoping.move_binding(scope_id, function_scope_id, &name);
+            scoping.set_symbol_scope_id(symbol_id, function_scope_id);
+        }
+
+        let flags = self.ctx.scoping_mut().symbol_flags_mut(symbol_id);
+        flags.remove(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable);
+        flags.remove(SymbolFlags::Function);
+        flags.insert(SymbolFlags::FunctionScopedVariable);
+    }
+
+    /// `var a = 1, b, c = 2` -> `a = 1, c = 2`
+    fn transform_declaration(&mut self, decl: VariableDeclaration<'a>) -> Option<Expression<'a>> {
+        let mut expressions = self.ctx.ast.vec();
+        for declarator in decl.declarations {
+            declarator.id.bound_names(&mut |ident| self.hoist_binding(ident));
+            if let Some(init) = declarator.init {
+                let target = binding_pattern_to_assignment_target(declarator.id, self.ctx);
+                expressions.push(self.ctx.ast.expression_assignment(
+                    declarator.span,
+                    AssignmentOperator::Assign,
+                    target,
+                    init,
+                ));
+            }
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, expressions)),
+        }
+    }
+
+    /// `function foo() {}` -> `foo = function _foo() {}`
+    fn transform_function_declaration(&mut self, mut func: Function<'a>) -> Statement<'a> {
+        let ident = func.id.take().unwrap();
+        self.hoist_binding(&ident);
+
+        let scope_id = func.scope_id();
+        func.id = Some(
+            self.ctx
+                .generate_uid(&ident.name, scope_id, SymbolFlags::Function)
+                .create_binding_identifier(self.ctx),
+        );
+        func.r#type = FunctionType::FunctionExpression;
+        let span = func.span;
+        let target = BoundIdentifier::from_binding_ident(&ident).create_write_target(self.ctx);
+        let expression = self.ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            target,
+            Expression::FunctionExpression(self.ctx.ast.alloc(func)),
+        );
+        self.ctx.ast.statement_expression(span, expression)
+    }
+}
+
+impl<'a> VisitMut<'a> for Hoister<'a, '_> {
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        let mut functions = vec![];
+        let mut new_stmts = self.ctx.ast.vec_with_capacity(stmts.len());
+        for stmt in stmts.take_in(self.ctx.ast) {
+            match stmt {
+                Statement::VariableDeclaration(decl) => {
+                    let span = decl.span;
+                    if let Some(expression) = self.transform_declaration(decl.unbox()) {
+                        new_stmts.push(self.ctx.ast.statement_expression(span, expression));
+                    }
+                }
+                Statement::FunctionDeclaration(func) => {
+                    functions.push(self.transform_function_declaration(func.unbox()));
+                }
+                mut stmt => {
+                    self.visit_statement(&mut stmt);
+                    new_stmts.push(stmt);
+                }
+            }
+        }
+        if !functions.is_empty() {
+            new_stmts.splice(0..0, functions);
+        }
+        *stmts = new_stmts;
+    }
+
+    /// Declarations which are not in a statement list, e.g. `if (x) var y = 1;`
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        match stmt.take_in(self.ctx.ast) {
+            Statement::VariableDeclaration(decl) => {
+                let span = decl.span;
+                *stmt = match self.transform_declaration(decl.unbox()) {
+                    Some(expression) => self.ctx.ast.statement_expression(span, expression),
+                    None => self.ctx.ast.statement_empty(span),
+                };
+            }
+            Statement::FunctionDeclaration(func) => {
+                *stmt = self.transform_function_declaration(func.unbox());
+            }
+            mut other => {
+                walk_mut::walk_statement(self, &mut other);
+                *stmt = other;
+            }
+        }
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init {
+            let decl = decl.take_in(self.ctx.ast);
+            stmt.init = self.transform_declaration(decl).map(ForStatementInit::from);
+        }
+        walk_mut::walk_for_statement(self, stmt);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        self.transform_for_statement_left(&mut stmt.left);
+        walk_mut::walk_for_in_statement(self, stmt);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        self.transform_for_statement_left(&mut stmt.left);
+        walk_mut::walk_for_of_statement(self, stmt);
+    }
+
+    // Do not enter nested functions and classes, their declarations are not hoisted
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &mut ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+impl<'a> Hoister<'a, '_> {
+    /// `for (var x in obj)` -> `for (x in obj)`
+    fn transform_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        let ForStatementLeft::VariableDeclaration(decl) = left else { return };
+        let declarator = decl.declarations.pop().unwrap();
+        declarator.id.bound_names(&mut |ident| self.hoist_binding(ident));
+        *left =
+            ForStatementLeft::from(binding_pattern_to_assignment_target(declarator.id, self.ctx));
+    }
+}
@@ -0,0 +1,135 @@
+//! Detection of leaps, and targets of `break` and `continue` statements.
+//!
+//! A "leap" is anything which transfers control out of the normal flow of a statement:
+//! `yield`, `break`, `continue`, `return` and `throw`.
+//! Only statements and expressions which contain a leap need to be split into `case`s
+//! of the dispatch loop. Everything else is emitted as it is.
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_semantic::ScopeFlags;
+use oxc_span::Atom;
+
+use super::emit::Loc;
+
+/// Visitor to find leaps, excluding nested functions.
+#[derive(Default)]
+pub(super) struct LeapFinder {
+    found: bool,
+}
+
+impl LeapFinder {
+    /// Check if a node contains a leap. `visit` is called with the finder to visit the node.
+    pub(super) fn check(visit: impl FnOnce(&mut Self)) -> bool {
+        let mut finder = Self::default();
+        visit(&mut finder);
+        finder.found
+    }
+
+    pub(super) fn statement(stmt: &Statement<'_>) -> bool {
+        Self::check(|finder| finder.visit_statement(stmt))
+    }
+
+    pub(super) fn expression(expr: &Expression<'_>) -> bool {
+        Self::check(|finder| finder.visit_expression(expr))
+    }
+}
+
+impl<'a> Visit<'a> for LeapFinder {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        if !self.found {
+            walk::walk_statement(self, stmt);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if !self.found {
+            walk::walk_expression(self, expr);
+        }
+    }
+
+    fn visit_yield_expression(&mut self, _expr: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_break_statement(&mut self, _stmt: &BreakStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_continue_statement(&mut self, _stmt: &ContinueStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_return_statement(&mut self, _stmt: &ReturnStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_throw_statement(&mut self, _stmt: &ThrowStatement<'a>) {
+        self.found = true;
+    }
+
+    // Leaps in nested functions do not leave the generator function
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+}
+
+/// Statement which can be the target of `break` or `continue`.
+enum LeapEntry<'a> {
+    Loop { break_loc: Loc, continue_loc: Loc, label: Option<Atom<'a>> },
+    Switch { break_loc: Loc },
+    Labeled { break_loc: Loc, label: Atom<'a> },
+}
+
+/// Stack of statements which can be the target of `break` or `continue`.
+#[derive(Default)]
+pub(super) struct LeapManager<'a> {
+    entries: Vec<LeapEntry<'a>>,
+}
+
+impl<'a> LeapManager<'a> {
+    pub(super) fn push_loop(&mut self, break_loc: Loc, continue_loc: Loc, label: Option<Atom<'a>>) {
+        self.entries.push(LeapEntry::Loop { break_loc, continue_loc, label });
+    }
+
+    pub(super) fn push_switch(&mut self, break_loc: Loc) {
+        self.entries.push(LeapEntry::Switch { break_loc });
+    }
+
+    pub(super) fn push_labeled(&mut self, break_loc: Loc, label: Atom<'a>) {
+        self.entries.push(LeapEntry::Labeled { break_loc, label });
+    }
+
+    pub(super) fn pop(&mut self) {
+        self.entries.pop();
+    }
+
+    /// Get location which `break` or `break label` jumps to.
+    pub(super) fn break_loc(&self, label: Option<Atom<'a>>) -> Option<Loc> {
+        self.entries.iter().rev().find_map(|entry| match (entry, label) {
+            (
+                LeapEntry::Loop { break_loc, label: Some(entry_label), .. }
+                | LeapEntry::Labeled { break_loc, label: entry_label },
+                Some(label),
+            ) if *entry_label == label => Some(*break_loc),
+            // Unlabeled `break` ignores labeled statements
+            (LeapEntry::Loop { break_loc, .. } | LeapEntry::Switch { break_loc }, None) => {
+                Some(*break_loc)
+            }
+            _ => None,
+        })
+    }
+
+    /// Get location which `continue` or `continue label` jumps to.
+    pub(super) fn continue_loc(&self, label: Option<Atom<'a>>) -> Option<Loc> {
+        self.entries.iter().rev().find_map(|entry| match (entry, label) {
+            (LeapEntry::Loop { continue_loc, label: Some(entry_label), .. }, Some(label))
+                if *entry_label == label =>
+            {
+                Some(*continue_loc)
+            }
+            (LeapEntry::Loop { continue_loc, .. }, None) => Some(*continue_loc),
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,489 @@
+//! ES2015: Regenerator
+//!
+//! This plugin transforms generator functions into state machines,
+//! which run on `regeneratorRuntime` helper.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function* foo(x) {
+//!   try {
+//!     yield x;
+//!   } finally {
+//!     cleanup();
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _marked = /*#__PURE__*/babelHelpers.regeneratorRuntime().mark(foo);
+//! function foo(x) {
+//!   return babelHelpers.regeneratorRuntime().wrap(function foo$(_context) {
+//!     while (1) switch (_context.prev = _context.next) {
+//!       case 0:
+//!         _context.prev = 0;
+//!         _context.next = 3;
+//!         return x;
+//!       case 3:
+//!         _context.prev = 3;
+//!         cleanup();
+//!         return _context.finish(3);
+//!       case 6:
+//!       case "end":
+//!         return _context.stop();
+//!     }
+//!   }, _marked, null, [[0,, 3, 6]]);
+//! }
+//! ```
+//!
+//! Async functions and async generator functions are transformed to generator functions by
+//! ES2017 async-to-generator and ES2018 async-generator-functions transforms. When this plugin
+//! is enabled, those generator functions are passed through this transform too,
+//! so async code can be targeted to ES5.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-regenerator](https://babel.dev/docs/babel-plugin-transform-regenerator),
+//! which uses [regenerator-transform](https://github.com/facebook/regenerator/tree/main/packages/transform).
+//!
+//! The transform runs in 2 passes over a generator function's body:
+//!
+//! 1. [`Hoister`] hoists declarations out of the body, as the body is split into `case`s.
+//! 2. [`Emitter`] explodes statements and expressions containing leaps (`yield`, `break`, etc)
+//!    into a flat listing of statements, which is turned into a dispatch loop.
+//!
+//! The runtime is loaded as `regeneratorRuntime` helper, so it is referenced according to
+//! the [`HelperLoaderMode`] option, e.g. `babelHelpers.regeneratorRuntime()` in
+//! [`HelperLoaderMode::External`] mode, or imported from `@babel/runtime` in
+//! [`HelperLoaderMode::Runtime`] mode.
+//!
+//! Unlike Babel, generator methods are not converted to properties. They are wrapped with
+//! `null` as the outer function instead.
+//!
+//! Not supported:
+//! * `yield` in destructuring patterns, optional chains, tagged templates and `for...of` loops.
+//!   Destructuring and `for...of` loops are transformed by other ES2015 transforms,
+//!   so these are only an issue if those transforms are disabled.
+//! * `with` statements containing `yield`.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-regenerator>
+//! * Regenerator transform: <https://github.com/facebook/regenerator/tree/main/packages/transform/src>
+//! * Regenerator runtime: <https://github.com/facebook/regenerator/blob/main/packages/runtime/runtime.js>
+//!
+//! [`HelperLoaderMode`]: crate::HelperLoaderMode
+//! [`HelperLoaderMode::External`]: crate::HelperLoaderMode::External
+//! [`HelperLoaderMode::Runtime`]: crate::HelperLoaderMode::Runtime
+
+use oxc_allocator::{Box as ArenaBox, GetAddress, TakeIn, UnstableAddress};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{Atom, SPAN};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+mod emit;
+mod hoist;
+mod leap;
+
+use emit::Emitter;
+use hoist::Hoister;
+
+/// Regenerator transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct Regenerator<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Regenerator<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Regenerator<'a, '_> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::FunctionExpression(func) = expr else { return };
+        if !func.generator || func.r#async {
+            return;
+        }
+        // Object methods are transformed in `exit_function`
+        if let Ancestor::ObjectPropertyValue(prop) = ctx.parent()
+            && *prop.method()
+        {
+            return;
+        }
+
+        let Expression::FunctionExpression(func) = expr.take_in(ctx.ast) else { unreachable!() };
+        *expr = self.transform_generator_expression(func, ctx);
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !func.generator || func.r#async {
+            return;
+        }
+
+        match ctx.parent() {
+            // `class A { *foo() {} }`
+            Ancestor::MethodDefinitionValue(_) => {
+                self.transform_function(func, None, ctx);
+            }
+            // `({ *foo() {} })`
+            Ancestor::ObjectPropertyValue(prop) if *prop.method() => {
+                self.transform_function(func, None, ctx);
+            }
+            _ if func.is_declaration() => self.transform_function_declaration(func, ctx),
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Regenerator<'a, '_> {
+    /// Transform a generator function expression.
+    ///
+    /// `function* () {}` -> `regeneratorRuntime().mark(function _callee() { ... })`
+    pub fn transform_generator_expression(
+        &self,
+        mut func: ArenaBox<'a, Function<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let id = if let Some(id) = &func.id {
+            BoundIdentifier::from_binding_ident(id)
+        } else {
+            let binding = ctx.generate_uid("callee", func.scope_id(), SymbolFlags::Function);
+            func.id = Some(binding.create_binding_identifier(ctx));
+            binding
+        };
+        let outer = id.create_read_expression(ctx);
+        self.transform_function(&mut func, Some(outer), ctx);
+        self.create_mark_call(Expression::FunctionExpression(func), ctx)
+    }
+
+    /// Transform a generator function declaration.
+    ///
+    /// ```js
+    /// var _marked = regeneratorRuntime().mark(foo);
+    /// function foo() { ... }
+    /// ```
+    fn transform_function_declaration(&self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `export default function* () {}`
+        let id = if let Some(id) = &func.id {
+            BoundIdentifier::from_binding_ident(id)
+        } else {
+            let binding = ctx.generate_uid("callee", ctx.current_scope_id(), SymbolFlags::Function);
+            func.id = Some(binding.create_binding_identifier(ctx));
+            binding
+        };
+
+        let marked = ctx.generate_uid_in_current_hoist_scope("marked");
+        let mark = self.create_mark_call(id.create_read_expression(ctx), ctx);
+        if ctx.current_scope_id() == ctx.current_hoist_scope_id() {
+            self.ctx.var_declarations.insert_var_with_init(&marked, mark, ctx);
+        } else {
+            // Function declaration in a block.
+            // Insert `var _marked = ...` before it, as `var_declarations` inserts at top of function.
+            let address = match ctx.parent() {
+                Ancestor::ExportNamedDeclarationDeclaration(decl) => decl.address(),
+                Ancestor::ExportDefaultDeclarationDeclaration(decl) => decl.address(),
+                _ => func.unstable_address(),
+            };
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                marked.create_binding_pattern(ctx),
+                NONE,
+                Some(mark),
+                false,
+            );
+            let declaration = ctx.ast.declaration_variable(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            );
+            self.ctx.statement_injector.insert_before(&address, Statement::from(declaration));
+        }
+
+        let outer = marked.create_read_expression(ctx);
+        self.transform_function(func, Some(outer), ctx);
+    }
+
+    /// `/*#__PURE__*/ regeneratorRuntime().mark(func)`
+    fn create_mark_call(&self, func: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let callee = runtime_property(self.ctx, "mark", ctx);
+        ctx.ast.expression_call_with_pure(
+            SPAN,
+            callee,
+            NONE,
+            ctx.ast.vec1(Argument::from(func)),
+            false,
+            true,
+        )
+    }
+
+    /// Replace body of generator function with a call to `regeneratorRuntime().wrap`,
+    /// and turn it into a normal function.
+    ///
+    /// ```js
+    /// function foo() {
+    ///   var a, b;
+    ///   return regeneratorRuntime().wrap(function foo$(_context) {
+    ///     while (1) switch (_context.prev = _context.next) { ... }
+    ///   }, outer, this, tryLocsList);
+    /// }
+    /// ```
+    fn transform_function(
+        &self,
+        func: &mut Function<'a>,
+        outer: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let function_scope_id = func.scope_id();
+        let mut vars = Hoister::hoist(func, ctx);
+
+        // Replace `arguments` with `_args`, as `arguments` in the inner function is different
+        let mut usage = ThisArgumentsReplacer::new(function_scope_id, ctx);
+        usage.visit_function_body(func.body.as_mut().unwrap());
+        let ThisArgumentsReplacer { uses_this, arguments_binding, .. } = usage;
+        if let Some(arguments_binding) = arguments_binding {
+            let symbol_id = ctx.scoping().find_binding(function_scope_id, "arguments");
+            let init = ctx.create_ident_expr(
+                SPAN,
+                Atom::from("arguments"),
+                symbol_id,
+                oxc_semantic::ReferenceFlags::Read,
+            );
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                arguments_binding.create_binding_pattern(ctx),
+                NONE,
+                Some(init),
+                false,
+            );
+            vars.get_or_insert_with(|| {
+                ctx.ast.variable_declaration(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    ctx.ast.vec(),
+                    false,
+                )
+            })
+            .declarations
+            .push(declarator);
+        }
+
+        // Create inner function: `function foo$(_context) { while (1) switch (...) { ... } }`
+        let inner_scope_id = ctx.create_child_scope(function_scope_id, ScopeFlags::Function);
+        let switch_scope_id = ctx.create_child_scope(inner_scope_id, ScopeFlags::empty());
+        let context =
+            ctx.generate_uid("context", inner_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        let body = func.body.as_mut().unwrap();
+        let mut emitter = Emitter::new(context.clone(), switch_scope_id, self.ctx);
+        emitter.explode_statements(body.statements.take_in(ctx.ast), ctx);
+        let (dispatch_loop, try_locs) = emitter.into_dispatch_loop(ctx);
+
+        // Scopes of statements in the body are now inside the `switch` statement
+        ScopeParentSetter::new(switch_scope_id, ctx).visit_statement(&dispatch_loop);
+
+        let inner_name = match &func.id {
+            Some(id) => ctx.ast.atom_from_strs_array([id.name.as_str(), "$"]),
+            None => ctx.generate_uid_name("callee$"),
+        };
+        let inner_id = ctx.generate_binding(inner_name, inner_scope_id, SymbolFlags::Function);
+        let params =
+            ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, context.create_binding_pattern(ctx)));
+        let params =
+            ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, params, NONE);
+        let inner_body = ctx.ast.function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(dispatch_loop));
+        let inner = ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            Some(inner_id.create_binding_identifier(ctx)),
+            params,
+            inner_body,
+            inner_scope_id,
+        );
+
+        // `regeneratorRuntime().wrap(inner, outer, this, tryLocsList)`
+        let mut arguments = ctx.ast.vec_with_capacity(4);
+        arguments.push(Argument::FunctionExpression(inner));
+        arguments
+            .push(Argument::from(outer.unwrap_or_else(|| ctx.ast.expression_null_literal(SPAN))));
+        if uses_this {
+            arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        } else if try_locs.is_some() {
+            arguments.push(Argument::from(ctx.ast.expression_null_literal(SPAN)));
+        }
+        if let Some(try_locs) = try_locs {
+            arguments.push(Argument::from(try_locs));
+        }
+        let wrap = runtime_property(self.ctx, "wrap", ctx);
+        let wrap = ctx.ast.expression_call(SPAN, wrap, NONE, arguments, false);
+
+        let body = func.body.as_mut().unwrap();
+        if let Some(vars) = vars {
+            body.statements.push(Statement::VariableDeclaration(ctx.ast.alloc(vars)));
+        }
+        body.statements.push(ctx.ast.statement_return(SPAN, Some(wrap)));
+        func.generator = false;
+    }
+}
+
+/// `regeneratorRuntime().name`
+fn runtime_property<'a>(
+    transform_ctx: &TransformCtx<'a>,
+    name: &'a str,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let runtime =
+        transform_ctx.helper_call_expr(Helper::RegeneratorRuntime, SPAN, ctx.ast.vec(), ctx);
+    Expression::from(ctx.ast.member_expression_static(
+        SPAN,
+        runtime,
+        ctx.ast.identifier_name(SPAN, name),
+        false,
+    ))
+}
+
+/// Visitor to find usage of `this`, and replace `arguments` with `_args`.
+///
+/// Descends into arrow functions, but not into other functions,
+/// as they have their own `this` and `arguments`.
+struct ThisArgumentsReplacer<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+    function_scope_id: ScopeId,
+    uses_this: bool,
+    arguments_binding: Option<BoundIdentifier<'a>>,
+}
+
+impl<'a, 'c> ThisArgumentsReplacer<'a, 'c> {
+    fn new(function_scope_id: ScopeId, ctx: &'c mut TraverseCtx<'a>) -> Self {
+        Self { ctx, function_scope_id, uses_this: false, arguments_binding: None }
+    }
+}
+
+impl<'a> VisitMut<'a> for ThisArgumentsReplacer<'a, '_> {
+    fn visit_this_expression(&mut self, _expr: &mut ThisExpression) {
+        self.uses_this = true;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if ident.name != "arguments" {
+            return;
+        }
+        let reference = self.ctx.scoping().get_reference(ident.reference_id());
+        if reference.symbol_id().is_some() {
+            return;
+        }
+        let flags = reference.flags();
+        self.ctx.delete_reference_for_identifier(ident);
+        let function_scope_id = self.function_scope_id;
+        let binding = self.arguments_binding.get_or_insert_with(|| {
+            self.ctx.generate_uid("args", function_scope_id, SymbolFlags::FunctionScopedVariable)
+        });
+        *ident = binding.create_reference(flags, self.ctx);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        // `this` in class body refers to the class or its instance,
+        // but `extends` clause and computed keys are evaluated in the outer scope
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in &mut class.body.body {
+            match element {
+                ClassElement::MethodDefinition(method) if method.computed => {
+                    self.visit_property_key(&mut method.key);
+                }
+                ClassElement::PropertyDefinition(prop) if prop.computed => {
+                    self.visit_property_key(&mut prop.key);
+                }
+                ClassElement::AccessorProperty(prop) if prop.computed => {
+                    self.visit_property_key(&mut prop.key);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        walk_mut::walk_arrow_function_expression(self, arrow);
+    }
+}
+
+/// Visitor to set parent of scopes which are directly in the dispatch loop to the `switch` scope.
+///
+/// Also moves bindings of class declarations into the `switch` scope,
+/// as their block scopes have been removed.
+struct ScopeParentSetter<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+    switch_scope_id: ScopeId,
+    /// Depth of nested scopes, below the `switch` scope
+    depth: usize,
+}
+
+impl<'a, 'c> ScopeParentSetter<'a, 'c> {
+    fn new(switch_scope_id: ScopeId, ctx: &'c mut TraverseCtx<'a>) -> Self {
+        Self { ctx, switch_scope_id, depth: 0 }
+    }
+}
+
+impl<'a> Visit<'a> for ScopeParentSetter<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &std::cell::Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            let scope_id = scope_id.get().unwrap();
+            self.ctx.scoping_mut().change_scope_parent_id(scope_id, Some(self.switch_scope_id));
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &SwitchStatement<'a>) {
+        if stmt.scope_id() == self.switch_scope_id {
+            // Do not count the dispatch `switch` as a nested scope
+            self.visit_expression(&stmt.discriminant);
+            for case in &stmt.cases {
+                self.visit_switch_case(case);
+            }
+        } else {
+            walk::walk_switch_statement(self, stmt);
+        }
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if self.depth == 0
+            && class.is_declaration()
+            && let Some(id) = &class.id
+        {
+            let symbol_id = id.symbol_id();
+            let scoping = self.ctx.scoping_mut();
+            let scope_id = scoping.symbol_scope_id(symbol_id);
+            if scope_id != self.switch_scope_id {
+                scoping.move_binding(scope_id, self.switch_scope_id, &id.name);
+                scoping.set_symbol_scope_id(symbol_id, self.switch_scope_id);
+            }
+        }
+        walk::walk_class(self, class);
+    }
+}
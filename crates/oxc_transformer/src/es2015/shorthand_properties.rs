@@ -0,0 +1,93 @@
+//! ES2015: Shorthand Properties
+//!
+//! This plugin transforms shorthand properties and methods in object literals.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var o = { a, b() {} };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var o = { a: a, b: function() {} };
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-shorthand-properties](https://babel.dev/docs/babel-plugin-transform-shorthand-properties).
+//!
+//! Methods which contain `super` are left as they are, because `super` is only valid in methods.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-shorthand-properties>
+//! * Object initializer specification: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_semantic::ScopeFlags;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+/// Shorthand properties transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct ShorthandProperties;
+
+impl ShorthandProperties {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ShorthandProperties {
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, _ctx: &mut TraverseCtx<'a>) {
+        // `{ a }` -> `{ a: a }`
+        prop.shorthand = false;
+
+        // `{ b() {} }` -> `{ b: function() {} }`
+        if prop.method
+            && let Expression::FunctionExpression(func) = &prop.value
+            && !SuperFinder::contains_super(func)
+        {
+            prop.method = false;
+        }
+    }
+}
+
+/// Visitor to find `super` in a method, excluding nested methods and classes.
+#[derive(Default)]
+struct SuperFinder {
+    found: bool,
+}
+
+impl SuperFinder {
+    fn contains_super(func: &Function<'_>) -> bool {
+        let mut finder = Self::default();
+        walk::walk_function(&mut finder, func, ScopeFlags::Function);
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for SuperFinder {
+    fn visit_super(&mut self, _sup: &Super) {
+        self.found = true;
+    }
+
+    // `super` is only valid in nested methods, where it refers to home object of those methods
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        // `super` in a nested class refers to that class. Only check `extends` clause.
+        if let Some(super_class) = &class.super_class {
+            self.visit_expression(super_class);
+        }
+    }
+}
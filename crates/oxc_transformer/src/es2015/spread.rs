@@ -0,0 +1,344 @@
+//! ES2015: Spread
+//!
+//! This plugin transforms spread elements in array literals, function calls and `new` expressions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! [a, ...b, c];
+//! f(...args);
+//! obj.method(a, ...args);
+//! new Foo(...args);
+//! ```
+//!
+//! Output:
+//! ```js
+//! [a].concat(babelHelpers.toConsumableArray(b), [c]);
+//! f.apply(void 0, babelHelpers.toConsumableArray(args));
+//! obj.method.apply(obj, [a].concat(babelHelpers.toConsumableArray(args)));
+//! babelHelpers.construct(Foo, babelHelpers.toConsumableArray(args));
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! This option can also be enabled with `CompilerAssumptions::iterable_is_array`.
+//!
+//! When `true`, spread values are assumed to be arrays, and are passed to `concat` / `apply`
+//! directly, instead of being converted with `toConsumableArray` helper.
+//!
+//! ### `allowArrayLike`
+//!
+//! This option can also be enabled with `CompilerAssumptions::array_like_is_iterable`.
+//!
+//! When `true`, array-like objects (objects with a `length` property, which are not iterable)
+//! can be spread. The helper call is wrapped in `maybeArrayLike` helper.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-spread](https://babel.dev/docs/babel-plugin-transform-spread).
+//!
+//! Calls with `super` as callee (`super(...args)`) are not transformed here.
+//! Classes transform lowers them, and calls [`Spread::transform_array_expression`] on the
+//! resulting arguments array.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-spread>
+//! * Spread syntax specification: <https://tc39.es/ecma262/#prod-SpreadElement>
+
+use serde::Deserialize;
+
+use oxc_allocator::{Box as ArenaBox, TakeIn};
+use oxc_ast::{NONE, ast::*};
+use oxc_span::SPAN;
+use oxc_syntax::reference::ReferenceFlags;
+use oxc_traverse::Traverse;
+
+use crate::{
+    ArenaVec,
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SpreadOptions {
+    pub loose: bool,
+
+    pub allow_array_like: bool,
+}
+
+/// Spread transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+#[derive(Clone, Copy)]
+pub struct Spread<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// If `true`, use spread values directly, instead of converting them with `toConsumableArray` helper.
+    iterable_is_array: bool,
+    /// If `true`, wrap `toConsumableArray` helper calls in `maybeArrayLike` helper.
+    array_like_is_iterable: bool,
+}
+
+impl<'a, 'ctx> Spread<'a, 'ctx> {
+    pub fn new(options: SpreadOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            iterable_is_array: options.loose || ctx.assumptions.iterable_is_array,
+            array_like_is_iterable: options.allow_array_like
+                || ctx.assumptions.array_like_is_iterable,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Spread<'a, '_> {
+    // `#[inline]` for fast exit for expressions which are not any of the transformed types
+    #[inline]
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            // `[a, ...b]`
+            Expression::ArrayExpression(array)
+                if array.elements.iter().any(ArrayExpressionElement::is_spread) =>
+            {
+                let Expression::ArrayExpression(array) = expr.take_in(ctx.ast) else {
+                    unreachable!()
+                };
+                *expr = self.transform_array_expression(array, ctx);
+            }
+            // `f(...args)`
+            Expression::CallExpression(call)
+                if !call.optional
+                    && !call.callee.is_super()
+                    && call.arguments.iter().any(Argument::is_spread) =>
+            {
+                self.transform_call_expression(call, ctx);
+            }
+            // `new Foo(...args)`
+            Expression::NewExpression(new_expr)
+                if new_expr.arguments.iter().any(Argument::is_spread) =>
+            {
+                self.transform_new_expression(expr, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Spread<'a, '_> {
+    /// Transform array expression containing spread elements.
+    ///
+    /// * `[...a]` -> `_toConsumableArray(a)`
+    /// * `[a, ...b, c]` -> `[a].concat(_toConsumableArray(b), [c])`
+    /// * `[...a, b]` -> `[].concat(_toConsumableArray(a), [b])`
+    pub fn transform_array_expression(
+        &self,
+        array: ArenaBox<'a, ArrayExpression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let ArrayExpression { span, elements } = array.unbox();
+        let mut parts = self.build_parts(elements.into_iter(), ctx);
+
+        // `[...a]` -> `_toConsumableArray(a)`.
+        // Only if result is a new array. `a` itself cannot be returned in loose mode.
+        if parts.len() == 1 && parts[0].is_new_array {
+            return parts.pop().unwrap().expression;
+        }
+
+        // Concat to first part if it's an array literal, otherwise to an empty array
+        let first = if parts.first().is_some_and(|part| part.is_array_literal) {
+            parts.remove(0).expression
+        } else {
+            ctx.ast.expression_array(SPAN, ctx.ast.vec())
+        };
+        Self::create_concat_call(span, first, parts, ctx)
+    }
+
+    /// Transform call expression with spread arguments.
+    ///
+    /// * `f(...args)` -> `f.apply(void 0, _toConsumableArray(args))`
+    /// * `obj.f(a, ...args)` -> `obj.f.apply(obj, [a].concat(_toConsumableArray(args)))`
+    /// * `obj().f(...args)` -> `(_obj = obj()).f.apply(_obj, _toConsumableArray(args))`
+    fn transform_call_expression(&self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let arguments = self.transform_arguments(call.arguments.take_in(ctx.ast), ctx);
+
+        let this = match &mut call.callee {
+            Expression::StaticMemberExpression(member) => {
+                self.create_this_argument(&mut member.object, ctx)
+            }
+            Expression::ComputedMemberExpression(member) => {
+                self.create_this_argument(&mut member.object, ctx)
+            }
+            Expression::PrivateFieldExpression(member) => {
+                self.create_this_argument(&mut member.object, ctx)
+            }
+            _ => ctx.ast.void_0(SPAN),
+        };
+
+        let callee = call.callee.take_in(ctx.ast);
+        let property = ctx.ast.identifier_name(SPAN, "apply");
+        call.callee =
+            Expression::from(ctx.ast.member_expression_static(SPAN, callee, property, false));
+        call.arguments = ctx.ast.vec_from_array([Argument::from(this), Argument::from(arguments)]);
+    }
+
+    /// Transform `new` expression with spread arguments.
+    ///
+    /// `new Foo(...args)` -> `_construct(Foo, _toConsumableArray(args))`
+    fn transform_new_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::NewExpression(new_expr) = expr.take_in(ctx.ast) else { unreachable!() };
+        let NewExpression { span, callee, arguments, .. } = new_expr.unbox();
+        let arguments = self.transform_arguments(arguments, ctx);
+        let arguments = ctx.ast.vec_from_array([Argument::from(callee), Argument::from(arguments)]);
+        *expr = self.ctx.helper_call_expr(Helper::Construct, span, arguments, ctx);
+    }
+
+    /// Convert arguments containing spread to a single array expression.
+    ///
+    /// * `(...a)` -> `_toConsumableArray(a)`
+    /// * `(a, ...b)` -> `[a].concat(_toConsumableArray(b))`
+    fn transform_arguments(
+        &self,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let elements = arguments.into_iter().map(ArrayExpressionElement::from);
+        let mut parts = self.build_parts(elements, ctx);
+        // Arguments are not mutated by the callee, so no need to copy a single part
+        let first = parts.remove(0).expression;
+        if parts.is_empty() { first } else { Self::create_concat_call(SPAN, first, parts, ctx) }
+    }
+
+    /// Get `this` argument for `apply` call from callee's object.
+    ///
+    /// * `super.f(...args)` -> `this`
+    /// * `obj.f(...args)` -> `obj`
+    /// * `obj().f(...args)` -> `_obj`, and callee's object becomes `_obj = obj()`
+    fn create_this_argument(
+        &self,
+        object: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if object.is_super() {
+            return ctx.ast.expression_this(SPAN);
+        }
+        let (assignment, reference) =
+            self.ctx.duplicate_expression(object.take_in(ctx.ast), true, ctx);
+        *object = assignment;
+        reference
+    }
+
+    /// Split elements into parts to be concatenated.
+    ///
+    /// Runs of elements which are not spread are collected into array literals.
+    /// Spread elements are converted to arrays.
+    fn build_parts(
+        &self,
+        elements: impl Iterator<Item = ArrayExpressionElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Part<'a>> {
+        let mut parts = vec![];
+        let mut run = ctx.ast.vec();
+        for element in elements {
+            if let ArrayExpressionElement::SpreadElement(spread) = element {
+                if !run.is_empty() {
+                    parts.push(Part::array_literal(ctx.ast.expression_array(SPAN, run)));
+                    run = ctx.ast.vec();
+                }
+                parts.push(self.create_spread_part(spread.unbox().argument, ctx));
+            } else {
+                run.push(element);
+            }
+        }
+        if !run.is_empty() {
+            parts.push(Part::array_literal(ctx.ast.expression_array(SPAN, run)));
+        }
+        parts
+    }
+
+    /// Convert argument of a spread element to an array.
+    ///
+    /// * `...a` -> `_toConsumableArray(a)`
+    /// * `...arguments` -> `Array.prototype.slice.call(arguments)`
+    /// * `...[a, b]` -> `[a, b]`
+    /// * `...a` -> `a` (`iterable_is_array`)
+    /// * `...a` -> `_maybeArrayLike(_toConsumableArray, a)` (`array_like_is_iterable`)
+    fn create_spread_part(&self, argument: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Part<'a> {
+        let is_arguments =
+            matches!(&argument, Expression::Identifier(ident) if ident.name == "arguments");
+        if (self.iterable_is_array && !is_arguments)
+            || matches!(argument, Expression::ArrayExpression(_))
+        {
+            return Part { expression: argument, is_new_array: false, is_array_literal: false };
+        }
+
+        let expression = if is_arguments {
+            // `Array.prototype.slice.call(arguments)`
+            let array =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("Array"), ReferenceFlags::Read);
+            let prototype = ctx.ast.identifier_name(SPAN, "prototype");
+            let prototype =
+                Expression::from(ctx.ast.member_expression_static(SPAN, array, prototype, false));
+            let slice = ctx.ast.identifier_name(SPAN, "slice");
+            let slice =
+                Expression::from(ctx.ast.member_expression_static(SPAN, prototype, slice, false));
+            let call = ctx.ast.identifier_name(SPAN, "call");
+            let callee =
+                Expression::from(ctx.ast.member_expression_static(SPAN, slice, call, false));
+            ctx.ast.expression_call(
+                SPAN,
+                callee,
+                NONE,
+                ctx.ast.vec1(Argument::from(argument)),
+                false,
+            )
+        } else if self.array_like_is_iterable {
+            let helper = self.ctx.helper_load(Helper::ToConsumableArray, ctx);
+            let arguments =
+                ctx.ast.vec_from_array([Argument::from(helper), Argument::from(argument)]);
+            self.ctx.helper_call_expr(Helper::MaybeArrayLike, SPAN, arguments, ctx)
+        } else {
+            let arguments = ctx.ast.vec1(Argument::from(argument));
+            self.ctx.helper_call_expr(Helper::ToConsumableArray, SPAN, arguments, ctx)
+        };
+        Part { expression, is_new_array: true, is_array_literal: false }
+    }
+
+    /// `first.concat(part1, part2)`
+    fn create_concat_call(
+        span: Span,
+        first: Expression<'a>,
+        parts: Vec<Part<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let property = ctx.ast.identifier_name(SPAN, "concat");
+        let callee =
+            Expression::from(ctx.ast.member_expression_static(SPAN, first, property, false));
+        let arguments =
+            ctx.ast.vec_from_iter(parts.into_iter().map(|part| Argument::from(part.expression)));
+        ctx.ast.expression_call(span, callee, NONE, arguments, false)
+    }
+}
+
+/// A part of an array being built from spread elements.
+struct Part<'a> {
+    expression: Expression<'a>,
+    /// `true` if `expression` evaluates to a newly created array (e.g. result of a helper call)
+    is_new_array: bool,
+    /// `true` if `expression` is an array literal containing elements which were not spread
+    is_array_literal: bool,
+}
+
+impl<'a> Part<'a> {
+    fn array_literal(expression: Expression<'a>) -> Self {
+        Self { expression, is_new_array: true, is_array_literal: true }
+    }
+}
@@ -0,0 +1,296 @@
+//! ES2015: Template Literals
+//!
+//! This plugin transforms template literals and tagged template expressions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! `foo${bar}baz${qux}`;
+//! tag`foo${bar}`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _templateObject;
+//! "foo".concat(bar, "baz").concat(qux);
+//! tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", ""])), bar);
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! This option can also be enabled with `CompilerAssumptions::ignore_to_primitive_hint`
+//! and `CompilerAssumptions::mutable_template_object`.
+//!
+//! When `true`, template literals are concatenated with `+` operator instead of `String.prototype.concat`:
+//! `"foo" + bar + "baz" + qux`.
+//!
+//! Template objects passed to tags are created with `taggedTemplateLiteralLoose` helper,
+//! which does not freeze them.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-template-literals](https://babel.dev/docs/babel-plugin-transform-template-literals).
+//!
+//! Template objects are cached in a top-level `var`, so that evaluating the same tagged template
+//! multiple times passes the same template object to the tag, as the specification requires.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-template-literals>
+//! * Template literals specification: <https://tc39.es/ecma262/#sec-template-literals>
+
+use std::iter;
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::SymbolFlags;
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TemplateLiteralsOptions {
+    pub loose: bool,
+}
+
+/// Template literals transform.
+///
+/// See [module docs] for details.
+///
+/// [module docs]: self
+pub struct TemplateLiterals<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// If `true`, concatenate with `+` instead of calling `concat`.
+    ignore_to_primitive_hint: bool,
+    /// If `true`, create template objects with `taggedTemplateLiteralLoose` helper.
+    mutable_template_object: bool,
+}
+
+impl<'a, 'ctx> TemplateLiterals<'a, 'ctx> {
+    pub fn new(options: TemplateLiteralsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            ignore_to_primitive_hint: options.loose || ctx.assumptions.ignore_to_primitive_hint,
+            mutable_template_object: options.loose || ctx.assumptions.mutable_template_object,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for TemplateLiterals<'a, '_> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::TemplateLiteral(_) => {
+                let Expression::TemplateLiteral(template) = expr.take_in(ctx.ast) else {
+                    unreachable!()
+                };
+                *expr = self.transform_template_literal(template.unbox(), ctx);
+            }
+            Expression::TaggedTemplateExpression(_) => {
+                let Expression::TaggedTemplateExpression(tagged) = expr.take_in(ctx.ast) else {
+                    unreachable!()
+                };
+                *expr = self.transform_tagged_template(tagged.unbox(), ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> TemplateLiterals<'a, '_> {
+    /// Transform a template literal.
+    ///
+    /// * `` `a${b}c${d}` `` -> `"a".concat(b, "c").concat(d)`
+    /// * `` `a${b}c${d}` `` -> `"a" + b + "c" + d` (`ignore_to_primitive_hint`)
+    fn transform_template_literal(
+        &self,
+        template: TemplateLiteral<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let TemplateLiteral { span, quasis, expressions } = template;
+
+        // Collect non-empty strings and expressions, in source order
+        let mut nodes = Vec::with_capacity(quasis.len() + expressions.len());
+        let mut expressions = expressions.into_iter();
+        for quasi in &quasis {
+            // Template literals which are not tagged cannot contain invalid escapes
+            let cooked = quasi.value.cooked.unwrap_or(quasi.value.raw);
+            if !cooked.is_empty() {
+                nodes.push(ctx.ast.expression_string_literal(SPAN, cooked, None));
+            }
+            if let Some(expression) = expressions.next()
+                && !expression.is_specific_string_literal("")
+            {
+                nodes.push(expression);
+            }
+        }
+
+        // Ensure result is a string, as `+` is left-associative
+        let is_string =
+            |node: Option<&Expression<'a>>| matches!(node, Some(Expression::StringLiteral(_)));
+        let starts_with_string =
+            is_string(nodes.first()) || (self.ignore_to_primitive_hint && is_string(nodes.get(1)));
+        if !starts_with_string {
+            nodes.insert(0, ctx.ast.expression_string_literal(SPAN, "", None));
+        }
+
+        let mut nodes = nodes.into_iter();
+        let mut root = nodes.next().unwrap();
+        if self.ignore_to_primitive_hint {
+            for node in nodes {
+                root = ctx.ast.expression_binary(SPAN, root, BinaryOperator::Addition, node);
+            }
+        } else {
+            root = Self::build_concat_calls(root, nodes, ctx);
+        }
+
+        if let Expression::StringLiteral(string) = &mut root {
+            string.span = span;
+        }
+        root
+    }
+
+    /// Build `concat` calls.
+    ///
+    /// Literals are appended to arguments of the previous `concat` call, and so is the first
+    /// expression, as it is converted to a string after the first string.
+    /// All other expressions start a new `concat` call, so they are converted to strings
+    /// in the right order.
+    fn build_concat_calls(
+        first: Expression<'a>,
+        rest: impl Iterator<Item = Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut root = first;
+        let mut first_expression = true;
+        for node in rest {
+            let mut can_be_inserted = node.is_literal() || node.is_string_literal();
+            if !can_be_inserted && first_expression {
+                can_be_inserted = true;
+                first_expression = false;
+            }
+            if can_be_inserted && let Expression::CallExpression(call) = &mut root {
+                call.arguments.push(Argument::from(node));
+                continue;
+            }
+            let property = ctx.ast.identifier_name(SPAN, "concat");
+            let callee =
+                Expression::from(ctx.ast.member_expression_static(SPAN, root, property, false));
+            root = ctx.ast.expression_call(
+                SPAN,
+                callee,
+                NONE,
+                ctx.ast.vec1(Argument::from(node)),
+                false,
+            );
+        }
+        root
+    }
+
+    /// Transform a tagged template expression.
+    ///
+    /// `` tag`a${b}` ``
+    /// ->
+    /// `tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["a", ""])), b)`
+    fn transform_tagged_template(
+        &self,
+        tagged: TaggedTemplateExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let TaggedTemplateExpression { span, tag, quasi, type_arguments } = tagged;
+        let TemplateLiteral { quasis, expressions, .. } = quasi;
+
+        let needs_raw_array = quasis.iter().any(|quasi| match &quasi.value.cooked {
+            Some(cooked) => cooked.as_str() != quasi.value.raw.as_str(),
+            None => true,
+        });
+
+        // `["a", ""]`, with `void 0` for strings with invalid escapes
+        let cooked_elements = ctx.ast.vec_from_iter(quasis.iter().map(|quasi| {
+            let expr = match quasi.value.cooked {
+                Some(cooked) => ctx.ast.expression_string_literal(SPAN, cooked, None),
+                None => ctx.ast.void_0(SPAN),
+            };
+            ArrayExpressionElement::from(expr)
+        }));
+        let cooked_argument = Argument::from(ctx.ast.expression_array(SPAN, cooked_elements));
+
+        // `["a\\n", ""]`, only if any raw string differs from the cooked one
+        let raw_argument = needs_raw_array.then(|| {
+            let elements = ctx.ast.vec_from_iter(quasis.iter().map(|quasi| {
+                let string = ctx.ast.expression_string_literal(SPAN, quasi.value.raw, None);
+                ArrayExpressionElement::from(string)
+            }));
+            Argument::from(ctx.ast.expression_array(SPAN, elements))
+        });
+
+        let helper = if self.mutable_template_object {
+            Helper::TaggedTemplateLiteralLoose
+        } else {
+            Helper::TaggedTemplateLiteral
+        };
+        let template_arguments =
+            ctx.ast.vec_from_iter(iter::once(cooked_argument).chain(raw_argument));
+        let template_object = self.ctx.helper_call_expr(helper, SPAN, template_arguments, ctx);
+
+        // `_templateObject || (_templateObject = template_object)`
+        let binding = self.create_top_level_binding(ctx);
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            template_object,
+        );
+        let template_object = ctx.ast.expression_logical(
+            SPAN,
+            binding.create_read_expression(ctx),
+            LogicalOperator::Or,
+            assignment,
+        );
+
+        let arguments: ArenaVec<'a, Argument<'a>> = ctx.ast.vec_from_iter(
+            iter::once(Argument::from(template_object))
+                .chain(expressions.into_iter().map(Argument::from)),
+        );
+        ctx.ast.expression_call(span, tag, type_arguments, arguments, false)
+    }
+
+    /// Create `var _templateObject;` at top level of the program.
+    fn create_top_level_binding(&self, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        let binding = ctx.generate_uid(
+            "templateObject",
+            ctx.scoping().root_scope_id(),
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let kind = VariableDeclarationKind::Var;
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            binding.create_binding_pattern(ctx),
+            NONE,
+            None,
+            false,
+        );
+        let stmt = Statement::from(ctx.ast.declaration_variable(
+            SPAN,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        ));
+        self.ctx.top_level_statements.insert_statement(stmt);
+        binding
+    }
+}
@@ -67,6 +67,7 @@ use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
 use crate::{
     common::helper_loader::Helper,
     context::{TransformCtx, TraverseCtx},
+    es2015::Regenerator,
     state::TransformState,
 };
 
@@ -76,8 +77,11 @@ pub struct AsyncToGenerator<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> AsyncToGenerator<'a, 'ctx> {
-    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { ctx, executor: AsyncGeneratorExecutor::new(Helper::AsyncToGenerator, ctx) }
+    pub fn new(regenerator: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            executor: AsyncGeneratorExecutor::new(Helper::AsyncToGenerator, regenerator, ctx),
+        }
     }
 }
 
@@ -188,12 +192,14 @@ impl<'a> AsyncToGenerator<'a, '_> {
 
 pub struct AsyncGeneratorExecutor<'a, 'ctx> {
     helper: Helper,
+    /// Regenerator transform, to lower the generator functions created by this executor
+    regenerator: Option<Regenerator<'a, 'ctx>>,
     ctx: &'ctx TransformCtx<'a>,
 }
 
 impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
-    pub fn new(helper: Helper, ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { helper, ctx }
+    pub fn new(helper: Helper, regenerator: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { helper, regenerator: regenerator.then(|| Regenerator::new(ctx)), ctx }
     }
 
     /// Transforms async method definitions to generator functions wrapped in asyncToGenerator.
@@ -684,6 +690,11 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
     ///    BODY
     /// });
     /// ```
+    ///
+    /// If regenerator transform is enabled, the generator function is transformed by it:
+    /// ```js
+    /// asyncToGenerator(regeneratorRuntime().mark(function _callee(PARAMS) { ... }));
+    /// ```
     fn create_async_to_generator_call(
         &self,
         params: ArenaBox<'a, FormalParameters<'a>>,
@@ -693,7 +704,11 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
     ) -> Expression<'a> {
         let mut function = Self::create_function(None, params, body, scope_id, ctx);
         function.generator = true;
-        let arguments = ctx.ast.vec1(Argument::FunctionExpression(function));
+        let function = match &self.regenerator {
+            Some(regenerator) => regenerator.transform_generator_expression(function, ctx),
+            None => Expression::FunctionExpression(function),
+        };
+        let arguments = ctx.ast.vec1(Argument::from(function));
         self.ctx.helper_call_expr(self.helper, SPAN, arguments, ctx)
     }
 
@@ -84,8 +84,11 @@ pub struct AsyncGeneratorFunctions<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> AsyncGeneratorFunctions<'a, 'ctx> {
-    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { ctx, executor: AsyncGeneratorExecutor::new(Helper::WrapAsyncGenerator, ctx) }
+    pub fn new(regenerator: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            executor: AsyncGeneratorExecutor::new(Helper::WrapAsyncGenerator, regenerator, ctx),
+        }
     }
 }
 
@@ -23,13 +23,13 @@ pub struct ES2018<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> ES2018<'a, 'ctx> {
-    pub fn new(options: ES2018Options, ctx: &'ctx TransformCtx<'a>) -> Self {
+    pub fn new(options: ES2018Options, regenerator: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             object_rest_spread: ObjectRestSpread::new(
                 options.object_rest_spread.unwrap_or_default(),
                 ctx,
             ),
-            async_generator_functions: AsyncGeneratorFunctions::new(ctx),
+            async_generator_functions: AsyncGeneratorFunctions::new(regenerator, ctx),
             options,
         }
     }
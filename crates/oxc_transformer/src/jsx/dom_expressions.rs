@@ -0,0 +1,1527 @@
+//! Solid / dom-expressions JSX
+//!
+//! This plugin compiles JSX with the template cloning model used by
+//! [babel-plugin-jsx-dom-expressions](https://github.com/ryansolid/dom-expressions/tree/main/packages/babel-plugin-jsx-dom-expressions),
+//! which is what `babel-preset-solid` is built on.
+//!
+//! Native elements are compiled to static HTML templates, which are hoisted to the top of the file.
+//! Only the dynamic parts of an element are updated by code: children are inserted with `insert`,
+//! reactive attributes are wrapped in `effect`, and common events are delegated to the document.
+//! Components are created with `createComponent`, with dynamic props turned into getters so that
+//! they are only read when the component accesses them.
+//!
+//! Has two modes which create different output:
+//! 1. DOM
+//! 2. SSR
+//!
+//! ## Example
+//!
+//! Input:
+//! ```jsx
+//! <div class="box" onClick={select}>Hello {name()}<span>!</span></div>;
+//! <Greeting name={user.name} />;
+//! ```
+//!
+//! Output:
+//! ```js
+//! // DOM
+//! import { template as _template, delegateEvents as _delegateEvents, insert as _insert, createComponent as _createComponent } from "solid-js/web";
+//! var _tmpl = /*#__PURE__*/ _template("<div class=\"box\">Hello <span>!</span></div>");
+//! (() => {
+//!     var _el = _tmpl(), _el2 = _el.firstChild.nextSibling;
+//!     _el.$$click = select;
+//!     _insert(_el, name, _el2);
+//!     return _el;
+//! })();
+//! _createComponent(Greeting, { get name() { return user.name; } });
+//! _delegateEvents(["click"]);
+//! ```
+//!
+//! ```js
+//! // SSR
+//! import { ssr as _ssr, escape as _escape, createComponent as _createComponent } from "solid-js/web";
+//! var _tmpl = ["<div class=\"box\">Hello ", "<span>!</span></div>"];
+//! _ssr(_tmpl, _escape(name()));
+//! _createComponent(Greeting, { get name() { return user.name; } });
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [babel-plugin-jsx-dom-expressions](https://github.com/ryansolid/dom-expressions/tree/main/packages/babel-plugin-jsx-dom-expressions).
+//!
+//! Differences from the Babel plugin:
+//! * Closing tags are always emitted in templates.
+//! * Hydration (`hydratable`) and SVG fragments outside of an `<svg>` element are not supported.
+//! * `ref` on components is passed as a normal prop.
+
+use std::{iter, mem};
+
+use cow_utils::CowUtils;
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::{AstBuilder, NONE, ast::*, match_expression};
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ReferenceId, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{Atom, GetSpan, SPAN, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator};
+use oxc_traverse::{BoundIdentifier, MaybeBoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::{create_member_callee, wrap_statements_in_arrow_function_iife},
+};
+
+use super::{
+    jsx_impl::{JsxImpl, get_read_identifier_reference},
+    options::{DomExpressionsGenerate, DomExpressionsOptions},
+};
+
+/// Events which are delegated to the document by `delegateEvents`.
+const DELEGATED_EVENTS: &[&str] = &[
+    "beforeinput",
+    "click",
+    "dblclick",
+    "contextmenu",
+    "focusin",
+    "focusout",
+    "input",
+    "keydown",
+    "keyup",
+    "mousedown",
+    "mousemove",
+    "mouseout",
+    "mouseover",
+    "mouseup",
+    "pointerdown",
+    "pointermove",
+    "pointerout",
+    "pointerover",
+    "pointerup",
+    "touchend",
+    "touchmove",
+    "touchstart",
+];
+
+/// Attributes which are set as DOM properties rather than with `setAttribute`.
+const PROPERTIES: &[&str] = &[
+    "value",
+    "checked",
+    "selected",
+    "muted",
+    "indeterminate",
+    "innerHTML",
+    "textContent",
+    "innerText",
+];
+
+/// Attributes which are rendered without a value when truthy in SSR.
+const BOOLEAN_ATTRIBUTES: &[&str] = &[
+    "allowfullscreen",
+    "async",
+    "autofocus",
+    "autoplay",
+    "checked",
+    "controls",
+    "default",
+    "disabled",
+    "formnovalidate",
+    "hidden",
+    "indeterminate",
+    "inert",
+    "ismap",
+    "loop",
+    "multiple",
+    "muted",
+    "nomodule",
+    "novalidate",
+    "open",
+    "playsinline",
+    "readonly",
+    "required",
+    "reversed",
+    "seamless",
+    "selected",
+];
+
+/// Elements which have no closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+pub struct DomExpressions<'a, 'ctx> {
+    options: DomExpressionsOptions,
+    module_name: Atom<'a>,
+
+    ctx: &'ctx TransformCtx<'a>,
+
+    // States
+    /// `var _web = require("solid-js/web")`, used in scripts
+    require_binding: Option<BoundIdentifier<'a>>,
+    /// `import { insert as _insert } from "solid-js/web"`, keyed by imported name
+    imports: FxHashMap<&'static str, BoundIdentifier<'a>>,
+    /// Hoisted templates, keyed by their static parts
+    templates: FxHashMap<Vec<String>, BoundIdentifier<'a>>,
+    /// `var _tmpl = ...` declarations, inserted after `import`s at the end of the file
+    template_declarations: Vec<Statement<'a>>,
+    /// Events passed to `delegateEvents` at the end of the file
+    delegated_events: Vec<Atom<'a>>,
+}
+
+impl<'a, 'ctx> DomExpressions<'a, 'ctx> {
+    pub fn new(
+        options: DomExpressionsOptions,
+        ast: AstBuilder<'a>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        let module_name = ast.atom(&options.module_name);
+        Self {
+            options,
+            module_name,
+            ctx,
+            require_binding: None,
+            imports: FxHashMap::default(),
+            templates: FxHashMap::default(),
+            template_declarations: vec![],
+            delegated_events: vec![],
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for DomExpressions<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.template_declarations.is_empty() {
+            let index = program
+                .body
+                .iter()
+                .position(|stmt| !matches!(stmt, Statement::ImportDeclaration(_)))
+                .unwrap_or(program.body.len());
+            program.body.splice(index..index, self.template_declarations.drain(..));
+        }
+
+        if self.delegated_events.is_empty() {
+            return;
+        }
+        // `_delegateEvents(["click", "input"]);`
+        let events = mem::take(&mut self.delegated_events).into_iter().map(|event| {
+            ArrayExpressionElement::from(ctx.ast.expression_string_literal(SPAN, event, None))
+        });
+        let events = ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(events));
+        let call = self.call_helper("delegateEvents", ctx.ast.vec1(Argument::from(events)), ctx);
+        program.body.push(ctx.ast.statement_expression(SPAN, call));
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !expr.is_jsx() {
+            return;
+        }
+        *expr = match expr.take_in(ctx.ast) {
+            Expression::JSXElement(e) => self.transform_element(e, ctx),
+            Expression::JSXFragment(e) => self.transform_fragment(e.unbox().children, ctx),
+            _ => unreachable!(),
+        };
+    }
+}
+
+/// A node in a DOM template.
+struct DomNode {
+    parent: Option<usize>,
+    /// Position in the parent's `childNodes`
+    index: usize,
+}
+
+/// Code which makes a node of a DOM template dynamic.
+struct DomOp<'a> {
+    node: usize,
+    kind: DomOpKind<'a>,
+}
+
+enum DomOpKind<'a> {
+    /// `_insert(_el, value, marker)`
+    Insert { value: Expression<'a>, wrap: bool, marker: Marker },
+    /// `_setAttribute(_el, "name", value)`, `_className(_el, value)`, `_el.value = value`, ...
+    Attribute { kind: AttributeKind, name: Atom<'a>, value: Expression<'a> },
+    /// `_el.$$click = handler`
+    DelegatedEvent { name: Atom<'a>, handler: Expression<'a> },
+    /// `_el.addEventListener("scroll", handler)`
+    EventListener { name: Atom<'a>, handler: Expression<'a> },
+    /// `typeof ref === "function" ? _use(ref, _el) : ref = _el`
+    Ref(Expression<'a>),
+    /// `_use(directive, _el, () => value)`
+    Directive { span: Span, name: Atom<'a>, value: Option<Expression<'a>> },
+    /// `_spread(_el, props, false, hasChildren)`
+    Spread { props: Expression<'a>, has_children: bool },
+}
+
+impl<'a> DomOpKind<'a> {
+    fn expression_mut(&mut self) -> Option<&mut Expression<'a>> {
+        match self {
+            Self::Insert { value, .. }
+            | Self::Attribute { value, .. }
+            | Self::DelegatedEvent { handler: value, .. }
+            | Self::EventListener { handler: value, .. }
+            | Self::Ref(value)
+            | Self::Spread { props: value, .. } => Some(value),
+            Self::Directive { value, .. } => value.as_mut(),
+        }
+    }
+}
+
+/// The node before which `insert` adds its value.
+#[derive(Clone, Copy)]
+enum Marker {
+    /// The value replaces all children of the parent.
+    None,
+    /// The value is appended to the parent.
+    Null,
+    /// The value is inserted before this node.
+    Node(usize),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AttributeKind {
+    Attribute,
+    Class,
+    Style,
+    Property,
+}
+
+impl AttributeKind {
+    fn from_name(name: &str) -> Self {
+        match name {
+            "class" => Self::Class,
+            "style" => Self::Style,
+            name if PROPERTIES.contains(&name) => Self::Property,
+            _ => Self::Attribute,
+        }
+    }
+}
+
+#[derive(Default)]
+struct DomTemplate<'a> {
+    html: String,
+    nodes: Vec<DomNode>,
+    ops: Vec<DomOp<'a>>,
+}
+
+impl<'a> DomTemplate<'a> {
+    fn add_node(&mut self, parent: Option<usize>, index: usize) -> usize {
+        self.nodes.push(DomNode { parent, index });
+        self.nodes.len() - 1
+    }
+
+    fn add_op(&mut self, node: usize, kind: DomOpKind<'a>) {
+        self.ops.push(DomOp { node, kind });
+    }
+}
+
+/// An SSR template: static strings, with a dynamic hole between each of them.
+struct SsrTemplate<'a> {
+    parts: Vec<String>,
+    holes: Vec<Expression<'a>>,
+}
+
+impl Default for SsrTemplate<'_> {
+    fn default() -> Self {
+        Self { parts: vec![String::new()], holes: vec![] }
+    }
+}
+
+impl<'a> SsrTemplate<'a> {
+    fn part(&mut self) -> &mut String {
+        self.parts.last_mut().unwrap()
+    }
+
+    fn push_str(&mut self, s: &str) {
+        self.part().push_str(s);
+    }
+
+    fn push_hole(&mut self, expr: Expression<'a>) {
+        self.holes.push(expr);
+        self.parts.push(String::new());
+    }
+}
+
+/// A child of an element, after whitespace is removed and adjacent text is merged.
+enum ChildItem<'a> {
+    Text(String),
+    /// Native element, which is inlined in the parent's template
+    Element(ArenaBox<'a, JSXElement<'a>>),
+    Expression(Expression<'a>, ChildKind),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ChildKind {
+    /// Does not need to be tracked, e.g. an identifier or a literal
+    Static,
+    /// Reads reactive state, e.g. `count()` or `props.name`
+    Dynamic,
+    /// Compiled JSX
+    Jsx,
+}
+
+/// Value of a native element's attribute.
+enum AttributeValue<'a> {
+    /// `<input disabled />`
+    Bare,
+    /// `<div class="box" />`
+    Static(Atom<'a>),
+    Expression(Expression<'a>),
+}
+
+impl<'a> AttributeValue<'a> {
+    fn into_expression(self, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        match self {
+            Self::Bare => ctx.ast.expression_boolean_literal(SPAN, true),
+            Self::Static(value) => ctx.ast.expression_string_literal(SPAN, value, None),
+            Self::Expression(expr) => expr,
+        }
+    }
+}
+
+// Elements
+impl<'a> DomExpressions<'a, '_> {
+    fn is_ssr(&self) -> bool {
+        self.options.generate == DomExpressionsGenerate::Ssr
+    }
+
+    fn transform_element(
+        &mut self,
+        element: ArenaBox<'a, JSXElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let JSXElement { span, opening_element, closing_element, children } = element.unbox();
+        JsxImpl::delete_reference_for_closing_element(closing_element.as_deref(), ctx);
+        let JSXOpeningElement { name, attributes, .. } = opening_element.unbox();
+        let Some(tag) = Self::native_tag_name(&name, ctx) else {
+            return self.transform_component(span, name, attributes, children, ctx);
+        };
+        if self.is_ssr() {
+            let mut template = SsrTemplate::default();
+            self.build_ssr_element(&mut template, tag, attributes, children, ctx);
+            self.finish_ssr_template(span, template, ctx)
+        } else {
+            let mut template = DomTemplate::default();
+            let root = template.add_node(None, 0);
+            self.build_dom_element(&mut template, root, tag, attributes, children, ctx);
+            self.finish_dom_template(span, template, ctx)
+        }
+    }
+
+    /// Get the tag name of a native element, or `None` for components.
+    fn native_tag_name(name: &JSXElementName<'a>, ctx: &TraverseCtx<'a>) -> Option<Atom<'a>> {
+        match name {
+            JSXElementName::Identifier(ident) => Some(ident.name),
+            JSXElementName::NamespacedName(namespaced) => Some(ctx.ast.atom_from_strs_array([
+                &namespaced.namespace.name,
+                ":",
+                &namespaced.name.name,
+            ])),
+            JSXElementName::IdentifierReference(_)
+            | JSXElementName::MemberExpression(_)
+            | JSXElementName::ThisExpression(_) => None,
+        }
+    }
+
+    /// Unwrap a native element found in the children of another native element.
+    fn split_native_element(
+        element: ArenaBox<'a, JSXElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Atom<'a>, ArenaVec<'a, JSXAttributeItem<'a>>, ArenaVec<'a, JSXChild<'a>>) {
+        let JSXElement { opening_element, closing_element, children, .. } = element.unbox();
+        JsxImpl::delete_reference_for_closing_element(closing_element.as_deref(), ctx);
+        let JSXOpeningElement { name, attributes, .. } = opening_element.unbox();
+        let tag = Self::native_tag_name(&name, ctx).unwrap();
+        (tag, attributes, children)
+    }
+
+    /// `<Comp a="1" b={b()}>x</Comp>` -> `_createComponent(Comp, { a: "1", get b() { return b(); }, children: "x" })`
+    fn transform_component(
+        &mut self,
+        span: Span,
+        name: JSXElementName<'a>,
+        attributes: ArenaVec<'a, JSXAttributeItem<'a>>,
+        children: ArenaVec<'a, JSXChild<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let component = match name {
+            JSXElementName::IdentifierReference(ident) => Expression::Identifier(ident),
+            JSXElementName::MemberExpression(member_expr) => {
+                JsxImpl::transform_jsx_member_expression(member_expr, ctx)
+            }
+            JSXElementName::ThisExpression(expr) => ctx.ast.expression_this(expr.span),
+            JSXElementName::Identifier(_) | JSXElementName::NamespacedName(_) => unreachable!(),
+        };
+        let props = self.build_props(attributes, Some(children), ctx);
+        let arguments = ctx.ast.vec_from_array([Argument::from(component), Argument::from(props)]);
+        let callee = self.helper("createComponent", ctx);
+        ctx.ast.expression_call(span, callee, NONE, arguments, false)
+    }
+
+    /// `<>a{b()}</>` -> `["a", _memo(() => b())]`
+    fn transform_fragment(
+        &mut self,
+        children: ArenaVec<'a, JSXChild<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut values = self.transform_children(children, ctx);
+        if !self.is_ssr() {
+            for (value, kind) in &mut values {
+                if *kind == ChildKind::Dynamic {
+                    let arrow = create_arrow(value.take_in(ctx.ast), ctx.current_scope_id(), ctx);
+                    *value = self.call_helper("memo", ctx.ast.vec1(Argument::from(arrow)), ctx);
+                }
+            }
+        }
+        if values.len() == 1 {
+            return values.pop().unwrap().0;
+        }
+        let elements = values.into_iter().map(|(value, _)| ArrayExpressionElement::from(value));
+        ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(elements))
+    }
+
+    /// Build the props object of a component, or of a native element with spread attributes.
+    ///
+    /// Spread attributes are merged with `_mergeProps(a, { b: 1 }, c)`.
+    fn build_props(
+        &mut self,
+        attributes: ArenaVec<'a, JSXAttributeItem<'a>>,
+        children: Option<ArenaVec<'a, JSXChild<'a>>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut sources = vec![];
+        let mut properties = ctx.ast.vec();
+        for attribute in attributes {
+            match attribute {
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    if !properties.is_empty() {
+                        sources.push(
+                            ctx.ast.expression_object(
+                                SPAN,
+                                mem::replace(&mut properties, ctx.ast.vec()),
+                            ),
+                        );
+                    }
+                    sources.push(spread.unbox().argument);
+                }
+                JSXAttributeItem::Attribute(attr) => {
+                    let JSXAttribute { span, name, value } = attr.unbox();
+                    let key = JsxImpl::get_attribute_name(name, ctx);
+                    let value = self.transform_attribute_value(value, ctx).into_expression(ctx);
+                    properties.push(create_prop(span, key, value, ctx));
+                }
+            }
+        }
+
+        if let Some(children) = children {
+            let span = match (children.first(), children.last()) {
+                (Some(first), Some(last)) => Span::new(first.span().start, last.span().end),
+                _ => SPAN,
+            };
+            let mut values = self.transform_children(children, ctx);
+            if !values.is_empty() {
+                let is_static = values.iter().all(|(_, kind)| *kind == ChildKind::Static);
+                let value = if values.len() == 1 {
+                    values.pop().unwrap().0
+                } else {
+                    let elements =
+                        values.into_iter().map(|(value, _)| ArrayExpressionElement::from(value));
+                    ctx.ast.expression_array(span, ctx.ast.vec_from_iter(elements))
+                };
+                let key = ctx.ast.property_key_static_identifier(SPAN, "children");
+                properties.push(if is_static {
+                    ctx.ast.object_property_kind_object_property(
+                        span,
+                        PropertyKind::Init,
+                        key,
+                        value,
+                        false,
+                        false,
+                        false,
+                    )
+                } else {
+                    create_getter(span, key, value, ctx)
+                });
+            }
+        }
+
+        if !properties.is_empty() || sources.is_empty() {
+            sources.push(ctx.ast.expression_object(SPAN, properties));
+        }
+        if sources.len() == 1 {
+            return sources.pop().unwrap();
+        }
+        let arguments = ctx.ast.vec_from_iter(sources.into_iter().map(Argument::from));
+        self.call_helper("mergeProps", arguments, ctx)
+    }
+
+    fn transform_attribute_value(
+        &mut self,
+        value: Option<JSXAttributeValue<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AttributeValue<'a> {
+        match value {
+            None => AttributeValue::Bare,
+            Some(JSXAttributeValue::StringLiteral(s)) => {
+                let mut decoded = None;
+                JsxImpl::decode_entities(s.value.as_str(), &mut decoded, s.value.len(), ctx);
+                AttributeValue::Static(decoded.map_or(s.value, Atom::from))
+            }
+            Some(JSXAttributeValue::ExpressionContainer(c)) => match c.unbox().expression {
+                JSXExpression::EmptyExpression(_) => AttributeValue::Bare,
+                JSXExpression::BooleanLiteral(b) if b.value => AttributeValue::Bare,
+                JSXExpression::StringLiteral(s) => AttributeValue::Static(s.value),
+                jsx_expr @ match_expression!(JSXExpression) => {
+                    AttributeValue::Expression(jsx_expr.into_expression())
+                }
+            },
+            Some(JSXAttributeValue::Element(e)) => {
+                AttributeValue::Expression(self.transform_element(e, ctx))
+            }
+            Some(JSXAttributeValue::Fragment(e)) => {
+                AttributeValue::Expression(self.transform_fragment(e.unbox().children, ctx))
+            }
+        }
+    }
+
+    /// Remove whitespace, merge adjacent text, flatten fragments and compile components.
+    fn collect_children(
+        &mut self,
+        children: ArenaVec<'a, JSXChild<'a>>,
+        items: &mut Vec<ChildItem<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for child in children {
+            let text = match child {
+                JSXChild::Text(text) => {
+                    match JsxImpl::fixup_whitespace_and_decode_entities(text.value, ctx) {
+                        Some(text) => text,
+                        None => continue,
+                    }
+                }
+                JSXChild::ExpressionContainer(container) => match container.unbox().expression {
+                    JSXExpression::EmptyExpression(_) => continue,
+                    JSXExpression::StringLiteral(s) => s.value,
+                    jsx_expr @ match_expression!(JSXExpression) => {
+                        let expr = jsx_expr.into_expression();
+                        let kind =
+                            if is_dynamic(&expr) { ChildKind::Dynamic } else { ChildKind::Static };
+                        items.push(ChildItem::Expression(expr, kind));
+                        continue;
+                    }
+                },
+                JSXChild::Element(element) => {
+                    if Self::native_tag_name(&element.opening_element.name, ctx).is_some() {
+                        items.push(ChildItem::Element(element));
+                    } else {
+                        let expr = self.transform_element(element, ctx);
+                        items.push(ChildItem::Expression(expr, ChildKind::Jsx));
+                    }
+                    continue;
+                }
+                JSXChild::Fragment(fragment) => {
+                    self.collect_children(fragment.unbox().children, items, ctx);
+                    continue;
+                }
+                JSXChild::Spread(spread) => {
+                    items
+                        .push(ChildItem::Expression(spread.unbox().expression, ChildKind::Dynamic));
+                    continue;
+                }
+            };
+            if let Some(ChildItem::Text(last)) = items.last_mut() {
+                last.push_str(&text);
+            } else {
+                items.push(ChildItem::Text(text.to_string()));
+            }
+        }
+    }
+
+    /// Compile the children of a component or fragment to expressions.
+    fn transform_children(
+        &mut self,
+        children: ArenaVec<'a, JSXChild<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<(Expression<'a>, ChildKind)> {
+        let mut items = vec![];
+        self.collect_children(children, &mut items, ctx);
+        items
+            .into_iter()
+            .map(|item| match item {
+                ChildItem::Text(text) => {
+                    let text = ctx.ast.atom(&text);
+                    (ctx.ast.expression_string_literal(SPAN, text, None), ChildKind::Static)
+                }
+                ChildItem::Element(element) => {
+                    (self.transform_element(element, ctx), ChildKind::Jsx)
+                }
+                ChildItem::Expression(expr, kind) => (expr, kind),
+            })
+            .collect()
+    }
+}
+
+// DOM
+impl<'a> DomExpressions<'a, '_> {
+    fn build_dom_element(
+        &mut self,
+        template: &mut DomTemplate<'a>,
+        node: usize,
+        tag: Atom<'a>,
+        attributes: ArenaVec<'a, JSXAttributeItem<'a>>,
+        children: ArenaVec<'a, JSXChild<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        template.html.push('<');
+        template.html.push_str(&tag);
+        let has_spread =
+            attributes.iter().any(|attr| matches!(attr, JSXAttributeItem::SpreadAttribute(_)));
+        let props = if has_spread {
+            Some(self.build_props(attributes, None, ctx))
+        } else {
+            for attribute in attributes {
+                let JSXAttributeItem::Attribute(attr) = attribute else { unreachable!() };
+                self.build_dom_attribute(template, node, attr.unbox(), ctx);
+            }
+            None
+        };
+        template.html.push('>');
+
+        let mut items = vec![];
+        self.collect_children(children, &mut items, ctx);
+        if let Some(props) = props {
+            let has_children = !items.is_empty();
+            template.add_op(node, DomOpKind::Spread { props, has_children });
+        }
+        if items.is_empty() && VOID_ELEMENTS.contains(&tag.as_str()) {
+            return;
+        }
+        self.build_dom_children(template, node, items, ctx);
+        template.html.push_str("</");
+        template.html.push_str(&tag);
+        template.html.push('>');
+    }
+
+    fn build_dom_attribute(
+        &mut self,
+        template: &mut DomTemplate<'a>,
+        node: usize,
+        attr: JSXAttribute<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let JSXAttribute { span, name, value } = attr;
+        let (namespace, name) = match name {
+            JSXAttributeName::Identifier(ident) => (None, ident.name),
+            JSXAttributeName::NamespacedName(namespaced) => {
+                (Some(namespaced.namespace.name), namespaced.name.name)
+            }
+        };
+        let value = self.transform_attribute_value(value, ctx);
+        let mut kind = None;
+        match namespace.as_deref() {
+            Some("on") => {
+                let handler = value.into_expression(ctx);
+                template.add_op(node, DomOpKind::EventListener { name, handler });
+                return;
+            }
+            Some("use") => {
+                let value = match value {
+                    AttributeValue::Bare => None,
+                    value => Some(value.into_expression(ctx)),
+                };
+                template.add_op(node, DomOpKind::Directive { span, name, value });
+                return;
+            }
+            Some("prop") => {
+                let value = value.into_expression(ctx);
+                let kind = AttributeKind::Property;
+                template.add_op(node, DomOpKind::Attribute { kind, name, value });
+                return;
+            }
+            Some("attr") => kind = Some(AttributeKind::Attribute),
+            None if name == "ref" => {
+                template.add_op(node, DomOpKind::Ref(value.into_expression(ctx)));
+                return;
+            }
+            None if is_event_name(&name) => {
+                let event = ctx.ast.atom(&name[2..].cow_to_ascii_lowercase());
+                let handler = value.into_expression(ctx);
+                if self.options.delegate_events && DELEGATED_EVENTS.contains(&event.as_str()) {
+                    if !self.delegated_events.contains(&event) {
+                        self.delegated_events.push(event);
+                    }
+                    template.add_op(node, DomOpKind::DelegatedEvent { name: event, handler });
+                } else {
+                    template.add_op(node, DomOpKind::EventListener { name: event, handler });
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        let name = match namespace {
+            Some(namespace) if namespace != "attr" => {
+                ctx.ast.atom_from_strs_array([&namespace, ":", &name])
+            }
+            _ if name == "className" => Atom::from("class"),
+            _ => name,
+        };
+        match value {
+            AttributeValue::Bare => {
+                template.html.push(' ');
+                template.html.push_str(&name);
+            }
+            AttributeValue::Static(value) => {
+                template.html.push(' ');
+                template.html.push_str(&name);
+                template.html.push_str("=\"");
+                escape_html(&value, true, &mut template.html);
+                template.html.push('"');
+            }
+            AttributeValue::Expression(value) => {
+                let kind = kind.unwrap_or_else(|| AttributeKind::from_name(&name));
+                template.add_op(node, DomOpKind::Attribute { kind, name, value });
+            }
+        }
+    }
+
+    fn build_dom_children(
+        &mut self,
+        template: &mut DomTemplate<'a>,
+        parent: usize,
+        items: Vec<ChildItem<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let len = items.len();
+        let mut index = 0;
+        // Node of the next element, when it is used as the marker of an `insert`
+        let mut next_element_node = None;
+        let mut items = items.into_iter().peekable();
+        while let Some(item) = items.next() {
+            match item {
+                ChildItem::Text(text) => {
+                    escape_html(&text, false, &mut template.html);
+                    index += 1;
+                }
+                ChildItem::Element(element) => {
+                    let node = next_element_node
+                        .take()
+                        .unwrap_or_else(|| template.add_node(Some(parent), index));
+                    let (tag, attributes, children) = Self::split_native_element(element, ctx);
+                    self.build_dom_element(template, node, tag, attributes, children, ctx);
+                    index += 1;
+                }
+                ChildItem::Expression(value, kind) => {
+                    let marker = match items.peek() {
+                        None if len == 1 => Marker::None,
+                        None => Marker::Null,
+                        Some(ChildItem::Element(_)) => {
+                            let node = template.add_node(Some(parent), index);
+                            next_element_node = Some(node);
+                            Marker::Node(node)
+                        }
+                        // Text and inserted values would otherwise be merged into
+                        // the same text node, so separate them with a comment.
+                        Some(_) => {
+                            template.html.push_str("<!>");
+                            let node = template.add_node(Some(parent), index);
+                            index += 1;
+                            Marker::Node(node)
+                        }
+                    };
+                    let wrap = kind == ChildKind::Dynamic;
+                    template.add_op(parent, DomOpKind::Insert { value, wrap, marker });
+                }
+            }
+        }
+    }
+
+    /// `_tmpl()`, or an IIFE which clones the template and makes its nodes dynamic:
+    ///
+    /// ```js
+    /// (() => {
+    ///     var _el = _tmpl(), _el2 = _el.firstChild;
+    ///     _insert(_el2, value);
+    ///     return _el;
+    /// })()
+    /// ```
+    fn finish_dom_template(
+        &mut self,
+        span: Span,
+        mut template: DomTemplate<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let template_ref = self.get_template(vec![mem::take(&mut template.html)], ctx);
+        let clone = ctx.ast.expression_call(span, template_ref, NONE, ctx.ast.vec(), false);
+        if template.ops.is_empty() {
+            return clone;
+        }
+
+        // Move the expressions of all ops into the scope of the IIFE
+        let mut stmts = ctx.ast.vec();
+        for op in &mut template.ops {
+            if let Some(expr) = op.kind.expression_mut() {
+                stmts.push(ctx.ast.statement_expression(SPAN, expr.take_in(ctx.ast)));
+            }
+        }
+        let scope_id =
+            ctx.insert_scope_below_statements(&stmts, ScopeFlags::Arrow | ScopeFlags::Function);
+        let mut exprs = stmts.into_iter().map(|stmt| match stmt {
+            Statement::ExpressionStatement(stmt) => stmt.unbox().expression,
+            _ => unreachable!(),
+        });
+        for op in &mut template.ops {
+            if let Some(expr) = op.kind.expression_mut() {
+                *expr = exprs.next().unwrap();
+            }
+        }
+
+        // Declare the nodes which are used by ops, and their ancestors
+        let mut used = vec![false; template.nodes.len()];
+        used[0] = true;
+        for op in &template.ops {
+            used[op.node] = true;
+            if let DomOpKind::Insert { marker: Marker::Node(node), .. } = op.kind {
+                used[node] = true;
+            }
+        }
+        for node in (1..template.nodes.len()).rev() {
+            if used[node] {
+                used[template.nodes[node].parent.unwrap()] = true;
+            }
+        }
+        let mut bindings: Vec<Option<BoundIdentifier<'a>>> = vec![None; template.nodes.len()];
+        let mut declarators = ctx.ast.vec();
+        let mut clone = Some(clone);
+        for (id, node) in template.nodes.iter().enumerate() {
+            if !used[id] {
+                continue;
+            }
+            let init = match node.parent {
+                None => clone.take().unwrap(),
+                Some(parent) => {
+                    let parent = bindings[parent].as_ref().unwrap();
+                    let mut expr =
+                        create_member_callee(parent.create_read_expression(ctx), "firstChild", ctx);
+                    for _ in 0..node.index {
+                        expr = create_member_callee(expr, "nextSibling", ctx);
+                    }
+                    expr
+                }
+            };
+            let binding = ctx.generate_uid("el", scope_id, SymbolFlags::FunctionScopedVariable);
+            declarators.push(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                binding.create_binding_pattern(ctx),
+                NONE,
+                Some(init),
+                false,
+            ));
+            bindings[id] = Some(binding);
+        }
+
+        let mut body = ctx.ast.vec_with_capacity(template.ops.len() + 2);
+        body.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarators,
+            false,
+        )));
+        for DomOp { node, kind } in template.ops {
+            let element = bindings[node].as_ref().unwrap();
+            let expr = self.create_dom_op(element, kind, &bindings, scope_id, ctx);
+            body.push(ctx.ast.statement_expression(SPAN, expr));
+        }
+        let root = bindings[0].as_ref().unwrap().create_read_expression(ctx);
+        body.push(ctx.ast.statement_return(SPAN, Some(root)));
+        wrap_statements_in_arrow_function_iife(body, scope_id, span, ctx)
+    }
+
+    fn create_dom_op(
+        &mut self,
+        element: &BoundIdentifier<'a>,
+        kind: DomOpKind<'a>,
+        bindings: &[Option<BoundIdentifier<'a>>],
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let el = element.create_read_expression(ctx);
+        match kind {
+            DomOpKind::Insert { value, wrap, marker } => {
+                // `() => count()` can be simplified to `count`
+                let value = match value {
+                    Expression::CallExpression(call)
+                        if wrap
+                            && call.arguments.is_empty()
+                            && !call.optional
+                            && call.callee.is_identifier_reference() =>
+                    {
+                        call.unbox().callee
+                    }
+                    value if wrap => create_arrow(value, scope_id, ctx),
+                    value => value,
+                };
+                let mut arguments =
+                    ctx.ast.vec_from_array([Argument::from(el), Argument::from(value)]);
+                match marker {
+                    Marker::None => {}
+                    Marker::Null => {
+                        arguments.push(Argument::from(ctx.ast.expression_null_literal(SPAN)));
+                    }
+                    Marker::Node(node) => {
+                        let marker = bindings[node].as_ref().unwrap().create_read_expression(ctx);
+                        arguments.push(Argument::from(marker));
+                    }
+                }
+                self.call_helper("insert", arguments, ctx)
+            }
+            DomOpKind::Attribute { kind, name, value } => {
+                let dynamic = is_dynamic(&value);
+                let setter = match kind {
+                    AttributeKind::Attribute => {
+                        let name = ctx.ast.expression_string_literal(SPAN, name, None);
+                        let arguments = ctx.ast.vec_from_array([
+                            Argument::from(el),
+                            Argument::from(name),
+                            Argument::from(value),
+                        ]);
+                        self.call_helper("setAttribute", arguments, ctx)
+                    }
+                    AttributeKind::Class | AttributeKind::Style => {
+                        let helper =
+                            if kind == AttributeKind::Class { "className" } else { "style" };
+                        let arguments =
+                            ctx.ast.vec_from_array([Argument::from(el), Argument::from(value)]);
+                        self.call_helper(helper, arguments, ctx)
+                    }
+                    AttributeKind::Property => {
+                        let property = ctx.ast.identifier_name(SPAN, name);
+                        let target = ctx.ast.member_expression_static(SPAN, el, property, false);
+                        ctx.ast.expression_assignment(
+                            SPAN,
+                            AssignmentOperator::Assign,
+                            AssignmentTarget::from(target),
+                            value,
+                        )
+                    }
+                };
+                if dynamic {
+                    let arrow = create_arrow(setter, scope_id, ctx);
+                    self.call_helper("effect", ctx.ast.vec1(Argument::from(arrow)), ctx)
+                } else {
+                    setter
+                }
+            }
+            DomOpKind::DelegatedEvent { name, handler } => {
+                let property =
+                    ctx.ast.identifier_name(SPAN, ctx.ast.atom_from_strs_array(["$$", &name]));
+                let target = ctx.ast.member_expression_static(SPAN, el, property, false);
+                ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    handler,
+                )
+            }
+            DomOpKind::EventListener { name, handler } => {
+                let callee = create_member_callee(el, "addEventListener", ctx);
+                let name = ctx.ast.expression_string_literal(SPAN, name, None);
+                let arguments =
+                    ctx.ast.vec_from_array([Argument::from(name), Argument::from(handler)]);
+                ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+            }
+            DomOpKind::Ref(value) => match value {
+                // `typeof ref === "function" ? _use(ref, _el) : ref = _el`
+                Expression::Identifier(ident) => {
+                    let binding = MaybeBoundIdentifier::from_identifier_reference(&ident, ctx);
+                    let test = ctx.ast.expression_binary(
+                        SPAN,
+                        ctx.ast.expression_unary(
+                            SPAN,
+                            UnaryOperator::Typeof,
+                            Expression::Identifier(ident),
+                        ),
+                        BinaryOperator::StrictEquality,
+                        ctx.ast.expression_string_literal(SPAN, "function", None),
+                    );
+                    let arguments = ctx.ast.vec_from_array([
+                        Argument::from(binding.create_read_expression(ctx)),
+                        Argument::from(el),
+                    ]);
+                    let consequent = self.call_helper("use", arguments, ctx);
+                    let alternate = ctx.ast.expression_assignment(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        binding.create_write_target(ctx),
+                        element.create_read_expression(ctx),
+                    );
+                    ctx.ast.expression_conditional(SPAN, test, consequent, alternate)
+                }
+                value => {
+                    let arguments =
+                        ctx.ast.vec_from_array([Argument::from(value), Argument::from(el)]);
+                    self.call_helper("use", arguments, ctx)
+                }
+            },
+            DomOpKind::Directive { span, name, value } => {
+                let directive = get_read_identifier_reference(span, name, ctx);
+                let mut arguments =
+                    ctx.ast.vec_from_array([Argument::from(directive), Argument::from(el)]);
+                if let Some(value) = value {
+                    arguments.push(Argument::from(create_arrow(value, scope_id, ctx)));
+                }
+                self.call_helper("use", arguments, ctx)
+            }
+            DomOpKind::Spread { props, has_children } => {
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(el),
+                    Argument::from(props),
+                    Argument::from(ctx.ast.expression_boolean_literal(SPAN, false)),
+                    Argument::from(ctx.ast.expression_boolean_literal(SPAN, has_children)),
+                ]);
+                self.call_helper("spread", arguments, ctx)
+            }
+        }
+    }
+}
+
+// SSR
+impl<'a> DomExpressions<'a, '_> {
+    fn build_ssr_element(
+        &mut self,
+        template: &mut SsrTemplate<'a>,
+        tag: Atom<'a>,
+        attributes: ArenaVec<'a, JSXAttributeItem<'a>>,
+        children: ArenaVec<'a, JSXChild<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if attributes.iter().any(|attr| matches!(attr, JSXAttributeItem::SpreadAttribute(_))) {
+            // `_ssrElement("div", props, () => children, false)`
+            let props = self.build_props(attributes, None, ctx);
+            let mut items = vec![];
+            self.collect_children(children, &mut items, ctx);
+            let children = if items.is_empty() {
+                ctx.ast.void_0(SPAN)
+            } else {
+                let mut children = SsrTemplate::default();
+                self.build_ssr_children(&mut children, items, ctx);
+                let children = self.finish_ssr_template(SPAN, children, ctx);
+                create_arrow(children, ctx.current_scope_id(), ctx)
+            };
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ctx.ast.expression_string_literal(SPAN, tag, None)),
+                Argument::from(props),
+                Argument::from(children),
+                Argument::from(ctx.ast.expression_boolean_literal(SPAN, false)),
+            ]);
+            let element = self.call_helper("ssrElement", arguments, ctx);
+            template.push_hole(element);
+            return;
+        }
+
+        template.push_str("<");
+        template.push_str(&tag);
+        for attribute in attributes {
+            let JSXAttributeItem::Attribute(attr) = attribute else { unreachable!() };
+            self.build_ssr_attribute(template, attr.unbox(), ctx);
+        }
+        template.push_str(">");
+
+        let mut items = vec![];
+        self.collect_children(children, &mut items, ctx);
+        if items.is_empty() && VOID_ELEMENTS.contains(&tag.as_str()) {
+            return;
+        }
+        self.build_ssr_children(template, items, ctx);
+        template.push_str("</");
+        template.push_str(&tag);
+        template.push_str(">");
+    }
+
+    fn build_ssr_attribute(
+        &mut self,
+        template: &mut SsrTemplate<'a>,
+        attr: JSXAttribute<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let JSXAttribute { name, value, .. } = attr;
+        let (namespace, name) = match name {
+            JSXAttributeName::Identifier(ident) => (None, ident.name),
+            JSXAttributeName::NamespacedName(namespaced) => {
+                (Some(namespaced.namespace.name), namespaced.name.name)
+            }
+        };
+        let value = self.transform_attribute_value(value, ctx);
+
+        // Events, refs, directives and properties only exist in the browser
+        let client_only = match namespace.as_deref() {
+            Some("on" | "use" | "prop") => true,
+            Some(_) => false,
+            None => name == "ref" || is_event_name(&name),
+        };
+        if client_only {
+            if let AttributeValue::Expression(expr) = &value {
+                delete_references(expr, ctx);
+            }
+            return;
+        }
+
+        let name = match namespace {
+            Some(namespace) if namespace != "attr" => {
+                ctx.ast.atom_from_strs_array([&namespace, ":", &name])
+            }
+            _ if name == "className" => Atom::from("class"),
+            _ => name,
+        };
+        match value {
+            AttributeValue::Bare => {
+                template.push_str(" ");
+                template.push_str(&name);
+            }
+            AttributeValue::Static(value) => {
+                template.push_str(" ");
+                template.push_str(&name);
+                template.push_str("=\"");
+                escape_html(&value, true, template.part());
+                template.push_str("\"");
+            }
+            AttributeValue::Expression(value) if name == "style" => {
+                // ` style="${_ssrStyle(value)}"`
+                template.push_str(" style=\"");
+                let style = self.call_helper("ssrStyle", ctx.ast.vec1(Argument::from(value)), ctx);
+                template.push_hole(style);
+                template.push_str("\"");
+            }
+            AttributeValue::Expression(value) => {
+                // `_ssrAttribute("name", _escape(value, true), false)`
+                let is_boolean = BOOLEAN_ATTRIBUTES.contains(&name.as_str());
+                let value = if is_boolean { value } else { self.escape(value, true, ctx) };
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)),
+                    Argument::from(value),
+                    Argument::from(ctx.ast.expression_boolean_literal(SPAN, is_boolean)),
+                ]);
+                let attribute = self.call_helper("ssrAttribute", arguments, ctx);
+                template.push_hole(attribute);
+            }
+        }
+    }
+
+    fn build_ssr_children(
+        &mut self,
+        template: &mut SsrTemplate<'a>,
+        items: Vec<ChildItem<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for item in items {
+            match item {
+                ChildItem::Text(text) => escape_html(&text, false, template.part()),
+                ChildItem::Element(element) => {
+                    let (tag, attributes, children) = Self::split_native_element(element, ctx);
+                    self.build_ssr_element(template, tag, attributes, children, ctx);
+                }
+                ChildItem::Expression(expr, _) => {
+                    let expr = self.escape(expr, false, ctx);
+                    template.push_hole(expr);
+                }
+            }
+        }
+    }
+
+    /// `_ssr(_tmpl, hole1, hole2)`
+    fn finish_ssr_template(
+        &mut self,
+        span: Span,
+        template: SsrTemplate<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let SsrTemplate { parts, mut holes } = template;
+        // `_ssrElement(...)` does not need to be wrapped in `_ssr`
+        if holes.len() == 1 && parts.iter().all(String::is_empty) {
+            return holes.pop().unwrap();
+        }
+        let template_ref = self.get_template(parts, ctx);
+        let arguments = ctx.ast.vec_from_iter(
+            iter::once(Argument::from(template_ref)).chain(holes.into_iter().map(Argument::from)),
+        );
+        let callee = self.helper("ssr", ctx);
+        ctx.ast.expression_call(span, callee, NONE, arguments, false)
+    }
+
+    /// `_escape(expr)` or `_escape(expr, true)`
+    fn escape(
+        &mut self,
+        expr: Expression<'a>,
+        attribute: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec1(Argument::from(expr));
+        if attribute {
+            arguments.push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+        }
+        self.call_helper("escape", arguments, ctx)
+    }
+}
+
+// Runtime
+impl<'a> DomExpressions<'a, '_> {
+    /// Get a reference to a runtime helper, importing it if it's not imported yet.
+    fn helper(&mut self, name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        if self.ctx.source_type.is_script() {
+            let binding = if let Some(binding) = &self.require_binding {
+                binding
+            } else {
+                let binding =
+                    ctx.generate_uid_in_root_scope("web", SymbolFlags::FunctionScopedVariable);
+                self.ctx.module_imports.add_default_import(
+                    self.module_name,
+                    binding.clone(),
+                    false,
+                );
+                self.require_binding.insert(binding)
+            };
+            let object = binding.create_read_expression(ctx);
+            return create_member_callee(object, name, ctx);
+        }
+
+        if let Some(binding) = self.imports.get(name) {
+            return binding.create_read_expression(ctx);
+        }
+        let binding = ctx.generate_uid_in_root_scope(name, SymbolFlags::Import);
+        self.ctx.module_imports.add_named_import(
+            self.module_name,
+            Atom::from(name),
+            binding.clone(),
+            false,
+        );
+        let expr = binding.create_read_expression(ctx);
+        self.imports.insert(name, binding);
+        expr
+    }
+
+    fn call_helper(
+        &mut self,
+        name: &'static str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = self.helper(name, ctx);
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// Get a reference to a hoisted template, hoisting it if no identical template exists yet.
+    ///
+    /// * DOM: `var _tmpl = /*#__PURE__*/ _template("<div></div>");`
+    /// * SSR: `var _tmpl = "<div></div>";` or `var _tmpl = ["<div>", "</div>"];`
+    fn get_template(&mut self, parts: Vec<String>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        if let Some(binding) = self.templates.get(&parts) {
+            return binding.create_read_expression(ctx);
+        }
+
+        let mut strings = parts.iter().map(|part| {
+            let part = ctx.ast.atom(part);
+            ctx.ast.expression_string_literal(SPAN, part, None)
+        });
+        let init = if self.is_ssr() {
+            if parts.len() == 1 {
+                strings.next().unwrap()
+            } else {
+                let elements = ctx.ast.vec_from_iter(strings.map(ArrayExpressionElement::from));
+                ctx.ast.expression_array(SPAN, elements)
+            }
+        } else {
+            let html = strings.next().unwrap();
+            let callee = self.helper("template", ctx);
+            let arguments = ctx.ast.vec1(Argument::from(html));
+            ctx.ast.expression_call_with_pure(SPAN, callee, NONE, arguments, false, true)
+        };
+
+        let binding = ctx.generate_uid_in_root_scope("tmpl", SymbolFlags::FunctionScopedVariable);
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(ctx),
+            NONE,
+            Some(init),
+            false,
+        );
+        self.template_declarations.push(Statement::VariableDeclaration(
+            ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            ),
+        ));
+        let expr = binding.create_read_expression(ctx);
+        self.templates.insert(parts, binding);
+        expr
+    }
+}
+
+/// `{ key: value }`, or `{ get key() { return value; } }` if `value` is dynamic.
+fn create_prop<'a>(
+    span: Span,
+    key: PropertyKey<'a>,
+    value: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    if is_dynamic(&value) {
+        create_getter(span, key, value, ctx)
+    } else {
+        ctx.ast.object_property_kind_object_property(
+            span,
+            PropertyKind::Init,
+            key,
+            value,
+            false,
+            false,
+            false,
+        )
+    }
+}
+
+/// `get key() { return value; }`
+fn create_getter<'a>(
+    span: Span,
+    key: PropertyKey<'a>,
+    value: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    let stmt = ctx.ast.statement_return(SPAN, Some(value));
+    let scope_id = ctx.insert_scope_below_statement_from_scope_id(
+        &stmt,
+        ctx.current_scope_id(),
+        ScopeFlags::Function | ScopeFlags::GetAccessor,
+    );
+    let params = ctx.ast.alloc_formal_parameters(
+        SPAN,
+        FormalParameterKind::UniqueFormalParameters,
+        ctx.ast.vec(),
+        NONE,
+    );
+    let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(stmt));
+    let function = ctx.ast.alloc_function_with_scope_id(
+        span,
+        FunctionType::FunctionExpression,
+        None,
+        false,
+        false,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        Some(body),
+        scope_id,
+    );
+    ctx.ast.object_property_kind_object_property(
+        span,
+        PropertyKind::Get,
+        key,
+        Expression::FunctionExpression(function),
+        false,
+        false,
+        false,
+    )
+}
+
+/// `() => body`, created in the scope `parent_scope_id`, which must contain `body`.
+fn create_arrow<'a>(
+    body: Expression<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let stmt = ctx.ast.statement_expression(SPAN, body);
+    let scope_id = ctx.insert_scope_below_statement_from_scope_id(
+        &stmt,
+        parent_scope_id,
+        ScopeFlags::Arrow | ScopeFlags::Function,
+    );
+    let params = ctx.ast.alloc_formal_parameters(
+        SPAN,
+        FormalParameterKind::ArrowFormalParameters,
+        ctx.ast.vec(),
+        NONE,
+    );
+    let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(stmt));
+    ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+        SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+    )
+}
+
+/// `onClick`, but not `one` or `on:click`.
+fn is_event_name(name: &str) -> bool {
+    name.len() > 2 && name.starts_with("on") && name.as_bytes()[2].is_ascii_uppercase()
+}
+
+/// Escape text for use in HTML.
+fn escape_html(s: &str, attribute: bool, out: &mut String) {
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '"' if attribute => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Check if an expression reads reactive state, and so must be re-evaluated when that state changes.
+///
+/// Calls and property accesses are dynamic, except inside functions, which are only
+/// evaluated when they are called.
+fn is_dynamic(expr: &Expression<'_>) -> bool {
+    let mut checker = DynamicChecker { dynamic: false };
+    checker.visit_expression(expr);
+    checker.dynamic
+}
+
+struct DynamicChecker {
+    dynamic: bool,
+}
+
+impl<'a> Visit<'a> for DynamicChecker {
+    fn visit_call_expression(&mut self, _it: &CallExpression<'a>) {
+        self.dynamic = true;
+    }
+
+    fn visit_new_expression(&mut self, _it: &NewExpression<'a>) {
+        self.dynamic = true;
+    }
+
+    fn visit_tagged_template_expression(&mut self, _it: &TaggedTemplateExpression<'a>) {
+        self.dynamic = true;
+    }
+
+    fn visit_member_expression(&mut self, _it: &MemberExpression<'a>) {
+        self.dynamic = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
+
+/// Delete references in an expression which is removed from the AST.
+fn delete_references<'a>(expr: &Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+    let mut collector = ReferenceCollector { references: vec![] };
+    collector.visit_expression(expr);
+    for (reference_id, name) in collector.references {
+        ctx.delete_reference(reference_id, &name);
+    }
+}
+
+struct ReferenceCollector<'a> {
+    references: Vec<(ReferenceId, Atom<'a>)>,
+}
+
+impl<'a> Visit<'a> for ReferenceCollector<'a> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        self.references.push((it.reference_id(), it.name));
+    }
+}
@@ -856,7 +856,7 @@ impl<'a> JsxImpl<'a, '_> {
         }
     }
 
-    fn transform_jsx_member_expression(
+    pub(super) fn transform_jsx_member_expression(
         expr: ArenaBox<'a, JSXMemberExpression<'a>>,
         ctx: &TraverseCtx<'a>,
     ) -> Expression<'a> {
@@ -958,7 +958,10 @@ impl<'a> JsxImpl<'a, '_> {
         }
     }
 
-    fn get_attribute_name(name: JSXAttributeName<'a>, ctx: &TraverseCtx<'a>) -> PropertyKey<'a> {
+    pub(super) fn get_attribute_name(
+        name: JSXAttributeName<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> PropertyKey<'a> {
         match name {
             JSXAttributeName::Identifier(ident) => {
                 let name = ident.name;
@@ -995,7 +998,7 @@ impl<'a> JsxImpl<'a, '_> {
     /// - Remove empty lines and join the rest with " ".
     ///
     /// <https://github.com/microsoft/TypeScript/blob/f0374ce2a9c465e27a15b7fa4a347e2bd9079450/src/compiler/transformers/jsx.ts#L557-L608>
-    fn fixup_whitespace_and_decode_entities(
+    pub(super) fn fixup_whitespace_and_decode_entities(
         text: Atom<'a>,
         ctx: &TraverseCtx<'a>,
     ) -> Option<Atom<'a>> {
@@ -1104,7 +1107,7 @@ impl<'a> JsxImpl<'a, '_> {
     ///
     /// Otherwise, leave `acc` as `None`. This indicates that the text contains no HTML entities.
     /// Caller can use a slice of the original text, rather than making any copies.
-    fn decode_entities(
+    pub(super) fn decode_entities(
         s: &str,
         acc: &mut Option<ArenaStringBuilder<'a>>,
         text_len: usize,
@@ -1183,7 +1186,7 @@ impl<'a> JsxImpl<'a, '_> {
         false
     }
 
-    fn delete_reference_for_closing_element(
+    pub(super) fn delete_reference_for_closing_element(
         element: Option<&JSXClosingElement<'a>>,
         ctx: &mut TraverseCtx<'a>,
     ) {
@@ -1196,7 +1199,7 @@ impl<'a> JsxImpl<'a, '_> {
 }
 
 /// Create `IdentifierReference` for var name in current scope which is read from
-fn get_read_identifier_reference<'a>(
+pub(super) fn get_read_identifier_reference<'a>(
     span: Span,
     name: Atom<'a>,
     ctx: &mut TraverseCtx<'a>,
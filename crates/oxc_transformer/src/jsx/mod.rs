@@ -10,6 +10,7 @@ use crate::{
 mod comments;
 mod diagnostics;
 mod display_name;
+mod dom_expressions;
 mod jsx_impl;
 mod jsx_self;
 mod jsx_source;
@@ -17,9 +18,12 @@ mod options;
 mod refresh;
 pub use comments::update_options_with_comments;
 use display_name::ReactDisplayName;
+use dom_expressions::DomExpressions;
 use jsx_impl::JsxImpl;
 use jsx_self::JsxSelf;
-pub use options::{JsxOptions, JsxRuntime, ReactRefreshOptions};
+pub use options::{
+    DomExpressionsGenerate, DomExpressionsOptions, JsxOptions, JsxRuntime, ReactRefreshOptions,
+};
 use refresh::ReactRefresh;
 
 /// [Preset React](https://babel.dev/docs/babel-preset-react)
@@ -30,8 +34,11 @@ use refresh::ReactRefresh;
 /// * [plugin-transform-react-jsx-self](https://babeljs.io/docs/babel-plugin-transform-react-jsx-self)
 /// * [plugin-transform-react-jsx-source](https://babel.dev/docs/babel-plugin-transform-react-jsx-source)
 /// * [plugin-transform-react-display-name](https://babeljs.io/docs/babel-plugin-transform-react-display-name)
+///
+/// When `dom_expressions` is set, JSX is compiled by [babel-plugin-jsx-dom-expressions](https://github.com/ryansolid/dom-expressions/tree/main/packages/babel-plugin-jsx-dom-expressions) instead.
 pub struct Jsx<'a, 'ctx> {
     implementation: JsxImpl<'a, 'ctx>,
+    dom_expressions: Option<DomExpressions<'a, 'ctx>>,
     display_name: ReactDisplayName<'a, 'ctx>,
     refresh: ReactRefresh<'a, 'ctx>,
     enable_jsx_plugin: bool,
@@ -56,8 +63,14 @@ impl<'a, 'ctx> Jsx<'a, 'ctx> {
             jsx_plugin, display_name_plugin, jsx_self_plugin, jsx_source_plugin, ..
         } = options;
         let refresh = options.refresh.clone();
+        let dom_expressions = options
+            .dom_expressions
+            .clone()
+            .filter(|_| jsx_plugin)
+            .map(|options| DomExpressions::new(options, ast, ctx));
         Self {
             implementation: JsxImpl::new(options, object_rest_spread_options, ast, ctx),
+            dom_expressions,
             display_name: ReactDisplayName::new(ctx),
             enable_jsx_plugin: jsx_plugin,
             display_name_plugin,
@@ -83,7 +96,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for Jsx<'a, '_> {
         if self.refresh_plugin {
             self.refresh.exit_program(program, ctx);
         }
-        if self.enable_jsx_plugin {
+        if let Some(dom_expressions) = &mut self.dom_expressions {
+            dom_expressions.exit_program(program, ctx);
+        } else if self.enable_jsx_plugin {
             self.implementation.exit_program(program, ctx);
         } else if self.source_plugin {
             self.implementation.jsx_source.exit_program(program, ctx);
@@ -120,7 +135,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for Jsx<'a, '_> {
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.enable_jsx_plugin {
+        if let Some(dom_expressions) = &mut self.dom_expressions {
+            dom_expressions.exit_expression(expr, ctx);
+        } else if self.enable_jsx_plugin {
             self.implementation.exit_expression(expr, ctx);
         }
         if self.refresh_plugin {
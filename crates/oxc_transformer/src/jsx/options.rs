@@ -105,6 +105,14 @@ pub struct JsxOptions {
 
     /// Fast Refresh
     pub refresh: Option<ReactRefreshOptions>,
+
+    /// Compile JSX with the template cloning model of
+    /// [dom-expressions](https://github.com/ryansolid/dom-expressions), as used by Solid.
+    ///
+    /// When set, `runtime`, `importSource`, `pragma` and `pragmaFrag` are ignored.
+    ///
+    /// Defaults to `None`.
+    pub dom_expressions: Option<DomExpressionsOptions>,
 }
 
 impl Default for JsxOptions {
@@ -138,6 +146,7 @@ impl JsxOptions {
             use_built_ins: None,
             use_spread: None,
             refresh: None,
+            dom_expressions: None,
         }
     }
 
@@ -157,6 +166,7 @@ impl JsxOptions {
             use_built_ins: None,
             use_spread: None,
             refresh: None,
+            dom_expressions: None,
         }
     }
 }
@@ -208,3 +218,52 @@ fn default_refresh_reg() -> String {
 fn default_refresh_sig() -> String {
     String::from("$RefreshSig$")
 }
+
+/// Which output `dom_expressions` compiles JSX to.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DomExpressionsGenerate {
+    /// Clone hoisted `<template>` elements and update them with `insert`/`effect` calls.
+    #[default]
+    Dom,
+    /// Render to strings with `ssr` and `escape` calls.
+    Ssr,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct DomExpressionsOptions {
+    /// The module runtime helpers (`template`, `insert`, `ssr`, ...) are imported from.
+    ///
+    /// Defaults to `solid-js/web`.
+    #[serde(default = "default_dom_expressions_module_name")]
+    pub module_name: String,
+
+    /// Whether to generate code for the browser (`dom`) or for server side rendering (`ssr`).
+    ///
+    /// Defaults to `dom`.
+    pub generate: DomExpressionsGenerate,
+
+    /// Delegate common UI events such as `onClick` to the document with `delegateEvents`,
+    /// instead of adding a listener to each element.
+    ///
+    /// Only used when `generate` is `dom`.
+    ///
+    /// Defaults to `true`.
+    #[serde(default = "default_as_true")]
+    pub delegate_events: bool,
+}
+
+impl Default for DomExpressionsOptions {
+    fn default() -> Self {
+        Self {
+            module_name: default_dom_expressions_module_name(),
+            generate: DomExpressionsGenerate::default(),
+            delegate_events: true,
+        }
+    }
+}
+
+fn default_dom_expressions_module_name() -> String {
+    String::from("solid-js/web")
+}
@@ -10,8 +10,9 @@ use std::path::Path;
 use oxc_allocator::{Allocator, TakeIn, Vec as ArenaVec};
 use oxc_ast::{AstBuilder, ast::*};
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_semantic::Scoping;
+use oxc_semantic::{Scoping, SemanticBuilder};
 use oxc_span::SPAN;
+use oxc_syntax::module_record::ModuleRecord;
 use oxc_traverse::{Traverse, traverse_mut};
 
 // Core
@@ -38,6 +39,7 @@ mod regexp;
 mod typescript;
 
 mod decorator;
+mod modules;
 mod plugins;
 
 use common::Common;
@@ -53,6 +55,8 @@ use es2021::ES2021;
 use es2022::ES2022;
 use es2026::ES2026;
 use jsx::Jsx;
+use modules::{ModuleFeatures, Modules};
+use proposals::Proposals;
 use regexp::RegExp;
 use rustc_hash::FxHashMap;
 use state::TransformState;
@@ -63,7 +67,11 @@ pub use crate::{
     common::helper_loader::{Helper, HelperLoaderMode, HelperLoaderOptions},
     compiler_assumptions::CompilerAssumptions,
     decorator::DecoratorOptions,
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ES2015Options, ForOfOptions, ParametersOptions, SpreadOptions,
+        TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::ES2018Options,
@@ -72,14 +80,22 @@ pub use crate::{
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
     es2026::ES2026Options,
-    jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions},
+    jsx::{
+        DomExpressionsGenerate, DomExpressionsOptions, JsxOptions, JsxRuntime, ReactRefreshOptions,
+    },
+    modules::{CommonJsOptions, ImportInterop},
     options::{
         ESFeature, ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
         babel::{BabelEnvOptions, BabelOptions},
     },
-    plugins::{PluginsOptions, StyledComponentsOptions},
+    plugins::{
+        CoreJsPolyfillsOptions, EmotionAutoLabel, EmotionOptions, PluginsOptions,
+        StyledComponentsOptions,
+    },
     proposals::ProposalOptions,
-    typescript::{RewriteExtensionsMode, TypeScriptOptions},
+    typescript::{
+        ConstEnumMembers, ConstEnumValue, ConstEnums, RewriteExtensionsMode, TypeScriptOptions,
+    },
 };
 
 #[non_exhaustive]
@@ -100,8 +116,8 @@ pub struct Transformer<'a> {
     plugins: PluginsOptions,
     jsx: JsxOptions,
     env: EnvOptions,
-    #[expect(dead_code)]
     proposals: ProposalOptions,
+    module_features: Option<ModuleFeatures>,
 }
 
 impl<'a> Transformer<'a> {
@@ -116,9 +132,18 @@ impl<'a> Transformer<'a> {
             jsx: options.jsx.clone(),
             env: options.env,
             proposals: options.proposals,
+            module_features: None,
         }
     }
 
+    /// Use the [`ModuleRecord`] produced by the parser to determine which helpers the module
+    /// transforms need, instead of scanning the program.
+    #[must_use]
+    pub fn with_module_record(mut self, module_record: &ModuleRecord<'a>) -> Self {
+        self.module_features = Some(ModuleFeatures::from_module_record(module_record));
+        self
+    }
+
     pub fn build_with_scoping(
         mut self,
         scoping: Scoping,
@@ -137,17 +162,21 @@ impl<'a> Transformer<'a> {
                 &mut self.jsx,
                 &self.ctx,
             );
+            if let Some(emotion) = &self.plugins.emotion {
+                emotion.update_jsx_options(&mut self.jsx);
+            }
         }
 
         let mut transformer = TransformerImpl {
             common: Common::new(&self.env, &self.ctx),
             decorator: Decorator::new(self.decorator, &self.ctx),
-            plugins: Plugins::new(self.plugins, &self.ctx),
+            plugins: Plugins::new(self.plugins, self.jsx.development, &self.ctx),
             x0_typescript: program
                 .source_type
                 .is_typescript()
                 .then(|| TypeScript::new(&self.typescript, &self.ctx)),
             x1_jsx: Jsx::new(self.jsx, self.env.es2018.object_rest_spread, ast_builder, &self.ctx),
+            x2_proposals: Proposals::new(self.proposals, &self.ctx),
             x2_es2026: ES2026::new(self.env.es2026, &self.ctx),
             x2_es2022: ES2022::new(
                 self.env.es2022,
@@ -158,15 +187,25 @@ impl<'a> Transformer<'a> {
             x2_es2021: ES2021::new(self.env.es2021, &self.ctx),
             x2_es2020: ES2020::new(self.env.es2020, &self.ctx),
             x2_es2019: ES2019::new(self.env.es2019),
-            x2_es2018: ES2018::new(self.env.es2018, &self.ctx),
+            x2_es2018: ES2018::new(self.env.es2018, self.env.es2015.regenerator, &self.ctx),
             x2_es2016: ES2016::new(self.env.es2016, &self.ctx),
-            x2_es2017: ES2017::new(self.env.es2017, &self.ctx),
+            x2_es2017: ES2017::new(self.env.es2017, self.env.es2015.regenerator, &self.ctx),
             x3_es2015: ES2015::new(self.env.es2015, &self.ctx),
             x4_regexp: RegExp::new(self.env.regexp, &self.ctx),
+            x5_modules: Modules::new(
+                self.env.commonjs,
+                self.proposals,
+                self.module_features,
+                &self.ctx,
+            ),
         };
 
         let state = TransformState::default();
-        let scoping = traverse_mut(&mut transformer, allocator, program, scoping, state);
+        let mut scoping = traverse_mut(&mut transformer, allocator, program, scoping, state);
+        if self.ctx.helper_loader.insert_inline_helpers(program) {
+            // Inlined helpers' scopes and symbols are not in `scoping`
+            scoping = SemanticBuilder::new().build(program).semantic.into_scoping();
+        }
         let helpers_used = self.ctx.helper_loader.used_helpers.borrow_mut().drain().collect();
         #[expect(deprecated)]
         TransformerReturn { errors: self.ctx.take_errors(), scoping, helpers_used }
@@ -179,6 +218,7 @@ struct TransformerImpl<'a, 'ctx> {
     decorator: Decorator<'a, 'ctx>,
     plugins: Plugins<'a, 'ctx>,
     x1_jsx: Jsx<'a, 'ctx>,
+    x2_proposals: Proposals<'a, 'ctx>,
     x2_es2026: ES2026<'a, 'ctx>,
     x2_es2022: ES2022<'a, 'ctx>,
     x2_es2021: ES2021<'a, 'ctx>,
@@ -187,9 +227,9 @@ struct TransformerImpl<'a, 'ctx> {
     x2_es2018: ES2018<'a, 'ctx>,
     x2_es2017: ES2017<'a, 'ctx>,
     x2_es2016: ES2016<'a, 'ctx>,
-    #[expect(unused)]
     x3_es2015: ES2015<'a, 'ctx>,
     x4_regexp: RegExp<'a, 'ctx>,
+    x5_modules: Modules<'a, 'ctx>,
     common: Common<'a, 'ctx>,
 }
 
@@ -200,7 +240,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         }
         self.plugins.enter_program(program, ctx);
         self.x1_jsx.enter_program(program, ctx);
+        self.x2_proposals.enter_program(program, ctx);
         self.x2_es2026.enter_program(program, ctx);
+        self.x5_modules.enter_program(program, ctx);
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -213,6 +255,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2020.exit_program(program, ctx);
         self.x2_es2018.exit_program(program, ctx);
         self.common.exit_program(program, ctx);
+        // Lower `import`s and `export`s last, including the ones injected by other plugins.
+        self.x5_modules.exit_program(program, ctx);
     }
 
     // ALPHASORT
@@ -226,6 +270,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
             typescript.enter_arrow_function_expression(arrow, ctx);
         }
         self.x2_es2018.enter_arrow_function_expression(arrow, ctx);
+        self.x3_es2015.enter_arrow_function_expression(arrow, ctx);
     }
 
     fn enter_variable_declaration(
@@ -234,6 +279,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.x2_es2018.enter_variable_declaration(decl, ctx);
+        self.x3_es2015.enter_variable_declaration(decl, ctx);
     }
 
     fn enter_variable_declarator(
@@ -289,6 +335,24 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_identifier_reference(node, ctx);
+        self.plugins.enter_identifier_reference(node, ctx);
+        self.x3_es2015.enter_identifier_reference(node, ctx);
+    }
+
+    fn enter_static_member_expression(
+        &mut self,
+        expr: &mut StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.plugins.enter_static_member_expression(expr, ctx);
+    }
+
+    fn enter_computed_member_expression(
+        &mut self,
+        expr: &mut ComputedMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.plugins.enter_computed_member_expression(expr, ctx);
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -316,6 +380,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_class(class, ctx);
         }
+        self.x3_es2015.enter_class(class, ctx);
     }
 
     fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -350,11 +415,13 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
             typescript.enter_expression(expr, ctx);
         }
         self.plugins.enter_expression(expr, ctx);
+        self.x2_proposals.enter_expression(expr, ctx);
         self.x2_es2022.enter_expression(expr, ctx);
         self.x2_es2021.enter_expression(expr, ctx);
         self.x2_es2020.enter_expression(expr, ctx);
         self.x2_es2018.enter_expression(expr, ctx);
         self.x2_es2016.enter_expression(expr, ctx);
+        self.x3_es2015.enter_expression(expr, ctx);
         self.x4_regexp.enter_expression(expr, ctx);
     }
 
@@ -364,6 +431,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2022.exit_expression(expr, ctx);
         self.x2_es2018.exit_expression(expr, ctx);
         self.x2_es2017.exit_expression(expr, ctx);
+        self.x3_es2015.exit_expression(expr, ctx);
+        self.decorator.exit_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -416,6 +485,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_function(func, ctx);
         self.x2_es2018.enter_function(func, ctx);
+        self.x3_es2015.enter_function(func, ctx);
     }
 
     fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -425,6 +495,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x1_jsx.exit_function(func, ctx);
         self.x2_es2018.exit_function(func, ctx);
         self.x2_es2017.exit_function(func, ctx);
+        self.x3_es2015.exit_function(func, ctx);
         self.common.exit_function(func, ctx);
     }
 
@@ -491,6 +562,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.decorator.exit_method_definition(def, ctx);
     }
 
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.enter_object_property(prop, ctx);
+    }
+
     fn enter_new_expression(&mut self, expr: &mut NewExpression<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_new_expression(expr, ctx);
@@ -546,6 +621,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_statements(stmts, ctx);
         }
+        self.x3_es2015.enter_statements(stmts, ctx);
     }
 
     fn exit_arrow_function_expression(
@@ -604,6 +680,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.decorator.exit_statement(stmt, ctx);
         self.x2_es2018.exit_statement(stmt, ctx);
         self.x2_es2017.exit_statement(stmt, ctx);
+        self.x3_es2015.exit_statement(stmt, ctx);
     }
 
     fn enter_tagged_template_expression(
@@ -623,6 +700,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         }
         self.x2_es2018.enter_statement(stmt, ctx);
         self.x2_es2026.enter_statement(stmt, ctx);
+        self.x3_es2015.enter_statement(stmt, ctx);
     }
 
     fn enter_declaration(&mut self, decl: &mut Declaration<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -657,6 +735,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_for_statement(stmt, ctx);
     }
 
     fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -665,6 +744,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         }
         self.x2_es2026.enter_for_of_statement(stmt, ctx);
         self.x2_es2018.enter_for_of_statement(stmt, ctx);
+        self.x3_es2015.enter_for_of_statement(stmt, ctx);
     }
 
     fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -672,6 +752,15 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
             typescript.enter_for_in_statement(stmt, ctx);
         }
         self.x2_es2018.enter_for_in_statement(stmt, ctx);
+        self.x3_es2015.enter_for_in_statement(stmt, ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_switch_statement(stmt, ctx);
     }
 
     fn enter_try_statement(&mut self, stmt: &mut TryStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -681,6 +770,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x2_es2019.enter_catch_clause(clause, ctx);
         self.x2_es2018.enter_catch_clause(clause, ctx);
+        self.x3_es2015.enter_catch_clause(clause, ctx);
     }
 
     fn enter_import_declaration(
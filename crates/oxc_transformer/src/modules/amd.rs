@@ -0,0 +1,493 @@
+//! AMD
+//!
+//! This plugin is enabled with `Module::Amd`.
+//!
+//! Lower ES modules to the Asynchronous Module Definition format.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo, { bar } from "foo";
+//! export let x = foo;
+//! x = bar();
+//! ```
+//!
+//! Output:
+//! ```js
+//! define(["exports", "foo"], function (_exports, _foo) {
+//!   "use strict";
+//!
+//!   Object.defineProperty(_exports, "__esModule", { value: true });
+//!   _exports.x = void 0;
+//!   _foo = babelHelpers.interopRequireWildcard(_foo);
+//!   let x = _exports.x = _foo.default;
+//!   _exports.x = x = (0, _foo.bar)();
+//! });
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-modules-amd](https://babeljs.io/docs/babel-plugin-transform-modules-amd).
+//!
+//! The module body becomes the body of a factory function, which receives the exports object and
+//! the exports of each dependency as params.
+//!
+//! `import.meta.url` is resolved from the `uri` of RequireJS's `module`,
+//! and `import()` is lowered to a `require` with callbacks.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-modules-amd/src/index.ts>
+//! * AMD specification: <https://github.com/amdjs/amdjs-api/blob/master/AMD.md>
+
+use std::mem;
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{Atom, SPAN, Span};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    utils::ast_builder::create_assignment,
+};
+
+use super::{
+    commonjs::ImportInterop,
+    diagnostics,
+    exports::{build_exports_header, export_declarations},
+    live_bindings::{LiveBindings, ModuleFormat},
+    metadata::ModuleMetadata,
+    utils::{
+        Exporter, create_function_scope, function_expression, member, object, string_array,
+        var_declaration, var_declaration_with_init,
+    },
+};
+
+pub fn transform_program<'a>(
+    program: &mut Program<'a>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let factory = Factory::build(program, false, transform_ctx, ctx);
+
+    // `define(["exports", "foo"], function (_exports, _foo) { ... });`
+    let mut arguments = ctx.ast.vec_with_capacity(2);
+    let dependencies = factory.dependency_names();
+    if !dependencies.is_empty() {
+        arguments.push(Argument::from(string_array(dependencies, ctx)));
+    }
+    arguments.push(Argument::from(factory.function));
+    let define = ctx.create_unbound_ident_expr(SPAN, Atom::from("define"), ReferenceFlags::Read);
+    let call = ctx.ast.expression_call(SPAN, define, NONE, arguments, false);
+    program.body.push(ctx.ast.statement_expression(SPAN, call));
+}
+
+/// What a param of the factory function receives.
+pub enum FactoryParam<'a> {
+    Require,
+    Exports,
+    Module,
+    Dependency(Atom<'a>),
+}
+
+/// The module body, wrapped in a factory function.
+pub struct Factory<'a> {
+    /// Params of the factory function, with what each of them receives.
+    pub params: Vec<(FactoryParam<'a>, BoundIdentifier<'a>)>,
+    /// Dependencies which are only imported for their side effects, and have no param.
+    pub side_effect_dependencies: Vec<Atom<'a>>,
+    pub function: Expression<'a>,
+}
+
+impl<'a> Factory<'a> {
+    /// Names of dependencies for `define`, in order of params.
+    pub fn dependency_names(&self) -> Vec<Atom<'a>> {
+        self.params
+            .iter()
+            .map(|(param, _)| match param {
+                FactoryParam::Require => Atom::from("require"),
+                FactoryParam::Exports => Atom::from("exports"),
+                FactoryParam::Module => Atom::from("module"),
+                FactoryParam::Dependency(source) => *source,
+            })
+            .chain(self.side_effect_dependencies.iter().copied())
+            .collect()
+    }
+
+    /// Move the body of `program` into a factory function.
+    ///
+    /// `program.body` is left empty, to be filled by the caller with the wrapper.
+    pub fn build(
+        program: &mut Program<'a>,
+        is_umd: bool,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Self {
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let scope_id = create_function_scope(root_scope_id, ctx);
+
+        let exports = ctx.generate_uid("exports", scope_id, SymbolFlags::FunctionScopedVariable);
+        let exporter = Exporter::Object(exports.to_maybe_bound_identifier());
+        let mut metadata = ModuleMetadata::collect(program, &exporter, true, transform_ctx, ctx);
+
+        // Imports are replaced with references to their dependency's binding
+        for &symbol_id in metadata.imports.keys() {
+            let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+            ctx.scoping_mut().remove_binding(root_scope_id, &name);
+        }
+        move_root_bindings(scope_id, ctx);
+        move_child_scopes(&program.body, scope_id, ctx);
+        export_declarations(&mut program.body, &metadata, &exporter, ctx);
+
+        metadata.create_dependency_bindings(scope_id, ctx);
+
+        let mut format = AmdFormat::new(is_umd, scope_id, transform_ctx);
+        let mut live_bindings =
+            LiveBindings::new(&metadata, &exporter, &mut format, true, scope_id, ctx);
+        live_bindings.visit_statements(&mut program.body);
+        let temps = mem::take(&mut live_bindings.temps);
+
+        let has_exports = metadata.has_exports();
+        if !has_exports {
+            ctx.scoping_mut().remove_binding(scope_id, &exports.name);
+        }
+
+        let mut statements = ctx.ast.vec();
+        if !temps.is_empty() {
+            statements.push(var_declaration(&temps, ctx));
+        }
+        let module = format.import_meta_declaration(scope_id, &mut statements, ctx);
+        if has_exports {
+            build_exports_header(
+                &metadata,
+                &exports.to_maybe_bound_identifier(),
+                scope_id,
+                &mut statements,
+                |statements, ctx| Self::build_interop(&metadata, statements, transform_ctx, ctx),
+                ctx,
+            );
+        } else {
+            Self::build_interop(&metadata, &mut statements, transform_ctx, ctx);
+        }
+        statements.extend(program.body.take_in(ctx.ast));
+
+        // Params: `require`, `exports`, `module`, then dependencies in order
+        let mut params = vec![];
+        if let Some(require) = format.require.take() {
+            params.push((FactoryParam::Require, require));
+        }
+        if has_exports {
+            params.push((FactoryParam::Exports, exports));
+        }
+        if let Some(module) = module {
+            params.push((FactoryParam::Module, module));
+        }
+        let mut side_effect_dependencies = vec![];
+        for dependency in metadata.dependencies {
+            match dependency.binding {
+                Some(binding) => {
+                    params.push((FactoryParam::Dependency(dependency.source), binding));
+                }
+                None => side_effect_dependencies.push(dependency.source),
+            }
+        }
+
+        let mut directives = ctx.ast.vec1(ctx.ast.use_strict_directive());
+        directives
+            .extend(program.directives.take_in(ctx.ast).into_iter().filter(|d| !d.is_use_strict()));
+        let param_bindings = params.iter().map(|(_, binding)| binding.clone()).collect::<Vec<_>>();
+        let function = function_expression(scope_id, &param_bindings, directives, statements, ctx);
+
+        Self { params, side_effect_dependencies, function }
+    }
+
+    /// `_dep = babelHelpers.interopRequireDefault(_dep);`
+    fn build_interop(
+        metadata: &ModuleMetadata<'a>,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for dependency in &metadata.dependencies {
+            let (Some(binding), Some(helper)) =
+                (&dependency.binding, dependency.interop(ImportInterop::Babel))
+            else {
+                continue;
+            };
+            let arguments = ctx.ast.vec1(Argument::from(binding.create_read_expression(ctx)));
+            let call = transform_ctx.helper_call_expr(helper, SPAN, arguments, ctx);
+            let assignment = create_assignment(binding, call, ctx);
+            statements.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+    }
+}
+
+/// Move bindings in the root scope to the scope of the function which wraps the module.
+pub fn move_root_bindings(scope_id: ScopeId, ctx: &mut TraverseCtx<'_>) {
+    let root_scope_id = ctx.scoping().root_scope_id();
+    let bindings = ctx
+        .scoping()
+        .get_bindings(root_scope_id)
+        .iter()
+        .map(|(name, symbol_id)| (ctx.ast.atom(name), *symbol_id))
+        .collect::<Vec<_>>();
+    let scoping = ctx.scoping_mut();
+    for (name, symbol_id) in bindings {
+        scoping.move_binding(root_scope_id, scope_id, &name);
+        scoping.set_symbol_scope_id(symbol_id, scope_id);
+    }
+}
+
+/// Change the parent of scopes in `statements`, which are at the top level of a program,
+/// to `scope_id`.
+pub fn move_child_scopes<'a>(
+    statements: &[Statement<'a>],
+    scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let mut mover = ChildScopeMover { ctx, scope_id, depth: 0 };
+    for stmt in statements {
+        mover.visit_statement(stmt);
+    }
+}
+
+/// [`ModuleFormat`] for AMD and UMD.
+pub struct AmdFormat<'a, 'ctx> {
+    is_umd: bool,
+    scope_id: ScopeId,
+    /// `require` param, for `import()`. AMD only.
+    pub require: Option<BoundIdentifier<'a>>,
+    /// `var _importMeta = { url: ... }`
+    import_meta: Option<BoundIdentifier<'a>>,
+    reported_top_level_await: bool,
+    transform_ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> AmdFormat<'a, 'ctx> {
+    pub fn new(is_umd: bool, scope_id: ScopeId, transform_ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            is_umd,
+            scope_id,
+            require: None,
+            import_meta: None,
+            reported_top_level_await: false,
+            transform_ctx,
+        }
+    }
+
+    fn format_name(&self) -> &'static str {
+        if self.is_umd { "UMD" } else { "AMD" }
+    }
+
+    /// `var _importMeta = { url: new URL(_module.uri, document.baseURI).href };`
+    ///
+    /// Returns the `module` param, if one is needed.
+    fn import_meta_declaration(
+        &mut self,
+        scope_id: ScopeId,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<BoundIdentifier<'a>> {
+        let import_meta = self.import_meta.take()?;
+        let (url, module) = if self.is_umd {
+            (self.umd_import_meta_url(ctx), None)
+        } else {
+            let module = ctx.generate_uid("module", scope_id, SymbolFlags::FunctionScopedVariable);
+            let uri = Expression::from(member(
+                module.create_read_expression(ctx),
+                Atom::from("uri"),
+                ctx,
+            ));
+            (Self::resolve_url(uri, ctx), Some(module))
+        };
+        let init = object([("url", url)], ctx);
+        statements.push(var_declaration_with_init(&import_meta, init, ctx));
+        module
+    }
+
+    /// `new URL(url, document.baseURI).href`
+    fn resolve_url(url: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let callee = ctx.create_unbound_ident_expr(SPAN, Atom::from("URL"), ReferenceFlags::Read);
+        let document =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("document"), ReferenceFlags::Read);
+        let base = Expression::from(member(document, Atom::from("baseURI"), ctx));
+        let arguments = ctx.ast.vec_from_array([Argument::from(url), Argument::from(base)]);
+        let new_url = ctx.ast.expression_new(SPAN, callee, NONE, arguments);
+        Expression::from(member(new_url, Atom::from("href"), ctx))
+    }
+
+    /// ```js
+    /// typeof document === "undefined"
+    ///   ? require("url").pathToFileURL(__filename).href
+    ///   : document.currentScript && document.currentScript.src || new URL("input.js", document.baseURI).href
+    /// ```
+    fn umd_import_meta_url(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let test = super::utils::compare_string(
+            super::utils::typeof_global("document", ctx),
+            BinaryOperator::StrictEquality,
+            "undefined",
+            ctx,
+        );
+
+        let require =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("require"), ReferenceFlags::Read);
+        let arguments = ctx.ast.vec1(Argument::from(ctx.ast.expression_string_literal(
+            SPAN,
+            Atom::from("url"),
+            None,
+        )));
+        let url_module = ctx.ast.expression_call(SPAN, require, NONE, arguments, false);
+        let callee = Expression::from(member(url_module, Atom::from("pathToFileURL"), ctx));
+        let filename =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("__filename"), ReferenceFlags::Read);
+        let arguments = ctx.ast.vec1(Argument::from(filename));
+        let file_url = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        let node_url = Expression::from(member(file_url, Atom::from("href"), ctx));
+
+        let current_script = |ctx: &mut TraverseCtx<'a>| {
+            let document =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("document"), ReferenceFlags::Read);
+            Expression::from(member(document, Atom::from("currentScript"), ctx))
+        };
+        let script = current_script(ctx);
+        let script_src = current_script(ctx);
+        let script_src = Expression::from(member(script_src, Atom::from("src"), ctx));
+        let script_url = ctx.ast.expression_logical(SPAN, script, LogicalOperator::And, script_src);
+        let file_name = self
+            .transform_ctx
+            .source_path
+            .file_name()
+            .map_or_else(|| String::from("unknown"), |name| name.to_string_lossy().to_string());
+        let file_name = ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&file_name), None);
+        let file_url = Self::resolve_url(file_name, ctx);
+        let browser_url =
+            ctx.ast.expression_logical(SPAN, script_url, LogicalOperator::Or, file_url);
+
+        ctx.ast.expression_conditional(SPAN, test, node_url, browser_url)
+    }
+}
+
+impl<'a> ModuleFormat<'a> for AmdFormat<'a, '_> {
+    fn import_meta(&mut self, span: Span, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let scope_id = self.scope_id;
+        let import_meta = self.import_meta.get_or_insert_with(|| {
+            ctx.generate_uid("importMeta", scope_id, SymbolFlags::FunctionScopedVariable)
+        });
+        import_meta.create_spanned_read_expression(span, ctx)
+    }
+
+    /// `import(source)` ->
+    /// ```js
+    /// new Promise(function (_resolve, _reject) {
+    ///   return _require([source], function (imported) {
+    ///     return _resolve(babelHelpers.interopRequireWildcard(imported));
+    ///   }, _reject);
+    /// })
+    /// ```
+    fn dynamic_import(
+        &mut self,
+        mut expr: ArenaBox<'a, ImportExpression<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if self.is_umd {
+            self.transform_ctx.error(diagnostics::dynamic_import_not_supported("UMD", expr.span));
+            return Expression::ImportExpression(expr);
+        }
+        let factory_scope_id = self.scope_id;
+        let require = self
+            .require
+            .get_or_insert_with(|| {
+                ctx.generate_uid("require", factory_scope_id, SymbolFlags::FunctionScopedVariable)
+            })
+            .clone();
+
+        let executor_scope_id = create_function_scope(scope_id, ctx);
+        let resolve =
+            ctx.generate_uid("resolve", executor_scope_id, SymbolFlags::FunctionScopedVariable);
+        let reject =
+            ctx.generate_uid("reject", executor_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        let callback_scope_id = create_function_scope(executor_scope_id, ctx);
+        let imported = ctx.generate_binding(
+            Atom::from("imported"),
+            callback_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let arguments = ctx.ast.vec1(Argument::from(imported.create_read_expression(ctx)));
+        let interop = self.transform_ctx.helper_call_expr(
+            crate::Helper::InteropRequireWildcard,
+            SPAN,
+            arguments,
+            ctx,
+        );
+        let arguments = ctx.ast.vec1(Argument::from(interop));
+        let resolved = ctx.ast.expression_call(
+            SPAN,
+            resolve.create_read_expression(ctx),
+            NONE,
+            arguments,
+            false,
+        );
+        let body = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(resolved)));
+        let callback =
+            function_expression(callback_scope_id, &[imported], ctx.ast.vec(), body, ctx);
+
+        let source = expr.source.take_in(ctx.ast);
+        let sources =
+            ctx.ast.expression_array(SPAN, ctx.ast.vec1(ArrayExpressionElement::from(source)));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(sources),
+            Argument::from(callback),
+            Argument::from(reject.create_read_expression(ctx)),
+        ]);
+        let required = ctx.ast.expression_call(
+            SPAN,
+            require.create_read_expression(ctx),
+            NONE,
+            arguments,
+            false,
+        );
+        let body = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(required)));
+        let executor =
+            function_expression(executor_scope_id, &[resolve, reject], ctx.ast.vec(), body, ctx);
+
+        let promise =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Promise"), ReferenceFlags::Read);
+        let arguments = ctx.ast.vec1(Argument::from(executor));
+        ctx.ast.expression_new(expr.span, promise, NONE, arguments)
+    }
+
+    fn top_level_await(&mut self, span: Span) {
+        if !self.reported_top_level_await {
+            self.reported_top_level_await = true;
+            let format = self.format_name();
+            self.transform_ctx.error(diagnostics::top_level_await_not_supported(format, span));
+        }
+    }
+}
+
+/// Change the parent of scopes at the top level of a program to the factory function's scope.
+struct ChildScopeMover<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+    scope_id: ScopeId,
+    depth: usize,
+}
+
+impl<'a> Visit<'a> for ChildScopeMover<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &std::cell::Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            let scope_id = scope_id.get().unwrap();
+            self.ctx.scoping_mut().change_scope_parent_id(scope_id, Some(self.scope_id));
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
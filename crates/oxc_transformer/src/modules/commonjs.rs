@@ -0,0 +1,417 @@
+//! CommonJS
+//!
+//! This plugin is enabled with `Module::CommonJS`.
+//!
+//! Lower ES modules to CommonJS, as used by Node.js.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo, { bar } from "foo";
+//! export let x = foo;
+//! x = bar();
+//! ```
+//!
+//! Output:
+//! ```js
+//! "use strict";
+//!
+//! Object.defineProperty(exports, "__esModule", { value: true });
+//! exports.x = void 0;
+//! var _foo = babelHelpers.interopRequireWildcard(require("foo"));
+//! let x = exports.x = _foo.default;
+//! exports.x = x = (0, _foo.bar)();
+//! ```
+//!
+//! ## Options
+//!
+//! * `strictMode`: Add a `"use strict"` directive. Defaults to `true`.
+//! * `lazy`: `require` dependencies when an imported binding is first used, instead of when the
+//!   module is loaded. Local dependencies (`./foo`) are never lazy.
+//! * `importInterop`: How the exports of a dependency are interpreted.
+//!   * `"babel"`: Dependencies which are not ES modules compiled by Babel have their
+//!     `module.exports` as the default export.
+//!   * `"node"`: The same as Node.js importing CommonJS from an ES module. `module.exports` is
+//!     always the default export.
+//!   * `"none"`: Dependencies are always treated as ES modules compiled by Babel.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-modules-commonjs](https://babeljs.io/docs/babel-plugin-transform-modules-commonjs).
+//!
+//! `import()` is lowered to a `require` which runs once the returned promise resolves.
+//! With the `import_defer` proposal enabled, `import defer * as ns from "x"` is loaded lazily,
+//! the same as with the `lazy` option.
+//! Top-level `await` cannot be lowered, and is reported as an error.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-modules-commonjs/src/index.ts>
+
+use std::mem;
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::VisitMut;
+use oxc_semantic::{ReferenceFlags, ScopeId, SymbolFlags};
+use oxc_span::{Atom, SPAN, Span};
+use oxc_traverse::{BoundIdentifier, MaybeBoundIdentifier};
+use serde::Deserialize;
+
+use crate::{
+    Helper,
+    context::{TransformCtx, TraverseCtx},
+    utils::ast_builder::create_assignment,
+};
+
+use super::{
+    diagnostics,
+    exports::{build_exports_header, export_declarations},
+    live_bindings::{LiveBindings, ModuleFormat},
+    metadata::{Dependency, ModuleMetadata},
+    utils::{Exporter, create_function_scope, function_expression, member, var_declaration},
+};
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CommonJsOptions {
+    /// Add a `"use strict"` directive to the output.
+    pub strict_mode: bool,
+    /// `require` non-local dependencies when an imported binding is first used.
+    pub lazy: bool,
+    pub import_interop: ImportInterop,
+}
+
+impl Default for CommonJsOptions {
+    fn default() -> Self {
+        Self { strict_mode: true, lazy: false, import_interop: ImportInterop::default() }
+    }
+}
+
+/// How the exports of a dependency are interpreted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportInterop {
+    /// `module.exports` is the default export, unless the dependency has an `__esModule` marker.
+    #[default]
+    Babel,
+    /// `module.exports` is always the default export, as in Node.js.
+    Node,
+    /// Dependencies are always ES modules compiled to CommonJS.
+    None,
+}
+
+pub fn transform_program<'a>(
+    program: &mut Program<'a>,
+    options: CommonJsOptions,
+    import_defer: bool,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let root_scope_id = ctx.scoping().root_scope_id();
+    let exports = MaybeBoundIdentifier::new(Atom::from("exports"), None);
+    let exporter = Exporter::Object(exports.clone());
+    // Lazy dependencies are functions, so namespace imports can't be their binding
+    let mut metadata =
+        ModuleMetadata::collect(program, &exporter, !options.lazy, transform_ctx, ctx);
+    if options.import_interop == ImportInterop::Node {
+        metadata.use_node_interop();
+    }
+    if options.lazy || import_defer {
+        metadata.mark_lazy_dependencies(|dependency| {
+            (import_defer && dependency.is_deferred())
+                || (options.lazy && !dependency.source.starts_with('.'))
+        });
+    }
+
+    // Imports are replaced with references to their dependency's binding
+    for &symbol_id in metadata.imports.keys() {
+        let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+        ctx.scoping_mut().remove_binding(root_scope_id, &name);
+    }
+    export_declarations(&mut program.body, &metadata, &exporter, ctx);
+
+    metadata.create_dependency_bindings(root_scope_id, ctx);
+    for dependency in metadata.dependencies.iter().filter(|dependency| dependency.lazy) {
+        let symbol_id = dependency.binding.as_ref().unwrap().symbol_id;
+        *ctx.scoping_mut().symbol_flags_mut(symbol_id) = SymbolFlags::Function;
+    }
+
+    let mut format = CommonJsFormat::new(options.import_interop, transform_ctx);
+    let mut live_bindings =
+        LiveBindings::new(&metadata, &exporter, &mut format, true, root_scope_id, ctx);
+    live_bindings.visit_statements(&mut program.body);
+    let temps = mem::take(&mut live_bindings.temps);
+
+    let mut statements = ctx.ast.vec();
+    if !temps.is_empty() {
+        statements.push(var_declaration(&temps, ctx));
+    }
+    let require_dependencies = |statements: &mut ArenaVec<'a, Statement<'a>>,
+                                ctx: &mut TraverseCtx<'a>| {
+        build_requires(
+            &metadata,
+            options.import_interop,
+            root_scope_id,
+            statements,
+            transform_ctx,
+            ctx,
+        );
+    };
+    if metadata.has_exports() {
+        build_exports_header(
+            &metadata,
+            &exports,
+            root_scope_id,
+            &mut statements,
+            require_dependencies,
+            ctx,
+        );
+    } else {
+        require_dependencies(&mut statements, ctx);
+    }
+    statements.extend(program.body.take_in(ctx.ast));
+    program.body = statements;
+
+    if options.strict_mode && !program.has_use_strict_directive() {
+        program.directives.insert(0, ctx.ast.use_strict_directive());
+    }
+}
+
+/// ```js
+/// require("side-effect");
+/// var _foo = babelHelpers.interopRequireDefault(require("foo"));
+/// function _lazy() { ... }
+/// ```
+fn build_requires<'a>(
+    metadata: &ModuleMetadata<'a>,
+    import_interop: ImportInterop,
+    scope_id: ScopeId,
+    statements: &mut ArenaVec<'a, Statement<'a>>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    for dependency in &metadata.dependencies {
+        let source = ctx.ast.expression_string_literal(SPAN, dependency.source, None);
+        let Some(binding) = &dependency.binding else {
+            statements.push(ctx.ast.statement_expression(SPAN, require(source, ctx)));
+            continue;
+        };
+        let init = interop_require(dependency, import_interop, source, transform_ctx, ctx);
+        if dependency.lazy {
+            statements.push(lazy_require(binding, init, scope_id, ctx));
+            continue;
+        }
+        // Binding can be a namespace import, which keeps the span of its declaration
+        let span = ctx.scoping().symbol_span(binding.symbol_id);
+        let id = ctx.ast.binding_identifier_with_symbol_id(span, binding.name, binding.symbol_id);
+        let kind = VariableDeclarationKind::Var;
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            BindingPattern::BindingIdentifier(ctx.ast.alloc(id)),
+            NONE,
+            Some(init),
+            false,
+        );
+        statements.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        )));
+    }
+}
+
+/// `babelHelpers.interopRequireDefault(require("foo"))`
+fn interop_require<'a>(
+    dependency: &Dependency<'a>,
+    import_interop: ImportInterop,
+    source: Expression<'a>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let required = require(source, ctx);
+    match dependency.interop(import_interop) {
+        Some(helper) => interop(helper, import_interop, required, transform_ctx, ctx),
+        None => required,
+    }
+}
+
+/// `babelHelpers.interopRequireWildcard(value)`, or `babelHelpers.interopRequireWildcard(value, true)`
+/// with Node.js interop.
+fn interop<'a>(
+    helper: Helper,
+    import_interop: ImportInterop,
+    value: Expression<'a>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let mut arguments = ctx.ast.vec1(Argument::from(value));
+    if import_interop == ImportInterop::Node {
+        arguments.push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+    }
+    transform_ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+}
+
+/// `require(source)`
+fn require<'a>(source: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let callee = ctx.create_unbound_ident_expr(SPAN, Atom::from("require"), ReferenceFlags::Read);
+    ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(Argument::from(source)), false)
+}
+
+/// ```js
+/// function _foo() {
+///   const data = require("foo");
+///   _foo = function () {
+///     return data;
+///   };
+///   return data;
+/// }
+/// ```
+fn lazy_require<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Expression<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let scope_id = create_function_scope(parent_scope_id, ctx);
+    let data = ctx.generate_binding(
+        Atom::from("data"),
+        scope_id,
+        SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+    );
+
+    let kind = VariableDeclarationKind::Const;
+    let declarator = ctx.ast.variable_declarator(
+        SPAN,
+        kind,
+        data.create_binding_pattern(ctx),
+        NONE,
+        Some(init),
+        false,
+    );
+    let declaration = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ));
+
+    let getter_scope_id = create_function_scope(scope_id, ctx);
+    let getter_body =
+        ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(data.create_read_expression(ctx))));
+    let getter = function_expression(getter_scope_id, &[], ctx.ast.vec(), getter_body, ctx);
+    let replace = ctx.ast.statement_expression(SPAN, create_assignment(binding, getter, ctx));
+
+    let result = ctx.ast.statement_return(SPAN, Some(data.create_read_expression(ctx)));
+    let body = ctx.ast.function_body(
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.vec_from_array([declaration, replace, result]),
+    );
+    let params =
+        ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, ctx.ast.vec(), NONE);
+    Statement::FunctionDeclaration(ctx.ast.alloc_plain_function_with_scope_id(
+        FunctionType::FunctionDeclaration,
+        SPAN,
+        Some(binding.create_binding_identifier(ctx)),
+        params,
+        body,
+        scope_id,
+    ))
+}
+
+/// [`ModuleFormat`] for CommonJS.
+struct CommonJsFormat<'a, 'ctx> {
+    import_interop: ImportInterop,
+    reported_top_level_await: bool,
+    transform_ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> CommonJsFormat<'a, 'ctx> {
+    fn new(import_interop: ImportInterop, transform_ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { import_interop, reported_top_level_await: false, transform_ctx }
+    }
+}
+
+impl<'a> ModuleFormat<'a> for CommonJsFormat<'a, '_> {
+    /// `import.meta` has no equivalent in CommonJS, and is left as is.
+    fn import_meta(&mut self, span: Span, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let meta = ctx.ast.identifier_name(SPAN, Atom::from("import"));
+        let property = ctx.ast.identifier_name(SPAN, Atom::from("meta"));
+        ctx.ast.expression_meta_property(span, meta, property)
+    }
+
+    /// * `import("foo")` ->
+    ///   `Promise.resolve().then(function () { return babelHelpers.interopRequireWildcard(require("foo")); })`
+    /// * `import(source)` ->
+    ///   ``Promise.resolve(`${source}`).then(function (s) { return babelHelpers.interopRequireWildcard(require(s)); })``
+    fn dynamic_import(
+        &mut self,
+        mut expr: ArenaBox<'a, ImportExpression<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let source = expr.source.take_in(ctx.ast);
+        let callback_scope_id = create_function_scope(scope_id, ctx);
+        let is_static = match &source {
+            Expression::StringLiteral(_) => true,
+            Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+            _ => false,
+        };
+        // The source is converted to a string before the `require`, as `import()` does
+        let (resolved, params, source) = if is_static {
+            (None, vec![], source)
+        } else {
+            let specifier = ctx.generate_binding(
+                Atom::from("s"),
+                callback_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let template = if matches!(source, Expression::TemplateLiteral(_)) {
+                source
+            } else {
+                let quasis = ctx.ast.vec_from_array([false, true].map(|tail| {
+                    let value =
+                        TemplateElementValue { raw: Atom::from(""), cooked: Some(Atom::from("")) };
+                    ctx.ast.template_element(SPAN, value, tail)
+                }));
+                ctx.ast.expression_template_literal(SPAN, quasis, ctx.ast.vec1(source))
+            };
+            let read = specifier.create_read_expression(ctx);
+            (Some(template), vec![specifier], read)
+        };
+
+        let mut required = require(source, ctx);
+        if self.import_interop != ImportInterop::None {
+            required = interop(
+                Helper::InteropRequireWildcard,
+                self.import_interop,
+                required,
+                self.transform_ctx,
+                ctx,
+            );
+        }
+        let body = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(required)));
+        let callback = function_expression(callback_scope_id, &params, ctx.ast.vec(), body, ctx);
+
+        // `Promise.resolve(resolved).then(callback)`
+        let promise =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Promise"), ReferenceFlags::Read);
+        let resolve = Expression::from(member(promise, Atom::from("resolve"), ctx));
+        let arguments = ctx.ast.vec_from_iter(resolved.map(Argument::from));
+        let resolved = ctx.ast.expression_call(SPAN, resolve, NONE, arguments, false);
+        let then = Expression::from(member(resolved, Atom::from("then"), ctx));
+        let arguments = ctx.ast.vec1(Argument::from(callback));
+        ctx.ast.expression_call(expr.span, then, NONE, arguments, false)
+    }
+
+    fn top_level_await(&mut self, span: Span) {
+        if !self.reported_top_level_await {
+            self.reported_top_level_await = true;
+            self.transform_ctx.error(diagnostics::top_level_await_not_supported("CommonJS", span));
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+#[cold]
+pub fn top_level_await_not_supported(format: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Top-level await is not supported in {format} modules."))
+        .with_label(span)
+        .with_help("Use the SystemJS or ES module format to preserve top-level await.")
+}
+
+#[cold]
+pub fn dynamic_import_not_supported(format: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Dynamic import() is not supported in {format} modules."))
+        .with_label(span)
+}
@@ -0,0 +1,292 @@
+//! Exports of a module which is lowered to a format with an exports object (AMD, UMD, CommonJS).
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeId, SymbolFlags};
+use oxc_span::{Atom, SPAN};
+use oxc_traverse::{BoundIdentifier, MaybeBoundIdentifier};
+
+use crate::context::TraverseCtx;
+
+use super::{
+    metadata::ModuleMetadata,
+    utils::{
+        Exporter, create_function_scope, define_property, function_expression, getter_descriptor,
+        member, object, var_declaration_with_init,
+    },
+};
+
+/// ```js
+/// Object.defineProperty(_exports, "__esModule", { value: true });
+/// var _exportNames = { a: true };
+/// Object.defineProperty(_exports, "b", { enumerable: true, get: function () { return _dep.b; } });
+/// _exports.a = void 0;
+/// _exports.f = f;
+/// _dep = babelHelpers.interopRequireDefault(_dep);    <- `init_dependencies`
+/// _exports.ns = _dep;
+/// Object.keys(_dep).forEach(function (key) { ... });
+/// ```
+///
+/// `init_dependencies` adds the statements which initialize the bindings of dependencies,
+/// which must run after local exports are initialized, but before namespace re-exports.
+pub fn build_exports_header<'a>(
+    metadata: &ModuleMetadata<'a>,
+    exports: &MaybeBoundIdentifier<'a>,
+    scope_id: ScopeId,
+    statements: &mut ArenaVec<'a, Statement<'a>>,
+    init_dependencies: impl FnOnce(&mut ArenaVec<'a, Statement<'a>>, &mut TraverseCtx<'a>),
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let exporter = Exporter::Object(exports.clone());
+
+    let descriptor = object([("value", ctx.ast.expression_boolean_literal(SPAN, true))], ctx);
+    let expr = define_property(
+        exports.create_read_expression(ctx),
+        Atom::from("__esModule"),
+        descriptor,
+        ctx,
+    );
+    statements.push(ctx.ast.statement_expression(SPAN, expr));
+
+    let export_names = (!metadata.star_exports.is_empty())
+        .then(|| {
+            let names = metadata.exported_names().collect::<Vec<_>>();
+            (!names.is_empty()).then(|| {
+                let binding =
+                    ctx.generate_uid("exportNames", scope_id, SymbolFlags::FunctionScopedVariable);
+                let properties = ctx.ast.vec_from_iter(names.into_iter().map(|name| {
+                    ctx.ast.object_property_kind_object_property(
+                        SPAN,
+                        PropertyKind::Init,
+                        ctx.ast.property_key_static_identifier(SPAN, name),
+                        ctx.ast.expression_boolean_literal(SPAN, true),
+                        false,
+                        false,
+                        false,
+                    )
+                }));
+                let init = ctx.ast.expression_object(SPAN, properties);
+                statements.push(var_declaration_with_init(&binding, init, ctx));
+                binding
+            })
+        })
+        .flatten();
+
+    // Re-exports are getters, so they're live
+    for (name, import) in &metadata.reexports {
+        let Some(property) = import.imported.property() else { continue };
+        let dependency = metadata.dependencies[import.dependency].binding.as_ref().unwrap();
+        let value = dependency.create_read_expression(ctx);
+        let value = Expression::from(member(value, property, ctx));
+        let descriptor = getter_descriptor(value, scope_id, ctx);
+        let expr = define_property(exports.create_read_expression(ctx), *name, descriptor, ctx);
+        statements.push(ctx.ast.statement_expression(SPAN, expr));
+    }
+
+    // `_exports.a = _exports.b = void 0;`
+    let uninitialized = metadata
+        .local_export_names
+        .iter()
+        .filter(|(_, symbol_id)| !ctx.scoping().symbol_flags(*symbol_id).is_function())
+        .map(|(name, _)| *name)
+        .chain(metadata.has_default_expression.then(|| Atom::from("default")))
+        .collect::<Vec<_>>();
+    if !uninitialized.is_empty() {
+        statements.push(exporter.export_statement(&uninitialized, ctx.ast.void_0(SPAN), ctx));
+    }
+
+    // Function declarations are hoisted, so they're exported before anything else runs.
+    // `_exports.f = f;`
+    for (name, symbol_id) in &metadata.local_export_names {
+        if ctx.scoping().symbol_flags(*symbol_id).is_function() {
+            let symbol_name = ctx.scoping().symbol_name(*symbol_id);
+            let binding = BoundIdentifier::new(ctx.ast.atom(symbol_name), *symbol_id);
+            let value = binding.create_read_expression(ctx);
+            statements.push(exporter.export_statement(&[*name], value, ctx));
+        }
+    }
+
+    init_dependencies(statements, ctx);
+
+    // `_exports.ns = _dep;`
+    for (name, import) in &metadata.reexports {
+        if import.imported.property().is_none() {
+            let dependency = metadata.dependencies[import.dependency].binding.as_ref().unwrap();
+            let value = dependency.create_read_expression(ctx);
+            statements.push(exporter.export_statement(&[*name], value, ctx));
+        }
+    }
+
+    for &dependency in &metadata.star_exports {
+        let dependency = metadata.dependencies[dependency].binding.as_ref().unwrap();
+        statements.push(star_export_loop(
+            dependency,
+            exports,
+            export_names.as_ref(),
+            scope_id,
+            ctx,
+        ));
+    }
+}
+
+/// Export top level class and variable declarations once they're initialized.
+///
+/// * `class C {}` -> `class C {} _exports.C = C;`
+/// * `let x = 1;` -> `let x = _exports.x = 1;`
+/// * `let { a } = obj;` -> `let { a } = obj; _exports.a = a;`
+pub fn export_declarations<'a>(
+    body: &mut ArenaVec<'a, Statement<'a>>,
+    metadata: &ModuleMetadata<'a>,
+    exporter: &Exporter<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    if metadata.local_exports.is_empty() {
+        return;
+    }
+    let old_body = body.take_in(ctx.ast);
+    for mut stmt in old_body {
+        let mut exports = vec![];
+        match &mut stmt {
+            Statement::ClassDeclaration(class) => {
+                if let Some(id) = &class.id {
+                    exports.push(BoundIdentifier::from_binding_ident(id));
+                }
+            }
+            Statement::VariableDeclaration(decl) => {
+                for declarator in &mut decl.declarations {
+                    if let BindingPattern::BindingIdentifier(id) = &declarator.id {
+                        let names = metadata.local_exports.get(&id.symbol_id());
+                        if let (Some(names), Some(init)) = (names, &mut declarator.init) {
+                            *init = exporter.export(names, init.take_in(ctx.ast), ctx);
+                        }
+                    } else {
+                        declarator.id.bound_names(&mut |ident| {
+                            exports.push(BoundIdentifier::from_binding_ident(ident));
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+        body.push(stmt);
+        for binding in exports {
+            if let Some(names) = metadata.local_exports.get(&binding.symbol_id) {
+                let value = binding.create_read_expression(ctx);
+                body.push(exporter.export_statement(names, value, ctx));
+            }
+        }
+    }
+}
+
+/// ```js
+/// Object.keys(_dep).forEach(function (key) {
+///   if (key === "default" || key === "__esModule") return;
+///   if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+///   if (key in _exports && _exports[key] === _dep[key]) return;
+///   Object.defineProperty(_exports, key, {
+///     enumerable: true,
+///     get: function () {
+///       return _dep[key];
+///     }
+///   });
+/// });
+/// ```
+fn star_export_loop<'a>(
+    dependency: &BoundIdentifier<'a>,
+    exports: &MaybeBoundIdentifier<'a>,
+    export_names: Option<&BoundIdentifier<'a>>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let scope_id = create_function_scope(parent_scope_id, ctx);
+    let key =
+        ctx.generate_binding(Atom::from("key"), scope_id, SymbolFlags::FunctionScopedVariable);
+    let mut body = ctx.ast.vec();
+
+    // `if (key === "default" || key === "__esModule") return;`
+    let is_default = key_equals(&key, "default", ctx);
+    let is_es_module = key_equals(&key, "__esModule", ctx);
+    let test = ctx.ast.expression_logical(SPAN, is_default, LogicalOperator::Or, is_es_module);
+    body.push(if_return(test, ctx));
+
+    // `if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;`
+    if let Some(export_names) = export_names {
+        let object =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+        let callee = ["prototype", "hasOwnProperty", "call"]
+            .into_iter()
+            .fold(object, |object, name| Expression::from(member(object, Atom::from(name), ctx)));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(export_names.create_read_expression(ctx)),
+            Argument::from(key.create_read_expression(ctx)),
+        ]);
+        let test = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        body.push(if_return(test, ctx));
+    }
+
+    // `if (key in _exports && _exports[key] === _dep[key]) return;`
+    let key_in_exports = ctx.ast.expression_binary(
+        SPAN,
+        key.create_read_expression(ctx),
+        BinaryOperator::In,
+        exports.create_read_expression(ctx),
+    );
+    let exported = computed_member(exports.create_read_expression(ctx), &key, ctx);
+    let imported = computed_member(dependency.create_read_expression(ctx), &key, ctx);
+    let is_same =
+        ctx.ast.expression_binary(SPAN, exported, BinaryOperator::StrictEquality, imported);
+    let test = ctx.ast.expression_logical(SPAN, key_in_exports, LogicalOperator::And, is_same);
+    body.push(if_return(test, ctx));
+
+    // `Object.defineProperty(_exports, key, { enumerable: true, get: function () { return _dep[key]; } });`
+    let value = computed_member(dependency.create_read_expression(ctx), &key, ctx);
+    let descriptor = getter_descriptor(value, scope_id, ctx);
+    let callee = ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+    let callee = Expression::from(member(callee, Atom::from("defineProperty"), ctx));
+    let arguments = ctx.ast.vec_from_array([
+        Argument::from(exports.create_read_expression(ctx)),
+        Argument::from(key.create_read_expression(ctx)),
+        Argument::from(descriptor),
+    ]);
+    let define = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    body.push(ctx.ast.statement_expression(SPAN, define));
+
+    let callback = function_expression(scope_id, &[key], ctx.ast.vec(), body, ctx);
+
+    // `Object.keys(_dep).forEach(callback);`
+    let object = ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+    let keys = Expression::from(member(object, Atom::from("keys"), ctx));
+    let arguments = ctx.ast.vec1(Argument::from(dependency.create_read_expression(ctx)));
+    let keys = ctx.ast.expression_call(SPAN, keys, NONE, arguments, false);
+    let for_each = Expression::from(member(keys, Atom::from("forEach"), ctx));
+    let arguments = ctx.ast.vec1(Argument::from(callback));
+    let call = ctx.ast.expression_call(SPAN, for_each, NONE, arguments, false);
+    ctx.ast.statement_expression(SPAN, call)
+}
+
+/// `key === "value"`
+fn key_equals<'a>(
+    key: &BoundIdentifier<'a>,
+    value: &'static str,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let left = key.create_read_expression(ctx);
+    let right = ctx.ast.expression_string_literal(SPAN, Atom::from(value), None);
+    ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictEquality, right)
+}
+
+/// `object[key]`
+fn computed_member<'a>(
+    object: Expression<'a>,
+    key: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let key = key.create_read_expression(ctx);
+    Expression::from(ctx.ast.member_expression_computed(SPAN, object, key, false))
+}
+
+/// `if (test) return;`
+fn if_return<'a>(test: Expression<'a>, ctx: &TraverseCtx<'a>) -> Statement<'a> {
+    ctx.ast.statement_if(SPAN, test, ctx.ast.statement_return(SPAN, None), None)
+}
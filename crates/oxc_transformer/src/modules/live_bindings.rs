@@ -0,0 +1,449 @@
+//! Rewrite a module's body, so that it behaves the same once its `import`s and `export`s are gone.
+//!
+//! * References to imported bindings -> property of the dependency's exports.
+//!   `foo` -> `_dep.foo`, `foo()` -> `(0, _dep.foo)()`.
+//! * Writes to exported bindings also update the export.
+//!   `x = 1` -> `_exports.x = x = 1`, `x++` -> `_exports.x = ++x`.
+//! * Top-level `this` -> `void 0`.
+//! * `import.meta` and `import()` are lowered by the [`ModuleFormat`].
+//! * Top-level `await` is reported to the [`ModuleFormat`].
+
+use std::{cell::Cell, mem};
+
+use oxc_allocator::{Box as ArenaBox, TakeIn};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{Atom, SPAN, Span};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{context::TraverseCtx, utils::ast_builder::create_assignment};
+
+use super::{
+    metadata::ModuleMetadata,
+    utils::{Exporter, member},
+};
+
+/// Lowering of module features which differs between module formats.
+pub trait ModuleFormat<'a> {
+    /// `import.meta`
+    fn import_meta(&mut self, span: Span, ctx: &mut TraverseCtx<'a>) -> Expression<'a>;
+
+    /// `import(source)`
+    ///
+    /// `scope_id` is the scope which contains the expression.
+    fn dynamic_import(
+        &mut self,
+        expr: ArenaBox<'a, ImportExpression<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a>;
+
+    /// `await` or `for await` at top level.
+    fn top_level_await(&mut self, span: Span);
+}
+
+pub struct LiveBindings<'a, 'c, F: ModuleFormat<'a>> {
+    metadata: &'c ModuleMetadata<'a>,
+    exporter: &'c Exporter<'a>,
+    format: &'c mut F,
+    /// Rewrite references to imported bindings.
+    /// `false` if imported bindings are kept as variables, which are updated by the module format.
+    rewrite_imports: bool,
+    /// Scope which temporary variables are created in.
+    module_scope_id: ScopeId,
+    /// Temporary variables which the caller must declare in the module scope.
+    pub temps: Vec<BoundIdentifier<'a>>,
+    scope_ids: Vec<ScopeId>,
+    /// Depth of functions which have their own `this`.
+    this_depth: u32,
+    /// Depth of all functions, including arrow functions.
+    function_depth: u32,
+    /// `true` if the expression being visited is the expression of an `ExpressionStatement`.
+    is_statement: bool,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'c, F: ModuleFormat<'a>> LiveBindings<'a, 'c, F> {
+    pub fn new(
+        metadata: &'c ModuleMetadata<'a>,
+        exporter: &'c Exporter<'a>,
+        format: &'c mut F,
+        rewrite_imports: bool,
+        module_scope_id: ScopeId,
+        ctx: &'c mut TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            metadata,
+            exporter,
+            format,
+            rewrite_imports,
+            module_scope_id,
+            temps: vec![],
+            scope_ids: vec![module_scope_id],
+            this_depth: 0,
+            function_depth: 0,
+            is_statement: false,
+            ctx,
+        }
+    }
+
+    fn current_scope_id(&self) -> ScopeId {
+        *self.scope_ids.last().unwrap()
+    }
+
+    fn symbol_id(&self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+    }
+
+    /// Names which the binding `ident` refers to is exported as.
+    fn export_names(&self, ident: &IdentifierReference<'a>) -> Option<&'c [Atom<'a>]> {
+        let metadata = self.metadata;
+        let symbol_id = self.symbol_id(ident)?;
+        metadata.local_exports.get(&symbol_id).map(Vec::as_slice)
+    }
+
+    /// `foo` -> `_dep.foo`, if `foo` is an imported binding.
+    ///
+    /// If the dependency is lazy, its binding is called: `foo` -> `_dep().foo`.
+    ///
+    /// Returns `None` if `ident` does not refer to an imported binding.
+    fn rewrite_import_reference(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+    ) -> Option<Expression<'a>> {
+        if !self.rewrite_imports {
+            return None;
+        }
+        let symbol_id = self.symbol_id(ident)?;
+        let import = self.metadata.imports.get(&symbol_id)?;
+        let dependency = &self.metadata.dependencies[import.dependency];
+        let binding = dependency.binding.as_ref()?;
+        self.ctx.delete_reference_for_identifier(ident);
+        let mut object = binding.create_spanned_read_expression(ident.span, self.ctx);
+        if dependency.lazy {
+            let arguments = self.ctx.ast.vec();
+            object = self.ctx.ast.expression_call(ident.span, object, NONE, arguments, false);
+        }
+        Some(match import.imported.property() {
+            Some(name) => Expression::from(member(object, name, self.ctx)),
+            None => object,
+        })
+    }
+
+    /// Whether `expr` is a reference to an imported binding which will be rewritten to a member
+    /// expression.
+    fn is_imported_property(&self, expr: &Expression<'a>) -> bool {
+        let Expression::Identifier(ident) = expr else { return false };
+        self.rewrite_imports
+            && self
+                .symbol_id(ident)
+                .and_then(|symbol_id| self.metadata.imports.get(&symbol_id))
+                .is_some_and(|import| import.imported.property().is_some())
+    }
+
+    /// `callee` -> `(0, callee)`, so it's not called with its object as `this`.
+    fn unbind_callee(&self, callee: &mut Expression<'a>) {
+        let expr = callee.take_in(self.ctx.ast);
+        let zero = self.ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal);
+        *callee = self.ctx.ast.expression_sequence(SPAN, self.ctx.ast.vec_from_array([zero, expr]));
+    }
+
+    fn create_temp(&mut self, name: &str) -> BoundIdentifier<'a> {
+        let binding =
+            self.ctx.generate_uid(name, self.module_scope_id, SymbolFlags::FunctionScopedVariable);
+        self.temps.push(binding.clone());
+        binding
+    }
+
+    /// `x = 1` -> `_exports.x = x = 1`
+    fn export_assignment(&mut self, expr: &mut Expression<'a>, is_statement: bool) {
+        let Expression::AssignmentExpression(assign) = expr else { return };
+        if let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left {
+            if let Some(names) = self.export_names(ident) {
+                let value = expr.take_in(self.ctx.ast);
+                *expr = self.exporter.export(names, value, self.ctx);
+            }
+            return;
+        }
+        if assign.left.is_simple_assignment_target() {
+            return;
+        }
+
+        // `[a, b] = arr` -> `([a, b] = arr, _exports.a = a, _exports.b = b)`
+        let mut collector = WriteReferenceCollector::new(self.ctx);
+        collector.visit_assignment_target(&assign.left);
+        let bindings = collector.bindings;
+        let exports = self.exports_of_bindings(bindings);
+        if exports.is_empty() {
+            return;
+        }
+        let value = expr.take_in(self.ctx.ast);
+        *expr = self.export_after(value, exports, is_statement);
+    }
+
+    /// `x++` -> `_exports.x = ++x`
+    fn export_update(&mut self, expr: &mut Expression<'a>, is_statement: bool) {
+        let Expression::UpdateExpression(update) = expr else { return };
+        let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument else {
+            return;
+        };
+        let Some(names) = self.export_names(ident) else { return };
+        if update.prefix || is_statement {
+            update.prefix = true;
+            let value = expr.take_in(self.ctx.ast);
+            *expr = self.exporter.export(names, value, self.ctx);
+            return;
+        }
+
+        // `x++` -> `(_x = x++, _exports.x = x, _x)`
+        let binding = BoundIdentifier::new(ident.name, self.symbol_id(ident).unwrap());
+        let value = expr.take_in(self.ctx.ast);
+        *expr = self.export_after(value, vec![(names, binding)], false);
+    }
+
+    /// `expr` -> `(_temp = expr, _exports.a = a, _temp)`
+    ///
+    /// If the value is unused, the temp var is omitted: `(expr, _exports.a = a)`.
+    fn export_after(
+        &mut self,
+        value: Expression<'a>,
+        exports: Vec<(&[Atom<'a>], BoundIdentifier<'a>)>,
+        is_statement: bool,
+    ) -> Expression<'a> {
+        let temp = (!is_statement).then(|| self.create_temp("temp"));
+        let mut expressions = self.ctx.ast.vec_with_capacity(exports.len() + 2);
+        expressions.push(match &temp {
+            Some(temp) => create_assignment(temp, value, self.ctx),
+            None => value,
+        });
+        for (names, binding) in exports {
+            let value = binding.create_read_expression(self.ctx);
+            expressions.push(self.exporter.export(names, value, self.ctx));
+        }
+        if let Some(temp) = temp {
+            expressions.push(temp.create_read_expression(self.ctx));
+        }
+        self.ctx.ast.expression_sequence(SPAN, expressions)
+    }
+
+    fn exports_of_bindings(
+        &self,
+        bindings: Vec<BoundIdentifier<'a>>,
+    ) -> Vec<(&'c [Atom<'a>], BoundIdentifier<'a>)> {
+        let metadata = self.metadata;
+        bindings
+            .into_iter()
+            .filter_map(|binding| {
+                metadata
+                    .local_exports
+                    .get(&binding.symbol_id)
+                    .map(|names| (names.as_slice(), binding))
+            })
+            .collect()
+    }
+
+    /// `for (x of y) body` -> `for (x of y) { _exports.x = x; body }`
+    fn export_for_left(
+        &mut self,
+        left: &ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        scope_id: ScopeId,
+    ) {
+        let bindings = match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let mut bindings = vec![];
+                decl.bound_names(&mut |ident| {
+                    bindings.push(BoundIdentifier::from_binding_ident(ident));
+                });
+                bindings
+            }
+            left => {
+                let mut collector = WriteReferenceCollector::new(self.ctx);
+                collector.visit_assignment_target(left.to_assignment_target());
+                collector.bindings
+            }
+        };
+        let exports = self.exports_of_bindings(bindings);
+        if exports.is_empty() {
+            return;
+        }
+        let statements = exports.into_iter().map(|(names, binding)| {
+            let value = binding.create_read_expression(self.ctx);
+            self.exporter.export_statement(names, value, self.ctx)
+        });
+        let statements = statements.collect::<Vec<_>>();
+        if let Statement::BlockStatement(block) = body {
+            block.body.splice(0..0, statements);
+        } else {
+            let block_scope_id = self.ctx.insert_scope_below_statement_from_scope_id(
+                body,
+                scope_id,
+                ScopeFlags::empty(),
+            );
+            let mut block_body = self.ctx.ast.vec_from_iter(statements);
+            block_body.push(body.take_in(self.ctx.ast));
+            *body = self.ctx.ast.statement_block_with_scope_id(SPAN, block_body, block_scope_id);
+        }
+    }
+}
+
+impl<'a, F: ModuleFormat<'a>> VisitMut<'a> for LiveBindings<'a, '_, F> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_ids.push(scope_id.get().unwrap());
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_ids.pop();
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        let is_statement = mem::take(&mut self.is_statement);
+        match expr {
+            Expression::ThisExpression(this) if self.this_depth == 0 => {
+                *expr = self.ctx.ast.void_0(this.span);
+                return;
+            }
+            Expression::MetaProperty(meta)
+                if meta.meta.name == "import" && meta.property.name == "meta" =>
+            {
+                *expr = self.format.import_meta(meta.span, self.ctx);
+                return;
+            }
+            Expression::Identifier(ident) => {
+                if let Some(new_expr) = self.rewrite_import_reference(ident) {
+                    *expr = new_expr;
+                }
+                return;
+            }
+            Expression::AwaitExpression(await_expr) if self.function_depth == 0 => {
+                self.format.top_level_await(await_expr.span);
+            }
+            _ => {}
+        }
+
+        walk_mut::walk_expression(self, expr);
+
+        match expr {
+            Expression::ImportExpression(_) => {
+                let Expression::ImportExpression(import) = expr.take_in(self.ctx.ast) else {
+                    unreachable!()
+                };
+                let scope_id = self.current_scope_id();
+                *expr = self.format.dynamic_import(import, scope_id, self.ctx);
+            }
+            Expression::AssignmentExpression(_) => self.export_assignment(expr, is_statement),
+            Expression::UpdateExpression(_) => self.export_update(expr, is_statement),
+            _ => {}
+        }
+    }
+
+    fn visit_expression_statement(&mut self, stmt: &mut ExpressionStatement<'a>) {
+        self.is_statement = true;
+        self.visit_expression(&mut stmt.expression);
+    }
+
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        let unbind = self.is_imported_property(&call.callee);
+        walk_mut::walk_call_expression(self, call);
+        if unbind {
+            self.unbind_callee(&mut call.callee);
+        }
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &mut TaggedTemplateExpression<'a>) {
+        let unbind = self.is_imported_property(&expr.tag);
+        walk_mut::walk_tagged_template_expression(self, expr);
+        if unbind {
+            self.unbind_callee(&mut expr.tag);
+        }
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        // `{ foo }` -> `{ foo: _dep.foo }`
+        if prop.shorthand
+            && self.rewrite_imports
+            && let Expression::Identifier(ident) = &prop.value
+            && self.symbol_id(ident).is_some_and(|id| self.metadata.imports.contains_key(&id))
+        {
+            prop.shorthand = false;
+        }
+        walk_mut::walk_object_property(self, prop);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        walk_mut::walk_for_in_statement(self, stmt);
+        let scope_id = stmt.scope_id();
+        self.export_for_left(&stmt.left, &mut stmt.body, scope_id);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        if stmt.r#await && self.function_depth == 0 {
+            self.format.top_level_await(stmt.span);
+        }
+        walk_mut::walk_for_of_statement(self, stmt);
+        let scope_id = stmt.scope_id();
+        self.export_for_left(&stmt.left, &mut stmt.body, scope_id);
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        if decl.kind.is_await() && self.function_depth == 0 {
+            self.format.top_level_await(decl.span);
+        }
+        walk_mut::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.this_depth += 1;
+        self.function_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.function_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        self.this_depth += 1;
+        walk_mut::walk_class(self, class);
+        self.this_depth -= 1;
+    }
+
+    fn visit_static_block(&mut self, block: &mut StaticBlock<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_static_block(self, block);
+        self.function_depth -= 1;
+    }
+}
+
+/// Collect bindings which are written to in an assignment target.
+struct WriteReferenceCollector<'a, 'c> {
+    bindings: Vec<BoundIdentifier<'a>>,
+    ctx: &'c TraverseCtx<'a>,
+}
+
+impl<'a, 'c> WriteReferenceCollector<'a, 'c> {
+    fn new(ctx: &'c TraverseCtx<'a>) -> Self {
+        Self { bindings: vec![], ctx }
+    }
+}
+
+impl<'a> Visit<'a> for WriteReferenceCollector<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.ctx.scoping().get_reference(ident.reference_id());
+        if let Some(symbol_id) = reference.symbol_id()
+            && reference.is_write()
+        {
+            self.bindings.push(BoundIdentifier::new(ident.name, symbol_id));
+        }
+    }
+
+    fn visit_expression(&mut self, _expr: &Expression<'a>) {
+        // Default values and computed keys are not written to
+    }
+}
@@ -0,0 +1,498 @@
+//! Collect the dependencies, imports and exports of a module,
+//! and remove `import` / `export` declarations from its body.
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceId, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::Atom;
+use oxc_traverse::BoundIdentifier;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    Helper,
+    context::{TransformCtx, TraverseCtx},
+};
+
+use super::{commonjs::ImportInterop, utils::Exporter};
+
+/// A module requested by `import` / `export ... from` declarations.
+pub struct Dependency<'a> {
+    pub source: Atom<'a>,
+    /// Binding which holds the dependency's exports.
+    ///
+    /// `None` if the dependency is only imported for its side effects,
+    /// or if bindings for it are created by the module format.
+    pub binding: Option<BoundIdentifier<'a>>,
+    /// The dependency is loaded when an imported binding is first used (CommonJS `lazy` option).
+    /// Its binding is a function which returns the dependency's exports.
+    pub lazy: bool,
+    imports_default: bool,
+    imports_named: bool,
+    imports_namespace: bool,
+    /// Imported with `import defer * as ns from "x"`.
+    imports_deferred: bool,
+    /// Imported with an `import` which is not deferred.
+    imports_eagerly: bool,
+    /// Runtime helpers are CommonJS modules which never need interop.
+    is_helper: bool,
+}
+
+impl<'a> Dependency<'a> {
+    /// Interop helper which the dependency's exports need to be passed through.
+    ///
+    /// With [`ImportInterop::Node`], `interopRequireWildcard` must be called with `true` as its
+    /// second argument.
+    pub fn interop(&self, import_interop: ImportInterop) -> Option<Helper> {
+        if self.is_helper {
+            return None;
+        }
+        match import_interop {
+            ImportInterop::Babel => {}
+            ImportInterop::Node => {
+                return self.imports_namespace.then_some(Helper::InteropRequireWildcard);
+            }
+            ImportInterop::None => return None,
+        }
+        if self.imports_namespace || (self.imports_default && self.imports_named) {
+            Some(Helper::InteropRequireWildcard)
+        } else if self.imports_default {
+            Some(Helper::InteropRequireDefault)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the dependency is only imported with `import defer`.
+    pub fn is_deferred(&self) -> bool {
+        self.imports_deferred && !self.imports_eagerly
+    }
+
+    /// Get the binding for the dependency, creating it from the module source if there is none.
+    pub fn get_or_create_binding(
+        &mut self,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        self.binding
+            .get_or_insert_with(|| {
+                ctx.generate_uid(
+                    &source_to_name(&self.source),
+                    scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                )
+            })
+            .clone()
+    }
+}
+
+/// Name of a binding imported from a dependency.
+#[derive(Clone)]
+pub enum Imported<'a> {
+    Default,
+    Named(Atom<'a>),
+    Namespace,
+}
+
+impl<'a> Imported<'a> {
+    fn from_name(name: Atom<'a>) -> Self {
+        if name == "default" { Self::Default } else { Self::Named(name) }
+    }
+
+    /// Property of the dependency's exports object, or `None` for the object itself.
+    pub fn property(&self) -> Option<Atom<'a>> {
+        match self {
+            Self::Default => Some(Atom::from("default")),
+            Self::Named(name) => Some(*name),
+            Self::Namespace => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ImportBinding<'a> {
+    pub dependency: usize,
+    pub imported: Imported<'a>,
+}
+
+pub struct ModuleMetadata<'a> {
+    pub dependencies: Vec<Dependency<'a>>,
+    /// Imported bindings, except the ones which became a dependency's binding.
+    pub imports: FxHashMap<SymbolId, ImportBinding<'a>>,
+    /// Exported local bindings, and the names they are exported as.
+    pub local_exports: FxHashMap<SymbolId, Vec<Atom<'a>>>,
+    /// Names of local exports, in source order.
+    pub local_export_names: Vec<(Atom<'a>, SymbolId)>,
+    /// Exports of imported bindings: `export { a as b } from "x"`, `export * as ns from "x"`.
+    pub reexports: Vec<(Atom<'a>, ImportBinding<'a>)>,
+    /// Dependencies in `export * from "x"`.
+    pub star_exports: Vec<usize>,
+    /// `export default <expression>`
+    pub has_default_expression: bool,
+}
+
+impl<'a> ModuleMetadata<'a> {
+    /// Collect the metadata and remove `import` / `export` declarations from the program.
+    ///
+    /// `export default <expression>` is converted to an export with `exporter`.
+    ///
+    /// If `reuse_import_bindings` is `true`, namespace imports (`import * as ns from "x"`)
+    /// become the binding of their dependency.
+    pub fn collect(
+        program: &mut Program<'a>,
+        exporter: &Exporter<'a>,
+        reuse_import_bindings: bool,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Self {
+        let mut collector = Collector {
+            metadata: Self {
+                dependencies: vec![],
+                imports: FxHashMap::default(),
+                local_exports: FxHashMap::default(),
+                local_export_names: vec![],
+                reexports: vec![],
+                star_exports: vec![],
+                has_default_expression: false,
+            },
+            reuse_import_bindings,
+            local_specifiers: vec![],
+            transform_ctx,
+        };
+
+        let body = program.body.take_in(ctx.ast);
+        let mut new_body = ctx.ast.vec_with_capacity(body.len());
+        for stmt in body {
+            if let Some(stmt) = collector.collect_statement(stmt, exporter, ctx) {
+                new_body.push(stmt);
+            }
+        }
+        program.body = new_body;
+
+        collector.resolve_local_specifiers(ctx);
+        collector.metadata
+    }
+
+    /// Whether the module has any exports.
+    pub fn has_exports(&self) -> bool {
+        !self.local_export_names.is_empty()
+            || !self.reexports.is_empty()
+            || !self.star_exports.is_empty()
+            || self.has_default_expression
+    }
+
+    /// Create bindings for dependencies which are referenced, and have none yet.
+    pub fn create_dependency_bindings(&mut self, scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let mut used = vec![false; self.dependencies.len()];
+        for import in self.imports.values() {
+            used[import.dependency] = true;
+        }
+        for (_, import) in &self.reexports {
+            used[import.dependency] = true;
+        }
+        for &dependency in &self.star_exports {
+            used[dependency] = true;
+        }
+        for (dependency, used) in self.dependencies.iter_mut().zip(used) {
+            if used || dependency.binding.is_some() {
+                dependency.get_or_create_binding(scope_id, ctx);
+            }
+        }
+    }
+
+    /// With [`ImportInterop::Node`], the default export of a CommonJS module is its
+    /// `module.exports`, so default imports refer to the dependency's binding itself.
+    ///
+    /// Unless the dependency is also imported as a namespace, in which case the binding is
+    /// the result of `interopRequireWildcard`, and `default` is a property of it.
+    pub fn use_node_interop(&mut self) {
+        let dependencies = &self.dependencies;
+        let imports = self.imports.values_mut().chain(self.reexports.iter_mut().map(|(_, i)| i));
+        for import in imports {
+            let dependency = &dependencies[import.dependency];
+            if matches!(import.imported, Imported::Default)
+                && !dependency.imports_namespace
+                && !dependency.is_helper
+            {
+                import.imported = Imported::Namespace;
+            }
+        }
+    }
+
+    /// Mark dependencies as lazy if `is_lazy` returns `true` for them.
+    ///
+    /// Dependencies which are re-exported, only imported for their side effects,
+    /// or are runtime helpers, are always loaded eagerly.
+    pub fn mark_lazy_dependencies(&mut self, is_lazy: impl Fn(&Dependency<'a>) -> bool) {
+        let mut eager = vec![true; self.dependencies.len()];
+        for import in self.imports.values() {
+            eager[import.dependency] = false;
+        }
+        for (_, import) in &self.reexports {
+            eager[import.dependency] = true;
+        }
+        for &dependency in &self.star_exports {
+            eager[dependency] = true;
+        }
+        for (dependency, eager) in self.dependencies.iter_mut().zip(eager) {
+            dependency.lazy = !eager
+                && !dependency.is_helper
+                && dependency.binding.is_none()
+                && is_lazy(dependency);
+        }
+    }
+
+    /// All names exported explicitly by the module.
+    pub fn exported_names(&self) -> impl Iterator<Item = Atom<'a>> + '_ {
+        self.local_export_names
+            .iter()
+            .map(|(name, _)| *name)
+            .chain(self.reexports.iter().map(|(name, _)| *name))
+            .chain(self.has_default_expression.then(|| Atom::from("default")))
+    }
+}
+
+struct Collector<'a, 'ctx> {
+    metadata: ModuleMetadata<'a>,
+    reuse_import_bindings: bool,
+    /// `export { a as b }` without a source, resolved after all imports have been collected.
+    local_specifiers: Vec<(Atom<'a>, Atom<'a>, ReferenceId)>,
+    transform_ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a> Collector<'a, '_> {
+    fn collect_statement(
+        &mut self,
+        stmt: Statement<'a>,
+        exporter: &Exporter<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        match stmt {
+            Statement::ImportDeclaration(decl) => {
+                if !decl.import_kind.is_type() {
+                    self.collect_import(&decl, ctx);
+                }
+                None
+            }
+            Statement::ExportAllDeclaration(decl) => {
+                if !decl.export_kind.is_type() {
+                    let dependency = self.dependency(decl.source.value);
+                    if let Some(exported) = &decl.exported {
+                        self.metadata.dependencies[dependency].imports_namespace = true;
+                        let import = ImportBinding { dependency, imported: Imported::Namespace };
+                        self.metadata.reexports.push((exported.name(), import));
+                    } else {
+                        self.metadata.star_exports.push(dependency);
+                    }
+                }
+                None
+            }
+            Statement::ExportNamedDeclaration(decl) => {
+                let decl = decl.unbox();
+                if decl.export_kind.is_type() {
+                    return None;
+                }
+                if let Some(source) = &decl.source {
+                    let dependency = self.dependency(source.value);
+                    for specifier in decl.specifiers.iter().filter(|s| !s.export_kind.is_type()) {
+                        let imported = self.import_name(dependency, specifier.local.name());
+                        let import = ImportBinding { dependency, imported };
+                        self.metadata.reexports.push((specifier.exported.name(), import));
+                    }
+                    return None;
+                }
+                for specifier in decl.specifiers.iter().filter(|s| !s.export_kind.is_type()) {
+                    if let ModuleExportName::IdentifierReference(ident) = &specifier.local {
+                        self.local_specifiers.push((
+                            specifier.exported.name(),
+                            ident.name,
+                            ident.reference_id(),
+                        ));
+                    }
+                }
+                let declaration = decl.declaration?;
+                if declaration.is_typescript_syntax() {
+                    return None;
+                }
+                declaration.bound_names(&mut |ident| {
+                    self.add_local_export(ident.name, ident.symbol_id());
+                });
+                Some(Statement::from(declaration))
+            }
+            Statement::ExportDefaultDeclaration(decl) => {
+                let decl = decl.unbox();
+                match decl.declaration {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                        let id =
+                            Self::default_declaration_id(&mut func.id, SymbolFlags::Function, ctx);
+                        self.add_local_export(Atom::from("default"), id);
+                        Some(Statement::FunctionDeclaration(func))
+                    }
+                    ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                        let id =
+                            Self::default_declaration_id(&mut class.id, SymbolFlags::Class, ctx);
+                        self.add_local_export(Atom::from("default"), id);
+                        Some(Statement::ClassDeclaration(class))
+                    }
+                    ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => None,
+                    declaration => {
+                        self.metadata.has_default_expression = true;
+                        let expr = declaration.into_expression();
+                        let expr = exporter.export(&[Atom::from("default")], expr, ctx);
+                        Some(ctx.ast.statement_expression(decl.span, expr))
+                    }
+                }
+            }
+            Statement::TSExportAssignment(_) | Statement::TSNamespaceExportDeclaration(_) => None,
+            stmt => Some(stmt),
+        }
+    }
+
+    fn collect_import(&mut self, decl: &ImportDeclaration<'a>, ctx: &mut TraverseCtx<'a>) {
+        let dependency = self.dependency(decl.source.value);
+        // Deferred namespaces are loaded on first use, so never become the dependency's binding
+        let is_deferred = decl.phase == Some(ImportPhase::Defer);
+        let dep = &mut self.metadata.dependencies[dependency];
+        if is_deferred {
+            dep.imports_deferred = true;
+        } else {
+            dep.imports_eagerly = true;
+        }
+        for specifier in decl.specifiers.iter().flatten() {
+            let (local, imported) = match specifier {
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    (&specifier.local, self.import_name(dependency, Atom::from("default")))
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    self.metadata.dependencies[dependency].imports_namespace = true;
+                    (&specifier.local, Imported::Namespace)
+                }
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    if specifier.import_kind.is_type() {
+                        continue;
+                    }
+                    (&specifier.local, self.import_name(dependency, specifier.imported.name()))
+                }
+            };
+            let dep = &mut self.metadata.dependencies[dependency];
+            // Helpers are imported as default, but are plain CommonJS modules
+            let is_module_object = matches!(imported, Imported::Namespace)
+                || (dep.is_helper && matches!(imported, Imported::Default));
+            if self.reuse_import_bindings
+                && !is_deferred
+                && is_module_object
+                && dep.binding.is_none()
+            {
+                let symbol_id = local.symbol_id();
+                *ctx.scoping_mut().symbol_flags_mut(symbol_id) =
+                    SymbolFlags::FunctionScopedVariable;
+                dep.binding = Some(BoundIdentifier::from_binding_ident(local));
+                continue;
+            }
+            let imported = if dep.is_helper { Imported::Namespace } else { imported };
+            self.metadata.imports.insert(local.symbol_id(), ImportBinding { dependency, imported });
+        }
+    }
+
+    /// Give an anonymous `export default function() {}` / `export default class {}` a name.
+    fn default_declaration_id(
+        id: &mut Option<BindingIdentifier<'a>>,
+        flags: SymbolFlags,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> SymbolId {
+        if let Some(id) = id {
+            return id.symbol_id();
+        }
+        let binding = ctx.generate_uid_in_root_scope("default", flags);
+        *id = Some(binding.create_binding_identifier(ctx));
+        binding.symbol_id
+    }
+
+    fn add_local_export(&mut self, name: Atom<'a>, symbol_id: SymbolId) {
+        self.metadata.local_exports.entry(symbol_id).or_default().push(name);
+        self.metadata.local_export_names.push((name, symbol_id));
+    }
+
+    fn import_name(&mut self, dependency: usize, name: Atom<'a>) -> Imported<'a> {
+        let imported = Imported::from_name(name);
+        let dep = &mut self.metadata.dependencies[dependency];
+        match imported {
+            Imported::Default => dep.imports_default = true,
+            Imported::Named(_) => dep.imports_named = true,
+            Imported::Namespace => dep.imports_namespace = true,
+        }
+        imported
+    }
+
+    /// Get index of dependency for `source`, adding it if it's not present.
+    fn dependency(&mut self, source: Atom<'a>) -> usize {
+        let dependencies = &mut self.metadata.dependencies;
+        if let Some(index) = dependencies.iter().position(|dep| dep.source == source) {
+            return index;
+        }
+        dependencies.push(Dependency {
+            source,
+            binding: None,
+            lazy: false,
+            imports_default: false,
+            imports_named: false,
+            imports_namespace: false,
+            imports_deferred: false,
+            imports_eagerly: false,
+            is_helper: self.transform_ctx.helper_loader.is_runtime_helper_source(&source),
+        });
+        dependencies.len() - 1
+    }
+
+    /// Resolve `export { a as b }`, now that all imports have been collected.
+    fn resolve_local_specifiers(&mut self, ctx: &mut TraverseCtx<'a>) {
+        for (exported, name, reference_id) in std::mem::take(&mut self.local_specifiers) {
+            let symbol_id = ctx.scoping().get_reference(reference_id).symbol_id();
+            ctx.delete_reference(reference_id, &name);
+            let Some(symbol_id) = symbol_id else { continue };
+            if let Some(import) = self.metadata.imports.get(&symbol_id) {
+                self.metadata.reexports.push((exported, import.clone()));
+            } else if let Some(dependency) = self.metadata.dependencies.iter().position(|dep| {
+                dep.binding.as_ref().is_some_and(|binding| binding.symbol_id == symbol_id)
+            }) {
+                let import = ImportBinding { dependency, imported: Imported::Namespace };
+                self.metadata.reexports.push((exported, import));
+            } else if ctx.scoping().symbol_flags(symbol_id).is_value() {
+                self.add_local_export(exported, symbol_id);
+            }
+        }
+    }
+}
+
+/// `"./foo-bar.js"` -> `"fooBar"`
+///
+/// Name is derived from the last segment of the module source, with the extension removed.
+pub fn source_to_name(source: &str) -> String {
+    let name = source.trim_end_matches('/');
+    let name = name.rsplit('/').next().unwrap_or(name);
+    let name =
+        name.split_once('.').map_or(name, |(stem, _)| if stem.is_empty() { name } else { stem });
+    to_camel_case(name)
+}
+
+/// `"foo-bar"` -> `"fooBar"`
+pub fn to_camel_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut upper_next = false;
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+            if upper_next && !result.is_empty() {
+                result.push(c.to_ascii_uppercase());
+            } else {
+                result.push(c);
+            }
+            upper_next = false;
+        } else {
+            upper_next = true;
+        }
+    }
+    if result.is_empty() || result.starts_with(|c: char| c.is_ascii_digit()) {
+        result.insert(0, '_');
+    }
+    result
+}
@@ -0,0 +1,233 @@
+//! ES Module Transforms
+//!
+//! Lower `import` / `export` declarations to the module system selected by [`Module`]:
+//!
+//! * CommonJS: [`commonjs`]
+//! * AMD: [`amd`]
+//! * UMD: [`umd`]
+//! * SystemJS: [`systemjs`]
+//!
+//! These transforms run after all other plugins have finished with the program in `exit_program`,
+//! so that `import`s injected by other plugins (e.g. runtime helpers, JSX runtime) are lowered too.
+//!
+//! Interop helpers which the module needs are determined ahead of time from the [`ModuleRecord`]
+//! produced by the parser (see [`crate::Transformer::with_module_record`]), so that in
+//! [`HelperLoaderMode::Runtime`] mode their `import`s are inserted together with all other
+//! injected imports, and then lowered along with them.
+//!
+//! References:
+//! * Babel: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-helper-module-transforms>
+
+use oxc_ast::ast::*;
+use oxc_syntax::module_record::{ExportImportName, ImportImportName, ModuleRecord};
+use oxc_traverse::Traverse;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    Helper, HelperLoaderMode, Module, ProposalOptions,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub use commonjs::{CommonJsOptions, ImportInterop};
+
+mod amd;
+mod commonjs;
+mod diagnostics;
+mod exports;
+mod live_bindings;
+mod metadata;
+mod systemjs;
+mod umd;
+mod utils;
+
+/// Imports of a module which determine the interop helpers it needs.
+///
+/// Collected from the parser's [`ModuleRecord`] or, failing that, from the `import` / `export`
+/// declarations of the program before it is transformed.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ModuleFeatures {
+    interop_default: bool,
+    interop_wildcard: bool,
+    /// Namespace imports or `import()`, which are interop'd with Node.js interop too.
+    interop_namespace: bool,
+}
+
+#[derive(Default, Clone, Copy)]
+struct RequestedImports {
+    default: bool,
+    named: bool,
+    namespace: bool,
+}
+
+impl ModuleFeatures {
+    pub fn from_module_record(module_record: &ModuleRecord<'_>) -> Self {
+        let mut requests = FxHashMap::<&str, RequestedImports>::default();
+        for entry in module_record.import_entries.iter().filter(|entry| !entry.is_type) {
+            let request = requests.entry(entry.module_request.name.as_str()).or_default();
+            match &entry.import_name {
+                ImportImportName::Default(_) => request.default = true,
+                ImportImportName::NamespaceObject => request.namespace = true,
+                ImportImportName::Name(name) if name.name == "default" => request.default = true,
+                ImportImportName::Name(_) => request.named = true,
+            }
+        }
+        for entry in module_record.indirect_export_entries.iter().filter(|entry| !entry.is_type) {
+            let Some(module_request) = &entry.module_request else { continue };
+            let request = requests.entry(module_request.name.as_str()).or_default();
+            match &entry.import_name {
+                ExportImportName::All => request.namespace = true,
+                ExportImportName::Name(name) if name.name == "default" => request.default = true,
+                ExportImportName::Name(_) => request.named = true,
+                ExportImportName::AllButDefault | ExportImportName::Null => {}
+            }
+        }
+        let mut features = Self::from_requests(requests.into_values());
+        // Dynamic `import()`s are interop'd with `interopRequireWildcard`
+        if !module_record.dynamic_imports.is_empty() {
+            features.interop_wildcard = true;
+            features.interop_namespace = true;
+        }
+        features
+    }
+
+    fn from_program(program: &Program<'_>) -> Self {
+        let mut requests = FxHashMap::<&str, RequestedImports>::default();
+        for stmt in &program.body {
+            match stmt {
+                Statement::ImportDeclaration(decl) if !decl.import_kind.is_type() => {
+                    let request = requests.entry(decl.source.value.as_str()).or_default();
+                    for specifier in decl.specifiers.iter().flatten() {
+                        match specifier {
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                                request.default = true;
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {
+                                request.namespace = true;
+                            }
+                            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                                if specifier.imported.name() == "default" {
+                                    request.default = true;
+                                } else {
+                                    request.named = true;
+                                }
+                            }
+                        }
+                    }
+                }
+                Statement::ExportAllDeclaration(decl) if decl.exported.is_some() => {
+                    requests.entry(decl.source.value.as_str()).or_default().namespace = true;
+                }
+                Statement::ExportNamedDeclaration(decl) => {
+                    let Some(source) = &decl.source else { continue };
+                    let request = requests.entry(source.value.as_str()).or_default();
+                    for specifier in &decl.specifiers {
+                        if specifier.local.name() == "default" {
+                            request.default = true;
+                        } else {
+                            request.named = true;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self::from_requests(requests.into_values())
+    }
+
+    fn from_requests(requests: impl Iterator<Item = RequestedImports>) -> Self {
+        let mut features = Self::default();
+        for request in requests {
+            features.interop_namespace |= request.namespace;
+            if request.namespace || (request.default && request.named) {
+                features.interop_wildcard = true;
+            } else if request.default {
+                features.interop_default = true;
+            }
+        }
+        features
+    }
+
+    /// Interop helpers which are needed with `import_interop`.
+    fn helpers(self, import_interop: ImportInterop) -> impl Iterator<Item = Helper> {
+        let helpers = match import_interop {
+            ImportInterop::Babel => [
+                (self.interop_default, Helper::InteropRequireDefault),
+                (self.interop_wildcard, Helper::InteropRequireWildcard),
+            ],
+            ImportInterop::Node => [
+                (false, Helper::InteropRequireDefault),
+                (self.interop_namespace, Helper::InteropRequireWildcard),
+            ],
+            ImportInterop::None => {
+                [(false, Helper::InteropRequireDefault), (false, Helper::InteropRequireWildcard)]
+            }
+        };
+        helpers.into_iter().filter(|(needed, _)| *needed).map(|(_, helper)| helper)
+    }
+}
+
+pub struct Modules<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    commonjs: CommonJsOptions,
+    proposals: ProposalOptions,
+    features: Option<ModuleFeatures>,
+}
+
+impl<'a, 'ctx> Modules<'a, 'ctx> {
+    pub fn new(
+        commonjs: CommonJsOptions,
+        proposals: ProposalOptions,
+        features: Option<ModuleFeatures>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        Self { ctx, commonjs, proposals, features }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.ctx.source_type.is_module()
+            && matches!(
+                self.ctx.module,
+                Module::CommonJS | Module::Amd | Module::Umd | Module::SystemJs
+            )
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Modules<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_enabled() || self.ctx.module.is_systemjs() {
+            return;
+        }
+        if !matches!(self.ctx.helper_loader.mode(), HelperLoaderMode::Runtime) {
+            return;
+        }
+        // Load interop helpers now, so their `import`s are inserted along with other imports.
+        let features = self.features.unwrap_or_else(|| ModuleFeatures::from_program(program));
+        let import_interop = if self.ctx.module.is_commonjs() {
+            self.commonjs.import_interop
+        } else {
+            ImportInterop::Babel
+        };
+        for helper in features.helpers(import_interop) {
+            if let Expression::Identifier(ident) = self.ctx.helper_load(helper, ctx) {
+                ctx.delete_reference_for_identifier(&ident);
+            }
+        }
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.is_enabled() {
+            return;
+        }
+        match self.ctx.module {
+            Module::CommonJS => {
+                let import_defer = self.proposals.import_defer;
+                commonjs::transform_program(program, self.commonjs, import_defer, self.ctx, ctx);
+            }
+            Module::Amd => amd::transform_program(program, self.ctx, ctx),
+            Module::Umd => umd::transform_program(program, self.ctx, ctx),
+            Module::SystemJs => systemjs::transform_program(program, self.ctx, ctx),
+            Module::Preserve | Module::Esm => {}
+        }
+    }
+}
@@ -0,0 +1,547 @@
+//! SystemJS
+//!
+//! This plugin is enabled with `Module::SystemJs`.
+//!
+//! Lower ES modules to the `System.register` format.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo, { bar } from "foo";
+//! export let x = foo;
+//! export function f() {}
+//! x = bar();
+//! ```
+//!
+//! Output:
+//! ```js
+//! System.register(["foo"], function (_export, _context) {
+//!   "use strict";
+//!
+//!   var foo, bar, x;
+//!   function f() {}
+//!   _export("f", f);
+//!   return {
+//!     setters: [function (_foo) {
+//!       foo = _foo.default;
+//!       bar = _foo.bar;
+//!     }],
+//!     execute: function () {
+//!       _export("x", x = foo);
+//!       _export("x", x = bar());
+//!     }
+//!   };
+//! });
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-modules-systemjs](https://babeljs.io/docs/babel-plugin-transform-modules-systemjs).
+//!
+//! Unlike AMD and UMD, SystemJS supports live bindings natively: imported bindings are kept as
+//! variables which the module loader updates by calling the setters, and exports are updated by
+//! calling `_export` whenever an exported binding is written to.
+//!
+//! All top level bindings are hoisted to `var`s in the outer function, so that they're available
+//! to the setters, and function declarations are hoisted along with them, so that circular
+//! dependencies can call them before `execute` runs.
+//!
+//! `import.meta` is `_context.meta`, `import()` is `_context.import()`, and top level `await`
+//! makes `execute` an async function.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-modules-systemjs/src/index.ts>
+//! * System.register format: <https://github.com/systemjs/systemjs/blob/main/docs/system-register.md>
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{Atom, SPAN, Span};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    es2015::binding_pattern_to_assignment_target,
+    utils::ast_builder::create_assignment,
+};
+
+use super::{
+    amd::{move_child_scopes, move_root_bindings},
+    live_bindings::{LiveBindings, ModuleFormat},
+    metadata::ModuleMetadata,
+    utils::{
+        Exporter, create_function_scope, function_expression, member, object, string_array,
+        var_declaration_with_init,
+    },
+};
+
+pub fn transform_program<'a>(
+    program: &mut Program<'a>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let root_scope_id = ctx.scoping().root_scope_id();
+    let scope_id = create_function_scope(root_scope_id, ctx);
+    let export = ctx.generate_uid("export", scope_id, SymbolFlags::FunctionScopedVariable);
+    let context = ctx.generate_uid("context", scope_id, SymbolFlags::FunctionScopedVariable);
+    let exporter = Exporter::Function(export.clone());
+
+    let mut metadata = ModuleMetadata::collect(program, &exporter, false, transform_ctx, ctx);
+
+    // Imported bindings become `var`s which are assigned by the setters
+    for &symbol_id in metadata.imports.keys() {
+        *ctx.scoping_mut().symbol_flags_mut(symbol_id) = SymbolFlags::FunctionScopedVariable;
+    }
+
+    // Split function declarations, which are hoisted to the outer function, from the rest of the
+    // body, which runs in `execute`.
+    let execute_scope_id = create_function_scope(scope_id, ctx);
+    let (functions, body): (Vec<_>, Vec<_>) = program
+        .body
+        .take_in(ctx.ast)
+        .into_iter()
+        .partition(|stmt| matches!(stmt, Statement::FunctionDeclaration(_)));
+    let mut functions = ctx.ast.vec_from_iter(functions);
+    let mut body = ctx.ast.vec_from_iter(body);
+    move_root_bindings(scope_id, ctx);
+    move_child_scopes(&functions, scope_id, ctx);
+    move_child_scopes(&body, execute_scope_id, ctx);
+
+    hoist_declarations(&mut body, scope_id, ctx);
+
+    let mut format = SystemJsFormat { context: context.clone(), has_top_level_await: false };
+    let mut live_bindings =
+        LiveBindings::new(&metadata, &exporter, &mut format, false, scope_id, ctx);
+    live_bindings.visit_statements(&mut functions);
+    live_bindings.visit_statements(&mut body);
+    let is_async = format.has_top_level_await;
+
+    // `var a, b, c;`
+    let mut hoisted = ctx
+        .scoping()
+        .get_bindings(scope_id)
+        .values()
+        .copied()
+        .filter(|&symbol_id| {
+            !ctx.scoping().symbol_flags(symbol_id).is_function()
+                && symbol_id != export.symbol_id
+                && symbol_id != context.symbol_id
+        })
+        .collect::<Vec<_>>();
+    hoisted.sort_unstable();
+
+    let mut statements = ctx.ast.vec();
+    if !hoisted.is_empty() {
+        statements.push(hoisted_var_declaration(&hoisted, ctx));
+    }
+    statements.extend(functions);
+
+    // `_export("f", f);`
+    for (name, symbol_id) in &metadata.local_export_names {
+        if ctx.scoping().symbol_flags(*symbol_id).is_function() {
+            let binding = symbol_binding(*symbol_id, ctx);
+            let value = binding.create_read_expression(ctx);
+            statements.push(exporter.export_statement(&[*name], value, ctx));
+        }
+    }
+
+    // `setters: [function (_foo) { foo = _foo.default; }]`
+    let setters = ctx.ast.vec_from_iter(
+        (0..metadata.dependencies.len())
+            .map(|index| {
+                ArrayExpressionElement::from(build_setter(
+                    &mut metadata,
+                    index,
+                    &exporter,
+                    scope_id,
+                    ctx,
+                ))
+            })
+            .collect::<Vec<_>>(),
+    );
+    let setters = ctx.ast.expression_array(SPAN, setters);
+
+    // `execute: function () { ... }`
+    let mut execute = function_expression(execute_scope_id, &[], ctx.ast.vec(), body, ctx);
+    if let Expression::FunctionExpression(func) = &mut execute {
+        func.r#async = is_async;
+    }
+
+    let returned = object([("setters", setters), ("execute", execute)], ctx);
+    statements.push(ctx.ast.statement_return(SPAN, Some(returned)));
+
+    let mut directives = ctx.ast.vec1(ctx.ast.use_strict_directive());
+    directives
+        .extend(program.directives.take_in(ctx.ast).into_iter().filter(|d| !d.is_use_strict()));
+    let register = function_expression(scope_id, &[export, context], directives, statements, ctx);
+
+    // `System.register(["foo"], function (_export, _context) { ... });`
+    let dependencies = string_array(metadata.dependencies.iter().map(|dep| dep.source), ctx);
+    let system = ctx.create_unbound_ident_expr(SPAN, Atom::from("System"), ReferenceFlags::Read);
+    let callee = Expression::from(member(system, Atom::from("register"), ctx));
+    let arguments =
+        ctx.ast.vec_from_array([Argument::from(dependencies), Argument::from(register)]);
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    program.body.push(ctx.ast.statement_expression(SPAN, call));
+}
+
+/// ```js
+/// function (_foo) {
+///   foo = _foo.default;
+///   _export("bar", _foo.bar);
+///   var _exportObj = {};
+///   for (var _key in _foo) {
+///     if (_key !== "default" && _key !== "__esModule") _exportObj[_key] = _foo[_key];
+///   }
+///   _export(_exportObj);
+/// }
+/// ```
+fn build_setter<'a>(
+    metadata: &mut ModuleMetadata<'a>,
+    index: usize,
+    exporter: &Exporter<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let scope_id = create_function_scope(parent_scope_id, ctx);
+    let mut imports = metadata
+        .imports
+        .iter()
+        .filter(|(_, import)| import.dependency == index)
+        .map(|(symbol_id, import)| (*symbol_id, import.imported.property()))
+        .collect::<Vec<_>>();
+    imports.sort_unstable_by_key(|(symbol_id, _)| *symbol_id);
+    let reexports = metadata
+        .reexports
+        .iter()
+        .filter(|(_, import)| import.dependency == index)
+        .map(|(name, import)| (*name, import.imported.property()))
+        .collect::<Vec<_>>();
+    let is_star_export = metadata.star_exports.contains(&index);
+    if imports.is_empty() && reexports.is_empty() && !is_star_export {
+        return function_expression(scope_id, &[], ctx.ast.vec(), ctx.ast.vec(), ctx);
+    }
+
+    let module = metadata.dependencies[index].get_or_create_binding(scope_id, ctx);
+    let imported = |property: Option<Atom<'a>>, ctx: &mut TraverseCtx<'a>| {
+        let object = module.create_read_expression(ctx);
+        match property {
+            Some(property) => Expression::from(member(object, property, ctx)),
+            None => object,
+        }
+    };
+
+    let mut body = ctx.ast.vec();
+    for (symbol_id, property) in imports {
+        let binding = symbol_binding(symbol_id, ctx);
+        let value = imported(property, ctx);
+        let assignment = create_assignment(&binding, value, ctx);
+        body.push(ctx.ast.statement_expression(SPAN, assignment));
+    }
+    for (name, property) in reexports {
+        let value = imported(property, ctx);
+        body.push(exporter.export_statement(&[name], value, ctx));
+    }
+    if is_star_export {
+        star_export(&module, exporter, scope_id, &mut body, ctx);
+    }
+    function_expression(scope_id, &[module], ctx.ast.vec(), body, ctx)
+}
+
+/// ```js
+/// var _exportObj = {};
+/// for (var _key in _foo) {
+///   if (_key !== "default" && _key !== "__esModule") _exportObj[_key] = _foo[_key];
+/// }
+/// _export(_exportObj);
+/// ```
+fn star_export<'a>(
+    module: &BoundIdentifier<'a>,
+    exporter: &Exporter<'a>,
+    scope_id: ScopeId,
+    body: &mut ArenaVec<'a, Statement<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let Exporter::Function(export) = exporter else { unreachable!() };
+    let export_obj = ctx.generate_uid("exportObj", scope_id, SymbolFlags::FunctionScopedVariable);
+    let key = ctx.generate_uid("key", scope_id, SymbolFlags::FunctionScopedVariable);
+
+    let init = ctx.ast.expression_object(SPAN, ctx.ast.vec());
+    body.push(var_declaration_with_init(&export_obj, init, ctx));
+
+    let for_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+    let left = ctx.ast.for_statement_left_variable_declaration(
+        SPAN,
+        VariableDeclarationKind::Var,
+        ctx.ast.vec1(ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            key.create_binding_pattern(ctx),
+            NONE,
+            None,
+            false,
+        )),
+        false,
+    );
+    let not_key = |value: &'static str, ctx: &mut TraverseCtx<'a>| {
+        let left = key.create_read_expression(ctx);
+        let right = ctx.ast.expression_string_literal(SPAN, Atom::from(value), None);
+        ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictInequality, right)
+    };
+    let not_default = not_key("default", ctx);
+    let not_es_module = not_key("__esModule", ctx);
+    let test = ctx.ast.expression_logical(SPAN, not_default, LogicalOperator::And, not_es_module);
+    let target = ctx.ast.member_expression_computed(
+        SPAN,
+        export_obj.create_read_expression(ctx),
+        key.create_read_expression(ctx),
+        false,
+    );
+    let value = ctx.ast.member_expression_computed(
+        SPAN,
+        module.create_read_expression(ctx),
+        key.create_read_expression(ctx),
+        false,
+    );
+    let assignment = ctx.ast.expression_assignment(
+        SPAN,
+        AssignmentOperator::Assign,
+        AssignmentTarget::from(target),
+        Expression::from(value),
+    );
+    let consequent = ctx.ast.statement_expression(SPAN, assignment);
+    let if_statement = ctx.ast.statement_if(SPAN, test, consequent, None);
+    let block_scope_id = ctx.create_child_scope(for_scope_id, ScopeFlags::empty());
+    let for_body =
+        ctx.ast.statement_block_with_scope_id(SPAN, ctx.ast.vec1(if_statement), block_scope_id);
+    let right = module.create_read_expression(ctx);
+    body.push(ctx.ast.statement_for_in_with_scope_id(SPAN, left, right, for_body, for_scope_id));
+
+    let callee = export.create_read_expression(ctx);
+    let arguments = ctx.ast.vec1(Argument::from(export_obj.create_read_expression(ctx)));
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    body.push(ctx.ast.statement_expression(SPAN, call));
+}
+
+fn symbol_binding<'a>(symbol_id: SymbolId, ctx: &TraverseCtx<'a>) -> BoundIdentifier<'a> {
+    BoundIdentifier::new(ctx.ast.atom(ctx.scoping().symbol_name(symbol_id)), symbol_id)
+}
+
+/// `var a, b;`, with the spans of the bindings' original declarations.
+fn hoisted_var_declaration<'a>(symbol_ids: &[SymbolId], ctx: &TraverseCtx<'a>) -> Statement<'a> {
+    let kind = VariableDeclarationKind::Var;
+    let declarations = ctx.ast.vec_from_iter(symbol_ids.iter().map(|&symbol_id| {
+        let span = ctx.scoping().symbol_span(symbol_id);
+        let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+        let id = ctx.ast.binding_identifier_with_symbol_id(span, name, symbol_id);
+        let id = BindingPattern::BindingIdentifier(ctx.ast.alloc(id));
+        ctx.ast.variable_declarator(SPAN, kind, id, NONE, None, false)
+    }));
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        declarations,
+        false,
+    ))
+}
+
+/// Convert declarations of top level bindings to assignments, as the bindings are hoisted.
+///
+/// * `let x = 1;` -> `x = 1;`
+/// * `class C {}` -> `C = class {};`
+/// * `if (a) { var y = 2; }` -> `if (a) { y = 2; }`
+fn hoist_declarations<'a>(
+    body: &mut ArenaVec<'a, Statement<'a>>,
+    hoisted_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let mut hoister = DeclarationHoister { hoisted_scope_id, ctx };
+    let old_body = body.take_in(hoister.ctx.ast);
+    for mut stmt in old_body {
+        match &mut stmt {
+            Statement::ClassDeclaration(class) => {
+                let id = class.id.take().unwrap();
+                class.r#type = ClassType::ClassExpression;
+                *hoister.ctx.scoping_mut().symbol_flags_mut(id.symbol_id()) =
+                    SymbolFlags::FunctionScopedVariable;
+                let binding = BoundIdentifier::from_binding_ident(&id);
+                let target = binding.create_spanned_write_target(id.span, hoister.ctx);
+                let Statement::ClassDeclaration(class) = stmt else { unreachable!() };
+                let span = class.span;
+                let assignment = hoister.ctx.ast.expression_assignment(
+                    span,
+                    AssignmentOperator::Assign,
+                    target,
+                    Expression::ClassExpression(class),
+                );
+                body.push(hoister.ctx.ast.statement_expression(span, assignment));
+            }
+            Statement::VariableDeclaration(decl) => {
+                // Declarations without initializers have nothing left to do
+                if let Some(expr) = hoister.declaration_to_expression(decl) {
+                    body.push(hoister.ctx.ast.statement_expression(decl.span, expr));
+                }
+            }
+            _ => {
+                hoister.visit_statement(&mut stmt);
+                body.push(stmt);
+            }
+        }
+    }
+}
+
+/// Convert `var` declarations of hoisted bindings, which are nested in blocks and loops,
+/// to assignments.
+struct DeclarationHoister<'a, 'c> {
+    /// Scope which top level bindings have been moved to.
+    hoisted_scope_id: ScopeId,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> DeclarationHoister<'a, '_> {
+    fn is_hoisted(&self, decl: &VariableDeclaration<'a>) -> bool {
+        decl.kind.is_var()
+            && decl.declarations.first().is_some_and(|declarator| {
+                let mut is_hoisted = false;
+                declarator.id.bound_names(&mut |ident| {
+                    is_hoisted |= self.ctx.scoping().symbol_scope_id(ident.symbol_id())
+                        == self.hoisted_scope_id;
+                });
+                is_hoisted
+            })
+    }
+
+    /// Convert declarators to assignments, and mark their bindings as `var`s.
+    ///
+    /// Returns `None` if no declarators have an initializer.
+    fn declaration_to_expression(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut expressions = self.ctx.ast.vec();
+        for declarator in decl.declarations.take_in(self.ctx.ast) {
+            declarator.id.bound_names(&mut |ident| {
+                *self.ctx.scoping_mut().symbol_flags_mut(ident.symbol_id()) =
+                    SymbolFlags::FunctionScopedVariable;
+            });
+            let Some(init) = declarator.init else { continue };
+            let target = binding_pattern_to_assignment_target(declarator.id, self.ctx);
+            expressions.push(self.ctx.ast.expression_assignment(
+                declarator.span,
+                AssignmentOperator::Assign,
+                target,
+                init,
+            ));
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, expressions)),
+        }
+    }
+
+    fn declaration_to_statement(&mut self, decl: &mut VariableDeclaration<'a>) -> Statement<'a> {
+        let span = decl.span;
+        match self.declaration_to_expression(decl) {
+            Some(expr) => self.ctx.ast.statement_expression(span, expr),
+            None => self.ctx.ast.statement_empty(span),
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for DeclarationHoister<'a, '_> {
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if let Statement::VariableDeclaration(decl) = stmt
+            && self.is_hoisted(decl)
+        {
+            *stmt = self.declaration_to_statement(decl);
+            return;
+        }
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn visit_for_statement_init(&mut self, init: &mut ForStatementInit<'a>) {
+        if let ForStatementInit::VariableDeclaration(decl) = init
+            && self.is_hoisted(decl)
+        {
+            // `for (var i = 0;;)` -> `for (i = 0;;)`
+            *init = match self.declaration_to_expression(decl) {
+                Some(expr) => ForStatementInit::from(expr),
+                None => ForStatementInit::from(self.ctx.ast.void_0(SPAN)),
+            };
+            return;
+        }
+        walk_mut::walk_for_statement_init(self, init);
+    }
+
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if let ForStatementLeft::VariableDeclaration(decl) = left
+            && self.is_hoisted(decl)
+            && decl.declarations.len() == 1
+        {
+            // `for (var x in obj)` -> `for (x in obj)`
+            let declarator = decl.declarations.pop().unwrap();
+            declarator.id.bound_names(&mut |ident| {
+                *self.ctx.scoping_mut().symbol_flags_mut(ident.symbol_id()) =
+                    SymbolFlags::FunctionScopedVariable;
+            });
+            *left = ForStatementLeft::from(binding_pattern_to_assignment_target(
+                declarator.id,
+                self.ctx,
+            ));
+            return;
+        }
+        walk_mut::walk_for_statement_left(self, left);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {
+        // `var`s in functions are declared in the function's scope
+    }
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &mut ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+
+    fn visit_expression(&mut self, _expr: &mut Expression<'a>) {
+        // Expressions cannot contain statements, except in functions and classes
+    }
+}
+
+/// [`ModuleFormat`] for SystemJS.
+struct SystemJsFormat<'a> {
+    /// `_context` param of the `System.register` function.
+    context: BoundIdentifier<'a>,
+    has_top_level_await: bool,
+}
+
+impl<'a> ModuleFormat<'a> for SystemJsFormat<'a> {
+    /// `import.meta` -> `_context.meta`
+    fn import_meta(&mut self, span: Span, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let object = self.context.create_spanned_read_expression(span, ctx);
+        Expression::from(member(object, Atom::from("meta"), ctx))
+    }
+
+    /// `import(source)` -> `_context.import(source)`
+    fn dynamic_import(
+        &mut self,
+        mut expr: ArenaBox<'a, ImportExpression<'a>>,
+        _scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object = self.context.create_read_expression(ctx);
+        let callee = Expression::from(member(object, Atom::from("import"), ctx));
+        let mut arguments = ctx.ast.vec1(Argument::from(expr.source.take_in(ctx.ast)));
+        if let Some(options) = expr.options.take() {
+            arguments.push(Argument::from(options));
+        }
+        ctx.ast.expression_call(expr.span, callee, NONE, arguments, false)
+    }
+
+    fn top_level_await(&mut self, _span: Span) {
+        self.has_top_level_await = true;
+    }
+}
@@ -0,0 +1,245 @@
+//! UMD
+//!
+//! This plugin is enabled with `Module::Umd`.
+//!
+//! Lower ES modules to the Universal Module Definition format, which loads as AMD, CommonJS,
+//! or by setting a browser global.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo from "foo";
+//! export const x = foo;
+//! ```
+//!
+//! Output:
+//! ```js
+//! (function (global, factory) {
+//!   if (typeof define === "function" && define.amd) {
+//!     define(["exports", "foo"], factory);
+//!   } else if (typeof exports !== "undefined") {
+//!     factory(exports, require("foo"));
+//!   } else {
+//!     var mod = { exports: {} };
+//!     factory(mod.exports, global.foo);
+//!     global.input = mod.exports;
+//!   }
+//! })(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function (_exports, _foo) {
+//!   "use strict";
+//!
+//!   Object.defineProperty(_exports, "__esModule", { value: true });
+//!   _exports.x = void 0;
+//!   _foo = babelHelpers.interopRequireDefault(_foo);
+//!   const x = _exports.x = _foo.default;
+//! });
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-modules-umd](https://babeljs.io/docs/babel-plugin-transform-modules-umd).
+//!
+//! The factory function is the same as for [AMD](super::amd).
+//! `import()` is not supported, as there is no way to load a module asynchronously which works
+//! with all 3 loaders.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-modules-umd/src/index.ts>
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{Atom, SPAN};
+
+use crate::context::{TransformCtx, TraverseCtx};
+
+use super::{
+    amd::{Factory, FactoryParam},
+    metadata::{source_to_name, to_camel_case},
+    utils::{
+        compare_string, create_function_scope, function_expression, member, object, string_array,
+        typeof_global, var_declaration_with_init,
+    },
+};
+
+pub fn transform_program<'a>(
+    program: &mut Program<'a>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let factory = Factory::build(program, true, transform_ctx, ctx);
+
+    let root_scope_id = ctx.scoping().root_scope_id();
+    let scope_id = create_function_scope(root_scope_id, ctx);
+    let global =
+        ctx.generate_binding(Atom::from("global"), scope_id, SymbolFlags::FunctionScopedVariable);
+    let factory_param =
+        ctx.generate_binding(Atom::from("factory"), scope_id, SymbolFlags::FunctionScopedVariable);
+
+    // `if (typeof define === "function" && define.amd) { define(["exports", "foo"], factory); }`
+    let is_amd = {
+        let define_type = compare_string(
+            typeof_global("define", ctx),
+            BinaryOperator::StrictEquality,
+            "function",
+            ctx,
+        );
+        let define =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("define"), ReferenceFlags::Read);
+        let define_amd = Expression::from(member(define, Atom::from("amd"), ctx));
+        ctx.ast.expression_logical(SPAN, define_type, LogicalOperator::And, define_amd)
+    };
+    let define = {
+        let callee =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("define"), ReferenceFlags::Read);
+        let mut arguments = ctx.ast.vec_with_capacity(2);
+        let dependencies = factory.dependency_names();
+        if !dependencies.is_empty() {
+            arguments.push(Argument::from(string_array(dependencies, ctx)));
+        }
+        arguments.push(Argument::from(factory_param.create_read_expression(ctx)));
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        block(ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)), scope_id, ctx)
+    };
+
+    // `else if (typeof exports !== "undefined") { factory(exports, require("foo")); }`
+    let is_commonjs = compare_string(
+        typeof_global("exports", ctx),
+        BinaryOperator::StrictInequality,
+        "undefined",
+        ctx,
+    );
+    let commonjs = {
+        let mut arguments = ctx.ast.vec();
+        for (param, _) in &factory.params {
+            let argument = match param {
+                FactoryParam::Exports => {
+                    ctx.create_unbound_ident_expr(SPAN, Atom::from("exports"), ReferenceFlags::Read)
+                }
+                FactoryParam::Dependency(source) => require(*source, ctx),
+                FactoryParam::Require | FactoryParam::Module => unreachable!(),
+            };
+            arguments.push(Argument::from(argument));
+        }
+        for source in &factory.side_effect_dependencies {
+            arguments.push(Argument::from(require(*source, ctx)));
+        }
+        let callee = factory_param.create_read_expression(ctx);
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        block(ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)), scope_id, ctx)
+    };
+
+    // ```js
+    // else {
+    //   var mod = { exports: {} };
+    //   factory(mod.exports, global.foo);
+    //   global.input = mod.exports;
+    // }
+    // ```
+    let browser = {
+        let module =
+            ctx.generate_binding(Atom::from("mod"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let exports = ctx.ast.expression_object(SPAN, ctx.ast.vec());
+        let init = object([("exports", exports)], ctx);
+        let declaration = var_declaration_with_init(&module, init, ctx);
+
+        let module_exports = |ctx: &mut TraverseCtx<'a>| {
+            Expression::from(member(module.create_read_expression(ctx), Atom::from("exports"), ctx))
+        };
+        let global_property = |name: &str, ctx: &mut TraverseCtx<'a>| {
+            let name = ctx.ast.atom(name);
+            member(global.create_read_expression(ctx), name, ctx)
+        };
+        let mut arguments = ctx.ast.vec();
+        for (param, _) in &factory.params {
+            let argument = match param {
+                FactoryParam::Exports => module_exports(ctx),
+                FactoryParam::Dependency(source) => {
+                    Expression::from(global_property(&source_to_name(source), ctx))
+                }
+                FactoryParam::Require | FactoryParam::Module => unreachable!(),
+            };
+            arguments.push(Argument::from(argument));
+        }
+        for source in &factory.side_effect_dependencies {
+            let argument = global_property(&source_to_name(source), ctx);
+            arguments.push(Argument::from(Expression::from(argument)));
+        }
+        let callee = factory_param.create_read_expression(ctx);
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+
+        let file_name = transform_ctx
+            .source_path
+            .file_stem()
+            .map_or_else(|| String::from("unknown"), |name| name.to_string_lossy().to_string());
+        let target = AssignmentTarget::from(global_property(&to_camel_case(&file_name), ctx));
+        let value = module_exports(ctx);
+        let assignment =
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+
+        let body = ctx.ast.vec_from_array([
+            declaration,
+            ctx.ast.statement_expression(SPAN, call),
+            ctx.ast.statement_expression(SPAN, assignment),
+        ]);
+        block(body, scope_id, ctx)
+    };
+
+    let else_if = ctx.ast.statement_if(SPAN, is_commonjs, commonjs, Some(browser));
+    let if_statement = ctx.ast.statement_if(SPAN, is_amd, define, Some(else_if));
+    let wrapper = function_expression(
+        scope_id,
+        &[global, factory_param],
+        ctx.ast.vec(),
+        ctx.ast.vec1(if_statement),
+        ctx,
+    );
+
+    // `typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this`
+    let global_this = {
+        let global_this_test = compare_string(
+            typeof_global("globalThis", ctx),
+            BinaryOperator::StrictInequality,
+            "undefined",
+            ctx,
+        );
+        let global_this =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("globalThis"), ReferenceFlags::Read);
+        let self_test = compare_string(
+            typeof_global("self", ctx),
+            BinaryOperator::StrictInequality,
+            "undefined",
+            ctx,
+        );
+        let self_ident =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("self"), ReferenceFlags::Read);
+        let this = ctx.ast.expression_this(SPAN);
+        let alternate = ctx.ast.expression_conditional(SPAN, self_test, self_ident, this);
+        ctx.ast.expression_conditional(SPAN, global_this_test, global_this, alternate)
+    };
+
+    let callee = ctx.ast.expression_parenthesized(SPAN, wrapper);
+    let arguments =
+        ctx.ast.vec_from_array([Argument::from(global_this), Argument::from(factory.function)]);
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    program.body.push(ctx.ast.statement_expression(SPAN, call));
+}
+
+/// `require("source")`
+fn require<'a>(source: Atom<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let callee = ctx.create_unbound_ident_expr(SPAN, Atom::from("require"), ReferenceFlags::Read);
+    let arguments =
+        ctx.ast.vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, source, None)));
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// `{ statements }`, with a new block scope in `parent_scope_id`.
+fn block<'a>(
+    statements: ArenaVec<'a, Statement<'a>>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+    ctx.ast.statement_block_with_scope_id(SPAN, statements, scope_id)
+}
@@ -0,0 +1,225 @@
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::{BoundIdentifier, MaybeBoundIdentifier};
+
+use crate::context::TraverseCtx;
+
+/// How values are exported from a module.
+pub enum Exporter<'a> {
+    /// `_exports.name = value` (AMD, UMD), `exports.name = value` (CommonJS)
+    Object(MaybeBoundIdentifier<'a>),
+    /// `_export("name", value)` (SystemJS)
+    Function(BoundIdentifier<'a>),
+}
+
+impl<'a> Exporter<'a> {
+    /// Export `value` under all of `names`.
+    ///
+    /// * `_exports.a = _exports.b = value`
+    /// * `_export("a", _export("b", value))`
+    pub fn export(
+        &self,
+        names: &[Atom<'a>],
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        names.iter().rev().fold(value, |value, &name| match self {
+            Self::Object(exports) => {
+                let object = exports.create_read_expression(ctx);
+                let target = AssignmentTarget::from(member(object, name, ctx));
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+            }
+            Self::Function(export) => {
+                let callee = export.create_read_expression(ctx);
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)),
+                    Argument::from(value),
+                ]);
+                ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+            }
+        })
+    }
+
+    /// `_exports.a = _exports.b = value;` / `_export("a", _export("b", value));`
+    pub fn export_statement(
+        &self,
+        names: &[Atom<'a>],
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let expr = self.export(names, value, ctx);
+        ctx.ast.statement_expression(SPAN, expr)
+    }
+}
+
+/// `object.name`, or `object["name"]` if `name` is not a valid identifier.
+pub fn member<'a>(
+    object: Expression<'a>,
+    name: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> MemberExpression<'a> {
+    if is_identifier_name(&name) {
+        let property = ctx.ast.identifier_name(SPAN, name);
+        ctx.ast.member_expression_static(SPAN, object, property, false)
+    } else {
+        let property = ctx.ast.expression_string_literal(SPAN, name, None);
+        ctx.ast.member_expression_computed(SPAN, object, property, false)
+    }
+}
+
+/// `function (params) { statements }`
+pub fn function_expression<'a>(
+    scope_id: ScopeId,
+    params: &[BoundIdentifier<'a>],
+    directives: ArenaVec<'a, Directive<'a>>,
+    statements: ArenaVec<'a, Statement<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    // Params can be bindings of `import`s, which keep the span of their declaration
+    let items = ctx.ast.vec_from_iter(params.iter().map(|param| {
+        let span = ctx.scoping().symbol_span(param.symbol_id);
+        let id = ctx.ast.binding_identifier_with_symbol_id(span, param.name, param.symbol_id);
+        ctx.ast.plain_formal_parameter(SPAN, BindingPattern::BindingIdentifier(ctx.ast.alloc(id)))
+    }));
+    let params = ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, items, NONE);
+    let body = ctx.ast.function_body(SPAN, directives, statements);
+    Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        params,
+        body,
+        scope_id,
+    ))
+}
+
+/// Create a scope for a function created by the module transforms.
+pub fn create_function_scope(parent_scope_id: ScopeId, ctx: &mut TraverseCtx<'_>) -> ScopeId {
+    ctx.create_child_scope(parent_scope_id, ScopeFlags::Function | ScopeFlags::StrictMode)
+}
+
+/// `Object.defineProperty(object, "name", descriptor)`
+pub fn define_property<'a>(
+    object: Expression<'a>,
+    name: Atom<'a>,
+    descriptor: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let callee = ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+    let callee = Expression::from(member(callee, Atom::from("defineProperty"), ctx));
+    let arguments = ctx.ast.vec_from_array([
+        Argument::from(object),
+        Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)),
+        Argument::from(descriptor),
+    ]);
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// `{ key: value, ... }`
+pub fn object<'a, const N: usize>(
+    properties: [(&'static str, Expression<'a>); N],
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let properties = ctx.ast.vec_from_iter(properties.into_iter().map(|(key, value)| {
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            ctx.ast.property_key_static_identifier(SPAN, Atom::from(key)),
+            value,
+            false,
+            false,
+            false,
+        )
+    }));
+    ctx.ast.expression_object(SPAN, properties)
+}
+
+/// `{ enumerable: true, get: function () { return value; } }`
+pub fn getter_descriptor<'a>(
+    value: Expression<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let scope_id = create_function_scope(parent_scope_id, ctx);
+    let body = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value)));
+    let get = function_expression(scope_id, &[], ctx.ast.vec(), body, ctx);
+    object([("enumerable", ctx.ast.expression_boolean_literal(SPAN, true)), ("get", get)], ctx)
+}
+
+/// `typeof name`
+pub fn typeof_global<'a>(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let ident = ctx.create_unbound_ident_expr(SPAN, Atom::from(name), ReferenceFlags::Read);
+    ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, ident)
+}
+
+/// `left op "right"`
+pub fn compare_string<'a>(
+    left: Expression<'a>,
+    operator: BinaryOperator,
+    right: &'static str,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let right = ctx.ast.expression_string_literal(SPAN, Atom::from(right), None);
+    ctx.ast.expression_binary(SPAN, left, operator, right)
+}
+
+/// `[ "a", "b" ]`
+pub fn string_array<'a>(
+    strings: impl IntoIterator<Item = Atom<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let elements = ctx.ast.vec_from_iter(strings.into_iter().map(|string| {
+        ArrayExpressionElement::from(ctx.ast.expression_string_literal(SPAN, string, None))
+    }));
+    ctx.ast.expression_array(SPAN, elements)
+}
+
+/// `var a, b;`
+pub fn var_declaration<'a>(
+    bindings: &[BoundIdentifier<'a>],
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let kind = VariableDeclarationKind::Var;
+    let declarations = ctx.ast.vec_from_iter(bindings.iter().map(|binding| {
+        ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            binding.create_binding_pattern(ctx),
+            NONE,
+            None,
+            false,
+        )
+    }));
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        declarations,
+        false,
+    ))
+}
+
+/// `var a = init;`
+pub fn var_declaration_with_init<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let kind = VariableDeclarationKind::Var;
+    let declarator = ctx.ast.variable_declarator(
+        SPAN,
+        kind,
+        binding.create_binding_pattern(ctx),
+        NONE,
+        Some(init),
+        false,
+    );
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
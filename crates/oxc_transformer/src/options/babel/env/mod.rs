@@ -22,6 +22,10 @@ pub struct BabelEnvOptions {
     #[deprecated = "Not Implemented"]
     pub loose: bool,
 
+    /// Module format to transform ES modules to.
+    ///
+    /// `"auto"` (the default) and `false` keep ES modules as they are. `"commonjs"`, `"amd"`,
+    /// `"umd"` and `"systemjs"` transform them, see [`EnvOptions::module`](crate::EnvOptions::module).
     pub modules: Module,
 
     #[deprecated = "Not Implemented"]
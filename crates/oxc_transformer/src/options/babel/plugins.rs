@@ -1,9 +1,16 @@
 use serde::Deserialize;
 
 use crate::{
-    DecoratorOptions, TypeScriptOptions, es2015::ArrowFunctionsOptions,
-    es2018::ObjectRestSpreadOptions, es2022::ClassPropertiesOptions, jsx::JsxOptions,
-    plugins::StyledComponentsOptions,
+    CommonJsOptions, DecoratorOptions, TypeScriptOptions,
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ForOfOptions, ParametersOptions, SpreadOptions,
+        TemplateLiteralsOptions,
+    },
+    es2018::ObjectRestSpreadOptions,
+    es2022::ClassPropertiesOptions,
+    jsx::JsxOptions,
+    plugins::{EmotionOptions, StyledComponentsOptions},
 };
 
 use super::PluginPresetEntries;
@@ -40,7 +47,13 @@ pub struct BabelPlugins {
     pub react_jsx_source: bool,
     pub react_display_name: bool,
     // modules
-    pub modules_commonjs: bool,
+    pub modules_commonjs: Option<CommonJsOptions>,
+    pub modules_amd: bool,
+    pub modules_umd: bool,
+    pub modules_systemjs: bool,
+    // proposals
+    pub import_defer: bool,
+    pub import_wasm_source: bool,
     // regexp
     pub sticky_flag: bool,
     pub unicode_flag: bool,
@@ -53,6 +66,17 @@ pub struct BabelPlugins {
     pub set_notation: bool,
     // ES2015
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    pub classes: Option<ClassesOptions>,
+    pub block_scoping: Option<BlockScopingOptions>,
+    pub destructuring: Option<DestructuringOptions>,
+    pub spread: Option<SpreadOptions>,
+    pub parameters: Option<ParametersOptions>,
+    pub template_literals: Option<TemplateLiteralsOptions>,
+    pub shorthand_properties: bool,
+    pub computed_properties: Option<ComputedPropertiesOptions>,
+    pub for_of: Option<ForOfOptions>,
+    pub new_target: bool,
+    pub regenerator: bool,
     // ES2016
     pub exponentiation_operator: bool,
     // ES2017
@@ -77,6 +101,7 @@ pub struct BabelPlugins {
     pub legacy_decorator: Option<DecoratorOptions>,
     // Built-in plugins
     pub styled_components: Option<StyledComponentsOptions>,
+    pub emotion: Option<EmotionOptions>,
     pub tagged_template_escape: bool,
 }
 
@@ -125,7 +150,13 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-react-display-name" => p.react_display_name = true,
                 "transform-react-jsx-self" => p.react_jsx_self = true,
                 "transform-react-jsx-source" => p.react_jsx_source = true,
-                "transform-modules-commonjs" => p.modules_commonjs = true,
+                "transform-modules-commonjs" => {
+                    p.modules_commonjs =
+                        entry.value::<CommonJsOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-modules-amd" => p.modules_amd = true,
+                "transform-modules-umd" => p.modules_umd = true,
+                "transform-modules-systemjs" => p.modules_systemjs = true,
                 "transform-sticky-regex" => p.sticky_flag = true,
                 "transform-unicode-regex" => p.unicode_flag = true,
                 "transform-dotall-regex" => p.dot_all_flag = true,
@@ -140,6 +171,46 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "transform-classes" => {
+                    p.classes =
+                        entry.value::<ClassesOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-block-scoping" => {
+                    p.block_scoping =
+                        entry.value::<BlockScopingOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-destructuring" => {
+                    p.destructuring = entry
+                        .value::<DestructuringOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-spread" => {
+                    p.spread =
+                        entry.value::<SpreadOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-parameters" => {
+                    p.parameters =
+                        entry.value::<ParametersOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-template-literals" => {
+                    p.template_literals = entry
+                        .value::<TemplateLiteralsOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-shorthand-properties" => p.shorthand_properties = true,
+                "transform-computed-properties" => {
+                    p.computed_properties = entry
+                        .value::<ComputedPropertiesOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-for-of" => {
+                    p.for_of = entry.value::<ForOfOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-new-target" => p.new_target = true,
+                "transform-regenerator" => p.regenerator = true,
                 "transform-exponentiation-operator" => p.exponentiation_operator = true,
                 "transform-async-to-generator" => p.async_to_generator = true,
                 "transform-object-rest-spread" => {
@@ -167,12 +238,18 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         entry.value::<DecoratorOptions>().map_err(|err| p.errors.push(err)).ok();
                 }
                 "transform-explicit-resource-management" => p.explicit_resource_management = true,
+                "proposal-import-defer" => p.import_defer = true,
+                "proposal-import-wasm-source" => p.import_wasm_source = true,
                 "styled-components" => {
                     p.styled_components = entry
                         .value::<StyledComponentsOptions>()
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "emotion" => {
+                    p.emotion =
+                        entry.value::<EmotionOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
                 "tagged-template-transform" => {
                     p.tagged_template_escape = true;
                 }
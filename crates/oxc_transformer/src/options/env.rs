@@ -1,7 +1,14 @@
+use std::path::Path;
+
 use serde::Deserialize;
 
 use crate::{
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    CommonJsOptions,
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ES2015Options, ForOfOptions, ParametersOptions, SpreadOptions,
+        TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::{ES2018Options, ObjectRestSpreadOptions},
@@ -22,6 +29,9 @@ pub struct EnvOptions {
     /// Specify what module code is generated.
     pub module: Module,
 
+    /// Options for [`Module::CommonJS`].
+    pub commonjs: CommonJsOptions,
+
     pub regexp: RegExpOptions,
 
     pub es2015: ES2015Options,
@@ -51,6 +61,7 @@ impl EnvOptions {
     pub fn enable_all(include_unfinished_plugins: bool) -> Self {
         Self {
             module: Module::default(),
+            commonjs: CommonJsOptions::default(),
             regexp: RegExpOptions {
                 sticky_flag: true,
                 unicode_flag: true,
@@ -68,6 +79,60 @@ impl EnvOptions {
                 } else {
                     None
                 },
+                // Turned off because it is not ready.
+                classes: if include_unfinished_plugins {
+                    Some(ClassesOptions::default())
+                } else {
+                    None
+                },
+                // Turned off because it is not ready.
+                block_scoping: if include_unfinished_plugins {
+                    Some(BlockScopingOptions::default())
+                } else {
+                    None
+                },
+                // Turned off because it is not ready.
+                destructuring: if include_unfinished_plugins {
+                    Some(DestructuringOptions::default())
+                } else {
+                    None
+                },
+                // Turned off because it is not ready.
+                spread: if include_unfinished_plugins {
+                    Some(SpreadOptions::default())
+                } else {
+                    None
+                },
+                // Turned off because it is not ready.
+                parameters: if include_unfinished_plugins {
+                    Some(ParametersOptions::default())
+                } else {
+                    None
+                },
+                // Turned off because it is not ready.
+                template_literals: if include_unfinished_plugins {
+                    Some(TemplateLiteralsOptions::default())
+                } else {
+                    None
+                },
+                // Turned off because it is not ready.
+                shorthand_properties: include_unfinished_plugins,
+                // Turned off because it is not ready.
+                computed_properties: if include_unfinished_plugins {
+                    Some(ComputedPropertiesOptions::default())
+                } else {
+                    None
+                },
+                // Turned off because it is not ready.
+                for_of: if include_unfinished_plugins {
+                    Some(ForOfOptions::default())
+                } else {
+                    None
+                },
+                // Turned off because it is not ready.
+                new_target: include_unfinished_plugins,
+                // Turned off because it is not ready.
+                regenerator: include_unfinished_plugins,
             },
             es2016: ES2016Options { exponentiation_operator: true },
             es2017: ES2017Options { async_to_generator: true },
@@ -106,6 +171,21 @@ impl EnvOptions {
         EngineTargets::try_from_query(query).map(Self::from)
     }
 
+    /// Initialize from the [browserslist config] that applies to `path`,
+    /// e.g. `.browserslistrc` or the `browserslist` key of `package.json`.
+    ///
+    /// Returns `None` if no config is found.
+    ///
+    /// # Errors
+    ///
+    /// * When the config cannot be read or parsed.
+    /// * When the query failed to parse.
+    ///
+    /// [browserslist config]: <https://github.com/browserslist/browserslist#config-file>
+    pub fn from_browserslist_config(path: &Path) -> Result<Option<Self>, String> {
+        EngineTargets::from_browserslist_config(path).map(|targets| targets.map(Self::from))
+    }
+
     /// # Errors
     ///
     /// * When the query failed to parse.
@@ -121,9 +201,11 @@ impl EnvOptions {
     }
 }
 
+/// Module transforms only apply when `modules` is set to a module format explicitly,
+/// `"auto"` and `false` keep ES modules.
 impl From<BabelEnvOptions> for EnvOptions {
     fn from(o: BabelEnvOptions) -> Self {
-        Self::from(o.targets)
+        Self { module: o.modules, ..Self::from(o.targets) }
     }
 }
 
@@ -133,6 +215,7 @@ impl From<EngineTargets> for EnvOptions {
         use ESFeature::*;
         Self {
             module: Module::default(),
+            commonjs: CommonJsOptions::default(),
             regexp: RegExpOptions {
                 sticky_flag: o.has_feature(ES2015StickyRegex),
                 unicode_flag: o.has_feature(ES2015UnicodeRegex),
@@ -145,6 +228,17 @@ impl From<EngineTargets> for EnvOptions {
             },
             es2015: ES2015Options {
                 arrow_function: o.has_feature(ES2015ArrowFunctions).then(Default::default),
+                classes: o.has_feature(ES2015Classes).then(Default::default),
+                block_scoping: o.has_feature(ES2015BlockScoping).then(Default::default),
+                destructuring: o.has_feature(ES2015Destructuring).then(Default::default),
+                spread: o.has_feature(ES2015Spread).then(Default::default),
+                parameters: o.has_feature(ES2015Parameters).then(Default::default),
+                template_literals: o.has_feature(ES2015TemplateLiterals).then(Default::default),
+                shorthand_properties: o.has_feature(ES2015ShorthandProperties),
+                computed_properties: o.has_feature(ES2015ComputedProperties).then(Default::default),
+                for_of: o.has_feature(ES2015ForOf).then(Default::default),
+                new_target: o.has_feature(ES2015NewTarget),
+                regenerator: o.has_feature(ES2015Regenerator),
             },
             es2016: ES2016Options {
                 exponentiation_operator: o.has_feature(ES2016ExponentiationOperator),
@@ -79,7 +79,11 @@ impl TransformOptions {
             cwd: PathBuf::new(),
             assumptions: CompilerAssumptions::default(),
             typescript: TypeScriptOptions::default(),
-            decorator: DecoratorOptions { legacy: true, emit_decorator_metadata: true },
+            decorator: DecoratorOptions {
+                legacy: true,
+                standard: false,
+                emit_decorator_metadata: true,
+            },
             jsx: JsxOptions {
                 development: true,
                 refresh: Some(ReactRefreshOptions::default()),
@@ -89,7 +93,9 @@ impl TransformOptions {
             proposals: ProposalOptions::default(),
             plugins: PluginsOptions {
                 styled_components: Some(StyledComponentsOptions::default()),
+                emotion: None,
                 tagged_template_transform: true,
+                core_js_polyfills: None,
             },
             helper_loader: HelperLoaderOptions {
                 mode: HelperLoaderMode::Runtime,
@@ -156,8 +162,22 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             .or_else(|| options.plugins.typescript.clone())
             .unwrap_or_default();
 
+        if let Some(o) = &options.plugins.proposal_decorators
+            && o.version != "2023-11"
+        {
+            errors.push(format!(
+                "Decorators version {:?} is not supported yet, only \"2023-11\" is supported.",
+                o.version
+            ));
+        }
+
         let decorator = DecoratorOptions {
             legacy: options.plugins.legacy_decorator.is_some(),
+            standard: options
+                .plugins
+                .proposal_decorators
+                .as_ref()
+                .is_some_and(|o| o.version == "2023-11"),
             emit_decorator_metadata: options
                 .plugins
                 .legacy_decorator
@@ -204,6 +224,21 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let es2015 = ES2015Options {
             arrow_function: options.plugins.arrow_function.or(env.es2015.arrow_function),
+            classes: options.plugins.classes.or(env.es2015.classes),
+            block_scoping: options.plugins.block_scoping.or(env.es2015.block_scoping),
+            destructuring: options.plugins.destructuring.or(env.es2015.destructuring),
+            spread: options.plugins.spread.or(env.es2015.spread),
+            parameters: options.plugins.parameters.or(env.es2015.parameters),
+            template_literals: options.plugins.template_literals.or(env.es2015.template_literals),
+            shorthand_properties: options.plugins.shorthand_properties
+                || env.es2015.shorthand_properties,
+            computed_properties: options
+                .plugins
+                .computed_properties
+                .or(env.es2015.computed_properties),
+            for_of: options.plugins.for_of.or(env.es2015.for_of),
+            new_target: options.plugins.new_target || env.es2015.new_target,
+            regenerator: options.plugins.regenerator || env.es2015.regenerator,
         };
 
         let es2016 = ES2016Options {
@@ -267,6 +302,9 @@ impl TryFrom<&BabelOptions> for TransformOptions {
         if let Some(styled_components) = &options.plugins.styled_components {
             plugins.styled_components = Some(styled_components.clone());
         }
+        if let Some(emotion) = &options.plugins.emotion {
+            plugins.emotion = Some(emotion.clone());
+        }
         plugins.tagged_template_transform = options.plugins.tagged_template_escape;
 
         Ok(Self {
@@ -277,6 +315,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             jsx,
             env: EnvOptions {
                 module,
+                commonjs: options.plugins.modules_commonjs.unwrap_or_default(),
                 regexp,
                 es2015,
                 es2016,
@@ -290,7 +329,10 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                     explicit_resource_management: options.plugins.explicit_resource_management,
                 },
             },
-            proposals: ProposalOptions::default(),
+            proposals: ProposalOptions {
+                import_defer: options.plugins.import_defer,
+                source_phase_imports: options.plugins.import_wasm_source,
+            },
             helper_loader,
             plugins,
         })
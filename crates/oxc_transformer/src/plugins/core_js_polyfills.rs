@@ -0,0 +1,238 @@
+//! core-js Polyfills
+//!
+//! This plugin injects the [core-js] 3 modules needed by the built-ins used in the program,
+//! like Babel's `useBuiltIns: "usage"` option of `@babel/preset-env`.
+//!
+//! Global references, static properties of globals (e.g. `Object.hasOwn`) and instance
+//! properties (e.g. `.at`) are looked up in a table of core-js modules, and a module is
+//! injected when any of the target engines does not support it natively.
+//! References to local bindings which shadow a global are ignored.
+//!
+//! Instance properties are matched by name only, so `x.at(0)` injects the polyfills of both
+//! `Array.prototype.at` and `String.prototype.at`.
+//!
+//! ## Example
+//!
+//! Input (targets `chrome80`):
+//! ```js
+//! Object.hasOwn(obj, "a");
+//! arr.at(-1);
+//! ```
+//!
+//! Output:
+//! ```js
+//! import "core-js/modules/es.object.has-own.js";
+//! import "core-js/modules/es.array.at.js";
+//! import "core-js/modules/es.string.at-alternative.js";
+//! Object.hasOwn(obj, "a");
+//! arr.at(-1);
+//! ```
+//!
+//! `require` calls are injected instead of `import` statements for scripts.
+//!
+//! ## References
+//!
+//! - Babel plugin: <https://github.com/babel/babel-polyfills/tree/main/packages/babel-plugin-polyfill-corejs3>
+//! - core-js: <https://github.com/zloirock/core-js>
+
+use oxc_ast::ast::*;
+use oxc_semantic::IsGlobalReference;
+use oxc_traverse::Traverse;
+
+use crate::{
+    EngineTargets,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct CoreJsPolyfillsOptions {
+    /// The engines to polyfill for.
+    ///
+    /// Modules which are supported by all target engines are not injected.
+    pub targets: EngineTargets,
+}
+
+/// Modules needed by global references, e.g. `Promise`.
+const GLOBALS: &[(&str, &[&str])] = &[
+    ("AggregateError", &["es.aggregate-error"]),
+    ("Map", &["es.map", "es.object.to-string", "es.array.iterator", "es.string.iterator"]),
+    ("Promise", &["es.promise", "es.object.to-string"]),
+    ("Set", &["es.set", "es.object.to-string", "es.array.iterator", "es.string.iterator"]),
+    ("Symbol", &["es.symbol", "es.symbol.description", "es.object.to-string"]),
+    ("WeakMap", &["es.weak-map", "es.object.to-string", "es.array.iterator"]),
+    ("WeakSet", &["es.weak-set", "es.object.to-string", "es.array.iterator"]),
+    ("globalThis", &["es.global-this"]),
+    ("queueMicrotask", &["web.queue-microtask"]),
+    ("structuredClone", &["web.structured-clone"]),
+];
+
+/// Modules needed by static properties of globals, e.g. `Object.hasOwn`.
+const STATIC_PROPERTIES: &[(&str, &str, &[&str])] = &[
+    ("Array", "from", &["es.array.from", "es.string.iterator"]),
+    ("Array", "fromAsync", &["es.array.from-async", "es.promise"]),
+    ("Array", "of", &["es.array.of"]),
+    ("Map", "groupBy", &["es.map.group-by", "es.map"]),
+    ("Math", "cbrt", &["es.math.cbrt"]),
+    ("Math", "log10", &["es.math.log10"]),
+    ("Math", "log2", &["es.math.log2"]),
+    ("Math", "sign", &["es.math.sign"]),
+    ("Math", "trunc", &["es.math.trunc"]),
+    ("Number", "isFinite", &["es.number.is-finite"]),
+    ("Number", "isInteger", &["es.number.is-integer"]),
+    ("Number", "isNaN", &["es.number.is-nan"]),
+    ("Number", "isSafeInteger", &["es.number.is-safe-integer"]),
+    ("Object", "assign", &["es.object.assign"]),
+    ("Object", "entries", &["es.object.entries"]),
+    ("Object", "fromEntries", &["es.object.from-entries", "es.array.iterator"]),
+    ("Object", "getOwnPropertyDescriptors", &["es.object.get-own-property-descriptors"]),
+    ("Object", "groupBy", &["es.object.group-by"]),
+    ("Object", "hasOwn", &["es.object.has-own"]),
+    ("Object", "values", &["es.object.values"]),
+    ("Promise", "allSettled", &["es.promise.all-settled", "es.promise"]),
+    ("Promise", "any", &["es.promise.any", "es.aggregate-error", "es.promise"]),
+    ("Promise", "try", &["es.promise.try", "es.promise"]),
+    ("Promise", "withResolvers", &["es.promise.with-resolvers", "es.promise"]),
+    ("Reflect", "apply", &["es.reflect.apply"]),
+    ("Reflect", "has", &["es.reflect.has"]),
+    ("Reflect", "ownKeys", &["es.reflect.own-keys"]),
+    ("String", "fromCodePoint", &["es.string.from-code-point"]),
+    ("String", "raw", &["es.string.raw"]),
+    ("Symbol", "asyncIterator", &["es.symbol.async-iterator"]),
+];
+
+/// Modules needed by instance properties, e.g. `arr.at`.
+const INSTANCE_PROPERTIES: &[(&str, &[&str])] = &[
+    ("at", &["es.array.at", "es.string.at-alternative"]),
+    ("copyWithin", &["es.array.copy-within"]),
+    ("description", &["es.symbol.description"]),
+    ("difference", &["es.set.difference.v2"]),
+    ("endsWith", &["es.string.ends-with"]),
+    ("fill", &["es.array.fill"]),
+    ("finally", &["es.promise.finally", "es.promise"]),
+    ("find", &["es.array.find"]),
+    ("findIndex", &["es.array.find-index"]),
+    ("findLast", &["es.array.find-last"]),
+    ("findLastIndex", &["es.array.find-last-index"]),
+    ("flat", &["es.array.flat"]),
+    ("flatMap", &["es.array.flat-map"]),
+    ("includes", &["es.array.includes", "es.string.includes"]),
+    ("intersection", &["es.set.intersection.v2"]),
+    ("isDisjointFrom", &["es.set.is-disjoint-from.v2"]),
+    ("isSubsetOf", &["es.set.is-subset-of.v2"]),
+    ("isSupersetOf", &["es.set.is-superset-of.v2"]),
+    ("isWellFormed", &["es.string.is-well-formed"]),
+    ("matchAll", &["es.string.match-all"]),
+    ("padEnd", &["es.string.pad-end"]),
+    ("padStart", &["es.string.pad-start"]),
+    ("repeat", &["es.string.repeat"]),
+    ("replaceAll", &["es.string.replace-all"]),
+    ("startsWith", &["es.string.starts-with"]),
+    ("symmetricDifference", &["es.set.symmetric-difference.v2"]),
+    ("toReversed", &["es.array.to-reversed"]),
+    ("toSorted", &["es.array.to-sorted"]),
+    ("toSpliced", &["es.array.to-spliced"]),
+    ("toWellFormed", &["es.string.to-well-formed"]),
+    ("trimEnd", &["es.string.trim-end"]),
+    ("trimLeft", &["es.string.trim-start"]),
+    ("trimRight", &["es.string.trim-end"]),
+    ("trimStart", &["es.string.trim-start"]),
+    ("union", &["es.set.union.v2"]),
+    ("with", &["es.array.with"]),
+];
+
+pub struct CoreJsPolyfills<'a, 'ctx> {
+    options: CoreJsPolyfillsOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> CoreJsPolyfills<'a, 'ctx> {
+    pub fn new(options: CoreJsPolyfillsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for CoreJsPolyfills<'a, '_> {
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some((_, modules)) = GLOBALS.iter().find(|(name, _)| ident.name == *name)
+            && ident.is_global_reference(ctx.scoping())
+        {
+            self.inject(modules, ctx);
+        }
+    }
+
+    fn enter_static_member_expression(
+        &mut self,
+        expr: &mut StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.inject_property(&expr.object, &expr.property.name, ctx);
+    }
+
+    fn enter_computed_member_expression(
+        &mut self,
+        expr: &mut ComputedMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Expression::StringLiteral(property) = &expr.expression {
+            self.inject_property(&expr.object, &property.value, ctx);
+        }
+    }
+}
+
+impl<'a> CoreJsPolyfills<'a, '_> {
+    /// Inject the modules needed by `object[property]`.
+    fn inject_property(&self, object: &Expression<'a>, property: &str, ctx: &TraverseCtx<'a>) {
+        if let Expression::Identifier(object) = object
+            && object.is_global_reference(ctx.scoping())
+        {
+            if let Some((_, _, modules)) = STATIC_PROPERTIES
+                .iter()
+                .find(|(global, name, _)| object.name == *global && property == *name)
+            {
+                self.inject(modules, ctx);
+            }
+            // Static properties of globals are not instance properties, e.g. `Array.from`.
+            if GLOBALS.iter().any(|(name, _)| object.name == *name)
+                || STATIC_PROPERTIES.iter().any(|(global, _, _)| object.name == *global)
+            {
+                return;
+            }
+        }
+        if let Some((_, modules)) = INSTANCE_PROPERTIES.iter().find(|(name, _)| property == *name) {
+            self.inject(modules, ctx);
+        }
+    }
+
+    fn inject(&self, modules: &[&str], ctx: &TraverseCtx<'a>) {
+        for module in modules {
+            if self.options.targets.needs_core_js_module(module) {
+                let source = ctx.ast.atom_from_strs_array(["core-js/modules/", module, ".js"]);
+                self.ctx.module_imports.add_side_effect_import(source);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_compat::core_js_modules;
+
+    use super::{GLOBALS, INSTANCE_PROPERTIES, STATIC_PROPERTIES};
+
+    #[test]
+    fn modules_have_compat_data() {
+        let modules = GLOBALS
+            .iter()
+            .chain(INSTANCE_PROPERTIES)
+            .flat_map(|(_, modules)| *modules)
+            .chain(STATIC_PROPERTIES.iter().flat_map(|(_, _, modules)| *modules));
+        for module in modules {
+            assert!(core_js_modules().contains_key(module), "{module} has no compat data");
+        }
+    }
+}
@@ -0,0 +1,645 @@
+//! Emotion
+//!
+//! This plugin adds labels and source maps to Emotion styles in development, minifies
+//! CSS in tagged templates, and compiles the JSX `css` prop with Emotion's JSX runtime.
+//!
+//! > This plugin is port from the official Babel plugin for Emotion.
+//!
+//! ## Implementation Status
+//!
+//! > Note: Currently, this plugin only supports Emotion imported via import statements.
+//!
+//! ### Options:
+//! **✅ Fully Supported:**
+//! - `sourceMap`: Appends a source map comment to styles in development
+//! - `autoLabel`: Adds a `label` to styles, from the name of the variable, function or
+//!   property they're assigned to
+//! - `labelFormat`: Format of the `label`, supports `[local]`, `[filename]` and `[dirname]`
+//! - `cssProp`: Uses `@emotion/react` as the JSX import source
+//!
+//! **❌ Not Yet Implemented:**
+//! - `importMap`: Custom import path handling
+//! - `cssPropOptimization`: Serializing object styles in the `css` prop
+//! - The `css` prop with the classic JSX runtime
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import { css } from '@emotion/react';
+//! import styled from '@emotion/styled';
+//!
+//! const style = css`
+//!   color: blue;
+//! `;
+//! const Button = styled.button`
+//!   padding: ${padding}px;
+//! `;
+//! ```
+//!
+//! Output (in development, without source maps):
+//! ```js
+//! import { css } from '@emotion/react';
+//! import styled from '@emotion/styled';
+//!
+//! const style = /*#__PURE__*/ css("color:blue;", "label:style;");
+//! const Button = /*#__PURE__*/ styled("button", {
+//!   target: "e1abcde0",
+//!   label: "Button"
+//! })("padding:", padding, "px;");
+//! ```
+//!
+//! ## References
+//!
+//! - Babel plugin: <https://github.com/emotion-js/emotion/tree/main/packages/babel-plugin>
+//! - Documentation: <https://emotion.sh/docs/@emotion/babel-plugin>
+
+use std::borrow::Cow;
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::SymbolId;
+use oxc_span::SPAN;
+use oxc_traverse::{Ancestor, Traverse};
+
+use crate::{
+    JsxOptions, JsxRuntime,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::styled_components::{get_file_hash, minify_template_literal};
+
+/// The JSX import source used for the `css` prop.
+const JSX_IMPORT_SOURCE: &str = "@emotion/react";
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct EmotionOptions {
+    /// Appends an inline source map comment to styles in development, so that browser
+    /// devtools show where a style was defined.
+    ///
+    /// Default: `true`
+    pub source_map: bool,
+
+    /// Controls when a `label` is added to styles.
+    ///
+    /// Labels are appended to generated class names, e.g. `css-1ew3zbg-Button`, and are
+    /// inferred from the name of the variable, function or property the style is assigned to.
+    ///
+    /// Default: `dev-only`
+    pub auto_label: EmotionAutoLabel,
+
+    /// Format of the `label`.
+    ///
+    /// - `[local]` is replaced with the name of the variable the style is assigned to
+    /// - `[filename]` is replaced with the name of the file, without its extension
+    /// - `[dirname]` is replaced with the name of the directory containing the file
+    ///
+    /// Default: `"[local]"`
+    pub label_format: String,
+
+    /// Uses `@emotion/react` as the import source of the automatic JSX runtime, which
+    /// supports the `css` prop. An import source set via options or a `@jsxImportSource`
+    /// pragma takes precedence.
+    ///
+    /// Default: `true`
+    pub css_prop: bool,
+}
+
+impl Default for EmotionOptions {
+    fn default() -> Self {
+        Self {
+            source_map: true,
+            auto_label: EmotionAutoLabel::default(),
+            label_format: String::from("[local]"),
+            css_prop: true,
+        }
+    }
+}
+
+impl EmotionOptions {
+    /// Set the JSX import source to `@emotion/react`, unless an import source has been set.
+    pub(crate) fn update_jsx_options(&self, jsx: &mut JsxOptions) {
+        if self.css_prop
+            && jsx.jsx_plugin
+            && jsx.runtime == JsxRuntime::Automatic
+            && jsx.dom_expressions.is_none()
+            && jsx.import_source.is_none()
+        {
+            jsx.import_source = Some(String::from(JSX_IMPORT_SOURCE));
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmotionAutoLabel {
+    /// Only add labels in development.
+    #[default]
+    DevOnly,
+    /// Always add labels.
+    Always,
+    /// Never add labels.
+    Never,
+}
+
+/// Functions exported by Emotion packages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EmotionHelper {
+    /// `css` from `@emotion/react` or `@emotion/css`
+    Css,
+    /// `keyframes` from `@emotion/react` or `@emotion/css`
+    Keyframes,
+    /// `injectGlobal` from `@emotion/css`
+    InjectGlobal,
+    /// Default export of `@emotion/styled`
+    Styled,
+}
+
+impl EmotionHelper {
+    fn from_import(source: &str, imported: &str) -> Option<Self> {
+        match (source, imported) {
+            ("@emotion/react" | "@emotion/css", "css") => Some(Self::Css),
+            ("@emotion/react" | "@emotion/css", "keyframes") => Some(Self::Keyframes),
+            ("@emotion/css", "injectGlobal") => Some(Self::InjectGlobal),
+            ("@emotion/styled" | "@emotion/styled/base", "default") => Some(Self::Styled),
+            _ => None,
+        }
+    }
+}
+
+pub struct Emotion<'a, 'ctx> {
+    pub options: EmotionOptions,
+    pub ctx: &'ctx TransformCtx<'a>,
+
+    /// `true` if labels are added to styles
+    label: bool,
+    /// `true` if source maps are appended to styles
+    source_map: bool,
+
+    // State
+    /// Symbols of Emotion imports
+    bindings: Vec<(SymbolId, EmotionHelper)>,
+    /// Counter for generating unique `target` class names of styled components
+    target_count: usize,
+}
+
+impl<'a, 'ctx> Emotion<'a, 'ctx> {
+    pub fn new(options: EmotionOptions, development: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let label = match options.auto_label {
+            EmotionAutoLabel::DevOnly => development,
+            EmotionAutoLabel::Always => true,
+            EmotionAutoLabel::Never => false,
+        };
+        let source_map = options.source_map && development;
+        Self { options, ctx, label, source_map, bindings: vec![], target_count: 0 }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Emotion<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.collect_bindings(program);
+    }
+
+    #[inline] // Because it's a hot path, and most `Expression`s are not `TaggedTemplateExpression`s
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.bindings.is_empty() && matches!(expr, Expression::TaggedTemplateExpression(_)) {
+            self.transform_tagged_template_expression(expr, ctx);
+        }
+    }
+
+    fn enter_call_expression(&mut self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.bindings.is_empty() {
+            return;
+        }
+        if let Expression::Identifier(ident) = &call.callee {
+            match self.get_helper(ident, ctx) {
+                Some(EmotionHelper::Css | EmotionHelper::Keyframes) => {
+                    call.pure = true;
+                    if self.label
+                        && let Some(label) = self.get_label(ctx)
+                    {
+                        let label = ctx.ast.atom_from_strs_array(["label:", &label, ";"]);
+                        call.arguments.push(Argument::from(
+                            ctx.ast.expression_string_literal(SPAN, label, None),
+                        ));
+                    }
+                    self.add_source_map(call, ctx);
+                }
+                Some(EmotionHelper::InjectGlobal) => self.add_source_map(call, ctx),
+                Some(EmotionHelper::Styled) | None => {}
+            }
+        } else if self.transform_styled_callee(&mut call.callee, ctx) {
+            call.pure = true;
+            self.add_source_map(call, ctx);
+        }
+    }
+}
+
+impl<'a> Emotion<'a, '_> {
+    /// Collects import bindings which import from Emotion packages.
+    fn collect_bindings(&mut self, program: &Program<'a>) {
+        for statement in &program.body {
+            let Statement::ImportDeclaration(import) = statement else { continue };
+            let Some(specifiers) = &import.specifiers else { continue };
+            let source = import.source.value.as_str();
+            if !source.starts_with("@emotion/") {
+                continue;
+            }
+
+            for specifier in specifiers {
+                let (imported, local) = match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        (specifier.imported.name(), &specifier.local)
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                        (Atom::from("default"), &specifier.local)
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => continue,
+                };
+                if let Some(helper) = EmotionHelper::from_import(source, &imported) {
+                    self.bindings.push((local.symbol_id(), helper));
+                }
+            }
+        }
+    }
+
+    /// Returns the Emotion helper which the identifier refers to.
+    fn get_helper(
+        &self,
+        ident: &IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<EmotionHelper> {
+        let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        self.bindings.iter().find(|(id, _)| *id == symbol_id).map(|(_, helper)| *helper)
+    }
+
+    /// Returns `true` if the expression creates a styled component, i.e. `styled.div` or `styled(Component)`.
+    fn is_styled(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let ident = match expr {
+            Expression::StaticMemberExpression(member) => &member.object,
+            Expression::CallExpression(call) => &call.callee,
+            _ => return false,
+        };
+        matches!(ident, Expression::Identifier(ident)
+            if self.get_helper(ident, ctx) == Some(EmotionHelper::Styled))
+    }
+
+    /// Transforms `` css`color: ${color};` `` into `css("color:", color, ";")`,
+    /// minifying the CSS.
+    ///
+    /// The resulting call expression gets a label and source map in `enter_call_expression`.
+    fn transform_tagged_template_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let Expression::TaggedTemplateExpression(tagged) = expr else {
+            unreachable!();
+        };
+
+        let is_emotion = match &tagged.tag {
+            Expression::Identifier(ident) => matches!(
+                self.get_helper(ident, ctx),
+                Some(EmotionHelper::Css | EmotionHelper::Keyframes | EmotionHelper::InjectGlobal)
+            ),
+            tag => self.is_styled(tag, ctx),
+        };
+        // Templates with invalid escapes can't be represented as strings
+        if !is_emotion || tagged.quasi.quasis.iter().any(|quasi| quasi.value.cooked.is_none()) {
+            return;
+        }
+
+        let TaggedTemplateExpression { span, tag, mut quasi, type_arguments } =
+            tagged.take_in(ctx.ast);
+        minify_template_literal(&mut quasi, ctx.ast);
+
+        let mut arguments = ctx.ast.vec_with_capacity(quasi.quasis.len() + quasi.expressions.len());
+        let mut expressions = quasi.expressions.into_iter();
+        for quasi in quasi.quasis {
+            let raw = quasi.value.raw;
+            if !raw.is_empty() {
+                let value = match cook_template_raw(&raw) {
+                    Cow::Borrowed(_) => raw,
+                    Cow::Owned(value) => ctx.ast.atom(&value),
+                };
+                arguments.push(Argument::from(
+                    ctx.ast.expression_string_literal(quasi.span, value, None),
+                ));
+            }
+            if let Some(expression) = expressions.next() {
+                arguments.push(Argument::from(expression));
+            }
+        }
+        if arguments.is_empty() {
+            arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, "", None)));
+        }
+
+        *expr = ctx.ast.expression_call(span, tag, type_arguments, arguments, false);
+    }
+
+    /// Transforms `styled.div` into `styled("div", { target, label })`, and `styled(Component)`
+    /// into `styled(Component, { target, label })`.
+    ///
+    /// Returns `true` if the expression creates a styled component.
+    fn transform_styled_callee(
+        &mut self,
+        callee: &mut Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        if !self.is_styled(callee, ctx) {
+            return false;
+        }
+
+        let mut properties = ctx.ast.vec_with_capacity(2);
+        let target = self.get_target_class_name(ctx);
+        properties.push(create_object_property("target", target, ctx));
+        if self.label
+            && let Some(label) = self.get_label(ctx)
+        {
+            properties.push(create_object_property("label", label, ctx));
+        }
+
+        match callee {
+            Expression::StaticMemberExpression(member) => {
+                let member = member.take_in(ctx.ast);
+                let tag = ctx.ast.expression_string_literal(
+                    member.property.span,
+                    member.property.name,
+                    None,
+                );
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(tag),
+                    Argument::from(ctx.ast.expression_object(SPAN, properties)),
+                ]);
+                *callee =
+                    ctx.ast.expression_call(member.span, member.object, NONE, arguments, false);
+            }
+            Expression::CallExpression(call) => match call.arguments.get_mut(1) {
+                // `styled(Component, { shouldForwardProp })`
+                Some(Argument::ObjectExpression(object)) => {
+                    properties.retain(|new_property| {
+                        !object.properties.iter().any(|property| {
+                            matches!((property, new_property), (
+                                ObjectPropertyKind::ObjectProperty(property),
+                                ObjectPropertyKind::ObjectProperty(new_property),
+                            ) if property.key.static_name() == new_property.key.static_name())
+                        })
+                    });
+                    object.properties.extend(properties);
+                }
+                Some(_) => {}
+                None => {
+                    call.arguments
+                        .push(Argument::from(ctx.ast.expression_object(SPAN, properties)));
+                }
+            },
+            _ => unreachable!(),
+        }
+
+        true
+    }
+
+    /// `e<file_hash><count>`
+    fn get_target_class_name(&mut self, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+        let mut buffer = itoa::Buffer::new();
+        let count = buffer.format(self.target_count);
+        self.target_count += 1;
+        ctx.ast.atom_from_strs_array(["e", get_file_hash(self.ctx).as_str(), count])
+    }
+
+    /// Returns the label formatted by `labelFormat`, or `None` if the name of the style is needed
+    /// but can't be inferred.
+    fn get_label(&self, ctx: &TraverseCtx<'a>) -> Option<Atom<'a>> {
+        let format = self.options.label_format.as_str();
+        let local = if format.contains("[local]") { Some(get_local_name(ctx)?) } else { None };
+
+        let path = &self.ctx.source_path;
+        let file_name = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+        let dir_name = path
+            .parent()
+            .and_then(|parent| parent.file_name())
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+
+        let mut label = String::with_capacity(format.len());
+        let mut rest = format;
+        while let Some(start) = rest.find('[') {
+            label.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let (part, len) = if rest.starts_with("[local]") {
+                (local.as_deref().unwrap_or_default(), "[local]".len())
+            } else if rest.starts_with("[filename]") {
+                (file_name, "[filename]".len())
+            } else if rest.starts_with("[dirname]") {
+                (dir_name, "[dirname]".len())
+            } else {
+                label.push('[');
+                rest = &rest[1..];
+                continue;
+            };
+            push_sanitized_label_part(&mut label, part);
+            rest = &rest[len..];
+        }
+        label.push_str(rest);
+
+        Some(ctx.ast.atom(&label))
+    }
+
+    /// Appends `"/*# sourceMappingURL=data:application/json;charset=utf-8;base64,... */"`
+    /// to the arguments of the call, mapping the styles to the position of the call.
+    fn add_source_map(&self, call: &mut CallExpression<'a>, ctx: &TraverseCtx<'a>) {
+        if !self.source_map {
+            return;
+        }
+
+        let source_text = self.ctx.source_text;
+        let start = (call.span.start as usize).min(source_text.len());
+        let before = &source_text[..start];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let line = before.bytes().filter(|&b| b == b'\n').count();
+        let column = before[line_start..].encode_utf16().count();
+
+        // A single segment, mapping generated `1:0` to the original position
+        let mut mappings = String::from("AA"); // Generated column 0, source 0
+        encode_vlq(&mut mappings, line);
+        encode_vlq(&mut mappings, column);
+
+        let file_name =
+            self.ctx.source_path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+        let source_map = serde_json::json!({
+            "version": 3,
+            "sources": [file_name],
+            "names": [],
+            "mappings": mappings,
+            "file": file_name,
+            "sourcesContent": [source_text],
+        });
+        let source_map = BASE64_STANDARD.encode(source_map.to_string());
+        let comment = ctx.ast.atom_from_strs_array([
+            "/*# sourceMappingURL=data:application/json;charset=utf-8;base64,",
+            &source_map,
+            " */",
+        ]);
+        call.arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, comment, None)));
+    }
+}
+
+/// Infers the name of a style from the variable, function or property it's assigned to.
+fn get_local_name<'a>(ctx: &TraverseCtx<'a>) -> Option<Atom<'a>> {
+    for ancestor in ctx.ancestors() {
+        match ancestor {
+            // `const X = css`
+            Ancestor::VariableDeclaratorInit(declarator) => {
+                return declarator.id().get_binding_identifier().map(|ident| ident.name);
+            }
+            // `X = css` or `X.prop = css`
+            Ancestor::AssignmentExpressionRight(assignment) => {
+                return match assignment.left() {
+                    AssignmentTarget::AssignmentTargetIdentifier(ident) => Some(ident.name),
+                    AssignmentTarget::StaticMemberExpression(member) => Some(member.property.name),
+                    _ => None,
+                };
+            }
+            // `const X = { Y: css }`
+            Ancestor::ObjectPropertyValue(property) => {
+                return property.key().static_name().map(|name| ctx.ast.atom(&name));
+            }
+            // `class Y { (static) X = css }`
+            Ancestor::PropertyDefinitionValue(property) => {
+                return property.key().static_name().map(|name| ctx.ast.atom(&name));
+            }
+            // `class Y { X() { return css } }`
+            Ancestor::MethodDefinitionValue(method) => {
+                return method.key().static_name().map(|name| ctx.ast.atom(&name));
+            }
+            // `function X() { return css }`
+            // Anonymous functions continue to look for the variable they're assigned to.
+            Ancestor::FunctionBody(func) => {
+                if let Some(id) = func.id() {
+                    return Some(id.name);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Pushes a part of a label, replacing characters which are not valid in a class name with `-`.
+fn push_sanitized_label_part(label: &mut String, part: &str) {
+    let mut in_invalid = false;
+    for c in part.trim().chars() {
+        if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+            label.push(c);
+            in_invalid = false;
+        } else if !in_invalid {
+            label.push('-');
+            in_invalid = true;
+        }
+    }
+}
+
+/// Get the cooked value of a template element from its raw value.
+///
+/// Escapes which are not valid in templates are kept as is. They can't appear here,
+/// because templates with invalid escapes are not transformed.
+fn cook_template_raw(raw: &str) -> Cow<'_, str> {
+    if !raw.contains(['\\', '\r']) {
+        return Cow::Borrowed(raw);
+    }
+
+    let mut cooked = String::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            // Line terminators are normalized to `\n` in templates
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                cooked.push('\n');
+            }
+            '\\' => match chars.next() {
+                Some('n') => cooked.push('\n'),
+                Some('r') => cooked.push('\r'),
+                Some('t') => cooked.push('\t'),
+                Some('b') => cooked.push('\u{8}'),
+                Some('f') => cooked.push('\u{c}'),
+                Some('v') => cooked.push('\u{b}'),
+                Some('0') => cooked.push('\0'),
+                // Line continuation
+                Some('\n' | '\u{2028}' | '\u{2029}') => {}
+                Some('\r') => {
+                    chars.next_if_eq(&'\n');
+                }
+                Some('x') => {
+                    let hex: String = chars.by_ref().take(2).collect();
+                    push_code_point(&mut cooked, &hex, "\\x");
+                }
+                Some('u') => {
+                    let hex: String = if chars.next_if_eq(&'{').is_some() {
+                        chars.by_ref().take_while(|&c| c != '}').collect()
+                    } else {
+                        chars.by_ref().take(4).collect()
+                    };
+                    push_code_point(&mut cooked, &hex, "\\u");
+                }
+                Some(c) => cooked.push(c),
+                None => cooked.push('\\'),
+            },
+            c => cooked.push(c),
+        }
+    }
+    Cow::Owned(cooked)
+}
+
+fn push_code_point(cooked: &mut String, hex: &str, prefix: &str) {
+    if let Some(c) = u32::from_str_radix(hex, 16).ok().and_then(char::from_u32) {
+        cooked.push(c);
+    } else {
+        cooked.push_str(prefix);
+        cooked.push_str(hex);
+    }
+}
+
+/// Encodes a non-negative number as a Base64 VLQ.
+fn encode_vlq(out: &mut String, value: usize) {
+    const BASE64_CHARS: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut value = value << 1;
+    loop {
+        let mut digit = value & 0b1_1111;
+        value >>= 5;
+        if value > 0 {
+            digit |= 0b10_0000;
+        }
+        out.push(BASE64_CHARS[digit] as char);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+/// `{ key: "value" }`
+//     ^^^^^^^^^^^^
+fn create_object_property<'a>(
+    key: &'static str,
+    value: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    let key = ctx.ast.property_key_static_identifier(SPAN, key);
+    let value = ctx.ast.expression_string_literal(SPAN, value, None);
+    ctx.ast.object_property_kind_object_property(
+        SPAN,
+        PropertyKind::Init,
+        key,
+        value,
+        false,
+        false,
+        false,
+    )
+}
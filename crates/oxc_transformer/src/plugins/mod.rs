@@ -1,7 +1,11 @@
+mod core_js_polyfills;
+mod emotion;
 mod options;
 mod styled_components;
 mod tagged_template_transform;
 
+pub use core_js_polyfills::CoreJsPolyfillsOptions;
+pub use emotion::{EmotionAutoLabel, EmotionOptions};
 pub use options::PluginsOptions;
 use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
@@ -10,27 +14,34 @@ pub use styled_components::StyledComponentsOptions;
 use crate::{
     context::{TransformCtx, TraverseCtx},
     plugins::{
-        styled_components::StyledComponents, tagged_template_transform::TaggedTemplateTransform,
+        core_js_polyfills::CoreJsPolyfills, emotion::Emotion, styled_components::StyledComponents,
+        tagged_template_transform::TaggedTemplateTransform,
     },
     state::TransformState,
 };
 
 pub struct Plugins<'a, 'ctx> {
     styled_components: Option<StyledComponents<'a, 'ctx>>,
+    emotion: Option<Emotion<'a, 'ctx>>,
     tagged_template_escape: Option<TaggedTemplateTransform<'a, 'ctx>>,
+    core_js_polyfills: Option<CoreJsPolyfills<'a, 'ctx>>,
 }
 
 impl<'a, 'ctx> Plugins<'a, 'ctx> {
-    pub fn new(options: PluginsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+    pub fn new(options: PluginsOptions, development: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             styled_components: options
                 .styled_components
                 .map(|options| StyledComponents::new(options, ctx)),
+            emotion: options.emotion.map(|options| Emotion::new(options, development, ctx)),
             tagged_template_escape: if options.tagged_template_transform {
                 Some(TaggedTemplateTransform::new(ctx))
             } else {
                 None
             },
+            core_js_polyfills: options
+                .core_js_polyfills
+                .map(|options| CoreJsPolyfills::new(options, ctx)),
         }
     }
 }
@@ -40,6 +51,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
         if let Some(styled_components) = &mut self.styled_components {
             styled_components.enter_program(node, ctx);
         }
+        if let Some(emotion) = &mut self.emotion {
+            emotion.enter_program(node, ctx);
+        }
     }
 
     fn enter_variable_declarator(
@@ -52,6 +66,36 @@ impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
         }
     }
 
+    fn enter_identifier_reference(
+        &mut self,
+        node: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(core_js_polyfills) = &mut self.core_js_polyfills {
+            core_js_polyfills.enter_identifier_reference(node, ctx);
+        }
+    }
+
+    fn enter_static_member_expression(
+        &mut self,
+        node: &mut StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(core_js_polyfills) = &mut self.core_js_polyfills {
+            core_js_polyfills.enter_static_member_expression(node, ctx);
+        }
+    }
+
+    fn enter_computed_member_expression(
+        &mut self,
+        node: &mut ComputedMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(core_js_polyfills) = &mut self.core_js_polyfills {
+            core_js_polyfills.enter_computed_member_expression(node, ctx);
+        }
+    }
+
     fn enter_expression(
         &mut self,
         node: &mut Expression<'a>,
@@ -60,6 +104,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
         if let Some(styled_components) = &mut self.styled_components {
             styled_components.enter_expression(node, ctx);
         }
+        if let Some(emotion) = &mut self.emotion {
+            emotion.enter_expression(node, ctx);
+        }
         if let Some(tagged_template_escape) = &mut self.tagged_template_escape {
             tagged_template_escape.enter_expression(node, ctx);
         }
@@ -69,5 +116,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
         if let Some(styled_components) = &mut self.styled_components {
             styled_components.enter_call_expression(node, ctx);
         }
+        if let Some(emotion) = &mut self.emotion {
+            emotion.enter_call_expression(node, ctx);
+        }
     }
 }
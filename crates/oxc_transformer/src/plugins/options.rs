@@ -1,7 +1,9 @@
-use super::StyledComponentsOptions;
+use super::{CoreJsPolyfillsOptions, EmotionOptions, StyledComponentsOptions};
 
 #[derive(Default, Debug, Clone)]
 pub struct PluginsOptions {
     pub styled_components: Option<StyledComponentsOptions>,
+    pub emotion: Option<EmotionOptions>,
     pub tagged_template_transform: bool,
+    pub core_js_polyfills: Option<CoreJsPolyfillsOptions>,
 }
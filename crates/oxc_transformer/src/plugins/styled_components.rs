@@ -615,7 +615,7 @@ impl<'a> StyledComponents<'a, '_> {
                 String::with_capacity(PREFIX_LEN)
             };
 
-            prefix.extend(["sc-", self.get_file_hash().as_str(), "-"]);
+            prefix.extend(["sc-", get_file_hash(self.ctx).as_str(), "-"]);
 
             self.component_id_prefix = Some(prefix);
             self.component_id_prefix.as_deref().unwrap()
@@ -628,35 +628,6 @@ impl<'a> StyledComponents<'a, '_> {
         ctx.ast.atom_from_strs_array([prefix, count])
     }
 
-    /// Generates a unique file hash based on the source path or source code.
-    fn get_file_hash(&self) -> InlineString<7, u8> {
-        #[inline]
-        fn base36_encode(mut num: u64) -> InlineString<7, u8> {
-            const BASE36_BYTES: &[u8; 36] = b"abcdefghijklmnopqrstuvwxyz0123456789";
-
-            num %= 36_u64.pow(6); // 36^6, to ensure the result is <= 6 characters long.
-
-            let mut str = InlineString::new();
-            while num != 0 {
-                // SAFETY: `num < 36.pow(6)` to start with, is and divided by 36 on each turn of loop,
-                // so we cannot push more than 6 bytes. Capacity of `InlineString` is 7.
-                // All bytes in `BASE36_BYTES` are ASCII.
-                unsafe { str.push_unchecked(BASE36_BYTES[(num % 36) as usize]) };
-                num /= 36;
-            }
-            str
-        }
-
-        let mut hasher = FxHasher::default();
-        if self.ctx.source_path.is_absolute() {
-            self.ctx.source_path.hash(&mut hasher);
-        } else {
-            self.ctx.source_text.hash(&mut hasher);
-        }
-
-        base36_encode(hasher.finish())
-    }
-
     /// Returns the block name based on the file stem or parent directory name.
     fn get_block_name(&mut self, ctx: &TraverseCtx<'a>) -> Option<Atom<'a>> {
         if !self.options.file_name {
@@ -811,6 +782,35 @@ impl<'a> StyledComponents<'a, '_> {
     }
 }
 
+/// Generates a unique file hash based on the source path or source code.
+pub(super) fn get_file_hash(ctx: &TransformCtx) -> InlineString<7, u8> {
+    #[inline]
+    fn base36_encode(mut num: u64) -> InlineString<7, u8> {
+        const BASE36_BYTES: &[u8; 36] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+        num %= 36_u64.pow(6); // 36^6, to ensure the result is <= 6 characters long.
+
+        let mut str = InlineString::new();
+        while num != 0 {
+            // SAFETY: `num < 36.pow(6)` to start with, is and divided by 36 on each turn of loop,
+            // so we cannot push more than 6 bytes. Capacity of `InlineString` is 7.
+            // All bytes in `BASE36_BYTES` are ASCII.
+            unsafe { str.push_unchecked(BASE36_BYTES[(num % 36) as usize]) };
+            num /= 36;
+        }
+        str
+    }
+
+    let mut hasher = FxHasher::default();
+    if ctx.source_path.is_absolute() {
+        ctx.source_path.hash(&mut hasher);
+    } else {
+        ctx.source_text.hash(&mut hasher);
+    }
+
+    base36_encode(hasher.finish())
+}
+
 fn is_valid_styled_component_source(source: &str) -> bool {
     matches!(
         source,
@@ -863,7 +863,7 @@ fn is_valid_styled_component_source(source: &str) -> bool {
 /// quasis = ["width:", "px;color:red;height:100px;"]
 /// expressions = [width]
 /// ```
-fn minify_template_literal<'a>(lit: &mut TemplateLiteral<'a>, ast: AstBuilder<'a>) {
+pub(super) fn minify_template_literal<'a>(lit: &mut TemplateLiteral<'a>, ast: AstBuilder<'a>) {
     const NOT_IN_STRING: u8 = 0;
     /// `Span` used as a sentinel indicating quasi should be removed.
     /// Source text is limited to max `u32::MAX` bytes, so it's impossible for a `TemplateElement`
@@ -0,0 +1,15 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+#[cold]
+pub fn import_defer_not_supported(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("`import defer` can only be lowered when transforming to CommonJS.")
+        .with_label(span)
+}
+
+#[cold]
+pub fn source_phase_import_not_supported(format: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Source phase imports are not supported in {format} modules."))
+        .with_label(span)
+        .with_help("Use the CommonJS or ES module format to lower source phase imports.")
+}
@@ -0,0 +1,83 @@
+//! Proposal: Deferring Module Evaluation
+//!
+//! This plugin is enabled with `ProposalOptions::import_defer`.
+//!
+//! `import defer * as ns from "x"` loads the module, but only evaluates it when a property of
+//! `ns` is first accessed.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import defer * as ns from "x";
+//! export function f() {
+//!   return ns.value;
+//! }
+//! ```
+//!
+//! Output (with `Module::CommonJS`):
+//! ```js
+//! "use strict";
+//!
+//! Object.defineProperty(exports, "__esModule", { value: true });
+//! exports.f = f;
+//! function _x() {
+//!   const data = babelHelpers.interopRequireWildcard(require("x"));
+//!   _x = function () {
+//!     return data;
+//!   };
+//!   return data;
+//! }
+//! function f() {
+//!   return _x().value;
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-import-defer](https://babeljs.io/docs/babel-plugin-proposal-import-defer).
+//!
+//! Deferred evaluation can only be expressed by a module system which evaluates dependencies on
+//! demand, so lowering is done by the CommonJS transform, which loads deferred dependencies the
+//! same as with its `lazy` option. For any other module format, deferred imports are reported as
+//! an error.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-proposal-import-defer/src/index.ts>
+//! * Proposal: <https://github.com/tc39/proposal-defer-import-eval>
+
+use oxc_ast::ast::*;
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::diagnostics;
+
+pub struct ImportDefer<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ImportDefer<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ImportDefer<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.ctx.module.is_commonjs() {
+            return;
+        }
+        for stmt in &program.body {
+            if let Statement::ImportDeclaration(decl) = stmt
+                && decl.phase == Some(ImportPhase::Defer)
+            {
+                self.ctx.error(diagnostics::import_defer_not_supported(decl.span));
+            }
+        }
+    }
+}
@@ -1,3 +1,53 @@
+//! Proposals
+//!
+//! Lowering of stage 3 proposals, each enabled by its own flag in [`ProposalOptions`].
+
+use oxc_ast::ast::*;
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+mod diagnostics;
+mod import_defer;
 mod options;
+mod source_phase_imports;
 
+use import_defer::ImportDefer;
 pub use options::ProposalOptions;
+use source_phase_imports::SourcePhaseImports;
+
+pub struct Proposals<'a, 'ctx> {
+    import_defer: Option<ImportDefer<'a, 'ctx>>,
+    source_phase_imports: Option<SourcePhaseImports<'a, 'ctx>>,
+}
+
+impl<'a, 'ctx> Proposals<'a, 'ctx> {
+    pub fn new(options: ProposalOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            import_defer: options.import_defer.then(|| ImportDefer::new(ctx)),
+            source_phase_imports: options
+                .source_phase_imports
+                .then(|| SourcePhaseImports::new(ctx)),
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Proposals<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(import_defer) = &mut self.import_defer {
+            import_defer.enter_program(program, ctx);
+        }
+        if let Some(source_phase_imports) = &mut self.source_phase_imports {
+            source_phase_imports.enter_program(program, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(source_phase_imports) = &mut self.source_phase_imports {
+            source_phase_imports.enter_expression(expr, ctx);
+        }
+    }
+}
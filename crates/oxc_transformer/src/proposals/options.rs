@@ -1,5 +1,18 @@
-#[expect(clippy::empty_structs_with_brackets)]
+/// Lowering of stage 3 proposals, which are not part of any ECMAScript version yet.
+///
+/// Each proposal is enabled individually, and only applies to syntax which the parser supports.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ProposalOptions {
-    // Currently no proposals are configured here.
+    /// Load `import defer * as ns from "x"` when a property of `ns` is first accessed.
+    ///
+    /// Only supported when transforming to [`Module::CommonJS`](crate::Module::CommonJS).
+    ///
+    /// <https://github.com/tc39/proposal-defer-import-eval>
+    pub import_defer: bool,
+
+    /// Compile WebAssembly modules imported with `import source x from "./x.wasm"` and
+    /// `import.source("./x.wasm")`.
+    ///
+    /// <https://github.com/tc39/proposal-source-phase-imports>
+    pub source_phase_imports: bool,
 }
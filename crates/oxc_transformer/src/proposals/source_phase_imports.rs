@@ -0,0 +1,272 @@
+//! Proposal: Source Phase Imports
+//!
+//! This plugin is enabled with `ProposalOptions::source_phase_imports`.
+//!
+//! Lower imports of the source phase of WebAssembly modules, which give a compiled
+//! `WebAssembly.Module` instead of an instance's exports.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import source mod from "./mod.wasm";
+//! const lib = import.source("./lib.wasm");
+//! ```
+//!
+//! Output (ES module):
+//! ```js
+//! const mod = await WebAssembly.compileStreaming(fetch(import.meta.resolve("./mod.wasm")));
+//! const lib = WebAssembly.compileStreaming(fetch(import.meta.resolve("./lib.wasm")));
+//! ```
+//!
+//! Output (with `Module::CommonJS`):
+//! ```js
+//! const mod = new WebAssembly.Module(require("fs").readFileSync(require.resolve("./mod.wasm")));
+//! const lib = Promise.resolve().then(function () {
+//!   return WebAssembly.compile(require("fs").readFileSync(require.resolve("./lib.wasm")));
+//! });
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-import-wasm-source](https://babeljs.io/docs/babel-plugin-proposal-import-wasm-source).
+//!
+//! Static imports keep their position in the module body, so a WebAssembly module is compiled when
+//! its `import` is reached, rather than before the module is evaluated.
+//! AMD, UMD and SystemJS have no way to load a file's source, and are reported as an error.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-proposal-import-wasm-source/src/index.ts>
+//! * Proposal: <https://github.com/tc39/proposal-source-phase-imports>
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{Atom, SPAN};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    Module,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::diagnostics;
+
+pub struct SourcePhaseImports<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> SourcePhaseImports<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for SourcePhaseImports<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        for stmt in &mut program.body {
+            if let Statement::ImportDeclaration(decl) = stmt
+                && decl.phase == Some(ImportPhase::Source)
+                && let Some(new_stmt) = self.transform_import_declaration(decl, ctx)
+            {
+                *stmt = new_stmt;
+            }
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::ImportExpression(import) = expr
+            && import.phase == Some(ImportPhase::Source)
+            && let Some(new_expr) = self.transform_import_expression(import, ctx)
+        {
+            *expr = new_expr;
+        }
+    }
+}
+
+impl<'a> SourcePhaseImports<'a, '_> {
+    /// Name of the module format, if source phase imports can't be lowered to it.
+    fn unsupported_format(&self) -> Option<&'static str> {
+        match self.ctx.module {
+            Module::Preserve | Module::Esm | Module::CommonJS => None,
+            Module::Amd => Some("AMD"),
+            Module::Umd => Some("UMD"),
+            Module::SystemJs => Some("SystemJS"),
+        }
+    }
+
+    /// `import source x from "./x.wasm"` -> `const x = ...;`
+    fn transform_import_declaration(
+        &self,
+        decl: &mut ImportDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        if let Some(format) = self.unsupported_format() {
+            self.ctx.error(diagnostics::source_phase_import_not_supported(format, decl.span));
+            return None;
+        }
+        // The parser only allows a single default specifier in a source phase import
+        let Some(ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier)) =
+            decl.specifiers.as_mut().and_then(|specifiers| specifiers.pop())
+        else {
+            return None;
+        };
+        let local = specifier.unbox().local;
+        *ctx.scoping_mut().symbol_flags_mut(local.symbol_id()) =
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable;
+
+        let source = Expression::StringLiteral(ctx.ast.alloc(decl.source.clone()));
+        let init = if self.ctx.module.is_commonjs() {
+            // `new WebAssembly.Module(require("fs").readFileSync(require.resolve(source)))`
+            let callee = web_assembly_member("Module", ctx);
+            let arguments = ctx.ast.vec1(Argument::from(read_file_sync(source, ctx)));
+            ctx.ast.expression_new(SPAN, callee, NONE, arguments)
+        } else {
+            // `await WebAssembly.compileStreaming(fetch(import.meta.resolve(source)))`
+            ctx.ast.expression_await(SPAN, compile_streaming(source, ctx))
+        };
+
+        let kind = VariableDeclarationKind::Const;
+        let id = BindingPattern::BindingIdentifier(ctx.ast.alloc(local));
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, id, NONE, Some(init), false);
+        Some(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            decl.span,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        )))
+    }
+
+    /// `import.source(source)` -> `Promise<WebAssembly.Module>`
+    fn transform_import_expression(
+        &self,
+        import: &mut ImportExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if let Some(format) = self.unsupported_format() {
+            self.ctx.error(diagnostics::source_phase_import_not_supported(format, import.span));
+            return None;
+        }
+        let source = import.source.take_in(ctx.ast);
+        if !self.ctx.module.is_commonjs() {
+            // `WebAssembly.compileStreaming(fetch(import.meta.resolve(source)))`
+            return Some(compile_streaming(source, ctx));
+        }
+
+        // ```js
+        // Promise.resolve(`${source}`).then(function (s) {
+        //   return WebAssembly.compile(require("fs").readFileSync(require.resolve(s)));
+        // })
+        // ```
+        // The source is converted to a string before the promise resolves, as `import.source()` does.
+        let scope_id = ctx.create_child_scope(
+            ctx.current_scope_id(),
+            ScopeFlags::Function | ScopeFlags::StrictMode,
+        );
+        let is_static = matches!(&source, Expression::StringLiteral(_));
+        let (resolved, specifier, source) = if is_static {
+            (None, None, source)
+        } else {
+            let specifier = ctx.generate_binding(
+                Atom::from("s"),
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let quasis = ctx.ast.vec_from_array([false, true].map(|tail| {
+                let value =
+                    TemplateElementValue { raw: Atom::from(""), cooked: Some(Atom::from("")) };
+                ctx.ast.template_element(SPAN, value, tail)
+            }));
+            let template = ctx.ast.expression_template_literal(SPAN, quasis, ctx.ast.vec1(source));
+            let read = specifier.create_read_expression(ctx);
+            (Some(template), Some(specifier), read)
+        };
+
+        let compile = web_assembly_member("compile", ctx);
+        let arguments = ctx.ast.vec1(Argument::from(read_file_sync(source, ctx)));
+        let compiled = ctx.ast.expression_call(SPAN, compile, NONE, arguments, false);
+        let callback = function_expression(specifier.as_ref(), compiled, scope_id, ctx);
+
+        let promise =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Promise"), ReferenceFlags::Read);
+        let resolve = static_member(promise, "resolve", ctx);
+        let arguments = ctx.ast.vec_from_iter(resolved.map(Argument::from));
+        let resolved = ctx.ast.expression_call(SPAN, resolve, NONE, arguments, false);
+        let then = static_member(resolved, "then", ctx);
+        let arguments = ctx.ast.vec1(Argument::from(callback));
+        Some(ctx.ast.expression_call(import.span, then, NONE, arguments, false))
+    }
+}
+
+/// `WebAssembly.compileStreaming(fetch(import.meta.resolve(source)))`
+fn compile_streaming<'a>(source: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let meta = ctx.ast.expression_meta_property(
+        SPAN,
+        ctx.ast.identifier_name(SPAN, Atom::from("import")),
+        ctx.ast.identifier_name(SPAN, Atom::from("meta")),
+    );
+    let resolve = static_member(meta, "resolve", ctx);
+    let url =
+        ctx.ast.expression_call(SPAN, resolve, NONE, ctx.ast.vec1(Argument::from(source)), false);
+    let fetch = ctx.create_unbound_ident_expr(SPAN, Atom::from("fetch"), ReferenceFlags::Read);
+    let response =
+        ctx.ast.expression_call(SPAN, fetch, NONE, ctx.ast.vec1(Argument::from(url)), false);
+    let callee = web_assembly_member("compileStreaming", ctx);
+    ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(Argument::from(response)), false)
+}
+
+/// `require("fs").readFileSync(require.resolve(source))`
+fn read_file_sync<'a>(source: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let require = ctx.create_unbound_ident_expr(SPAN, Atom::from("require"), ReferenceFlags::Read);
+    let resolve = static_member(require, "resolve", ctx);
+    let path =
+        ctx.ast.expression_call(SPAN, resolve, NONE, ctx.ast.vec1(Argument::from(source)), false);
+
+    let require = ctx.create_unbound_ident_expr(SPAN, Atom::from("require"), ReferenceFlags::Read);
+    let fs = ctx.ast.expression_string_literal(SPAN, Atom::from("fs"), None);
+    let fs = ctx.ast.expression_call(SPAN, require, NONE, ctx.ast.vec1(Argument::from(fs)), false);
+    let read_file_sync = static_member(fs, "readFileSync", ctx);
+    ctx.ast.expression_call(SPAN, read_file_sync, NONE, ctx.ast.vec1(Argument::from(path)), false)
+}
+
+/// `WebAssembly.name`
+fn web_assembly_member<'a>(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let object =
+        ctx.create_unbound_ident_expr(SPAN, Atom::from("WebAssembly"), ReferenceFlags::Read);
+    static_member(object, name, ctx)
+}
+
+/// `object.name`
+fn static_member<'a>(
+    object: Expression<'a>,
+    name: &'static str,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let property = ctx.ast.identifier_name(SPAN, Atom::from(name));
+    Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+}
+
+/// `function (param) { return value; }`
+fn function_expression<'a>(
+    param: Option<&BoundIdentifier<'a>>,
+    value: Expression<'a>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let items = ctx.ast.vec_from_iter(
+        param.map(|param| ctx.ast.plain_formal_parameter(SPAN, param.create_binding_pattern(ctx))),
+    );
+    let params = ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, items, NONE);
+    let statements = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value)));
+    let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), statements);
+    Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        params,
+        body,
+        scope_id,
+    ))
+}
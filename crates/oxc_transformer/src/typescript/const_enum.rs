@@ -0,0 +1,225 @@
+//! Const Enum Inlining
+//!
+//! References to members of a `const enum` declared in another file are replaced with the
+//! members' values. When files are transformed in isolation, the values are unknown, so they
+//! are provided up-front with [`ConstEnums`], e.g. collected from the other files (or their
+//! `.d.ts` declarations) by [`ConstEnums::collect`].
+//!
+//! Members of `declare const enum`s in the file are inlined too, as they have no runtime object.
+//!
+//! ## Example
+//!
+//! Input (`Direction` of `./enums` is `{ Up: 0, Down: 1 }`):
+//! ```ts
+//! import { Direction } from "./enums";
+//! move(Direction.Down);
+//! ```
+//!
+//! Output:
+//! ```js
+//! move(1);
+//! ```
+//!
+//! The import is elided when all its references are inlined, unless
+//! [`TypeScriptOptions::only_remove_type_imports`](super::TypeScriptOptions::only_remove_type_imports) is set.
+//!
+//! ## References
+//!
+//! - TypeScript: <https://www.typescriptlang.org/docs/handbook/enums.html#const-enums>
+//! - Babel: <https://babeljs.io/docs/babel-plugin-transform-typescript#optimizeconstenums>
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_semantic::SymbolId;
+use oxc_span::SPAN;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+use super::r#enum::TypeScriptEnum;
+
+/// Value of a `const enum` member.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstEnumValue {
+    Number(f64),
+    String(String),
+}
+
+/// Values of the members of a `const enum`, keyed by member name.
+pub type ConstEnumMembers = FxHashMap<String, ConstEnumValue>;
+
+/// `const enum`s declared outside of the file being transformed.
+///
+/// See [`TypeScriptOptions::const_enums`](super::TypeScriptOptions::const_enums).
+#[derive(Debug, Default, Clone)]
+pub struct ConstEnums {
+    /// Enums exported by modules, keyed by import specifier and then by export name.
+    modules: FxHashMap<String, FxHashMap<String, ConstEnumMembers>>,
+    /// Enums declared in the global scope, e.g. `declare const enum` in a global `.d.ts`.
+    globals: FxHashMap<String, ConstEnumMembers>,
+}
+
+impl ConstEnums {
+    /// Add the enums exported by the module imported as `specifier`, keyed by export name.
+    ///
+    /// `specifier` is matched against the import declarations as written, e.g. `./enums`.
+    pub fn add_module(
+        &mut self,
+        specifier: impl Into<String>,
+        enums: FxHashMap<String, ConstEnumMembers>,
+    ) {
+        self.modules.entry(specifier.into()).or_default().extend(enums);
+    }
+
+    /// Add an enum declared in the global scope.
+    pub fn add_global(&mut self, name: impl Into<String>, members: ConstEnumMembers) {
+        self.globals.insert(name.into(), members);
+    }
+
+    /// Collect the top-level `const enum` declarations of `program`, keyed by enum name.
+    ///
+    /// Members whose value can't be evaluated at build time are omitted.
+    pub fn collect<'a>(
+        program: &Program<'a>,
+        allocator: &'a Allocator,
+    ) -> FxHashMap<String, ConstEnumMembers> {
+        let ast = AstBuilder::new(allocator);
+        let mut evaluator = TypeScriptEnum::new();
+        program
+            .body
+            .iter()
+            .filter_map(const_enum_declaration)
+            .map(|decl| (decl.id.name.to_string(), evaluator.evaluate_members(decl, ast)))
+            .collect()
+    }
+}
+
+fn const_enum_declaration<'b, 'a>(stmt: &'b Statement<'a>) -> Option<&'b TSEnumDeclaration<'a>> {
+    let decl = match stmt {
+        Statement::TSEnumDeclaration(decl) => decl,
+        Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+            Some(Declaration::TSEnumDeclaration(decl)) => decl,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    decl.r#const.then_some(decl)
+}
+
+pub struct TypeScriptConstEnum {
+    const_enums: ConstEnums,
+    /// Enums bound to a symbol, i.e. imported enums and `declare const enum`s.
+    enums: FxHashMap<SymbolId, ConstEnumMembers>,
+    /// Enums exported by namespace imports, e.g. `import * as enums from "./enums"`.
+    namespaces: FxHashMap<SymbolId, FxHashMap<String, ConstEnumMembers>>,
+}
+
+impl TypeScriptConstEnum {
+    pub fn new(const_enums: ConstEnums) -> Self {
+        Self { const_enums, enums: FxHashMap::default(), namespaces: FxHashMap::default() }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptConstEnum {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut evaluator = TypeScriptEnum::new();
+        for stmt in &program.body {
+            if let Statement::ImportDeclaration(decl) = stmt {
+                self.add_import(decl);
+            } else if let Some(decl) = const_enum_declaration(stmt)
+                && decl.declare
+            {
+                let members = evaluator.evaluate_members(decl, ctx.ast);
+                self.enums.insert(decl.id.symbol_id(), members);
+            }
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let (object, property) = match expr {
+            Expression::StaticMemberExpression(member) => {
+                (&member.object, member.property.name.as_str())
+            }
+            Expression::ComputedMemberExpression(member) => match &member.expression {
+                Expression::StringLiteral(property) => (&member.object, property.value.as_str()),
+                _ => return,
+            },
+            _ => return,
+        };
+        let Some((ident, members)) = self.lookup(object, ctx) else { return };
+        let Some(value) = members.get(property).cloned() else { return };
+
+        let (reference_id, name) = (ident.reference_id(), ident.name);
+        ctx.delete_reference(reference_id, &name);
+        *expr = match value {
+            ConstEnumValue::Number(value) => TypeScriptEnum::get_initializer_expr(value, ctx),
+            ConstEnumValue::String(value) => {
+                ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&value), None)
+            }
+        };
+    }
+}
+
+impl TypeScriptConstEnum {
+    /// Bind the local names of the enums imported by `decl`.
+    fn add_import(&mut self, decl: &ImportDeclaration<'_>) {
+        if decl.import_kind.is_type() {
+            return;
+        }
+        let Some(enums) = self.const_enums.modules.get(decl.source.value.as_str()) else {
+            return;
+        };
+        for specifier in decl.specifiers.iter().flatten() {
+            match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    if specifier.import_kind.is_type() {
+                        continue;
+                    }
+                    if let Some(members) = enums.get(specifier.imported.name().as_str()) {
+                        self.enums.insert(specifier.local.symbol_id(), members.clone());
+                    }
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    if let Some(members) = enums.get("default") {
+                        self.enums.insert(specifier.local.symbol_id(), members.clone());
+                    }
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    self.namespaces.insert(specifier.local.symbol_id(), enums.clone());
+                }
+            }
+        }
+    }
+
+    /// Find the enum referenced by `object`, and the identifier the reference goes through.
+    ///
+    /// `object` is either an enum (`Direction`) or an enum of a namespace import (`enums.Direction`).
+    /// Identifiers created by other transforms, which have no reference, are ignored.
+    fn lookup<'b, 'a>(
+        &self,
+        object: &'b Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<(&'b IdentifierReference<'a>, &ConstEnumMembers)> {
+        match object {
+            Expression::Identifier(ident) => {
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id.get()?).symbol_id();
+                let members = match symbol_id {
+                    Some(symbol_id) => self.enums.get(&symbol_id),
+                    None => self.const_enums.globals.get(ident.name.as_str()),
+                }?;
+                Some((ident, members))
+            }
+            Expression::StaticMemberExpression(member) => {
+                let Expression::Identifier(ident) = &member.object else { return None };
+                let symbol_id =
+                    ctx.scoping().get_reference(ident.reference_id.get()?).symbol_id()?;
+                let members =
+                    self.namespaces.get(&symbol_id)?.get(member.property.name.as_str())?;
+                Some((ident, members))
+            }
+            _ => None,
+        }
+    }
+}
@@ -3,7 +3,7 @@ use std::cell::Cell;
 use rustc_hash::FxHashMap;
 
 use oxc_allocator::{StringBuilder, TakeIn, Vec as ArenaVec};
-use oxc_ast::{NONE, ast::*};
+use oxc_ast::{AstBuilder, NONE, ast::*};
 use oxc_ast_visit::{VisitMut, walk_mut};
 use oxc_data_structures::stack::NonEmptyStack;
 use oxc_ecmascript::{ToInt32, ToUint32};
@@ -19,6 +19,8 @@ use oxc_traverse::{BoundIdentifier, Traverse};
 
 use crate::{context::TraverseCtx, state::TransformState};
 
+use super::const_enum::{ConstEnumMembers, ConstEnumValue};
+
 /// enum member values (or None if it can't be evaluated at build time) keyed by names
 type PrevMembers<'a> = FxHashMap<Atom<'a>, Option<ConstantValue<'a>>>;
 
@@ -229,7 +231,7 @@ impl<'a> TypeScriptEnum<'a> {
 
             let init = if let Some(mut initializer) = member.initializer {
                 let constant_value =
-                    self.computed_constant_value(&initializer, &previous_enum_members, ctx);
+                    self.computed_constant_value(&initializer, &previous_enum_members, ctx.ast);
 
                 previous_enum_members.insert(member_name, constant_value);
 
@@ -319,11 +321,44 @@ impl<'a> TypeScriptEnum<'a> {
         statements
     }
 
+    /// Evaluate the members of `decl` without transforming it, e.g. for a `declare const enum`
+    /// which has no runtime object.
+    ///
+    /// Members whose value can't be evaluated at build time are omitted.
+    pub(super) fn evaluate_members(
+        &mut self,
+        decl: &TSEnumDeclaration<'a>,
+        ast: AstBuilder<'a>,
+    ) -> ConstEnumMembers {
+        let mut prev_constant_number = Some(-1.0);
+        let mut previous_enum_members = self.enums.entry(decl.id.name).or_default().clone();
+
+        for member in &decl.body.members {
+            let constant_value = if let Some(initializer) = &member.initializer {
+                self.computed_constant_value(initializer, &previous_enum_members, ast)
+            } else {
+                prev_constant_number.map(|value| ConstantValue::Number(value + 1.0))
+            };
+            prev_constant_number = match constant_value {
+                Some(ConstantValue::Number(value)) => Some(value),
+                _ => None,
+            };
+            previous_enum_members.insert(member.id.static_name(), constant_value);
+        }
+
+        let members = previous_enum_members
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), ConstEnumValue::from((*value)?))))
+            .collect();
+        self.enums.insert(decl.id.name, previous_enum_members);
+        members
+    }
+
     fn get_number_literal_expression(value: f64, ctx: &TraverseCtx<'a>) -> Expression<'a> {
         ctx.ast.expression_numeric_literal(SPAN, value, None, NumberBase::Decimal)
     }
 
-    fn get_initializer_expr(value: f64, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    pub(super) fn get_initializer_expr(value: f64, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
         let is_negative = value < 0.0;
 
         // Infinity
@@ -354,6 +389,15 @@ enum ConstantValue<'a> {
     String(Atom<'a>),
 }
 
+impl From<ConstantValue<'_>> for ConstEnumValue {
+    fn from(value: ConstantValue<'_>) -> Self {
+        match value {
+            ConstantValue::Number(value) => Self::Number(value),
+            ConstantValue::String(value) => Self::String(value.to_string()),
+        }
+    }
+}
+
 impl<'a> TypeScriptEnum<'a> {
     /// Evaluate the expression to a constant value.
     /// Refer to [babel](https://github.com/babel/babel/blob/610897a9a96c5e344e77ca9665df7613d2f88358/packages/babel-plugin-transform-typescript/src/enum.ts#L241C1-L394C2)
@@ -361,9 +405,9 @@ impl<'a> TypeScriptEnum<'a> {
         &self,
         expr: &Expression<'a>,
         prev_members: &PrevMembers<'a>,
-        ctx: &TraverseCtx<'a>,
+        ast: AstBuilder<'a>,
     ) -> Option<ConstantValue<'a>> {
-        self.evaluate(expr, prev_members, ctx)
+        self.evaluate(expr, prev_members, ast)
     }
 
     fn evaluate_ref(
@@ -405,7 +449,7 @@ impl<'a> TypeScriptEnum<'a> {
         &self,
         expr: &Expression<'a>,
         prev_members: &PrevMembers<'a>,
-        ctx: &TraverseCtx<'a>,
+        ast: AstBuilder<'a>,
     ) -> Option<ConstantValue<'a>> {
         match expr {
             Expression::Identifier(_)
@@ -413,10 +457,10 @@ impl<'a> TypeScriptEnum<'a> {
             | Expression::StaticMemberExpression(_)
             | Expression::PrivateFieldExpression(_) => self.evaluate_ref(expr, prev_members),
             Expression::BinaryExpression(expr) => {
-                self.eval_binary_expression(expr, prev_members, ctx)
+                self.eval_binary_expression(expr, prev_members, ast)
             }
             Expression::UnaryExpression(expr) => {
-                self.eval_unary_expression(expr, prev_members, ctx)
+                self.eval_unary_expression(expr, prev_members, ast)
             }
             Expression::NumericLiteral(lit) => Some(ConstantValue::Number(lit.value)),
             Expression::StringLiteral(lit) => Some(ConstantValue::String(lit.value)),
@@ -424,11 +468,11 @@ impl<'a> TypeScriptEnum<'a> {
                 let value = if let Some(quasi) = lit.single_quasi() {
                     quasi
                 } else {
-                    let mut value = StringBuilder::new_in(ctx.ast.allocator);
+                    let mut value = StringBuilder::new_in(ast.allocator);
                     for (i, quasi) in lit.quasis.iter().enumerate() {
                         value.push_str(&quasi.value.cooked.unwrap_or(quasi.value.raw));
                         if i < lit.expressions.len() {
-                            match self.evaluate(&lit.expressions[i], prev_members, ctx)? {
+                            match self.evaluate(&lit.expressions[i], prev_members, ast)? {
                                 ConstantValue::String(str) => value.push_str(&str),
                                 ConstantValue::Number(num) => value.push_str(&num.to_js_string()),
                             }
@@ -439,7 +483,7 @@ impl<'a> TypeScriptEnum<'a> {
                 Some(ConstantValue::String(value))
             }
             Expression::ParenthesizedExpression(expr) => {
-                self.evaluate(&expr.expression, prev_members, ctx)
+                self.evaluate(&expr.expression, prev_members, ast)
             }
             _ => None,
         }
@@ -449,10 +493,10 @@ impl<'a> TypeScriptEnum<'a> {
         &self,
         expr: &BinaryExpression<'a>,
         prev_members: &PrevMembers<'a>,
-        ctx: &TraverseCtx<'a>,
+        ast: AstBuilder<'a>,
     ) -> Option<ConstantValue<'a>> {
-        let left = self.evaluate(&expr.left, prev_members, ctx)?;
-        let right = self.evaluate(&expr.right, prev_members, ctx)?;
+        let left = self.evaluate(&expr.left, prev_members, ast)?;
+        let right = self.evaluate(&expr.right, prev_members, ast)?;
 
         if matches!(expr.operator, BinaryOperator::Addition)
             && (matches!(left, ConstantValue::String(_))
@@ -460,16 +504,16 @@ impl<'a> TypeScriptEnum<'a> {
         {
             let left_string = match left {
                 ConstantValue::String(str) => str,
-                ConstantValue::Number(v) => ctx.ast.atom(&v.to_js_string()),
+                ConstantValue::Number(v) => ast.atom(&v.to_js_string()),
             };
 
             let right_string = match right {
                 ConstantValue::String(str) => str,
-                ConstantValue::Number(v) => ctx.ast.atom(&v.to_js_string()),
+                ConstantValue::Number(v) => ast.atom(&v.to_js_string()),
             };
 
             return Some(ConstantValue::String(
-                ctx.ast.atom_from_strs_array([&left_string, &right_string]),
+                ast.atom_from_strs_array([&left_string, &right_string]),
             ));
         }
 
@@ -516,9 +560,9 @@ impl<'a> TypeScriptEnum<'a> {
         &self,
         expr: &UnaryExpression<'a>,
         prev_members: &PrevMembers<'a>,
-        ctx: &TraverseCtx<'a>,
+        ast: AstBuilder<'a>,
     ) -> Option<ConstantValue<'a>> {
-        let value = self.evaluate(&expr.argument, prev_members, ctx)?;
+        let value = self.evaluate(&expr.argument, prev_members, ast)?;
 
         let value = match value {
             ConstantValue::Number(value) => value,
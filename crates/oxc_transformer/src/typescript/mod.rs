@@ -9,6 +9,7 @@ use crate::{
 
 mod annotations;
 mod class;
+mod const_enum;
 mod diagnostics;
 mod r#enum;
 mod module;
@@ -17,6 +18,8 @@ mod options;
 mod rewrite_extensions;
 
 use annotations::TypeScriptAnnotations;
+use const_enum::TypeScriptConstEnum;
+pub use const_enum::{ConstEnumMembers, ConstEnumValue, ConstEnums};
 use r#enum::TypeScriptEnum;
 use module::TypeScriptModule;
 use namespace::TypeScriptNamespace;
@@ -48,6 +51,7 @@ pub struct TypeScript<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
 
     annotations: TypeScriptAnnotations<'a, 'ctx>,
+    const_enum: TypeScriptConstEnum,
     r#enum: TypeScriptEnum<'a>,
     namespace: TypeScriptNamespace<'a, 'ctx>,
     module: TypeScriptModule<'a, 'ctx>,
@@ -61,6 +65,7 @@ impl<'a, 'ctx> TypeScript<'a, 'ctx> {
         Self {
             ctx,
             annotations: TypeScriptAnnotations::new(options, ctx),
+            const_enum: TypeScriptConstEnum::new(options.const_enums.clone()),
             r#enum: TypeScriptEnum::new(),
             namespace: TypeScriptNamespace::new(options, ctx),
             module: TypeScriptModule::new(options.only_remove_type_imports, ctx),
@@ -80,6 +85,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
             program.body.clear();
         } else {
             program.source_type = program.source_type.with_javascript(true);
+            self.const_enum.enter_program(program, ctx);
             self.namespace.enter_program(program, ctx);
             self.module.enter_program(program, ctx);
         }
@@ -139,6 +145,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.enter_expression(expr, ctx);
+        self.const_enum.enter_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
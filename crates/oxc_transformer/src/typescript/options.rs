@@ -5,6 +5,8 @@ use serde::{
     de::{self, Visitor},
 };
 
+use super::ConstEnums;
+
 fn default_for_jsx_pragma() -> Cow<'static, str> {
     Cow::Borrowed("React.createElement")
 }
@@ -87,6 +89,15 @@ pub struct TypeScriptOptions {
     /// Unused.
     pub optimize_const_enums: bool,
 
+    /// Values of `const enum`s declared in other files.
+    ///
+    /// References to their members are replaced with the values, and imports which are
+    /// only used by these references are removed.
+    ///
+    /// Defaults to none.
+    #[serde(skip)]
+    pub const_enums: ConstEnums,
+
     // Preset options
     /// Modifies extensions in import and export declarations.
     ///
@@ -108,6 +119,7 @@ impl Default for TypeScriptOptions {
             allow_declare_fields: default_as_true(),
             remove_class_fields_without_initializer: false,
             optimize_const_enums: false,
+            const_enums: ConstEnums::default(),
             rewrite_import_extensions: None,
         }
     }
@@ -0,0 +1,78 @@
+use std::path::Path;
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{ConstEnumValue, ConstEnums, TransformOptions, Transformer};
+
+fn test(source_text: &str, const_enums: &ConstEnums) -> String {
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::ts()).parse().program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let mut options = TransformOptions::default();
+    options.typescript.const_enums = const_enums.clone();
+    Transformer::new(&allocator, Path::new(""), &options).build_with_scoping(scoping, &mut program);
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+fn const_enums() -> ConstEnums {
+    let allocator = Allocator::default();
+    let source_text = "
+        export declare const enum Direction { Up, Down, Left = Down + 10, Right }
+        export declare const enum Color { Red = 'RED', Green = `${Red}_GREEN`, Blue = -1 }
+        export declare const enum Computed { A = Math.random(), B = 1 }
+        export declare enum NotConst { A }
+        declare const enum Local { A }
+    ";
+    let program = Parser::new(&allocator, source_text, SourceType::d_ts()).parse().program;
+    let enums = ConstEnums::collect(&program, &allocator);
+    assert_eq!(enums["Direction"]["Right"], ConstEnumValue::Number(12.0));
+    assert_eq!(enums["Color"]["Green"], ConstEnumValue::String("RED_GREEN".to_string()));
+    assert!(!enums["Computed"].contains_key("A"));
+    assert!(!enums.contains_key("NotConst"));
+
+    let mut const_enums = ConstEnums::default();
+    const_enums.add_module("./enums", enums);
+    let mut global = FxHashMap::default();
+    global.insert("A".to_string(), ConstEnumValue::Number(1.0));
+    const_enums.add_global("GlobalEnum", global);
+    const_enums
+}
+
+#[test]
+fn const_enums_inline_imports() {
+    let const_enums = const_enums();
+    let cases = [
+        (
+            "import { Direction, Color } from './enums';\nf(Direction.Left, Direction['Right'], Color.Green, Color.Blue);",
+            "f(11, 12, 'RED_GREEN', -1);\nexport {};\n",
+        ),
+        // Renamed and namespace imports
+        (
+            "import { Direction as D } from './enums';\nimport * as enums from './enums';\nf(D.Down, enums.Direction.Up);",
+            "f(1, 0);\nexport {};\n",
+        ),
+        // The import is kept while the enum object is referenced
+        (
+            "import { Direction, Computed } from './enums';\nf(Direction, Computed.A, Computed.B);",
+            "import { Direction, Computed } from './enums';\nf(Direction, Computed.A, 1);\n",
+        ),
+        // Shadowed imports and other modules are not inlined
+        (
+            "import { Direction } from './other';\nf(Direction.Up, (Direction) => Direction.Up);",
+            "import { Direction } from './other';\nf(Direction.Up, (Direction) => Direction.Up);\n",
+        ),
+        // Global enums and `declare const enum` in the file
+        ("declare const enum Local { A = 2, B }\nf(GlobalEnum.A, Local.B);", "f(1, 3);\n"),
+    ];
+    for (source_text, expected) in cases {
+        assert_eq!(test(source_text, &const_enums), expected, "{source_text}");
+    }
+}
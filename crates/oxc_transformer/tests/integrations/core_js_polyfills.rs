@@ -0,0 +1,79 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{CoreJsPolyfillsOptions, EngineTargets, TransformOptions, Transformer};
+
+use crate::test;
+
+fn options(targets: &str) -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.plugins.core_js_polyfills =
+        Some(CoreJsPolyfillsOptions { targets: EngineTargets::from_target(targets).unwrap() });
+    options
+}
+
+#[test]
+fn core_js_polyfills() {
+    use std::fmt::Write;
+
+    let cases = [
+        // Static properties of globals
+        "Object.hasOwn(a, 'b'); Promise.withResolvers(); Array.from(a);",
+        // Instance properties, including computed string keys and optional chains
+        "a.at(-1); b['replaceAll']('c', 'd'); c?.findLast(f); d.flat().flat();",
+        // Globals
+        "new Map(); new Set(); globalThis.x; structuredClone(a);",
+        // Shadowed globals and unknown properties are ignored
+        "const Object = {}; Object.hasOwn(a); let Map; new Map(); a.foo();",
+        // Supported by the targets
+        "Array.of(1); Math.trunc(1); a.padStart(2);",
+    ];
+
+    let options = options("chrome60");
+
+    #[cfg_attr(miri, expect(unused_variables))]
+    let snapshot = cases.into_iter().enumerate().fold(String::new(), |mut w, (i, case)| {
+        let result = test(case, &options).unwrap();
+        write!(w, "########## {i}\n{case}\n----------\n{result}\n").unwrap();
+        w
+    });
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("core_js_polyfills", snapshot);
+        });
+    }
+}
+
+#[test]
+fn core_js_polyfills_targets() {
+    let code = "Object.hasOwn(a, 'b');\na.at(-1);\nnew Promise(f);\n";
+    // Supported natively.
+    assert_eq!(test(code, &options("chrome100")).unwrap(), code);
+    assert_eq!(test(code, &options("es2022")).unwrap(), code);
+    // Only `Object.hasOwn` is missing.
+    assert_eq!(
+        test(code, &options("chrome92")).unwrap(),
+        format!("import 'core-js/modules/es.object.has-own.js';\n{code}")
+    );
+}
+
+#[test]
+fn core_js_polyfills_require() {
+    let source_text = "Object.hasOwn(a, 'b');";
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::cjs()).parse().program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    Transformer::new(&allocator, Path::new(""), &options("chrome80"))
+        .build_with_scoping(scoping, &mut program);
+    let code = Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    assert_eq!(code, "require('core-js/modules/es.object.has-own.js');\nObject.hasOwn(a, 'b');\n");
+}
@@ -0,0 +1,108 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{
+    DomExpressionsGenerate, DomExpressionsOptions, TransformOptions, Transformer,
+};
+
+fn test(source_text: &str, options: &TransformOptions) -> String {
+    let source_type = SourceType::jsx();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new("test.jsx"), options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+fn options(generate: DomExpressionsGenerate) -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.jsx.dom_expressions =
+        Some(DomExpressionsOptions { generate, ..DomExpressionsOptions::default() });
+    options
+}
+
+const CASES: &[&str] = &[
+    // Static templates are hoisted and shared
+    "const a = <div class=\"box\" id='a'>Hello &amp; <b>world</b></div>; const b = <div class=\"box\" id='a'>Hello &amp; <b>world</b></div>;",
+    // Inserted children, with and without markers
+    "<div>{a}</div>; <div>Hello {name()}<span>!</span></div>; <p>{a} and {b.c}</p>; <ul><li>{x}</li>{y}</ul>;",
+    // Attributes
+    "<input disabled value={value()} />; <div className={cls()} style={style} title={t} attr:data-x={x} prop:foo={foo} />;",
+    // Events, refs and directives
+    "<button onClick={inc} onScroll={scroll} on:custom={custom}>+</button>; <div ref={el} use:tooltip={text()} />; <div ref={(e) => (el = e)} />;",
+    // Spread attributes
+    "<div {...props} class=\"a\">{child}</div>;",
+    // Components
+    "<Show when={visible()} fallback={<p>Loading</p>}><Item name=\"a\" count={1} {...rest} /></Show>; <ctx.Provider value={v}>text</ctx.Provider>;",
+    // Fragments
+    "<>a{b()}<div /></>; <>{x}</>;",
+];
+
+fn snapshot(generate: DomExpressionsGenerate) -> String {
+    use std::fmt::Write;
+
+    let options = options(generate);
+    CASES.iter().enumerate().fold(String::new(), |mut w, (i, case)| {
+        let result = test(case, &options);
+        write!(w, "########## {i}\n{case}\n----------\n{result}\n").unwrap();
+        w
+    })
+}
+
+#[test]
+fn dom_expressions_dom() {
+    #[cfg_attr(miri, expect(unused_variables))]
+    let snapshot = snapshot(DomExpressionsGenerate::Dom);
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("dom_expressions_dom", snapshot);
+        });
+    }
+}
+
+#[test]
+fn dom_expressions_ssr() {
+    #[cfg_attr(miri, expect(unused_variables))]
+    let snapshot = snapshot(DomExpressionsGenerate::Ssr);
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("dom_expressions_ssr", snapshot);
+        });
+    }
+}
+
+#[test]
+fn dom_expressions_module_name() {
+    let mut options = options(DomExpressionsGenerate::Dom);
+    if let Some(dom_expressions) = &mut options.jsx.dom_expressions {
+        dom_expressions.module_name = "my-renderer".to_string();
+        dom_expressions.delegate_events = false;
+    }
+    let result = test("<a onClick={go}>{x}</a>", &options);
+    assert_eq!(
+        result,
+        "import { template as _template, insert as _insert } from 'my-renderer';\n\
+         var _tmpl = /* @__PURE__ */ _template('<a></a>');\n\
+         (() => {\n\
+         \tvar _el = _tmpl();\n\
+         \t_el.addEventListener('click', go);\n\
+         \t_insert(_el, x);\n\
+         \treturn _el;\n\
+         })();\n"
+    );
+}
@@ -0,0 +1,148 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{EmotionAutoLabel, EmotionOptions, TransformOptions, Transformer};
+
+fn test(source_text: &str, source_path: &str, options: &TransformOptions) -> String {
+    let source_type = SourceType::from_path(source_path).unwrap();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new(source_path), options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+fn options(development: bool, emotion: EmotionOptions) -> TransformOptions {
+    let mut options = TransformOptions::default();
+    options.jsx.development = development;
+    options.plugins.emotion = Some(emotion);
+    options
+}
+
+#[test]
+fn emotion() {
+    use std::fmt::Write;
+
+    let cases = [
+        // Tagged templates are minified and labelled
+        "import { css } from '@emotion/react';
+const style = css`
+  color: ${color}; /* comment */
+  &:hover { content: '\\\\201C'; }
+`;",
+        // Calls are labelled
+        "import { css, keyframes } from '@emotion/react';
+import { injectGlobal } from '@emotion/css';
+const style = css({ color: 'red' }, other);
+const bounce = keyframes`from { top: 0 } to { top: 10px }`;
+injectGlobal`body { margin: 0 }`;",
+        // Styled components get a target and label
+        "import styled from '@emotion/styled';
+const Button = styled.button`padding: ${(p) => p.padding}px;`;
+const Link = styled(RouterLink)({ color: 'blue' });
+const Input = styled('input', { shouldForwardProp, label: 'Custom' })`border: none;`;",
+        // Labels are inferred from functions, properties and classes
+        "import { css } from '@emotion/react';
+function Card() { return [css`color: red;`, { title: css`color: blue;` }]; }
+const Avatar = () => css`border-radius: 50%;`;
+class Panel { header = css`margin: 0;`; render() { return css`padding: 0;`; } }
+theme.link = css`color: green;`;
+css`color: black;`;",
+        // Other bindings are not transformed
+        "import { css } from 'other';
+import styled from '@emotion/styled';
+css`color: red;`;
+function f(styled) { return styled.div`color: red;`; }",
+    ];
+
+    let options = options(true, EmotionOptions { source_map: false, ..EmotionOptions::default() });
+
+    #[cfg_attr(miri, expect(unused_variables))]
+    let snapshot = cases.into_iter().enumerate().fold(String::new(), |mut w, (i, case)| {
+        let result = test(case, "styles.js", &options);
+        write!(w, "########## {i}\n{case}\n----------\n{result}\n").unwrap();
+        w
+    });
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("emotion", snapshot);
+        });
+    }
+}
+
+#[test]
+fn emotion_production() {
+    let code = "import { css } from '@emotion/react';\nconst style = css`\n  color: red;\n`;\n";
+    let expected =
+        "import { css } from '@emotion/react';\nconst style = /* @__PURE__ */ css('color:red;');\n";
+    assert_eq!(test(code, "styles.js", &options(false, EmotionOptions::default())), expected);
+
+    // `autoLabel: "always"` adds labels in production
+    let emotion =
+        EmotionOptions { auto_label: EmotionAutoLabel::Always, ..EmotionOptions::default() };
+    assert_eq!(
+        test(code, "styles.js", &options(false, emotion)),
+        "import { css } from '@emotion/react';\nconst style = /* @__PURE__ */ css('color:red;', 'label:style;');\n"
+    );
+
+    // `autoLabel: "never"` doesn't add labels in development
+    let emotion = EmotionOptions {
+        auto_label: EmotionAutoLabel::Never,
+        source_map: false,
+        ..EmotionOptions::default()
+    };
+    assert_eq!(test(code, "styles.js", &options(true, emotion)), expected);
+}
+
+#[test]
+fn emotion_label_format() {
+    let emotion = EmotionOptions {
+        source_map: false,
+        label_format: "[dirname]-[filename]--[local]".to_string(),
+        ..EmotionOptions::default()
+    };
+    let code = "import { css } from '@emotion/react';\nconst my$style = css`color: red;`;\n";
+    assert_eq!(
+        test(code, "src/Button/index.js", &options(true, emotion)),
+        "import { css } from '@emotion/react';\nconst my$style = /* @__PURE__ */ css('color:red;', 'label:Button-index--my-style;');\n"
+    );
+}
+
+#[test]
+fn emotion_source_map() {
+    let code = "import { css } from '@emotion/react';\nconst style = css`color: red;`;\n";
+    let result = test(code, "styles.js", &options(true, EmotionOptions::default()));
+    let prefix = "const style = /* @__PURE__ */ css('color:red;', 'label:style;', '/*# sourceMappingURL=data:application/json;charset=utf-8;base64,";
+    assert!(result.contains(prefix), "{result}");
+    assert!(result.ends_with(" */');\n"), "{result}");
+}
+
+#[test]
+fn emotion_css_prop() {
+    let code = "const a = <div css={style} />;\n";
+    let options = options(false, EmotionOptions::default());
+    assert_eq!(
+        test(code, "App.jsx", &options),
+        "import { jsx as _jsx } from '@emotion/react/jsx-runtime';\nconst a = /* @__PURE__ */ _jsx('div', { css: style });\n"
+    );
+
+    // `@jsxImportSource` takes precedence
+    let code = format!("/** @jsxImportSource preact */\n{code}");
+    assert_eq!(
+        test(&code, "App.jsx", &options),
+        "import { jsx as _jsx } from 'preact/jsx-runtime';\n/** @jsxImportSource preact */\nconst a = /* @__PURE__ */ _jsx('div', { css: style });\n"
+    );
+}
@@ -26,6 +26,9 @@ fn es_target() {
         ("es2022", "await fetch('/')"),                    // no error for es2022
         ("es2021", "async function f() { await fetch('/'); }"), // no error inside async function
         ("es2021", "{ await fetch('/'); }"), // test top-level await in block, should also error
+        ("es5", "`a${b}`"),
+        ("es5", "({ [a]: b, c() {} })"),
+        ("es5", "for (var x of y) {}"),
     ];
 
     // Test no transformation for esnext.
@@ -0,0 +1,59 @@
+use oxc_transformer::{HelperLoaderMode, TransformOptions};
+
+use crate::test;
+
+#[test]
+fn inline_helpers() {
+    use std::fmt::Write;
+
+    let cases = [
+        // Helpers and their dependencies are inlined once
+        "async function foo() { return { ...a, ...(await b) } } async function bar() { return { ...c } }",
+        // Helper names don't collide with existing bindings
+        "var _objectSpread = 1, _defineProperty = 2; function _typeof() {} ({ ...a })",
+        "import { x } from 'x'; class A { #a = x; }",
+    ];
+
+    let mut options = TransformOptions::from_target("es2016").unwrap();
+    options.helper_loader.mode = HelperLoaderMode::Inline;
+
+    #[cfg_attr(miri, expect(unused_variables))]
+    let snapshot = cases.into_iter().enumerate().fold(String::new(), |mut w, (i, case)| {
+        let result = test(case, &options).unwrap();
+        write!(w, "########## {i}\n{case}\n----------\n{result}\n").unwrap();
+        w
+    });
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("inline_helpers", snapshot);
+        });
+    }
+}
+
+/// Inline helpers are copies of the `@oxc-project/runtime` helpers, and must be kept in sync with them.
+#[test]
+#[cfg(not(miri))]
+fn inline_helpers_match_runtime() {
+    use std::{fs, path::Path};
+
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let helpers_dir = manifest_dir.join("src/common/helper_loader/helpers");
+    let runtime_dir = manifest_dir.join("../../npm/runtime/src/helpers/esm");
+
+    let mut outdated = vec![];
+    for entry in fs::read_dir(&helpers_dir).unwrap() {
+        let path = entry.unwrap().path();
+        let file_name = path.file_name().unwrap();
+        let helper = fs::read_to_string(&path).unwrap();
+        if fs::read_to_string(runtime_dir.join(file_name)).ok().as_ref() != Some(&helper) {
+            outdated.push(file_name.to_string_lossy().into_owned());
+        }
+    }
+    outdated.sort_unstable();
+    assert!(
+        outdated.is_empty(),
+        "Inline helpers differ from `npm/runtime/src/helpers/esm`, copy them over again: {outdated:?}"
+    );
+}
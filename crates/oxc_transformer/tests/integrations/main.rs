@@ -1,4 +1,10 @@
+mod const_enums;
+mod core_js_polyfills;
+mod dom_expressions;
+mod emotion;
 mod es_target;
+mod helper_loader;
+mod modules;
 mod targets;
 
 use std::path::Path;
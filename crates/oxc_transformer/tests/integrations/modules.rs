@@ -0,0 +1,28 @@
+use oxc_span::SourceType;
+use oxc_transformer::{EnvOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+fn env_options(json: &str) -> TransformOptions {
+    let env = serde_json::from_str::<EnvOptions>(json).unwrap();
+    TransformOptions { env, ..TransformOptions::default() }
+}
+
+#[test]
+fn preset_env_keeps_esm_by_default() {
+    let source = "import a from 'a';\nexport default a;\n";
+    let expected = Ok(codegen(source, SourceType::mjs()));
+    for json in ["{}", r#"{ "modules": "auto" }"#, r#"{ "modules": false }"#] {
+        assert_eq!(test(source, &env_options(json)), expected, "{json}");
+    }
+}
+
+#[test]
+fn preset_env_transforms_explicit_modules() {
+    let source = "import a from 'a';\nexport default a;\n";
+    let code = test(source, &env_options(r#"{ "modules": "commonjs" }"#)).unwrap();
+    assert!(code.contains("require('a')"), "{code}");
+    assert!(!code.contains("import "), "{code}");
+    let code = test(source, &env_options(r#"{ "modules": "amd" }"#)).unwrap();
+    assert!(code.starts_with("define("), "{code}");
+}
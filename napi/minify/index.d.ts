@@ -60,6 +60,15 @@ export interface CompressOptions {
    * @default true
    */
   sequences?: boolean
+  /**
+   * Inline functions called once, immediately invoked function expressions,
+   * and small pure functions into their call sites.
+   *
+   * `function foo(a) { return a.b } foo(x)` -> `x.b`
+   *
+   * @default false
+   */
+  inlineFunctions?: boolean
   /**
    * Set of label names to drop from the code.
    *
@@ -70,6 +79,15 @@ export interface CompressOptions {
   dropLabels?: Array<string>
   /** Limit the maximum number of iterations for debugging purpose. */
   maxIterations?: number
+  /**
+   * Record every rewrite made by the compressor in `MinifyResult.trace`,
+   * for finding the optimization responsible for a wrong output.
+   *
+   * This is slow, use it for debugging only.
+   *
+   * @default false
+   */
+  trace?: boolean
   /** Treeshake options. */
   treeshake?: TreeShakeOptions
 }
@@ -139,12 +157,37 @@ export interface MinifyOptions {
   mangle?: boolean | MangleOptions
   codegen?: boolean | CodegenOptions
   sourcemap?: boolean
+  /**
+   * Source map of the input source text, e.g. from a previous build step.
+   *
+   * When `sourcemap` is `true`, the returned source map is composed with it,
+   * so it points to the original sources instead of the input source text.
+   */
+  inputSourceMap?: SourceMap
 }
 
 export interface MinifyResult {
   code: string
   map?: SourceMap
   errors: Array<OxcError>
+  /** Rewrites made by the compressor, returned when `compress.trace` is `true`. */
+  trace?: Array<MinifyRewrite>
+}
+
+/** A rewrite made by the compressor. */
+export interface MinifyRewrite {
+  /** The fixed-point iteration the rewrite was made in, starting from `0`. */
+  iteration: number
+  /** Name of the optimization, e.g. `fold_binary_expr`. */
+  pass: string
+  /** Start offset of the rewritten node in the source text. */
+  start: number
+  /** End offset of the rewritten node in the source text. */
+  end: number
+  /** The rewritten node before the optimization. */
+  before: string
+  /** The rewritten node after the optimization. */
+  after: string
 }
 
 /** Minify synchronously. */
@@ -31,11 +31,43 @@ pub struct MinifyResult {
     pub code: String,
     pub map: Option<SourceMap>,
     pub errors: Vec<OxcError>,
+    /// Rewrites made by the compressor, returned when `compress.trace` is `true`.
+    pub trace: Option<Vec<MinifyRewrite>>,
+}
+
+/// A rewrite made by the compressor.
+#[napi(object)]
+pub struct MinifyRewrite {
+    /// The fixed-point iteration the rewrite was made in, starting from `0`.
+    pub iteration: u8,
+    /// Name of the optimization, e.g. `fold_binary_expr`.
+    pub pass: String,
+    /// Start offset of the rewritten node in the source text.
+    pub start: u32,
+    /// End offset of the rewritten node in the source text.
+    pub end: u32,
+    /// The rewritten node before the optimization.
+    pub before: String,
+    /// The rewritten node after the optimization.
+    pub after: String,
+}
+
+impl From<oxc_minifier::Rewrite> for MinifyRewrite {
+    fn from(rewrite: oxc_minifier::Rewrite) -> Self {
+        Self {
+            iteration: rewrite.iteration,
+            pass: rewrite.pass.to_string(),
+            start: rewrite.span.start,
+            end: rewrite.span.end,
+            before: rewrite.before,
+            after: rewrite.after,
+        }
+    }
 }
 
 fn minify_impl(filename: &str, source_text: &str, options: Option<MinifyOptions>) -> MinifyResult {
     use oxc_codegen::CodegenOptions;
-    let options = options.unwrap_or_default();
+    let mut options = options.unwrap_or_default();
 
     let minifier_options = match oxc_minifier::MinifierOptions::try_from(&options) {
         Ok(options) => options,
@@ -64,7 +96,11 @@ fn minify_impl(filename: &str, source_text: &str, options: Option<MinifyOptions>
     let parser_ret = Parser::new(&allocator, source_text, source_type).parse();
     let mut program = parser_ret.program;
 
-    let scoping = Minifier::new(minifier_options).minify(&allocator, &mut program).scoping;
+    let trace = minifier_options.compress.as_ref().is_some_and(|o| o.trace);
+    let minifier_ret = Minifier::new(minifier_options).minify(&allocator, &mut program);
+    let scoping = minifier_ret.scoping;
+    let trace =
+        trace.then(|| minifier_ret.trace.into_iter().map(MinifyRewrite::from).collect::<Vec<_>>());
 
     let mut codegen_options = match &options.codegen {
         // Need to remove all comments.
@@ -77,12 +113,29 @@ fn minify_impl(filename: &str, source_text: &str, options: Option<MinifyOptions>
         codegen_options.source_map_path = Some(PathBuf::from(&filename));
     }
 
+    if let Some(input_source_map) = options.input_source_map.take() {
+        match oxc_napi::parse_input_source_map(input_source_map) {
+            Ok(input_source_map) => codegen_options.input_source_map = Some(input_source_map),
+            Err(error) => {
+                return MinifyResult {
+                    errors: OxcError::from_diagnostics(
+                        filename,
+                        source_text,
+                        vec![OxcDiagnostic::error(error)],
+                    ),
+                    ..MinifyResult::default()
+                };
+            }
+        }
+    }
+
     let ret = Codegen::new().with_options(codegen_options).with_scoping(scoping).build(&program);
 
     MinifyResult {
         code: ret.code,
         map: ret.map.map(oxc_sourcemap::napi::SourceMap::from),
         errors: OxcError::from_diagnostics(filename, source_text, parser_ret.errors),
+        trace,
     }
 }
 
@@ -2,6 +2,7 @@ use napi::Either;
 use napi_derive::napi;
 
 use oxc_compat::EngineTargets;
+use oxc_sourcemap::napi::SourceMap;
 
 #[napi(object)]
 pub struct TreeShakeOptions {
@@ -122,6 +123,14 @@ pub struct CompressOptions {
     /// @default true
     pub sequences: Option<bool>,
 
+    /// Inline functions called once, immediately invoked function expressions,
+    /// and small pure functions into their call sites.
+    ///
+    /// `function foo(a) { return a.b } foo(x)` -> `x.b`
+    ///
+    /// @default false
+    pub inline_functions: Option<bool>,
+
     /// Set of label names to drop from the code.
     ///
     /// Labeled statements matching these names will be removed during minification.
@@ -132,6 +141,14 @@ pub struct CompressOptions {
     /// Limit the maximum number of iterations for debugging purpose.
     pub max_iterations: Option<u8>,
 
+    /// Record every rewrite made by the compressor in `MinifyResult.trace`,
+    /// for finding the optimization responsible for a wrong output.
+    ///
+    /// This is slow, use it for debugging only.
+    ///
+    /// @default false
+    pub trace: Option<bool>,
+
     /// Treeshake options.
     pub treeshake: Option<TreeShakeOptions>,
 }
@@ -150,6 +167,7 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
             drop_debugger: o.drop_debugger.unwrap_or(default.drop_debugger),
             join_vars: o.join_vars.unwrap_or(true),
             sequences: o.sequences.unwrap_or(true),
+            inline_functions: o.inline_functions.unwrap_or(default.inline_functions),
             unused: match &o.unused {
                 Some(Either::A(true)) => oxc_minifier::CompressOptionsUnused::Remove,
                 Some(Either::A(false)) => oxc_minifier::CompressOptionsUnused::Keep,
@@ -170,6 +188,7 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
                 .map(|labels| labels.iter().cloned().collect())
                 .unwrap_or_default(),
             max_iterations: o.max_iterations,
+            trace: o.trace.unwrap_or(default.trace),
         })
     }
 }
@@ -226,6 +245,7 @@ impl From<&MangleOptions> for oxc_minifier::MangleOptions {
                 None => default.keep_names,
             },
             debug: o.debug.unwrap_or(default.debug),
+            properties: None,
         }
     }
 }
@@ -287,6 +307,12 @@ pub struct MinifyOptions {
     pub codegen: Option<Either<bool, CodegenOptions>>,
 
     pub sourcemap: Option<bool>,
+
+    /// Source map of the input source text, e.g. from a previous build step.
+    ///
+    /// When `sourcemap` is `true`, the returned source map is composed with it,
+    /// so it points to the original sources instead of the input source text.
+    pub input_source_map: Option<SourceMap>,
 }
 
 impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
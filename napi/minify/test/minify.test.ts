@@ -18,6 +18,20 @@ describe("simple", () => {
     });
   });
 
+  it("uses the `inputSourceMap` option", () => {
+    const first = minifySync("test.js", code, { compress: false, mangle: false, sourcemap: true });
+    const ret = minifySync("test.min.js", first.code, {
+      sourcemap: true,
+      inputSourceMap: first.map,
+    });
+    expect(ret.errors.length).toBe(0);
+    expect(ret.map).toMatchObject({
+      sources: ["test.js"],
+      sourcesContent: [code],
+      version: 3,
+    });
+  });
+
   it("can turn off everything", () => {
     const ret = minifySync("test.js", code, {
       compress: false,
@@ -48,6 +62,32 @@ describe("simple", () => {
     expect(ret.code).toBe("OTHER:baz();");
     expect(ret.errors.length).toBe(0);
   });
+
+  it("supports inlineFunctions option", () => {
+    const code = "function foo(a) { return a + 1 } console.log(foo(1));";
+    const ret = minifySync("test.js", code, {
+      module: true,
+      compress: { inlineFunctions: true },
+      mangle: false,
+    });
+    expect(ret.code).toBe("console.log(2);");
+    expect(ret.errors.length).toBe(0);
+  });
+
+  it("supports trace option", () => {
+    const code = "console.log(1 + 1)";
+    const ret = minifySync("test.js", code, { compress: { trace: true } });
+    expect(ret.code).toBe("console.log(2);");
+    expect(ret.trace).toContainEqual({
+      iteration: 0,
+      pass: "fold_binary_expr",
+      start: 12,
+      end: 17,
+      before: "1 + 1",
+      after: "2",
+    });
+    expect(minifySync("test.js", code).trace).toBeUndefined();
+  });
 });
 
 describe("treeshake options", () => {
@@ -340,6 +340,7 @@ impl Oxc {
                 top_level: o.top_level,
                 keep_names: MangleOptionsKeepNames { function: o.keep_names, class: o.keep_names },
                 debug: false,
+                properties: None,
             })
         } else {
             None
@@ -584,11 +585,13 @@ impl Oxc {
             source_map_path: Some(path.to_path_buf()),
             ..CodegenOptions::default()
         };
-        let (scoping, class_private_mappings) =
-            minifier_return.map(|m| (m.scoping, m.class_private_mappings)).unwrap_or_default();
+        let (scoping, class_private_mappings, property_mappings) = minifier_return
+            .map(|m| (m.scoping, m.class_private_mappings, m.property_mappings))
+            .unwrap_or_default();
         let codegen_result = Codegen::new()
             .with_scoping(scoping)
             .with_private_member_mappings(class_private_mappings)
+            .with_property_mappings(property_mappings)
             .with_options(options)
             .build(program);
         self.codegen_text = codegen_result.code;
@@ -48,6 +48,26 @@ export interface ArrowFunctionsOptions {
   spec?: boolean
 }
 
+export interface BlockScopingOptions {
+  /**
+   * Throw an error instead of wrapping a loop body in a closure,
+   * when closures in the loop body capture block-scoped bindings.
+   *
+   * @default false
+   */
+  throwIfClosureRequired?: boolean
+}
+
+export interface ClassesOptions {
+  /**
+   * Assign methods to the prototype instead of defining them with `Object.defineProperty`,
+   * and skip the runtime check that classes are not called without `new`.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface CompilerAssumptions {
   ignoreFunctionLength?: boolean
   noDocumentAll?: boolean
@@ -97,6 +117,15 @@ export interface CompilerAssumptions {
   setPublicClassFields?: boolean
 }
 
+export interface ComputedPropertiesOptions {
+  /**
+   * Set properties with assignments instead of `Object.defineProperty`.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface DecoratorOptions {
   /**
    * Enables experimental support for decorators, which is a version of decorators that predates the TC39 standardization process.
@@ -108,6 +137,16 @@ export interface DecoratorOptions {
    * @default false
    */
   legacy?: boolean
+  /**
+   * Enables the standard decorators transform, which follows the `2023-11` version of the
+   * TC39 decorators proposal.
+   *
+   * This option has no effect when `legacy` is true.
+   *
+   * @see https://github.com/tc39/proposal-decorators
+   * @default false
+   */
+  standard?: boolean
   /**
    * Enables emitting decorator metadata.
    *
@@ -120,9 +159,136 @@ export interface DecoratorOptions {
   emitDecoratorMetadata?: boolean
 }
 
+export interface DestructuringOptions {
+  /**
+   * Assume destructured values are arrays, and access elements by index
+   * instead of using the iterator protocol.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Allow array-like objects, such as `arguments`, to be destructured as arrays.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
+}
+
+export interface DomExpressionsOptions {
+  /**
+   * The module runtime helpers are imported from.
+   *
+   * @default 'solid-js/web'
+   */
+  moduleName?: string
+  /**
+   * Generate DOM creation code or server-side string rendering code.
+   *
+   * @default 'dom'
+   */
+  generate?: 'dom' | 'ssr'
+  /**
+   * Use event delegation for common UI events.
+   *
+   * @default true
+   */
+  delegateEvents?: boolean
+}
+
+/**
+ * Configure how Emotion styles are transformed.
+ *
+ * Labels and source maps are only added in development, i.e. when {@link JsxOptions#development} is set.
+ *
+ * @see {@link https://emotion.sh/docs/@emotion/babel-plugin}
+ */
+export interface EmotionOptions {
+  /**
+   * Appends an inline source map comment to styles in development.
+   *
+   * @default true
+   */
+  sourceMap?: boolean
+  /**
+   * Controls when a `label` is added to styles.
+   *
+   * @default 'dev-only'
+   */
+  autoLabel?: 'dev-only' | 'always' | 'never'
+  /**
+   * Format of the `label`, supports `[local]`, `[filename]` and `[dirname]`.
+   *
+   * @default '[local]'
+   */
+  labelFormat?: string
+  /**
+   * Uses `@emotion/react` as the import source of the automatic JSX runtime,
+   * unless an import source is set.
+   *
+   * @default true
+   */
+  cssProp?: boolean
+}
+
 export interface Es2015Options {
   /** Transform arrow functions into function expressions. */
   arrowFunction?: ArrowFunctionsOptions
+  /** Transform classes into functions. */
+  classes?: ClassesOptions
+  /** Transform `let` and `const` declarations into `var` declarations. */
+  blockScoping?: BlockScopingOptions
+  /** Transform destructuring patterns in declarations, assignments and parameters. */
+  destructuring?: DestructuringOptions
+  /** Transform spread elements in arrays, calls and `new` expressions. */
+  spread?: SpreadOptions
+  /** Transform default and rest parameters. */
+  parameters?: ParametersOptions
+  /** Transform template literals and tagged template expressions. */
+  templateLiterals?: TemplateLiteralsOptions
+  /**
+   * Transform shorthand properties and methods in object literals.
+   *
+   * @default false
+   */
+  shorthandProperties?: boolean
+  /** Transform computed property keys in object literals. */
+  computedProperties?: ComputedPropertiesOptions
+  /** Transform `for...of` loops. */
+  forOf?: ForOfOptions
+  /**
+   * Transform `new.target`.
+   *
+   * @default false
+   */
+  newTarget?: boolean
+  /**
+   * Transform generator functions into state machines using `regeneratorRuntime`.
+   *
+   * @default false
+   */
+  regenerator?: boolean
+}
+
+export interface ForOfOptions {
+  /**
+   * Skip closing iterators when a loop exits early.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Assume iterated values are arrays, and iterate them by index.
+   *
+   * @default false
+   */
+  assumeArray?: boolean
+  /**
+   * Allow array-like objects, such as `arguments`, to be iterated as arrays.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
 }
 
 export declare const enum HelperMode {
@@ -146,7 +312,18 @@ export declare const enum HelperMode {
    * babelHelpers.helperName(...arguments);
    * ```
    */
-  External = 'External'
+  External = 'External',
+  /**
+   * Inline mode: Helper functions are inserted at the top of the program.
+   *
+   * Example:
+   *
+   * ```js
+   * function _helperName(...arguments) { ... }
+   * _helperName(...arguments);
+   * ```
+   */
+  Inline = 'Inline'
 }
 
 export interface Helpers {
@@ -275,6 +452,15 @@ export interface JsxOptions {
    * @default false
    */
   refresh?: boolean | ReactRefreshOptions
+  /**
+   * Compile JSX for Solid with `babel-plugin-jsx-dom-expressions` semantics
+   * instead of React's `createElement` / `jsx` calls.
+   *
+   * `runtime`, `importSource`, `pragma` and `pragmaFrag` are ignored when this is set.
+   *
+   * @default undefined
+   */
+  domExpressions?: DomExpressionsOptions
 }
 
 /**
@@ -335,9 +521,29 @@ export interface ModuleRunnerTransformResult {
 /** @deprecated Only works for Vite. */
 export declare function moduleRunnerTransformSync(filename: string, sourceText: string, options?: ModuleRunnerTransformOptions | undefined | null): ModuleRunnerTransformResult
 
+export interface ParametersOptions {
+  /**
+   * Keep parameters with default values in the parameter list,
+   * without preserving the function's `length` property.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface PluginsOptions {
   styledComponents?: StyledComponentsOptions
+  emotion?: EmotionOptions
   taggedTemplateEscape?: boolean
+  /**
+   * Inject `core-js` 3 polyfills for the built-ins used in the code that are not supported
+   * by {@link TransformOptions#target}, like Babel's `useBuiltIns: "usage"`.
+   *
+   * The `core-js` package must be installed.
+   *
+   * @default false
+   */
+  coreJsPolyfills?: boolean
 }
 
 export interface ReactRefreshOptions {
@@ -361,6 +567,22 @@ export interface ReactRefreshOptions {
  *
  * @see {@link https://styled-components.com/docs/tooling#babel-plugin}
  */
+export interface SpreadOptions {
+  /**
+   * Assume spread values are arrays, and concatenate them directly
+   * instead of using the iterator protocol.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Allow array-like objects, such as `arguments`, to be spread as arrays.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
+}
+
 export interface StyledComponentsOptions {
   /**
    * Enhances the attached CSS class name on each component with richer output to help
@@ -449,6 +671,16 @@ export interface StyledComponentsOptions {
  * @returns a promise that resolves to an object containing the transformed code,
  * source maps, and any errors that occurred during parsing or transformation.
  */
+export interface TemplateLiteralsOptions {
+  /**
+   * Concatenate template literals with `+` instead of `String.prototype.concat`,
+   * and do not freeze template objects passed to tags.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export declare function transform(filename: string, sourceText: string, options?: TransformOptions | undefined | null): Promise<TransformResult>
 
 /**
@@ -476,6 +708,13 @@ export interface TransformOptions {
    * @see {@link SourceMap}
    */
   sourcemap?: boolean
+  /**
+   * Source map of the input source text, e.g. from a preprocessor which ran before Oxc.
+   *
+   * When {@link TransformOptions#sourcemap} is `true`, the returned source map is composed
+   * with it, so it points to the original sources instead of the input source text.
+   */
+  inputSourceMap?: SourceMap
   /** Set assumptions in order to produce smaller output. */
   assumptions?: CompilerAssumptions
   /** Configure how TypeScript is transformed. */
@@ -645,4 +884,16 @@ export interface TypeScriptOptions {
    * @default false
    */
   rewriteImportExtensions?: 'rewrite' | 'remove' | boolean
+  /**
+   * Values of `const enum`s declared in other files, keyed by import specifier, export name
+   * and member name. References to their members are replaced with the values.
+   *
+   * Enums declared in the global scope are keyed by an empty import specifier.
+   *
+   * @example
+   * ```js
+   * { './enums': { Direction: { Up: 0, Down: 1 } } }
+   * ```
+   */
+  constEnums?: Record<string, Record<string, Record<string, string | number>>>
 }
@@ -19,8 +19,8 @@ use oxc::{
     semantic::{SemanticBuilder, SemanticBuilderReturn},
     span::SourceType,
     transformer::{
-        EnvOptions, HelperLoaderMode, HelperLoaderOptions, JsxRuntime, ProposalOptions,
-        RewriteExtensionsMode,
+        ConstEnumValue, ConstEnums, CoreJsPolyfillsOptions, EngineTargets, EnvOptions,
+        HelperLoaderMode, HelperLoaderOptions, JsxRuntime, ProposalOptions, RewriteExtensionsMode,
     },
     transformer_plugins::{
         InjectGlobalVariablesConfig, InjectImport, ModuleRunnerTransform,
@@ -107,6 +107,12 @@ pub struct TransformOptions {
     /// @see {@link SourceMap}
     pub sourcemap: Option<bool>,
 
+    /// Source map of the input source text, e.g. from a preprocessor which ran before Oxc.
+    ///
+    /// When {@link TransformOptions#sourcemap} is `true`, the returned source map is composed
+    /// with it, so it points to the original sources instead of the input source text.
+    pub input_source_map: Option<SourceMap>,
+
     /// Set assumptions in order to produce smaller output.
     pub assumptions: Option<CompilerAssumptions>,
 
@@ -153,11 +159,17 @@ impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
     type Error = String;
 
     fn try_from(options: TransformOptions) -> Result<Self, Self::Error> {
-        let env = match options.target {
-            Some(Either::A(s)) => EnvOptions::from_target(&s)?,
-            Some(Either::B(list)) => EnvOptions::from_target_list(&list)?,
-            _ => EnvOptions::default(),
+        let targets = match options.target {
+            Some(Either::A(s)) => Some(EngineTargets::from_target(&s)?),
+            Some(Either::B(list)) => Some(EngineTargets::from_target_list(&list)?),
+            _ => None,
         };
+        let env = targets.clone().map(EnvOptions::from).unwrap_or_default();
+        let core_js_polyfills = options
+            .plugins
+            .as_ref()
+            .is_some_and(|plugins| plugins.core_js_polyfills == Some(true))
+            .then(|| CoreJsPolyfillsOptions { targets: targets.unwrap_or_default() });
         Ok(Self {
             cwd: options.cwd.map(PathBuf::from).unwrap_or_default(),
             assumptions: options.assumptions.map(Into::into).unwrap_or_default(),
@@ -185,10 +197,10 @@ impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
             helper_loader: options
                 .helpers
                 .map_or_else(HelperLoaderOptions::default, HelperLoaderOptions::from),
-            plugins: options
-                .plugins
-                .map(oxc::transformer::PluginsOptions::from)
-                .unwrap_or_default(),
+            plugins: oxc::transformer::PluginsOptions {
+                core_js_polyfills,
+                ..options.plugins.map(oxc::transformer::PluginsOptions::from).unwrap_or_default()
+            },
         })
     }
 }
@@ -327,6 +339,17 @@ pub struct TypeScriptOptions {
     /// @default false
     #[napi(ts_type = "'rewrite' | 'remove' | boolean")]
     pub rewrite_import_extensions: Option<Either<bool, String>>,
+    /// Values of `const enum`s declared in other files, keyed by import specifier, export name
+    /// and member name. References to their members are replaced with the values.
+    ///
+    /// Enums declared in the global scope are keyed by an empty import specifier.
+    ///
+    /// @example
+    /// ```js
+    /// { './enums': { Direction: { Up: 0, Down: 1 } } }
+    /// ```
+    #[napi(ts_type = "Record<string, Record<string, Record<string, string | number>>>")]
+    pub const_enums: Option<ConstEnumsByModule>,
 }
 
 impl From<TypeScriptOptions> for oxc::transformer::TypeScriptOptions {
@@ -341,6 +364,7 @@ impl From<TypeScriptOptions> for oxc::transformer::TypeScriptOptions {
             allow_namespaces: options.allow_namespaces.unwrap_or(ops.allow_namespaces),
             allow_declare_fields: options.allow_declare_fields.unwrap_or(ops.allow_declare_fields),
             optimize_const_enums: false,
+            const_enums: options.const_enums.map(const_enums).unwrap_or_default(),
             remove_class_fields_without_initializer: options
                 .remove_class_fields_without_initializer
                 .unwrap_or(ops.remove_class_fields_without_initializer),
@@ -364,6 +388,37 @@ impl From<TypeScriptOptions> for oxc::transformer::TypeScriptOptions {
     }
 }
 
+/// Import specifier -> export name -> member name -> value.
+type ConstEnumsByModule =
+    FxHashMap<String, FxHashMap<String, FxHashMap<String, Either<String, f64>>>>;
+
+fn const_enums(modules: ConstEnumsByModule) -> ConstEnums {
+    let mut const_enums = ConstEnums::default();
+    for (specifier, enums) in modules {
+        let enums = enums.into_iter().map(|(name, members)| {
+            let members = members
+                .into_iter()
+                .map(|(member, value)| {
+                    let value = match value {
+                        Either::A(value) => ConstEnumValue::String(value),
+                        Either::B(value) => ConstEnumValue::Number(value),
+                    };
+                    (member, value)
+                })
+                .collect();
+            (name, members)
+        });
+        if specifier.is_empty() {
+            for (name, members) in enums {
+                const_enums.add_global(name, members);
+            }
+        } else {
+            const_enums.add_module(specifier, enums.collect());
+        }
+    }
+    const_enums
+}
+
 #[napi(object)]
 #[derive(Default)]
 pub struct DecoratorOptions {
@@ -376,6 +431,15 @@ pub struct DecoratorOptions {
     /// @default false
     pub legacy: Option<bool>,
 
+    /// Enables the standard decorators transform, which follows the `2023-11` version of the
+    /// TC39 decorators proposal.
+    ///
+    /// This option has no effect when `legacy` is true.
+    ///
+    /// @see https://github.com/tc39/proposal-decorators
+    /// @default false
+    pub standard: Option<bool>,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
@@ -390,6 +454,7 @@ impl From<DecoratorOptions> for oxc::transformer::DecoratorOptions {
     fn from(options: DecoratorOptions) -> Self {
         oxc::transformer::DecoratorOptions {
             legacy: options.legacy.unwrap_or_default(),
+            standard: options.standard.unwrap_or_default(),
             emit_decorator_metadata: options.emit_decorator_metadata.unwrap_or_default(),
         }
     }
@@ -467,7 +532,15 @@ pub struct StyledComponentsOptions {
 #[derive(Default)]
 pub struct PluginsOptions {
     pub styled_components: Option<StyledComponentsOptions>,
+    pub emotion: Option<EmotionOptions>,
     pub tagged_template_escape: Option<bool>,
+    /// Inject `core-js` 3 polyfills for the built-ins used in the code that are not supported
+    /// by {@link TransformOptions#target}, like Babel's `useBuiltIns: "usage"`.
+    ///
+    /// The `core-js` package must be installed.
+    ///
+    /// @default false
+    pub core_js_polyfills: Option<bool>,
 }
 
 impl From<PluginsOptions> for oxc::transformer::PluginsOptions {
@@ -476,7 +549,10 @@ impl From<PluginsOptions> for oxc::transformer::PluginsOptions {
             styled_components: options
                 .styled_components
                 .map(oxc::transformer::StyledComponentsOptions::from),
+            emotion: options.emotion.map(oxc::transformer::EmotionOptions::from),
             tagged_template_transform: options.tagged_template_escape.unwrap_or(false),
+            // Set from `TransformOptions::target` in `TryFrom<TransformOptions>`.
+            core_js_polyfills: None,
         }
     }
 }
@@ -505,6 +581,53 @@ impl From<StyledComponentsOptions> for oxc::transformer::StyledComponentsOptions
     }
 }
 
+/// Configure how Emotion styles are transformed.
+///
+/// Labels and source maps are only added in development, i.e. when {@link JsxOptions#development} is set.
+///
+/// @see {@link https://emotion.sh/docs/@emotion/babel-plugin}
+#[napi(object)]
+#[derive(Default)]
+pub struct EmotionOptions {
+    /// Appends an inline source map comment to styles in development.
+    ///
+    /// @default true
+    pub source_map: Option<bool>,
+
+    /// Controls when a `label` is added to styles.
+    ///
+    /// @default 'dev-only'
+    #[napi(ts_type = "'dev-only' | 'always' | 'never'")]
+    pub auto_label: Option<String>,
+
+    /// Format of the `label`, supports `[local]`, `[filename]` and `[dirname]`.
+    ///
+    /// @default '[local]'
+    pub label_format: Option<String>,
+
+    /// Uses `@emotion/react` as the import source of the automatic JSX runtime,
+    /// unless an import source is set.
+    ///
+    /// @default true
+    pub css_prop: Option<bool>,
+}
+
+impl From<EmotionOptions> for oxc::transformer::EmotionOptions {
+    fn from(options: EmotionOptions) -> Self {
+        let ops = oxc::transformer::EmotionOptions::default();
+        oxc::transformer::EmotionOptions {
+            source_map: options.source_map.unwrap_or(ops.source_map),
+            auto_label: match options.auto_label.as_deref() {
+                Some("always") => oxc::transformer::EmotionAutoLabel::Always,
+                Some("never") => oxc::transformer::EmotionAutoLabel::Never,
+                /* "dev-only" */ _ => oxc::transformer::EmotionAutoLabel::DevOnly,
+            },
+            label_format: options.label_format.unwrap_or(ops.label_format),
+            css_prop: options.css_prop.unwrap_or(ops.css_prop),
+        }
+    }
+}
+
 /// Configure how TSX and JSX are transformed.
 ///
 /// @see {@link https://babeljs.io/docs/babel-plugin-transform-react-jsx#options}
@@ -587,6 +710,14 @@ pub struct JsxOptions {
     ///
     /// @default false
     pub refresh: Option<Either<bool, ReactRefreshOptions>>,
+
+    /// Compile JSX for Solid with `babel-plugin-jsx-dom-expressions` semantics
+    /// instead of React's `createElement` / `jsx` calls.
+    ///
+    /// `runtime`, `importSource`, `pragma` and `pragmaFrag` are ignored when this is set.
+    ///
+    /// @default undefined
+    pub dom_expressions: Option<DomExpressionsOptions>,
 }
 
 impl From<JsxOptions> for oxc::transformer::JsxOptions {
@@ -609,6 +740,9 @@ impl From<JsxOptions> for oxc::transformer::JsxOptions {
                 Either::A(b) => b.then(oxc::transformer::ReactRefreshOptions::default),
                 Either::B(options) => Some(oxc::transformer::ReactRefreshOptions::from(options)),
             }),
+            dom_expressions: options
+                .dom_expressions
+                .map(oxc::transformer::DomExpressionsOptions::from),
             ..Default::default()
         }
     }
@@ -640,6 +774,39 @@ impl From<ReactRefreshOptions> for oxc::transformer::ReactRefreshOptions {
     }
 }
 
+#[napi(object)]
+pub struct DomExpressionsOptions {
+    /// The module runtime helpers are imported from.
+    ///
+    /// @default 'solid-js/web'
+    pub module_name: Option<String>,
+
+    /// Generate DOM creation code or server-side string rendering code.
+    ///
+    /// @default 'dom'
+    #[napi(ts_type = "'dom' | 'ssr'")]
+    pub generate: Option<String>,
+
+    /// Use event delegation for common UI events.
+    ///
+    /// @default true
+    pub delegate_events: Option<bool>,
+}
+
+impl From<DomExpressionsOptions> for oxc::transformer::DomExpressionsOptions {
+    fn from(options: DomExpressionsOptions) -> Self {
+        let ops = oxc::transformer::DomExpressionsOptions::default();
+        oxc::transformer::DomExpressionsOptions {
+            module_name: options.module_name.unwrap_or(ops.module_name),
+            generate: match options.generate.as_deref() {
+                Some("ssr") => oxc::transformer::DomExpressionsGenerate::Ssr,
+                /* "dom" */ _ => oxc::transformer::DomExpressionsGenerate::Dom,
+            },
+            delegate_events: options.delegate_events.unwrap_or(ops.delegate_events),
+        }
+    }
+}
+
 #[napi(object)]
 pub struct ArrowFunctionsOptions {
     /// This option enables the following:
@@ -657,15 +824,202 @@ impl From<ArrowFunctionsOptions> for oxc::transformer::ArrowFunctionsOptions {
     }
 }
 
+#[napi(object)]
+pub struct ClassesOptions {
+    /// Assign methods to the prototype instead of defining them with `Object.defineProperty`,
+    /// and skip the runtime check that classes are not called without `new`.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ClassesOptions> for oxc::transformer::ClassesOptions {
+    fn from(options: ClassesOptions) -> Self {
+        oxc::transformer::ClassesOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct BlockScopingOptions {
+    /// Throw an error instead of wrapping a loop body in a closure,
+    /// when closures in the loop body capture block-scoped bindings.
+    ///
+    /// @default false
+    pub throw_if_closure_required: Option<bool>,
+}
+
+impl From<BlockScopingOptions> for oxc::transformer::BlockScopingOptions {
+    fn from(options: BlockScopingOptions) -> Self {
+        oxc::transformer::BlockScopingOptions {
+            throw_if_closure_required: options.throw_if_closure_required.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct DestructuringOptions {
+    /// Assume destructured values are arrays, and access elements by index
+    /// instead of using the iterator protocol.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+    /// Allow array-like objects, such as `arguments`, to be destructured as arrays.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<DestructuringOptions> for oxc::transformer::DestructuringOptions {
+    fn from(options: DestructuringOptions) -> Self {
+        oxc::transformer::DestructuringOptions {
+            loose: options.loose.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct SpreadOptions {
+    /// Assume spread values are arrays, and concatenate them directly
+    /// instead of using the iterator protocol.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+    /// Allow array-like objects, such as `arguments`, to be spread as arrays.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<SpreadOptions> for oxc::transformer::SpreadOptions {
+    fn from(options: SpreadOptions) -> Self {
+        oxc::transformer::SpreadOptions {
+            loose: options.loose.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ParametersOptions {
+    /// Keep parameters with default values in the parameter list,
+    /// without preserving the function's `length` property.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ParametersOptions> for oxc::transformer::ParametersOptions {
+    fn from(options: ParametersOptions) -> Self {
+        oxc::transformer::ParametersOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct TemplateLiteralsOptions {
+    /// Concatenate template literals with `+` instead of `String.prototype.concat`,
+    /// and do not freeze template objects passed to tags.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<TemplateLiteralsOptions> for oxc::transformer::TemplateLiteralsOptions {
+    fn from(options: TemplateLiteralsOptions) -> Self {
+        oxc::transformer::TemplateLiteralsOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct ComputedPropertiesOptions {
+    /// Set properties with assignments instead of `Object.defineProperty`.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ComputedPropertiesOptions> for oxc::transformer::ComputedPropertiesOptions {
+    fn from(options: ComputedPropertiesOptions) -> Self {
+        oxc::transformer::ComputedPropertiesOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct ForOfOptions {
+    /// Skip closing iterators when a loop exits early.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+    /// Assume iterated values are arrays, and iterate them by index.
+    ///
+    /// @default false
+    pub assume_array: Option<bool>,
+    /// Allow array-like objects, such as `arguments`, to be iterated as arrays.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<ForOfOptions> for oxc::transformer::ForOfOptions {
+    fn from(options: ForOfOptions) -> Self {
+        oxc::transformer::ForOfOptions {
+            loose: options.loose.unwrap_or_default(),
+            assume_array: options.assume_array.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
 #[napi(object)]
 pub struct Es2015Options {
     /// Transform arrow functions into function expressions.
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    /// Transform classes into functions.
+    pub classes: Option<ClassesOptions>,
+    /// Transform `let` and `const` declarations into `var` declarations.
+    pub block_scoping: Option<BlockScopingOptions>,
+    /// Transform destructuring patterns in declarations, assignments and parameters.
+    pub destructuring: Option<DestructuringOptions>,
+    /// Transform spread elements in arrays, calls and `new` expressions.
+    pub spread: Option<SpreadOptions>,
+    /// Transform default and rest parameters.
+    pub parameters: Option<ParametersOptions>,
+    /// Transform template literals and tagged template expressions.
+    pub template_literals: Option<TemplateLiteralsOptions>,
+    /// Transform shorthand properties and methods in object literals.
+    ///
+    /// @default false
+    pub shorthand_properties: Option<bool>,
+    /// Transform computed property keys in object literals.
+    pub computed_properties: Option<ComputedPropertiesOptions>,
+    /// Transform `for...of` loops.
+    pub for_of: Option<ForOfOptions>,
+    /// Transform `new.target`.
+    ///
+    /// @default false
+    pub new_target: Option<bool>,
+    /// Transform generator functions into state machines using `regeneratorRuntime`.
+    ///
+    /// @default false
+    pub regenerator: Option<bool>,
 }
 
 impl From<Es2015Options> for oxc::transformer::ES2015Options {
     fn from(options: Es2015Options) -> Self {
-        oxc::transformer::ES2015Options { arrow_function: options.arrow_function.map(Into::into) }
+        oxc::transformer::ES2015Options {
+            arrow_function: options.arrow_function.map(Into::into),
+            classes: options.classes.map(Into::into),
+            block_scoping: options.block_scoping.map(Into::into),
+            destructuring: options.destructuring.map(Into::into),
+            spread: options.spread.map(Into::into),
+            parameters: options.parameters.map(Into::into),
+            template_literals: options.template_literals.map(Into::into),
+            shorthand_properties: options.shorthand_properties.unwrap_or_default(),
+            computed_properties: options.computed_properties.map(Into::into),
+            for_of: options.for_of.map(Into::into),
+            new_target: options.new_target.unwrap_or_default(),
+            regenerator: options.regenerator.unwrap_or_default(),
+        }
     }
 }
 
@@ -696,6 +1050,15 @@ pub enum HelperMode {
     /// babelHelpers.helperName(...arguments);
     /// ```
     External,
+    /// Inline mode: Helper functions are inserted at the top of the program.
+    ///
+    /// Example:
+    ///
+    /// ```js
+    /// function _helperName(...arguments) { ... }
+    /// _helperName(...arguments);
+    /// ```
+    Inline,
 }
 
 impl From<Helpers> for HelperLoaderOptions {
@@ -712,6 +1075,7 @@ impl From<HelperMode> for HelperLoaderMode {
         match value {
             HelperMode::Runtime => Self::Runtime,
             HelperMode::External => Self::External,
+            HelperMode::Inline => Self::Inline,
         }
     }
 }
@@ -722,6 +1086,7 @@ struct Compiler {
     isolated_declaration_options: Option<oxc::isolated_declarations::IsolatedDeclarationsOptions>,
 
     sourcemap: bool,
+    input_source_map: Option<oxc_sourcemap::SourceMap>,
 
     printed: String,
     printed_sourcemap: Option<SourceMap>,
@@ -748,6 +1113,13 @@ impl Compiler {
 
         let sourcemap = options.as_ref().and_then(|o| o.sourcemap).unwrap_or_default();
 
+        let input_source_map = options
+            .as_mut()
+            .and_then(|options| options.input_source_map.take())
+            .map(oxc_napi::parse_input_source_map)
+            .transpose()
+            .map_err(|err| vec![OxcDiagnostic::error(err)])?;
+
         let define = options
             .as_mut()
             .and_then(|options| options.define.take())
@@ -793,6 +1165,7 @@ impl Compiler {
             transform_options,
             isolated_declaration_options,
             sourcemap,
+            input_source_map,
             printed: String::default(),
             printed_sourcemap: None,
             declaration: None,
@@ -814,6 +1187,13 @@ impl CompilerInterface for Compiler {
         self.sourcemap
     }
 
+    fn codegen_options(&self) -> Option<CodegenOptions> {
+        Some(CodegenOptions {
+            input_source_map: self.input_source_map.clone(),
+            ..CodegenOptions::default()
+        })
+    }
+
     fn transform_options(&self) -> Option<&oxc::transformer::TransformOptions> {
         Some(&self.transform_options)
     }
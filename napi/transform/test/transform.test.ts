@@ -43,6 +43,19 @@ describe("simple", () => {
       version: 3,
     });
   });
+
+  it("uses the `inputSourceMap` option", () => {
+    const first = transformSync("test.ts", code, { sourcemap: true });
+    const ret = transformSync("test.js", first.code, {
+      sourcemap: true,
+      inputSourceMap: first.map,
+    });
+    expect(ret.map).toMatchObject({
+      sources: ["test.ts"],
+      sourcesContent: ["export class A<T> {}"],
+      version: 3,
+    });
+  });
 });
 
 describe("transform", () => {
@@ -201,6 +214,20 @@ describe("jsx", () => {
     });
     expect(ret.code).toEqual("const foo = <div />;\n");
   });
+
+  it("compiles jsx with dom expressions", () => {
+    const ret = transformSync("test.tsx", code, {
+      jsx: {
+        domExpressions: {},
+      },
+    });
+    expect(ret.code).toMatchInlineSnapshot(`
+      "import { template as _template } from "solid-js/web";
+      var _tmpl = /* @__PURE__ */ _template("<div></div>");
+      const foo = _tmpl();
+      "
+    `);
+  });
 });
 
 describe("react refresh plugin", () => {
@@ -448,6 +475,26 @@ describe("typescript", () => {
         "
       `);
     });
+
+    test("constEnums", () => {
+      const code = `
+        import { Direction } from "./enums";
+        move(Direction.Down, Color.Red);
+      `;
+      const ret = transformSync("test.ts", code, {
+        typescript: {
+          constEnums: {
+            "./enums": { Direction: { Up: 0, Down: 1 } },
+            "": { Color: { Red: "red" } },
+          },
+        },
+      });
+      expect(ret.code).toMatchInlineSnapshot(`
+        "move(1, "red");
+        export {};
+        "
+      `);
+    });
   });
 });
 
@@ -477,3 +524,43 @@ describe("styled-components", () => {
 		`);
   });
 });
+
+describe("emotion", () => {
+  test("matches output", () => {
+    const code = `
+      import { css } from '@emotion/react';
+
+      const style = css\`
+        color: red;
+      \`;
+    `;
+    const ret = transformSync("test.js", code, {
+      plugins: {
+        emotion: {
+          autoLabel: "always",
+        },
+      },
+    });
+    expect(ret.code).toMatchInlineSnapshot(`
+			"import { css } from "@emotion/react";
+			const style = /* @__PURE__ */ css("color:red;", "label:style;");
+			"
+		`);
+  });
+});
+
+describe("core-js polyfills", () => {
+  test("injects polyfills not supported by the target", () => {
+    const code = "Object.hasOwn(a, 'b'); a.at(-1);";
+    const ret = transformSync("test.js", code, {
+      target: "chrome92",
+      plugins: { coreJsPolyfills: true },
+    });
+    expect(ret.code).toMatchInlineSnapshot(`
+			"import "core-js/modules/es.object.has-own.js";
+			Object.hasOwn(a, "b");
+			a.at(-1);
+			"
+		`);
+  });
+});
@@ -0,0 +1,4 @@
+class Foo {
+  @dec accessor #a;
+  @dec accessor #b = 123;
+}
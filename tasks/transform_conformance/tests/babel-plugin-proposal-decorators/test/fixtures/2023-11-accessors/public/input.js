@@ -0,0 +1,5 @@
+class Foo {
+  @dec accessor a;
+  @dec accessor b = 123;
+  @dec accessor ["c"] = 456;
+}
@@ -0,0 +1,4 @@
+class Foo {
+  @dec static accessor a;
+  @dec static accessor b = 123;
+}
@@ -0,0 +1,34 @@
+var _init_a, _init_extra_a, _init_b, _init_extra_b;
+class Foo {
+  static {
+    [_init_a, _init_extra_a, _init_b, _init_extra_b] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      9,
+      "a"
+    ], [
+      dec,
+      9,
+      "b"
+    ]]).e;
+  }
+  static #A = _init_a();
+  static get a() {
+    return this.#A;
+  }
+  static set a(v) {
+    this.#A = v;
+  }
+  static {
+    _init_extra_a();
+  }
+  static #B = _init_b(123);
+  static get b() {
+    return this.#B;
+  }
+  static set b(v) {
+    this.#B = v;
+  }
+  static {
+    _init_extra_b();
+  }
+}
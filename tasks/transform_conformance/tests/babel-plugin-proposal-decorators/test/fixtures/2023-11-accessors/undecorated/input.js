@@ -0,0 +1,5 @@
+class Foo {
+  accessor a = 1;
+  static accessor b;
+  accessor [key] = 2;
+}
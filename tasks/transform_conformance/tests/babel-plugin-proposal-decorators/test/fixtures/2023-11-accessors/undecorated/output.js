@@ -0,0 +1,25 @@
+var _computedKey;
+_computedKey = babelHelpers.toPropertyKey(key);
+class Foo {
+  #A = 1;
+  get a() {
+    return this.#A;
+  }
+  set a(v) {
+    this.#A = v;
+  }
+  static #B;
+  static get b() {
+    return this.#B;
+  }
+  static set b(v) {
+    this.#B = v;
+  }
+  #C = 2;
+  get [_computedKey]() {
+    return this.#C;
+  }
+  set [_computedKey](v) {
+    this.#C = v;
+  }
+}
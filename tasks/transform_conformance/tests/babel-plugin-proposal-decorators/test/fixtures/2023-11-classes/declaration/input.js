@@ -0,0 +1,6 @@
+@dec
+class Foo {
+  static create() {
+    return new Foo();
+  }
+}
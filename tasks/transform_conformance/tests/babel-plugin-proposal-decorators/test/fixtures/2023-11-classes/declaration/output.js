@@ -0,0 +1,12 @@
+var _Foo, _initClass;
+let Foo = (class Foo {
+  static {
+    [_Foo, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static create() {
+    return new _Foo();
+  }
+  static {
+    _initClass();
+  }
+}, _Foo);
@@ -0,0 +1,2 @@
+@dec
+export default class Foo {}
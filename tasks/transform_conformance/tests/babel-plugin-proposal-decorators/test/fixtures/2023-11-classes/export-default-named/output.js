@@ -0,0 +1,10 @@
+var _Foo, _initClass;
+let Foo = (class Foo {
+  static {
+    [_Foo, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static {
+    _initClass();
+  }
+}, _Foo);
+export default Foo;
@@ -0,0 +1,9 @@
+var _Foo, _initClass;
+export let Foo = (class Foo {
+  static {
+    [_Foo, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static {
+    _initClass();
+  }
+}, _Foo);
@@ -0,0 +1,2 @@
+const Foo = @dec class {};
+const Bar = @dec class Bar extends Foo {};
@@ -0,0 +1,17 @@
+var _Class, _initClass, _Bar, _initClass2;
+const Foo = (class {
+  static {
+    [_Class, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static {
+    _initClass();
+  }
+}, _Class);
+const Bar = (class Bar extends Foo {
+  static {
+    [_Bar, _initClass2] = babelHelpers.applyDecs2311(this, [dec], [], 0, void 0, Foo).c;
+  }
+  static {
+    _initClass2();
+  }
+}, _Bar);
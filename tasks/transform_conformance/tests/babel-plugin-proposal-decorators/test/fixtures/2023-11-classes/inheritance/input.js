@@ -0,0 +1,11 @@
+class Bar {}
+
+@dec1
+class Foo extends Bar {
+  @dec2 a = 1;
+
+  constructor() {
+    super();
+    this.b = 2;
+  }
+}
@@ -0,0 +1,19 @@
+var _Foo, _init_a, _init_extra_a, _initClass;
+class Bar {}
+let Foo = (class Foo extends Bar {
+  static {
+    ({e: [_init_a, _init_extra_a], c: [_Foo, _initClass]} = babelHelpers.applyDecs2311(this, [dec1], [[
+      dec2,
+      0,
+      "a"
+    ]], 0, void 0, Bar));
+  }
+  a = _init_a(this, 1);
+  constructor() {
+    _init_extra_a(super());
+    this.b = 2;
+  }
+  static {
+    _initClass();
+  }
+}, _Foo);
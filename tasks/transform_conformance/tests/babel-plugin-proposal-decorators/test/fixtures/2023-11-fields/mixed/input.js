@@ -0,0 +1,6 @@
+class Foo {
+  a = 1;
+  @dec b = 2;
+  c = 3;
+  @dec static d = 4;
+}
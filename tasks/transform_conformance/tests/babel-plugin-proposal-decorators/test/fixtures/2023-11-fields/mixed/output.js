@@ -0,0 +1,21 @@
+var _init_b, _init_extra_b, _init_d, _init_extra_d;
+class Foo {
+  static {
+    [_init_d, _init_extra_d, _init_b, _init_extra_b] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      8,
+      "d"
+    ], [
+      dec,
+      0,
+      "b"
+    ]]).e;
+  }
+  a = 1;
+  b = _init_b(this, 2);
+  c = (_init_extra_b(this), 3);
+  static d = _init_d(4);
+  static {
+    _init_extra_d();
+  }
+}
@@ -0,0 +1,4 @@
+class Foo {
+  @dec #a;
+  @dec #b = 123;
+}
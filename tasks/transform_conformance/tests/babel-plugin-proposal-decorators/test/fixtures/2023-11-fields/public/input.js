@@ -0,0 +1,5 @@
+class Foo {
+  @dec a;
+  @dec b = 123;
+  @dec ["c"] = 456;
+}
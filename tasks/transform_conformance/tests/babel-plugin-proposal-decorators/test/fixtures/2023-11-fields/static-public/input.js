@@ -0,0 +1,4 @@
+class Foo {
+  @dec static a;
+  @dec static b = 123;
+}
@@ -0,0 +1,22 @@
+var _init_a, _init_extra_a, _init_b, _init_extra_b;
+class Foo {
+  static {
+    [_init_a, _init_extra_a, _init_b, _init_extra_b] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      8,
+      "a"
+    ], [
+      dec,
+      8,
+      "b"
+    ]]).e;
+  }
+  static a = _init_a();
+  static {
+    _init_extra_a();
+  }
+  static b = _init_b(123);
+  static {
+    _init_extra_b();
+  }
+}
@@ -0,0 +1,13 @@
+class Foo {
+  value = 1;
+
+  @dec
+  get a() {
+    return this.value;
+  }
+
+  @dec
+  set a(v) {
+    this.value = v;
+  }
+}
@@ -0,0 +1,21 @@
+var _initProto;
+class Foo {
+  static {
+    [_initProto] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      3,
+      "a"
+    ], [
+      dec,
+      4,
+      "a"
+    ]]).e;
+  }
+  value = (_initProto(this), 1);
+  get a() {
+    return this.value;
+  }
+  set a(v) {
+    this.value = v;
+  }
+}
@@ -0,0 +1,8 @@
+function dec(_, context) {
+  context.metadata.decorated = true;
+}
+
+@dec
+class Foo {
+  @dec a;
+}
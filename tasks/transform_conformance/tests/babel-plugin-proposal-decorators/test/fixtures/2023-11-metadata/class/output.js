@@ -0,0 +1,20 @@
+var _Foo, _init_a, _init_extra_a, _initClass;
+function dec(_, context) {
+  context.metadata.decorated = true;
+}
+let Foo = (class Foo {
+  static {
+    ({e: [_init_a, _init_extra_a], c: [_Foo, _initClass]} = babelHelpers.applyDecs2311(this, [dec], [[
+      dec,
+      0,
+      "a"
+    ]]));
+  }
+  constructor() {
+    _init_extra_a(this);
+  }
+  a = _init_a(this);
+  static {
+    _initClass();
+  }
+}, _Foo);
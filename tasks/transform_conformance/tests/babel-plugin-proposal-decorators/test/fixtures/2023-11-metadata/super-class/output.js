@@ -0,0 +1,9 @@
+var _Foo, _initClass, _getBase;
+let Foo = (class Foo extends (_getBase = getBase()) {
+  static {
+    [_Foo, _initClass] = babelHelpers.applyDecs2311(this, [dec], [], 0, void 0, _getBase).c;
+  }
+  static {
+    _initClass();
+  }
+}, _Foo);
@@ -0,0 +1,12 @@
+class Foo {
+  value = 1;
+
+  @dec
+  #a() {
+    return this.value;
+  }
+
+  callA() {
+    return this.#a();
+  }
+}
@@ -0,0 +1,20 @@
+var _initProto, _call_a;
+class Foo {
+  static {
+    [_call_a, _initProto] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      2,
+      "a",
+      function() {
+        return this.value;
+      }
+    ]], 0, (_) => #a in _).e;
+  }
+  value = (_initProto(this), 1);
+  get #a() {
+    return _call_a;
+  }
+  callA() {
+    return this.#a();
+  }
+}
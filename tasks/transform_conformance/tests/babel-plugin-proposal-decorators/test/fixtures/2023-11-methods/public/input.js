@@ -0,0 +1,13 @@
+class Foo {
+  value = 1;
+
+  @dec
+  a() {
+    return this.value;
+  }
+
+  @dec
+  ["b"]() {
+    return this.value;
+  }
+}
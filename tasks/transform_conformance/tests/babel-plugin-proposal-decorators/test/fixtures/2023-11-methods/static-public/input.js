@@ -0,0 +1,8 @@
+class Foo {
+  static value = 1;
+
+  @dec
+  static a() {
+    return this.value;
+  }
+}
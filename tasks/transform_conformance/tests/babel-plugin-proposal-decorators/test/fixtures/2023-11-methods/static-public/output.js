@@ -0,0 +1,15 @@
+var _initStatic;
+class Foo {
+  static {
+    [_initStatic] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      10,
+      "a"
+    ]]).e;
+    _initStatic(this);
+  }
+  static value = 1;
+  static a() {
+    return this.value;
+  }
+}
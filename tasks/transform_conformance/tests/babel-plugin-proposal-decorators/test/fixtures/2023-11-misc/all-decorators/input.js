@@ -0,0 +1,8 @@
+@dec
+class Foo {
+  @dec a = 1;
+  @dec accessor b = 2;
+  @dec c() {}
+  @dec static d() {}
+  @dec get e() {}
+}
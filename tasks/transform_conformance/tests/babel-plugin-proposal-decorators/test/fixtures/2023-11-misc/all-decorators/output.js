@@ -0,0 +1,50 @@
+var _Foo, _initProto, _initStatic, _init_a, _init_extra_a, _init_b, _init_extra_b, _initClass;
+let Foo = (class Foo {
+  static {
+    ({e: [_init_b, _init_extra_b, _init_a, _init_extra_a, _initProto, _initStatic], c: [_Foo, _initClass]} = babelHelpers.applyDecs2311(this, [dec], [
+      [
+        dec,
+        10,
+        "d"
+      ],
+      [
+        dec,
+        1,
+        "b"
+      ],
+      [
+        dec,
+        2,
+        "c"
+      ],
+      [
+        dec,
+        3,
+        "e"
+      ],
+      [
+        dec,
+        0,
+        "a"
+      ]
+    ]));
+    _initStatic(this);
+  }
+  constructor() {
+    _init_extra_b(this);
+  }
+  a = (_initProto(this), _init_a(this, 1));
+  #A = (_init_extra_a(this), _init_b(this, 2));
+  get b() {
+    return this.#A;
+  }
+  set b(v) {
+    this.#A = v;
+  }
+  c() {}
+  static d() {}
+  get e() {}
+  static {
+    _initClass();
+  }
+}, _Foo);
@@ -0,0 +1,5 @@
+class Foo {
+  [a()] = 1;
+  @dec [b()] = 2;
+  [c()]() {}
+}
@@ -0,0 +1,17 @@
+var _computedKey, _computedKey2, _init_computedKey, _init_extra_computedKey, _computedKey3;
+_computedKey = babelHelpers.toPropertyKey(a()), _computedKey2 = babelHelpers.toPropertyKey(b()), _computedKey3 = babelHelpers.toPropertyKey(c());
+class Foo {
+  static {
+    [_init_computedKey, _init_extra_computedKey] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      0,
+      _computedKey2
+    ]]).e;
+  }
+  constructor() {
+    _init_extra_computedKey(this);
+  }
+  [_computedKey] = 1;
+  [_computedKey2] = _init_computedKey(this, 2);
+  [_computedKey3]() {}
+}
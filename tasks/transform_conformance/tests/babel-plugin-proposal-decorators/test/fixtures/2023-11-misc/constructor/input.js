@@ -0,0 +1,7 @@
+class Foo {
+  @dec a() {}
+
+  constructor() {
+    this.b = 1;
+  }
+}
@@ -0,0 +1,15 @@
+var _initProto;
+class Foo {
+  static {
+    [_initProto] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      2,
+      "a"
+    ]]).e;
+  }
+  a() {}
+  constructor() {
+    _initProto(this);
+    this.b = 1;
+  }
+}
@@ -0,0 +1,6 @@
+class Foo {
+  @dec() a;
+  @obj.dec b;
+  @this.dec c;
+  @(getDecorators()[0]) d;
+}
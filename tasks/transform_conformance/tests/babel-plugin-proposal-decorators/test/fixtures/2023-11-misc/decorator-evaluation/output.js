@@ -0,0 +1,35 @@
+var _dec, _init_a, _init_extra_a, _init_b, _init_extra_b, _obj, _dec2, _init_c, _init_extra_c, _dec3, _init_d, _init_extra_d;
+_dec = dec(), _dec2 = (_obj = this).dec, _dec3 = getDecorators()[0];
+class Foo {
+  static {
+    [_init_a, _init_extra_a, _init_b, _init_extra_b, _init_c, _init_extra_c, _init_d, _init_extra_d] = babelHelpers.applyDecs2311(this, [], [
+      [
+        _dec,
+        0,
+        "a"
+      ],
+      [
+        [obj, obj.dec],
+        16,
+        "b"
+      ],
+      [
+        [_obj, _dec2],
+        16,
+        "c"
+      ],
+      [
+        _dec3,
+        0,
+        "d"
+      ]
+    ]).e;
+  }
+  constructor() {
+    _init_extra_d(this);
+  }
+  a = _init_a(this);
+  b = (_init_extra_a(this), _init_b(this));
+  c = (_init_extra_b(this), _init_c(this));
+  d = (_init_extra_c(this), _init_d(this));
+}
@@ -0,0 +1,6 @@
+import defer * as ns from "x";
+import defer * as local from "./local";
+
+export function f() {
+  return [ns.value, local.value, ns];
+}
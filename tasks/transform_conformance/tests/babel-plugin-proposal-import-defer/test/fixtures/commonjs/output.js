@@ -0,0 +1,24 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.f = f;
+function _x() {
+  const data = babelHelpers.interopRequireWildcard(require("x"));
+  _x = function() {
+    return data;
+  };
+  return data;
+}
+function _local() {
+  const data = babelHelpers.interopRequireWildcard(require("./local"));
+  _local = function() {
+    return data;
+  };
+  return data;
+}
+function f() {
+  return [
+    _x().value,
+    _local().value,
+    _x()
+  ];
+}
@@ -0,0 +1,3 @@
+import defer * as ns from "x";
+
+console.log(ns.value);
@@ -0,0 +1,4 @@
+import defer * as ns from "x";
+import { value } from "x";
+
+console.log(ns.value, value);
@@ -0,0 +1,3 @@
+"use strict";
+var _x = babelHelpers.interopRequireWildcard(require("x"));
+console.log(_x.value, _x.value);
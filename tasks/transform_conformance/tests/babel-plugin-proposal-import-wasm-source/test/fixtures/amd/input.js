@@ -0,0 +1 @@
+import source mod from "./mod.wasm";
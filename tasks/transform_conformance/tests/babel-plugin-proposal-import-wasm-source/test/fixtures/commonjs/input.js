@@ -0,0 +1,5 @@
+import source mod from "./mod.wasm";
+
+export const instance = new WebAssembly.Instance(mod);
+export const lib = import.source("./lib.wasm");
+export const dynamic = import.source(name);
@@ -0,0 +1,11 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.instance = exports.lib = exports.dynamic = void 0;
+const mod = new WebAssembly.Module(require("fs").readFileSync(require.resolve("./mod.wasm")));
+const instance = exports.instance = new WebAssembly.Instance(mod);
+const lib = exports.lib = Promise.resolve().then(function() {
+  return WebAssembly.compile(require("fs").readFileSync(require.resolve("./lib.wasm")));
+});
+const dynamic = exports.dynamic = Promise.resolve(`${name}`).then(function(s) {
+  return WebAssembly.compile(require("fs").readFileSync(require.resolve(s)));
+});
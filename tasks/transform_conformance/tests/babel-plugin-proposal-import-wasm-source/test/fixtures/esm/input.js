@@ -0,0 +1,4 @@
+import source mod from "./mod.wasm";
+
+const instance = await WebAssembly.instantiate(mod);
+export const lib = import.source("./lib.wasm");
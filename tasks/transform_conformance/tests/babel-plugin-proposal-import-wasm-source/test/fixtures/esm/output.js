@@ -0,0 +1,3 @@
+const mod = await WebAssembly.compileStreaming(fetch(import.meta.resolve("./mod.wasm")));
+const instance = await WebAssembly.instantiate(mod);
+export const lib = WebAssembly.compileStreaming(fetch(import.meta.resolve("./lib.wasm")));
@@ -0,0 +1,10 @@
+let a = 1;
+const b = 2;
+{
+  let c = a + b;
+  const d = c;
+}
+function f() {
+  let e = 3;
+  return e;
+}
@@ -0,0 +1,10 @@
+var a = 1;
+var b = 2;
+{
+  var c = a + b;
+  var d = c;
+}
+function f() {
+  var e = 3;
+  return e;
+}
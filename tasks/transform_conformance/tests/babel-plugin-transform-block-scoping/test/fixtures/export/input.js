@@ -0,0 +1,2 @@
+export let a = 1;
+export const b = 2;
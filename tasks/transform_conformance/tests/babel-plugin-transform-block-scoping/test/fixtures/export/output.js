@@ -0,0 +1,2 @@
+export var a = 1;
+export var b = 2;
@@ -0,0 +1,9 @@
+for (const item of items) {
+  let x;
+  if (item) x = item;
+  console.log(x);
+}
+while (cond()) {
+  let y, z = 1;
+  fns.push(function () {});
+}
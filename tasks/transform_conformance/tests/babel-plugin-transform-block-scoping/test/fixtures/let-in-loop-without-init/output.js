@@ -0,0 +1,9 @@
+for (var item of items) {
+  var x = void 0;
+  if (item) x = item;
+  console.log(x);
+}
+while (cond()) {
+  var y = void 0, z = 1;
+  fns.push(function() {});
+}
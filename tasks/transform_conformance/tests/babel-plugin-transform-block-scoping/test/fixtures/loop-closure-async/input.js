@@ -0,0 +1,6 @@
+async function f() {
+  for (const url of urls) {
+    const res = await fetch(url);
+    fns.push(() => res);
+  }
+}
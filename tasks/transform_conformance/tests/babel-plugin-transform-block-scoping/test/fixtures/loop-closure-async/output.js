@@ -0,0 +1,9 @@
+async function f() {
+  var _loop = async function() {
+    var res = await fetch(url);
+    fns.push(() => res);
+  };
+  for (var url of urls) {
+    await _loop();
+  }
+}
@@ -0,0 +1,8 @@
+while (cond()) {
+  const value = next();
+  fns.push(() => value);
+}
+do {
+  let value = next();
+  setTimeout(() => console.log(value));
+} while (cond());
@@ -0,0 +1,14 @@
+var _loop = function() {
+  var value = next();
+  fns.push(() => value);
+};
+while (cond()) {
+  _loop();
+}
+var _loop2 = function() {
+  var value = next();
+  setTimeout(() => console.log(value));
+};
+do {
+  _loop2();
+} while (cond());
@@ -0,0 +1,12 @@
+function f() {
+  outer: for (let i = 0; i < 10; i++) {
+    for (let j = 0; j < 10; j++) {
+      fns.push(() => i + j);
+      if (i === j) continue;
+      if (i > j) break;
+      if (j > 5) continue outer;
+      if (j > 8) break outer;
+      if (i > 8) return i;
+    }
+  }
+}
@@ -0,0 +1,24 @@
+function f() {
+  var _loop2 = function(i) {
+    var _loop = function(j) {
+      fns.push(() => i + j);
+      if (i === j) return;
+      if (i > j) return 0;
+      if (j > 5) return 1;
+      if (j > 8) return 2;
+      if (i > 8) return { v: i };
+    };
+    for (var j = 0; j < 10; j++) {
+      var _ret = _loop(j);
+      if (_ret === 0) break;
+      if (_ret === 1) return;
+      if (_ret === 2) return 0;
+      if (_ret) return { v: _ret.v };
+    }
+  };
+  outer: for (var i = 0; i < 10; i++) {
+    var _ret2 = _loop2(i);
+    if (_ret2 === 0) break outer;
+    if (_ret2) return _ret2.v;
+  }
+}
@@ -0,0 +1 @@
+if (cond) for (let i = 0; i < 3; i++) fns.push(() => i);
@@ -0,0 +1,8 @@
+if (cond) {
+  var _loop = function(i) {
+    fns.push(() => i);
+  };
+  for (var i = 0; i < 3; i++) {
+    _loop(i);
+  }
+}
@@ -0,0 +1,5 @@
+function f() {
+  for (let i = 0; i < arguments.length; i++) {
+    this.fns.push(() => arguments[i]);
+  }
+}
@@ -0,0 +1,8 @@
+function f() {
+  var _this = this, _arguments = arguments, _loop = function(i) {
+    _this.fns.push(() => _arguments[i]);
+  };
+  for (var i = 0; i < arguments.length; i++) {
+    _loop(i);
+  }
+}
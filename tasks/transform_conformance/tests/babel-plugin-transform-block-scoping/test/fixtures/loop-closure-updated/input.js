@@ -0,0 +1,4 @@
+for (let i = 0; i < 10; i++) {
+  fns.push(() => i);
+  i += 2;
+}
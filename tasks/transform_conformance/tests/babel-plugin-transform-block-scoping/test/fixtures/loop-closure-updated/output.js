@@ -0,0 +1,8 @@
+var _loop = function(_i) {
+  fns.push(() => _i);
+  _i += 2;
+  i = _i;
+};
+for (var i = 0; i < 10; i++) {
+  _loop(i);
+}
@@ -0,0 +1,6 @@
+for (let i = 0; i < 3; i++) {
+  var x = i, y;
+  var { a, b: [c = 1] } = obj;
+  fns.push(() => i);
+}
+console.log(x, y, a, c);
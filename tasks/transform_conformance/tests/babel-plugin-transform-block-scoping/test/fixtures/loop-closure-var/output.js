@@ -0,0 +1,9 @@
+var _loop = function(i) {
+  x = i;
+  ({a, b: [c = 1]} = obj);
+  fns.push(() => i);
+}, x, y, a, c;
+for (var i = 0; i < 3; i++) {
+  _loop(i);
+}
+console.log(x, y, a, c);
@@ -0,0 +1,9 @@
+for (let i = 0; i < 3; i++) {
+  fns.push(() => i);
+}
+for (const key in obj) {
+  fns.push(function () {
+    return key;
+  });
+}
+for (const value of values) fns.push(() => value);
@@ -0,0 +1,20 @@
+var _loop = function(i) {
+  fns.push(() => i);
+};
+for (var i = 0; i < 3; i++) {
+  _loop(i);
+}
+var _loop2 = function(key) {
+  fns.push(function() {
+    return key;
+  });
+};
+for (var key in obj) {
+  _loop2(key);
+}
+var _loop3 = function(value) {
+  fns.push(() => value);
+};
+for (var value of values) {
+  _loop3(value);
+}
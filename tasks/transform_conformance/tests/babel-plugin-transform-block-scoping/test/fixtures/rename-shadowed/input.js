@@ -0,0 +1,25 @@
+let a = 1;
+{
+  let a = 2;
+  console.log(a);
+}
+{
+  let b = 1;
+  ({ a, b } = obj);
+}
+{
+  let b = 2;
+  ({ b } = obj);
+  console.log(b);
+}
+function f(x) {
+  if (x) {
+    let x = 1;
+    return x;
+  }
+  return x;
+}
+{
+  let window = 1;
+}
+console.log(window);
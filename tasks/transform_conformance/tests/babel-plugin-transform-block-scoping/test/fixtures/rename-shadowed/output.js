@@ -0,0 +1,25 @@
+var a = 1;
+{
+  var _a = 2;
+  console.log(_a);
+}
+{
+  var b = 1;
+  ({a, b} = obj);
+}
+{
+  var _b = 2;
+  ({b: _b} = obj);
+  console.log(_b);
+}
+function f(x) {
+  if (x) {
+    var _x = 1;
+    return _x;
+  }
+  return x;
+}
+{
+  var _window = 1;
+}
+console.log(window);
@@ -0,0 +1,10 @@
+let x = 1;
+switch (y) {
+  case 1:
+    let x = 2;
+    console.log(x);
+    break;
+  case 2:
+    const z = x;
+    break;
+}
@@ -0,0 +1,10 @@
+var x = 1;
+switch (y) {
+  case 1:
+    var _x = 2;
+    console.log(_x);
+    break;
+  case 2:
+    var z = _x;
+    break;
+}
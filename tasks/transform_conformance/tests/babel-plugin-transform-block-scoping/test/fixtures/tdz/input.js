@@ -0,0 +1,6 @@
+console.log(a);
+let a = 1;
+function f() {
+  return b;
+}
+const b = 2;
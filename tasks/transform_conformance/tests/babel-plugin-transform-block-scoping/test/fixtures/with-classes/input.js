@@ -0,0 +1,5 @@
+let A = 1;
+{
+  class A {}
+  new A();
+}
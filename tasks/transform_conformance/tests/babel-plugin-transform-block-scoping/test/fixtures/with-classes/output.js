@@ -0,0 +1,11 @@
+var A = 1;
+{
+  var _A = /* @__PURE__ */ function() {
+    "use strict";
+    function _A() {
+      babelHelpers.classCallCheck(this, _A);
+    }
+    return babelHelpers.createClass(_A);
+  }();
+  new _A();
+}
@@ -0,0 +1,11 @@
+class Foo {
+  get value() {
+    return this._value;
+  }
+  set value(v) {
+    this._value = v;
+  }
+  static get instance() {
+    return new Foo();
+  }
+}
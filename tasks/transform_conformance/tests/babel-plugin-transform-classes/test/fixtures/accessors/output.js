@@ -0,0 +1,20 @@
+let Foo = /* @__PURE__ */ function() {
+  "use strict";
+  function Foo() {
+    babelHelpers.classCallCheck(this, Foo);
+  }
+  return babelHelpers.createClass(Foo, [{
+    key: "value",
+    get: function() {
+      return this._value;
+    },
+    set: function(v) {
+      this._value = v;
+    }
+  }], [{
+    key: "instance",
+    get: function() {
+      return new Foo();
+    }
+  }]);
+}();
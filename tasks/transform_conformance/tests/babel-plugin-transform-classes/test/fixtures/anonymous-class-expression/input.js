@@ -0,0 +1,4 @@
+const Foo = class {
+  method() {}
+};
+foo(class {});
@@ -0,0 +1,17 @@
+const Foo = /* @__PURE__ */ function() {
+  "use strict";
+  function Foo() {
+    babelHelpers.classCallCheck(this, Foo);
+  }
+  return babelHelpers.createClass(Foo, [{
+    key: "method",
+    value: function method() {}
+  }]);
+}();
+foo(/* @__PURE__ */ function() {
+  "use strict";
+  function _Class() {
+    babelHelpers.classCallCheck(this, _Class);
+  }
+  return babelHelpers.createClass(_Class);
+}());
@@ -0,0 +1,20 @@
+let Foo = /* @__PURE__ */ function() {
+  "use strict";
+  function Foo() {
+    babelHelpers.classCallCheck(this, Foo);
+  }
+  return babelHelpers.createClass(Foo);
+}();
+let Bar = /* @__PURE__ */ function() {
+  "use strict";
+  function Bar(a) {
+    babelHelpers.classCallCheck(this, Bar);
+    this.a = a;
+  }
+  return babelHelpers.createClass(Bar, [{
+    key: "method",
+    value: function method() {
+      return this.a;
+    }
+  }]);
+}();
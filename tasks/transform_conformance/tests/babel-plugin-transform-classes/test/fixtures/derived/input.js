@@ -0,0 +1,14 @@
+class Foo extends Bar {}
+
+class Baz extends Bar {
+  constructor(a, b) {
+    super(a);
+    this.b = b;
+  }
+}
+
+class Qux extends Bar {
+  constructor() {
+    super(...arguments);
+  }
+}
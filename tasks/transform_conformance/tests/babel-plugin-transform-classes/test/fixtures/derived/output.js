@@ -0,0 +1,30 @@
+let Foo = /* @__PURE__ */ function(_Bar) {
+  "use strict";
+  function Foo() {
+    babelHelpers.classCallCheck(this, Foo);
+    return babelHelpers.callSuper(this, Foo, arguments);
+  }
+  babelHelpers.inherits(Foo, _Bar);
+  return babelHelpers.createClass(Foo);
+}(Bar);
+let Baz = /* @__PURE__ */ function(_Bar2) {
+  "use strict";
+  function Baz(a, b) {
+    var _this;
+    babelHelpers.classCallCheck(this, Baz);
+    _this = babelHelpers.callSuper(this, Baz, [a]);
+    _this.b = b;
+    return _this;
+  }
+  babelHelpers.inherits(Baz, _Bar2);
+  return babelHelpers.createClass(Baz);
+}(Bar);
+let Qux = /* @__PURE__ */ function(_Bar3) {
+  "use strict";
+  function Qux() {
+    babelHelpers.classCallCheck(this, Qux);
+    return babelHelpers.callSuper(this, Qux, arguments);
+  }
+  babelHelpers.inherits(Qux, _Bar3);
+  return babelHelpers.createClass(Qux);
+}(Bar);
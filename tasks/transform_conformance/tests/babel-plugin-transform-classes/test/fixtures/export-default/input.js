@@ -0,0 +1,4 @@
+export default class {
+  method() {}
+}
+export class Foo {}
@@ -0,0 +1,12 @@
+export default /* @__PURE__ */ (function() {
+  function _default() {
+    babelHelpers.classCallCheck(this, _default);
+  }
+  return babelHelpers.createClass(_default, [{
+    key: "method",
+    value: function method() {}
+  }]);
+})();
+export let Foo = /* @__PURE__ */ babelHelpers.createClass(function Foo() {
+  babelHelpers.classCallCheck(this, Foo);
+});
@@ -0,0 +1 @@
+class MyError extends Error {}
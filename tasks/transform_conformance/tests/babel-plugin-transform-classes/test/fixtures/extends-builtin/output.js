@@ -0,0 +1,9 @@
+let MyError = /* @__PURE__ */ function(_Error) {
+  "use strict";
+  function MyError() {
+    babelHelpers.classCallCheck(this, MyError);
+    return babelHelpers.callSuper(this, MyError, arguments);
+  }
+  babelHelpers.inherits(MyError, _Error);
+  return babelHelpers.createClass(MyError);
+}(/* @__PURE__ */ babelHelpers.wrapNativeSuper(Error));
@@ -0,0 +1,10 @@
+class Foo {}
+
+class Bar {
+  constructor(a) {
+    this.a = a;
+  }
+  method() {
+    return this.a;
+  }
+}
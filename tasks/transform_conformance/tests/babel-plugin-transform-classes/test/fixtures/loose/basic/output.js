@@ -0,0 +1,16 @@
+let Foo = /* @__PURE__ */ function() {
+  "use strict";
+  function Foo() {}
+  return Foo;
+}();
+let Bar = /* @__PURE__ */ function() {
+  "use strict";
+  function Bar(a) {
+    this.a = a;
+  }
+  var _proto = Bar.prototype;
+  _proto.method = function method() {
+    return this.a;
+  };
+  return Bar;
+}();
@@ -0,0 +1,25 @@
+class Foo extends Bar {
+  method() {
+    super.method();
+    super.value = 1;
+    return super.value;
+  }
+  static create() {
+    return super.create();
+  }
+}
+
+class Baz extends Bar {}
+
+class Qux extends Bar {
+  constructor(a, b) {
+    super(a);
+    this.b = b;
+  }
+}
+
+class Quux extends Bar {
+  constructor() {
+    super(...arguments);
+  }
+}
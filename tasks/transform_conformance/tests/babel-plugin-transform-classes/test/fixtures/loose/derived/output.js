@@ -0,0 +1,44 @@
+let Foo = /* @__PURE__ */ function(_Bar) {
+  "use strict";
+  function Foo() {
+    return _Bar.apply(this, arguments) || this;
+  }
+  babelHelpers.inheritsLoose(Foo, _Bar);
+  var _proto = Foo.prototype;
+  _proto.method = function method() {
+    babelHelpers.superPropGet(Foo, "method", this, 3)([]);
+    babelHelpers.superPropSet(Foo, "value", 1, this, 1, 1);
+    return babelHelpers.superPropGet(Foo, "value", this, 1);
+  };
+  Foo.create = function create() {
+    return babelHelpers.superPropGet(Foo, "create", this, 2)([]);
+  };
+  return Foo;
+}(Bar);
+let Baz = /* @__PURE__ */ function(_Bar2) {
+  "use strict";
+  function Baz() {
+    return _Bar2.apply(this, arguments) || this;
+  }
+  babelHelpers.inheritsLoose(Baz, _Bar2);
+  return Baz;
+}(Bar);
+let Qux = /* @__PURE__ */ function(_Bar3) {
+  "use strict";
+  function Qux(a, b) {
+    var _this;
+    _this = _Bar3.call(this, a) || this;
+    _this.b = b;
+    return _this;
+  }
+  babelHelpers.inheritsLoose(Qux, _Bar3);
+  return Qux;
+}(Bar);
+let Quux = /* @__PURE__ */ function(_Bar4) {
+  "use strict";
+  function Quux() {
+    return _Bar4.apply(this, arguments) || this;
+  }
+  babelHelpers.inheritsLoose(Quux, _Bar4);
+  return Quux;
+}(Bar);
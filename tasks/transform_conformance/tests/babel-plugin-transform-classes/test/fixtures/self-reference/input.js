@@ -0,0 +1,12 @@
+class Foo {
+  method() {
+    return Foo;
+  }
+}
+Foo = null;
+
+let Bar = class {
+  static create() {
+    return new Bar();
+  }
+};
@@ -0,0 +1,25 @@
+let Foo = /* @__PURE__ */ function() {
+  "use strict";
+  function Foo() {
+    babelHelpers.classCallCheck(this, Foo);
+  }
+  return babelHelpers.createClass(Foo, [{
+    key: "method",
+    value: function method() {
+      return Foo;
+    }
+  }]);
+}();
+Foo = null;
+let Bar = /* @__PURE__ */ function() {
+  "use strict";
+  function _Bar() {
+    babelHelpers.classCallCheck(this, _Bar);
+  }
+  return babelHelpers.createClass(_Bar, null, [{
+    key: "create",
+    value: function create() {
+      return new Bar();
+    }
+  }]);
+}();
@@ -0,0 +1,6 @@
+class Foo {
+  static create() {
+    return new Foo();
+  }
+  ["computed" + "Key"]() {}
+}
@@ -0,0 +1,15 @@
+let Foo = /* @__PURE__ */ function() {
+  "use strict";
+  function Foo() {
+    babelHelpers.classCallCheck(this, Foo);
+  }
+  return babelHelpers.createClass(Foo, [{
+    key: "computed" + "Key",
+    value: function() {}
+  }], [{
+    key: "create",
+    value: function create() {
+      return new Foo();
+    }
+  }]);
+}();
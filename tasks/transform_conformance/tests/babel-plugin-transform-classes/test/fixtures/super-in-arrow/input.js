@@ -0,0 +1,14 @@
+class Foo extends Bar {
+  constructor() {
+    const fn = () => super();
+    if (cond) {
+      fn();
+    } else {
+      super();
+    }
+    this.x = 1;
+  }
+  method() {
+    return () => super.method();
+  }
+}
@@ -0,0 +1,22 @@
+let Foo = /* @__PURE__ */ function(_Bar) {
+  "use strict";
+  function Foo() {
+    var _this;
+    babelHelpers.classCallCheck(this, Foo);
+    const fn = () => _this = babelHelpers.callSuper(this, Foo);
+    if (cond) {
+      fn();
+    } else {
+      _this = babelHelpers.callSuper(this, Foo);
+    }
+    _this.x = 1;
+    return babelHelpers.assertThisInitialized(_this);
+  }
+  babelHelpers.inherits(Foo, _Bar);
+  return babelHelpers.createClass(Foo, [{
+    key: "method",
+    value: function method() {
+      return () => babelHelpers.superPropGet(Foo, "method", this, 3)([]);
+    }
+  }]);
+}(Bar);
@@ -0,0 +1,10 @@
+class Foo extends Bar {
+  method() {
+    super.method();
+    super.value = 1;
+    return super.value;
+  }
+  static create() {
+    return super.create();
+  }
+}
@@ -0,0 +1,21 @@
+let Foo = /* @__PURE__ */ function(_Bar) {
+  "use strict";
+  function Foo() {
+    babelHelpers.classCallCheck(this, Foo);
+    return babelHelpers.callSuper(this, Foo, arguments);
+  }
+  babelHelpers.inherits(Foo, _Bar);
+  return babelHelpers.createClass(Foo, [{
+    key: "method",
+    value: function method() {
+      babelHelpers.superPropGet(Foo, "method", this, 3)([]);
+      babelHelpers.superPropSet(Foo, "value", 1, this, 1, 1);
+      return babelHelpers.superPropGet(Foo, "value", this, 1);
+    }
+  }], [{
+    key: "create",
+    value: function create() {
+      return babelHelpers.superPropGet(Foo, "create", this, 2)([]);
+    }
+  }]);
+}(Bar);
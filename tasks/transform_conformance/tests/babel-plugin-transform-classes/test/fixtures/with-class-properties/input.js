@@ -0,0 +1,8 @@
+class Foo extends Bar {
+  prop = 1;
+  static staticProp = 2;
+  #private = 3;
+  method() {
+    return this.#private;
+  }
+}
@@ -0,0 +1,20 @@
+var _private = /* @__PURE__ */ new WeakMap();
+let Foo = /* @__PURE__ */ function(_Bar) {
+  "use strict";
+  function Foo(..._args) {
+    var _this;
+    babelHelpers.classCallCheck(this, Foo);
+    _this = babelHelpers.callSuper(this, Foo, [..._args]);
+    babelHelpers.defineProperty(_this, "prop", 1);
+    babelHelpers.classPrivateFieldInitSpec(_this, _private, 3);
+    return _this;
+  }
+  babelHelpers.inherits(Foo, _Bar);
+  return babelHelpers.createClass(Foo, [{
+    key: "method",
+    value: function method() {
+      return babelHelpers.classPrivateFieldGet2(_private, this);
+    }
+  }]);
+}(Bar);
+babelHelpers.defineProperty(Foo, "staticProp", 2);
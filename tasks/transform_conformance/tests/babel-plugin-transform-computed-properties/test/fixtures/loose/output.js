@@ -0,0 +1,3 @@
+var _obj, _obj2;
+var a = (_obj = {}, _obj[x] = 1, _obj);
+var b = (_obj2 = { c: 1 }, _obj2[x] = 2, _obj2.d = 3, babelHelpers.defineAccessor("get", _obj2, y, function() {}), _obj2);
@@ -0,0 +1,3 @@
+var a = { c: 1, [x]: 2, d: 3, "e": 4, 5: 6 };
+var b = { [x]: 1, get [y]() {}, set [y](v) {} };
+var c = { [x]: 1, ...rest };
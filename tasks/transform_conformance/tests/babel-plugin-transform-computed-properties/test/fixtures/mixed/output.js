@@ -0,0 +1,7 @@
+var _obj, _obj2;
+var a = (_obj = { c: 1 }, babelHelpers.defineProperty(_obj, x, 2), babelHelpers.defineProperty(_obj, "d", 3), babelHelpers.defineProperty(_obj, "e", 4), babelHelpers.defineProperty(_obj, 5, 6), _obj);
+var b = (_obj2 = {}, babelHelpers.defineProperty(_obj2, x, 1), babelHelpers.defineAccessor("get", _obj2, y, function() {}), babelHelpers.defineAccessor("set", _obj2, y, function(v) {}), _obj2);
+var c = {
+  [x]: 1,
+  ...rest
+};
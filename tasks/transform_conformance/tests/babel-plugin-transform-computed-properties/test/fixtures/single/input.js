@@ -0,0 +1,2 @@
+var a = { [x]: 1 };
+var b = { c: 1, [x]: 2 };
@@ -0,0 +1,2 @@
+var a = babelHelpers.defineProperty({}, x, 1);
+var b = babelHelpers.defineProperty({ c: 1 }, x, 2);
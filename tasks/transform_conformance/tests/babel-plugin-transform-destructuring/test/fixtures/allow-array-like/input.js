@@ -0,0 +1,3 @@
+function f() {
+  var [a, b] = arguments;
+}
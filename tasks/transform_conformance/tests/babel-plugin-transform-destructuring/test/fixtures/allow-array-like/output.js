@@ -0,0 +1,3 @@
+function f() {
+  var _arguments = babelHelpers.maybeArrayLike(babelHelpers.slicedToArray, arguments, 2), a = _arguments[0], b = _arguments[1];
+}
@@ -0,0 +1,5 @@
+[a, b] = [b, a];
+({ c, d: { e } } = obj);
+x = [f, g] = arr;
+const fn = () => ({ h } = obj);
+for ([i, j] = pair; i < j; i++);
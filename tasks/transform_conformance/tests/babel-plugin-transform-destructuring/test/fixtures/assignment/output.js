@@ -0,0 +1,9 @@
+var _ref, _obj, _arr, _arr2, _pair;
+_ref = babelHelpers.slicedToArray([b, a], 2), a = _ref[0], b = _ref[1];
+_obj = obj, c = _obj.c, e = _obj.d.e;
+x = (_arr = arr, _arr2 = babelHelpers.slicedToArray(_arr, 2), f = _arr2[0], g = _arr2[1], _arr);
+const fn = () => {
+  var _obj2;
+  return _obj2 = obj, h = _obj2.h, _obj2;
+};
+for (_pair = babelHelpers.slicedToArray(pair, 2), i = _pair[0], j = _pair[1]; i < j; i++);
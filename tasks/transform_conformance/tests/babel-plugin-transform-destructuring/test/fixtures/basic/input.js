@@ -0,0 +1,5 @@
+var [a, b] = arr;
+var { c, d: e = 1 } = obj;
+let [g, , h = 2, ...i] = arr;
+const { j: { k }, [key]: l } = obj;
+var [m] = [1, 2];
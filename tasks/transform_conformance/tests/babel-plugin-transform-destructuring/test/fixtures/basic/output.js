@@ -0,0 +1,5 @@
+var _arr = babelHelpers.slicedToArray(arr, 2), a = _arr[0], b = _arr[1];
+var _obj = obj, c = _obj.c, _obj$d = _obj.d, e = _obj$d === void 0 ? 1 : _obj$d;
+let _arr2 = babelHelpers.toArray(arr), g = _arr2[0], _arr2$ = _arr2[2], h = _arr2$ === void 0 ? 2 : _arr2$, i = _arr2.slice(3);
+const _obj2 = obj, k = _obj2.j.k, l = _obj2[key];
+var _ref = babelHelpers.slicedToArray([1, 2], 1), m = _ref[0];
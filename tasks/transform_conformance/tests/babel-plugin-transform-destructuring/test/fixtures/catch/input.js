@@ -0,0 +1,5 @@
+try {
+  foo();
+} catch ({ message, code = 0 }) {
+  console.log(message, code);
+}
@@ -0,0 +1,6 @@
+try {
+  foo();
+} catch (_ref) {
+  let message = _ref.message, _ref$code = _ref.code, code = _ref$code === void 0 ? 0 : _ref$code;
+  console.log(message, code);
+}
@@ -0,0 +1,2 @@
+export var [a, b] = arr;
+export const { c, d: e } = obj;
@@ -0,0 +1,4 @@
+var _arr = babelHelpers.slicedToArray(arr, 2), a = _arr[0], b = _arr[1];
+export { a, b };
+const _obj = obj, c = _obj.c, e = _obj.d;
+export { c, e };
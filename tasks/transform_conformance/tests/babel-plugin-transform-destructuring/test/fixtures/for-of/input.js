@@ -0,0 +1,6 @@
+for (const [key, value] of entries) {
+  console.log(key, value);
+}
+for (var { a, b } of list) a + b;
+for ([c, d] of pairs) c + d;
+for (let { length } in obj) length;
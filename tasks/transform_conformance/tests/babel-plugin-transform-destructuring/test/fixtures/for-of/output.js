@@ -0,0 +1,17 @@
+for (const _ref of entries) {
+  const _ref2 = babelHelpers.slicedToArray(_ref, 2), key = _ref2[0], value = _ref2[1];
+  console.log(key, value);
+}
+for (var _ref3 of list) {
+  var a = _ref3.a, b = _ref3.b;
+  a + b;
+}
+for (var _ref4 of pairs) {
+  var _ref5;
+  _ref5 = babelHelpers.slicedToArray(_ref4, 2), c = _ref5[0], d = _ref5[1];
+  c + d;
+}
+for (let _ref6 in obj) {
+  let length = _ref6.length;
+  length;
+}
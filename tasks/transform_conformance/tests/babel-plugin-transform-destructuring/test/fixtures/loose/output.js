@@ -0,0 +1,3 @@
+var _ref;
+var _arr = arr, a = _arr[0], b = _arr[1], c = _arr.slice(2);
+_ref = [e, d], d = _ref[0], e = _ref[1];
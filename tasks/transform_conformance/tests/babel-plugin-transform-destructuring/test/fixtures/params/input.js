@@ -0,0 +1,5 @@
+function f([a, b], { c }) {
+  return a + b + c;
+}
+const g = ({ d }, e) => d + e;
+const h = ({ i }) => i;
@@ -0,0 +1,12 @@
+function f(_ref, _ref2) {
+  var _ref3 = babelHelpers.slicedToArray(_ref, 2), a = _ref3[0], b = _ref3[1], c = _ref2.c;
+  return a + b + c;
+}
+const g = (_ref4, e) => {
+  var d = _ref4.d;
+  return d + e;
+};
+const h = (_ref5) => {
+  var i = _ref5.i;
+  return i;
+};
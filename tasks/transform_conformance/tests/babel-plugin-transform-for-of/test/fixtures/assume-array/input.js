@@ -0,0 +1,4 @@
+for (const x of arr) {
+  console.log(x);
+}
+for (const y of foo()) {}
@@ -0,0 +1,9 @@
+for (let _i = 0, _arr = arr; _i < _arr.length; _i++) {
+  const x = _arr[_i];
+  {
+    console.log(x);
+  }
+}
+for (let _i2 = 0, _foo = foo(); _i2 < _foo.length; _i2++) {
+  const y = _foo[_i2];
+}
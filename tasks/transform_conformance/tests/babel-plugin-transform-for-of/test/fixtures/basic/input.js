@@ -0,0 +1,10 @@
+for (const x of arr) {
+  console.log(x);
+}
+for (let [a, b] of arr) {}
+outer: for (var y of arr) {
+  for (z of y) {
+    continue outer;
+  }
+}
+if (foo) for (const x of arr) bar(x);
@@ -0,0 +1 @@
+for (const x of arr) {}
@@ -0,0 +1,3 @@
+for (let _i = 0, _arr = arr; _i < _arr.length; _i++) {
+  const x = _arr[_i];
+}
@@ -0,0 +1,6 @@
+for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(arr), _step; !(_step = _iterator()).done;) {
+  const x = _step.value;
+  {
+    console.log(x);
+  }
+}
@@ -0,0 +1,12 @@
+define(["require", "exports"], function(_require, _exports) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.load = load;
+  function load() {
+    return new Promise(function(_resolve, _reject) {
+      return _require(["./lazy.js"], function(imported) {
+        return _resolve(babelHelpers.interopRequireWildcard(imported));
+      }, _reject);
+    });
+  }
+});
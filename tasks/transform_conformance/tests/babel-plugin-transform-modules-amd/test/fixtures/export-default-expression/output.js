@@ -0,0 +1,6 @@
+define(["exports"], function(_exports) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.default = void 0;
+  _exports.default = 1 + 2;
+});
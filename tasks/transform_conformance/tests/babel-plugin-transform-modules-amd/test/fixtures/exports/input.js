@@ -0,0 +1,16 @@
+export let a = 1;
+export const b = 2, c = 3;
+export var { d, e: [f] } = obj;
+export function g() {
+  a++;
+  return a;
+}
+export class H {}
+let i = 0;
+export { i, i as j };
+
+a = 2;
+[a] = [3];
+i += 1;
+const k = i++;
+for (a of [4]) {}
@@ -0,0 +1,26 @@
+define(["exports"], function(_exports) {
+  "use strict";
+  var _temp;
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.a = _exports.b = _exports.c = _exports.d = _exports.f = _exports.H = _exports.i = _exports.j = void 0;
+  _exports.g = g;
+  let a = _exports.a = 1;
+  const b = _exports.b = 2, c = _exports.c = 3;
+  var { d, e: [f] } = obj;
+  _exports.d = d;
+  _exports.f = f;
+  function g() {
+    _exports.a = ++a;
+    return a;
+  }
+  class H {}
+  _exports.H = H;
+  let i = _exports.i = _exports.j = 0;
+  _exports.a = a = 2;
+  [a] = [3], _exports.a = a;
+  _exports.i = _exports.j = i += 1;
+  const k = (_temp = i++, _exports.i = _exports.j = i, _temp);
+  for (a of [4]) {
+    _exports.a = a;
+  }
+});
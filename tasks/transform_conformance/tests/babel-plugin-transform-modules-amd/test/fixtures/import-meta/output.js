@@ -0,0 +1,5 @@
+define(["module"], function(_module) {
+  "use strict";
+  var _importMeta = { url: new URL(_module.uri, document.baseURI).href };
+  console.log(_importMeta.url);
+});
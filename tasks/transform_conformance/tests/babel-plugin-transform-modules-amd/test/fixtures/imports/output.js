@@ -0,0 +1,19 @@
+define([
+  "foo",
+  "bar",
+  "ns",
+  "mixed",
+  "side-effect"
+], function(_foo, _bar, ns, _mixed) {
+  "use strict";
+  _foo = babelHelpers.interopRequireDefault(_foo);
+  ns = babelHelpers.interopRequireWildcard(ns);
+  _mixed = babelHelpers.interopRequireWildcard(_mixed);
+  (0, _foo.default)();
+  (0, _bar.bar)(_bar.baz, ns.x);
+  const obj = {
+    bar: _bar.bar,
+    named: _mixed.named
+  };
+  tag`${_mixed.default}`;
+});
@@ -0,0 +1,5 @@
+define(["foo"], function(_foo) {
+  "use strict";
+  _foo = babelHelpers.interopRequireDefault(_foo);
+  (0, _foo.default)();
+});
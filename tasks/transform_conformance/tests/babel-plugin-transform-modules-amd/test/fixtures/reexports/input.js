@@ -0,0 +1,4 @@
+export { a, b as c, default as d } from "foo";
+export * from "bar";
+export * as ns from "baz";
+export const e = 1;
@@ -0,0 +1,50 @@
+define([
+  "exports",
+  "foo",
+  "bar",
+  "baz"
+], function(_exports, _foo, _bar, _baz) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  var _exportNames = {
+    e: true,
+    a: true,
+    c: true,
+    d: true,
+    ns: true
+  };
+  Object.defineProperty(_exports, "a", {
+    enumerable: true,
+    get: function() {
+      return _foo.a;
+    }
+  });
+  Object.defineProperty(_exports, "c", {
+    enumerable: true,
+    get: function() {
+      return _foo.b;
+    }
+  });
+  Object.defineProperty(_exports, "d", {
+    enumerable: true,
+    get: function() {
+      return _foo.default;
+    }
+  });
+  _exports.e = void 0;
+  _foo = babelHelpers.interopRequireWildcard(_foo);
+  _baz = babelHelpers.interopRequireWildcard(_baz);
+  _exports.ns = _baz;
+  Object.keys(_bar).forEach(function(key) {
+    if (key === "default" || key === "__esModule") return;
+    if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+    if (key in _exports && _exports[key] === _bar[key]) return;
+    Object.defineProperty(_exports, key, {
+      enumerable: true,
+      get: function() {
+        return _bar[key];
+      }
+    });
+  });
+  const e = _exports.e = 1;
+});
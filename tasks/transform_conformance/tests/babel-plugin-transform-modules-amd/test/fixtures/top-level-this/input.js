@@ -0,0 +1,4 @@
+export const self = this;
+export function f() {
+  return this;
+}
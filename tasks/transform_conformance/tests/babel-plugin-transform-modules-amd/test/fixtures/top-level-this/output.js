@@ -0,0 +1,10 @@
+define(["exports"], function(_exports) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.self = void 0;
+  _exports.f = f;
+  const self = _exports.self = void 0;
+  function f() {
+    return this;
+  }
+});
@@ -0,0 +1,7 @@
+import("./a");
+import(`./b`);
+import(name);
+
+async function load() {
+  return await import(`./${name}`);
+}
@@ -0,0 +1,15 @@
+"use strict";
+Promise.resolve().then(function() {
+  return babelHelpers.interopRequireWildcard(require("./a"));
+});
+Promise.resolve().then(function() {
+  return babelHelpers.interopRequireWildcard(require(`./b`));
+});
+Promise.resolve(`${name}`).then(function(s) {
+  return babelHelpers.interopRequireWildcard(require(s));
+});
+async function load() {
+  return await Promise.resolve(`./${name}`).then(function(s) {
+    return babelHelpers.interopRequireWildcard(require(s));
+  });
+}
@@ -0,0 +1,13 @@
+export const a = 1;
+export let b = 2, c;
+export function f() {
+  return this;
+}
+export class C {}
+export { a as aa };
+
+b = 3;
+c++;
+console.log(this);
+
+export default a + b;
@@ -0,0 +1,15 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.a = exports.b = exports.c = exports.C = exports.aa = exports.default = void 0;
+exports.f = f;
+const a = exports.a = exports.aa = 1;
+let b = exports.b = 2, c;
+function f() {
+  return this;
+}
+class C {}
+exports.C = C;
+exports.b = b = 3;
+exports.c = ++c;
+console.log(void 0);
+exports.default = a + b;
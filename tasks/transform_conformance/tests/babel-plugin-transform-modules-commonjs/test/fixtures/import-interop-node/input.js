@@ -0,0 +1,7 @@
+import foo from "foo";
+import { bar } from "bar";
+import * as ns from "ns";
+import def, * as both from "both";
+
+import("dynamic");
+console.log(foo, bar, ns, def, both);
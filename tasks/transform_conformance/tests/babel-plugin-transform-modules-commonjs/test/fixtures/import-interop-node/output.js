@@ -0,0 +1,9 @@
+"use strict";
+var _foo = require("foo");
+var _bar = require("bar");
+var ns = babelHelpers.interopRequireWildcard(require("ns"), true);
+var both = babelHelpers.interopRequireWildcard(require("both"), true);
+Promise.resolve().then(function() {
+  return babelHelpers.interopRequireWildcard(require("dynamic"), true);
+});
+console.log(_foo, _bar.bar, ns, both.default, both);
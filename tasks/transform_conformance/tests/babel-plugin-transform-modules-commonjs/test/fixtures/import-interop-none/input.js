@@ -0,0 +1,5 @@
+import foo from "foo";
+import * as ns from "ns";
+
+import("dynamic");
+console.log(foo, ns);
@@ -0,0 +1,7 @@
+"use strict";
+var _foo = require("foo");
+var ns = require("ns");
+Promise.resolve().then(function() {
+  return require("dynamic");
+});
+console.log(_foo.default, ns);
@@ -0,0 +1,9 @@
+import "side-effect";
+import foo from "foo";
+import { bar, baz as qux } from "bar";
+import * as ns from "ns";
+import def, { named } from "mixed";
+
+foo(bar, qux, ns, def, named);
+bar();
+const obj = { bar, qux };
@@ -0,0 +1,10 @@
+import "side-effect";
+import foo from "foo";
+import { bar } from "bar";
+import * as ns from "ns";
+import local from "./local";
+export { reexported } from "reexported";
+
+export function f() {
+  return [foo, bar(), ns, local];
+}
@@ -0,0 +1,41 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+Object.defineProperty(exports, "reexported", {
+  enumerable: true,
+  get: function() {
+    return _reexported.reexported;
+  }
+});
+exports.f = f;
+require("side-effect");
+function _foo() {
+  const data = babelHelpers.interopRequireDefault(require("foo"));
+  _foo = function() {
+    return data;
+  };
+  return data;
+}
+function _bar() {
+  const data = require("bar");
+  _bar = function() {
+    return data;
+  };
+  return data;
+}
+function _ns() {
+  const data = babelHelpers.interopRequireWildcard(require("ns"));
+  _ns = function() {
+    return data;
+  };
+  return data;
+}
+var _local = babelHelpers.interopRequireDefault(require("./local"));
+var _reexported = require("reexported");
+function f() {
+  return [
+    _foo().default,
+    (0, _bar().bar)(),
+    _ns(),
+    _local.default
+  ];
+}
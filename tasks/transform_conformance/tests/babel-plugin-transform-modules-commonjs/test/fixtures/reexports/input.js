@@ -0,0 +1,4 @@
+export { x, default as y } from "dep";
+export * as ns from "ns";
+export * from "star";
+export const local = 1;
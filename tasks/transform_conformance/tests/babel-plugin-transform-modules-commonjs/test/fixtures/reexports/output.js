@@ -0,0 +1,37 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+var _exportNames = {
+  local: true,
+  x: true,
+  y: true,
+  ns: true
+};
+Object.defineProperty(exports, "x", {
+  enumerable: true,
+  get: function() {
+    return _dep.x;
+  }
+});
+Object.defineProperty(exports, "y", {
+  enumerable: true,
+  get: function() {
+    return _dep.default;
+  }
+});
+exports.local = void 0;
+var _dep = babelHelpers.interopRequireWildcard(require("dep"));
+var _ns = babelHelpers.interopRequireWildcard(require("ns"));
+var _star = require("star");
+exports.ns = _ns;
+Object.keys(_star).forEach(function(key) {
+  if (key === "default" || key === "__esModule") return;
+  if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+  if (key in exports && exports[key] === _star[key]) return;
+  Object.defineProperty(exports, key, {
+    enumerable: true,
+    get: function() {
+      return _star[key];
+    }
+  });
+});
+const local = exports.local = 1;
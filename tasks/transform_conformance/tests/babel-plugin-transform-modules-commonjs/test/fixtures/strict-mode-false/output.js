@@ -0,0 +1,4 @@
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.b = void 0;
+var _a = babelHelpers.interopRequireDefault(require("a"));
+const b = exports.b = _a.default;
@@ -0,0 +1 @@
+await Promise.resolve();
@@ -0,0 +1,11 @@
+export let x = 1;
+export const { y, z: [w] } = obj;
+export function f() {
+  x++;
+}
+export class C {}
+let i;
+export { i as j };
+i = 2;
+x += 1;
+const k = x++;
@@ -0,0 +1,19 @@
+System.register([], function(_export, _context) {
+  "use strict";
+  var x, y, w, C, i, k, _temp;
+  function f() {
+    _export("x", ++x);
+  }
+  _export("f", f);
+  return {
+    setters: [],
+    execute: function() {
+      _export("x", x = 1);
+      ({y, z: [w]} = obj), _export("y", y), _export("w", w);
+      _export("C", C = class {});
+      _export("j", i = 2);
+      _export("x", x += 1);
+      k = (_temp = x++, _export("x", x), _temp);
+    }
+  };
+});
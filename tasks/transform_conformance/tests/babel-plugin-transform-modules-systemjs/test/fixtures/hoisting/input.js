@@ -0,0 +1,10 @@
+if (cond) {
+  var a = 1;
+}
+for (var i = 0; i < 1; i++) {}
+for (var key in obj) {}
+function f() {
+  var local = 2;
+  return local;
+}
+class D {}
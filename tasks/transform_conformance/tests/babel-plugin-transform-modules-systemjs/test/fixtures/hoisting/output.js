@@ -0,0 +1,19 @@
+System.register([], function(_export, _context) {
+  "use strict";
+  var a, i, key, D;
+  function f() {
+    var local = 2;
+    return local;
+  }
+  return {
+    setters: [],
+    execute: function() {
+      if (cond) {
+        a = 1;
+      }
+      for (i = 0; i < 1; i++) {}
+      for (key in obj) {}
+      D = class {};
+    }
+  };
+});
@@ -0,0 +1,11 @@
+System.register([], function(_export, _context) {
+  "use strict";
+  var url, lazy;
+  return {
+    setters: [],
+    execute: function() {
+      _export("url", url = _context.meta.url);
+      _export("lazy", lazy = () => _context.import("./lazy.js"));
+    }
+  };
+});
@@ -0,0 +1,4 @@
+import "side-effect";
+import foo, { bar } from "foo";
+import * as ns from "ns";
+foo(bar, ns);
@@ -0,0 +1,23 @@
+System.register([
+  "side-effect",
+  "foo",
+  "ns"
+], function(_export, _context) {
+  "use strict";
+  var foo, bar, ns;
+  return {
+    setters: [
+      function() {},
+      function(_foo) {
+        foo = _foo.default;
+        bar = _foo.bar;
+      },
+      function(_ns) {
+        ns = _ns;
+      }
+    ],
+    execute: function() {
+      foo(bar, ns);
+    }
+  };
+});
@@ -0,0 +1,3 @@
+export { a, b as c } from "foo";
+export * from "bar";
+export * as ns from "baz";
@@ -0,0 +1,26 @@
+System.register([
+  "foo",
+  "bar",
+  "baz"
+], function(_export, _context) {
+  "use strict";
+  return {
+    setters: [
+      function(_foo) {
+        _export("a", _foo.a);
+        _export("c", _foo.b);
+      },
+      function(_bar) {
+        var _exportObj = {};
+        for (var _key in _bar) {
+          if (_key !== "default" && _key !== "__esModule") _exportObj[_key] = _bar[_key];
+        }
+        _export(_exportObj);
+      },
+      function(_baz) {
+        _export("ns", _baz);
+      }
+    ],
+    execute: function() {}
+  };
+});
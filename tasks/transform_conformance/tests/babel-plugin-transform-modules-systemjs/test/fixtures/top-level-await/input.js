@@ -0,0 +1,2 @@
+import foo from "foo";
+export const x = await foo();
@@ -0,0 +1,12 @@
+System.register(["foo"], function(_export, _context) {
+  "use strict";
+  var foo, x;
+  return {
+    setters: [function(_foo) {
+      foo = _foo.default;
+    }],
+    execute: async function() {
+      _export("x", x = await foo());
+    }
+  };
+});
@@ -0,0 +1 @@
+import("./lazy.js");
@@ -0,0 +1,15 @@
+(function(global, factory) {
+  if (typeof define === "function" && define.amd) {
+    define(factory);
+  } else if (typeof exports !== "undefined") {
+    factory();
+  } else {
+    var mod = { exports: {} };
+    factory();
+    global.input = mod.exports;
+  }
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function() {
+  "use strict";
+  var _importMeta = { url: typeof document === "undefined" ? require("url").pathToFileURL(__filename).href : document.currentScript && document.currentScript.src || new URL("input.js", document.baseURI).href };
+  console.log(_importMeta.url);
+});
@@ -0,0 +1,7 @@
+import "side-effect";
+import foo from "foo";
+import { bar } from "./bar-baz.js";
+export const x = foo;
+export function f() {
+  return bar();
+}
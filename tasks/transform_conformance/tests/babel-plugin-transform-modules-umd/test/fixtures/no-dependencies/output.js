@@ -0,0 +1,17 @@
+(function(global, factory) {
+  if (typeof define === "function" && define.amd) {
+    define(["exports"], factory);
+  } else if (typeof exports !== "undefined") {
+    factory(exports);
+  } else {
+    var mod = { exports: {} };
+    factory(mod.exports);
+    global.input = mod.exports;
+  }
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.default = void 0;
+  class _default {}
+  _exports.default = _default;
+});
@@ -0,0 +1,23 @@
+class Foo {
+  constructor() {
+    this.a = new.target;
+  }
+  b = new.target;
+  method() {
+    return new.target;
+  }
+  static {
+    new.target;
+  }
+}
+var o = {
+  method() {
+    return new.target;
+  },
+  get a() {
+    return new.target;
+  },
+  b: function () {
+    return new.target;
+  },
+};
@@ -0,0 +1,23 @@
+class Foo {
+  constructor() {
+    this.a = this.constructor;
+  }
+  b = this.constructor;
+  method() {
+    return void 0;
+  }
+  static {
+    this.constructor;
+  }
+}
+var o = {
+  method() {
+    return void 0;
+  },
+  get a() {
+    return void 0;
+  },
+  b: function _target() {
+    return this instanceof _target ? this.constructor : void 0;
+  }
+};
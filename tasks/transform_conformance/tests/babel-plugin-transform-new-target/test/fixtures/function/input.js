@@ -0,0 +1,10 @@
+function Foo() {
+  const a = () => new.target;
+  return new.target;
+}
+var Bar = function () {
+  return new.target;
+};
+export default function () {
+  return new.target;
+}
@@ -0,0 +1 @@
+const f = (a = 1, ...b) => a + b.length;
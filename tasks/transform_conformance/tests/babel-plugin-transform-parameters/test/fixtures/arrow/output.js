@@ -0,0 +1,7 @@
+const f = function() {
+  var a = arguments.length > 0 && arguments[0] !== void 0 ? arguments[0] : 1;
+  for (var _len = arguments.length, b = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+    b[_key - 1] = arguments[_key];
+  }
+  return a + b.length;
+};
@@ -0,0 +1,7 @@
+function f(a) {
+  for (var _len = arguments.length, _ref = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+    _ref[_key - 1] = arguments[_key];
+  }
+  var _ref2 = babelHelpers.slicedToArray(_ref, 2), b = _ref2[0], c = _ref2[1];
+  return b + c;
+}
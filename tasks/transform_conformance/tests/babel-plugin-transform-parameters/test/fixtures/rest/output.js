@@ -0,0 +1,6 @@
+function f(a, b) {
+  for (var _len = arguments.length, c = new Array(_len > 2 ? _len - 2 : 0), _key = 2; _key < _len; _key++) {
+    c[_key - 2] = arguments[_key];
+  }
+  return c;
+}
@@ -0,0 +1,3 @@
+function f({ a }, [b] = [], c = a) {
+  return a + b + c;
+}
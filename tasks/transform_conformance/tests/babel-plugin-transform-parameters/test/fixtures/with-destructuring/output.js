@@ -0,0 +1,6 @@
+function f(_ref) {
+  var a = _ref.a;
+  var _ref2 = babelHelpers.slicedToArray(arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : [], 1), b = _ref2[0];
+  var c = arguments.length > 2 && arguments[2] !== void 0 ? arguments[2] : a;
+  return a + b + c;
+}
@@ -0,0 +1,9 @@
+function* gen() {
+  yield arguments.length;
+  yield this.value;
+  const fn = () => arguments[0] + this.value;
+  yield fn();
+  yield function () {
+    return arguments;
+  };
+}
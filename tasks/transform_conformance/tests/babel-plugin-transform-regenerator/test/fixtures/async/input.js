@@ -0,0 +1,8 @@
+async function foo(x) {
+  const y = await x;
+  return y + 1;
+}
+
+const bar = async () => {
+  await foo(1);
+};
@@ -0,0 +1,37 @@
+function foo(_x) {
+  return _foo.apply(this, arguments);
+}
+function _foo() {
+  _foo = babelHelpers.asyncToGenerator(babelHelpers.regeneratorRuntime().mark(function _callee(x) {
+    var y;
+    return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+      while (1) switch (_context.prev = _context.next) {
+        case 0:
+          _context.next = 2;
+          return x;
+        case 2:
+          y = _context.sent;
+          return _context.abrupt("return", y + 1);
+        case 4:
+        case "end": return _context.stop();
+      }
+    }, _callee);
+  }));
+  return _foo.apply(this, arguments);
+}
+const bar = function() {
+  var _ref = babelHelpers.asyncToGenerator(babelHelpers.regeneratorRuntime().mark(function _callee2() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee2$(_context2) {
+      while (1) switch (_context2.prev = _context2.next) {
+        case 0:
+          _context2.next = 2;
+          return foo(1);
+        case 2:
+        case "end": return _context2.stop();
+      }
+    }, _callee2);
+  }));
+  return function bar() {
+    return _ref.apply(this, arguments);
+  };
+}();
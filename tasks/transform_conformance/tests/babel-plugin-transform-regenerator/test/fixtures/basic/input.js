@@ -0,0 +1,13 @@
+function* foo(x) {
+  const y = yield x;
+  return y + 1;
+}
+
+const bar = function* () {
+  yield 1;
+  yield 2;
+};
+
+export default function* () {
+  yield foo(1);
+}
@@ -0,0 +1,41 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(foo), _marked2 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(_callee2);
+function foo(x) {
+  var y;
+  return babelHelpers.regeneratorRuntime().wrap(function foo$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return x;
+      case 2:
+        y = _context.sent;
+        return _context.abrupt("return", y + 1);
+      case 4:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+const bar = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function _callee() {
+  return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context2) {
+    while (1) switch (_context2.prev = _context2.next) {
+      case 0:
+        _context2.next = 2;
+        return 1;
+      case 2:
+        _context2.next = 4;
+        return 2;
+      case 4:
+      case "end": return _context2.stop();
+    }
+  }, _callee);
+});
+export default function _callee2() {
+  return babelHelpers.regeneratorRuntime().wrap(function _callee2$(_context3) {
+    while (1) switch (_context3.prev = _context3.next) {
+      case 0:
+        _context3.next = 2;
+        return foo(1);
+      case 2:
+      case "end": return _context3.stop();
+    }
+  }, _marked2);
+}
@@ -0,0 +1,12 @@
+var x = 1;
+function* gen() {
+  let a = yield 1;
+  {
+    let x = a;
+    yield x;
+  }
+  yield inner();
+  function inner() {
+    return x;
+  }
+}
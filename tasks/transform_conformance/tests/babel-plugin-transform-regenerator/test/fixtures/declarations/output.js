@@ -0,0 +1,25 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+var x = 1;
+function gen() {
+  var a, _x, inner;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        inner = function _inner() {
+          return x;
+        };
+        _context.next = 3;
+        return 1;
+      case 3:
+        a = _context.sent;
+        _x = a;
+        _context.next = 7;
+        return _x;
+      case 7:
+        _context.next = 9;
+        return inner();
+      case 9:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,5 @@
+function* gen(other) {
+  const result = yield* other;
+  yield* [1, 2, 3];
+  return result;
+}
@@ -0,0 +1,5 @@
+function* keys(obj) {
+  for (var key in obj) {
+    yield key;
+  }
+}
@@ -0,0 +1,22 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(keys);
+function keys(obj) {
+  var key;
+  return babelHelpers.regeneratorRuntime().wrap(function keys$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0: _context.t0 = babelHelpers.regeneratorRuntime().keys(obj);
+      case 1:
+        if ((_context.t1 = _context.t0()).done) {
+          _context.next = 7;
+          break;
+        }
+        key = _context.t1.value;
+        _context.next = 5;
+        return key;
+      case 5:
+        _context.next = 1;
+        break;
+      case 7:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,9 @@
+function* gen(rows) {
+  outer: for (var i = 0; i < rows.length; i++) {
+    for (var j = 0; j < rows[i].length; j++) {
+      if (rows[i][j] === null) continue outer;
+      if (rows[i][j] === undefined) break outer;
+      yield rows[i][j];
+    }
+  }
+}
@@ -0,0 +1,44 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(rows) {
+  var i, j;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0: i = 0;
+      case 1:
+        if (!(i < rows.length)) {
+          _context.next = 16;
+          break;
+        }
+        j = 0;
+      case 3:
+        if (!(j < rows[i].length)) {
+          _context.next = 13;
+          break;
+        }
+        if (!(rows[i][j] === null)) {
+          _context.next = 6;
+          break;
+        }
+        return _context.abrupt("continue", 13);
+      case 6:
+        if (!(rows[i][j] === undefined)) {
+          _context.next = 8;
+          break;
+        }
+        return _context.abrupt("break", 16);
+      case 8:
+        _context.next = 10;
+        return rows[i][j];
+      case 10:
+        j++;
+        _context.next = 3;
+        break;
+      case 13:
+        i++;
+        _context.next = 1;
+        break;
+      case 16:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,8 @@
+function* gen(a) {
+  const x = a && (yield 1);
+  const y = a || (yield 2);
+  const z = a ?? (yield 3);
+  const w = a ? yield 4 : yield 5;
+  a.b += yield 6;
+  return call(yield 7, a.c(yield 8));
+}
@@ -0,0 +1,74 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(a) {
+  var x, y, z, w;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.t0 = a;
+        if (!_context.t0) {
+          _context.next = 5;
+          break;
+        }
+        _context.next = 4;
+        return 1;
+      case 4: _context.t0 = _context.sent;
+      case 5:
+        x = _context.t0;
+        _context.t1 = a;
+        if (_context.t1) {
+          _context.next = 11;
+          break;
+        }
+        _context.next = 10;
+        return 2;
+      case 10: _context.t1 = _context.sent;
+      case 11:
+        y = _context.t1;
+        _context.t2 = a;
+        if (_context.t2 != null) {
+          _context.next = 17;
+          break;
+        }
+        _context.next = 16;
+        return 3;
+      case 16: _context.t2 = _context.sent;
+      case 17:
+        z = _context.t2;
+        if (!a) {
+          _context.next = 24;
+          break;
+        }
+        _context.next = 21;
+        return 4;
+      case 21:
+        _context.t3 = _context.sent;
+        _context.next = 27;
+        break;
+      case 24:
+        _context.next = 26;
+        return 5;
+      case 26: _context.t3 = _context.sent;
+      case 27:
+        w = _context.t3;
+        _context.t4 = a.b;
+        _context.next = 31;
+        return 6;
+      case 31:
+        a.b = _context.t4 += _context.sent;
+        _context.t5 = call;
+        _context.next = 35;
+        return 7;
+      case 35:
+        _context.t6 = _context.sent;
+        _context.t7 = a;
+        _context.next = 39;
+        return 8;
+      case 39:
+        _context.t8 = _context.sent;
+        _context.t9 = _context.t7.c.call(_context.t7, _context.t8);
+        return _context.abrupt("return", (0, _context.t5)(_context.t6, _context.t9));
+      case 42:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
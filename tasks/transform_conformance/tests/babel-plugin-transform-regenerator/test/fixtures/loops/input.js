@@ -0,0 +1,13 @@
+function* range(n) {
+  for (var i = 0; i < n; i++) {
+    if (i % 2) continue;
+    yield i;
+  }
+  while (n > 0) {
+    n = n - (yield n);
+    if (n === 3) break;
+  }
+  do {
+    yield n;
+  } while (n--);
+}
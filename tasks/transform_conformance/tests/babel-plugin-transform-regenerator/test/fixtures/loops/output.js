@@ -0,0 +1,54 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(range);
+function range(n) {
+  var i;
+  return babelHelpers.regeneratorRuntime().wrap(function range$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0: i = 0;
+      case 1:
+        if (!(i < n)) {
+          _context.next = 9;
+          break;
+        }
+        if (!(i % 2)) {
+          _context.next = 4;
+          break;
+        }
+        return _context.abrupt("continue", 6);
+      case 4:
+        _context.next = 6;
+        return i;
+      case 6:
+        i++;
+        _context.next = 1;
+        break;
+      case 9:
+        if (!(n > 0)) {
+          _context.next = 19;
+          break;
+        }
+        _context.t0 = n;
+        _context.next = 13;
+        return n;
+      case 13:
+        _context.t1 = _context.sent;
+        n = _context.t0 - _context.t1;
+        if (!(n === 3)) {
+          _context.next = 17;
+          break;
+        }
+        return _context.abrupt("break", 19);
+      case 17:
+        _context.next = 9;
+        break;
+      case 19:
+        _context.next = 21;
+        return n;
+      case 21: if (n--) {
+        _context.next = 19;
+        break;
+      }
+      case 22:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,14 @@
+const obj = {
+  *foo() {
+    yield this;
+  },
+};
+
+class A {
+  *bar() {
+    yield 1;
+  }
+  static *baz() {
+    yield 2;
+  }
+}
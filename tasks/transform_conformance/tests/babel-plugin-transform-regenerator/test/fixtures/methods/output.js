@@ -0,0 +1,35 @@
+const obj = { foo() {
+  return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return this;
+      case 2:
+      case "end": return _context.stop();
+    }
+  }, null, this);
+} };
+class A {
+  bar() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee$2(_context2) {
+      while (1) switch (_context2.prev = _context2.next) {
+        case 0:
+          _context2.next = 2;
+          return 1;
+        case 2:
+        case "end": return _context2.stop();
+      }
+    }, null);
+  }
+  static baz() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee$3(_context3) {
+      while (1) switch (_context3.prev = _context3.next) {
+        case 0:
+          _context3.next = 2;
+          return 2;
+        case 2:
+        case "end": return _context3.stop();
+      }
+    }, null);
+  }
+}
@@ -0,0 +1,11 @@
+function* gen(x) {
+  switch (x) {
+    case 1:
+      yield "one";
+      break;
+    case yield "two":
+      return 2;
+    default:
+      yield "other";
+  }
+}
@@ -0,0 +1,44 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(x) {
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.t0 = x;
+        if (!(_context.t0 === 1)) {
+          _context.next = 5;
+          break;
+        }
+        _context.t1 = 17;
+        _context.next = 15;
+        break;
+      case 5:
+        _context.t2 = _context.t0;
+        _context.next = 8;
+        return "two";
+      case 8:
+        _context.t3 = _context.sent;
+        if (!(_context.t2 === _context.t3)) {
+          _context.next = 13;
+          break;
+        }
+        _context.t4 = 20;
+        _context.next = 14;
+        break;
+      case 13: _context.t4 = 21;
+      case 14: _context.t1 = _context.t4;
+      case 15:
+        _context.next = _context.t1;
+        break;
+      case 17:
+        _context.next = 19;
+        return "one";
+      case 19: return _context.abrupt("break", 23);
+      case 20: return _context.abrupt("return", 2);
+      case 21:
+        _context.next = 23;
+        return "other";
+      case 23:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,14 @@
+function* gen() {
+  try {
+    yield 1;
+  } catch (err) {
+    yield err.message;
+  } finally {
+    yield cleanup();
+  }
+  try {
+    yield 2;
+  } catch (e) {
+    log(e);
+  }
+}
@@ -0,0 +1,42 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.prev = 0;
+        _context.next = 3;
+        return 1;
+      case 3:
+        _context.next = 9;
+        break;
+      case 5:
+        _context.prev = 5;
+        _context.t0 = _context["catch"](0);
+        _context.next = 9;
+        return _context.t0.message;
+      case 9:
+        _context.prev = 9;
+        _context.next = 12;
+        return cleanup();
+      case 12: return _context.finish(9);
+      case 13:
+        _context.prev = 13;
+        _context.next = 16;
+        return 2;
+      case 16:
+        _context.next = 21;
+        break;
+      case 18:
+        _context.prev = 18;
+        _context.t1 = _context["catch"](13);
+        log(_context.t1);
+      case 21:
+      case "end": return _context.stop();
+    }
+  }, _marked, null, [[
+    0,
+    5,
+    9,
+    13
+  ], [13, 18]]);
+}
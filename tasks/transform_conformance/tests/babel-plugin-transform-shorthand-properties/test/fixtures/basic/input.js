@@ -0,0 +1,11 @@
+var o = {
+  a,
+  b() {
+    return 1;
+  },
+  async c() {},
+  *d() {},
+  get e() {
+    return 1;
+  },
+};
@@ -0,0 +1,11 @@
+var o = {
+  a,
+  b: function() {
+    return 1;
+  },
+  c: async function() {},
+  d: function* () {},
+  get e() {
+    return 1;
+  }
+};
@@ -0,0 +1,13 @@
+var o = {
+  a() {
+    return super.a();
+  },
+  b: function() {
+    return function() {
+      return 1;
+    };
+  },
+  c() {
+    return class extends super.c {};
+  }
+};
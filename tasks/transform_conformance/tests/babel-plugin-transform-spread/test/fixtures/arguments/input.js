@@ -0,0 +1,3 @@
+function f() {
+  return g(...arguments);
+}
@@ -0,0 +1,3 @@
+function f() {
+  return g.apply(void 0, Array.prototype.slice.call(arguments));
+}
@@ -0,0 +1,3 @@
+var a = babelHelpers.toConsumableArray(b);
+var c = [1].concat(babelHelpers.toConsumableArray(d), [2], babelHelpers.toConsumableArray(e));
+var f = [].concat([1, 2], [3]);
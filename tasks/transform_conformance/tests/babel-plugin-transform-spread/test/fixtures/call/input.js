@@ -0,0 +1,5 @@
+foo(...args);
+foo(a, ...args, b);
+obj.method(...args);
+obj.a.b(...args);
+getObj().method(...args);
@@ -0,0 +1,6 @@
+var _obj, _obj$a, _getObj;
+foo.apply(void 0, babelHelpers.toConsumableArray(args));
+foo.apply(void 0, [a].concat(babelHelpers.toConsumableArray(args), [b]));
+(_obj = obj).method.apply(_obj, babelHelpers.toConsumableArray(args));
+(_obj$a = obj.a).b.apply(_obj$a, babelHelpers.toConsumableArray(args));
+(_getObj = getObj()).method.apply(_getObj, babelHelpers.toConsumableArray(args));
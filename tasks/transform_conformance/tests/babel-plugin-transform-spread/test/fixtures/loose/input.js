@@ -0,0 +1,2 @@
+var a = [...b, 1];
+foo(...args);
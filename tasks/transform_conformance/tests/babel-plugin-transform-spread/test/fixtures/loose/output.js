@@ -0,0 +1,2 @@
+var a = [].concat(b, [1]);
+foo.apply(void 0, args);
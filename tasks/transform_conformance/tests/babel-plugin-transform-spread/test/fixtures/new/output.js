@@ -0,0 +1,2 @@
+babelHelpers.construct(Foo, babelHelpers.toConsumableArray(args));
+babelHelpers.construct(Foo, [a].concat(babelHelpers.toConsumableArray(args)));
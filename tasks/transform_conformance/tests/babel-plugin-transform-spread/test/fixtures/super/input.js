@@ -0,0 +1,5 @@
+class Foo extends Bar {
+  constructor(...args) {
+    super(...args);
+  }
+}
@@ -0,0 +1,9 @@
+let Foo = /* @__PURE__ */ function(_Bar) {
+  "use strict";
+  function Foo(...args) {
+    babelHelpers.classCallCheck(this, Foo);
+    return babelHelpers.callSuper(this, Foo, babelHelpers.toConsumableArray(args));
+  }
+  babelHelpers.inherits(Foo, _Bar);
+  return babelHelpers.createClass(Foo);
+}(Bar);
@@ -0,0 +1,5 @@
+var a = `foo`;
+var b = `foo${bar}`;
+var c = `${foo}bar${baz}`;
+var d = `${foo}${bar}`;
+var e = `a${1}b${"c"}d${foo}e${bar}`;
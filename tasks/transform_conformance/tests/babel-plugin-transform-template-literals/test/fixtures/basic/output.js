@@ -0,0 +1,5 @@
+var a = "foo";
+var b = "foo".concat(bar);
+var c = "".concat(foo, "bar").concat(baz);
+var d = "".concat(foo).concat(bar);
+var e = "a".concat(1, "b", "c", "d", foo, "e").concat(bar);
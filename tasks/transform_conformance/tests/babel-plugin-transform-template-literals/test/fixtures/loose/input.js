@@ -0,0 +1,3 @@
+var a = `foo${bar}baz${qux}`;
+var b = `${foo}`;
+tag`foo${bar}`;
@@ -0,0 +1,7 @@
+tag`foo${bar}baz`;
+tag`\n${bar}`;
+tag`\unicode`;
+a.b`c`;
+function f() {
+  return tag`foo`;
+}
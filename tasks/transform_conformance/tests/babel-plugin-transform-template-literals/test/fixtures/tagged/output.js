@@ -0,0 +1,12 @@
+var _templateObject;
+var _templateObject2;
+var _templateObject3;
+var _templateObject4;
+var _templateObject5;
+tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", "baz"])), bar);
+tag(_templateObject2 || (_templateObject2 = babelHelpers.taggedTemplateLiteral(["\n", ""], ["\\n", ""])), bar);
+tag(_templateObject3 || (_templateObject3 = babelHelpers.taggedTemplateLiteral([void 0], ["\\unicode"])));
+a.b(_templateObject4 || (_templateObject4 = babelHelpers.taggedTemplateLiteral(["c"])));
+function f() {
+  return tag(_templateObject5 || (_templateObject5 = babelHelpers.taggedTemplateLiteral(["foo"])));
+}